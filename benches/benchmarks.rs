@@ -0,0 +1,284 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Timings of encoding and decoding through the public interface, which print what they measured.
+//!
+//! cargo bench --bench benchmarks [name of the benchmark to run]
+
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use lepton_jpeg::{
+    decode_lepton, decode_lepton_streaming, decode_lepton_streaming_prefetch, decode_segment,
+    encode_lepton, encode_lepton_verify, plan_decode, EnabledFeatures,
+};
+
+fn read_file(filename: &str) -> Vec<u8> {
+    std::fs::read(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join(filename),
+    )
+    .unwrap()
+}
+
+fn encode(original: &[u8], num_threads: usize, features: &EnabledFeatures) -> Vec<u8> {
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(original),
+        &mut Cursor::new(&mut lepton),
+        num_threads,
+        features,
+    )
+    .unwrap();
+
+    lepton
+}
+
+/// the average time f takes over iterations calls, in ms
+fn time(iterations: u32, mut f: impl FnMut()) -> f64 {
+    let begin = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    begin.elapsed().as_secs_f64() * 1000.0 / f64::from(iterations)
+}
+
+/// how long encoding and decoding an image split into 16 segments takes on 1, 8 and 16 threads, where the
+/// workers pass their chunks to the coordinating thread through a channel, which is the cost of multiplexing them
+/// on top of the coding if the machine has fewer cores than that
+fn multiplexed_threads() {
+    let original = read_file("hq.jpg");
+
+    // the chunks are interleaved the same way whatever the number of threads
+    let features = EnabledFeatures {
+        target_segments: Some(16),
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+
+    let mut expected = None;
+    for num_threads in [1, 8, 16] {
+        let mut lepton = Vec::new();
+        let encode_time = time(3, || lepton = encode(&original, num_threads, &features));
+
+        let mut output = Vec::new();
+        let decode_time = time(3, || {
+            output.clear();
+            decode_lepton(&mut Cursor::new(&lepton), &mut output, num_threads).unwrap();
+        });
+
+        assert!(output == original);
+        assert!(expected.get_or_insert_with(|| lepton.clone()) == &lepton);
+
+        println!(
+            "{0} threads: encode {1:.1} ms, decode {2:.1} ms",
+            num_threads, encode_time, decode_time
+        );
+    }
+}
+
+/// how long encoding with verification takes compared to encoding alone and to decoding the whole file once it is
+/// encoded, which is what verifying did before the segments were checked while the others are still encoded. With
+/// enough cores, the time approaches the larger of encoding and verifying rather than their sum.
+fn verification_overlap() {
+    let original = read_file("hq.jpg");
+    let features = EnabledFeatures::default();
+    let num_threads = 8;
+
+    let mut lepton = Vec::new();
+    let encode_time = time(3, || lepton = encode(&original, num_threads, &features));
+
+    let decode_time = time(3, || {
+        let mut output = Vec::new();
+        decode_lepton(&mut Cursor::new(&lepton), &mut output, num_threads).unwrap();
+        assert!(output == original);
+    });
+
+    let verify_time = time(3, || {
+        let (verified, _) = encode_lepton_verify(&original, num_threads, &features).unwrap();
+        assert!(verified.len() == lepton.len());
+    });
+
+    println!(
+        "{0} threads: encode {1:.1} ms, decode {2:.1} ms, encode then decode {3:.1} ms, overlapped verification {4:.1} ms",
+        num_threads,
+        encode_time,
+        decode_time,
+        encode_time + decode_time,
+        verify_time
+    );
+}
+
+/// how many of the sample JPEGs under 100KB a second get encoded, which are encoded as a single segment on the
+/// calling thread
+fn small_images() {
+    let images: Vec<Vec<u8>> =
+        std::fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("images"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|file| file.extension().map_or(false, |e| e == "jpg"))
+            .map(|file| std::fs::read(file).unwrap())
+            .filter(|jpeg| jpeg.len() < 100 * 1024)
+            .collect();
+
+    // skip the ones that lepton can't compress
+    let features = EnabledFeatures::default();
+    let images: Vec<&Vec<u8>> = images
+        .iter()
+        .filter(|jpeg| {
+            encode_lepton(
+                &mut Cursor::new(jpeg),
+                &mut Cursor::new(Vec::new()),
+                8,
+                &features,
+            )
+            .is_ok()
+        })
+        .collect();
+
+    const ITERATIONS: u32 = 50;
+    let image_time = time(ITERATIONS, || {
+        for jpeg in &images {
+            encode(jpeg, 8, &features);
+        }
+    }) / images.len() as f64;
+
+    println!(
+        "{0} images: {1:.0} images per second",
+        images.len(),
+        1000.0 / image_time
+    );
+}
+
+/// how long decoding a segment on its own takes
+fn decode_single_segment() {
+    let original = read_file("hq.jpg");
+    let lepton = encode(&original, 8, &EnabledFeatures::all());
+
+    let plan = plan_decode(&lepton).unwrap();
+
+    let segment_time = time(10, || {
+        decode_segment(&plan, 0).unwrap();
+    });
+
+    println!("decode: {0:.3} ms for the first segment", segment_time);
+}
+
+/// hands out data no faster than rate bytes per second, like a download would
+struct RateLimitedReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    rate: f64,
+    start: Instant,
+}
+
+impl Read for RateLimitedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(16384).min(self.data.len() - self.position);
+
+        let due = Duration::from_secs_f64((self.position + n) as f64 / self.rate);
+        if let Some(wait) = due.checked_sub(self.start.elapsed()) {
+            std::thread::sleep(wait);
+        }
+
+        buf[..n].copy_from_slice(&self.data[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+/// how long decoding a file that arrives at a limited rate takes when the input is read ahead compared to when it
+/// isn't, next to how long the input takes to arrive and how long decoding it takes once it is all there.
+/// LEPTON_BENCHMARK_RATE sets the rate of the input in MB/s, 20 by default.
+fn prefetch_rate_limited() {
+    let rate = std::env::var("LEPTON_BENCHMARK_RATE")
+        .ok()
+        .and_then(|r| r.parse::<f64>().ok())
+        .unwrap_or(20.0)
+        * 1024.0
+        * 1024.0;
+
+    let original = read_file("slrcity.jpg");
+
+    // the segments of a file with a segment index follow each other, so each one can finish before the next
+    // arrives
+    let lepton = encode(
+        &original,
+        8,
+        &EnabledFeatures {
+            target_segments: Some(8),
+            segment_index: true,
+            ..EnabledFeatures::default()
+        },
+    );
+
+    let decode_time = time(1, || {
+        let mut output = Vec::new();
+        decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+        assert!(output == original);
+    });
+
+    println!(
+        "{0} bytes arrive in {1:.0} ms and decode in {2:.0} ms once they are there",
+        lepton.len(),
+        lepton.len() as f64 / rate * 1000.0,
+        decode_time
+    );
+
+    for prefetch in [false, true] {
+        let mut reader = RateLimitedReader {
+            data: &lepton,
+            position: 0,
+            rate,
+            start: Instant::now(),
+        };
+
+        let mut output = Vec::new();
+        if prefetch {
+            decode_lepton_streaming_prefetch(
+                &mut reader,
+                &mut output,
+                8,
+                &EnabledFeatures::default(),
+            )
+            .unwrap();
+        } else {
+            decode_lepton_streaming(&mut reader, &mut output, 8).unwrap();
+        }
+        assert!(output == original);
+
+        println!(
+            "{0}: decoded after {1:.0} ms",
+            if prefetch { "prefetched" } else { "streamed" },
+            reader.start.elapsed().as_secs_f64() * 1000.0
+        );
+    }
+}
+
+fn main() {
+    let benchmarks: [(&str, fn()); 5] = [
+        ("multiplexed_threads", multiplexed_threads),
+        ("verification_overlap", verification_overlap),
+        ("small_images", small_images),
+        ("decode_single_segment", decode_single_segment),
+        ("prefetch_rate_limited", prefetch_rate_limited),
+    ];
+
+    // cargo bench passes --bench, and anything else picks the benchmarks whose name contains it
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|a| !a.starts_with("--"))
+        .collect();
+
+    for (name, benchmark) in benchmarks {
+        if filters.is_empty() || filters.iter().any(|f| name.contains(f.as_str())) {
+            println!("{0}:", name);
+            benchmark();
+        }
+    }
+}
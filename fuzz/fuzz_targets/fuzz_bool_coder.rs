@@ -0,0 +1,20 @@
+#![no_main]
+
+use lepton_jpeg::testing::roundtrip_decisions;
+
+use libfuzzer_sys::fuzz_target;
+
+// roundtrips the decisions of the bool coder. Each pair of bytes is a probability out of 256 that the
+// decision is false, and the decision in the low bit with the number of times it is repeated in the
+// others, so that short inputs can reach the long runs of 0xff bytes that a carry can go through.
+fuzz_target!(|data: &[u8]| {
+    let mut decisions = Vec::new();
+    for pair in data.chunks_exact(2) {
+        let repeat = usize::from(pair[1] >> 1) + 1;
+        decisions.extend(std::iter::repeat((pair[0], pair[1] & 1 != 0)).take(repeat));
+    }
+
+    if let Err(divergence) = roundtrip_decisions(&decisions) {
+        panic!("{0}", divergence);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use lepton_jpeg::decode_lepton;
+
+use libfuzzer_sys::fuzz_target;
+
+// decodes the input as a lepton file. The corpus starts out with valid files and ones whose header
+// claims sizes the file can't back up, such as gigabytes of data after the end of the image.
+fuzz_target!(|data: &[u8]| {
+    let mut output = Vec::new();
+
+    let _ = decode_lepton(&mut Cursor::new(data), &mut output, 8);
+});
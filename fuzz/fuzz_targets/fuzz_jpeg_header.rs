@@ -0,0 +1,31 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use lepton_jpeg::{encode_lepton, EnabledFeatures};
+
+use libfuzzer_sys::fuzz_target;
+
+// targets the JPEG marker parsing directly by always supplying a valid SOI,
+// so the fuzzer doesn't have to discover it before it can reach the segment loop
+fuzz_target!(|data: &[u8]| {
+    let mut input = vec![0xffu8, 0xd8];
+    input.extend_from_slice(data);
+
+    let mut output = Vec::new();
+
+    // keep the jpeg dimensions small otherwise the fuzzer gets really slow
+    let features = EnabledFeatures {
+        progressive: true,
+        max_jpeg_height: 1024,
+        max_jpeg_width: 1024,
+        ..EnabledFeatures::default()
+    };
+
+    let _ = encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut output),
+        8,
+        &features,
+    );
+});
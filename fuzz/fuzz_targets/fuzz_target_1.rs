@@ -19,6 +19,7 @@ fuzz_target!(|data: &[u8]| {
             progressive: true,
             max_jpeg_height: 1024,
             max_jpeg_width: 1024,
+            ..EnabledFeatures::default()
         };
 
         r = encode_lepton(&mut Cursor::new(&data), &mut writer, 8, &features);
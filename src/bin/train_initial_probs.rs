@@ -0,0 +1,47 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Creates the table of initial counts that the trained initial probabilities start the model with.
+//!
+//! usage: train_initial_probs <output.rs> <image.jpg>...
+//!
+//! The output replaces src/structs/trained_initial_counts.rs.
+
+use std::env;
+use std::fs;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        eprintln!("usage: train_initial_probs <output.rs> <image.jpg>...");
+        process::exit(1);
+    }
+
+    let jpegs: Vec<Vec<u8>> = args[2..]
+        .iter()
+        .map(|path| {
+            fs::read(path).unwrap_or_else(|e| {
+                eprintln!("failed to read {0}: {1}", path, e);
+                process::exit(1);
+            })
+        })
+        .collect();
+    let jpegs: Vec<&[u8]> = jpegs.iter().map(|j| j.as_slice()).collect();
+
+    match lepton_jpeg::train_initial_probs(&jpegs) {
+        Ok(source) => {
+            if let Err(e) = fs::write(&args[1], source) {
+                eprintln!("failed to write {0}: {1}", args[1], e);
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("error training the initial probabilities: {0}", e);
+            process::exit(e.exit_code as i32);
+        }
+    }
+}
@@ -11,7 +11,7 @@ pub enum JPegDecodeStatus {
     ScanCompleted,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Copy, Clone)]
 pub enum JPegType {
     Unknown,
     Sequential,
@@ -334,12 +334,99 @@ pub const MAX_THREADS_SUPPORTED_BY_LEPTON_FORMAT: usize = 16; // Number of threa
 pub const EOI: [u8; 2] = [0xFF, crate::jpeg_code::EOI]; // EOI segment
 pub const SOI: [u8; 2] = [0xFF, crate::jpeg_code::SOI]; // SOI segment
 pub const LEPTON_FILE_HEADER: [u8; 2] = [0xcf, 0x84]; // the tau symbol for a tau lepton in utf-8
+pub const LEPTON_CONTAINER_HEADER: [u8; 4] = [0xcf, 0x84, b'L', b'C']; // in place of the version of a single file
 pub const LEPTON_HEADER_BASELINE_JPEG_TYPE: [u8; 1] = [b'Z'];
 pub const LEPTON_HEADER_PROGRESSIVE_JPEG_TYPE: [u8; 1] = [b'X'];
+pub const LEPTON_HEADER_CHUNKED_JPEG_TYPE: [u8; 1] = [b'Y'];
 pub const LEPTON_HEADER_MARKER: [u8; 3] = *b"HDR";
 pub const LEPTON_HEADER_PAD_MARKER: [u8; 3] = *b"P0D";
+pub const LEPTON_HEADER_PAD_EXCEPTIONS_MARKER: [u8; 3] = *b"PDX";
 pub const LEPTON_HEADER_JPG_RESTARTS_MARKER: [u8; 3] = *b"CRS";
 pub const LEPTON_HEADER_JPG_RESTART_ERRORS_MARKER: [u8; 3] = *b"FRS";
+pub const LEPTON_HEADER_RESTART_EXCEPTIONS_MARKER: [u8; 3] = *b"RSX";
+pub const LEPTON_HEADER_SCAN_SEGMENTS_MARKER: [u8; 3] = *b"SSG";
+pub const LEPTON_HEADER_CHECKSUM_MARKER: [u8; 3] = *b"CRC";
+pub const LEPTON_HEADER_ENCODER_INFO_MARKER: [u8; 3] = *b"ENC";
+pub const LEPTON_HEADER_FILE_TYPE_MARKER: [u8; 3] = *b"TYP";
+pub const LEPTON_HEADER_SEGMENT_INDEX_MARKER: [u8; 3] = *b"SIX";
+pub const LEPTON_HEADER_ORIGINAL_SIZE_MARKER: [u8; 3] = *b"OSZ";
+pub const LEPTON_HEADER_PASSTHROUGH_MARKER: [u8; 3] = *b"PST";
+pub const LEPTON_HEADER_LARGE_SIZES_MARKER: [u8; 3] = *b"LSZ";
+pub const LEPTON_HEADER_MODEL_PRIMER_MARKER: [u8; 3] = *b"PRM";
+pub const LEPTON_HEADER_MODEL_TUNING_MARKER: [u8; 3] = *b"TUN";
+
+/// the encoder info has a fixed size so that the header size stays predictable
+pub const ENCODER_INFO_VERSION_SIZE: usize = 16;
+pub const ENCODER_INFO_GIT_REVISION_SIZE: usize = 40;
+pub const ENCODER_INFO_SIZE: usize = LEPTON_HEADER_ENCODER_INFO_MARKER.len()
+    + ENCODER_INFO_VERSION_SIZE
+    + ENCODER_INFO_GIT_REVISION_SIZE
+    + 4;
+
+/// optional capabilities a file can require from the decoder, stored in the lepton header
+pub const LEPTON_FEATURE_RESTART_EXCEPTIONS: u32 = 1 << 0;
+pub const LEPTON_FEATURE_SCAN_SEGMENTS: u32 = 1 << 1;
+pub const LEPTON_FEATURE_MULTI_SCAN_SEQUENTIAL: u32 = 1 << 2;
+pub const LEPTON_FEATURE_CHECKSUM: u32 = 1 << 3;
+pub const LEPTON_FEATURE_SEGMENT_INDEX: u32 = 1 << 4;
+pub const LEPTON_FEATURE_ORIGINAL_SIZE: u32 = 1 << 5;
+pub const LEPTON_FEATURE_PASSTHROUGH: u32 = 1 << 6;
+/// the sizes and offsets that can exceed 4GB are stored with 64 bits, see LeptonHeader::large_sizes
+pub const LEPTON_FEATURE_LARGE_SIZES: u32 = 1 << 7;
+/// the multiplexed data is followed by the CRC32 of the data of each thread segment, see LeptonHeader::segment_checksums
+pub const LEPTON_FEATURE_SEGMENT_CHECKSUMS: u32 = 1 << 8;
+/// the file only keeps what is needed to recreate the image, so the decoder doesn't recreate the original file
+pub const LEPTON_FEATURE_COEFFICIENTS_ONLY: u32 = 1 << 9;
+/// the model of each thread segment starts from a primer instead of the default counts, see ModelPrimer
+pub const LEPTON_FEATURE_MODEL_PRIMER: u32 = 1 << 10;
+/// the branches of the model adapt with other values than the defaults, see ModelTuning. Only builds with the
+/// experimental-tuning feature can decode these files.
+pub const LEPTON_FEATURE_MODEL_TUNING: u32 = 1 << 11;
+/// the Cr component is coded with its own model instead of sharing the chroma model with Cb, see
+/// LeptonHeader::separate_chroma_models
+pub const LEPTON_FEATURE_SEPARATE_CHROMA_MODELS: u32 = 1 << 12;
+
+/// the coefficients are coded with another CoefficientCodec than the model, see CoefficientCodecKind. Only
+/// builds with the experimental-codec feature can decode these files.
+pub const LEPTON_FEATURE_EXPERIMENTAL_CODEC: u32 = 1 << 13;
+/// the DC is predicted in steps of its quantization, see LeptonHeader::quantized_dc_prediction
+pub const LEPTON_FEATURE_QUANTIZED_DC_PREDICTION: u32 = 1 << 14;
+/// the DC is predicted from the DCs of the neighbor blocks, see LeptonHeader::fast_dc_prediction
+pub const LEPTON_FEATURE_FAST_DC_PREDICTION: u32 = 1 << 15;
+/// the model of each thread segment starts from counts trained on photos instead of the uniform ones, see
+/// LeptonHeader::trained_initial_probs
+pub const LEPTON_FEATURE_TRAINED_INITIAL_PROBS: u32 = 1 << 16;
+
+/// all the features that this version can decode
+pub const LEPTON_SUPPORTED_FEATURES: u32 = LEPTON_STANDARD_FEATURES
+    | if cfg!(feature = "experimental-tuning") {
+        LEPTON_FEATURE_MODEL_TUNING
+    } else {
+        0
+    }
+    | if cfg!(feature = "experimental-codec") {
+        LEPTON_FEATURE_EXPERIMENTAL_CODEC
+    } else {
+        0
+    };
+
+/// the features that all builds can decode
+pub const LEPTON_STANDARD_FEATURES: u32 = LEPTON_FEATURE_RESTART_EXCEPTIONS
+    | LEPTON_FEATURE_SCAN_SEGMENTS
+    | LEPTON_FEATURE_MULTI_SCAN_SEQUENTIAL
+    | LEPTON_FEATURE_CHECKSUM
+    | LEPTON_FEATURE_SEGMENT_INDEX
+    | LEPTON_FEATURE_ORIGINAL_SIZE
+    | LEPTON_FEATURE_PASSTHROUGH
+    | LEPTON_FEATURE_LARGE_SIZES
+    | LEPTON_FEATURE_SEGMENT_CHECKSUMS
+    | LEPTON_FEATURE_COEFFICIENTS_ONLY
+    | LEPTON_FEATURE_MODEL_PRIMER
+    | LEPTON_FEATURE_SEPARATE_CHROMA_MODELS
+    | LEPTON_FEATURE_QUANTIZED_DC_PREDICTION
+    | LEPTON_FEATURE_FAST_DC_PREDICTION
+    | LEPTON_FEATURE_TRAINED_INITIAL_PROBS;
+
 pub const LEPTON_HEADER_LUMA_SPLIT_MARKER: [u8; 2] = *b"HH";
 pub const LEPTON_HEADER_EARLY_EOF_MARKER: [u8; 3] = *b"EEE";
 pub const LEPTON_HEADER_PREFIX_GARBAGE_MARKER: [u8; 3] = *b"PGR";
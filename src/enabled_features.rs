@@ -1,4 +1,210 @@
+use std::sync::Arc;
+
+use crate::consts::*;
+use crate::structs::model::ModelSnapshotHook;
+use crate::structs::model_primer::ModelPrimer;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::SymmetryRecorder;
+use crate::structs::thread_lifecycle::ThreadLifecycleHook;
+
+/// revision of the lepton format the encoder writes, so that files can be read by decoders that were deployed
+/// before the newer parts of the format existed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LeptonVersion {
+    /// the format of the original C++ encoder: no feature flags and none of the optional sections
+    V1,
+
+    /// adds the feature flags and the sections and trailers that came with them: pad bit and restart
+    /// exceptions, scan segments, multi-scan sequential images, the CRC and size of the original file, the
+    /// segment index, passthrough files, 64-bit sizes and the encoder info
+    V2,
+
+    /// adds segment checksums, the file type and coefficients only files
+    V3,
+
+    /// adds model primers
+    V4,
+
+    /// adds separate chroma models
+    V5,
+
+    /// adds quantized and fast DC prediction
+    V6,
+
+    /// adds trained initial probabilities
+    #[default]
+    V7,
+}
+
+impl LeptonVersion {
+    /// all the versions, oldest first
+    pub const ALL: [LeptonVersion; 7] = [
+        LeptonVersion::V1,
+        LeptonVersion::V2,
+        LeptonVersion::V3,
+        LeptonVersion::V4,
+        LeptonVersion::V5,
+        LeptonVersion::V6,
+        LeptonVersion::V7,
+    ];
+
+    /// the oldest version that has all of required_features, which is the version of a file with them as far as
+    /// decoding it goes, or the newest version if none has all of them
+    pub fn of_features(required_features: u32) -> LeptonVersion {
+        LeptonVersion::ALL
+            .into_iter()
+            .find(|v| required_features & !v.supported_features() == 0)
+            .unwrap_or(LeptonVersion::V7)
+    }
+
+    /// the feature flags a decoder for this version understands
+    pub fn supported_features(&self) -> u32 {
+        match self {
+            LeptonVersion::V1 => 0,
+            LeptonVersion::V2 => {
+                LEPTON_FEATURE_RESTART_EXCEPTIONS
+                    | LEPTON_FEATURE_SCAN_SEGMENTS
+                    | LEPTON_FEATURE_MULTI_SCAN_SEQUENTIAL
+                    | LEPTON_FEATURE_CHECKSUM
+                    | LEPTON_FEATURE_SEGMENT_INDEX
+                    | LEPTON_FEATURE_ORIGINAL_SIZE
+                    | LEPTON_FEATURE_PASSTHROUGH
+                    | LEPTON_FEATURE_LARGE_SIZES
+            }
+            LeptonVersion::V3 => {
+                LeptonVersion::V2.supported_features()
+                    | LEPTON_FEATURE_SEGMENT_CHECKSUMS
+                    | LEPTON_FEATURE_COEFFICIENTS_ONLY
+            }
+            LeptonVersion::V4 => {
+                LeptonVersion::V5.supported_features() & !LEPTON_FEATURE_SEPARATE_CHROMA_MODELS
+            }
+            LeptonVersion::V5 => {
+                LeptonVersion::V6.supported_features()
+                    & !(LEPTON_FEATURE_QUANTIZED_DC_PREDICTION | LEPTON_FEATURE_FAST_DC_PREDICTION)
+            }
+            LeptonVersion::V6 => LEPTON_SUPPORTED_FEATURES & !LEPTON_FEATURE_TRAINED_INITIAL_PROBS,
+            LeptonVersion::V7 => LEPTON_SUPPORTED_FEATURES,
+        }
+    }
+}
+
+impl TryFrom<u32> for LeptonVersion {
+    type Error = u32;
+
+    fn try_from(version: u32) -> Result<Self, u32> {
+        match version {
+            1 => Ok(LeptonVersion::V1),
+            2 => Ok(LeptonVersion::V2),
+            3 => Ok(LeptonVersion::V3),
+            4 => Ok(LeptonVersion::V4),
+            5 => Ok(LeptonVersion::V5),
+            6 => Ok(LeptonVersion::V6),
+            7 => Ok(LeptonVersion::V7),
+            _ => Err(version),
+        }
+    }
+}
+
+/// what a lepton file preserves of the original JPEG
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EncodeMode {
+    /// the decoder recreates the original file byte for byte
+    #[default]
+    Exact,
+
+    /// only the coefficients and the header segments needed to decode them (SOF, DQT, DHT, DRI and SOS) are
+    /// kept, so the decoder creates a clean JPEG with the same pixels rather than the original file. APPn and
+    /// COM segments (with EXIF, XMP and color profiles) and any data after the image are dropped.
+    CoefficientsOnly,
+}
+
+/// Experimental: how the probability of each branch of the model adapts to the bits it codes. Each branch
+/// counts the zeros and ones it has seen, and the probability of a zero is the share of zeros. The defaults are
+/// what the format has always used, and any other values are recorded in the lepton file
+/// (LEPTON_FEATURE_MODEL_TUNING) so that the decoder uses the same ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelTuning {
+    /// once a count reaches max_count, both counts are divided by 2^adaptation_shift, so larger values forget
+    /// older bits faster. Between 1 and 7.
+    pub adaptation_shift: u8,
+
+    /// largest value of a count, so smaller values adapt faster and larger ones make more confident
+    /// predictions. At least 2.
+    pub max_count: u8,
+
+    /// probability of a zero (out of 256) that each branch starts with, which is approximated with the smallest
+    /// counts that give it. At least 1.
+    pub initial_probability: u8,
+}
+
+impl Default for ModelTuning {
+    fn default() -> Self {
+        Self {
+            adaptation_shift: 1,
+            max_count: 255,
+            initial_probability: 128,
+        }
+    }
+}
+
+impl ModelTuning {
+    /// whether the values can be used, since the counts would otherwise get stuck or overflow
+    pub fn is_valid(&self) -> bool {
+        (1..=7).contains(&self.adaptation_shift)
+            && self.max_count >= 2
+            && self.initial_probability >= 1
+    }
+
+    /// the packed counts (zeros in the high byte, ones in the low byte) that a branch starts with: the
+    /// smallest ones whose probability is closest to initial_probability
+    pub fn initial_counts(&self) -> u16 {
+        let target = i32::from(self.initial_probability);
+
+        let mut best = (i32::MAX, u32::MAX, 0x0101);
+        for zeros in 1..=u32::from(self.max_count) {
+            for ones in 1..=u32::from(self.max_count) {
+                let probability = ((zeros << 8) / (zeros + ones)) as i32;
+                let candidate = ((probability - target).abs(), zeros + ones);
+                if candidate < (best.0, best.1) {
+                    best = (candidate.0, candidate.1, (zeros << 8) | ones);
+                }
+            }
+        }
+
+        best.2 as u16
+    }
+}
+
+/// Experimental: which implementation of CoefficientCodec codes the coefficients of the blocks. Anything other
+/// than the model is recorded in the lepton file (LEPTON_FEATURE_EXPERIMENTAL_CODEC), which can then only be
+/// decoded by builds with the experimental-codec feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoefficientCodecKind {
+    /// the model the format has always used
+    #[default]
+    Model,
+
+    /// like the model, but with a branch for the sign of each coefficient of the 7x7 block instead of one for
+    /// all of them, see PositionalSignCodec
+    PositionalSign,
+}
+
+/// what the encoder does when running as many thread segments at the same time as there are threads would take it
+/// over EnabledFeatures::memory_budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryBudgetPolicy {
+    /// runs fewer segments at the same time, down to one after the other, and only fails if a single segment
+    /// doesn't fit
+    #[default]
+    Serialize,
+
+    /// fails with ExceedsMemoryBudget as soon as a segment doesn't fit, rather than take longer than expected
+    Fail,
+}
+
 // features that are enabled in the encoder. Turn off for potential backward compat issues.
+#[derive(Clone)]
 pub struct EnabledFeatures {
     /// disables reading of progressive images
     pub progressive: bool,
@@ -8,6 +214,154 @@ pub struct EnabledFeatures {
 
     // maxmimum jpeg height
     pub max_jpeg_height: i32,
+
+    /// stores a CRC of the original JPEG in the lepton file, which the decoder checks the output against
+    pub checksum: bool,
+
+    /// stores the exact size of the original JPEG in the lepton file, which the decoder checks the output against
+    pub original_size: bool,
+
+    /// stores a CRC of the compressed data of each thread segment, so that the decoder can tell which part of
+    /// a damaged file is corrupted
+    pub segment_checksums: bool,
+
+    /// writes the thread segments one after the other followed by an index of where each one is, so that
+    /// a decoder can seek to the rows it needs. Requires buffering the entire output while encoding.
+    pub segment_index: bool,
+
+    /// stores the original JPEG as is inside the lepton file if encoding it doesn't make it smaller, so that
+    /// the lepton file is never more than a small fixed header larger than the original. Older decoders can't
+    /// read these files.
+    pub passthrough: bool,
+
+    /// splits the image into thread segments based only on the image itself rather than on max_threads, so that
+    /// the same JPEG always encodes to the same bytes no matter how many cores the encoding machine has. The
+    /// segments are still encoded in parallel on up to max_threads threads. Enable this when the output is
+    /// verified against or deduplicated with output from other machines.
+    ///
+    /// The chunks of the segments are also written in turn, one of each segment that has any left, rather than
+    /// in the order the workers happen to finish them. This costs memory: the chunks of a segment whose turn
+    /// hasn't come yet are held until the segments before it catch up, which can be most of the output if the
+    /// first segment is slow to encode. With segment_index the segments are written after each other anyway.
+    pub deterministic: bool,
+
+    /// number of thread segments to split the image into instead of one per available thread, for example so
+    /// that a decoder with more cores than the encoder can use all of them. Clamped to at least one MCU row per
+    /// segment and to the 16 segments the format supports.
+    pub target_segments: Option<usize>,
+
+    /// images with fewer blocks than this in all their components are encoded as a single thread segment on the
+    /// calling thread, without any workers. For images this small, the time a second segment saves doesn't make
+    /// up for the few hundred bytes its model costs in learning from scratch. Zero turns this off, and it doesn't
+    /// apply when target_segments is set.
+    pub single_segment_max_blocks: u32,
+
+    /// amount of output each encoder thread collects before it is interleaved into the file. None picks a
+    /// size based on how much data each thread has.
+    pub chunk_size: Option<usize>,
+
+    /// maximum size of the data after the end of the image (including the EOI marker it starts with). The encoder
+    /// refuses JPEGs with more, and the decoder refuses files whose header claims more before allocating it, so
+    /// that a tiny file can't make it allocate gigabytes.
+    pub max_trailing_bytes: u64,
+
+    /// whether the file recreates the original exactly or only its image
+    pub encode_mode: EncodeMode,
+
+    /// revision of the format to write. The encoder fails with FeatureRequiresNewerVersion rather than write
+    /// anything the version doesn't have, whether an option asks for it or the image needs it. The decoder
+    /// refuses files that need features the version doesn't have, the same way a decoder of that version would.
+    pub format_version: LeptonVersion,
+
+    /// Experimental: primer that the model of each thread segment starts from, see ModelPrimer. The decoder
+    /// needs the same primer to decode the file, and fails with MissingDictionary without it.
+    pub model_primer: Option<Arc<ModelPrimer>>,
+
+    /// Experimental: how the model adapts, see ModelTuning. Values other than the defaults are recorded in the
+    /// lepton file, which can then only be decoded by builds with the experimental-tuning feature.
+    #[cfg(feature = "experimental-tuning")]
+    pub model_tuning: ModelTuning,
+
+    /// Experimental: how the coefficients are coded, see CoefficientCodecKind. Anything other than the model is
+    /// recorded in the lepton file, which can then only be decoded by builds with the experimental-codec
+    /// feature.
+    #[cfg(feature = "experimental-codec")]
+    pub coefficient_codec: CoefficientCodecKind,
+
+    /// codes the Cr component with its own model instead of sharing the chroma model with Cb, which helps images
+    /// where the statistics of the two differ strongly. Most photos come out slightly larger, since the second
+    /// model has to learn from scratch. Doubles the memory the model of each thread needs.
+    pub separate_chroma_models: bool,
+
+    /// predicts the DC of each block in steps of its quantization, see LeptonHeader::quantized_dc_prediction.
+    /// Helps images with fine quantization of the DC, which most photos from cameras and phones have.
+    pub quantized_dc_prediction: bool,
+
+    /// trades encoding speed for the size of the lepton file: 0 predicts the DC from the DCs of the neighbor
+    /// blocks, which saves the IDCT of each block but makes the files a few percent larger (see
+    /// LeptonHeader::fast_dc_prediction), 1 is the default, and 2 turns on separate_chroma_models and
+    /// quantized_dc_prediction. The level only picks the feature flags the file is written with, so decoding
+    /// doesn't need to know it. Format versions without the flags of level 0 or 2 fall back to level 1.
+    pub compression_effort: u8,
+
+    /// starts the model of each thread segment from counts trained on a set of photos instead of uniform ones,
+    /// which mostly helps small images, where the model has few blocks to learn from. Images that are unlike
+    /// photos, such as drawings and scans, can come out larger.
+    pub trained_initial_probs: bool,
+
+    /// allocates the whole model of each thread before it starts coding, instead of allocating the rarely used
+    /// parts as they are first needed. Costs a few hundred KB per thread even for tiny images, but avoids
+    /// allocations while coding. Doesn't change the lepton file.
+    pub low_latency: bool,
+
+    /// makes the decoder check that the data of each thread segment ends right after the flush of the encoder,
+    /// and fail with TrailingGarbageInSegment if anything comes after it. The decoder doesn't need what comes
+    /// after the last block, so otherwise garbage there goes unnoticed. On by default in debug builds. Doesn't
+    /// change the lepton file.
+    pub strict_segment_end: bool,
+
+    /// number of pieces of 64KB of output each decoder thread can have waiting to be written before it waits for
+    /// the writer, which caps the memory the decoder uses when it decodes faster than the output is written, for
+    /// example to a slow network connection. At least 1. Doesn't change the lepton file.
+    pub max_output_buffers: usize,
+
+    /// most bytes of the multiplexed data that the decoder reads ahead for thread segments that no worker has
+    /// got to yet, after which it waits for the workers. A worker that waits for data lets the reader go on
+    /// anyway, since the chunks it needs can come after the ones that are held, so this only bounds what is
+    /// buffered for files whose segments follow each other, such as the ones written with segment_index.
+    /// Doesn't change the lepton file.
+    pub max_read_ahead: u64,
+
+    /// most bytes that the block images of the image, the model of each thread segment and the buffers of its
+    /// output can take at the same time while encoding, counting each at the most it can grow to. The image has
+    /// to fit by itself, and memory_budget_policy says what happens when there isn't room for another segment.
+    /// None doesn't limit it. Doesn't change the lepton file.
+    pub memory_budget: Option<u64>,
+
+    /// whether the encoder runs fewer segments at the same time or fails when memory_budget is reached
+    pub memory_budget_policy: MemoryBudgetPolicy,
+
+    /// makes the encoder decode each thread segment as soon as it is encoded, on a worker of its own while the
+    /// other segments are still being encoded, and fail with VerificationContentMismatch at the first block whose
+    /// coefficients come back different. The lepton file is put together in memory, and its header, the
+    /// multiplexed data, the segment index, the segment checksums and the file size are checked against the
+    /// segments before it is written out. The JPEG is then recreated from the segments with the header of the
+    /// file and checked against the size and CRC of the original, except in coefficients only mode. The decoding
+    /// isn't counted against memory_budget. encode_lepton_verify turns this on. Doesn't change the lepton file.
+    pub verify_segments: bool,
+
+    /// Debugging aid: called with snapshots of the model as the encoder or decoder reaches the blocks it asks
+    /// for, see ModelSnapshotHook. Doesn't change the lepton file.
+    pub model_snapshot_hook: Option<ModelSnapshotHook>,
+
+    /// Instrumentation: called on the worker threads of the encoder and the decoder as they start and finish
+    /// each thread segment, see ThreadLifecycleHook. Doesn't change the lepton file.
+    pub thread_lifecycle_hook: Option<ThreadLifecycleHook>,
+
+    /// Debugging aid: logs the decisions of the bool coder of each thread segment, see SymmetryRecorder. Doesn't
+    /// change the lepton file.
+    #[cfg(feature = "debug-symmetry")]
+    pub symmetry_recorder: Option<SymmetryRecorder>,
 }
 
 impl Default for EnabledFeatures {
@@ -16,17 +370,229 @@ impl Default for EnabledFeatures {
             progressive: true,
             max_jpeg_width: 16386,
             max_jpeg_height: 16386,
+            checksum: true,
+            original_size: true,
+            segment_checksums: true,
+            segment_index: false,
+            passthrough: false,
+            deterministic: false,
+            target_segments: None,
+            single_segment_max_blocks: 8192,
+            chunk_size: None,
+            max_trailing_bytes: 1 << 30,
+            encode_mode: EncodeMode::Exact,
+            format_version: LeptonVersion::V7,
+            model_primer: None,
+            #[cfg(feature = "experimental-tuning")]
+            model_tuning: ModelTuning::default(),
+            #[cfg(feature = "experimental-codec")]
+            coefficient_codec: CoefficientCodecKind::Model,
+            separate_chroma_models: false,
+            quantized_dc_prediction: false,
+            compression_effort: 1,
+            trained_initial_probs: false,
+            low_latency: false,
+            strict_segment_end: cfg!(debug_assertions),
+            max_output_buffers: 64,
+            max_read_ahead: 64 * 1024 * 1024,
+            memory_budget: None,
+            memory_budget_policy: MemoryBudgetPolicy::Serialize,
+            verify_segments: false,
+            model_snapshot_hook: None,
+            thread_lifecycle_hook: None,
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_recorder: None,
         }
     }
 }
 
 impl EnabledFeatures {
+    /// the boolean options packed into bits (progressive = 1, checksum = 2, segment_index = 4, original_size = 8,
+    /// passthrough = 16, segment_checksums = 32, deterministic = 64, coefficients only encode_mode = 128,
+    /// model_primer = 256, model_tuning other than the defaults = 512, separate_chroma_models = 1024,
+    /// coefficient_codec other than the model = 2048, quantized_dc_prediction = 4096, compression_effort 0 = 8192,
+    /// trained_initial_probs = 16384), which is how they are recorded in the lepton file. The options that
+    /// compression_effort turns on are recorded as if they were set.
+    pub fn to_bits(&self) -> u32 {
+        u32::from(self.progressive)
+            | (u32::from(self.checksum) << 1)
+            | (u32::from(self.segment_index) << 2)
+            | (u32::from(self.original_size) << 3)
+            | (u32::from(self.passthrough) << 4)
+            | (u32::from(self.segment_checksums) << 5)
+            | (u32::from(self.deterministic) << 6)
+            | (u32::from(self.encode_mode == EncodeMode::CoefficientsOnly) << 7)
+            | (u32::from(self.model_primer.is_some()) << 8)
+            | (u32::from(self.get_model_tuning() != ModelTuning::default()) << 9)
+            | (u32::from(self.get_separate_chroma_models()) << 10)
+            | (u32::from(self.get_coefficient_codec() != CoefficientCodecKind::Model) << 11)
+            | (u32::from(self.get_quantized_dc_prediction()) << 12)
+            | (u32::from(self.get_fast_dc_prediction()) << 13)
+            | (u32::from(self.trained_initial_probs) << 14)
+    }
+
     /// parameters that allow everything
     pub fn all() -> Self {
         Self {
             progressive: true,
             max_jpeg_height: i32::MAX,
             max_jpeg_width: i32::MAX,
+            checksum: true,
+            original_size: true,
+            segment_checksums: true,
+            segment_index: true,
+            passthrough: true,
+            deterministic: false,
+            target_segments: None,
+            single_segment_max_blocks: 8192,
+            chunk_size: None,
+            max_trailing_bytes: u64::MAX,
+            encode_mode: EncodeMode::Exact,
+            format_version: LeptonVersion::V7,
+            model_primer: None,
+            #[cfg(feature = "experimental-tuning")]
+            model_tuning: ModelTuning::default(),
+            #[cfg(feature = "experimental-codec")]
+            coefficient_codec: CoefficientCodecKind::Model,
+            separate_chroma_models: false,
+            quantized_dc_prediction: false,
+            compression_effort: 1,
+            trained_initial_probs: false,
+            low_latency: false,
+            strict_segment_end: cfg!(debug_assertions),
+            max_output_buffers: 64,
+            max_read_ahead: 64 * 1024 * 1024,
+            memory_budget: None,
+            memory_budget_policy: MemoryBudgetPolicy::Serialize,
+            verify_segments: false,
+            model_snapshot_hook: None,
+            thread_lifecycle_hook: None,
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_recorder: None,
         }
     }
+
+    /// the model tuning to encode with, which is always the default without the experimental-tuning feature
+    pub fn get_model_tuning(&self) -> ModelTuning {
+        #[cfg(feature = "experimental-tuning")]
+        return self.model_tuning;
+
+        #[cfg(not(feature = "experimental-tuning"))]
+        ModelTuning::default()
+    }
+
+    /// the codec to encode with, which is always the model without the experimental-codec feature
+    pub fn get_coefficient_codec(&self) -> CoefficientCodecKind {
+        #[cfg(feature = "experimental-codec")]
+        return self.coefficient_codec;
+
+        #[cfg(not(feature = "experimental-codec"))]
+        CoefficientCodecKind::Model
+    }
+
+    /// whether Cr gets its own model, either because separate_chroma_models is set or because of the
+    /// compression_effort
+    pub fn get_separate_chroma_models(&self) -> bool {
+        self.separate_chroma_models || self.compression_effort >= 2
+    }
+
+    /// whether the DC is predicted in steps of its quantization, either because quantized_dc_prediction is set
+    /// or because of the compression_effort
+    pub fn get_quantized_dc_prediction(&self) -> bool {
+        self.quantized_dc_prediction || self.compression_effort >= 2
+    }
+
+    /// whether the DC is predicted from the DCs of the neighbor blocks, which a compression_effort of 0 does
+    pub fn get_fast_dc_prediction(&self) -> bool {
+        self.compression_effort == 0
+    }
+
+    /// targets format_version, turning off the options that store something the version doesn't have
+    pub fn with_format_version(self, format_version: LeptonVersion) -> Self {
+        let supported = format_version.supported_features();
+
+        Self {
+            checksum: self.checksum && supported & LEPTON_FEATURE_CHECKSUM != 0,
+            original_size: self.original_size && supported & LEPTON_FEATURE_ORIGINAL_SIZE != 0,
+            segment_checksums: self.segment_checksums
+                && supported & LEPTON_FEATURE_SEGMENT_CHECKSUMS != 0,
+            segment_index: self.segment_index && supported & LEPTON_FEATURE_SEGMENT_INDEX != 0,
+            passthrough: self.passthrough && supported & LEPTON_FEATURE_PASSTHROUGH != 0,
+            model_primer: self
+                .model_primer
+                .filter(|_| supported & LEPTON_FEATURE_MODEL_PRIMER != 0),
+            #[cfg(feature = "experimental-tuning")]
+            model_tuning: if supported & LEPTON_FEATURE_MODEL_TUNING != 0 {
+                self.model_tuning
+            } else {
+                ModelTuning::default()
+            },
+            #[cfg(feature = "experimental-codec")]
+            coefficient_codec: if supported & LEPTON_FEATURE_EXPERIMENTAL_CODEC != 0 {
+                self.coefficient_codec
+            } else {
+                CoefficientCodecKind::Model
+            },
+            separate_chroma_models: self.separate_chroma_models
+                && supported & LEPTON_FEATURE_SEPARATE_CHROMA_MODELS != 0,
+            quantized_dc_prediction: self.quantized_dc_prediction
+                && supported & LEPTON_FEATURE_QUANTIZED_DC_PREDICTION != 0,
+            trained_initial_probs: self.trained_initial_probs
+                && supported & LEPTON_FEATURE_TRAINED_INITIAL_PROBS != 0,
+            compression_effort: match self.compression_effort {
+                0 if supported & LEPTON_FEATURE_FAST_DC_PREDICTION == 0 => 1,
+                2 if supported & LEPTON_FEATURE_QUANTIZED_DC_PREDICTION == 0 => 1,
+                effort => effort,
+            },
+            format_version,
+            ..self
+        }
+    }
+
+    /// the feature flags that the enabled options need, whatever the image
+    pub fn required_features(&self) -> u32 {
+        let mut features = 0;
+
+        if self.checksum {
+            features |= LEPTON_FEATURE_CHECKSUM;
+        }
+        if self.original_size {
+            features |= LEPTON_FEATURE_ORIGINAL_SIZE;
+        }
+        if self.segment_checksums {
+            features |= LEPTON_FEATURE_SEGMENT_CHECKSUMS;
+        }
+        if self.segment_index {
+            features |= LEPTON_FEATURE_SEGMENT_INDEX;
+        }
+        if self.passthrough {
+            features |= LEPTON_FEATURE_PASSTHROUGH;
+        }
+        if self.encode_mode == EncodeMode::CoefficientsOnly {
+            features |= LEPTON_FEATURE_COEFFICIENTS_ONLY;
+        }
+        if self.model_primer.is_some() {
+            features |= LEPTON_FEATURE_MODEL_PRIMER;
+        }
+        if self.get_model_tuning() != ModelTuning::default() {
+            features |= LEPTON_FEATURE_MODEL_TUNING;
+        }
+        if self.get_separate_chroma_models() {
+            features |= LEPTON_FEATURE_SEPARATE_CHROMA_MODELS;
+        }
+        if self.get_coefficient_codec() != CoefficientCodecKind::Model {
+            features |= LEPTON_FEATURE_EXPERIMENTAL_CODEC;
+        }
+        if self.get_quantized_dc_prediction() {
+            features |= LEPTON_FEATURE_QUANTIZED_DC_PREDICTION;
+        }
+        if self.get_fast_dc_prediction() {
+            features |= LEPTON_FEATURE_FAST_DC_PREDICTION;
+        }
+        if self.trained_initial_probs {
+            features |= LEPTON_FEATURE_TRAINED_INITIAL_PROBS;
+        }
+
+        features
+    }
 }
@@ -4,6 +4,8 @@
  *  This software incorporates material from third parties. See NOTICE.txt for details.
  *--------------------------------------------------------------------------------------------*/
 
+use std::ops::Range;
+
 use crate::lepton_error::{ExitCode, LeptonError};
 
 macro_rules! here {
@@ -32,6 +34,129 @@ pub fn err_exit_code<T>(_error_code: ExitCode, message: &str) -> anyhow::Result<
     }));
 }
 
+/// fails for a coefficient with a magnitude beyond what the model codes, see MAX_COEFFICIENT. index is the position
+/// of the coefficient in the block in raster order, 0 for the DC.
+#[cold]
+pub fn err_coefficient_out_of_range<T>(
+    component: usize,
+    dpos: i32,
+    index: usize,
+    value: i32,
+) -> anyhow::Result<T> {
+    err_exit_code(
+        ExitCode::CoefficientOutOfRange,
+        format!(
+            "CoefficientOutOfRange {{ component: {0}, dpos: {1}, index: {2}, value: {3} }}",
+            component, dpos, index, value
+        )
+        .as_str(),
+    )
+}
+
+/// fails the verification of the encoder at the first block whose coefficients decoded differently from how they
+/// were encoded
+#[cold]
+pub fn err_block_mismatch<T>(component: usize, dpos: i32) -> anyhow::Result<T> {
+    err_exit_code(
+        ExitCode::VerificationContentMismatch,
+        format!(
+            "BlockMismatch {{ component: {0}, dpos: {1} }}",
+            component, dpos
+        )
+        .as_str(),
+    )
+}
+
+/// fails a thread segment that gave up because another segment of the same image failed, whose error is the one
+/// that gets reported
+#[cold]
+pub fn err_cancelled<T>() -> anyhow::Result<T> {
+    err_exit_code(
+        ExitCode::GeneralFailure,
+        "cancelled since another thread segment failed",
+    )
+}
+
+/// adds where the data that e was raised for was found to its message, if it is a LeptonError, in the form of
+/// "<message> at <location>"
+#[cold]
+fn add_error_location(mut e: anyhow::Error, location: String) -> anyhow::Error {
+    if let Some(x) = e.downcast_mut::<LeptonError>() {
+        x.message = format!("{0} at {1}", x.message, location);
+    }
+    e
+}
+
+/// adds the position in the file of the scan data where decoding the MCU mcu failed to e, as
+/// CorruptScan { byte_offset, bit_offset, mcu }. position_bits is the offset in bits from the start of the file.
+#[cold]
+pub fn add_scan_position(e: anyhow::Error, position_bits: u64, mcu: i32) -> anyhow::Error {
+    add_error_location(
+        e,
+        format!(
+            "CorruptScan {{ byte_offset: {0}, bit_offset: {1}, mcu: {2} }}",
+            position_bits / 8,
+            position_bits % 8,
+            mcu
+        ),
+    )
+}
+
+/// adds the position in the arithmetic coded data of a thread segment where decoding the row of blocks row of
+/// component failed to e, as CorruptCoefficients { byte_offset, bit_offset, component, row }. position_bits is
+/// the offset in bits from the start of the coded data of the segment.
+#[cold]
+pub fn add_coefficients_position(
+    e: anyhow::Error,
+    position_bits: u64,
+    component: usize,
+    row: i32,
+) -> anyhow::Error {
+    add_error_location(
+        e,
+        format!(
+            "CorruptCoefficients {{ byte_offset: {0}, bit_offset: {1}, component: {2}, row: {3} }}",
+            position_bits / 8,
+            position_bits % 8,
+            component,
+            row
+        ),
+    )
+}
+
+/// adds the thread segment that e is about, such as the one a decoder worker was decoding when it failed, as
+/// Segment { index, luma_y_start, luma_y_end }, where the luma rows are the rows of luma blocks the segment covers.
+/// Other errors than a LeptonError get it as context, so that it still shows up in their message.
+#[cold]
+pub fn add_segment_position(
+    e: anyhow::Error,
+    segment_index: usize,
+    luma_rows: Range<i32>,
+) -> anyhow::Error {
+    let location = format!(
+        "Segment {{ index: {0}, luma_y_start: {1}, luma_y_end: {2} }}",
+        segment_index, luma_rows.start, luma_rows.end
+    );
+
+    if e.downcast_ref::<LeptonError>().is_some() {
+        add_error_location(e, location)
+    } else {
+        e.context(location)
+    }
+}
+
+/// narrows a size or offset to the type of the field it is stored in, failing instead of wrapping around if it
+/// doesn't fit
+pub fn narrow_size<T: TryFrom<u64>>(value: u64, field: &str) -> anyhow::Result<T> {
+    match T::try_from(value) {
+        Ok(v) => Ok(v),
+        Err(_) => err_exit_code(
+            ExitCode::SizeOverflow,
+            format!("{0} of {1} doesn't fit in the lepton file", field, value).as_str(),
+        ),
+    }
+}
+
 pub fn buffer_prefix_matches_marker<const BS: usize, const MS: usize>(
     buffer: [u8; BS],
     marker: [u8; MS],
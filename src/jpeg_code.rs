@@ -33,3 +33,9 @@ pub const DQT: u8 = 0xDB;
 
 /// Define restart interval
 pub const DRI: u8 = 0xDD;
+
+/// Application segment 0 (APP1 to APP15 follow it)
+pub const APP0: u8 = 0xE0;
+
+/// Comment
+pub const COM: u8 = 0xFE;
@@ -27,7 +27,7 @@ pub enum ExitCode {
     //HeaderTooLarge = 34,
     //BlockOffsetOOM = 37,
     UnsupportedJpeg = 42,
-    //WrapperOutputWriteFailed = 101,
+    WrapperOutputWriteFailed = 101,
     BadLeptonFile = 102,
 
     // Add new failures here
@@ -39,6 +39,22 @@ pub enum ExitCode {
     VerificationContentMismatch = 1005,
     SyntaxError = 1006,
     FileNotFound = 1007,
+    CannotReencode = 1008,
+    BufferTooSmall = 1009,
+    NoSegmentIndex = 1010,
+    SizeOverflow = 1011,
+    CorruptSegment = 1012,
+    NotAJpeg = 1013,
+    NotLepton = 1014,
+    NoScanData = 1015,
+    TrailingDataTooLarge = 1016,
+    FeatureRequiresNewerVersion = 1017,
+    ShardMismatch = 1018,
+    MissingDictionary = 1019,
+    UnexpectedEndOfSegment = 1020,
+    TrailingGarbageInSegment = 1021,
+    CorruptHandoff = 1022,
+    ExceedsMemoryBudget = 1023,
 }
 
 impl Display for ExitCode {
@@ -8,23 +8,63 @@ mod consts;
 mod helpers;
 mod jpeg_code;
 pub mod metrics;
+pub mod multiplexer;
 mod structs;
 
 pub mod enabled_features;
 pub mod lepton_error;
 
-pub use crate::enabled_features::EnabledFeatures;
+pub use crate::enabled_features::{
+    CoefficientCodecKind, EnabledFeatures, EncodeMode, LeptonVersion, MemoryBudgetPolicy,
+    ModelTuning,
+};
 pub use crate::lepton_error::{ExitCode, LeptonError};
 pub use metrics::Metrics;
+pub use structs::block_bits::BlockBitsMap;
+pub use structs::compression_estimate::CompressionEstimate;
+pub use structs::cpu_cores::{auto_thread_count, CpuTopology};
+pub use structs::decode_plan::{DecodePlan, SegmentOutput};
+pub use structs::jpeg_write::{EncodedRows, RowBoundary};
+pub use structs::lepton_container::ContainerEntry;
+pub use structs::lepton_format::{EncoderInfo, HeaderEdit, LeptonFileType};
+pub use structs::lepton_layout::{LeptonLayout, SegmentLayout};
+pub use structs::lepton_recovery::DamageReport;
+pub use structs::lepton_shard::LeptonShard;
+pub use structs::model::{ModelSnapshot, ModelSnapshotCallback, ModelSnapshotHook};
+pub use structs::model_primer::ModelPrimer;
+pub use structs::thread_handoff::ThreadHandoff;
+pub use structs::thread_lifecycle::{ThreadLifecycleEvent, ThreadLifecycleHook, WorkerKind};
+
+#[cfg(feature = "conformance")]
+pub use structs::conformance::ConformanceVectors;
+#[cfg(feature = "debug-symmetry")]
+pub use structs::symmetry_log::{Divergence, SymmetryEntry, SymmetryRecorder};
 
 use core::result::Result;
+use std::ffi::c_void;
 use std::panic::catch_unwind;
 
 use std::io::{Cursor, Read, Seek, Write};
+use std::ops::Range;
 
+use crate::structs::decode_plan::{assemble_wrapper, decode_segment_wrapper, plan_decode_wrapper};
+use crate::structs::lepton_container::{
+    decode_entry_wrapper, encode_many_wrapper, is_lepton_container, read_container_entries_wrapper,
+};
 use crate::structs::lepton_format::{
-    decode_lepton_wrapper, encode_lepton_wrapper, encode_lepton_wrapper_verify,
+    compute_decoded_size_wrapper, decode_lepton_wrapper, decode_lepton_wrapper_chunked,
+    decode_lepton_wrapper_into, decode_lepton_wrapper_optimize_huffman,
+    decode_lepton_wrapper_prefetch, decode_lepton_wrapper_streaming,
+    decode_lepton_wrapper_with_features, decode_rows_wrapper, encode_lepton_wrapper,
+    encode_lepton_wrapper_verify, extract_trailing_data_wrapper, read_encoder_info_wrapper,
+    read_lepton_file_type_wrapper, read_original_file_size_wrapper,
+};
+use crate::structs::lepton_layout::inspect_lepton_structure_wrapper;
+use crate::structs::lepton_recovery::decode_lepton_lenient_wrapper;
+use crate::structs::lepton_shard::{
+    decode_shard_wrapper, join_lepton_wrapper, split_lepton_wrapper,
 };
+use crate::structs::model_primer::{primer_from_bytes_wrapper, train_primer_wrapper};
 
 /// translates internal anyhow based exception into externally visible exception
 fn translate_error(e: anyhow::Error) -> LeptonError {
@@ -54,7 +94,327 @@ pub fn decode_lepton<R: Read + Seek, W: Write>(
     decode_lepton_wrapper(reader, writer, num_threads).map_err(translate_error)
 }
 
-/// Encodes JPEG as compressed Lepton format.
+/// Same as decode_lepton, but with the limits the decoder enforces taken from enabled_features. Only
+/// max_trailing_bytes, format_version, model_primer, strict_segment_end, max_output_buffers and max_read_ahead
+/// apply to decoding.
+pub fn decode_lepton_with_features<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_with_features(reader, writer, num_threads, enabled_features)
+        .map_err(translate_error)
+}
+
+/// Decodes Lepton container from a stream, such as a download that is still in progress, and recreates the
+/// original JPEG file. Decoding starts as the data arrives, and for baseline images written with a segment index
+/// the output is written before the end of the input is reached.
+pub fn decode_lepton_streaming<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_streaming(reader, writer, num_threads).map_err(translate_error)
+}
+
+/// Same as decode_lepton_streaming, but keeps reading the input on a thread of its own while the output of the
+/// segments that are done is written, up to enabled_features.max_read_ahead bytes, so that a slow input keeps
+/// arriving in the meantime. The decoding limits are taken from enabled_features as in decode_lepton_with_features.
+///
+/// The reading thread can't be interrupted in the middle of a read, so this doesn't return, also not with an error
+/// about a corrupt file, before the read it is in returns. A reader that can block for a long time, such as a
+/// socket, needs a timeout or has to be shut down from another thread for the call to return in time.
+pub fn decode_lepton_streaming_prefetch<R: Read + Send, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_prefetch(reader, writer, num_threads, enabled_features)
+        .map_err(translate_error)
+}
+
+/// Decodes Lepton container, salvaging as much of the image as possible if it is corrupted. Each thread segment
+/// is decoded on its own, and the MCU rows of the ones that fail are written as gray blocks and listed in the
+/// returned report. The output only matches the original if the report has no damaged rows and the checksum,
+/// if any, matches. Only single scan baseline images are supported.
+pub fn decode_lepton_lenient<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+) -> Result<DamageReport, LeptonError> {
+    decode_lepton_lenient_wrapper(reader, writer, num_threads).map_err(translate_error)
+}
+
+/// Decodes Lepton container into the output buffer. result_size is set to the size of the recreated JPEG, also
+/// if the call fails with BufferTooSmall, which is checked before any decoding work is done.
+pub fn decode_lepton_into<R: Read + Seek>(
+    reader: &mut R,
+    output: &mut [u8],
+    num_threads: usize,
+    result_size: &mut u64,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_into(reader, output, num_threads, result_size).map_err(translate_error)
+}
+
+/// Decodes Lepton container and hands the recreated JPEG to the callback in chunks of chunk_size bytes (the last
+/// one can be shorter) so the output never has to be in memory all at once. Decoding stops with the error
+/// returned by the callback if it fails.
+pub fn decode_lepton_chunked<R: Read + Seek>(
+    reader: &mut R,
+    chunk_size: usize,
+    num_threads: usize,
+    mut callback: impl FnMut(&[u8]) -> Result<(), LeptonError>,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_chunked(reader, chunk_size, num_threads, |chunk| {
+        callback(chunk).map_err(anyhow::Error::new)
+    })
+    .map_err(translate_error)
+}
+
+/// Decodes Lepton container into a baseline JPEG with optimal Huffman tables. The image has exactly the same
+/// coefficients as the original, but the output is NOT a byte exact copy of the original JPEG file.
+pub fn decode_lepton_optimize_huffman<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+) -> Result<Metrics, LeptonError> {
+    decode_lepton_wrapper_optimize_huffman(reader, writer, num_threads).map_err(translate_error)
+}
+
+/// Computes the exact size of the JPEG that decode_lepton would recreate without keeping the output
+pub fn compute_decoded_size<R: Read + Seek>(
+    reader: &mut R,
+    num_threads: usize,
+) -> Result<u64, LeptonError> {
+    compute_decoded_size_wrapper(reader, num_threads).map_err(translate_error)
+}
+
+/// Reads the build of the encoder that wrote the Lepton file from its header, None if it wasn't recorded
+pub fn read_encoder_info<R: Read>(reader: &mut R) -> Result<Option<EncoderInfo>, LeptonError> {
+    read_encoder_info_wrapper(reader).map_err(translate_error)
+}
+
+/// Reads the exact size of the original JPEG from the header of the Lepton file, None if it wasn't recorded
+pub fn read_original_file_size<R: Read>(reader: &mut R) -> Result<Option<u64>, LeptonError> {
+    read_original_file_size_wrapper(reader).map_err(translate_error)
+}
+
+/// Reads whether the JPEG in the Lepton file is baseline or progressive and how many scans it has from the header,
+/// without decoding anything. Files that store the original as is don't have this information.
+pub fn read_lepton_file_type<R: Read>(reader: &mut R) -> Result<LeptonFileType, LeptonError> {
+    read_lepton_file_type_wrapper(reader).map_err(translate_error)
+}
+
+/// Returns the data that follows the end of the image in the original JPEG (such as the video of a motion photo)
+/// without decoding any of the image
+pub fn extract_trailing_data(lepton: &[u8]) -> Result<Vec<u8>, LeptonError> {
+    extract_trailing_data_wrapper(&mut Cursor::new(lepton)).map_err(translate_error)
+}
+
+/// Same as extract_trailing_data, but reads from a stream. Only the header at the start of the Lepton file is
+/// read, so the rest of the stream doesn't need to have arrived yet.
+pub fn extract_trailing_data_streaming<R: Read>(reader: &mut R) -> Result<Vec<u8>, LeptonError> {
+    extract_trailing_data_wrapper(reader).map_err(translate_error)
+}
+
+/// Applies edit to the header of a Lepton file, such as recomputing the segment checksums or fixing the stored
+/// size of the original, and copies the coded data through unchanged. Edits that would change the JPEG the file
+/// decodes to are refused.
+pub fn rewrite_header(lepton: &[u8], edit: HeaderEdit) -> Result<Vec<u8>, LeptonError> {
+    structs::lepton_format::rewrite_header(lepton, edit).map_err(translate_error)
+}
+
+/// Encodes the JPEG and captures the parsed header, the thread segment boundaries, the first prefix_length coded
+/// bytes of each segment and the final file in a deterministic layout, so that other lepton implementations can
+/// be checked against it. See the conformance module for the layout.
+#[cfg(feature = "conformance")]
+pub fn generate_conformance_vectors(
+    jpeg: &[u8],
+    prefix_length: usize,
+) -> Result<ConformanceVectors, LeptonError> {
+    structs::conformance::generate_conformance_vectors_wrapper(jpeg, prefix_length)
+        .map_err(translate_error)
+}
+
+/// Recreates the entropy coded scan data of the MCU rows in mcu_row_range of the original JPEG, decoding only the
+/// parts of the Lepton file that are needed for them. The file must have been encoded with
+/// EnabledFeatures::segment_index, and only single scan baseline images are supported.
+///
+/// The returned data is byte aligned with the rest of the scan: its first byte already contains the start.overhang
+/// bits belonging to the rows before the range, and the end.overhang bits are left out since they share a byte
+/// with the rows after it. The start and end boundaries also contain the DC predictors of each component, which
+/// the first block after the boundary is coded relative to. Consecutive ranges can be concatenated as they are.
+pub fn decode_rows(lepton: &[u8], mcu_row_range: Range<i32>) -> Result<EncodedRows, LeptonError> {
+    decode_rows_wrapper(&mut Cursor::new(lepton), mcu_row_range).map_err(translate_error)
+}
+
+/// Splits a Lepton file into one shard per thread segment, each with a copy of the header, so that the segments
+/// can be decoded on different machines with decode_shard and joined back into the same file with join_lepton.
+/// Passthrough files have no segments to split.
+pub fn split_lepton(lepton: &[u8]) -> Result<Vec<LeptonShard>, LeptonError> {
+    split_lepton_wrapper(lepton).map_err(translate_error)
+}
+
+/// Reassembles the Lepton file that split_lepton created the shards from, byte for byte. The shards can be in any
+/// order, but fails with ShardMismatch if any are missing, duplicated, from another file or don't have contiguous
+/// row ranges.
+pub fn join_lepton(shards: &[LeptonShard]) -> Result<Vec<u8>, LeptonError> {
+    join_lepton_wrapper(shards).map_err(translate_error)
+}
+
+/// Recreates the entropy coded scan data of the MCU rows of a shard, the same way as decode_rows does. Only single
+/// scan baseline images are supported. The output of the shards of a file can be concatenated in order.
+pub fn decode_shard(shard: &LeptonShard) -> Result<EncodedRows, LeptonError> {
+    decode_shard_wrapper(shard).map_err(translate_error)
+}
+
+/// Reads the header of a Lepton file and finds its thread segments, for callers that schedule the decoding
+/// themselves instead of having the library start threads. Each segment can then be decoded with decode_segment,
+/// in any order and on any thread, and assemble puts the outputs together into the JPEG. The plan can be shared
+/// between threads and the outputs sent between them.
+pub fn plan_decode(lepton: &[u8]) -> Result<DecodePlan, LeptonError> {
+    plan_decode_wrapper(lepton).map_err(translate_error)
+}
+
+/// Decodes segment index of the plan on the calling thread. Segments don't depend on each other, so they can be
+/// decoded at the same time.
+pub fn decode_segment(plan: &DecodePlan, index: usize) -> Result<SegmentOutput, LeptonError> {
+    decode_segment_wrapper(plan, index).map_err(translate_error)
+}
+
+/// Puts the decoded segments of the plan together into the JPEG, which is checked against the size and CRC of the
+/// original like decode_lepton does. The outputs can be in any order, but fails with ShardMismatch unless there is
+/// exactly one for each segment. Progressive images are only written once all their segments are decoded, which
+/// assemble does on the calling thread.
+pub fn assemble(plan: DecodePlan, outputs: Vec<SegmentOutput>) -> Result<Vec<u8>, LeptonError> {
+    assemble_wrapper(plan, outputs).map_err(translate_error)
+}
+
+/// Predicts the size of the Lepton file for a JPEG with the default features by encoding only every
+/// sample_interval-th MCU row and extrapolating to the whole image, which is much faster than a full encode.
+pub fn estimate_compression(
+    jpeg: &[u8],
+    sample_interval: usize,
+) -> Result<CompressionEstimate, LeptonError> {
+    structs::compression_estimate::estimate_compression_wrapper(jpeg, sample_interval)
+        .map_err(translate_error)
+}
+
+/// Estimates the bits that each block of a JPEG costs when it is encoded as a single thread segment with the
+/// default features, as a heat map per component. Nothing is coded, the bits come from the probabilities that the
+/// model gives each decision, and they add up to within a fraction of a percent of the size of the coded data.
+pub fn estimate_block_bits(jpeg: &[u8]) -> Result<Vec<BlockBitsMap>, LeptonError> {
+    structs::block_bits::estimate_block_bits_wrapper(jpeg).map_err(translate_error)
+}
+
+/// Experimental: creates a primer for EnabledFeatures::model_primer by encoding the JPEGs one after the other,
+/// so that images similar to them start out with a model that already knows their statistics. Files encoded with
+/// the primer can only be decoded with the same one.
+pub fn train_primer(jpegs: &[&[u8]]) -> Result<ModelPrimer, LeptonError> {
+    train_primer_wrapper(jpegs).map_err(translate_error)
+}
+
+/// Encodes the JPEG and decodes the result in process, logging the context and symbol of each decision of the
+/// arithmetic coder on both sides, and returns the first decision where the decoder didn't do what the encoder did,
+/// or None if both made the same decisions and the decoder recreated the JPEG. Each thread segment keeps the last
+/// capacity decisions, and the image is coded again as often as it takes to find the first divergence.
+#[cfg(feature = "debug-symmetry")]
+pub fn check_symmetry(
+    jpeg: &[u8],
+    enabled_features: &EnabledFeatures,
+    capacity: usize,
+) -> Result<Option<Divergence>, LeptonError> {
+    structs::symmetry_log::find_divergence(jpeg, enabled_features, capacity)
+        .map_err(translate_error)
+}
+
+/// Creates the table of initial counts that EnabledFeatures::trained_initial_probs starts the model with, by
+/// encoding each of the JPEGs with a fresh model and adding up the counts of each branch. Returns the Rust source
+/// that replaces src/structs/trained_initial_counts.rs. Files encoded with the new table can only be decoded by
+/// builds that have the same one.
+#[cfg(feature = "train-initial-probs")]
+pub fn train_initial_probs(jpegs: &[&[u8]]) -> Result<String, LeptonError> {
+    structs::initial_probs::training::train_initial_probs_wrapper(jpegs).map_err(translate_error)
+}
+
+/// Utilities for testing the coder itself, for the test-utils feature.
+#[cfg(feature = "test-utils")]
+pub mod testing {
+    pub use crate::structs::model_fuzz::{BoolCoderStats, Divergence};
+
+    /// Generates pseudo-random coefficients for at least blocks blocks from seed, codes them with the per-block
+    /// encoder and decodes them again without the JPEG layers, and returns the first coefficient that the decoder
+    /// didn't get back, or the error of the coder that failed. The same seed always generates the same blocks.
+    pub fn roundtrip_blocks(seed: u64, blocks: usize) -> Result<(), Divergence> {
+        match crate::structs::model_fuzz::roundtrip_blocks_wrapper(seed, blocks) {
+            Ok(None) => Ok(()),
+            Ok(Some(divergence)) => Err(divergence),
+            Err(e) => Err(Divergence::Failed(crate::translate_error(e))),
+        }
+    }
+
+    /// Codes the decisions with the bool coder, each with the probability out of 256 that it is false, decodes
+    /// them again and returns what coding them took, or the first decision that the decoder didn't get back or
+    /// the error of the coder that failed.
+    pub fn roundtrip_decisions(decisions: &[(u8, bool)]) -> Result<BoolCoderStats, Divergence> {
+        bool_coder_result(crate::structs::model_fuzz::roundtrip_decisions_wrapper(
+            decisions,
+        ))
+    }
+
+    /// Generates decisions pseudo-random decisions from seed, with their probabilities, and roundtrips them
+    /// like roundtrip_decisions. Besides bits that follow their probabilities and ones that don't, they include
+    /// the extreme probabilities 1 and 255 and long runs of the same bit. The same seed always generates the
+    /// same decisions.
+    pub fn bool_coder_roundtrip(seed: u64, decisions: usize) -> Result<BoolCoderStats, Divergence> {
+        bool_coder_result(crate::structs::model_fuzz::bool_coder_roundtrip_wrapper(
+            seed, decisions,
+        ))
+    }
+
+    /// Runs f with the encodes that it starts on the calling thread waiting for as long as delay returns before
+    /// each chunk of a thread segment is handed on, called with the segment and the number of the chunk within
+    /// it, so that tests can change the order in which the workers finish their chunks. Doesn't change the lepton
+    /// file.
+    pub fn with_chunk_delay<T>(
+        delay: fn(segment: usize, chunk: usize) -> std::time::Duration,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        use crate::structs::lepton_format::CHUNK_DELAY;
+
+        let previous = CHUNK_DELAY.with(|d| d.replace(Some(delay)));
+        let result = f();
+        CHUNK_DELAY.with(|d| d.set(previous));
+
+        result
+    }
+
+    fn bool_coder_result(
+        result: anyhow::Result<(BoolCoderStats, Option<Divergence>)>,
+    ) -> Result<BoolCoderStats, Divergence> {
+        match result {
+            Ok((stats, None)) => Ok(stats),
+            Ok((_, Some(divergence))) => Err(divergence),
+            Err(e) => Err(Divergence::Failed(crate::translate_error(e))),
+        }
+    }
+}
+
+/// Reads a primer serialized with ModelPrimer::to_bytes
+pub fn primer_from_bytes(data: &[u8]) -> Result<ModelPrimer, LeptonError> {
+    primer_from_bytes_wrapper(data).map_err(translate_error)
+}
+
+/// Lists the sizes of the header and the thread segments of a Lepton file without decoding it. A truncated file
+/// returns what could be read with LeptonLayout::truncated set.
+pub fn inspect_lepton_structure(data: &[u8]) -> Result<LeptonLayout, LeptonError> {
+    inspect_lepton_structure_wrapper(data).map_err(translate_error)
+}
+
+/// Encodes JPEG as compressed Lepton format. A max_threads of 0 uses a thread per physical core, see
+/// auto_thread_count, and the number that was used is in the returned metrics.
 pub fn encode_lepton<R: Read + Seek, W: Write + Seek>(
     reader: &mut R,
     writer: &mut W,
@@ -64,6 +424,52 @@ pub fn encode_lepton<R: Read + Seek, W: Write + Seek>(
     encode_lepton_wrapper(reader, writer, max_threads, enabled_features).map_err(translate_error)
 }
 
+/// Encodes several JPEGs into a single container with a table of contents in front of the Lepton file of each one.
+/// The entries are encoded in parallel, sharing max_threads between them.
+pub fn encode_many(
+    inputs: &[&[u8]],
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Vec<u8>, LeptonError> {
+    let inputs: Vec<(Option<&str>, &[u8])> = inputs.iter().map(|input| (None, *input)).collect();
+
+    encode_many_wrapper(&inputs[..], max_threads, enabled_features).map_err(translate_error)
+}
+
+/// Same as encode_many, but also records a hash of the name of each original file (see ContainerEntry::hash_name)
+/// in the table of contents so that entries can be looked up by name.
+pub fn encode_many_named(
+    inputs: &[(&str, &[u8])],
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Vec<u8>, LeptonError> {
+    let inputs: Vec<(Option<&str>, &[u8])> = inputs
+        .iter()
+        .map(|(name, input)| (Some(*name), *input))
+        .collect();
+
+    encode_many_wrapper(&inputs[..], max_threads, enabled_features).map_err(translate_error)
+}
+
+/// Decodes the entry at index of a container written by encode_many back into the original JPEG
+pub fn decode_entry(
+    container: &[u8],
+    index: usize,
+    num_threads: usize,
+) -> Result<Vec<u8>, LeptonError> {
+    decode_entry_wrapper(container, index, num_threads).map_err(translate_error)
+}
+
+/// Reads the table of contents of a container written by encode_many
+pub fn read_container_entries(container: &[u8]) -> Result<Vec<ContainerEntry>, LeptonError> {
+    read_container_entries_wrapper(container).map_err(translate_error)
+}
+
+/// True if the data is a container written by encode_many rather than a single Lepton file
+pub fn is_container(data: &[u8]) -> bool {
+    is_lepton_container(data)
+}
+
 /// Compresses JPEG into Lepton format and compares input to output to verify that compression roundtrip is OK
 pub fn encode_lepton_verify(
     input_data: &[u8],
@@ -73,7 +479,31 @@ pub fn encode_lepton_verify(
     encode_lepton_wrapper_verify(input_data, max_threads, enabled_features).map_err(translate_error)
 }
 
-/// C ABI interface for compressing image, exposed from DLL
+/// Stops the worker threads that encoding and decoding keep between calls and waits for them to exit. Calls that
+/// are still running finish first, and the next call starts the threads again. The idle threads hold no
+/// resources, so this isn't needed before the process exits.
+pub fn shutdown() {
+    structs::thread_pool::shutdown()
+}
+
+thread_local! {
+    /// the message of the last error that a C ABI function returned on this thread, see WrapperGetLastErrorMessage
+    static LAST_ERROR_MESSAGE: std::cell::RefCell<String> = const { std::cell::RefCell::new(String::new()) };
+}
+
+/// remembers the message of an error that a C ABI function returns for WrapperGetLastErrorMessage
+fn set_last_error_message(message: String) {
+    LAST_ERROR_MESSAGE.with(|m| *m.borrow_mut() = message);
+}
+
+/// remembers e for WrapperGetLastErrorMessage and returns its exit code, for the C ABI functions to return
+fn ffi_error(e: &LeptonError) -> i32 {
+    set_last_error_message(e.to_string());
+    e.exit_code as i32
+}
+
+/// C ABI interface for compressing image, exposed from DLL. A number_of_threads of 0 or less uses a thread per
+/// physical core. Writes files that decoders from before 0.4.0 can read, see WrapperCompressOptions::default.
 #[no_mangle]
 pub unsafe extern "C" fn WrapperCompressImage(
     input_buffer: *const u8,
@@ -82,8 +512,91 @@ pub unsafe extern "C" fn WrapperCompressImage(
     output_buffer_size: u64,
     number_of_threads: i32,
     result_size: *mut u64,
+) -> i32 {
+    compress_image(
+        input_buffer,
+        input_buffer_size,
+        output_buffer,
+        output_buffer_size,
+        number_of_threads,
+        WrapperCompressOptions::default(),
+        result_size,
+    )
+}
+
+/// the options of WrapperCompressImageWithOptions, laid out for C
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct WrapperCompressOptions {
+    /// 0 to 2, see EnabledFeatures::compression_effort
+    pub compression_effort: u8,
+    /// 1 to 7, the LeptonVersion to write. The options that the version doesn't have are turned off, see
+    /// EnabledFeatures::with_format_version.
+    pub format_version: u32,
+}
+
+impl Default for WrapperCompressOptions {
+    /// the options of WrapperCompressImage, which writes LeptonVersion::V1 files like the versions before 0.4.0
+    /// did, so that the decoders that are already deployed can read them
+    fn default() -> Self {
+        WrapperCompressOptions {
+            compression_effort: EnabledFeatures::default().compression_effort,
+            format_version: 1,
+        }
+    }
+}
+
+/// C ABI interface for compressing image with the options in WrapperCompressOptions, exposed from DLL
+///
+/// # Safety
+///
+/// input_buffer must point to input_buffer_size readable bytes and output_buffer to output_buffer_size writable
+/// bytes that don't overlap them, options to a readable WrapperCompressOptions and result_size to a writable u64,
+/// all valid until the call returns.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperCompressImageWithOptions(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    output_buffer: *mut u8,
+    output_buffer_size: u64,
+    number_of_threads: i32,
+    options: *const WrapperCompressOptions,
+    result_size: *mut u64,
+) -> i32 {
+    compress_image(
+        input_buffer,
+        input_buffer_size,
+        output_buffer,
+        output_buffer_size,
+        number_of_threads,
+        *options,
+        result_size,
+    )
+}
+
+unsafe fn compress_image(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    output_buffer: *mut u8,
+    output_buffer_size: u64,
+    number_of_threads: i32,
+    options: WrapperCompressOptions,
+    result_size: *mut u64,
 ) -> i32 {
     match catch_unwind(|| {
+        let Ok(format_version) = LeptonVersion::try_from(options.format_version) else {
+            set_last_error_message(format!(
+                "unknown format version {0}",
+                options.format_version
+            ));
+            return ExitCode::SyntaxError as i32;
+        };
+
+        let enabled_features = EnabledFeatures {
+            compression_effort: options.compression_effort,
+            ..EnabledFeatures::default().with_format_version(format_version)
+        };
+
         let input = std::slice::from_raw_parts(input_buffer, input_buffer_size as usize);
 
         let output = std::slice::from_raw_parts_mut(output_buffer, output_buffer_size as usize);
@@ -94,16 +607,17 @@ pub unsafe extern "C" fn WrapperCompressImage(
         match encode_lepton_wrapper(
             &mut reader,
             &mut writer,
-            number_of_threads as usize,
-            &EnabledFeatures::default(),
+            number_of_threads.max(0) as usize,
+            &enabled_features,
         ) {
             Ok(_) => {}
             Err(e) => match e.root_cause().downcast_ref::<LeptonError>() {
                 // try to extract the exit code if it was a well known error
                 Some(x) => {
-                    return x.exit_code as i32;
+                    return ffi_error(x);
                 }
                 None => {
+                    set_last_error_message(format!("unexpected error {0:?}", e));
                     return -1 as i32;
                 }
             },
@@ -111,7 +625,7 @@ pub unsafe extern "C" fn WrapperCompressImage(
 
         *result_size = writer.position().into();
 
-        return 0;
+        0
     }) {
         Ok(code) => {
             return code;
@@ -122,7 +636,8 @@ pub unsafe extern "C" fn WrapperCompressImage(
     }
 }
 
-/// C ABI interface for decompressing image, exposed from DLL
+/// C ABI interface for decompressing image, exposed from DLL. If the output buffer is too small, BufferTooSmall
+/// is returned right away and result_size is set to the size of buffer needed.
 #[no_mangle]
 pub unsafe extern "C" fn WrapperDecompressImage(
     input_buffer: *const u8,
@@ -138,17 +653,19 @@ pub unsafe extern "C" fn WrapperDecompressImage(
         let output = std::slice::from_raw_parts_mut(output_buffer, output_buffer_size as usize);
 
         let mut reader = Cursor::new(input);
-        let mut writer = Cursor::new(output);
 
-        match decode_lepton_wrapper(&mut reader, &mut writer, number_of_threads as usize) {
+        match decode_lepton_into(
+            &mut reader,
+            output,
+            number_of_threads.max(0) as usize,
+            &mut *result_size,
+        ) {
             Ok(_) => {}
             Err(e) => {
-                return translate_error(e).exit_code as i32;
+                return ffi_error(&e);
             }
         }
 
-        *result_size = writer.position().into();
-
         return 0;
     }) {
         Ok(code) => {
@@ -159,3 +676,191 @@ pub unsafe extern "C" fn WrapperDecompressImage(
         }
     }
 }
+
+/// C ABI interface for decompressing image and handing the output to a callback in chunks of chunk_size bytes
+/// (the last one can be shorter) rather than into a single buffer, exposed from DLL. The callback gets passed the
+/// context pointer and returns 0 to continue, anything else stops decompression with WrapperOutputWriteFailed.
+///
+/// # Safety
+///
+/// input_buffer must point to input_buffer_size readable bytes that aren't changed until the call returns.
+/// callback must be safe to call with context, which is passed through as is, from the calling thread. The data
+/// pointer it gets is only valid for size bytes and only until the callback returns.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperDecompressImageChunked(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    chunk_size: u64,
+    number_of_threads: i32,
+    callback: extern "C" fn(context: *mut c_void, data: *const u8, size: u64) -> i32,
+    context: *mut c_void,
+) -> i32 {
+    catch_unwind(|| {
+        let input = std::slice::from_raw_parts(input_buffer, input_buffer_size as usize);
+
+        let mut reader = Cursor::new(input);
+
+        match decode_lepton_chunked(
+            &mut reader,
+            chunk_size as usize,
+            number_of_threads.max(0) as usize,
+            |chunk| {
+                let r = callback(context, chunk.as_ptr(), chunk.len() as u64);
+                if r != 0 {
+                    return Err(LeptonError {
+                        exit_code: ExitCode::WrapperOutputWriteFailed,
+                        message: format!("output callback failed with {0}", r),
+                    });
+                }
+                Ok(())
+            },
+        ) {
+            Ok(_) => {}
+            Err(e) => {
+                return ffi_error(&e);
+            }
+        }
+
+        0
+    })
+    .unwrap_or(-2)
+}
+
+/// C ABI interface for reading the build of the encoder that wrote a Lepton file, exposed from DLL. The version
+/// and git revision are written as zero terminated strings, cut off if they don't fit into the buffers. If the
+/// file doesn't contain the information, the strings are empty and enabled_features is zero.
+///
+/// # Safety
+///
+/// input_buffer must point to input_buffer_size readable bytes, version and git_revision to version_size and
+/// git_revision_size writable bytes, and enabled_features to a writable u32. None of them may overlap, and all of
+/// them must stay valid until the call returns.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperGetEncoderInfo(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    version: *mut u8,
+    version_size: u64,
+    git_revision: *mut u8,
+    git_revision_size: u64,
+    enabled_features: *mut u32,
+) -> i32 {
+    catch_unwind(|| {
+        let input = std::slice::from_raw_parts(input_buffer, input_buffer_size as usize);
+
+        let info = match read_encoder_info(&mut Cursor::new(input)) {
+            Ok(info) => info.unwrap_or(EncoderInfo {
+                version: String::new(),
+                git_revision: String::new(),
+                enabled_features: 0,
+            }),
+            Err(e) => {
+                return ffi_error(&e);
+            }
+        };
+
+        for (s, buffer, buffer_size) in [
+            (&info.version, version, version_size),
+            (&info.git_revision, git_revision, git_revision_size),
+        ] {
+            let buffer = std::slice::from_raw_parts_mut(buffer, buffer_size as usize);
+            if !buffer.is_empty() {
+                let len = std::cmp::min(s.len(), buffer.len() - 1);
+                buffer[..len].copy_from_slice(&s.as_bytes()[..len]);
+                buffer[len] = 0;
+            }
+        }
+
+        *enabled_features = info.enabled_features;
+
+        0
+    })
+    .unwrap_or(-2)
+}
+
+/// C ABI interface for reading the exact size of the original JPEG from the header of a Lepton file, exposed from
+/// DLL. original_file_size is set to zero if the file doesn't record it.
+///
+/// # Safety
+///
+/// input_buffer must point to input_buffer_size readable bytes and original_file_size to a writable u64, both
+/// valid until the call returns.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperGetOriginalFileSize(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    original_file_size: *mut u64,
+) -> i32 {
+    catch_unwind(|| {
+        let input = std::slice::from_raw_parts(input_buffer, input_buffer_size as usize);
+
+        match read_original_file_size(&mut Cursor::new(input)) {
+            Ok(size) => {
+                *original_file_size = size.unwrap_or(0);
+            }
+            Err(e) => {
+                return ffi_error(&e);
+            }
+        }
+
+        0
+    })
+    .unwrap_or(-2)
+}
+
+/// C ABI interface for finding out whether the JPEG in a Lepton file is baseline or progressive without decoding
+/// it, exposed from DLL. progressive is set to 1 for progressive images and 0 otherwise, and scan_count to the
+/// number of scans in the JPEG.
+///
+/// # Safety
+///
+/// input_buffer must point to input_buffer_size readable bytes, and progressive and scan_count to a writable i32
+/// and u32, all valid until the call returns.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperGetLeptonFileType(
+    input_buffer: *const u8,
+    input_buffer_size: u64,
+    progressive: *mut i32,
+    scan_count: *mut u32,
+) -> i32 {
+    catch_unwind(|| {
+        let input = std::slice::from_raw_parts(input_buffer, input_buffer_size as usize);
+
+        match read_lepton_file_type(&mut Cursor::new(input)) {
+            Ok(file_type) => {
+                *progressive = i32::from(file_type.progressive);
+                *scan_count = file_type.scan_count;
+            }
+            Err(e) => {
+                return ffi_error(&e);
+            }
+        }
+
+        0
+    })
+    .unwrap_or(-2)
+}
+
+/// C ABI interface for reading the message of the last error that a function of this interface returned on the
+/// calling thread, exposed from DLL. The message is written as a zero terminated string, cut off if it doesn't fit
+/// into the buffer, and is empty if no function has failed yet. Errors in the scan data of a JPEG or in the coded
+/// data of a Lepton file include where in the data they were found.
+///
+/// # Safety
+///
+/// message must point to message_size writable bytes that stay valid until the call returns, and must not be null
+/// even if message_size is zero.
+#[no_mangle]
+pub unsafe extern "C" fn WrapperGetLastErrorMessage(message: *mut u8, message_size: u64) -> i32 {
+    let buffer = std::slice::from_raw_parts_mut(message, message_size as usize);
+    if !buffer.is_empty() {
+        LAST_ERROR_MESSAGE.with(|m| {
+            let m = m.borrow();
+            let len = std::cmp::min(m.len(), buffer.len() - 1);
+            buffer[..len].copy_from_slice(&m.as_bytes()[..len]);
+            buffer[len] = 0;
+        });
+    }
+
+    0
+}
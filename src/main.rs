@@ -9,7 +9,12 @@ mod enabled_features;
 mod helpers;
 mod jpeg_code;
 mod lepton_error;
+// the tool compiles the modules of the library as its own, so whatever only the library exports or calls would
+// be reported as unused here. The library build still reports the code that nothing uses.
+#[allow(dead_code)]
 mod metrics;
+mod multiplexer;
+#[allow(dead_code)]
 mod structs;
 
 use anyhow;
@@ -28,10 +33,12 @@ use std::{
     time::Duration,
 };
 
-use crate::enabled_features::EnabledFeatures;
+use crate::enabled_features::{EnabledFeatures, EncodeMode, LeptonVersion, MemoryBudgetPolicy};
 use crate::helpers::here;
 use crate::structs::lepton_format::{
-    decode_lepton_wrapper, encode_lepton_wrapper_verify, LeptonHeader,
+    compute_decoded_size_wrapper, decode_lepton_wrapper_chunked,
+    decode_lepton_wrapper_optimize_huffman, decode_lepton_wrapper_with_features,
+    encode_lepton_wrapper_verify, LeptonHeader,
 };
 
 fn parse_numeric_parameter(arg: &str, name: &str) -> Option<i32> {
@@ -47,11 +54,17 @@ fn main_with_result() -> anyhow::Result<()> {
     let args: Vec<String> = env::args().collect();
 
     let mut filenames = Vec::new();
-    let mut num_threads = 8;
+    // 0 leaves it to the library to use a thread per physical core
+    let mut num_threads = 0;
     let mut iterations = 1;
     let mut dump = false;
     let mut all = false;
     let mut overwrite = false;
+    let mut size = false;
+    let mut optimize = false;
+    let mut chunk_size = None;
+    #[cfg(feature = "stats")]
+    let mut branch_stats_file = None;
     let mut enabled_features = EnabledFeatures::default();
 
     // only output the log if we are connected to a console (otherwise if there is redirection we would corrupt the file)
@@ -60,19 +73,90 @@ fn main_with_result() -> anyhow::Result<()> {
     }
 
     for i in 1..args.len() {
+        // writes the branch usage of the model to a CSV file after encoding
+        #[cfg(feature = "stats")]
+        if let Some(x) = args[i].strip_prefix("-branchstats:") {
+            branch_stats_file = Some(x);
+            continue;
+        }
+
         if args[i].starts_with("-") {
             if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-threads:") {
                 num_threads = x;
             } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-iter:") {
                 iterations = x;
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-chunk:") {
+                chunk_size = Some(x);
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-muxchunk:") {
+                enabled_features.chunk_size = Some(x as usize);
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-segments:") {
+                enabled_features.target_segments = Some(x as usize);
+            } else if let Some(x) =
+                parse_numeric_parameter(args[i].as_str(), "-singlesegmentblocks:")
+            {
+                enabled_features.single_segment_max_blocks = x as u32;
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-outputbuffers:") {
+                enabled_features.max_output_buffers = (x as usize).max(1);
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-readahead:") {
+                enabled_features.max_read_ahead = x as u64 * 1024 * 1024;
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-memorybudget:") {
+                enabled_features.memory_budget = Some(x as u64 * 1024 * 1024);
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-maxtrailing:") {
+                enabled_features.max_trailing_bytes = x as u64;
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-effort:") {
+                if !(0..=2).contains(&x) {
+                    return err_exit_code(
+                        ExitCode::SyntaxError,
+                        format!("unknown compression effort {0}", x).as_str(),
+                    );
+                }
+                enabled_features.compression_effort = x as u8;
+            } else if let Some(x) = parse_numeric_parameter(args[i].as_str(), "-formatversion:") {
+                let Ok(format_version) = LeptonVersion::try_from(x as u32) else {
+                    return err_exit_code(
+                        ExitCode::SyntaxError,
+                        format!("unknown format version {0}", x).as_str(),
+                    );
+                };
+                enabled_features = enabled_features.with_format_version(format_version);
             } else if args[i] == "-dump" {
                 dump = true;
             } else if args[i] == "-all" {
                 all = true;
+            } else if args[i] == "-size" {
+                size = true;
+            } else if args[i] == "-optimize" {
+                optimize = true;
             } else if args[i] == "-overwrite" {
                 overwrite = true;
             } else if args[i] == "-noprogressive" {
                 enabled_features.progressive = false;
+            } else if args[i] == "-nochecksum" {
+                enabled_features.checksum = false;
+            } else if args[i] == "-nooriginalsize" {
+                enabled_features.original_size = false;
+            } else if args[i] == "-nosegmentchecksums" {
+                enabled_features.segment_checksums = false;
+            } else if args[i] == "-segmentindex" {
+                enabled_features.segment_index = true;
+            } else if args[i] == "-coefficientsonly" {
+                enabled_features.encode_mode = EncodeMode::CoefficientsOnly;
+            } else if args[i] == "-separatechroma" {
+                enabled_features.separate_chroma_models = true;
+            } else if args[i] == "-quantizeddc" {
+                enabled_features.quantized_dc_prediction = true;
+            } else if args[i] == "-trainedinit" {
+                enabled_features.trained_initial_probs = true;
+            } else if args[i] == "-lowlatency" {
+                enabled_features.low_latency = true;
+            } else if args[i] == "-memorybudgetfail" {
+                enabled_features.memory_budget_policy = MemoryBudgetPolicy::Fail;
+            } else if args[i] == "-strictsegmentend" {
+                enabled_features.strict_segment_end = true;
+            } else if args[i] == "-deterministic" {
+                enabled_features.deterministic = true;
+            } else if args[i] == "-passthrough" {
+                enabled_features.passthrough = true;
             } else {
                 return err_exit_code(
                     ExitCode::SyntaxError,
@@ -110,9 +194,10 @@ fn main_with_result() -> anyhow::Result<()> {
             lh.read_lepton_header(&mut reader).context(here!())?;
 
             let _metrics;
+            let remaining_size = filelen - reader.stream_position()?;
 
             (block_image, _metrics) = lh
-                .decode_as_single_image(&mut reader, filelen, num_threads as usize)
+                .decode_as_single_image(&mut reader, Some(remaining_size), num_threads as usize)
                 .context(here!())?;
 
             loop {
@@ -152,6 +237,17 @@ fn main_with_result() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if size {
+        let mut reader = BufReader::new(File::open(filenames[0]).context(here!())?);
+
+        println!(
+            "decoded size: {0} bytes",
+            compute_decoded_size_wrapper(&mut reader, num_threads as usize).context(here!())?
+        );
+
+        return Ok(());
+    }
+
     let mut input_data = Vec::new();
     if filenames.len() != 2 {
         if atty::is(atty::Stream::Stdin) || atty::is(atty::Stream::Stdout) {
@@ -198,10 +294,11 @@ fn main_with_result() -> anyhow::Result<()> {
             .context(here!())?;
 
             info!(
-                "compressed input {0}, output {1} bytes (ratio = {2:.1}%)",
+                "compressed input {0}, output {1} bytes (ratio = {2:.1}%) with {3} threads",
                 input_data.len(),
                 output_data.len(),
-                ((input_data.len() as f64) / (output_data.len() as f64) - 1.0) * 100.0
+                ((input_data.len() as f64) / (output_data.len() as f64) - 1.0) * 100.0,
+                metrics.get_max_threads()
             );
         } else if input_data[0] == 0xcf && input_data[1] == 0x84 {
             // the source is a lepton file, so run the decoder
@@ -209,8 +306,35 @@ fn main_with_result() -> anyhow::Result<()> {
 
             output_data = Vec::with_capacity(input_data.len());
 
-            metrics = decode_lepton_wrapper(&mut reader, &mut output_data, num_threads as usize)
-                .context(here!())?;
+            metrics = if optimize {
+                // not a byte exact copy of the original, but the same image with smaller Huffman tables
+                decode_lepton_wrapper_optimize_huffman(
+                    &mut reader,
+                    &mut output_data,
+                    num_threads as usize,
+                )
+                .context(here!())?
+            } else if let Some(chunk_size) = chunk_size {
+                // deliver the output in chunks the way a caller without a contiguous buffer would get it
+                decode_lepton_wrapper_chunked(
+                    &mut reader,
+                    chunk_size as usize,
+                    num_threads as usize,
+                    |chunk| {
+                        output_data.extend_from_slice(chunk);
+                        Ok(())
+                    },
+                )
+                .context(here!())?
+            } else {
+                decode_lepton_wrapper_with_features(
+                    &mut reader,
+                    &mut output_data,
+                    num_threads as usize,
+                    &enabled_features,
+                )
+                .context(here!())?
+            };
         } else {
             return err_exit_code(
                 ExitCode::BadLeptonFile,
@@ -246,6 +370,11 @@ fn main_with_result() -> anyhow::Result<()> {
         fileout.write_all(&output_data[..]).context(here!())?
     }
 
+    #[cfg(feature = "stats")]
+    if let Some(file) = branch_stats_file {
+        std::fs::write(file, metrics.branch_usage_csv()).context(here!())?;
+    }
+
     if iterations > 1 {
         info!(
             "Overall average CPU consumed per iteration {0}ms ",
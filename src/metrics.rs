@@ -25,10 +25,71 @@ pub struct ModelComponentStatistics {
     pub total_compressed: i64,
 }
 
+/// how often a branch of the model was used by the encoder and the probability it ended up with
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchUsage {
+    /// thread segment whose model the branch belongs to
+    pub segment: i32,
+    /// 0 for the model shared by the components, 1 for the model of Cr with separate chroma models
+    pub model: u8,
+    /// table of the model that the branch is in, see Model::branch_usage for what its indices are
+    pub table: &'static str,
+    pub indices: Vec<usize>,
+    /// number of bits coded with the branch
+    pub visits: u32,
+    /// probability of a zero (out of 256) after the last bit
+    pub probability: u8,
+}
+
+/// cheap counters of the arithmetic coder, which are only collected with the stats feature. The decoder counts
+/// the same decisions, renormalizations and bits as the encoder of the same file, only the carries are something
+/// that the encoder alone has. The bits that start and end the stream of each thread segment aren't counted.
+#[cfg(feature = "stats")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoderStats {
+    /// binary decisions coded with the branches of the model
+    pub decisions: u64,
+    /// decisions after which the coder shifted bits out of its range
+    pub renormalizations: u64,
+    /// carries that the encoder propagated into bytes it had already written
+    pub carries: u64,
+    /// bits that the decisions of each component shifted out of the range, which is what they cost in the coded
+    /// data
+    pub component_bits: [u64; 4],
+}
+
+#[cfg(feature = "stats")]
+impl CoderStats {
+    /// the bytes of coded data that the decisions of component cost, rounded down
+    pub fn component_bytes(&self, component: usize) -> u64 {
+        self.component_bits[component] / 8
+    }
+
+    fn merge_from(&mut self, other: &CoderStats) {
+        self.decisions += other.decisions;
+        self.renormalizations += other.renormalizations;
+        self.carries += other.carries;
+        for (bits, other_bits) in self.component_bits.iter_mut().zip(other.component_bits) {
+            *bits += other_bits;
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct Metrics {
     map: HashMap<ModelComponent, ModelComponentStatistics>,
     cpu_time_worker_time: Duration,
+    /// the bytes of arithmetic coded data of each thread segment, by the index of the segment
+    segment_bytes: Vec<u64>,
+    /// the most bytes of multiplexed data that the decoder held for its workers at the same time
+    read_ahead_peak: u64,
+    /// the most threads that the encoder or decoder was allowed to use
+    max_threads: usize,
+    #[cfg(feature = "stats")]
+    branch_usage: Vec<BranchUsage>,
+    #[cfg(feature = "stats")]
+    coder_stats: CoderStats,
 }
 
 pub trait ModelStatsCollector {
@@ -99,6 +160,13 @@ impl Metrics {
         Metrics {
             map: self.map.drain().collect(),
             cpu_time_worker_time: self.cpu_time_worker_time,
+            segment_bytes: std::mem::take(&mut self.segment_bytes),
+            read_ahead_peak: self.read_ahead_peak,
+            max_threads: self.max_threads,
+            #[cfg(feature = "stats")]
+            branch_usage: std::mem::take(&mut self.branch_usage),
+            #[cfg(feature = "stats")]
+            coder_stats: std::mem::take(&mut self.coder_stats),
         }
     }
 
@@ -106,6 +174,42 @@ impl Metrics {
         self.cpu_time_worker_time
     }
 
+    /// counts bytes of coded data for the thread segment
+    pub fn record_segment_bytes(&mut self, segment: usize, bytes: u64) {
+        if self.segment_bytes.len() <= segment {
+            self.segment_bytes.resize(segment + 1, 0);
+        }
+        self.segment_bytes[segment] += bytes;
+    }
+
+    /// the bytes of arithmetic coded data that the encoder wrote for each thread segment, which is the size of
+    /// the segment in the file. Only the encoder counts them.
+    pub fn get_segment_bytes(&self) -> &[u64] {
+        &self.segment_bytes
+    }
+
+    /// records the most bytes of multiplexed data that the decoder held for its workers at the same time
+    pub fn record_read_ahead_peak(&mut self, bytes: u64) {
+        self.read_ahead_peak = self.read_ahead_peak.max(bytes);
+    }
+
+    /// the most bytes of multiplexed data that the decoder read ahead of its workers, see
+    /// EnabledFeatures::max_read_ahead. Only the decoder counts them.
+    pub fn get_read_ahead_peak(&self) -> u64 {
+        self.read_ahead_peak
+    }
+
+    /// records the most threads that the coder was allowed to use, after picking them for a max_threads of 0
+    pub fn record_max_threads(&mut self, max_threads: usize) {
+        self.max_threads = self.max_threads.max(max_threads);
+    }
+
+    /// the most threads that the encoder or decoder was allowed to use, which is what it picked with
+    /// auto_thread_count if it was called with a max_threads of 0
+    pub fn get_max_threads(&self) -> usize {
+        self.max_threads
+    }
+
     pub fn merge_from(&mut self, mut source_metrics: Metrics) {
         for x in source_metrics.map.drain() {
             let e = self
@@ -117,5 +221,84 @@ impl Metrics {
         }
 
         self.cpu_time_worker_time += source_metrics.cpu_time_worker_time;
+
+        if self.segment_bytes.len() < source_metrics.segment_bytes.len() {
+            self.segment_bytes
+                .resize(source_metrics.segment_bytes.len(), 0);
+        }
+        for (bytes, source_bytes) in self
+            .segment_bytes
+            .iter_mut()
+            .zip(source_metrics.segment_bytes)
+        {
+            *bytes += source_bytes;
+        }
+
+        self.record_read_ahead_peak(source_metrics.read_ahead_peak);
+        self.record_max_threads(source_metrics.max_threads);
+
+        #[cfg(feature = "stats")]
+        {
+            self.branch_usage.append(&mut source_metrics.branch_usage);
+            self.coder_stats.merge_from(&source_metrics.coder_stats);
+        }
+    }
+
+    /// counts a decision of component after which the coder shifted shift bits out of its range
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    pub fn record_coded_decision(&mut self, component: usize, shift: i32) {
+        self.coder_stats.decisions += 1;
+        self.coder_stats.renormalizations += u64::from(shift > 0);
+        self.coder_stats.component_bits[component] += shift as u64;
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    pub fn record_carry(&mut self) {
+        self.coder_stats.carries += 1;
+    }
+
+    #[cfg(feature = "stats")]
+    pub fn record_branch_usage(&mut self, mut usage: Vec<BranchUsage>) {
+        self.branch_usage.append(&mut usage);
+    }
+
+    /// number of bits the coder coded with the branches of the model
+    #[cfg(feature = "stats")]
+    pub fn get_coded_decisions(&self) -> u64 {
+        self.coder_stats.decisions
+    }
+
+    /// the counters of the arithmetic coders of all the thread segments, see CoderStats
+    #[cfg(feature = "stats")]
+    pub fn get_coder_stats(&self) -> &CoderStats {
+        &self.coder_stats
+    }
+
+    /// usage of every branch of the models of each thread segment, including the ones that were never used
+    #[cfg(feature = "stats")]
+    pub fn get_branch_usage(&self) -> &[BranchUsage] {
+        &self.branch_usage
+    }
+
+    /// the branch usage as CSV with a header line. The indices of a branch are separated by colons.
+    #[cfg(feature = "stats")]
+    pub fn branch_usage_csv(&self) -> String {
+        let mut csv = String::from("segment,model,table,indices,visits,probability\n");
+        for u in &self.branch_usage {
+            let indices: Vec<String> = u.indices.iter().map(|i| i.to_string()).collect();
+            csv += &format!(
+                "{0},{1},{2},{3},{4},{5}\n",
+                u.segment,
+                u.model,
+                u.table,
+                indices.join(":"),
+                u.visits,
+                u.probability
+            );
+        }
+
+        csv
     }
 }
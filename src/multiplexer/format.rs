@@ -0,0 +1,234 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Framing of the multiplexed thread data. The data of each thread is split into chunks of at most
+//! MAX_CHUNK_LENGTH bytes, and each chunk is preceded by a header that says which thread it belongs to and how long
+//! it is:
+//!
+//! | bytes | contents |
+//! | ----- | -------- |
+//! | 0     | thread id (0 to 15) |
+//! | 1..3  | length of the chunk minus one, as a little endian u16 |
+//!
+//! A chunk always has at least one byte, so there are no empty chunks and no terminator: the multiplexed data ends
+//! where the data that follows it in the file begins (the segment index, or the segment checksums and the file
+//! size). Files written by Lepton C++ also contain a single byte form of the header, where bits 4 and 5 of the
+//! byte select a length of 1024, 4096, 16384 or 65536 bytes. It is read but never written.
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+/// largest amount of data that fits in a single chunk
+pub const MAX_CHUNK_LENGTH: usize = 65536;
+
+/// largest thread id, since the format supports at most 16 threads
+pub const MAX_THREAD_ID: u8 = 15;
+
+/// which thread a chunk of the multiplexed data belongs to and how many bytes of data follow the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkHeader {
+    pub thread_id: u8,
+    pub length: usize,
+}
+
+impl ChunkHeader {
+    /// size of the header as it is written
+    pub const SIZE: usize = 3;
+
+    /// reads a header in either form
+    pub fn read<R: Read + ?Sized>(reader: &mut R) -> Result<ChunkHeader> {
+        let thread_marker = reader.read_u8()?;
+        let thread_id = thread_marker & 0xf;
+
+        let length = if thread_marker < 16 {
+            usize::from(reader.read_u16::<LittleEndian>()?) + 1
+        } else {
+            // the single byte form written by Lepton C++
+            let flags = (thread_marker >> 4) & 3;
+
+            1024 << (2 * flags)
+        };
+
+        Ok(ChunkHeader { thread_id, length })
+    }
+
+    /// writes the header in the three byte form. Fails for thread ids beyond MAX_THREAD_ID and for lengths that
+    /// aren't between 1 and MAX_CHUNK_LENGTH.
+    pub fn write<W: Write + ?Sized>(&self, writer: &mut W) -> Result<()> {
+        if self.thread_id > MAX_THREAD_ID {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("thread id {0} is beyond {1}", self.thread_id, MAX_THREAD_ID),
+            ));
+        }
+
+        if self.length == 0 || self.length > MAX_CHUNK_LENGTH {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "chunk length {0} isn't between 1 and {1}",
+                    self.length, MAX_CHUNK_LENGTH
+                ),
+            ));
+        }
+
+        writer.write_u8(self.thread_id)?;
+        writer.write_u16::<LittleEndian>((self.length - 1) as u16)
+    }
+}
+
+/// writes data for thread_id as chunks of at most MAX_CHUNK_LENGTH bytes. Empty data writes nothing, since there
+/// are no empty chunks.
+pub fn write_chunks<W: Write + ?Sized>(writer: &mut W, thread_id: u8, data: &[u8]) -> Result<()> {
+    for chunk in data.chunks(MAX_CHUNK_LENGTH) {
+        ChunkHeader {
+            thread_id,
+            length: chunk.len(),
+        }
+        .write(writer)?;
+        writer.write_all(chunk)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+use std::io::Cursor;
+
+/// the bytes of the header are the thread id followed by the length minus one in little endian
+#[test]
+fn header_bytes() {
+    let mut data = Vec::new();
+    ChunkHeader {
+        thread_id: 3,
+        length: 0x1235,
+    }
+    .write(&mut data)
+    .unwrap();
+    assert_eq!(data, [3, 0x34, 0x12]);
+
+    assert_eq!(
+        ChunkHeader::read(&mut Cursor::new(&data)).unwrap(),
+        ChunkHeader {
+            thread_id: 3,
+            length: 0x1235
+        }
+    );
+}
+
+/// every thread id with the shortest and longest lengths and the ones around the byte boundary read back the same
+#[test]
+fn header_round_trip() {
+    for thread_id in 0..=MAX_THREAD_ID {
+        for length in [1, 2, 255, 256, 257, MAX_CHUNK_LENGTH - 1, MAX_CHUNK_LENGTH] {
+            let header = ChunkHeader { thread_id, length };
+
+            let mut data = Vec::new();
+            header.write(&mut data).unwrap();
+            assert_eq!(data.len(), ChunkHeader::SIZE);
+            assert_eq!(ChunkHeader::read(&mut Cursor::new(&data)).unwrap(), header);
+        }
+    }
+}
+
+/// the longest chunk is written with all the length bits set
+#[test]
+fn header_max_length() {
+    let mut data = Vec::new();
+    ChunkHeader {
+        thread_id: MAX_THREAD_ID,
+        length: MAX_CHUNK_LENGTH,
+    }
+    .write(&mut data)
+    .unwrap();
+    assert_eq!(data, [15, 0xff, 0xff]);
+}
+
+/// headers that can't be written are refused without writing anything
+#[test]
+fn header_invalid() {
+    for header in [
+        ChunkHeader {
+            thread_id: 0,
+            length: 0,
+        },
+        ChunkHeader {
+            thread_id: 0,
+            length: MAX_CHUNK_LENGTH + 1,
+        },
+        ChunkHeader {
+            thread_id: MAX_THREAD_ID + 1,
+            length: 1,
+        },
+    ] {
+        let mut data = Vec::new();
+        assert_eq!(
+            header.write(&mut data).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert!(data.is_empty());
+    }
+}
+
+/// the single byte form of Lepton C++ has the length in bits 4 and 5
+#[test]
+fn header_cpp_form() {
+    for (flags, length) in [(0, 1024), (1, 4096), (2, 16384), (3, 65536)] {
+        for thread_id in 0..=MAX_THREAD_ID {
+            let data = [0x40 | (flags << 4) | thread_id];
+            assert_eq!(
+                ChunkHeader::read(&mut Cursor::new(&data)).unwrap(),
+                ChunkHeader { thread_id, length }
+            );
+        }
+    }
+}
+
+/// a header cut off in the middle is an error
+#[test]
+fn header_truncated() {
+    for data in [&[][..], &[1], &[1, 0]] {
+        assert_eq!(
+            ChunkHeader::read(&mut Cursor::new(data))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+}
+
+/// data is split into as many chunks as needed, and empty data doesn't write a zero length terminator
+#[test]
+fn chunks_split() {
+    let mut data = Vec::new();
+    write_chunks(&mut data, 2, &[]).unwrap();
+    assert!(data.is_empty());
+
+    let payload: Vec<u8> = (0..MAX_CHUNK_LENGTH * 2 + 1).map(|i| i as u8).collect();
+    write_chunks(&mut data, 2, &payload).unwrap();
+    assert_eq!(data.len(), payload.len() + 3 * ChunkHeader::SIZE);
+
+    let mut reader = Cursor::new(&data);
+    let mut read_back = Vec::new();
+    for expected_length in [MAX_CHUNK_LENGTH, MAX_CHUNK_LENGTH, 1] {
+        let header = ChunkHeader::read(&mut reader).unwrap();
+        assert_eq!(
+            header,
+            ChunkHeader {
+                thread_id: 2,
+                length: expected_length
+            }
+        );
+
+        let start = read_back.len();
+        read_back.resize(start + header.length, 0);
+        reader.read_exact(&mut read_back[start..]).unwrap();
+    }
+
+    assert_eq!(reader.position(), data.len() as u64);
+    assert_eq!(read_back, payload);
+}
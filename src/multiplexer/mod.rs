@@ -0,0 +1,10 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! The encoder threads each write their own stream of arithmetic coded data, which are interleaved into the lepton
+//! file in chunks so that the decoder threads can start on their part of the image as soon as it arrives.
+
+pub mod format;
@@ -4,22 +4,49 @@
  *  This software incorporates material from third parties. See NOTICE.txt for details.
  *--------------------------------------------------------------------------------------------*/
 
-use std::io::Read;
+use std::cmp;
+use std::io::{Read, Seek, SeekFrom};
 
 use crate::{helpers::err_exit_code, jpeg_code};
 
 use crate::lepton_error::ExitCode;
 
+/// how much of the scan data is read from the underlying reader at a time
+const BUFFER_SIZE: usize = 16384;
+
+/// true if any of the bytes of word is 0xff, with the zero byte test of "Bit Twiddling Hacks" on the complement.
+/// The test can flag the wrong byte after a zero byte, but never flags a word without one.
+#[inline(always)]
+fn has_ff_byte(word: u64) -> bool {
+    let x = !word;
+    (x.wrapping_sub(0x0101010101010101) & !x & 0x8080808080808080) != 0
+}
+
+/// the COM or APPn segments that read_reset_codes found between restart intervals, each with the number of RST
+/// markers that came before it
+pub type IntervalSegments = Vec<(u32, Vec<u8>)>;
+
 // Implemenation of bit reader on top of JPEG data stream as read by a reader
 pub struct BitReader<R> {
     inner: R,
     bits: u64,
     num_bits: u8,
-    cpos: u32,
-    offset: i32, // offset of next bit that we will read in the file
+    offset: i64, // offset of next bit that we will read in the file
     eof: bool,
-    prev_offset: i32, // position of last escape. used to adjust the current position.
+    prev_offset: i64, // position of last escape. used to adjust the current position.
     last_byte_read: u8,
+
+    /// the scan data read ahead from inner, of which the bytes before buffer_pos were taken
+    buffer: Vec<u8>,
+    buffer_pos: usize,
+    buffer_len: usize,
+    /// where the start of the buffer is in inner, counted from where the reader started
+    buffer_start: u64,
+
+    /// bytes that were loaded into the register after the num_bits bits, eight at a time from a word without a
+    /// 0xff. As far as the stream position and the overhang are concerned they haven't been read yet, so that the
+    /// reader behaves exactly as if it had read one byte at a time as it needed them.
+    spare: u8,
 }
 
 impl<R: Read> BitReader<R> {
@@ -28,11 +55,31 @@ impl<R: Read> BitReader<R> {
             inner: inner,
             bits: 0,
             num_bits: 0,
-            cpos: 0,
             offset: 0,
             eof: false,
             prev_offset: 0,
             last_byte_read: 0,
+            buffer: vec![0; BUFFER_SIZE],
+            buffer_pos: 0,
+            buffer_len: 0,
+            buffer_start: 0,
+            spare: 0,
+        }
+    }
+
+    /// where the reader is, to go back to with restore, including the bits in the register, the escapes and
+    /// whether the end of the data has been reached
+    #[cfg(test)] // for speculative decoding, which doesn't exist yet
+    pub fn checkpoint(&self) -> ReaderState {
+        ReaderState {
+            bits: self.bits,
+            num_bits: self.num_bits,
+            offset: self.offset,
+            eof: self.eof,
+            prev_offset: self.prev_offset,
+            last_byte_read: self.last_byte_read,
+            spare: self.spare,
+            input_position: self.buffer_start + self.buffer_pos as u64,
         }
     }
 
@@ -52,11 +99,19 @@ impl<R: Read> BitReader<R> {
         return Ok(retval);
     }
 
+    /// the next 8 bits and how many of them have been read into the register. The bits after those can already be
+    /// set, and only mean anything once the register is filled up to them.
     #[inline(always)]
     pub fn peek(&self) -> (u8, u8) {
         return ((self.bits >> 56) as u8, self.num_bits);
     }
 
+    /// the next 16 bits and how many of them have been read into the register, like peek
+    #[inline(always)]
+    pub fn peek16(&self) -> (u16, u8) {
+        ((self.bits >> 48) as u16, self.num_bits)
+    }
+
     #[inline(always)]
     pub fn advance(&mut self, bits: u8) {
         self.num_bits -= bits;
@@ -66,63 +121,140 @@ impl<R: Read> BitReader<R> {
     #[inline(always)]
     pub fn fill_register(&mut self, bits_to_read: u8) -> Result<(), std::io::Error> {
         while self.num_bits < bits_to_read {
-            let mut buffer = [0u8];
-            if self.inner.read(&mut buffer)? == 0 {
-                return self.fill_register_slow(None, bits_to_read);
-            } else if buffer[0] == 0xff {
-                return self.fill_register_slow(Some(buffer[0]), bits_to_read);
-            } else {
-                self.prev_offset = self.offset;
-                self.offset += 1;
-                self.bits |= (buffer[0] as u64) << (56 - self.num_bits);
-                self.num_bits += 8;
-                self.last_byte_read = buffer[0];
+            if self.spare == 0 && !self.load_word() {
+                return self.fill_register_slow(bits_to_read);
             }
+
+            // take as many of the bytes that were loaded ahead as are needed at once
+            let take = cmp::min(self.spare, (bits_to_read - self.num_bits + 7) / 8);
+            self.spare -= take;
+
+            self.offset += i64::from(take);
+            self.prev_offset = self.offset - 1;
+            self.num_bits += 8 * take;
+            self.last_byte_read = (self.bits >> (64 - self.num_bits)) as u8;
         }
         return Ok(());
     }
 
+    /// loads as many whole bytes of the next 8 bytes of the buffer as fit into the register as spare bytes, unless
+    /// there is a 0xff among them or the buffer has fewer left
+    #[inline(always)]
+    fn load_word(&mut self) -> bool {
+        if self.buffer_len - self.buffer_pos < 8 {
+            return false;
+        }
+
+        let word = u64::from_be_bytes(
+            self.buffer[self.buffer_pos..self.buffer_pos + 8]
+                .try_into()
+                .unwrap(),
+        );
+        if has_ff_byte(word) {
+            return false;
+        }
+
+        // as many whole bytes as fit behind the bits that are left
+        let fit = (64 - u32::from(self.num_bits)) / 8;
+        let loaded = if fit == 8 {
+            word
+        } else {
+            word & !(u64::MAX >> (8 * fit))
+        };
+        self.bits |= loaded >> self.num_bits;
+        self.buffer_pos += fit as usize;
+        self.spare = fit as u8;
+
+        true
+    }
+
+    /// the next byte of the stream, or None at the end of it
+    #[inline(always)]
+    fn read_byte(&mut self) -> std::io::Result<Option<u8>> {
+        if self.buffer_pos == self.buffer_len {
+            self.buffer_start += self.buffer_len as u64;
+            self.buffer_len = self.inner.read(&mut self.buffer)?;
+            self.buffer_pos = 0;
+
+            if self.buffer_len == 0 {
+                return Ok(None);
+            }
+        }
+
+        let b = self.buffer[self.buffer_pos];
+        self.buffer_pos += 1;
+        Ok(Some(b))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        for b in buf.iter_mut() {
+            *b = self.read_byte()?.ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// puts the bytes that were loaded into the register ahead of time back into the buffer. They all came from
+    /// the part of the buffer before buffer_pos, since it is only refilled once they are used up.
+    fn drop_spare_bytes(&mut self) {
+        if self.spare > 0 {
+            self.buffer_pos -= usize::from(self.spare);
+            self.spare = 0;
+
+            // with spare bytes, num_bits is at most 56
+            self.bits &= !(u64::MAX >> self.num_bits);
+        }
+    }
+
+    /// fills the register a byte at a time, taking care of escaped 0xffs and the end of the stream. Only called
+    /// once the spare bytes are used up.
     #[cold]
-    fn fill_register_slow(
-        &mut self,
-        mut byte_read: Option<u8>,
-        bits_to_read: u8,
-    ) -> Result<(), std::io::Error> {
+    fn fill_register_slow(&mut self, bits_to_read: u8) -> Result<(), std::io::Error> {
+        debug_assert_eq!(self.spare, 0);
+
         loop {
+            let byte_read = self.read_byte()?;
+
             if let Some(b) = byte_read {
                 // 0xff is an escape code, if the next by is zero, then it is just a normal 0
                 // otherwise it is a reset code, which should also be skipped
                 if b == 0xff {
-                    let mut buffer = [0u8];
+                    match self.read_byte()? {
+                        None => {
+                            // Handle case of truncation: Since we assume that everything passed the end
+                            // is a 0, if the file ends with 0xFF, then we have to assume that this was
+                            // an escaped 0xff. Don't mark as eof yet, since there are still the 8 bits to read.
+                            self.prev_offset = self.offset;
+                            self.offset += 1; // we only have 1 byte to advance in the stream and don't want to go past EOF.
+                            self.bits |= (0xff as u64) << (56 - self.num_bits);
+                            self.num_bits += 8;
+                            self.last_byte_read = 0xff;
 
-                    if self.inner.read(&mut buffer)? == 0 {
-                        // Handle case of truncation: Since we assume that everything passed the end
-                        // is a 0, if the file ends with 0xFF, then we have to assume that this was
-                        // an escaped 0xff. Don't mark as eof yet, since there are still the 8 bits to read.
-                        self.prev_offset = self.offset;
-                        self.offset += 1; // we only have 1 byte to advance in the stream and don't want to go past EOF.
-                        self.bits |= (0xff as u64) << (56 - self.num_bits);
-                        self.num_bits += 8;
-                        self.last_byte_read = 0xff;
-
-                        // continue since we still might need to read more 0 bits
-                    } else if buffer[0] == 0 {
-                        // this was an escaped FF
-                        self.prev_offset = self.offset;
-                        self.offset += 2;
-                        self.bits |= (0xff as u64) << (56 - self.num_bits);
-                        self.num_bits += 8;
-                        self.last_byte_read = 0xff;
-                    } else {
-                        // verify_reset_code should get called in all instances where there should be a reset code. If we find one that
-                        // is not where it is supposed to be, then we would fail to roundtrip the reset code, so just fail.
-                        return Err(std::io::Error::new(
-                            std::io::ErrorKind::InvalidData,
-                            format!(
-                                "invalid reset {0:x} {1:x} code found in stream at offset {2}",
-                                0xff, buffer[0], self.offset
-                            ),
-                        ));
+                            // continue since we still might need to read more 0 bits
+                        }
+                        Some(0) => {
+                            // this was an escaped FF
+                            self.prev_offset = self.offset;
+                            self.offset += 2;
+                            self.bits |= (0xff as u64) << (56 - self.num_bits);
+                            self.num_bits += 8;
+                            self.last_byte_read = 0xff;
+                        }
+                        Some(code) => {
+                            // verify_reset_code should get called in all instances where there should be a reset code. If we find one that
+                            // is not where it is supposed to be, then we would fail to roundtrip the reset code, so just fail.
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::InvalidData,
+                                format!(
+                                    "invalid reset {0:x} {1:x} code found in stream at offset {2}",
+                                    0xff, code, self.offset
+                                ),
+                            ));
+                        }
                     }
                 } else {
                     self.prev_offset = self.offset;
@@ -146,18 +278,11 @@ impl<R: Read> BitReader<R> {
             if self.num_bits >= bits_to_read {
                 break;
             }
-
-            let mut buffer = [0u8];
-            if self.inner.read(&mut buffer)? == 0 {
-                byte_read = None;
-            } else {
-                byte_read = Some(buffer[0]);
-            }
         }
         Ok(())
     }
 
-    pub fn get_stream_position(&self) -> i32 {
+    pub fn get_stream_position(&self) -> i64 {
         // if there are still bits left, then we should be referring to the previous offset
         if self.num_bits > 0 {
             // if we still have bits, we need to go back to the last offset
@@ -167,13 +292,24 @@ impl<R: Read> BitReader<R> {
         }
     }
 
+    /// the position of the next bit to be read in bits from where the reader started, counting the bytes that
+    /// escape a 0xff. While an escaped 0xff is still in the register, the position is already past its escape.
+    pub fn position_bits(&self) -> u64 {
+        cmp::max(self.offset * 8 - i64::from(self.num_bits), 0) as u64
+    }
+
     pub fn is_eof(&mut self) -> bool {
         return self.eof;
     }
 
-    /// used to verify whether this image is using 1s or 0s as fill bits.
-    /// Returns whether the fill bit was 1 or so or unknown (None)
-    pub fn read_and_verify_fill_bits(&mut self, pad_bit: &mut Option<u8>) -> anyhow::Result<()> {
+    /// used to verify whether this image is using 1s or 0s as fill bits. The first consistent
+    /// fill that we see establishes pad_bit. If the fill bits don't match pad_bit (some encoders mix
+    /// 1s and 0s or even use arbitrary patterns), then the actual bits are returned so that the caller
+    /// can record them as an exception for this position.
+    pub fn read_and_verify_fill_bits(
+        &mut self,
+        pad_bit: &mut Option<u8>,
+    ) -> anyhow::Result<Option<u8>> {
         // if there are bits left, we need to see whether they
         // are 1s or zeros.
 
@@ -189,55 +325,105 @@ impl<R: Read> BitReader<R> {
                     } else if actual == all_one {
                         *pad_bit = Some(0xff);
                     } else {
-                        return err_exit_code(
-                            ExitCode::UnsupportedJpeg,
-                            format!(
-                                "inconsistent pad bits num_bits={0} pattern={1:b}",
-                                num_bits_to_read, actual
-                            )
-                            .as_str(),
-                        );
+                        return Ok(Some(actual as u8));
                     }
                 }
                 Some(x) => {
-                    // if we already saw a padding, then it should match
+                    // if we already saw a padding, then it should match, otherwise we need to remember the exception
                     let expected = u16::from(x) & all_one;
                     if actual != expected {
-                        return err_exit_code(ExitCode::UnsupportedJpeg, format!("padding of {0} bits should be set to 1 actual={1:b} expected={2:b}", num_bits_to_read, actual, expected).as_str());
+                        return Ok(Some(actual as u8));
                     }
                 }
             }
         }
 
-        return Ok(());
+        Ok(None)
     }
 
-    pub fn verify_reset_code(&mut self) -> anyhow::Result<()> {
-        // we reached the end of a MCU, so we need to find a reset code and the huffman codes start get padded out, but the spec
-        // doesn't specify whether the padding should be 1s or 0s, so we ensure that at least the file is consistant so that we
-        // can recode it again just by remembering the pad bit.
+    /// reads the RST markers at the end of a restart interval. Normally this is exactly one marker with the next
+    /// number in sequence, but some encoders leave it out or write redundant ones, so all consecutive markers
+    /// are returned (without the 0xff prefix) and it is up to the caller to remember any deviation so the
+    /// file can be recreated exactly. If the scan data continues without a marker, the byte we had to
+    /// look at is kept in the bit register so that decoding can continue normally.
+    ///
+    /// Some broken files also have COM or APPn segments between the intervals, which decoders skip. These are
+    /// returned in their entirety along with the number of RST markers that came before them.
+    pub fn read_reset_codes(&mut self) -> anyhow::Result<(Vec<u8>, IntervalSegments)> {
+        // start from scratch after the padding
+        self.drop_spare_bytes();
+        self.bits = 0;
+        self.num_bits = 0;
 
-        let mut h = [0u8; 2];
-        self.inner.read_exact(&mut h)?;
-        if h[0] != 0xff || h[1] != (jpeg_code::RST0 + (self.cpos as u8 & 7)) {
-            return err_exit_code(
-                ExitCode::UnsupportedJpeg,
-                format!(
-                    "invalid reset code {0:x} {1:x} found in stream at offset {2}",
-                    h[0], h[1], self.offset
-                )
-                .as_str(),
-            );
+        let mut markers = Vec::new();
+        let mut segments = Vec::new();
+        loop {
+            let mut h = [0u8];
+            self.read_exact(&mut h)?;
+
+            if h[0] != 0xff {
+                self.push_back_byte(h[0], 1);
+                return Ok((markers, segments));
+            }
+
+            self.read_exact(&mut h)?;
+
+            if h[0] == 0 {
+                // escaped 0xff, so the scan data continues right away
+                self.push_back_byte(0xff, 2);
+                return Ok((markers, segments));
+            }
+
+            if h[0] == jpeg_code::COM || (h[0] >= jpeg_code::APP0 && h[0] <= jpeg_code::APP0 + 15) {
+                let mut len = [0u8; 2];
+                self.read_exact(&mut len)?;
+
+                let len = usize::from(u16::from_be_bytes(len));
+                if len < 2 {
+                    return err_exit_code(
+                        ExitCode::UnsupportedJpeg,
+                        format!(
+                            "invalid segment length {0} in stream at offset {1}",
+                            len, self.offset
+                        )
+                        .as_str(),
+                    );
+                }
+
+                let mut segment = vec![0xff, h[0], (len >> 8) as u8, len as u8];
+                segment.resize(len + 2, 0);
+                self.read_exact(&mut segment[4..])?;
+
+                segments.push((markers.len() as u32, segment));
+                self.offset += len as i64 + 2;
+                self.prev_offset = self.offset;
+                continue;
+            }
+
+            if h[0] < jpeg_code::RST0 || h[0] > jpeg_code::RST0 + 7 {
+                return err_exit_code(
+                    ExitCode::UnsupportedJpeg,
+                    format!(
+                        "invalid reset code {0:x} {1:x} found in stream at offset {2}",
+                        0xff, h[0], self.offset
+                    )
+                    .as_str(),
+                );
+            }
+
+            markers.push(h[0]);
+            self.offset += 2;
+            self.prev_offset = self.offset;
         }
+    }
 
-        // start from scratch after RST
-        self.cpos += 1;
-        self.offset += 2;
+    /// puts a byte of scan data that was read while looking for a RST marker into the empty bit register
+    fn push_back_byte(&mut self, b: u8, bytes_in_stream: i64) {
         self.prev_offset = self.offset;
-        self.bits = 0;
-        self.num_bits = 0;
-
-        Ok(())
+        self.offset += bytes_in_stream;
+        self.bits = u64::from(b) << 56;
+        self.num_bits = 8;
+        self.last_byte_read = b;
     }
 
     /// Retrieves the byte containing the next bit to be read in the stream, with only
@@ -255,6 +441,92 @@ impl<R: Read> BitReader<R> {
     }
 }
 
+impl<R: Read + Seek> BitReader<R> {
+    /// moves the underlying reader back to just after the last byte that was read from the scan, giving back the
+    /// data that was read ahead of it. Called once the scan has been read.
+    pub fn return_read_ahead(&mut self) -> std::io::Result<()> {
+        self.drop_spare_bytes();
+
+        let read_ahead = self.buffer_len - self.buffer_pos;
+        if read_ahead > 0 {
+            self.inner.seek(SeekFrom::Current(-(read_ahead as i64)))?;
+            self.buffer_start += self.buffer_pos as u64;
+            self.buffer_len = 0;
+            self.buffer_pos = 0;
+        }
+
+        Ok(())
+    }
+
+    /// goes back to where the reader was when checkpoint returned state, so that the bits after it are read again
+    /// exactly as they were the first time. The data is taken from the buffer if it is still there and read again
+    /// from inner if it isn't, which is the only time that the reader seeks.
+    #[cfg(test)] // for speculative decoding, which doesn't exist yet
+    pub fn restore(&mut self, state: &ReaderState) -> std::io::Result<()> {
+        // the bytes that were loaded ahead are given back, since they may not be in the buffer anymore
+        let position = state.input_position - u64::from(state.spare);
+        let buffer_end = self.buffer_start + self.buffer_len as u64;
+
+        if position >= self.buffer_start && position <= buffer_end {
+            self.buffer_pos = (position - self.buffer_start) as usize;
+        } else {
+            self.inner
+                .seek(SeekFrom::Current(position as i64 - buffer_end as i64))?;
+            self.buffer_start = position;
+            self.buffer_len = 0;
+            self.buffer_pos = 0;
+        }
+
+        self.bits = state.bits;
+        self.num_bits = state.num_bits;
+        self.offset = state.offset;
+        self.eof = state.eof;
+        self.prev_offset = state.prev_offset;
+        self.last_byte_read = state.last_byte_read;
+        self.spare = 0;
+        if state.spare > 0 {
+            // with spare bytes, num_bits is at most 56
+            self.bits &= !(u64::MAX >> state.num_bits);
+        }
+
+        Ok(())
+    }
+
+    /// the next bits_to_peek bits (at most 32) without reading them. Past the end of the data they are zeros, like
+    /// for read, and an invalid marker fails like it would for read. Since filling the register changes the stream
+    /// position, the reader is restored to where it was afterwards instead of only peeking at the register.
+    #[cfg(test)] // for speculative decoding, which doesn't exist yet
+    pub fn peek_bits(&mut self, bits_to_peek: u8) -> std::io::Result<u32> {
+        debug_assert!(bits_to_peek <= 32);
+
+        let state = self.checkpoint();
+
+        let low_bits = bits_to_peek.min(16);
+        let peeked = self
+            .read(bits_to_peek - low_bits)
+            .and_then(|high| Ok((u32::from(high) << low_bits) | u32::from(self.read(low_bits)?)));
+
+        self.restore(&state)?;
+
+        peeked
+    }
+}
+
+/// where a BitReader was, see BitReader::checkpoint
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReaderState {
+    bits: u64,
+    num_bits: u8,
+    offset: i64,
+    eof: bool,
+    prev_offset: i64,
+    last_byte_read: u8,
+    spare: u8,
+    /// where the first byte that wasn't loaded into the register is in inner, counted from where the reader started
+    input_position: u64,
+}
+
 #[cfg(test)]
 use std::io::Cursor;
 
@@ -324,3 +596,638 @@ fn read_truncate_ff() {
     assert_eq!(true, b.is_eof());
     assert_eq!(2, b.get_stream_position());
 }
+
+// all consecutive RST markers are returned, and if there are none the scan data continues where the marker should have been
+#[test]
+fn read_reset_codes() {
+    let arr = [0x12u8, 0xff, 0xd0, 0xff, 0xd0, 0x34, 0xff, 0x00, 0x56];
+
+    let mut b = BitReader::new(Cursor::new(&arr));
+
+    assert_eq!(0x12, b.read(8).unwrap());
+
+    // redundant marker
+    assert_eq!(
+        (vec![0xd0, 0xd0], Vec::new()),
+        b.read_reset_codes().unwrap()
+    );
+    assert_eq!(5, b.get_stream_position());
+    assert_eq!(0x34, b.read(8).unwrap());
+
+    // missing marker followed by an escaped 0xff
+    assert_eq!((Vec::new(), Vec::new()), b.read_reset_codes().unwrap());
+    assert_eq!(6, b.get_stream_position());
+    assert_eq!(0xff, b.read(8).unwrap());
+    assert_eq!(0x56, b.read(8).unwrap());
+    assert_eq!(9, b.get_stream_position());
+}
+
+// COM and APPn segments between the intervals are returned along with their position among the RST markers
+#[test]
+fn read_reset_codes_with_segments() {
+    let arr = [
+        0x12u8, 0xff, 0xfe, 0x00, 0x03, 0x41, 0xff, 0xd0, 0xff, 0xe1, 0x00, 0x02, 0x34,
+    ];
+
+    let mut b = BitReader::new(Cursor::new(&arr));
+
+    assert_eq!(0x12, b.read(8).unwrap());
+
+    assert_eq!(
+        (
+            vec![0xd0],
+            vec![
+                (0, vec![0xff, 0xfe, 0x00, 0x03, 0x41]),
+                (1, vec![0xff, 0xe1, 0x00, 0x02])
+            ]
+        ),
+        b.read_reset_codes().unwrap()
+    );
+    assert_eq!(12, b.get_stream_position());
+    assert_eq!(0x34, b.read(8).unwrap());
+}
+
+/// the reader as it was before it loaded words, which reads and escapes one byte at a time, used as a reference
+/// for checking that BitReader behaves exactly the same
+#[cfg(test)]
+struct ByteAtATimeReader<R> {
+    inner: R,
+    bits: u64,
+    num_bits: u8,
+    offset: i64,
+    eof: bool,
+    prev_offset: i64,
+    last_byte_read: u8,
+}
+
+#[cfg(test)]
+impl<R: Read> ByteAtATimeReader<R> {
+    fn new(inner: R) -> Self {
+        ByteAtATimeReader {
+            inner,
+            bits: 0,
+            num_bits: 0,
+            offset: 0,
+            eof: false,
+            prev_offset: 0,
+            last_byte_read: 0,
+        }
+    }
+
+    fn read(&mut self, bits_to_read: u8) -> std::io::Result<u16> {
+        if bits_to_read == 0 {
+            return Ok(0);
+        }
+
+        if self.num_bits < bits_to_read {
+            self.fill_register(bits_to_read)?;
+        }
+
+        let retval = (self.bits >> (64 - bits_to_read)) as u16;
+        self.bits <<= bits_to_read as usize;
+        self.num_bits -= bits_to_read;
+        Ok(retval)
+    }
+
+    fn peek(&self) -> (u8, u8) {
+        ((self.bits >> 56) as u8, self.num_bits)
+    }
+
+    fn advance(&mut self, bits: u8) {
+        self.num_bits -= bits;
+        self.bits <<= bits;
+    }
+
+    fn fill_register(&mut self, bits_to_read: u8) -> Result<(), std::io::Error> {
+        while self.num_bits < bits_to_read {
+            let mut buffer = [0u8];
+            if self.inner.read(&mut buffer)? == 0 {
+                return self.fill_register_slow(None, bits_to_read);
+            } else if buffer[0] == 0xff {
+                return self.fill_register_slow(Some(buffer[0]), bits_to_read);
+            } else {
+                self.prev_offset = self.offset;
+                self.offset += 1;
+                self.bits |= u64::from(buffer[0]) << (56 - self.num_bits);
+                self.num_bits += 8;
+                self.last_byte_read = buffer[0];
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_register_slow(
+        &mut self,
+        mut byte_read: Option<u8>,
+        bits_to_read: u8,
+    ) -> Result<(), std::io::Error> {
+        loop {
+            if let Some(b) = byte_read {
+                if b == 0xff {
+                    let mut buffer = [0u8];
+
+                    if self.inner.read(&mut buffer)? == 0 {
+                        self.prev_offset = self.offset;
+                        self.offset += 1;
+                        self.bits |= 0xff_u64 << (56 - self.num_bits);
+                        self.num_bits += 8;
+                        self.last_byte_read = 0xff;
+                    } else if buffer[0] == 0 {
+                        self.prev_offset = self.offset;
+                        self.offset += 2;
+                        self.bits |= 0xff_u64 << (56 - self.num_bits);
+                        self.num_bits += 8;
+                        self.last_byte_read = 0xff;
+                    } else {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!(
+                                "invalid reset {0:x} {1:x} code found in stream at offset {2}",
+                                0xff, buffer[0], self.offset
+                            ),
+                        ));
+                    }
+                } else {
+                    self.prev_offset = self.offset;
+                    self.offset += 1;
+                    self.bits |= u64::from(b) << (56 - self.num_bits);
+                    self.num_bits += 8;
+                    self.last_byte_read = b;
+                }
+            } else {
+                self.eof = true;
+                self.num_bits += 8;
+                self.prev_offset = self.offset;
+                self.last_byte_read = 0;
+            }
+
+            if self.num_bits >= bits_to_read {
+                break;
+            }
+
+            let mut buffer = [0u8];
+            if self.inner.read(&mut buffer)? == 0 {
+                byte_read = None;
+            } else {
+                byte_read = Some(buffer[0]);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_stream_position(&self) -> i64 {
+        if self.num_bits > 0 {
+            self.prev_offset
+        } else {
+            self.offset
+        }
+    }
+
+    fn is_eof(&mut self) -> bool {
+        self.eof
+    }
+
+    fn read_and_verify_fill_bits(
+        &mut self,
+        pad_bit: &mut Option<u8>,
+    ) -> anyhow::Result<Option<u8>> {
+        if self.num_bits > 0 && !self.eof {
+            let num_bits_to_read = self.num_bits;
+            let actual = self.read(num_bits_to_read)?;
+            let all_one = (1 << num_bits_to_read) - 1;
+
+            match *pad_bit {
+                None => {
+                    if actual == 0 {
+                        *pad_bit = Some(0);
+                    } else if actual == all_one {
+                        *pad_bit = Some(0xff);
+                    } else {
+                        return Ok(Some(actual as u8));
+                    }
+                }
+                Some(x) => {
+                    let expected = u16::from(x) & all_one;
+                    if actual != expected {
+                        return Ok(Some(actual as u8));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn read_reset_codes(&mut self) -> anyhow::Result<(Vec<u8>, IntervalSegments)> {
+        self.bits = 0;
+        self.num_bits = 0;
+
+        let mut markers = Vec::new();
+        let mut segments = Vec::new();
+        loop {
+            let mut h = [0u8];
+            self.inner.read_exact(&mut h)?;
+
+            if h[0] != 0xff {
+                self.push_back_byte(h[0], 1);
+                return Ok((markers, segments));
+            }
+
+            self.inner.read_exact(&mut h)?;
+
+            if h[0] == 0 {
+                self.push_back_byte(0xff, 2);
+                return Ok((markers, segments));
+            }
+
+            if h[0] == jpeg_code::COM || (h[0] >= jpeg_code::APP0 && h[0] <= jpeg_code::APP0 + 15) {
+                let mut len = [0u8; 2];
+                self.inner.read_exact(&mut len)?;
+
+                let len = usize::from(u16::from_be_bytes(len));
+                if len < 2 {
+                    return err_exit_code(
+                        ExitCode::UnsupportedJpeg,
+                        format!(
+                            "invalid segment length {0} in stream at offset {1}",
+                            len, self.offset
+                        )
+                        .as_str(),
+                    );
+                }
+
+                let mut segment = vec![0xff, h[0], (len >> 8) as u8, len as u8];
+                segment.resize(len + 2, 0);
+                self.inner.read_exact(&mut segment[4..])?;
+
+                segments.push((markers.len() as u32, segment));
+                self.offset += len as i64 + 2;
+                self.prev_offset = self.offset;
+                continue;
+            }
+
+            if h[0] < jpeg_code::RST0 || h[0] > jpeg_code::RST0 + 7 {
+                return err_exit_code(
+                    ExitCode::UnsupportedJpeg,
+                    format!(
+                        "invalid reset code {0:x} {1:x} found in stream at offset {2}",
+                        0xff, h[0], self.offset
+                    )
+                    .as_str(),
+                );
+            }
+
+            markers.push(h[0]);
+            self.offset += 2;
+            self.prev_offset = self.offset;
+        }
+    }
+
+    fn push_back_byte(&mut self, b: u8, bytes_in_stream: i64) {
+        self.prev_offset = self.offset;
+        self.offset += bytes_in_stream;
+        self.bits = u64::from(b) << 56;
+        self.num_bits = 8;
+        self.last_byte_read = b;
+    }
+
+    fn overhang(&self) -> (u8, u8) {
+        let bits_already_read = (64 - self.num_bits) & 7;
+
+        let mask = (((1 << bits_already_read) - 1) << (8 - bits_already_read)) as u8;
+
+        (bits_already_read, self.last_byte_read & mask)
+    }
+}
+
+/// what a reader tells about itself after an operation, with the bits of peek that haven't been read into the
+/// register masked out, since BitReader can already have loaded the ones after them
+#[cfg(test)]
+#[derive(Debug, PartialEq)]
+pub(crate) struct ReaderObservation {
+    pub result: String,
+    peek: (u8, u8),
+    stream_position: i64,
+    overhang: (u8, u8),
+    eof: bool,
+}
+
+#[cfg(test)]
+pub(crate) fn reader_observation(
+    result: String,
+    peek: (u8, u8),
+    stream_position: i64,
+    overhang: (u8, u8),
+    eof: bool,
+) -> ReaderObservation {
+    let (value, num_bits) = peek;
+    ReaderObservation {
+        result,
+        peek: (
+            if num_bits >= 8 {
+                value
+            } else {
+                value & !(0xffu8 >> num_bits)
+            },
+            num_bits,
+        ),
+        stream_position,
+        overhang,
+        eof,
+    }
+}
+
+/// scan data of length bytes with random bytes that aren't 0xff, with the sequence at offset
+#[cfg(test)]
+fn scan_data_with(
+    rng: &mut rand::rngs::StdRng,
+    length: usize,
+    offset: usize,
+    sequence: &[u8],
+) -> Vec<u8> {
+    use rand::Rng;
+
+    let mut data: Vec<u8> = (0..length).map(|_| rng.gen_range(0..0xff)).collect();
+    data.splice(offset..offset, sequence.iter().copied());
+    data
+}
+
+/// reading words gives the same bits, stream positions, overhangs and errors as reading a byte at a time, with
+/// escaped 0xffs, RST markers, segments, invalid markers and a 0xff at the end of the data at every alignment to
+/// the words, and leaves the underlying reader at the same position
+#[test]
+fn matches_byte_at_a_time_reader() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([2u8; 32]);
+
+    let sequences: [&[u8]; 7] = [
+        &[0xff, 0x00],
+        &[0xff, 0x00, 0xff, 0x00, 0xff, 0x00],
+        &[0xff, 0xd0],
+        &[0xff, 0xd3, 0xff, 0xd4],
+        &[0xff, 0xfe, 0x00, 0x03, 0x41, 0xff, 0xd0],
+        &[0xff, 0xd9],
+        &[0xff],
+    ];
+
+    for sequence in sequences {
+        for offset in 0..24 {
+            for trial in 0..20 {
+                let mut data = scan_data_with(&mut rng, 40, offset, sequence);
+                if trial % 4 == 0 {
+                    // a 0xff at the end of the data is taken as an escaped one
+                    data.push(0xff);
+                }
+
+                let mut fast = BitReader::new(Cursor::new(&data));
+                let mut reference = ByteAtATimeReader::new(Cursor::new(&data));
+                let (mut fast_pad_bit, mut reference_pad_bit) = (None, None);
+
+                for _ in 0..200 {
+                    let op = rng.gen_range(0..16);
+                    let bits = rng.gen_range(0..=16);
+
+                    let (fast_result, reference_result) = match op {
+                        0 => (
+                            format!("{0:?}", fast.fill_register(8).map_err(|e| e.to_string())),
+                            format!(
+                                "{0:?}",
+                                reference.fill_register(8).map_err(|e| e.to_string())
+                            ),
+                        ),
+                        1 => {
+                            let advance = bits.min(fast.peek().1);
+                            fast.advance(advance);
+                            reference.advance(advance);
+                            (String::new(), String::new())
+                        }
+                        2 => (
+                            format!(
+                                "{0:?}",
+                                fast.read_and_verify_fill_bits(&mut fast_pad_bit)
+                                    .map_err(|e| e.to_string())
+                            ),
+                            format!(
+                                "{0:?}",
+                                reference
+                                    .read_and_verify_fill_bits(&mut reference_pad_bit)
+                                    .map_err(|e| e.to_string())
+                            ),
+                        ),
+                        3 => (
+                            format!("{0:?}", fast.read_reset_codes().map_err(|e| e.to_string())),
+                            format!(
+                                "{0:?}",
+                                reference.read_reset_codes().map_err(|e| e.to_string())
+                            ),
+                        ),
+                        _ => (
+                            format!("{0:?}", fast.read(bits).map_err(|e| e.to_string())),
+                            format!("{0:?}", reference.read(bits).map_err(|e| e.to_string())),
+                        ),
+                    };
+
+                    let fast_state = reader_observation(
+                        fast_result,
+                        fast.peek(),
+                        fast.get_stream_position(),
+                        fast.overhang(),
+                        fast.is_eof(),
+                    );
+                    let reference_state = reader_observation(
+                        reference_result,
+                        reference.peek(),
+                        reference.get_stream_position(),
+                        reference.overhang(),
+                        reference.is_eof(),
+                    );
+                    assert_eq!(fast_state, reference_state, "data {0:x?}", data);
+
+                    if fast_state.result.starts_with("Err") {
+                        break;
+                    }
+                }
+
+                fast.return_read_ahead().unwrap();
+                assert_eq!(fast.inner.position(), reference.inner.position());
+            }
+        }
+    }
+}
+
+/// what the reader tells about itself after an operation, see reader_observation
+#[cfg(test)]
+fn observe<R: Read>(reader: &mut BitReader<R>, result: String) -> ReaderObservation {
+    reader_observation(
+        result,
+        reader.peek(),
+        reader.get_stream_position(),
+        reader.overhang(),
+        reader.is_eof(),
+    )
+}
+
+/// peeking leaves the reader as it was and gives the bits that are read next, and after going back to a checkpoint
+/// the operations since then give the same results and leave the reader in the same states, with escaped 0xffs,
+/// RST markers, segments, invalid markers and the end of the data at every alignment to the words
+#[test]
+fn peek_and_restore_replay_reads() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([5u8; 32]);
+
+    let sequences: [&[u8]; 6] = [
+        &[0xff, 0x00],
+        &[0xff, 0x00, 0xff, 0x00, 0xff, 0x00],
+        &[0xff, 0xd0],
+        &[0xff, 0xd3, 0xff, 0xd4],
+        &[0xff, 0xfe, 0x00, 0x03, 0x41, 0xff, 0xd0],
+        &[0xff, 0xd9],
+    ];
+
+    for sequence in sequences {
+        for offset in 0..24 {
+            for trial in 0..20 {
+                let mut data = scan_data_with(&mut rng, 40, offset, sequence);
+                if trial % 4 == 0 {
+                    data.push(0xff);
+                }
+
+                let mut reader = BitReader::new(Cursor::new(&data));
+
+                // the checkpoint with what the reader told about itself then, and the operations since then
+                let mut checkpoint = None;
+                let mut since_checkpoint = Vec::new();
+
+                for _ in 0..200 {
+                    let op = rng.gen_range(0..16);
+                    let bits = rng.gen_range(0..=16);
+
+                    match op {
+                        0 => {
+                            checkpoint =
+                                Some((reader.checkpoint(), observe(&mut reader, String::new())));
+                            since_checkpoint.clear();
+                            continue;
+                        }
+                        1 => {
+                            if let Some((state, observation)) = &checkpoint {
+                                reader.restore(state).unwrap();
+                                assert_eq!(observe(&mut reader, String::new()), *observation);
+
+                                for (op, bits, observation) in &since_checkpoint {
+                                    let result = apply_op(&mut reader, *op, *bits);
+                                    assert_eq!(observe(&mut reader, result), *observation);
+                                }
+                            }
+                            continue;
+                        }
+                        2 => {
+                            let peek_bits = rng.gen_range(0..=32);
+                            let before = observe(&mut reader, String::new());
+                            let peeked = reader.peek_bits(peek_bits);
+                            assert_eq!(observe(&mut reader, String::new()), before);
+
+                            let peeked = match peeked {
+                                Ok(peeked) => peeked,
+                                Err(_) => break,
+                            };
+                            if peek_bits <= 16 {
+                                assert_eq!(
+                                    reader.read(peek_bits).unwrap(),
+                                    peeked as u16,
+                                    "data {0:x?}",
+                                    data
+                                );
+                            }
+                            since_checkpoint.clear();
+                            checkpoint = None;
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    let result = apply_op(&mut reader, op, bits);
+                    let observation = observe(&mut reader, result);
+                    if observation.result.starts_with("Err") {
+                        break;
+                    }
+                    since_checkpoint.push((op, bits, observation));
+                }
+            }
+        }
+    }
+}
+
+/// reads bits or the RST markers, as the operations of peek_and_restore_replay_reads
+#[cfg(test)]
+fn apply_op<R: Read>(reader: &mut BitReader<R>, op: u32, bits: u8) -> String {
+    if op == 3 {
+        format!(
+            "{0:?}",
+            reader.read_reset_codes().map_err(|e| e.to_string())
+        )
+    } else {
+        format!("{0:?}", reader.read(bits).map_err(|e| e.to_string()))
+    }
+}
+
+/// going back to a checkpoint whose data is no longer in the buffer reads it again from the underlying reader,
+/// and returning the read ahead after that leaves the underlying reader after the last byte that was read
+#[test]
+fn restore_across_buffer_refills() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([6u8; 32]);
+
+    let mut data = Vec::new();
+    while data.len() < BUFFER_SIZE * 4 {
+        let b = rng.gen::<u8>();
+        data.push(b);
+        if b == 0xff {
+            data.push(0);
+        }
+    }
+
+    let mut reader = BitReader::new(Cursor::new(&data));
+    let mut reference = BitReader::new(Cursor::new(&data));
+
+    for _ in 0..20 {
+        let skip = rng.gen_range(0..BUFFER_SIZE);
+        for _ in 0..skip {
+            reader.read(7).unwrap();
+            reference.read(7).unwrap();
+        }
+
+        let state = reader.checkpoint();
+        let reads: Vec<u8> = (0..rng.gen_range(0..BUFFER_SIZE * 2))
+            .map(|_| rng.gen_range(1..=16))
+            .collect();
+        let values: Vec<u16> = reads.iter().map(|b| reader.read(*b).unwrap()).collect();
+
+        reader.restore(&state).unwrap();
+        for (bits, value) in reads.iter().zip(values.iter()) {
+            assert_eq!(reader.read(*bits).unwrap(), *value);
+            assert_eq!(reference.read(*bits).unwrap(), *value);
+        }
+        assert_eq!(
+            observe(&mut reader, String::new()),
+            observe(&mut reference, String::new())
+        );
+
+        if reader.is_eof() {
+            break;
+        }
+    }
+
+    reader.return_read_ahead().unwrap();
+    reference.return_read_ahead().unwrap();
+    assert_eq!(reader.inner.position(), reference.inner.position());
+}
@@ -6,6 +6,10 @@
 
 use std::io::Write;
 
+/// the most bits that BitWriter::write_bits64 takes at a time, the 64 bits of the register less the 7 bits of a
+/// partial byte, which is plenty for a Huffman code with the bits that follow it
+pub const MAX_WRITE_BITS: u8 = 57;
+
 pub struct BitWriter {
     data_buffer: Vec<u8>,
     fill_register: u64,
@@ -44,27 +48,60 @@ impl BitWriter {
         self.current_bit = tmp_current_bit;
     }
 
+    /// appends the 8 bytes of fill with a zero after each 0xff. The bytes are escaped into a staging buffer
+    /// without branching on them, since words with a 0xff are common enough in high entropy data that going
+    /// through them a byte at a time costs more than the check of the whole word saves.
+    #[cold]
+    #[inline(never)]
+    fn write_escaped_word(&mut self, fill: u64) {
+        // the zero after a 0xff is already there, since the next byte goes after it
+        let mut staging = [0u8; 16];
+        let mut len = 0;
+        for b in fill.to_be_bytes() {
+            staging[len] = b;
+            len += 1 + usize::from(b == 0xff);
+        }
+
+        self.data_buffer.extend_from_slice(&staging[..len]);
+    }
+
     #[inline(always)]
-    pub fn write(&mut self, mut val: u32, mut new_bits: u32) {
+    pub fn write(&mut self, val: u32, new_bits: u32) {
+        debug_assert!(new_bits <= 32);
+
+        self.write_bits64(u64::from(val), new_bits as u8);
+    }
+
+    /// writes the low count bits of value, most significant first, which lets a Huffman code and the bits that
+    /// follow it go out in one call. count can be up to MAX_WRITE_BITS.
+    #[inline(always)]
+    pub fn write_bits64(&mut self, mut val: u64, count: u8) {
         debug_assert!(
-            val < (1 << new_bits),
+            count <= MAX_WRITE_BITS,
+            "at most {0} bits can be written at a time",
+            MAX_WRITE_BITS
+        );
+        debug_assert!(
+            val < (1 << count),
             "value {0} should fit into the number of {1} bits provided",
             val,
-            new_bits
+            count
         );
 
+        let mut new_bits = u32::from(count);
+
         // first see if everything fits in the current register
         if new_bits <= self.current_bit {
-            self.fill_register |= (val as u64).wrapping_shl(self.current_bit - new_bits); // support corner case where new_bits is zero, we don't want to panic
+            self.fill_register |= val.wrapping_shl(self.current_bit - new_bits); // support corner case where new_bits is zero, we don't want to panic
             self.current_bit = self.current_bit - new_bits;
         } else {
             // if not, fill up the register so to the 64 bit boundary we can flush it hopefully without any 0xff bytes
-            let fill = self.fill_register | (val as u64).wrapping_shr(new_bits - self.current_bit);
+            let fill = self.fill_register | val.wrapping_shr(new_bits - self.current_bit);
 
             new_bits -= self.current_bit;
             val &= (1 << new_bits) - 1;
 
-            // flush bytes slowly if we have any 0xff bytes or if we are about to overflow the buffer
+            // escape the word if we have any 0xff bytes or if we are about to overflow the buffer
             // (overflow check matches implementation in RawVec so that the optimizer can remove the buffer growing code)
             if (fill & 0x8080808080808080 & !fill.wrapping_add(0x0101010101010101)) != 0
                 || self
@@ -73,23 +110,20 @@ impl BitWriter {
                     .wrapping_sub(self.data_buffer.len())
                     < 8
             {
-                self.fill_register = fill;
-                self.current_bit = 0;
-                self.flush_bytes_slowly();
+                self.write_escaped_word(fill);
             } else {
                 self.data_buffer.extend_from_slice(&fill.to_be_bytes());
             }
-            self.fill_register = (val as u64).wrapping_shl(64 - new_bits); // support corner case where new_bits is zero, we don't want to panic
+            self.fill_register = val.wrapping_shl(64 - new_bits); // support corner case where new_bits is zero, we don't want to panic
             self.current_bit = 64 - new_bits;
         }
     }
 
+    /// pads out the current byte using the low bits of fillbit (most significant first), which
+    /// matches how the fill bits were captured by BitReader::read_and_verify_fill_bits
     pub fn pad(&mut self, fillbit: u8) {
-        let mut offset = 1;
-        while (self.current_bit & 7) != 0 {
-            self.write(if (fillbit & offset) != 0 { 1 } else { 0 }, 1);
-            offset <<= 1;
-        }
+        let num_bits = self.current_bit & 7;
+        self.write(u32::from(fillbit) & ((1 << num_bits) - 1), num_bits);
 
         self.flush_bytes_slowly();
 
@@ -111,6 +145,20 @@ impl BitWriter {
         Ok(())
     }
 
+    /// flushes the escaped data buffer once it has grown past the given size, so that
+    /// the output is written out in reasonably sized chunks rather than for every row
+    pub fn flush_with_escape_if_over<W: Write>(
+        &mut self,
+        w: &mut W,
+        threshold: usize,
+    ) -> anyhow::Result<()> {
+        if self.data_buffer.len() >= threshold {
+            self.flush_with_escape(w)?;
+        }
+
+        Ok(())
+    }
+
     pub fn reset_from_overhang_byte_and_num_bits(&mut self, overhang_byte: u8, num_bits: u32) {
         self.data_buffer.clear();
 
@@ -120,6 +168,17 @@ impl BitWriter {
         self.current_bit = 64 - num_bits;
     }
 
+    /// returns the partially written byte (with the unwritten bits cleared) and the number of bits in it, which
+    /// is what reset_from_overhang_byte_and_num_bits needs to continue writing from here. Only valid after a flush.
+    pub fn overhang(&self) -> (u8, u8) {
+        debug_assert!(self.current_bit > 56, "flush before getting the overhang");
+
+        (
+            (self.fill_register >> 56) as u8,
+            (64 - self.current_bit) as u8,
+        )
+    }
+
     pub fn has_no_remainder(&self) -> bool {
         return self.current_bit == 64;
     }
@@ -234,3 +293,201 @@ fn roundtrip_randombits() {
         r.read_and_verify_fill_bits(&mut pad).unwrap();
     }
 }
+
+/// straightforward writer that emits and escapes one byte at a time, used as a reference
+/// for checking the output and performance of the 64 bit accumulator in BitWriter
+#[cfg(test)]
+struct ByteAtATimeWriter {
+    data_buffer: Vec<u8>,
+    fill_register: u32,
+    num_bits: u32,
+}
+
+#[cfg(test)]
+impl ByteAtATimeWriter {
+    fn new() -> Self {
+        ByteAtATimeWriter {
+            data_buffer: Vec::with_capacity(65536),
+            fill_register: 0,
+            num_bits: 0,
+        }
+    }
+
+    fn write(&mut self, val: u32, new_bits: u32) {
+        for i in (0..new_bits).rev() {
+            self.fill_register = (self.fill_register << 1) | ((val >> i) & 1);
+            self.num_bits += 1;
+
+            if self.num_bits == 8 {
+                let b = self.fill_register as u8;
+                self.data_buffer.push(b);
+                if b == 0xff {
+                    self.data_buffer.push(0);
+                }
+
+                self.fill_register = 0;
+                self.num_bits = 0;
+            }
+        }
+    }
+}
+
+/// generates symbols that look like the output of a high entropy image, where most
+/// of the bits are random and 0xff bytes occur frequently
+#[cfg(test)]
+fn high_entropy_symbols(count: usize) -> Vec<(u32, u32)> {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([1u8; 32]);
+
+    (0..count)
+        .map(|_| {
+            // huffman code combined with the coefficient bits, as written by write_coef
+            let bits = rng.gen_range(2..=27);
+            (rng.gen_range(0..(1u32 << bits)), bits)
+        })
+        .collect()
+}
+
+/// the accumulator must produce exactly the same escaped bytes as writing a byte at a time
+#[test]
+fn matches_byte_at_a_time_writer() {
+    let symbols = high_entropy_symbols(100000);
+
+    let mut fast = BitWriter::new();
+    let mut reference = ByteAtATimeWriter::new();
+
+    for &(val, bits) in &symbols {
+        fast.write(val, bits);
+        reference.write(val, bits);
+    }
+
+    // pad both out with one bits
+    let pad_bits = fast.current_bit & 7;
+    fast.pad(0xff);
+    reference.write((1 << pad_bits) - 1, pad_bits);
+
+    let mut w = Vec::new();
+    fast.flush_with_escape(&mut w).unwrap();
+
+    assert_eq!(w.len(), reference.data_buffer.len());
+    assert!(w[..] == reference.data_buffer[..]);
+}
+
+/// words with any number of 0xff bytes in any position are escaped like a byte at a time, including words that are
+/// all 0xff and 0xff bytes that straddle two words
+#[test]
+fn escaped_words_match_byte_at_a_time_writer() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([2u8; 32]);
+
+    for ff_chance in [0, 1, 4, 8] {
+        let mut fast = BitWriter::new();
+        let mut reference = ByteAtATimeWriter::new();
+
+        for _ in 0..10000 {
+            // mostly bytes of ones, written at any alignment
+            let bits = rng.gen_range(1..=24);
+            let val = if rng.gen_range(0..8) < ff_chance {
+                (1u32 << bits) - 1
+            } else {
+                rng.gen_range(0..(1u32 << bits))
+            };
+
+            fast.write(val, bits);
+            reference.write(val, bits);
+        }
+
+        let pad_bits = fast.current_bit & 7;
+        fast.pad(0xff);
+        reference.write((1 << pad_bits) - 1, pad_bits);
+
+        let mut w = Vec::new();
+        fast.flush_with_escape(&mut w).unwrap();
+
+        assert!(w[..] == reference.data_buffer[..]);
+    }
+}
+
+/// write_bits64 with no bits, one bit and the most bits at a time, starting at every alignment in the register,
+/// writes the same bits as a byte at a time
+#[test]
+fn write_bits64_boundary_counts() {
+    for count in [0, 1, MAX_WRITE_BITS] {
+        for offset in 0..64 {
+            let val = 0x0123_4567_89ab_cdef & ((1u64 << count) - 1);
+
+            let mut fast = BitWriter::new();
+            let mut reference = ByteAtATimeWriter::new();
+
+            // get to the offset with ones so that 0xff bytes are escaped across the boundary
+            for _ in 0..offset {
+                fast.write(1, 1);
+                reference.write(1, 1);
+            }
+            fast.write_bits64(val, count);
+            fast.write_bits64(val, count);
+            for _ in 0..2 {
+                reference.write((val >> 32) as u32, u32::from(count.max(32) - 32));
+                reference.write(val as u32, u32::from(count.min(32)));
+            }
+
+            let pad_bits = fast.current_bit & 7;
+            fast.pad(0xff);
+            reference.write((1 << pad_bits) - 1, pad_bits);
+
+            let mut w = Vec::new();
+            fast.flush_with_escape(&mut w).unwrap();
+
+            assert!(
+                w[..] == reference.data_buffer[..],
+                "count {0} offset {1}",
+                count,
+                offset
+            );
+        }
+    }
+}
+
+/// a Huffman code and the bits after it written with one call to write_bits64 come out the same as with a call
+/// to write for each of them
+#[test]
+fn write_bits64_matches_two_calls() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([3u8; 32]);
+
+    let mut combined = BitWriter::new();
+    let mut separate = BitWriter::new();
+
+    for _ in 0..100000 {
+        let code_len = rng.gen_range(1..=16);
+        let code = rng.gen_range(0..(1u32 << code_len));
+        let extra_len = rng.gen_range(0..=15);
+        let extra = rng.gen_range(0..(1u32 << extra_len));
+
+        combined.write_bits64(
+            (u64::from(code) << extra_len) | u64::from(extra),
+            (code_len + extra_len) as u8,
+        );
+        separate.write(code, code_len);
+        separate.write(extra, extra_len);
+    }
+
+    combined.pad(0);
+    separate.pad(0);
+
+    let mut w_combined = Vec::new();
+    combined.flush_with_escape(&mut w_combined).unwrap();
+    let mut w_separate = Vec::new();
+    separate.flush_with_escape(&mut w_separate).unwrap();
+
+    assert!(w_combined == w_separate);
+}
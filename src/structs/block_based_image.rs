@@ -4,10 +4,14 @@
  *  This software incorporates material from third parties. See NOTICE.txt for details.
  *--------------------------------------------------------------------------------------------*/
 
+use anyhow::Result;
 use log::info;
 
 use crate::consts::{ALIGNED_BLOCK_INDEX_DC_INDEX, RASTER_TO_ALIGNED, ZIGZAG_TO_ALIGNED};
+use crate::helpers::err_exit_code;
+use crate::lepton_error::ExitCode;
 
+use super::model::MAX_COEFFICIENT;
 use super::{block_context::BlockContext, jpeg_header::JPegHeader};
 
 /// holds the 8x8 blocks for a given component. Since we do multithreaded encoding,
@@ -44,10 +48,7 @@ impl BlockBasedImage {
         )
         .unwrap();
 
-        let dpos_offset = i32::try_from(
-            i64::from(max_size) * i64::from(luma_y_start) / i64::from(jpeg_header.cmp_info[0].bcv),
-        )
-        .unwrap();
+        let dpos_offset = Self::dpos_of_luma_row(jpeg_header, component, luma_y_start);
 
         return BlockBasedImage {
             block_width: block_width,
@@ -57,45 +58,95 @@ impl BlockBasedImage {
         };
     }
 
-    /// merges a bunch of block images generated by different threads into a single one used by progressive decoding
-    pub fn merge(images: &mut Vec<Vec<BlockBasedImage>>, index: usize) -> Self {
+    /// the first block of component in the row of luma blocks luma_y, which is where the image of a thread segment
+    /// that starts at that row starts
+    pub fn dpos_of_luma_row(jpeg_header: &JPegHeader, component: usize, luma_y: i32) -> i32 {
+        let info = &jpeg_header.cmp_info[component];
+
+        i32::try_from(
+            i64::from(info.bch) * i64::from(info.bcv) * i64::from(luma_y)
+                / i64::from(jpeg_header.cmp_info[0].bcv),
+        )
+        .unwrap()
+    }
+
+    /// bytes that the blocks of all the rows of a component take
+    pub fn memory_for(jpeg_header: &JPegHeader, component: usize) -> u64 {
+        let info = &jpeg_header.cmp_info[component];
+
+        u64::try_from(i64::from(info.bch) * i64::from(info.bcv)).unwrap_or(0)
+            * std::mem::size_of::<AlignedBlock>() as u64
+    }
+
+    /// merges a bunch of block images generated by different threads into a single one used by progressive decoding.
+    /// The images have to follow each other without gaps and have the same dimensions.
+    pub fn merge(images: &mut Vec<Vec<BlockBasedImage>>, index: usize) -> Result<Self> {
+        let Some(first) = images.first() else {
+            return err_exit_code(ExitCode::StreamInconsistent, "no images to merge");
+        };
+
         // figure out the total size of all the blocks so we can set the capacity correctly
         let total_size = images.iter().map(|x| x[index].image.len()).sum();
 
-        let mut contents = Vec::with_capacity(total_size);
-        let mut block_width = None;
-        let mut original_height = None;
+        let mut merged = BlockBasedImage {
+            block_width: first[index].block_width,
+            original_height: first[index].original_height,
+            image: Vec::with_capacity(total_size),
+            dpos_offset: 0,
+        };
 
         for v in images {
-            assert!(
-                v[index].dpos_offset == contents.len() as i32,
-                "previous content should match new content"
-            );
+            merged.append(&mut v[index], index)?;
+        }
 
-            if let Some(w) = block_width {
-                assert_eq!(w, v[index].block_width, "all block_width must match")
-            } else {
-                block_width = Some(v[index].block_width);
-            }
+        Ok(merged)
+    }
 
-            if let Some(w) = original_height {
-                assert_eq!(
-                    w, v[index].original_height,
-                    "all original_height must match"
+    /// moves the blocks of other, the image of component that a thread segment decoded, to the end of this one, so
+    /// that the image of the entire component can be put together as the segments finish. other has to start where
+    /// this one ends and have the same dimensions, and its memory is freed.
+    pub fn append(&mut self, other: &mut BlockBasedImage, component: usize) -> Result<()> {
+        let end = self.dpos_offset + self.image.len() as i32;
+        if other.dpos_offset != end {
+            return err_exit_code(
+                ExitCode::StreamInconsistent,
+                format!(
+                    "image for component {0} starts at block {1} rather than at {2} where the previous one ended",
+                    component, other.dpos_offset, end
                 )
-            } else {
-                original_height = Some(v[index].original_height);
-            }
+                .as_str(),
+            );
+        }
 
-            contents.append(&mut v[index].image);
+        if other.block_width != self.block_width || other.original_height != self.original_height {
+            return err_exit_code(
+                ExitCode::StreamInconsistent,
+                format!(
+                    "images for component {0} have different dimensions",
+                    component
+                )
+                .as_str(),
+            );
         }
 
-        return BlockBasedImage {
-            block_width: block_width.unwrap(),
-            original_height: original_height.unwrap(),
-            image: contents,
+        self.image.extend(std::mem::take(&mut other.image));
+
+        Ok(())
+    }
+
+    /// an image of the blocks in raster order, block_width blocks to a row, which doesn't belong to any JPEG
+    pub fn from_blocks(block_width: i32, blocks: &[&AlignedBlock]) -> Self {
+        BlockBasedImage {
+            block_width,
+            original_height: blocks.len() as i32 / block_width,
             dpos_offset: 0,
-        };
+            image: blocks
+                .iter()
+                .map(|b| AlignedBlock {
+                    raw_data: b.raw_data,
+                })
+                .collect(),
+        }
     }
 
     #[allow(dead_code)]
@@ -222,6 +273,22 @@ impl AlignedBlock {
         return num_non_zeros7x7;
     }
 
+    /// the raster index and value of the first coefficient with a magnitude that the model can't code, see
+    /// MAX_COEFFICIENT
+    pub fn find_coefficient_out_of_range(&self) -> Option<(usize, i16)> {
+        if self
+            .raw_data
+            .iter()
+            .all(|c| i32::from(c.unsigned_abs()) <= MAX_COEFFICIENT)
+        {
+            return None;
+        }
+
+        (0..64)
+            .map(|index| (index, self.get_coefficient_raster(index)))
+            .find(|(_, c)| i32::from(c.unsigned_abs()) > MAX_COEFFICIENT)
+    }
+
     pub fn get_coefficient(&self, index: usize) -> i16 {
         return self.raw_data[index];
     }
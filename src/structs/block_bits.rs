@@ -0,0 +1,245 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use default_boxed::DefaultBoxed;
+
+use crate::enabled_features::EnabledFeatures;
+use crate::helpers::*;
+use crate::structs::block_based_image::{AlignedBlock, BlockBasedImage};
+use crate::structs::idct::run_idct;
+use crate::structs::lepton_encoder::{lepton_estimate_block_bits, serialize_tokens};
+use crate::structs::lepton_format::{get_quantization_tables, read_jpeg_rows};
+use crate::structs::model::Model;
+use crate::structs::neighbor_summary::NeighborSummary;
+use crate::structs::probability_tables::ProbabilityTables;
+use crate::structs::probability_tables_set::ProbabilityTablesSet;
+use crate::structs::quantization_tables::QuantizationTables;
+use crate::structs::vpx_bool_writer::VPXBoolWriter;
+
+/// the bits that each block of a component costs to encode, as a heat map of the image in blocks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlockBitsMap {
+    pub component: usize,
+    /// size of the component in blocks
+    pub width: u32,
+    pub height: u32,
+    /// the bits of each block in raster order, zero for blocks that aren't coded
+    pub bits: Vec<f32>,
+}
+
+impl BlockBitsMap {
+    /// the bits of the block at column x and row y
+    pub fn get(&self, x: u32, y: u32) -> f32 {
+        self.bits[(y * self.width + x) as usize]
+    }
+
+    pub fn total_bits(&self) -> f64 {
+        self.bits.iter().map(|b| f64::from(*b)).sum()
+    }
+}
+
+/// the blocks that the contexts of a block are taken from, None where the block is at the edge of the image
+#[derive(Default)]
+pub struct BlockNeighbors<'a> {
+    pub left: Option<&'a AlignedBlock>,
+    pub above: Option<&'a AlignedBlock>,
+    pub above_left: Option<&'a AlignedBlock>,
+}
+
+/// estimates the bits that each block of jpeg costs when the whole image is coded as a single thread segment
+/// with the default features. Nothing is coded, but the bits are what the coder would spend on the decisions of
+/// each block, so they add up to the size of the arithmetic coded data except for the rounding of the last byte.
+pub fn estimate_block_bits_wrapper(jpeg: &[u8]) -> Result<Vec<BlockBitsMap>> {
+    let enabled_features = EnabledFeatures::default();
+
+    let (lp, image_data, _row_handoffs) =
+        read_jpeg_rows(&mut Cursor::new(jpeg), &enabled_features, |_jh| {}).context(here!())?;
+
+    let pts = ProbabilityTablesSet::new(
+        enabled_features.get_quantized_dc_prediction(),
+        enabled_features.get_fast_dc_prediction(),
+    );
+    let quantization_tables = get_quantization_tables(&lp.jpeg_header).context(here!())?;
+
+    let block_bits = lepton_estimate_block_bits(
+        &pts,
+        &quantization_tables,
+        &image_data,
+        &lp.truncate_components,
+        &mut Model::default_boxed(),
+    )
+    .context(here!())?;
+
+    let mut maps: Vec<BlockBitsMap> = image_data
+        .iter()
+        .enumerate()
+        .map(|(component, image)| {
+            let width = image.get_block_width() as u32;
+            let height = image.get_original_height() as u32;
+            BlockBitsMap {
+                component,
+                width,
+                height,
+                bits: vec![0.0; (width * height) as usize],
+            }
+        })
+        .collect();
+
+    for b in block_bits {
+        maps[b.component].bits[b.dpos as usize] = b.bits;
+    }
+
+    Ok(maps)
+}
+
+/// estimates the bits that block of component costs when it is coded with model, with the same contexts that the
+/// encoder would derive from its neighbors and the default features, but without coding anything or changing
+/// model. A coefficient that is out of range fails with CoefficientOutOfRange, with dpos 0 for the block above
+/// left, 1 for the one above, 2 for the one on the left and 3 for the block itself.
+// Model isn't part of the public API, so this can't be exported yet, and for now only the tests call it
+#[allow(dead_code)]
+pub fn estimate_block_bits(
+    model: &Model,
+    qt: &QuantizationTables,
+    component: usize,
+    neighbors: &BlockNeighbors,
+    block: &AlignedBlock,
+) -> Result<f32> {
+    // the branches are updated as the block is coded, so it is coded with a copy of the model
+    let mut estimate_model = Model::default_boxed();
+    #[cfg(feature = "experimental-tuning")]
+    estimate_model.set_tuning(model.get_tuning());
+    estimate_model.restore(&model.snapshot()).context(here!())?;
+
+    // a two block wide image with the block in the lower right corner, so that the encoder finds the
+    // neighbors where it expects them
+    let empty = AlignedBlock::default();
+    let image = BlockBasedImage::from_blocks(
+        2,
+        &[
+            neighbors.above_left.unwrap_or(&empty),
+            neighbors.above.unwrap_or(&empty),
+            neighbors.left.unwrap_or(&empty),
+            block,
+        ],
+    );
+
+    // the encoder only takes a block as a neighbor after it has checked its coefficients
+    for dpos in 0..3 {
+        if let Some((index, value)) = image.get_block(dpos).find_coefficient_out_of_range() {
+            return err_coefficient_out_of_range(component, dpos, index, value.into());
+        }
+    }
+
+    // what the encoder would have left behind about the neighbors after coding them
+    let q = qt.get_quantization_table();
+    let mut num_non_zeros = [NeighborSummary::new(); 4];
+    for (dpos, summary) in num_non_zeros.iter_mut().enumerate() {
+        let neighbor = image.get_block(dpos as i32);
+        summary.set_num_non_zeros(neighbor.get_count_of_non_zeros_7x7());
+
+        if dpos != 3 {
+            let mut pixels_sans_dc = [0i16; 64];
+            run_idct::<true>(neighbor, q, &mut pixels_sans_dc);
+            summary.set_horizontal(&pixels_sans_dc, q, neighbor.get_dc());
+            summary.set_vertical(&pixels_sans_dc, q, neighbor.get_dc());
+        }
+    }
+
+    let mut context = image.off_y(1);
+    context.next(true);
+
+    let pt = ProbabilityTables::new(
+        component,
+        neighbors.left.is_some(),
+        neighbors.above.is_some(),
+        false,
+        false,
+    );
+
+    let mut bool_writer = VPXBoolWriter::new_estimator()?;
+    #[cfg(feature = "experimental-tuning")]
+    bool_writer.set_tuning(model.get_tuning());
+    bool_writer.start_block(component, context.get_here_index());
+
+    if pt.is_all_present() {
+        serialize_tokens::<_, Model, true, true>(
+            &mut context,
+            qt,
+            &pt,
+            &mut estimate_model,
+            &image,
+            &mut num_non_zeros,
+            &mut bool_writer,
+        )
+        .context(here!())?;
+    } else {
+        serialize_tokens::<_, Model, false, true>(
+            &mut context,
+            qt,
+            &pt,
+            &mut estimate_model,
+            &image,
+            &mut num_non_zeros,
+            &mut bool_writer,
+        )
+        .context(here!())?;
+    }
+
+    Ok(bool_writer.take_block_bits().iter().map(|b| b.bits).sum())
+}
+
+/// the first block that is coded gets a fresh model and no neighbors, so estimating it on its own has to give the
+/// same bits as the heat map of the whole image
+#[test]
+fn first_block_matches_heat_map() {
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("tiny.jpg"),
+    )
+    .unwrap();
+
+    let maps = estimate_block_bits_wrapper(&jpeg).unwrap();
+
+    let (lp, image_data, _) = read_jpeg_rows(
+        &mut Cursor::new(&jpeg),
+        &EnabledFeatures::default(),
+        |_jh| {},
+    )
+    .unwrap();
+    let quantization_tables = get_quantization_tables(&lp.jpeg_header).unwrap();
+
+    let first = lepton_estimate_block_bits(
+        &ProbabilityTablesSet::new(false, false),
+        &quantization_tables,
+        &image_data,
+        &lp.truncate_components,
+        &mut Model::default_boxed(),
+    )
+    .unwrap()[0];
+    assert_eq!(first.dpos, 0);
+
+    let model = Model::default_boxed();
+    let bits = estimate_block_bits(
+        &model,
+        &quantization_tables[first.component],
+        first.component,
+        &BlockNeighbors::default(),
+        image_data[first.component].get_block(0),
+    )
+    .unwrap();
+
+    assert!(bits > 0.0);
+    assert_eq!(bits, first.bits);
+    assert_eq!(bits, maps[first.component].get(0, 0));
+
+    // the model that was passed in is unchanged
+    assert!(model.snapshot() == Model::default_boxed().snapshot());
+}
@@ -18,8 +18,7 @@ pub struct BlockContext {
 }
 
 impl BlockContext {
-    // for debugging
-    #[allow(dead_code)]
+    /// the dpos of the current block
     pub fn get_here_index(&self) -> i32 {
         self.cur_block_index
     }
@@ -99,8 +98,10 @@ impl BlockContext {
         return num_non_zeros[self.above_num_non_zero_index as usize].get_num_non_zeros();
     }
 
+    /// the first block of the image has nothing on the left, and reads its own summary instead of one before the
+    /// start, for callers that weigh out the left neighbor when it isn't present
     pub fn get_non_zeros_left(&self, num_non_zeros: &[NeighborSummary]) -> u8 {
-        return num_non_zeros[(self.cur_num_non_zeros_index - 1) as usize].get_num_non_zeros();
+        num_non_zeros[(self.cur_num_non_zeros_index - 1).max(0) as usize].get_num_non_zeros()
     }
 
     pub fn neighbor_context_here<'a>(
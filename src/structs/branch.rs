@@ -23,8 +23,14 @@
  executing any further logic.
 
 */
+#[cfg(feature = "experimental-tuning")]
+use crate::enabled_features::ModelTuning;
+
 pub struct Branch {
     counts: u16,
+    /// number of bits coded with the branch, see Model::branch_usage
+    #[cfg(feature = "stats")]
+    visits: u32,
 }
 
 impl Default for Branch {
@@ -52,7 +58,56 @@ static PROB_LOOKUP: [u8; 65536] = problookup();
 
 impl Branch {
     pub fn new() -> Self {
-        Branch { counts: 0x0101 }
+        Branch {
+            counts: 0x0101,
+            #[cfg(feature = "stats")]
+            visits: 0,
+        }
+    }
+
+    /// the packed true and false counts, which is the entire state of the branch
+    pub fn get_counts(&self) -> u16 {
+        self.counts
+    }
+
+    /// restores the state saved by get_counts. Returns false for values that no sequence of observations can
+    /// produce, since both counts start at one and only the all trues corner case has a count of zero.
+    pub fn set_counts(&mut self, counts: u16) -> bool {
+        if counts != 0x00ff && (counts & 0xff == 0 || counts >> 8 == 0) {
+            return false;
+        }
+
+        self.counts = counts;
+        true
+    }
+
+    /// a branch whose next decision is false with a probability of probability / 256, which the counts of some
+    /// branch give for every value, so that the bool coder can be tested with any probability
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn with_probability(probability: u8) -> Self {
+        let counts = if probability == 0 {
+            // the all trues corner case
+            0x00ff
+        } else {
+            (u16::from(probability) << 8) | (256 - u16::from(probability))
+        };
+
+        let mut branch = Branch::new();
+        branch.counts = counts;
+        debug_assert_eq!(branch.get_probability(), probability);
+        branch
+    }
+
+    /// number of bits that were coded with the branch
+    #[cfg(feature = "stats")]
+    pub fn get_visits(&self) -> u32 {
+        self.visits
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    pub fn record_visit(&mut self) {
+        self.visits += 1;
     }
 
     // used for debugging
@@ -73,6 +128,32 @@ impl Branch {
         PROB_LOOKUP[self.counts as usize]
     }
 
+    /// updates the counts with bit like record_and_update_true_obs and record_and_update_false_obs, but only
+    /// branches on the bit when its count is about to overflow, so the bits that are hard to predict don't cost
+    /// a misprediction
+    #[inline(always)]
+    pub fn record_and_update_obs(&mut self, bit: bool) {
+        // the true count is in the low byte and the false count in the high one, so the count of the bit and
+        // the increment are a shift away without branching on the bit
+        let shift = u32::from(!bit) * 8;
+        let count = (self.counts >> shift) & 0xff;
+
+        if count == 0xff || self.counts == 0x00ff {
+            self.record_and_update_obs_slow(bit);
+        } else {
+            self.counts += 1 << shift;
+        }
+    }
+
+    #[cold]
+    fn record_and_update_obs_slow(&mut self, bit: bool) {
+        if bit {
+            self.record_and_update_true_obs();
+        } else {
+            self.record_and_update_false_obs();
+        }
+    }
+
     #[inline(always)]
     pub fn record_and_update_true_obs(&mut self) {
         if (self.counts & 0xff) != 0xff {
@@ -92,6 +173,30 @@ impl Branch {
         }
     }
 
+    /// updates the counts with the values of tuning instead of the ones the format has always used. Unlike the
+    /// regular updates this has no corner cases, so it only codes the same way for values other than the
+    /// defaults.
+    #[cfg(feature = "experimental-tuning")]
+    #[inline(always)]
+    pub fn record_and_update_tuned_obs(&mut self, bit: bool, tuning: &ModelTuning) {
+        let mut zeros = u32::from(self.counts >> 8);
+        let mut ones = u32::from(self.counts & 0xff);
+
+        let count = if bit { ones } else { zeros };
+        if count >= u32::from(tuning.max_count) {
+            zeros = ((zeros + 1) >> tuning.adaptation_shift).max(1);
+            ones = ((ones + 1) >> tuning.adaptation_shift).max(1);
+        }
+
+        if bit {
+            ones += 1;
+        } else {
+            zeros += 1;
+        }
+
+        self.counts = ((zeros << 8) | ones) as u16;
+    }
+
     #[inline(always)]
     pub fn record_and_update_false_obs(&mut self) {
         if self.counts == 0x00ff {
@@ -171,7 +276,8 @@ fn test_all_probabilities() {
             continue;
         }
 
-        let mut new_f = Branch { counts: i as u16 };
+        let mut new_f = Branch::new();
+        new_f.counts = i;
 
         for _k in 0..10 {
             old_f.record_obs_and_update(false);
@@ -183,7 +289,8 @@ fn test_all_probabilities() {
             counts: [(i >> 8) as u8, i as u8],
             probability: 0,
         };
-        let mut new_t = Branch { counts: i as u16 };
+        let mut new_t = Branch::new();
+        new_t.counts = i;
 
         for _k in 0..10 {
             old_t.record_obs_and_update(true);
@@ -193,3 +300,102 @@ fn test_all_probabilities() {
         }
     }
 }
+
+/// the default initial probability starts the branches the way the format always has
+#[test]
+fn test_default_initial_counts() {
+    use crate::enabled_features::ModelTuning;
+
+    assert_eq!(
+        ModelTuning::default().initial_counts(),
+        Branch::new().get_counts()
+    );
+
+    let tuning = ModelTuning {
+        initial_probability: 192,
+        ..ModelTuning::default()
+    };
+    let mut b = Branch::new();
+    assert!(b.set_counts(tuning.initial_counts()));
+    assert_eq!(b.get_probability(), 192);
+    assert_eq!(b.get_counts(), 0x0301);
+}
+
+/// tuned updates keep the counts within max_count, and divide them once the observed one reaches it
+#[cfg(feature = "experimental-tuning")]
+#[test]
+fn test_tuned_updates() {
+    use crate::enabled_features::ModelTuning;
+
+    for adaptation_shift in 1..=7 {
+        for max_count in [2, 3, 16, 100, 255] {
+            let tuning = ModelTuning {
+                adaptation_shift,
+                max_count,
+                initial_probability: 128,
+            };
+
+            let mut b = Branch::new();
+            for i in 0..2000 {
+                b.record_and_update_tuned_obs(i % 7 != 0, &tuning);
+
+                let counts = b.get_counts();
+                assert!(counts >> 8 >= 1 && counts >> 8 <= u16::from(max_count));
+                assert!(counts & 0xff >= 1 && counts & 0xff <= u16::from(max_count));
+            }
+        }
+    }
+
+    let tuning = ModelTuning {
+        adaptation_shift: 2,
+        max_count: 8,
+        initial_probability: 128,
+    };
+    let mut b = Branch::new();
+    assert!(b.set_counts(0x0308));
+    b.record_and_update_tuned_obs(true, &tuning);
+    assert_eq!(b.get_counts(), 0x0103);
+    b.record_and_update_tuned_obs(false, &tuning);
+    assert_eq!(b.get_counts(), 0x0203);
+}
+
+/// updating with a bit gives the same counts as the updates for a true or a false, for all the counts a branch can
+/// have
+#[test]
+fn record_and_update_obs_matches() {
+    for i in 0u16..=65535 {
+        if !Branch::new().set_counts(i) {
+            continue;
+        }
+
+        for bit in [false, true] {
+            let mut branch = Branch::new();
+            branch.counts = i;
+            let mut reference = Branch::new();
+            reference.counts = i;
+
+            branch.record_and_update_obs(bit);
+            if bit {
+                reference.record_and_update_true_obs();
+            } else {
+                reference.record_and_update_false_obs();
+            }
+
+            assert_eq!(
+                branch.counts, reference.counts,
+                "counts {0:04x} bit {1}",
+                i, bit
+            );
+        }
+    }
+}
+
+/// every probability has counts that a branch can have
+#[test]
+fn with_probability_is_valid() {
+    for probability in 0..=255u8 {
+        let branch = Branch::with_probability(probability);
+        assert_eq!(branch.get_probability(), probability);
+        assert!(Branch::new().set_counts(branch.get_counts()));
+    }
+}
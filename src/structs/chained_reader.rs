@@ -0,0 +1,149 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::{Read, Result};
+
+/// reader over a sequence of slices as if they were one, so that data that arrives in pieces, like the chunks of
+/// a thread segment in the multiplexed data, can be decoded without copying it together first. A read only
+/// returns data from one slice, which the readers of the decoder handle like any other short read.
+pub struct ChainedReader<'a> {
+    /// what is left of the slice that is being read
+    current: &'a [u8],
+    /// the slices after it
+    rest: &'a [&'a [u8]],
+}
+
+impl<'a> ChainedReader<'a> {
+    pub fn new(slices: &'a [&'a [u8]]) -> Self {
+        ChainedReader {
+            current: &[],
+            rest: slices,
+        }
+    }
+
+    /// moves on to the next slice that isn't empty and reads from it, or returns zero at the end of the last one
+    #[cold]
+    #[inline(never)]
+    fn read_slow(&mut self, buf: &mut [u8]) -> Result<usize> {
+        while let Some((first, rest)) = self.rest.split_first() {
+            self.current = first;
+            self.rest = rest;
+
+            if !self.current.is_empty() {
+                return self.current.read(buf);
+            }
+        }
+
+        Ok(0)
+    }
+}
+
+impl Read for ChainedReader<'_> {
+    /// fast path for reads from the current slice, the slow path moves on to the next one
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if !self.current.is_empty() {
+            return self.current.read(buf);
+        }
+
+        self.read_slow(buf)
+    }
+}
+
+#[cfg(test)]
+fn random_split<'a>(rng: &mut impl rand::Rng, data: &'a [u8]) -> Vec<&'a [u8]> {
+    let mut slices = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() {
+        // empty slices too, which have to be skipped rather than taken as the end
+        let (first, r) = rest.split_at(rng.gen_range(0..=rest.len().min(300)));
+        slices.push(first);
+        rest = r;
+    }
+    slices.push(&[]);
+    slices
+}
+
+/// the slices read the same as the data they were split from, however the reads line up with them
+#[test]
+fn reads_slices_as_one() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([3u8; 32]);
+    let data: Vec<u8> = (0..10000).map(|_| rng.gen()).collect();
+
+    for _ in 0..20 {
+        let slices = random_split(&mut rng, &data);
+
+        let mut reader = ChainedReader::new(&slices);
+        let mut read = Vec::new();
+        loop {
+            let mut buf = [0u8; 97];
+            let n = reader.read(&mut buf[..rng.gen_range(1..=97)]).unwrap();
+            if n == 0 {
+                break;
+            }
+            read.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(read, data);
+
+        let mut read = Vec::new();
+        ChainedReader::new(&slices).read_to_end(&mut read).unwrap();
+        assert_eq!(read, data);
+    }
+
+    assert_eq!(ChainedReader::new(&[]).read(&mut [0u8; 4]).unwrap(), 0);
+    assert_eq!(
+        ChainedReader::new(&[&[], &[]]).read(&mut [0u8; 4]).unwrap(),
+        0
+    );
+}
+
+/// the bool reader and the bit reader decode the same from the slices as from the data they were split from
+#[test]
+fn readers_decode_slices_like_contiguous_data() {
+    use crate::metrics::ModelComponent;
+    use crate::structs::bit_reader::BitReader;
+    use crate::structs::branch::Branch;
+    use crate::structs::vpx_bool_reader::VPXBoolReader;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([4u8; 32]);
+    let data: Vec<u8> = (0..4096).map(|_| rng.gen()).collect();
+    let slices = random_split(&mut rng, &data);
+
+    let mut branches = [(); 2].map(|_| Branch::new());
+    let mut reference_branches = [(); 2].map(|_| Branch::new());
+    let mut reader = VPXBoolReader::new(ChainedReader::new(&slices)).unwrap();
+    let mut reference = VPXBoolReader::new(&data[..]).unwrap();
+    for i in 0..8192 {
+        assert_eq!(
+            reader
+                .get(&mut branches[i & 1], ModelComponent::Dummy)
+                .unwrap(),
+            reference
+                .get(&mut reference_branches[i & 1], ModelComponent::Dummy)
+                .unwrap()
+        );
+    }
+    assert_eq!(reader.position_bits(), reference.position_bits());
+
+    let mut reader = BitReader::new(ChainedReader::new(&slices));
+    let mut reference = BitReader::new(&data[..]);
+    for _ in 0..4000 {
+        let bits = rng.gen_range(1..=16);
+        let value = reader.read(bits);
+        let reference_value = reference.read(bits);
+        assert_eq!(value.is_ok(), reference_value.is_ok());
+        if let (Ok(value), Ok(reference_value)) = (value, reference_value) {
+            assert_eq!(value, reference_value);
+        }
+    }
+}
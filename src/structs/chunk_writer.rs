@@ -0,0 +1,99 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::{ErrorKind, Write};
+
+use anyhow::Result;
+
+/// writer that collects the output into chunks of a fixed size and hands each one to a callback as soon as it is full,
+/// so that the caller never needs a contiguous buffer for the entire file. The last partial chunk is handed over by finish.
+pub struct ChunkWriter<F> {
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    callback: F,
+
+    /// error returned by the callback, kept here since Write can only pass on an io::Error
+    error: Option<anyhow::Error>,
+}
+
+impl<F: FnMut(&[u8]) -> Result<()>> ChunkWriter<F> {
+    pub fn new(chunk_size: usize, callback: F) -> Self {
+        assert!(chunk_size > 0, "chunk size must be at least one byte");
+
+        ChunkWriter {
+            buffer: Vec::with_capacity(chunk_size),
+            chunk_size,
+            callback,
+            error: None,
+        }
+    }
+
+    /// hands whatever is left over to the callback (if anything)
+    pub fn finish(mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            (self.callback)(&self.buffer[..])?;
+        }
+
+        Ok(())
+    }
+
+    /// returns the error the callback failed with, if any
+    pub fn take_error(&mut self) -> Option<anyhow::Error> {
+        self.error.take()
+    }
+}
+
+impl<F: FnMut(&[u8]) -> Result<()>> Write for ChunkWriter<F> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.error.is_some() {
+            return Err(std::io::Error::new(
+                ErrorKind::Other,
+                "output callback already failed",
+            ));
+        }
+
+        let amount = std::cmp::min(buf.len(), self.chunk_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..amount]);
+
+        if self.buffer.len() == self.chunk_size {
+            if let Err(e) = (self.callback)(&self.buffer[..]) {
+                self.error = Some(e);
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "output callback failed",
+                ));
+            }
+
+            self.buffer.clear();
+        }
+
+        Ok(amount)
+    }
+
+    /// doesn't hand over the partial chunk so that all chunks except the last are exactly chunk_size
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn chunks_have_fixed_size() {
+    let mut chunks = Vec::new();
+
+    let mut writer = ChunkWriter::new(4, |c: &[u8]| {
+        chunks.push(c.to_vec());
+        Ok(())
+    });
+
+    writer.write_all(&[1, 2, 3]).unwrap();
+    writer.write_all(&[4, 5, 6, 7, 8, 9, 10]).unwrap();
+    writer.finish().unwrap();
+
+    assert_eq!(
+        chunks,
+        vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10]]
+    );
+}
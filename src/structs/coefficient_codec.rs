@@ -0,0 +1,362 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use anyhow::Result;
+use std::io::{Read, Write};
+
+use super::model::Model;
+use super::probability_tables::ProbabilityTables;
+use super::probability_tables_coefficient_context::ProbabilityTablesCoefficientContext;
+use super::quantization_tables::QuantizationTables;
+use super::vpx_bool_reader::VPXBoolReader;
+use super::vpx_bool_writer::VPXBoolWriter;
+
+#[cfg(feature = "experimental-codec")]
+pub use experimental::PositionalSignCodec;
+
+/// How the coefficients of a block are coded with the bool coder. The encoder and decoder walk the blocks,
+/// derive the contexts of each value from the neighbor blocks and their NeighborSummary, and hand them to the
+/// codec, which picks the branches the value is coded with. The decoder calls the read functions in exactly
+/// the same order as the encoder called the write functions.
+///
+/// Model is the implementation the format has always used. The encoder and decoder are generic over the codec,
+/// so they call Model directly without any virtual dispatch. Other implementations are for experimenting with
+/// context models, see CoefficientCodecKind.
+pub trait CoefficientCodec {
+    /// the model that the codec keeps its branches in, which the snapshots, the tuning and the statistics of
+    /// the branches come from
+    fn model(&self) -> &Model;
+
+    /// codes the number of non-zero coefficients in the 7x7 block, with num_non_zeros_context estimated from the
+    /// NeighborSummary of the blocks above and to the left
+    fn write_non_zero_7x7_count<W: Write, const ESTIMATE: bool>(
+        &mut self,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        color_index: usize,
+        num_non_zeros_context: u8,
+        num_non_zeros_7x7: u8,
+    ) -> Result<()>;
+
+    fn read_non_zero_7x7_count<R: Read>(
+        &mut self,
+        bool_reader: &mut VPXBoolReader<R>,
+        color_index: usize,
+        num_non_zeros_context: u8,
+    ) -> Result<u8>;
+
+    /// codes the coefficient of the 7x7 block at zig49 (coord in raster order), given the bin of the number of
+    /// non-zeros that are left and the bit length of the prediction from the same coefficient of the neighbor
+    /// blocks
+    #[allow(clippy::too_many_arguments)]
+    fn write_coef<W: Write, const ESTIMATE: bool>(
+        &mut self,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        color_index: usize,
+        coef: i16,
+        coord: usize,
+        zig49: usize,
+        num_non_zeros_bin: usize,
+        best_prior_bit_len: usize,
+    ) -> Result<()>;
+
+    fn read_coef<R: Read>(
+        &mut self,
+        bool_reader: &mut VPXBoolReader<R>,
+        color_index: usize,
+        coord: usize,
+        zig49: usize,
+        num_non_zeros_bin: usize,
+        best_prior_bit_len: usize,
+    ) -> Result<i16>;
+
+    /// codes the number of non-zero coefficients in the top row (HORIZONTAL) or left column of the block, given
+    /// how far the 7x7 block extends in that direction and its number of non-zeros
+    fn write_non_zero_edge_count<W: Write, const HORIZONTAL: bool, const ESTIMATE: bool>(
+        &mut self,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        color_index: usize,
+        est_eob: u8,
+        num_non_zeros_7x7: u8,
+        num_non_zeros_edge: u8,
+    ) -> Result<()>;
+
+    fn read_non_zero_edge_count<R: Read, const HORIZONTAL: bool>(
+        &mut self,
+        bool_reader: &mut VPXBoolReader<R>,
+        color_index: usize,
+        est_eob: u8,
+        num_non_zeros_7x7: u8,
+    ) -> Result<u8>;
+
+    /// codes a coefficient of the top row or left column, with the prediction from the neighbor blocks in ptcc8
+    #[allow(clippy::too_many_arguments)]
+    fn write_edge_coefficient<W: Write, const ESTIMATE: bool>(
+        &mut self,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        qt: &QuantizationTables,
+        pt: &ProbabilityTables,
+        coef: i16,
+        coord: usize,
+        zig15offset: usize,
+        ptcc8: &ProbabilityTablesCoefficientContext,
+    ) -> Result<()>;
+
+    fn read_edge_coefficient<R: Read>(
+        &mut self,
+        bool_reader: &mut VPXBoolReader<R>,
+        pt: &ProbabilityTables,
+        qt: &QuantizationTables,
+        coord: usize,
+        zig15offset: usize,
+        ptcc8: &ProbabilityTablesCoefficientContext,
+    ) -> Result<i16>;
+
+    /// codes the difference between the DC and its prediction from the edges of the neighbor blocks, given how
+    /// uncertain the prediction is
+    fn write_dc<W: Write, const ESTIMATE: bool>(
+        &mut self,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        color_index: usize,
+        coef: i16,
+        uncertainty: i16,
+        uncertainty2: i16,
+    ) -> Result<()>;
+
+    fn read_dc<R: Read>(
+        &mut self,
+        bool_reader: &mut VPXBoolReader<R>,
+        color_index: usize,
+        uncertainty: i16,
+        uncertainty2: i16,
+    ) -> Result<i16>;
+}
+
+#[cfg(feature = "experimental-codec")]
+mod experimental {
+    use anyhow::{Context, Result};
+    use default_boxed::DefaultBoxed;
+    use std::io::{Read, Write};
+
+    use crate::helpers::here;
+    use crate::metrics::{ModelComponent, ModelSubComponent};
+    use crate::structs::branch::Branch;
+    use crate::structs::model::{Model, BLOCK_TYPES};
+    use crate::structs::probability_tables::ProbabilityTables;
+    use crate::structs::probability_tables_coefficient_context::ProbabilityTablesCoefficientContext;
+    use crate::structs::quantization_tables::QuantizationTables;
+    use crate::structs::vpx_bool_reader::VPXBoolReader;
+    use crate::structs::vpx_bool_writer::VPXBoolWriter;
+
+    use super::CoefficientCodec;
+
+    #[derive(DefaultBoxed)]
+    struct PositionalSignCounts {
+        counts: [[Branch; 49]; BLOCK_TYPES],
+    }
+
+    /// Experimental: codes everything like Model, except that the sign of each coefficient of the 7x7 block is
+    /// coded with a branch for its position instead of the one branch that all of them share. The extra
+    /// branches aren't part of the snapshots or the statistics of the model.
+    pub struct PositionalSignCodec<'a> {
+        model: &'a mut Model,
+        sign_counts_7x7: Box<PositionalSignCounts>,
+    }
+
+    impl<'a> PositionalSignCodec<'a> {
+        pub fn new(model: &'a mut Model) -> Self {
+            #[allow(unused_mut)]
+            let mut sign_counts_7x7 = PositionalSignCounts::default_boxed();
+
+            // the extra branches start out like the ones of the model
+            #[cfg(feature = "experimental-tuning")]
+            {
+                let initial_counts = model.get_tuning().initial_counts();
+                for b in sign_counts_7x7.counts.iter_mut().flatten() {
+                    b.set_counts(initial_counts);
+                }
+            }
+
+            PositionalSignCodec {
+                model,
+                sign_counts_7x7,
+            }
+        }
+    }
+
+    impl CoefficientCodec for PositionalSignCodec<'_> {
+        fn model(&self) -> &Model {
+            self.model
+        }
+
+        fn write_non_zero_7x7_count<W: Write, const ESTIMATE: bool>(
+            &mut self,
+            bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+            color_index: usize,
+            num_non_zeros_context: u8,
+            num_non_zeros_7x7: u8,
+        ) -> Result<()> {
+            self.model.write_non_zero_7x7_count(
+                bool_writer,
+                color_index,
+                num_non_zeros_context,
+                num_non_zeros_7x7,
+            )
+        }
+
+        fn read_non_zero_7x7_count<R: Read>(
+            &mut self,
+            bool_reader: &mut VPXBoolReader<R>,
+            color_index: usize,
+            num_non_zeros_context: u8,
+        ) -> Result<u8> {
+            self.model
+                .read_non_zero_7x7_count(bool_reader, color_index, num_non_zeros_context)
+        }
+
+        fn write_coef<W: Write, const ESTIMATE: bool>(
+            &mut self,
+            bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+            color_index: usize,
+            coef: i16,
+            coord: usize,
+            zig49: usize,
+            num_non_zeros_bin: usize,
+            best_prior_bit_len: usize,
+        ) -> Result<()> {
+            let (exp, _, bits) = self.model.get_coef_branches(
+                coord,
+                num_non_zeros_bin,
+                color_index,
+                zig49,
+                best_prior_bit_len,
+            );
+
+            Model::write_length_sign_coef(
+                bool_writer,
+                coef,
+                exp,
+                &mut self.sign_counts_7x7.counts[color_index][zig49],
+                bits,
+                ModelComponent::Coef(ModelSubComponent::Exp),
+                ModelComponent::Coef(ModelSubComponent::Sign),
+                ModelComponent::Coef(ModelSubComponent::Noise),
+            )
+            .context(here!())
+        }
+
+        fn read_coef<R: Read>(
+            &mut self,
+            bool_reader: &mut VPXBoolReader<R>,
+            color_index: usize,
+            coord: usize,
+            zig49: usize,
+            num_non_zeros_bin: usize,
+            best_prior_bit_len: usize,
+        ) -> Result<i16> {
+            let (exp, _, bits) = self.model.get_coef_branches(
+                coord,
+                num_non_zeros_bin,
+                color_index,
+                zig49,
+                best_prior_bit_len,
+            );
+
+            Model::read_length_sign_coef(
+                bool_reader,
+                exp,
+                &mut self.sign_counts_7x7.counts[color_index][zig49],
+                bits,
+                ModelComponent::Coef(ModelSubComponent::Exp),
+                ModelComponent::Coef(ModelSubComponent::Sign),
+                ModelComponent::Coef(ModelSubComponent::Noise),
+            )
+            .context(here!())
+        }
+
+        fn write_non_zero_edge_count<W: Write, const HORIZONTAL: bool, const ESTIMATE: bool>(
+            &mut self,
+            bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+            color_index: usize,
+            est_eob: u8,
+            num_non_zeros_7x7: u8,
+            num_non_zeros_edge: u8,
+        ) -> Result<()> {
+            self.model
+                .write_non_zero_edge_count::<W, HORIZONTAL, ESTIMATE>(
+                    bool_writer,
+                    color_index,
+                    est_eob,
+                    num_non_zeros_7x7,
+                    num_non_zeros_edge,
+                )
+        }
+
+        fn read_non_zero_edge_count<R: Read, const HORIZONTAL: bool>(
+            &mut self,
+            bool_reader: &mut VPXBoolReader<R>,
+            color_index: usize,
+            est_eob: u8,
+            num_non_zeros_7x7: u8,
+        ) -> Result<u8> {
+            self.model.read_non_zero_edge_count::<R, HORIZONTAL>(
+                bool_reader,
+                color_index,
+                est_eob,
+                num_non_zeros_7x7,
+            )
+        }
+
+        fn write_edge_coefficient<W: Write, const ESTIMATE: bool>(
+            &mut self,
+            bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+            qt: &QuantizationTables,
+            pt: &ProbabilityTables,
+            coef: i16,
+            coord: usize,
+            zig15offset: usize,
+            ptcc8: &ProbabilityTablesCoefficientContext,
+        ) -> Result<()> {
+            self.model
+                .write_edge_coefficient(bool_writer, qt, pt, coef, coord, zig15offset, ptcc8)
+        }
+
+        fn read_edge_coefficient<R: Read>(
+            &mut self,
+            bool_reader: &mut VPXBoolReader<R>,
+            pt: &ProbabilityTables,
+            qt: &QuantizationTables,
+            coord: usize,
+            zig15offset: usize,
+            ptcc8: &ProbabilityTablesCoefficientContext,
+        ) -> Result<i16> {
+            self.model
+                .read_edge_coefficient(bool_reader, pt, qt, coord, zig15offset, ptcc8)
+        }
+
+        fn write_dc<W: Write, const ESTIMATE: bool>(
+            &mut self,
+            bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+            color_index: usize,
+            coef: i16,
+            uncertainty: i16,
+            uncertainty2: i16,
+        ) -> Result<()> {
+            self.model
+                .write_dc(bool_writer, color_index, coef, uncertainty, uncertainty2)
+        }
+
+        fn read_dc<R: Read>(
+            &mut self,
+            bool_reader: &mut VPXBoolReader<R>,
+            color_index: usize,
+            uncertainty: i16,
+            uncertainty2: i16,
+        ) -> Result<i16> {
+            self.model
+                .read_dc(bool_reader, color_index, uncertainty, uncertainty2)
+        }
+    }
+}
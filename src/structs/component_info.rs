@@ -4,7 +4,7 @@
  *  This software incorporates material from third parties. See NOTICE.txt for details.
  *--------------------------------------------------------------------------------------------*/
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ComponentInfo {
     /// quantization table
     pub q_table_index: u8,
@@ -0,0 +1,165 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use default_boxed::DefaultBoxed;
+
+use crate::consts::MAX_THREADS;
+use crate::enabled_features::{CoefficientCodecKind, EnabledFeatures};
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::multiplexer::format::ChunkHeader;
+use crate::structs::lepton_encoder::lepton_encode_row_range;
+use crate::structs::lepton_format::{
+    get_chunk_size, get_quantization_tables, read_jpeg_rows, split_row_handoffs_to_threads,
+    EncoderInfo, LeptonFileType,
+};
+use crate::structs::model::Model;
+use crate::structs::probability_tables_set::ProbabilityTablesSet;
+
+use crate::consts::JPegType;
+
+/// number of rows encoded before each sampled row to train the model
+const WARM_UP_ROWS: usize = 4;
+
+/// prediction of the size of the lepton file for a JPEG, made by encoding only some of its MCU rows
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompressionEstimate {
+    /// size of the JPEG
+    pub original_size: u64,
+    /// most likely size of the lepton file
+    pub estimated_size: u64,
+    /// the size is within these bounds with 95% confidence, as far as the sampled rows are representative
+    pub lower_bound: u64,
+    pub upper_bound: u64,
+    /// number of MCU rows that were encoded, out of total_rows
+    pub sampled_rows: u32,
+    pub total_rows: u32,
+}
+
+impl CompressionEstimate {
+    /// estimated size of the lepton file relative to the JPEG
+    pub fn estimated_ratio(&self) -> f64 {
+        self.estimated_size as f64 / self.original_size as f64
+    }
+}
+
+/// estimates how big the lepton file for jpeg will be with the default features by encoding every
+/// sample_interval-th MCU row and extrapolating from the sizes of those rows to the whole image. The header
+/// is created the same way the encoder would.
+pub fn estimate_compression_wrapper(
+    jpeg: &[u8],
+    sample_interval: usize,
+) -> Result<CompressionEstimate> {
+    if sample_interval == 0 {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            "sample interval must be at least one row",
+        );
+    }
+
+    let enabled_features = EnabledFeatures::default();
+
+    let (mut lp, image_data, row_handoffs) =
+        read_jpeg_rows(&mut Cursor::new(jpeg), &enabled_features, |_jh| {}).context(here!())?;
+
+    let pts = ProbabilityTablesSet::new(
+        enabled_features.get_quantized_dc_prediction(),
+        enabled_features.get_fast_dc_prediction(),
+    );
+    let quantization_tables = get_quantization_tables(&lp.jpeg_header).context(here!())?;
+
+    // a row encoded on its own pays for training the model from scratch, which the rows in the
+    // middle of a thread segment don't, so the size of a sampled row is the difference between
+    // encoding it after a few warm up rows and encoding only the warm up rows
+    let encoded_size = |start: usize, end: usize| -> Result<f64> {
+        if start == end {
+            return Ok(0.0);
+        }
+
+        let mut output = Vec::new();
+        lepton_encode_row_range(
+            &pts,
+            &quantization_tables,
+            &image_data,
+            &mut output,
+            0,
+            &lp.truncate_components,
+            row_handoffs[start].luma_y_start,
+            row_handoffs[end - 1].luma_y_end,
+            end == row_handoffs.len(),
+            true,
+            &mut Model::default_boxed(),
+            None,
+            CoefficientCodecKind::Model,
+        )
+        .context(here!())?;
+
+        Ok(output.len() as f64)
+    };
+
+    let mut row_sizes = Vec::new();
+    // sample the middle of each interval so that small images still get a row with warm up
+    let first_sample = (sample_interval / 2).min(row_handoffs.len() - 1);
+    for i in (first_sample..row_handoffs.len()).step_by(sample_interval) {
+        let start = i.saturating_sub(WARM_UP_ROWS);
+        row_sizes.push(encoded_size(start, i + 1)? - encoded_size(start, i)?);
+    }
+
+    // extrapolate the mean size of the sampled rows to all of them, with the standard error of the mean
+    // (corrected for sampling without replacement from a finite number of rows) as the uncertainty
+    let n = row_sizes.len() as f64;
+    let total = row_handoffs.len() as f64;
+    let mean = row_sizes.iter().sum::<f64>() / n;
+    let variance = if row_sizes.len() > 1 {
+        row_sizes
+            .iter()
+            .map(|s| (s - mean) * (s - mean))
+            .sum::<f64>()
+            / (n - 1.0)
+    } else {
+        0.0
+    };
+    let standard_error = total * (variance / n * (1.0 - n / total)).sqrt();
+    let estimated_data = total * mean;
+
+    // everything besides the coded data, which the encoder writes the same way whatever the rows contain
+    // only the number of segments matters for the size of the header, not where they are split
+    lp.thread_handoff = split_row_handoffs_to_threads(&row_handoffs, None, MAX_THREADS, None);
+    lp.original_file_crc = Some(0);
+    lp.original_file_size = Some(jpeg.len() as u64);
+    lp.encoder_info = Some(EncoderInfo::current(&enabled_features));
+    lp.file_type = Some(LeptonFileType {
+        progressive: lp.jpeg_header.jpeg_type == JPegType::Progressive,
+        scan_count: u32::try_from(lp.scnc)?,
+    });
+    lp.large_sizes = lp.needs_large_sizes();
+    lp.segment_checksums = enabled_features.segment_checksums;
+
+    let mut header = Vec::new();
+    lp.write_lepton_header(&mut header).context(here!())?;
+
+    // each chunk of the multiplexed data starts with the thread id and its length
+    let chunk_size = get_chunk_size(&enabled_features, &lp.thread_handoff) as f64;
+    let chunks = lp.thread_handoff.len() as f64 + estimated_data / chunk_size;
+
+    let overhead = header.len() as f64
+        + ChunkHeader::SIZE as f64 * chunks
+        + (lp.get_segment_checksums_len() + lp.get_file_size_len()) as f64;
+
+    Ok(CompressionEstimate {
+        original_size: jpeg.len() as u64,
+        estimated_size: (overhead + estimated_data).round() as u64,
+        lower_bound: (overhead + estimated_data - 1.96 * standard_error)
+            .max(overhead)
+            .round() as u64,
+        upper_bound: (overhead + estimated_data + 1.96 * standard_error).round() as u64,
+        sampled_rows: row_sizes.len() as u32,
+        total_rows: row_handoffs.len() as u32,
+    })
+}
@@ -0,0 +1,256 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Test vectors that capture the intermediate states of encoding a JPEG, so that another implementation of
+//! lepton (or a later version of this one) can find out where its output starts to diverge.
+//!
+//! The vectors for an image consist of two files:
+//!
+//! `<name>.json` describes the encoding with these fields, always written in this order:
+//! - `format`: "lepton-conformance-1"
+//! - `prefix_length`: the maximum number of coded bytes of each segment stored in the binary file
+//! - `jpeg`: `size` and `crc32` of the input file
+//! - `header`: the parsed JPEG header, which is `jpeg_type` ("baseline" or "progressive"), `width`, `height`,
+//!   `restart_interval`, `mcu_rows`, `components` (the `h` and `v` sampling factors, `q_table` index and
+//!   `block_width` and `block_height` of each), `q_tables` (the 64 entries of each table in zigzag order, for
+//!   the tables used by the components) and `garbage_size`, the number of bytes after the end of the image
+//! - `segments`: for each thread segment, the `luma_y_start` and `luma_y_end` rows, `jpeg_size` (the size of
+//!   its JPEG scan data), the `overhang_byte` and `overhang_bits` it starts with, the `last_dc` predictors
+//!   it starts with, `compressed_size` (the size of its arithmetic coded data, without the chunk framing)
+//!   and the `prefix_offset` and `prefix_length` of its first coded bytes in the binary file
+//! - `lepton`: `size` and `crc32` of the final lepton file
+//!
+//! `<name>.bin` contains the first coded bytes of each segment one after the other.
+//!
+//! CRCs are written as 8 lower case hex digits. The lepton file is encoded with the default features in
+//! deterministic mode, with an empty version and git revision in the encoder info so that the vectors don't
+//! change with every release.
+
+use std::cmp;
+use std::fmt::Write as _;
+use std::io::Cursor;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use flate2::Crc;
+
+use crate::consts::{JPegType, EOI, MAX_THREADS};
+use crate::enabled_features::EnabledFeatures;
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::structs::lepton_format::{
+    encode_lepton_contents, get_remaining_size, ChunkReader, EncoderInfo, LeptonHeader,
+};
+
+/// the encoding of a JPEG along with the intermediate states that conformance tests compare
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConformanceVectors {
+    /// description of the header and the thread segments, see the module documentation for the layout
+    pub json: String,
+    /// the first coded bytes of each segment, at the offsets given in the json
+    pub segment_prefixes: Vec<u8>,
+    /// the final lepton file
+    pub lepton: Vec<u8>,
+}
+
+impl ConformanceVectors {
+    /// writes the vectors to `<name>.json` and `<name>.bin` in dir. The lepton file itself isn't written,
+    /// the json identifies it by its size and CRC.
+    pub fn write_to(&self, dir: &Path, name: &str) -> std::io::Result<()> {
+        std::fs::write(dir.join(name.to_owned() + ".json"), &self.json)?;
+        std::fs::write(dir.join(name.to_owned() + ".bin"), &self.segment_prefixes)?;
+
+        Ok(())
+    }
+}
+
+/// the options the vectors are encoded with
+fn conformance_features() -> EnabledFeatures {
+    EnabledFeatures {
+        deterministic: true,
+        ..EnabledFeatures::default()
+    }
+}
+
+/// encodes jpeg and collects the vectors, keeping up to prefix_length coded bytes of each segment
+pub fn generate_conformance_vectors_wrapper(
+    jpeg: &[u8],
+    prefix_length: usize,
+) -> Result<ConformanceVectors> {
+    let enabled_features = conformance_features();
+
+    let mut lepton = Vec::new();
+    encode_lepton_contents(
+        &mut Cursor::new(jpeg),
+        &mut Cursor::new(&mut lepton),
+        MAX_THREADS,
+        &enabled_features,
+        EncoderInfo {
+            version: String::new(),
+            git_revision: String::new(),
+            enabled_features: enabled_features.to_bits(),
+        },
+    )
+    .context(here!())?;
+
+    // everything is taken from the file as a decoder sees it rather than from the encoder's state
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).context(here!())?;
+
+    let remaining_size = get_remaining_size(&mut reader).context(here!())?;
+
+    let mut compressed_sizes = vec![0u64; lh.thread_handoff.len()];
+    let mut prefixes = vec![Vec::new(); lh.thread_handoff.len()];
+
+    let mut chunk_reader = ChunkReader::for_header(&mut reader, &lh, Some(remaining_size));
+    while let Some((thread_id, buffer)) = chunk_reader.next_chunk().context(here!())? {
+        let thread_id = usize::from(thread_id);
+        if thread_id >= prefixes.len() {
+            return err_exit_code(ExitCode::BadLeptonFile, "invalid thread_id");
+        }
+
+        compressed_sizes[thread_id] += buffer.len() as u64;
+
+        let wanted = cmp::min(prefix_length - prefixes[thread_id].len(), buffer.len());
+        prefixes[thread_id].extend_from_slice(&buffer[..wanted]);
+    }
+
+    let mut json = String::new();
+    let mut segment_prefixes = Vec::new();
+
+    // writing to a String can't fail, so the results are ignored
+    let _ = writeln!(json, "{{");
+    let _ = writeln!(json, "  \"format\": \"lepton-conformance-1\",");
+    let _ = writeln!(json, "  \"prefix_length\": {0},", prefix_length);
+    let _ = writeln!(
+        json,
+        "  \"jpeg\": {{ \"size\": {0}, \"crc32\": \"{1:08x}\" }},",
+        jpeg.len(),
+        crc32(jpeg)
+    );
+
+    let jh = &lh.jpeg_header;
+    let _ = writeln!(json, "  \"header\": {{");
+    let _ = writeln!(
+        json,
+        "    \"jpeg_type\": \"{0}\",",
+        if jh.jpeg_type == JPegType::Progressive {
+            "progressive"
+        } else {
+            "baseline"
+        }
+    );
+    let _ = writeln!(json, "    \"width\": {0},", jh.img_width);
+    let _ = writeln!(json, "    \"height\": {0},", jh.img_height);
+    let _ = writeln!(json, "    \"restart_interval\": {0},", jh.rsti);
+    let _ = writeln!(
+        json,
+        "    \"mcu_rows\": {0},",
+        lh.truncate_components.mcu_count_vertical
+    );
+
+    let _ = writeln!(json, "    \"components\": [");
+    let mut q_tables = Vec::new();
+    for (i, ci) in jh.cmp_info[..jh.cmpc].iter().enumerate() {
+        let _ = writeln!(
+            json,
+            "      {{ \"h\": {0}, \"v\": {1}, \"q_table\": {2}, \"block_width\": {3}, \"block_height\": {4} }}{5}",
+            ci.sfh,
+            ci.sfv,
+            ci.q_table_index,
+            ci.bch,
+            ci.bcv,
+            separator(i, jh.cmpc)
+        );
+
+        if !q_tables.contains(&ci.q_table_index) {
+            q_tables.push(ci.q_table_index);
+        }
+    }
+    let _ = writeln!(json, "    ],");
+
+    let _ = writeln!(json, "    \"q_tables\": [");
+    for (i, q) in q_tables.iter().enumerate() {
+        let _ = writeln!(
+            json,
+            "      {{ \"index\": {0}, \"values\": {1} }}{2}",
+            q,
+            number_list(jh.q_tables[usize::from(*q)].iter()),
+            separator(i, q_tables.len())
+        );
+    }
+    let _ = writeln!(json, "    ],");
+
+    let garbage_size = if lh.garbage_data.starts_with(&EOI) {
+        lh.garbage_data.len() - EOI.len()
+    } else {
+        lh.garbage_data.len()
+    };
+    let _ = writeln!(json, "    \"garbage_size\": {0}", garbage_size);
+    let _ = writeln!(json, "  }},");
+
+    let _ = writeln!(json, "  \"segments\": [");
+    for (i, th) in lh.thread_handoff.iter().enumerate() {
+        let _ = writeln!(json, "    {{");
+        let _ = writeln!(json, "      \"luma_y_start\": {0},", th.luma_y_start);
+        let _ = writeln!(json, "      \"luma_y_end\": {0},", th.luma_y_end);
+        let _ = writeln!(json, "      \"jpeg_size\": {0},", th.segment_size);
+        let _ = writeln!(json, "      \"overhang_byte\": {0},", th.overhang_byte);
+        let _ = writeln!(json, "      \"overhang_bits\": {0},", th.num_overhang_bits);
+        let _ = writeln!(
+            json,
+            "      \"last_dc\": {0},",
+            number_list(th.last_dc.iter())
+        );
+        let _ = writeln!(json, "      \"compressed_size\": {0},", compressed_sizes[i]);
+        let _ = writeln!(
+            json,
+            "      \"prefix_offset\": {0},",
+            segment_prefixes.len()
+        );
+        let _ = writeln!(json, "      \"prefix_length\": {0}", prefixes[i].len());
+        let _ = writeln!(json, "    }}{0}", separator(i, lh.thread_handoff.len()));
+
+        segment_prefixes.extend_from_slice(&prefixes[i]);
+    }
+    let _ = writeln!(json, "  ],");
+
+    let _ = writeln!(
+        json,
+        "  \"lepton\": {{ \"size\": {0}, \"crc32\": \"{1:08x}\" }}",
+        lepton.len(),
+        crc32(&lepton)
+    );
+    let _ = writeln!(json, "}}");
+
+    Ok(ConformanceVectors {
+        json,
+        segment_prefixes,
+        lepton,
+    })
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+/// comma between the elements of a list
+fn separator(index: usize, len: usize) -> &'static str {
+    if index + 1 < len {
+        ","
+    } else {
+        ""
+    }
+}
+
+/// formats numbers as a json array on a single line
+fn number_list<T: std::fmt::Display>(values: impl Iterator<Item = T>) -> String {
+    let values: Vec<String> = values.map(|v| v.to_string()).collect();
+    format!("[{0}]", values.join(", "))
+}
@@ -0,0 +1,163 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::sync::Mutex;
+use std::thread;
+
+/// the cores of the machine that the process can run on, as far as they could be found out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpuTopology {
+    /// threads that can run at the same time, counting each thread of a core with SMT
+    pub logical: usize,
+    /// cores, counting the threads of each one once, if they are known
+    pub physical: Option<usize>,
+}
+
+/// the topology of this machine, which is only looked up by the first call that needs it
+static TOPOLOGY: Mutex<Option<CpuTopology>> = Mutex::new(None);
+
+impl CpuTopology {
+    /// the cores of this machine. The logical ones are the ones that the process is allowed to run on, and the
+    /// physical ones are read from /proc/cpuinfo on Linux with the physical-cores feature.
+    pub fn detect() -> Self {
+        *TOPOLOGY.lock().unwrap().get_or_insert_with(|| CpuTopology {
+            logical: thread::available_parallelism().map_or(1, |n| n.get()),
+            physical: detect_physical_cores(),
+        })
+    }
+}
+
+#[cfg(all(feature = "physical-cores", target_os = "linux"))]
+fn detect_physical_cores() -> Option<usize> {
+    parse_cpuinfo(&std::fs::read_to_string("/proc/cpuinfo").ok()?)
+}
+
+#[cfg(not(all(feature = "physical-cores", target_os = "linux")))]
+fn detect_physical_cores() -> Option<usize> {
+    None
+}
+
+/// the number of cores in the contents of /proc/cpuinfo, which are the different pairs of physical id and core id
+/// of its processors. None if the processors don't say, as in some virtual machines and on ARM.
+#[cfg(any(test, all(feature = "physical-cores", target_os = "linux")))]
+fn parse_cpuinfo(cpuinfo: &str) -> Option<usize> {
+    let mut cores = std::collections::HashSet::new();
+
+    for processor in cpuinfo.split("\n\n") {
+        let mut physical_id = None;
+        let mut core_id = None;
+        for line in processor.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+
+            match key.trim() {
+                "physical id" => physical_id = Some(value.trim()),
+                "core id" => core_id = Some(value.trim()),
+                _ => {}
+            }
+        }
+
+        if processor.trim().is_empty() {
+            continue;
+        }
+
+        cores.insert((physical_id?, core_id?));
+    }
+
+    if cores.is_empty() {
+        None
+    } else {
+        Some(cores.len())
+    }
+}
+
+/// how many threads to use when the caller leaves it to us: one per physical core, since the second thread of a
+/// core with SMT adds little to the speed of the coder but as much memory as any other thread. The process may
+/// be limited to fewer logical cores than the machine has physical ones, in which case those are used.
+pub fn auto_thread_count(topology: &CpuTopology) -> usize {
+    topology
+        .physical
+        .map_or(topology.logical, |p| p.min(topology.logical))
+        .max(1)
+}
+
+/// the number of threads to use for max_threads that a caller asked for, where 0 leaves it to
+/// auto_thread_count for the cores of this machine
+pub fn resolve_max_threads(max_threads: usize) -> usize {
+    if max_threads == 0 {
+        auto_thread_count(&CpuTopology::detect())
+    } else {
+        max_threads
+    }
+}
+
+/// uses the physical cores if they are known, without going over the logical ones the process is limited to
+#[test]
+fn auto_thread_count_prefers_physical_cores() {
+    let smt = CpuTopology {
+        logical: 16,
+        physical: Some(8),
+    };
+    assert_eq!(auto_thread_count(&smt), 8);
+
+    let unknown = CpuTopology {
+        logical: 6,
+        physical: None,
+    };
+    assert_eq!(auto_thread_count(&unknown), 6);
+
+    let limited = CpuTopology {
+        logical: 2,
+        physical: Some(8),
+    };
+    assert_eq!(auto_thread_count(&limited), 2);
+
+    let none = CpuTopology {
+        logical: 0,
+        physical: Some(0),
+    };
+    assert_eq!(auto_thread_count(&none), 1);
+
+    assert_eq!(resolve_max_threads(3), 3);
+    assert!(resolve_max_threads(0) >= 1);
+}
+
+/// the threads of each core with SMT are counted once, also across sockets
+#[test]
+fn parse_cpuinfo_counts_cores() {
+    let processor = |processor: u32, physical_id: u32, core_id: u32| {
+        format!(
+            "processor\t: {0}\nmodel name\t: test\nphysical id\t: {1}\ncore id\t\t: {2}\n",
+            processor, physical_id, core_id
+        )
+    };
+
+    // two sockets with two cores of two threads each
+    let cpuinfo = [
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, (p, c))| processor(i as u32, *p, *c))
+    .collect::<Vec<_>>()
+    .join("\n");
+    assert_eq!(parse_cpuinfo(&cpuinfo), Some(4));
+
+    // the processors of ARM machines don't have core ids
+    assert_eq!(
+        parse_cpuinfo("processor\t: 0\nBogoMIPS\t: 50.00\n\nprocessor\t: 1\nBogoMIPS\t: 50.00\n"),
+        None
+    );
+    assert_eq!(parse_cpuinfo(""), None);
+}
@@ -0,0 +1,185 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::{Read, Result, Seek, SeekFrom, Write};
+
+use flate2::Crc;
+
+/// reader that keeps a running CRC of everything read through it. Unlike flate2::CrcReader it can seek,
+/// which the JPEG reader needs to back up a little. Bytes that are read again after seeking backwards
+/// are only counted once, so the CRC is that of the file as long as we never skip ahead.
+pub struct CrcReader<R> {
+    inner: R,
+    crc: Crc,
+    position: u64,
+    /// position where the reader started, which the data of the CRC is counted from
+    start_position: u64,
+    /// position up to which the data has been added to the CRC
+    crc_position: u64,
+}
+
+impl<R: Read + Seek> CrcReader<R> {
+    pub fn new(mut inner: R) -> Result<Self> {
+        let position = inner.stream_position()?;
+
+        Ok(CrcReader {
+            inner,
+            crc: Crc::new(),
+            position,
+            start_position: position,
+            crc_position: position,
+        })
+    }
+
+    pub fn crc(&self) -> &Crc {
+        &self.crc
+    }
+
+    /// the number of bytes in the CRC. Crc::amount is only 32 bits, so it wraps for files of 4 GiB and more.
+    pub fn amount(&self) -> u64 {
+        self.crc_position - self.start_position
+    }
+}
+
+impl<R: Read> Read for CrcReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let amount = self.inner.read(buf)?;
+
+        let end = self.position + amount as u64;
+        if end > self.crc_position {
+            debug_assert!(self.position <= self.crc_position, "skipped data");
+
+            let already_counted = (self.crc_position - self.position) as usize;
+            self.crc.update(&buf[already_counted..amount]);
+            self.crc_position = end;
+        }
+
+        self.position = end;
+
+        Ok(amount)
+    }
+}
+
+impl<R: Seek> Seek for CrcReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.position = self.inner.seek(pos)?;
+        Ok(self.position)
+    }
+}
+
+/// writer that keeps a running CRC of everything written through it, like flate2::CrcWriter, but counts the bytes
+/// in 64 bits
+pub struct CrcWriter<W> {
+    inner: W,
+    crc: Crc,
+    amount: u64,
+}
+
+impl<W: Write> CrcWriter<W> {
+    pub fn new(inner: W) -> Self {
+        CrcWriter {
+            inner,
+            crc: Crc::new(),
+            amount: 0,
+        }
+    }
+
+    pub fn crc(&self) -> &Crc {
+        &self.crc
+    }
+
+    /// the number of bytes in the CRC, which unlike Crc::amount doesn't wrap at 4 GiB
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CrcWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let amount = self.inner.write(buf)?;
+        self.crc.update(&buf[..amount]);
+        self.amount += amount as u64;
+        Ok(amount)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+use std::io::Cursor;
+
+// reading the same data again after seeking backwards doesn't change the CRC
+#[test]
+fn crc_counts_reread_data_once() {
+    let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+    let mut expected = Crc::new();
+    expected.update(&data);
+
+    let mut reader = CrcReader::new(Cursor::new(&data)).unwrap();
+
+    let mut buf = [0u8; 5];
+    reader.read_exact(&mut buf).unwrap();
+    reader.seek(SeekFrom::Current(-3)).unwrap();
+
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+
+    assert_eq!(rest, [3, 4, 5, 6, 7, 8]);
+    assert_eq!(reader.crc().sum(), expected.sum());
+    assert_eq!(reader.amount(), 8);
+}
+
+/// the sizes don't wrap at 4 GiB like Crc::amount does. The data is zeros that are made up as they are read, so
+/// nothing of that size is allocated.
+#[test]
+fn crc_amount_counts_past_4gib() {
+    use std::io;
+
+    const SIZE: u64 = u32::MAX as u64 + 1000;
+
+    struct Zeros {
+        position: u64,
+    }
+
+    impl Read for Zeros {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let n = buf.len().min((SIZE - self.position) as usize);
+            buf[..n].fill(0);
+            self.position += n as u64;
+            Ok(n)
+        }
+    }
+
+    impl Seek for Zeros {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            assert_eq!(pos, SeekFrom::Current(0));
+            Ok(self.position)
+        }
+    }
+
+    let mut reader = CrcReader::new(Zeros { position: 0 }).unwrap();
+    let mut writer = CrcWriter::new(io::sink());
+    let mut buf = vec![0; 1 << 20];
+    loop {
+        let n = reader.read(&mut buf).unwrap();
+        if n == 0 {
+            break;
+        }
+        writer.write_all(&buf[..n]).unwrap();
+    }
+
+    assert_eq!(reader.amount(), SIZE);
+    assert_eq!(writer.amount(), SIZE);
+    assert_eq!(reader.crc().sum(), writer.crc().sum());
+    assert_eq!(u64::from(writer.crc().amount()), SIZE & 0xFFFF_FFFF);
+}
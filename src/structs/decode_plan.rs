@@ -0,0 +1,240 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::cmp;
+use std::io::{Cursor, Write};
+
+use anyhow::{Context, Result};
+use cpu_time::ThreadTime;
+use log::warn;
+
+use crate::consts::JPegType;
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::metrics::Metrics;
+use crate::structs::block_based_image::BlockBasedImage;
+use crate::structs::crc_reader::CrcWriter;
+use crate::structs::lepton_format::{merge_segment_images, write_segment_jpeg, LeptonHeader};
+use crate::structs::lepton_shard::{find_segment_chunks, LeptonShard};
+use crate::structs::thread_handoff::ThreadHandoff;
+
+/// a lepton file split into the thread segments that decode_segment_wrapper decodes, independently of each other
+/// and on whichever thread the caller likes, and that assemble_wrapper then puts together into the JPEG
+pub struct DecodePlan {
+    lh: LeptonHeader,
+    /// the chunks of each segment, without the header
+    segments: Vec<LeptonShard>,
+    /// the original file if the lepton file stores it as is, in which case there are no segments to decode
+    passthrough: Option<Vec<u8>>,
+}
+
+impl DecodePlan {
+    /// number of segments to decode, which is zero for files that store the original as is
+    pub fn num_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// the rows of luma blocks and the coder state of each segment
+    pub fn segments(&self) -> &[ThreadHandoff] {
+        if self.passthrough.is_some() {
+            &[]
+        } else {
+            &self.lh.thread_handoff
+        }
+    }
+}
+
+/// progressive and other multi-scan images need the coefficients of all the segments before any of the JPEG can
+/// be written, while the segments of a baseline image are written out as they are decoded
+fn needs_entire_image(lh: &LeptonHeader) -> bool {
+    lh.jpeg_header.jpeg_type == JPegType::Progressive || lh.has_additional_scans()
+}
+
+/// what decode_segment_wrapper made of one of the segments of a DecodePlan
+pub struct SegmentOutput {
+    /// which of the segments of the plan this is
+    pub segment: usize,
+    /// statistics and CPU time of decoding the segment
+    pub metrics: Metrics,
+    data: SegmentData,
+}
+
+impl SegmentOutput {
+    /// what becomes of the coefficients that were decoded for segment of lh: the scan data of its rows for a
+    /// baseline image, or the coefficients themselves for a multi-scan image
+    pub(crate) fn from_coefficients(
+        lh: &LeptonHeader,
+        segment: usize,
+        image_data: Vec<BlockBasedImage>,
+        metrics: Metrics,
+    ) -> Result<SegmentOutput> {
+        let data = if needs_entire_image(lh) {
+            SegmentData::Coefficients(image_data)
+        } else {
+            let thread_handoff = &lh.thread_handoff[segment];
+
+            let mut scan = Vec::new();
+            write_segment_jpeg(thread_handoff, &image_data, lh, &mut scan).context(here!())?;
+
+            // the output of a segment can't be longer than its scan data was
+            if scan.len() as i64 > thread_handoff.segment_size {
+                warn!("warning: truncating segment");
+                scan.truncate(thread_handoff.segment_size as usize);
+            }
+
+            SegmentData::Scan(scan)
+        };
+
+        Ok(SegmentOutput {
+            segment,
+            metrics,
+            data,
+        })
+    }
+}
+
+enum SegmentData {
+    /// the scan data of the rows of the segment of a baseline image
+    Scan(Vec<u8>),
+    /// the coefficients of the rows of the segment of a multi-scan image, which can only be written out once
+    /// those of all the segments are there
+    Coefficients(Vec<BlockBasedImage>),
+}
+
+/// reads the header of a complete lepton file and finds the chunks of each of its segments
+pub fn plan_decode_wrapper(lepton: &[u8]) -> Result<DecodePlan> {
+    let mut reader = Cursor::new(lepton);
+
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).context(here!())?;
+    let header_size = reader.position() as usize;
+
+    if let Some(size) = lh.passthrough_size {
+        // the original follows the header, and copy_passthrough fails if it is cut short
+        let end = cmp::min(header_size as u64 + size, lepton.len() as u64) as usize;
+
+        return Ok(DecodePlan {
+            passthrough: Some(lepton[header_size..end].to_vec()),
+            lh,
+            segments: Vec::new(),
+        });
+    }
+
+    // fail before any segment is decoded if the file needs a primer that we don't have
+    lh.check_model_primer().context(here!())?;
+
+    let segments = find_segment_chunks(lepton, &lh, header_size).context(here!())?;
+
+    Ok(DecodePlan {
+        lh,
+        segments,
+        passthrough: None,
+    })
+}
+
+/// decodes one of the segments of plan on the calling thread
+pub fn decode_segment_wrapper(plan: &DecodePlan, segment: usize) -> Result<SegmentOutput> {
+    let Some(shard) = plan.segments.get(segment) else {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            format!("file only has {0} segments", plan.segments.len()).as_str(),
+        );
+    };
+
+    let cpu_time = ThreadTime::now();
+
+    let (image_data, metrics) = plan
+        .lh
+        .decode_segment_chunks(&shard.chunks, segment, shard.checksum)
+        .context(here!())?;
+
+    let mut output = SegmentOutput::from_coefficients(&plan.lh, segment, image_data, metrics)
+        .context(here!())?;
+
+    output.metrics.record_cpu_worker_time(cpu_time.elapsed());
+
+    Ok(output)
+}
+
+/// puts the decoded segments of plan together into the JPEG, checking it against the size and CRC of the
+/// original. The outputs can be in any order, but there has to be one for each segment. The scans of multi-scan
+/// images are encoded on the calling thread.
+pub fn assemble_wrapper(mut plan: DecodePlan, outputs: Vec<SegmentOutput>) -> Result<Vec<u8>> {
+    let mut writer = CrcWriter::new(Vec::new());
+
+    if let Some(original) = &plan.passthrough {
+        plan.lh
+            .copy_passthrough(&mut &original[..], &mut writer)
+            .context(here!())?;
+        return Ok(writer.into_inner());
+    }
+
+    assemble_segments(&mut plan.lh, plan.segments.len(), outputs, &mut writer).context(here!())?;
+
+    Ok(writer.into_inner())
+}
+
+/// writes the JPEG of lh from the outputs of its num_segments segments, which can be in any order, checking it
+/// against the size and CRC of the original
+pub(crate) fn assemble_segments<W: Write>(
+    lh: &mut LeptonHeader,
+    num_segments: usize,
+    outputs: Vec<SegmentOutput>,
+    writer: &mut CrcWriter<W>,
+) -> Result<()> {
+    let mut ordered: Vec<Option<SegmentData>> = (0..num_segments).map(|_| None).collect();
+    for output in outputs {
+        match ordered.get_mut(output.segment) {
+            Some(slot @ None) => *slot = Some(output.data),
+            _ => {
+                return err_exit_code(
+                    ExitCode::ShardMismatch,
+                    format!(
+                        "output of segment {0} is there more than once or beyond the {1} segments of the file",
+                        output.segment, num_segments
+                    )
+                    .as_str(),
+                );
+            }
+        }
+    }
+
+    let mut scans = Vec::with_capacity(ordered.len());
+    let mut coefficients = Vec::with_capacity(ordered.len());
+    for (segment, data) in ordered.into_iter().enumerate() {
+        match data {
+            Some(SegmentData::Scan(scan)) => scans.push(scan),
+            Some(SegmentData::Coefficients(image_data)) => coefficients.push(image_data),
+            None => {
+                return err_exit_code(
+                    ExitCode::ShardMismatch,
+                    format!("output of segment {0} is missing", segment).as_str(),
+                );
+            }
+        }
+    }
+
+    lh.write_jpeg_start(writer).context(here!())?;
+
+    if needs_entire_image(lh) {
+        let merged = merge_segment_images(coefficients).context(here!())?;
+
+        lh.write_progressive_scans(&merged, writer, 1, &mut Metrics::default())
+            .context(here!())?;
+    } else {
+        for scan in scans {
+            writer.write_all(&scan[..]).context(here!())?;
+        }
+
+        if !lh.early_eof_encountered {
+            lh.write_trailing_rst_errors(writer).context(here!())?;
+        }
+    }
+
+    lh.write_jpeg_end(writer).context(here!())?;
+
+    Ok(())
+}
@@ -0,0 +1,378 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Support for recreating a baseline JPEG with optimal Huffman tables (like jpegtran -optimize)
+//! instead of the tables of the original file. The coefficients are untouched, so the resulting
+//! image decodes to exactly the same pixels, but the file is no longer a byte exact copy.
+
+use anyhow::Result;
+
+use crate::consts::{JPegDecodeStatus, JPegType};
+use crate::helpers::{b_short, err_exit_code, u16_bit_length};
+use crate::jpeg_code;
+use crate::lepton_error::ExitCode;
+
+use super::block_based_image::BlockBasedImage;
+use super::jpeg_header::JPegHeader;
+use super::jpeg_position_state::JpegPositionState;
+
+/// number of times each symbol is used per Huffman table, indexed by [dc=0/ac=1][table][symbol]
+pub struct HuffmanFrequencies {
+    counts: [[[u32; 256]; 4]; 2],
+}
+
+impl HuffmanFrequencies {
+    /// walks the scan in the same order as jpeg_write and counts the DC and AC symbols that each
+    /// Huffman table will have to encode. Only supports baseline images.
+    pub fn collect(framebuffer: &[BlockBasedImage], jf: &JPegHeader) -> Result<Self> {
+        if jf.jpeg_type != JPegType::Sequential {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "huffman table optimization is only supported for baseline images",
+            );
+        }
+
+        let mut f = HuffmanFrequencies {
+            counts: [[[0; 256]; 4]; 2],
+        };
+
+        let mut state = JpegPositionState::new(jf, 0);
+        let mut last_dc = [0i16; 4];
+
+        loop {
+            let cmp = state.get_cmp();
+            let block = framebuffer[cmp].get_block(state.get_dpos());
+
+            // diff coding for dc
+            let dc = block.get_coefficient_zigzag(0);
+            let diff = dc.wrapping_sub(last_dc[cmp]);
+            last_dc[cmp] = dc;
+
+            let dc_table = usize::from(jf.cmp_info[cmp].huff_dc);
+            let ac_table = usize::from(jf.cmp_info[cmp].huff_ac);
+
+            f.counts[0][dc_table][usize::from(u16_bit_length(diff.unsigned_abs()))] += 1;
+
+            // same run length logic as encode_block_seq
+            let mut z = 0;
+            for bpos in 1..64 {
+                let coef = block.get_coefficient_zigzag(bpos);
+                if coef == 0 {
+                    z += 1;
+                    continue;
+                }
+
+                while z >= 16 {
+                    f.counts[1][ac_table][0xF0] += 1;
+                    z -= 16;
+                }
+
+                let symbol = (z << 4) | usize::from(u16_bit_length(coef.unsigned_abs()));
+                f.counts[1][ac_table][symbol] += 1;
+                z = 0;
+            }
+
+            if z != 0 {
+                f.counts[1][ac_table][0x00] += 1;
+            }
+
+            match state.next_mcu_pos(jf) {
+                JPegDecodeStatus::ScanCompleted => break,
+                JPegDecodeStatus::RestartIntervalExpired => {
+                    last_dc = [0; 4];
+                    state.reset_rstw(jf);
+                }
+                JPegDecodeStatus::DecodeInProgress => {}
+            }
+        }
+
+        Ok(f)
+    }
+
+    /// builds the contents of a DHT segment containing optimal tables for every table used by the current scan
+    fn build_dht_segment(&self, jf: &JPegHeader) -> Result<Vec<u8>> {
+        let mut used = [[false; 4]; 2];
+        for i in 0..jf.cs_cmpc {
+            let ci = &jf.cmp_info[jf.cs_cmp[i]];
+            used[0][usize::from(ci.huff_dc)] = true;
+            used[1][usize::from(ci.huff_ac)] = true;
+        }
+
+        let mut segment = Vec::new();
+        for (class, used_tables) in used.iter().enumerate() {
+            for (table, is_used) in used_tables.iter().enumerate() {
+                if *is_used {
+                    let (bits, values) = gen_optimal_table(&self.counts[class][table])?;
+
+                    segment.push(((class as u8) << 4) | table as u8);
+                    segment.extend_from_slice(&bits);
+                    segment.extend_from_slice(&values[..]);
+                }
+            }
+        }
+
+        Ok(segment)
+    }
+
+    /// takes the raw JPEG header (the part that leads up to and includes the SOS) and returns a copy of it
+    /// where all the DHT segments are dropped and replaced by a single DHT segment with the optimal
+    /// tables right before the SOS. All other segments are kept as they are.
+    pub fn replace_huffman_tables(&self, raw_header: &[u8], jf: &JPegHeader) -> Result<Vec<u8>> {
+        let dht = self.build_dht_segment(jf)?;
+
+        let mut result = Vec::with_capacity(raw_header.len() + dht.len());
+
+        let mut pos = 0;
+        while pos + 4 <= raw_header.len() {
+            let marker = raw_header[pos + 1];
+            let end = pos + 2 + usize::from(b_short(raw_header[pos + 2], raw_header[pos + 3]));
+
+            if raw_header[pos] != 0xff || end > raw_header.len() {
+                return err_exit_code(
+                    ExitCode::UnsupportedJpeg,
+                    "invalid segment found while replacing huffman tables",
+                );
+            }
+
+            if marker == jpeg_code::SOS {
+                result.extend_from_slice(&[0xff, jpeg_code::DHT]);
+                result.extend_from_slice(&((dht.len() + 2) as u16).to_be_bytes());
+                result.extend_from_slice(&dht[..]);
+
+                // everything from here on is left as is
+                result.extend_from_slice(&raw_header[pos..]);
+                return Ok(result);
+            }
+
+            if marker != jpeg_code::DHT {
+                result.extend_from_slice(&raw_header[pos..end]);
+            }
+
+            pos = end;
+        }
+
+        err_exit_code(
+            ExitCode::UnsupportedJpeg,
+            "no SOS found while replacing huffman tables",
+        )
+    }
+}
+
+/// generates an optimal length-limited Huffman table for the given symbol frequencies as described in
+/// section K.2 of the JPEG spec (the same algorithm used by libjpeg). Returns the number of codes of each
+/// length from 1 to 16, followed by the symbols sorted by code length.
+fn gen_optimal_table(counts: &[u32; 256]) -> Result<([u8; 16], Vec<u8>)> {
+    let mut freq = [0u64; 257];
+    for (f, &c) in freq.iter_mut().zip(counts) {
+        *f = u64::from(c);
+    }
+
+    // reserve one code point so that no real symbol gets a code of all 1 bits
+    freq[256] = 1;
+
+    let mut codesize = [0usize; 257];
+    let mut others = [-1i32; 257];
+
+    loop {
+        // find the smallest nonzero frequency, preferring the largest symbol on ties
+        let mut c1 = None;
+        let mut v = u64::MAX;
+        for (i, &f) in freq.iter().enumerate() {
+            if f != 0 && f <= v {
+                v = f;
+                c1 = Some(i);
+            }
+        }
+
+        // find the next smallest nonzero frequency
+        let mut c2 = None;
+        v = u64::MAX;
+        for (i, &f) in freq.iter().enumerate() {
+            if f != 0 && f <= v && Some(i) != c1 {
+                v = f;
+                c2 = Some(i);
+            }
+        }
+
+        let (mut c1, mut c2) = match (c1, c2) {
+            (Some(c1), Some(c2)) => (c1, c2),
+            _ => break, // done if we've merged everything into one frequency
+        };
+
+        freq[c1] += freq[c2];
+        freq[c2] = 0;
+
+        // increment the codesize of everything in c1's tree branch
+        codesize[c1] += 1;
+        while others[c1] >= 0 {
+            c1 = others[c1] as usize;
+            codesize[c1] += 1;
+        }
+
+        // chain c2 onto c1's tree branch
+        others[c1] = c2 as i32;
+
+        // increment the codesize of everything in c2's tree branch
+        codesize[c2] += 1;
+        while others[c2] >= 0 {
+            c2 = others[c2] as usize;
+            codesize[c2] += 1;
+        }
+    }
+
+    // count the number of symbols of each code length
+    let mut bits = [0u32; 33];
+    for i in 0..257 {
+        if codesize[i] != 0 {
+            if codesize[i] > 32 {
+                return err_exit_code(
+                    ExitCode::UnsupportedJpeg,
+                    "huffman code size table overflow",
+                );
+            }
+            bits[codesize[i]] += 1;
+        }
+    }
+
+    // limit code lengths to 16 bits by moving pairs of symbols up the tree (K.2 figure K.3)
+    for i in (17..=32).rev() {
+        while bits[i] > 0 {
+            let mut j = i - 2;
+            while bits[j] == 0 {
+                j -= 1;
+            }
+
+            bits[i] -= 2;
+            bits[i - 1] += 1;
+            bits[j + 1] += 2;
+            bits[j] -= 1;
+        }
+    }
+
+    // remove the reserved code point from the longest code length
+    let mut i = 16;
+    while bits[i] == 0 {
+        i -= 1;
+    }
+    bits[i] -= 1;
+
+    let mut huffbits = [0u8; 16];
+    for i in 0..16 {
+        huffbits[i] = bits[i + 1] as u8;
+    }
+
+    // symbols sorted by code length, the order of codes with the same length doesn't matter
+    let mut values = Vec::new();
+    for len in 1..=32 {
+        for (symbol, size) in codesize[0..256].iter().enumerate() {
+            if *size == len {
+                values.push(symbol as u8);
+            }
+        }
+    }
+
+    Ok((huffbits, values))
+}
+
+#[cfg(test)]
+use std::io::Cursor;
+
+#[cfg(test)]
+use super::lepton_format::{decode_lepton_wrapper_optimize_huffman, read_jpeg};
+
+#[cfg(test)]
+use crate::enabled_features::EnabledFeatures;
+
+/// every code must fit within 16 bits, even for very skewed frequencies that would naturally generate longer codes
+#[test]
+fn optimal_table_is_length_limited() {
+    let mut counts = [0u32; 256];
+    for (i, c) in counts.iter_mut().take(28).enumerate() {
+        *c = 1 << i;
+    }
+
+    let (bits, values) = gen_optimal_table(&counts).unwrap();
+
+    assert_eq!(values.len(), 28);
+    assert_eq!(bits.iter().map(|x| usize::from(*x)).sum::<usize>(), 28);
+
+    // kraft inequality must leave room for the reserved all-ones code
+    let kraft: u32 = (0..16).map(|i| u32::from(bits[i]) << (15 - i)).sum();
+    assert!(kraft < 1 << 16);
+}
+
+/// recreating with optimized tables must shrink the file but keep exactly the same coefficients
+#[test]
+fn optimized_huffman_keeps_coefficients() {
+    for file in [
+        "android",
+        "iphonecrop",
+        "trailingrst",
+        "out_of_order_dqt",
+        "colorswap",
+    ] {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+        let lepton = std::fs::read(path.join(file.to_owned() + ".lep")).unwrap();
+
+        let mut optimized = Vec::new();
+        decode_lepton_wrapper_optimize_huffman(&mut Cursor::new(&lepton), &mut optimized, 8)
+            .unwrap_or_else(|e| panic!("{file} failed: {e:?}"));
+
+        assert!(optimized.len() < original.len());
+
+        let (_, original_blocks) = read_jpeg(
+            &mut Cursor::new(&original),
+            &EnabledFeatures::all(),
+            8,
+            |_| {},
+        )
+        .unwrap();
+        let (_, optimized_blocks) = read_jpeg(
+            &mut Cursor::new(&optimized),
+            &EnabledFeatures::all(),
+            8,
+            |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(original_blocks.len(), optimized_blocks.len());
+        for (a, b) in original_blocks.iter().zip(optimized_blocks.iter()) {
+            let num_blocks = a.get_block_width() * a.get_original_height();
+            assert_eq!(num_blocks, b.get_block_width() * b.get_original_height());
+
+            for dpos in 0..num_blocks {
+                assert_eq!(
+                    a.get_block(dpos).get_block(),
+                    b.get_block(dpos).get_block(),
+                    "{file} coefficients differ at {dpos}"
+                );
+            }
+        }
+    }
+}
+
+/// progressive and truncated images can't be recreated with optimized tables
+#[test]
+fn optimized_huffman_unsupported() {
+    for file in ["androidprogressive", "narrowrst"] {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+        let lepton = std::fs::read(path.join(file.to_owned() + ".lep")).unwrap();
+
+        let mut optimized = Vec::new();
+        let e =
+            decode_lepton_wrapper_optimize_huffman(&mut Cursor::new(&lepton), &mut optimized, 8)
+                .unwrap_err();
+
+        assert_eq!(
+            e.root_cause()
+                .downcast_ref::<crate::lepton_error::LeptonError>()
+                .unwrap()
+                .exit_code,
+            ExitCode::UnsupportedJpeg
+        );
+    }
+}
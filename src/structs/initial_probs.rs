@@ -0,0 +1,282 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use crate::enabled_features::LeptonVersion;
+use crate::structs::model::Model;
+use crate::structs::trained_initial_counts::TRAINED_INITIAL_COUNTS;
+
+/// how the branches of the model of each thread segment start out, which the version of the file decides so that
+/// files keep decoding with the initialization they were encoded with when the defaults change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelInit {
+    /// every branch starts with one true and one false observation, or the initial counts of the tuning
+    Uniform,
+    /// the branches that TRAINED_INITIAL_COUNTS lists start with its counts, see load_trained_initial_counts
+    TrainedV7,
+}
+
+impl ModelInit {
+    /// the initialization of the files of version. New initial counts or new sizes of the tables of the model
+    /// need a new version with its own initialization here, while the older versions keep theirs. A version that
+    /// only adds to the container has to keep the initialization of the version before it, since the encoder
+    /// picks the initialization before it knows all the features of the file.
+    pub fn for_version(version: LeptonVersion) -> ModelInit {
+        match version {
+            LeptonVersion::V1
+            | LeptonVersion::V2
+            | LeptonVersion::V3
+            | LeptonVersion::V4
+            | LeptonVersion::V5
+            | LeptonVersion::V6 => ModelInit::Uniform,
+            LeptonVersion::V7 => ModelInit::TrainedV7,
+        }
+    }
+
+    /// the initialization of a file with required_features, see LeptonVersion::of_features
+    pub fn for_features(required_features: u32) -> ModelInit {
+        ModelInit::for_version(LeptonVersion::of_features(required_features))
+    }
+
+    /// starts the branches of a new model the way this initialization does
+    pub fn apply(self, model: &mut Model) {
+        match self {
+            ModelInit::Uniform => {}
+            ModelInit::TrainedV7 => load_trained_initial_counts(model),
+        }
+    }
+}
+
+/// starts the branches of model with the counts of the trained table instead of one true and one false
+/// observation. The branches that the table doesn't list keep their counts.
+pub fn load_trained_initial_counts(model: &mut Model) {
+    // the counts were checked when the table was created, see trained_table_is_valid
+    model.load_counts(&TRAINED_INITIAL_COUNTS);
+}
+
+#[cfg(feature = "train-initial-probs")]
+pub mod training {
+    use std::fmt::Write;
+    use std::io::Cursor;
+
+    use anyhow::{Context, Result};
+    use default_boxed::DefaultBoxed;
+
+    use crate::enabled_features::{CoefficientCodecKind, EnabledFeatures};
+    use crate::helpers::*;
+    use crate::lepton_error::ExitCode;
+    use crate::structs::lepton_encoder::lepton_encode_row_range;
+    use crate::structs::lepton_format::{get_quantization_tables, read_jpeg};
+    use crate::structs::model::Model;
+    use crate::structs::probability_tables_set::ProbabilityTablesSet;
+
+    /// largest count that a trained branch starts with. A branch that is as confident as the training images would
+    /// make it is slow to adapt to an image that is different, so the counts are scaled down to this.
+    const TRAINED_MAX_COUNT: f64 = 4.0;
+
+    /// smallest sum of the counts that the training images leave a branch with for it to be in the table. The
+    /// branches that only a few decisions were coded with don't say much and would only make the table bigger.
+    const TRAINED_MIN_COUNT: f64 = 64.0;
+
+    /// the initial counts for a branch that the training images left with false_count and true_count in total,
+    /// keeping their ratio but scaled down to at most TRAINED_MAX_COUNT, or None if there are too few of them or they
+    /// round to the uniform counts
+    fn trained_counts(false_count: f64, true_count: f64) -> Option<u16> {
+        if false_count + true_count < TRAINED_MIN_COUNT {
+            return None;
+        }
+
+        let max = false_count.max(true_count);
+        let scale = |c: f64| ((c * TRAINED_MAX_COUNT / max).round() as u16).clamp(1, 255);
+        let counts = (scale(false_count) << 8) | scale(true_count);
+
+        if counts == 0x0101 {
+            None
+        } else {
+            Some(counts)
+        }
+    }
+
+    /// encodes each of the JPEGs with a fresh model and adds up the counts that each branch ends up with over
+    /// all of them, and returns the Rust source of a table of initial counts with the same ratios, listing the
+    /// branches that don't end up uniform in the order of Model::for_each_branch. The source replaces
+    /// src/structs/trained_initial_counts.rs.
+    pub fn train_initial_probs_wrapper(jpegs: &[&[u8]]) -> Result<String> {
+        if jpegs.is_empty() {
+            return err_exit_code(
+                ExitCode::SyntaxError,
+                "no images to train the initial probabilities on",
+            );
+        }
+
+        let pts = ProbabilityTablesSet::new(false, false);
+        let mut sums: Vec<(f64, f64)> = Vec::new();
+
+        for jpeg in jpegs {
+            let (lp, image_data) = read_jpeg(
+                &mut Cursor::new(jpeg),
+                &EnabledFeatures::default(),
+                1,
+                |_jh| {},
+            )
+            .context(here!())?;
+
+            let quantization_tables = get_quantization_tables(&lp.jpeg_header).context(here!())?;
+
+            let mut model = Model::default_boxed();
+            lepton_encode_row_range(
+                &pts,
+                &quantization_tables,
+                &image_data,
+                &mut std::io::sink(),
+                0,
+                &lp.truncate_components,
+                0,
+                lp.jpeg_header.cmp_info[0].bcv,
+                true,
+                true,
+                &mut model,
+                None,
+                CoefficientCodecKind::Model,
+            )
+            .context(here!())?;
+
+            let mut index = 0;
+            model.for_each_branch(&mut |b| {
+                if index == sums.len() {
+                    sums.push((0.0, 0.0));
+                }
+
+                // the branches that the image didn't use don't say anything about it
+                let counts = b.get_counts();
+                if counts != 0x0101 {
+                    // the all trues corner case stands for one false and 255 trues
+                    let (false_count, true_count) = if counts == 0x00ff {
+                        (1, 255)
+                    } else {
+                        (counts >> 8, counts & 0xff)
+                    };
+
+                    sums[index].0 += f64::from(false_count);
+                    sums[index].1 += f64::from(true_count);
+                }
+                index += 1;
+            });
+        }
+
+        let table: Vec<(usize, u16)> = sums
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (f, t))| trained_counts(*f, *t).map(|c| (i, c)))
+            .collect();
+
+        let mut source = String::new();
+        writeln!(
+            source,
+            "{0}",
+            r"/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/"
+        )?;
+        writeln!(source)?;
+        writeln!(
+            source,
+            "// Generated by train_initial_probs from {0} images, do not edit.",
+            jpegs.len()
+        )?;
+        writeln!(source)?;
+        writeln!(
+            source,
+            "/// the index of each branch in the order of Model::for_each_branch with its initial counts, see"
+        )?;
+        writeln!(source, "/// load_trained_initial_counts")?;
+        writeln!(source, "#[rustfmt::skip]")?;
+        writeln!(
+            source,
+            "pub static TRAINED_INITIAL_COUNTS: [(u32, u16); {0}] = [",
+            table.len()
+        )?;
+        for line in table.chunks(8) {
+            let entries: Vec<String> = line
+                .iter()
+                .map(|(i, c)| format!("({0}, 0x{1:04x})", i, c))
+                .collect();
+            writeln!(source, "    {0},", entries.join(", "))?;
+        }
+        writeln!(source, "];")?;
+
+        Ok(source)
+    }
+}
+
+/// the table lists each branch at most once, in the order of Model::for_each_branch, with counts that a branch
+/// can start with
+#[test]
+fn trained_table_is_valid() {
+    use crate::structs::branch::Branch;
+    use default_boxed::DefaultBoxed;
+
+    let mut model = Model::default_boxed();
+    let mut branch_count = 0;
+    model.for_each_branch(&mut |_b| branch_count += 1);
+
+    assert!(!TRAINED_INITIAL_COUNTS.is_empty());
+    for pair in TRAINED_INITIAL_COUNTS.windows(2) {
+        assert!(pair[0].0 < pair[1].0);
+    }
+    assert!(TRAINED_INITIAL_COUNTS.last().unwrap().0 < branch_count);
+
+    for (_, counts) in TRAINED_INITIAL_COUNTS.iter() {
+        assert_ne!(*counts, 0x0101);
+        assert!(Branch::new().set_counts(*counts));
+    }
+
+    // every branch of the table gets its counts and the others keep the uniform ones
+    let mut model = Model::default_boxed();
+    load_trained_initial_counts(&mut model);
+    let mut trained = TRAINED_INITIAL_COUNTS.iter().peekable();
+    let mut index = 0;
+    model.for_each_branch(&mut |b| {
+        match trained.next_if(|(i, _)| *i == index) {
+            Some((_, counts)) => assert_eq!(b.get_counts(), *counts),
+            None => assert_eq!(b.get_counts(), 0x0101),
+        }
+        index += 1;
+    });
+    assert!(trained.next().is_none());
+}
+
+/// a file gets the initialization of the oldest version that has its features, which only the trained initial
+/// probabilities change so far
+#[test]
+fn model_init_follows_version() {
+    use crate::consts::*;
+
+    for version in LeptonVersion::ALL {
+        assert_eq!(
+            LeptonVersion::of_features(version.supported_features()),
+            version
+        );
+        assert_eq!(
+            ModelInit::for_features(version.supported_features()),
+            ModelInit::for_version(version)
+        );
+    }
+
+    assert_eq!(LeptonVersion::of_features(0), LeptonVersion::V1);
+    assert_eq!(ModelInit::for_features(0), ModelInit::Uniform);
+    assert_eq!(
+        ModelInit::for_features(
+            LEPTON_FEATURE_SEGMENT_CHECKSUMS | LEPTON_FEATURE_SEPARATE_CHROMA_MODELS
+        ),
+        ModelInit::Uniform
+    );
+    assert_eq!(
+        ModelInit::for_features(LEPTON_FEATURE_TRAINED_INITIAL_PROBS),
+        ModelInit::TrainedV7
+    );
+}
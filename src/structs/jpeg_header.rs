@@ -34,7 +34,7 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use anyhow::{Context, Result};
 
-use std::io::Read;
+use std::io::{ErrorKind, Read};
 
 use crate::enabled_features::EnabledFeatures;
 use crate::helpers::*;
@@ -62,10 +62,21 @@ impl HuffCodes {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// the length in peek_code of a code that is longer than 8 bits, whose symbol is the index of the table in
+/// long_codes that the code continues in
+pub const LONG_CODE: u8 = 0xfe;
+
+/// the length in peek_code and long_codes of an invalid code, or of a code that the tables don't decode
+pub const NO_CODE: u8 = 0xff;
+
+#[derive(Clone, Debug)]
 pub struct HuffTree {
     pub node: [[u16; 2]; 256],
+    /// the symbol and the length of the code that the next 8 bits start with
     pub peek_code: [(u8, u8); 256],
+    /// a table of 256 entries for each of the first 8 bits of codes that are longer than 8 bits, with the symbol and
+    /// the length of the code that the 8 bits after them complete
+    pub long_codes: Vec<(u8, u8)>,
 }
 
 impl HuffTree {
@@ -73,11 +84,18 @@ impl HuffTree {
         HuffTree {
             node: [[0; 2]; 256],
             peek_code: [(0, 0); 256],
+            long_codes: Vec::new(),
         }
     }
+
+    /// the symbol and the length of the code that continues in table of long_codes with the 8 bits of next
+    #[inline(always)]
+    pub fn long_code(&self, table: u8, next: u8) -> (u8, u8) {
+        self.long_codes[usize::from(table) * 256 + usize::from(next)]
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct JPegHeader {
     pub q_tables: [[u16; 64]; 4],     // quantization tables 4 x 64
     h_codes: [[HuffCodes; 4]; 2],     // huffman codes (access via get_huff_xx_codes)
@@ -117,7 +135,7 @@ impl JPegHeader {
         return JPegHeader {
             q_tables: [[0; 64]; 4],
             h_codes: [[HuffCodes::new(); 4]; 2],
-            h_trees: [[HuffTree::new(); 4]; 2],
+            h_trees: [(); 2].map(|_| [(); 4].map(|_| HuffTree::new())),
             ht_set: [[0; 4]; 2],
             cmp_info: [
                 ComponentInfo::new(),
@@ -166,10 +184,24 @@ impl JPegHeader {
         reader: &mut R,
         enabled_features: &EnabledFeatures,
     ) -> Result<bool> {
+        self.parse_at(reader, enabled_features, 0)
+    }
+
+    /// Parses header for imageinfo, where start_offset is the position of the reader
+    /// within the file so that errors point at the right place
+    pub fn parse_at<R: Read>(
+        &mut self,
+        reader: &mut R,
+        enabled_features: &EnabledFeatures,
+        start_offset: usize,
+    ) -> Result<bool> {
+        // offset within the file we are parsing, used for error reporting
+        let mut offset = start_offset;
+
         // header parser loop
         loop {
             match self
-                .parse_next_segment(reader, enabled_features)
+                .parse_next_segment(reader, enabled_features, &mut offset)
                 .context(crate::helpers::here!())?
             {
                 ParseSegmentResult::EOI => {
@@ -214,8 +246,10 @@ impl JPegHeader {
             }
         }
 
-        self.mcuv = (1.0 * self.img_height as f64 / (8.0 * self.sfhm as f64)).ceil() as i32;
-        self.mcuh = (1.0 * self.img_width as f64 / (8.0 * self.sfvm as f64)).ceil() as i32;
+        // integer rounding so that the edge MCUs (and the padding blocks they contain) are counted
+        // exactly the same way libjpeg does for every sampling geometry
+        self.mcuv = div_round_up(self.img_height, 8 * self.sfhm);
+        self.mcuh = div_round_up(self.img_width, 8 * self.sfvm);
         self.mcuc = self.mcuv * self.mcuh;
 
         for cmp in 0..self.cmpc {
@@ -223,14 +257,12 @@ impl JPegHeader {
             self.cmp_info[cmp].bcv = self.mcuv * self.cmp_info[cmp].sfh;
             self.cmp_info[cmp].bch = self.mcuh * self.cmp_info[cmp].sfv;
             self.cmp_info[cmp].bc = self.cmp_info[cmp].bcv * self.cmp_info[cmp].bch;
-            self.cmp_info[cmp].ncv = (1.0
-                * self.img_height as f64
-                * (self.cmp_info[cmp].sfh as f64 / (8.0 * self.sfhm as f64)))
-                .ceil() as i32;
-            self.cmp_info[cmp].nch = (1.0
-                * self.img_width as f64
-                * (self.cmp_info[cmp].sfv as f64 / (8.0 * self.sfvm as f64)))
-                .ceil() as i32;
+
+            // non-interleaved scans only code the blocks that actually cover the component
+            self.cmp_info[cmp].ncv =
+                div_round_up(self.img_height * self.cmp_info[cmp].sfh, 8 * self.sfhm);
+            self.cmp_info[cmp].nch =
+                div_round_up(self.img_width * self.cmp_info[cmp].sfv, 8 * self.sfvm);
             self.cmp_info[cmp].nc = self.cmp_info[cmp].ncv * self.cmp_info[cmp].nch;
         }
 
@@ -274,6 +306,7 @@ impl JPegHeader {
         &mut self,
         reader: &mut R,
         enabled_features: &EnabledFeatures,
+        offset: &mut usize,
     ) -> Result<ParseSegmentResult> {
         let mut header = [0u8; 4];
 
@@ -283,34 +316,47 @@ impl JPegHeader {
         }
 
         if header[0] != 0xff {
-            return err_exit_code(ExitCode::UnsupportedJpeg, "invalid header encountered");
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                format!("invalid header encountered at offset {0}", *offset).as_str(),
+            );
         }
 
-        reader.read_exact(&mut header[1..2]).context(here!())?;
+        read_segment_bytes(reader, &mut header[1..2], 0, *offset)?;
         if header[1] == jpeg_code::EOI {
+            *offset += 2;
             return Ok(ParseSegmentResult::EOI);
         }
 
         // now read the second two bytes so we can get the size of the segment
-        reader.read_exact(&mut header[2..]).context(here!())?;
-
-        let mut segment_data = Vec::new();
+        let btype = header[1];
+        read_segment_bytes(reader, &mut header[2..], btype, *offset)?;
 
+        // the length includes the two bytes of the length field itself, so anything
+        // smaller than that is corrupt and would underflow when we subtract it out
         let segment_size = b_short(header[2], header[3]);
         if segment_size < 2 {
-            return err_exit_code(ExitCode::UnsupportedJpeg, "segment is too short");
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                format!(
+                    "segment FF{0:X} at offset {1} has invalid length {2}",
+                    btype, *offset, segment_size
+                )
+                .as_str(),
+            );
         }
 
-        segment_data.resize(usize::from(segment_size) - 2, 0);
+        let mut segment_data = vec![0; usize::from(segment_size) - 2];
 
-        reader.read_exact(&mut segment_data).context(here!())?;
+        read_segment_bytes(reader, &mut segment_data, btype, *offset)?;
+
+        *offset += usize::from(segment_size) + 2;
 
         let mut hpos = 0;
         let len = segment_data.len();
 
         let segment = &segment_data[..];
 
-        let btype = header[1];
         match btype
         {
             jpeg_code::DHT => // DHT segment
@@ -477,6 +523,12 @@ impl JPegHeader {
                     return err_exit_code(ExitCode::UnsupportedJpeg, "successive approximation parameter out of range");
                 }
 
+                // a longer segment would swallow the start of the scan data, which means the length is corrupt
+                if hpos + 3 != len
+                {
+                    return err_exit_code(ExitCode::UnsupportedJpeg, "size mismatch in sos marker");
+                }
+
                 return Ok(ParseSegmentResult::SOS);
             }
 
@@ -827,12 +879,35 @@ impl JPegHeader {
                 len += 1;
             }
 
-            if node == 0xffff || node < 256 {
-                // invalid code or code was too long to fit, so just say it requireds 256 bits
-                // so we will take the long path to decode it
-                ht.peek_code[peekbyte as usize] = (0, 0xff);
+            if node == 0xffff {
+                // invalid code, so just say it requires 256 bits so we will take the long path to report it
+                ht.peek_code[peekbyte] = (0, NO_CODE);
+            } else if node < 256 {
+                // the code is too long to fit, so it continues in a second table for the 8 bits after these
+                ht.peek_code[peekbyte] = ((ht.long_codes.len() / 256) as u8, LONG_CODE);
+
+                for nextbyte in 0..256 {
+                    let mut long_node = node;
+                    let mut long_len = len;
+
+                    while long_node < 256 && long_len <= 15 {
+                        long_node =
+                            ht.node[usize::from(long_node)][(nextbyte >> (15 - long_len)) & 0x1];
+
+                        long_len += 1;
+                    }
+
+                    // codes that are invalid or longer than 16 bits, which only a broken table has, are left to
+                    // the long path
+                    let long_code = if long_node == 0xffff || long_node < 256 {
+                        (0, NO_CODE)
+                    } else {
+                        ((long_node - 256) as u8, long_len)
+                    };
+                    ht.long_codes.push(long_code);
+                }
             } else {
-                ht.peek_code[peekbyte as usize] = ((node - 256) as u8, len);
+                ht.peek_code[peekbyte] = ((node - 256) as u8, len);
             }
         }
 
@@ -840,6 +915,32 @@ impl JPegHeader {
     }
 }
 
+/// reads the bytes of a segment, turning a premature end of file into a well-defined error
+/// that includes the marker and offset rather than a generic IO failure
+fn read_segment_bytes<R: Read>(
+    reader: &mut R,
+    buffer: &mut [u8],
+    marker: u8,
+    offset: usize,
+) -> Result<()> {
+    match reader.read_exact(buffer) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => err_exit_code(
+            ExitCode::UnsupportedJpeg,
+            format!(
+                "segment FF{0:X} at offset {1} extends past end of file",
+                marker, offset
+            )
+            .as_str(),
+        ),
+        Err(e) => Err(anyhow::Error::new(e).context(here!())),
+    }
+}
+
+fn div_round_up(value: i32, divisor: i32) -> i32 {
+    (value + divisor - 1) / divisor
+}
+
 fn ensure_space(segment: &[u8], hpos: usize, amount: usize) -> Result<()> {
     if hpos + amount > segment.len() {
         return err_exit_code(ExitCode::UnsupportedJpeg, "SOF too small");
@@ -847,3 +948,112 @@ fn ensure_space(segment: &[u8], hpos: usize, amount: usize) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+use std::io::Cursor;
+
+#[cfg(test)]
+fn parse_header_error(data: &[u8]) -> ExitCode {
+    let mut header = JPegHeader::new();
+    let e = header
+        .parse(&mut Cursor::new(data), &EnabledFeatures::all())
+        .unwrap_err();
+
+    e.root_cause()
+        .downcast_ref::<crate::lepton_error::LeptonError>()
+        .unwrap()
+        .exit_code
+}
+
+// segment lengths smaller than the length field itself are rejected rather than underflowing
+#[test]
+fn segment_length_too_short() {
+    assert_eq!(
+        parse_header_error(&[0xff, 0xe0, 0x00, 0x00]),
+        ExitCode::UnsupportedJpeg
+    );
+    assert_eq!(
+        parse_header_error(&[0xff, 0xe0, 0x00, 0x01]),
+        ExitCode::UnsupportedJpeg
+    );
+}
+
+// segment lengths that point past the end of the input are reported as a bad jpeg
+#[test]
+fn segment_length_past_eof() {
+    assert_eq!(
+        parse_header_error(&[0xff, 0xe0, 0x00, 0x10, 0x4a, 0x46]),
+        ExitCode::UnsupportedJpeg
+    );
+    assert_eq!(
+        parse_header_error(&[0xff, 0xe0, 0x00]),
+        ExitCode::UnsupportedJpeg
+    );
+}
+
+// errors point at the segment by its position in the file rather than in the part that is being parsed
+#[test]
+fn segment_error_reports_file_offset() {
+    let mut data = vec![0xff, 0xe0, 0x00, 0x04, 0x00, 0x00];
+    data.extend_from_slice(&[0xff, 0xe1, 0x00, 0x10]);
+
+    let mut header = JPegHeader::new();
+    let e = header
+        .parse_at(&mut Cursor::new(data), &EnabledFeatures::all(), 100)
+        .unwrap_err();
+
+    let message = e.root_cause().to_string();
+    assert!(message.contains("FFE1 at offset 106"), "{0}", message);
+}
+
+/// a DHT segment that defines the table of class and id with counts codes of each length for the symbols
+#[cfg(test)]
+pub fn dht_segment(class_and_id: u8, counts: &[u8; 16], symbols: &[u8]) -> Vec<u8> {
+    let len = 2 + 1 + 16 + symbols.len();
+
+    let mut segment = vec![0xff, 0xc4, (len >> 8) as u8, len as u8, class_and_id];
+    segment.extend_from_slice(counts);
+    segment.extend_from_slice(symbols);
+    segment
+}
+
+// a table that is defined again replaces the lookup tables of the one before instead of adding to them
+#[test]
+fn redefined_huffman_table_is_rebuilt() {
+    // a table with codes of up to 12 bits, and one with all its codes in the first 8 bits
+    let long = dht_segment(
+        0x10,
+        &[0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 0, 0],
+        &(0..36).collect::<Vec<u8>>(),
+    );
+    let short = dht_segment(
+        0x10,
+        &[0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 2, 3, 4, 5, 6, 7],
+    );
+
+    let parse = |segments: &[&[u8]]| {
+        let mut data = segments.concat();
+        data.extend_from_slice(&[0xff, 0xd9]);
+
+        let mut header = JPegHeader::new();
+        header
+            .parse(&mut Cursor::new(data), &EnabledFeatures::all())
+            .unwrap();
+        header.cmp_info[0].huff_ac = 0;
+        header
+    };
+
+    let first = parse(&[&long]);
+    assert!(!first.get_huff_ac_tree(0).long_codes.is_empty());
+
+    for (redefined, only) in [
+        (parse(&[&long, &short]), parse(&[&short])),
+        (parse(&[&short, &long]), parse(&[&long])),
+    ] {
+        let (redefined, only) = (redefined.get_huff_ac_tree(0), only.get_huff_ac_tree(0));
+        assert_eq!(redefined.node, only.node);
+        assert_eq!(redefined.peek_code, only.peek_code);
+        assert_eq!(redefined.long_codes, only.long_codes);
+    }
+}
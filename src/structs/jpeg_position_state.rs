@@ -49,23 +49,36 @@ impl JpegPositionState {
         let cmp = jf.cs_cmp[0];
         let mcumul = jf.cmp_info[cmp].sfv * jf.cmp_info[cmp].sfh;
 
-        let state = JpegPositionState {
+        let mut state = JpegPositionState {
             cmp,
             mcu,
             csc: 0,
             sub: 0,
             dpos: mcu * mcumul,
-            rstw: if jf.rsti != 0 {
-                jf.rsti - (mcu % jf.rsti)
-            } else {
-                0
-            },
+            rstw: 0,
             eobrun: 0,
             prev_eobrun: 0,
         };
+
+        if jf.rsti != 0 {
+            state.rstw = jf.rsti - (state.get_restart_unit(jf) % jf.rsti);
+        }
+
         return state;
     }
 
+    /// returns the index of the unit that restart intervals are counted in. For interleaved scans
+    /// this is the MCU, but for non-interleaved scans every coded block counts as an MCU and the
+    /// blocks that only pad out the MCUs on the right and bottom edges aren't coded.
+    fn get_restart_unit(&self, jf: &JPegHeader) -> i32 {
+        if jf.cs_cmpc == 1 {
+            let cmp_info = &jf.cmp_info[self.cmp];
+            (self.dpos / cmp_info.bch) * cmp_info.nch + (self.dpos % cmp_info.bch)
+        } else {
+            self.mcu
+        }
+    }
+
     pub fn get_mcu(&self) -> i32 {
         self.mcu
     }
@@ -78,7 +91,7 @@ impl JpegPositionState {
 
     pub fn get_cumulative_reset_markers(&self, jf: &JPegHeader) -> i32 {
         if self.rstw != 0 {
-            self.get_mcu() / jf.rsti
+            self.get_restart_unit(jf) / jf.rsti
         } else {
             0
         }
@@ -34,9 +34,9 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use anyhow::{Context, Result};
 use std::cmp::{self, max};
-use std::io::Read;
+use std::io::{Read, Seek};
 
-use crate::helpers::here;
+use crate::helpers::{add_scan_position, here};
 
 use super::bit_reader::BitReader;
 use super::block_based_image::{AlignedBlock, BlockBasedImage};
@@ -48,14 +48,15 @@ use crate::lepton_error::ExitCode;
 use crate::consts::*;
 use crate::helpers::*;
 
-use super::jpeg_header::HuffTree;
+use super::jpeg_header::{HuffTree, LONG_CODE, NO_CODE};
 
-pub fn read_scan<R: Read>(
+pub fn read_scan<R: Read + Seek>(
     lp: &mut LeptonHeader,
     reader: &mut R,
     thread_handoff: &mut Vec<ThreadHandoff>,
     image_data: &mut [BlockBasedImage],
 ) -> Result<()> {
+    let scan_start = reader.stream_position()?;
     let mut bit_reader = BitReader::new(reader);
 
     // init variables for decoding
@@ -63,6 +64,9 @@ pub fn read_scan<R: Read>(
 
     let mut do_handoff = true;
 
+    // index of the current restart interval within the scan
+    let mut interval = 0;
+
     // JPEG imagedata decoding routines
     let mut sta = JPegDecodeStatus::DecodeInProgress;
     while sta != JPegDecodeStatus::ScanCompleted {
@@ -80,6 +84,7 @@ pub fn read_scan<R: Read>(
                 image_data,
                 &mut do_handoff,
             )
+            .map_err(|e| scan_error(e, scan_start, &bit_reader, &state))
             .context(here!())?;
         } else if jf.cs_to == 0 && jf.cs_sah == 0 {
             // only need DC
@@ -102,7 +107,8 @@ pub fn read_scan<R: Read>(
                 // ---> succesive approximation first stage <---
 
                 // diff coding & bitshifting for dc
-                let coef = read_dc(&mut bit_reader, jf.get_huff_dc_tree(state.get_cmp()))?;
+                let coef = read_dc(&mut bit_reader, jf.get_huff_dc_tree(state.get_cmp()))
+                    .map_err(|e| scan_error(e, scan_start, &bit_reader, &state))?;
 
                 let v = coef.wrapping_add(last_dc[state.get_cmp()]);
                 last_dc[state.get_cmp()] = v;
@@ -126,23 +132,45 @@ pub fn read_scan<R: Read>(
 
         // if we saw a pad bit at the end of the block, then remember whether they were 1s or 0s. This
         // will be used later on to reconstruct the padding
-        bit_reader
+        let fill_bits = bit_reader
             .read_and_verify_fill_bits(&mut lp.pad_bit)
             .context(here!())?;
+        lp.record_pad_bits(interval, fill_bits);
 
-        // verify that we got the right RST code here since the above should do 1 mcu.
-        // If we didn't then we won't re-encode the file binary identical so there's no point in continuing
+        // read the RST markers that end the interval, remembering them (and any segments stuck in between) if they
+        // weren't the expected one so we can write out exactly the same markers when recreating the file
         if sta == JPegDecodeStatus::RestartIntervalExpired {
-            bit_reader.verify_reset_code().context(here!())?;
+            let (markers, segments) = bit_reader.read_reset_codes().context(here!())?;
+            lp.record_restart_markers(interval, markers);
+            lp.record_scan_segments(interval, segments);
 
             sta = JPegDecodeStatus::DecodeInProgress;
         }
+
+        interval += 1;
     }
 
+    bit_reader.return_read_ahead().context(here!())?;
+
     lp.scnc += 1; // increment scan counter
     Ok(())
 }
 
+/// adds where the reader of the scan that starts at scan_start in the file has got to, and the MCU that state is
+/// at, to an error of decoding the scan, see add_scan_position
+fn scan_error<R: Read>(
+    e: anyhow::Error,
+    scan_start: u64,
+    bit_reader: &BitReader<R>,
+    state: &JpegPositionState,
+) -> anyhow::Error {
+    add_scan_position(
+        e,
+        scan_start * 8 + bit_reader.position_bits(),
+        state.get_mcu(),
+    )
+}
+
 /// stores handoff information in vector for the current position. This should
 /// be enough information to independently restart encoding at this offset (at least for baseline images)
 fn crystallize_thread_handoff<R: Read>(
@@ -173,7 +201,7 @@ fn crystallize_thread_handoff<R: Read>(
 }
 
 // reads subsequent scans for progressive images
-pub fn read_progressive_scan<R: Read>(
+pub fn read_progressive_scan<R: Read + Seek>(
     lp: &mut LeptonHeader,
     reader: &mut R,
     image_data: &mut [BlockBasedImage],
@@ -185,11 +213,15 @@ pub fn read_progressive_scan<R: Read>(
         max(lp.jpeg_header.cs_sal, lp.jpeg_header.cs_sah),
     );
 
+    let scan_start = reader.stream_position()?;
     let mut bit_reader = BitReader::new(reader);
 
     // init variables for decoding
     let mut state = JpegPositionState::new(&lp.jpeg_header, 0);
 
+    // index of the current restart interval within the scan
+    let mut interval = 0;
+
     // JPEG imagedata decoding routines
     let mut sta = JPegDecodeStatus::DecodeInProgress;
     while sta != JPegDecodeStatus::ScanCompleted {
@@ -268,6 +300,7 @@ pub fn read_progressive_scan<R: Read>(
                             jf.cs_from,
                             jf.cs_to,
                         )
+                        .map_err(|e| scan_error(e, scan_start, &bit_reader, &state))
                         .context(here!())?;
 
                         state
@@ -315,6 +348,7 @@ pub fn read_progressive_scan<R: Read>(
                             jf.cs_from,
                             jf.cs_to,
                         )
+                        .map_err(|e| scan_error(e, scan_start, &bit_reader, &state))
                         .context(here!())?;
 
                         state
@@ -332,6 +366,7 @@ pub fn read_progressive_scan<R: Read>(
                             jf.cs_from,
                             jf.cs_to,
                         )
+                        .map_err(|e| scan_error(e, scan_start, &bit_reader, &state))
                         .context(here!())?;
                     }
 
@@ -352,19 +387,26 @@ pub fn read_progressive_scan<R: Read>(
 
         // if we saw a pad bit at the end of the block, then remember whether they were 1s or 0s. This
         // will be used later on to reconstruct the padding
-        bit_reader
+        let fill_bits = bit_reader
             .read_and_verify_fill_bits(&mut lp.pad_bit)
             .context(here!())?;
+        lp.record_pad_bits(interval, fill_bits);
 
-        // verify that we got the right RST code here since the above should do 1 mcu.
-        // If we didn't then we won't re-encode the file binary identical so there's no point in continuing
+        // read the RST markers that end the interval, remembering them (and any segments stuck in between) if they
+        // weren't the expected one so we can write out exactly the same markers when recreating the file
         if sta == JPegDecodeStatus::RestartIntervalExpired {
-            bit_reader.verify_reset_code().context(here!())?;
+            let (markers, segments) = bit_reader.read_reset_codes().context(here!())?;
+            lp.record_restart_markers(interval, markers);
+            lp.record_scan_segments(interval, segments);
 
             sta = JPegDecodeStatus::DecodeInProgress;
         }
+
+        interval += 1;
     }
 
+    bit_reader.return_read_ahead().context(here!())?;
+
     lp.scnc += 1; // increment scan counter
     Ok(())
 }
@@ -524,6 +566,31 @@ fn next_huff_code<R: Read>(bit_reader: &mut BitReader<R>, ctree: &HuffTree) -> R
     }
 }
 
+/// Decodes the next Huffman code from BitReader when it is longer than 8 bits, with the table of long_codes that
+/// its first 8 bits lead to. Only the bytes that decoding the code a bit at a time would read are read into the
+/// register, so that the position of the stream stays the same.
+fn next_long_huff_code<R: Read>(
+    bit_reader: &mut BitReader<R>,
+    tree: &HuffTree,
+    table: u8,
+) -> Result<u8> {
+    loop {
+        // the bits after the first 8 can be past the ones that have been read, but then the code that they lead
+        // to is longer than the bits that have been read as well, since the first of them decide the code
+        let (peek_value, peek_len) = bit_reader.peek16();
+        let (code, code_len) = tree.long_code(table, peek_value as u8);
+
+        if code_len <= peek_len {
+            bit_reader.advance(code_len);
+            return Ok(code);
+        } else if code_len == NO_CODE {
+            return next_huff_code(bit_reader, tree);
+        }
+
+        bit_reader.fill_register(code_len)?;
+    }
+}
+
 fn read_dc<R: Read>(bit_reader: &mut BitReader<R>, tree: &HuffTree) -> Result<i16> {
     let (z, coef) = read_coef(bit_reader, tree)?.unwrap_or((0, 0));
     if z != 0 {
@@ -536,14 +603,10 @@ fn read_dc<R: Read>(bit_reader: &mut BitReader<R>, tree: &HuffTree) -> Result<i1
     }
 }
 
+/// Reads and decodes the next Huffman code from BitReader, with the lookup tables of the tree for the codes that
+/// they cover
 #[inline(always)]
-fn read_coef<R: Read>(
-    bit_reader: &mut BitReader<R>,
-    tree: &HuffTree,
-) -> Result<Option<(usize, i16)>> {
-    // if the code we found is smaller or equal to the number of bits left, take the shortcut
-    let hc;
-
+fn read_huff_code<R: Read>(bit_reader: &mut BitReader<R>, tree: &HuffTree) -> Result<u8> {
     loop {
         // peek ahead to see if we can decode the symbol immediately
         // given what has already been read into the bitreader
@@ -554,19 +617,28 @@ fn read_coef<R: Read>(
 
         if code_len <= peek_len {
             // found code directly, so advance by the number of bits immediately
-            hc = code;
             bit_reader.advance(code_len);
-            break;
+            return Ok(code);
         } else if peek_len < 8 {
             // peek code works with up to 8 bits at a time. If we had less
             // than this, then we need to read more bits into the bitreader
             bit_reader.fill_register(8)?;
+        } else if code_len == LONG_CODE {
+            // a code that is bigger than 8 bits continues in the table for the next 8 bits
+            return next_long_huff_code(bit_reader, tree, code);
         } else {
-            // take slow path since we have a code that is bigger than 8 bits (but pretty rare)
-            hc = next_huff_code(bit_reader, tree)?;
-            break;
+            // take slow path since the code is invalid
+            return next_huff_code(bit_reader, tree);
         }
     }
+}
+
+#[inline(always)]
+fn read_coef<R: Read>(
+    bit_reader: &mut BitReader<R>,
+    tree: &HuffTree,
+) -> Result<Option<(usize, i16)>> {
+    let hc = read_huff_code(bit_reader, tree)?;
 
     // analyse code
     if hc != 0 {
@@ -745,3 +817,215 @@ fn decode_eobrun_sa<R: Read>(
 fn decode_eobrun_bits(s: u8, n: u16) -> u16 {
     n + (1 << s)
 }
+
+/// the values of the fields of a location that add_scan_position or add_coefficients_position added to message,
+/// or of the location of an error like CoefficientOutOfRange
+#[cfg(test)]
+pub(crate) fn error_location_fields(message: &str, location: &str) -> Vec<i64> {
+    let fields = &message[message.find(location).unwrap() + location.len()..];
+    let fields = &fields[..fields.find('}').unwrap()];
+
+    fields
+        .split(',')
+        .map(|f| f.split(':').nth(1).unwrap().trim().parse().unwrap())
+        .collect()
+}
+
+/// an invalid Huffman code in the middle of a row of MCUs of the scan is reported with a position right after it
+/// was put in, in the same row
+#[test]
+fn corrupt_scan_reports_position() {
+    use crate::enabled_features::EnabledFeatures;
+    use crate::lepton_error::LeptonError;
+    use crate::structs::lepton_format::read_jpeg_rows;
+    use std::io::Cursor;
+
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("android.jpg"),
+    )
+    .unwrap();
+
+    let (lp, _, handoffs) = read_jpeg_rows(
+        &mut Cursor::new(&jpeg),
+        &EnabledFeatures::default(),
+        |_jh| {},
+    )
+    .unwrap();
+    let mcuh = lp.jpeg_header.mcuh;
+
+    for row in [1, handoffs.len() / 2, handoffs.len() - 2] {
+        let start = handoffs[row].segment_offset_in_file as usize;
+        let end = handoffs[row + 1].segment_offset_in_file as usize;
+
+        // 48 ones can't be decoded, since a code that started before them can end with at most 15 of them, the
+        // magnitude after it has at most 11 bits and no code is all ones
+        let mut offset = (start + end) / 2;
+        while jpeg[offset - 1] == 0xff {
+            offset += 1;
+        }
+        let mut corrupt = jpeg.clone();
+        for i in 0..6 {
+            corrupt[offset + i * 2..offset + i * 2 + 2].copy_from_slice(&[0xff, 0x00]);
+        }
+
+        let e = match read_jpeg_rows(
+            &mut Cursor::new(&corrupt),
+            &EnabledFeatures::default(),
+            |_jh| {},
+        ) {
+            Ok(_) => panic!("the corrupt scan at offset {0} was read", offset),
+            Err(e) => e,
+        };
+        let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+        assert_eq!(e.exit_code, ExitCode::UnsupportedJpeg);
+
+        let fields = error_location_fields(&e.message, "CorruptScan {");
+        let position = fields[0] * 8 + fields[1];
+        assert!(fields[1] < 8);
+        assert!(
+            position >= offset as i64 * 8 && position <= (offset as i64 + 12) * 8,
+            "{0} at offset {1}",
+            e.message,
+            offset
+        );
+        assert_eq!(fields[2] / i64::from(mcuh), row as i64, "{0}", e.message);
+    }
+}
+
+/// a random Huffman table as AC table 0 of a header, with more codes of the longer lengths, which can leave some
+/// codes invalid
+#[cfg(test)]
+fn header_with_random_table(rng: &mut rand::rngs::StdRng) -> super::jpeg_header::JPegHeader {
+    use super::jpeg_header::{dht_segment, JPegHeader};
+    use crate::enabled_features::EnabledFeatures;
+    use rand::Rng;
+    use std::io::Cursor;
+
+    loop {
+        let mut counts = [0u8; 16];
+        let mut available = 2u32;
+        let mut symbols = 0u32;
+        for (i, count) in counts.iter_mut().enumerate() {
+            // a code of all ones doesn't fit into 16 bits
+            let max = if i == 15 {
+                available.saturating_sub(1)
+            } else {
+                available
+            };
+            let max = max.min(256 - symbols).min(1 << (i / 2));
+            *count = rng.gen_range(0..=max) as u8;
+
+            symbols += u32::from(*count);
+            available = (available - u32::from(*count)) * 2;
+        }
+
+        let mut segment = dht_segment(
+            0x10,
+            &counts,
+            &(0..symbols).map(|s| s as u8).collect::<Vec<u8>>(),
+        );
+        segment.extend_from_slice(&[0xff, 0xd9]);
+
+        // the tree of a table with too many invalid codes doesn't fit into its nodes
+        let mut header = JPegHeader::new();
+        if header
+            .parse(&mut Cursor::new(segment), &EnabledFeatures::all())
+            .is_ok()
+        {
+            header.cmp_info[0].huff_ac = 0;
+            return header;
+        }
+    }
+}
+
+/// decodes the next Huffman code like read_huff_code did before the second level tables, by walking the tree a bit
+/// at a time for the codes that are longer than 8 bits
+#[cfg(test)]
+fn read_huff_code_reference<R: Read>(bit_reader: &mut BitReader<R>, tree: &HuffTree) -> Result<u8> {
+    loop {
+        let (peek_value, peek_len) = bit_reader.peek();
+        let (code, code_len) = tree.peek_code[peek_value as usize];
+
+        if code_len <= peek_len {
+            bit_reader.advance(code_len);
+            return Ok(code);
+        } else if peek_len < 8 {
+            bit_reader.fill_register(8)?;
+        } else {
+            return next_huff_code(bit_reader, tree);
+        }
+    }
+}
+
+/// decoding with the second level tables gives the same symbols, stream positions and errors as walking the tree
+/// a bit at a time, for streams of valid codes of random tables and for random data with invalid codes
+#[test]
+fn lookup_tables_match_tree_walk() {
+    use super::bit_reader::reader_observation;
+    use super::bit_writer::BitWriter;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use std::io::Cursor;
+
+    let mut rng = StdRng::from_seed([4u8; 32]);
+
+    for trial in 0..200 {
+        let header = header_with_random_table(&mut rng);
+        let tree = header.get_huff_ac_tree(0);
+        let codes = header.get_huff_ac_codes(0);
+
+        let valid: Vec<usize> = (0..256).filter(|&s| codes.c_len[s] > 0).collect();
+        if valid.is_empty() {
+            continue;
+        }
+
+        let data = if trial % 2 == 0 {
+            let mut writer = BitWriter::new();
+            for _ in 0..500 {
+                let s = valid[rng.gen_range(0..valid.len())];
+                writer.write(u32::from(codes.c_val[s]), u32::from(codes.c_len[s]));
+            }
+            writer.pad(0xff);
+
+            let mut data = Vec::new();
+            writer.flush_with_escape(&mut data).unwrap();
+            data
+        } else {
+            (0..200).map(|_| rng.gen_range(0..0xff)).collect()
+        };
+
+        let mut lookup = BitReader::new(Cursor::new(&data));
+        let mut walk = BitReader::new(Cursor::new(&data));
+
+        for _ in 0..1000 {
+            let lookup_state = reader_observation(
+                format!(
+                    "{0:?}",
+                    read_huff_code(&mut lookup, tree).map_err(|e| e.to_string())
+                ),
+                lookup.peek(),
+                lookup.get_stream_position(),
+                lookup.overhang(),
+                lookup.is_eof(),
+            );
+            let walk_state = reader_observation(
+                format!(
+                    "{0:?}",
+                    read_huff_code_reference(&mut walk, tree).map_err(|e| e.to_string())
+                ),
+                walk.peek(),
+                walk.get_stream_position(),
+                walk.overhang(),
+                walk.is_eof(),
+            );
+            assert_eq!(lookup_state, walk_state, "data {0:x?}", data);
+
+            if lookup_state.result.starts_with("Err") || lookup.is_eof() {
+                break;
+            }
+        }
+    }
+}
@@ -42,7 +42,7 @@ use crate::{
     lepton_error::ExitCode,
 };
 
-use std::{io::Write, num::NonZeroI16};
+use std::{io::Write, num::NonZeroI16, ops::Range};
 
 use super::{
     bit_writer::BitWriter, block_based_image::BlockBasedImage, jpeg_header::HuffCodes,
@@ -50,6 +50,10 @@ use super::{
     thread_handoff::ThreadHandoff,
 };
 
+/// amount of encoded scan data we accumulate before passing it on to the writer. Restart markers
+/// and the end of the scan always flush since they are written directly to the writer.
+const WRITE_CHUNK_SIZE: usize = 65536;
+
 // write a range of rows corresponding to the thread_handoff structure into the writer. Only works with baseline non-progressive images.
 pub fn jpeg_write_row_range<W: Write>(
     writer: &mut W,
@@ -100,10 +104,14 @@ pub fn jpeg_write_row_range<W: Write>(
             )
             .context(here!())?;
 
-            huffw.flush_with_escape(writer).context(here!())?;
+            huffw
+                .flush_with_escape_if_over(writer, WRITE_CHUNK_SIZE)
+                .context(here!())?;
         }
     }
 
+    huffw.flush_with_escape(writer).context(here!())?;
+
     Ok(())
 }
 
@@ -149,7 +157,9 @@ pub fn jpeg_write_entire_scan<W: Write>(
             )
             .context(here!())?;
 
-            huffw.flush_with_escape(writer).context(here!())?;
+            huffw
+                .flush_with_escape_if_over(writer, WRITE_CHUNK_SIZE)
+                .context(here!())?;
 
             if r {
                 break;
@@ -162,6 +172,180 @@ pub fn jpeg_write_entire_scan<W: Write>(
     Ok(())
 }
 
+/// state of the entropy coder at the boundary between two MCU rows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowBoundary {
+    /// DC value of the last block of each component, which the next block is coded relative to
+    pub last_dc: [i16; 4],
+    /// the partially written byte with the bits that haven't been written yet cleared
+    pub overhang_byte: u8,
+    pub num_overhang_bits: u8,
+}
+
+/// entropy coded data of a range of MCU rows along with the coder state at the start and end of the range
+#[derive(Debug)]
+pub struct EncodedRows {
+    /// escaped scan data including any restart markers. The first byte contains the start.overhang
+    /// bits of the previous rows and the end.overhang bits are left for the following rows, so the
+    /// data of consecutive ranges can simply be concatenated.
+    pub data: Vec<u8>,
+    pub start: RowBoundary,
+    pub end: RowBoundary,
+}
+
+/// encodes only the MCU rows in mcu_row_range of a baseline image. Since the bit position where the
+/// range starts depends on everything before it, the preceding rows are coded as well, but without
+/// keeping their output. The library starts from a thread handoff instead, see encode_rows_from, so
+/// only the tests code from the top of the image.
+#[cfg(test)]
+pub fn encode_rows(
+    framebuffer: &[BlockBasedImage],
+    lh: &LeptonHeader,
+    mcu_row_range: Range<i32>,
+) -> Result<EncodedRows> {
+    // the coder state at the top of the image
+    let image_start = ThreadHandoff {
+        luma_y_start: 0,
+        luma_y_end: 0,
+        segment_offset_in_file: 0,
+        segment_size: 0,
+        overhang_byte: 0,
+        num_overhang_bits: 0,
+        last_dc: [0; 4],
+    };
+
+    encode_rows_from(framebuffer, lh, &image_start, mcu_row_range)
+}
+
+/// same as encode_rows, but starts coding at the row and coder state of the thread handoff rather than at
+/// the top of the image, so the framebuffer only needs to contain the rows from there on.
+pub fn encode_rows_from(
+    framebuffer: &[BlockBasedImage],
+    lh: &LeptonHeader,
+    thread_handoff: &ThreadHandoff,
+    mcu_row_range: Range<i32>,
+) -> Result<EncodedRows> {
+    if lh.jpeg_header.jpeg_type != JPegType::Sequential {
+        return err_exit_code(
+            ExitCode::UnsupportedJpeg,
+            "row ranges can only be encoded for baseline images",
+        );
+    }
+
+    let mut last_dc = thread_handoff.last_dc;
+    let mut huffw = BitWriter::new();
+    huffw.reset_from_overhang_byte_and_num_bits(
+        thread_handoff.overhang_byte,
+        thread_handoff.num_overhang_bits.into(),
+    );
+    let max_coded_heights = lh.truncate_components.get_max_coded_heights();
+
+    let mut data = Vec::new();
+    let mut start = None;
+
+    let boundary = |huffw: &BitWriter, last_dc: &[i16; 4]| {
+        let (overhang_byte, num_overhang_bits) = huffw.overhang();
+        RowBoundary {
+            last_dc: *last_dc,
+            overhang_byte,
+            num_overhang_bits,
+        }
+    };
+
+    let mut decode_index = 0;
+    loop {
+        let cur_row = RowSpec::get_row_spec_from_index(
+            decode_index,
+            framebuffer,
+            lh.truncate_components.mcu_count_vertical,
+            &max_coded_heights[..],
+        );
+
+        decode_index += 1;
+
+        if cur_row.done || cur_row.mcu_row_index >= mcu_row_range.end {
+            break;
+        }
+
+        if cur_row.skip
+            || !cur_row.last_row_to_complete_mcu
+            || cur_row.min_row_luma_y < thread_handoff.luma_y_start
+        {
+            continue;
+        }
+
+        let r = if cur_row.mcu_row_index < mcu_row_range.start {
+            let r = recode_one_mcu_row(
+                &mut huffw,
+                cur_row.mcu_row_index * lh.jpeg_header.mcuh,
+                &mut std::io::sink(),
+                &mut last_dc,
+                framebuffer,
+                lh,
+            )
+            .context(here!())?;
+
+            huffw.flush_with_escape(&mut std::io::sink())?;
+            r
+        } else {
+            if start.is_none() {
+                start = Some(boundary(&huffw, &last_dc));
+            }
+
+            let r = recode_one_mcu_row(
+                &mut huffw,
+                cur_row.mcu_row_index * lh.jpeg_header.mcuh,
+                &mut data,
+                &mut last_dc,
+                framebuffer,
+                lh,
+            )
+            .context(here!())?;
+
+            huffw.flush_with_escape(&mut data).context(here!())?;
+            r
+        };
+
+        if r {
+            break;
+        }
+    }
+
+    let end = boundary(&huffw, &last_dc);
+
+    Ok(EncodedRows {
+        data,
+        start: start.unwrap_or(end),
+        end,
+    })
+}
+
+/// counts the bytes that would have been written without storing them
+#[derive(Default)]
+struct ByteCounter {
+    count: u64,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.count += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// dry run that returns the number of bytes the given writing code would output (including byte stuffing
+/// and restart markers) without keeping them. Since the same coding path is used as for the real output,
+/// the computed size can't diverge from what is actually written.
+pub fn compute_size(write: impl FnOnce(&mut dyn Write) -> Result<()>) -> Result<u64> {
+    let mut counter = ByteCounter::default();
+    write(&mut counter).context(here!())?;
+    Ok(counter.count)
+}
+
 #[inline(never)]
 fn recode_one_mcu_row<W: Write>(
     huffw: &mut BitWriter,
@@ -177,6 +361,9 @@ fn recode_one_mcu_row<W: Write>(
 
     let mut cumulative_reset_markers = state.get_cumulative_reset_markers(jf);
 
+    // index of the restart interval we are in, used to look up the fill bits for the padding
+    let mut interval = state.get_cumulative_reset_markers(jf);
+
     let mut end_of_row = false;
     let mut correction_bits = Vec::new();
 
@@ -204,14 +391,14 @@ fn recode_one_mcu_row<W: Write>(
                 lastdc[state.get_cmp()] = dc;
 
                 // encode block
-                encode_block_seq(
+                let r = encode_block_seq(
                     huffw,
                     jf.get_huff_dc_codes(state.get_cmp()),
                     jf.get_huff_ac_codes(state.get_cmp()),
                     &block,
                 );
+                check_encoded(r, &state, ch)?;
 
-                huffw.flush_with_escape(writer).context(here!())?;
                 sta = state.next_mcu_pos(&jf);
             } else if jf.cs_to == 0 {
                 // ---> progressive DC encoding <---
@@ -224,7 +411,8 @@ fn recode_one_mcu_row<W: Write>(
                     lastdc[state.get_cmp()] = tmp;
 
                     // encode dc
-                    write_coef(huffw, v, 0, jf.get_huff_dc_codes(state.get_cmp()));
+                    let r = write_coef(huffw, v, 0, jf.get_huff_dc_codes(state.get_cmp()));
+                    check_encoded(r, &state, ch)?;
                 } else {
                     // ---> succesive approximation later stage <---
 
@@ -235,7 +423,6 @@ fn recode_one_mcu_row<W: Write>(
                     );
                 }
 
-                huffw.flush_with_escape(writer).context(here!())?;
                 sta = state.next_mcu_pos(jf);
             } else {
                 // ---> progressive AC encoding <---
@@ -253,15 +440,15 @@ fn recode_one_mcu_row<W: Write>(
                     // ---> succesive approximation first stage <---
 
                     // encode block
-                    encode_ac_prg_fs(
+                    let r = encode_ac_prg_fs(
                         huffw,
                         jf.get_huff_ac_codes(state.get_cmp()),
                         &block,
                         &mut state,
                         jf.cs_from,
                         jf.cs_to,
-                    )
-                    .context(here!())?;
+                    );
+                    check_encoded(r, &state, ch)?;
 
                     sta = state.next_mcu_pos(jf);
 
@@ -269,12 +456,11 @@ fn recode_one_mcu_row<W: Write>(
                     if sta != JPegDecodeStatus::DecodeInProgress {
                         encode_eobrun(huffw, jf.get_huff_ac_codes(state.get_cmp()), &mut state);
                     }
-                    huffw.flush_with_escape(writer).context(here!())?;
                 } else {
                     // ---> succesive approximation later stage <---
 
                     // encode block
-                    encode_ac_prg_sa(
+                    let r = encode_ac_prg_sa(
                         huffw,
                         jf.get_huff_ac_codes(state.get_cmp()),
                         &block,
@@ -282,8 +468,8 @@ fn recode_one_mcu_row<W: Write>(
                         jf.cs_from,
                         jf.cs_to,
                         &mut correction_bits,
-                    )
-                    .context(here!())?;
+                    );
+                    check_encoded(r, &state, ch)?;
 
                     sta = state.next_mcu_pos(jf);
 
@@ -294,7 +480,6 @@ fn recode_one_mcu_row<W: Write>(
                         // encode remaining correction bits
                         encode_crbits(huffw, &mut correction_bits);
                     }
-                    huffw.flush_with_escape(writer).context(here!())?;
                 }
             }
 
@@ -302,16 +487,14 @@ fn recode_one_mcu_row<W: Write>(
                 end_of_row = true;
                 if sta == JPegDecodeStatus::DecodeInProgress {
                     // completed only MCU aligned row, not reset interval so don't emit anything special
-                    huffw.flush_with_escape(writer).context(here!())?;
                     return Ok(false);
                 }
             }
-
-            huffw.flush_with_escape(writer).context(here!())?;
         }
 
         // pad huffman writer
-        huffw.pad(ch.pad_bit.unwrap_or(0));
+        huffw.pad(ch.get_pad_bits(interval));
+        interval += 1;
 
         assert!(
             huffw.has_no_remainder(),
@@ -332,9 +515,23 @@ fn recode_one_mcu_row<W: Write>(
                     || (!ch.rst_cnt_set)
                     || cumulative_reset_markers < ch.rst_cnt[ch.scnc]
                 {
-                    let rst = jpeg_code::RST0 + (cumulative_reset_markers & 7) as u8;
-                    writer.write_u8(0xFF)?;
-                    writer.write_u8(rst)?;
+                    // write the markers the original had here, which is normally just the one numbered after the interval,
+                    // along with any segments that were stuck in between them
+                    let nominal = [jpeg_code::RST0 + (cumulative_reset_markers & 7) as u8];
+                    let markers = ch
+                        .get_restart_exception(cumulative_reset_markers)
+                        .unwrap_or(&nominal);
+
+                    for (i, &rst) in markers.iter().enumerate() {
+                        for segment in ch.get_scan_segments(cumulative_reset_markers, i) {
+                            writer.write_all(segment)?;
+                        }
+                        writer.write_u8(0xFF)?;
+                        writer.write_u8(rst)?;
+                    }
+                    for segment in ch.get_scan_segments(cumulative_reset_markers, markers.len()) {
+                        writer.write_all(segment)?;
+                    }
                     cumulative_reset_markers += 1;
                 }
 
@@ -352,15 +549,39 @@ fn recode_one_mcu_row<W: Write>(
     Ok(false)
 }
 
+/// adds the position of the block to an encoding error so it can be traced back to the offending coefficient.
+///
+/// Truncated JPEGs are an exception: the blocks after the end of the file were never part of the original
+/// and whatever we write for them gets cut off. Since we only know the furthest block read per component
+/// and not where exactly in the MCU order the file ended, we don't report errors anywhere in the last MCU row.
+fn check_encoded(r: Result<()>, state: &JpegPositionState, ch: &LeptonHeader) -> Result<()> {
+    if r.is_err() && ch.early_eof_encountered {
+        let cmp_info = &ch.jpeg_header.cmp_info[state.get_cmp()];
+        let mcu_row = |dpos: i32| dpos / cmp_info.bch / cmp_info.sfv;
+
+        if mcu_row(state.get_dpos()) >= mcu_row(ch.max_dpos[state.get_cmp()]) {
+            return Ok(());
+        }
+    }
+
+    r.with_context(|| {
+        format!(
+            "while encoding component {0} dpos {1}",
+            state.get_cmp(),
+            state.get_dpos()
+        )
+    })
+}
+
 #[inline(never)]
 fn encode_block_seq(
     huffw: &mut BitWriter,
     dctbl: &HuffCodes,
     actbl: &HuffCodes,
     block: &[i16; 64],
-) {
+) -> Result<()> {
     // encode DC
-    write_coef(huffw, block[0], 0, dctbl);
+    write_coef(huffw, block[0], 0, dctbl)?;
 
     let mut z = 0;
 
@@ -379,7 +600,7 @@ fn encode_block_seq(
             z -= 16;
         }
 
-        write_coef(huffw, tmp, z, actbl);
+        write_coef(huffw, tmp, z, actbl)?;
 
         // reset zeroes
         z = 0;
@@ -389,20 +610,37 @@ fn encode_block_seq(
     if z != 0 {
         huffw.write(actbl.c_val[0x00].into(), actbl.c_len[0x00].into());
     }
+
+    Ok(())
 }
 
 /// encodes a coefficient which is a huffman code specifying the size followed
-/// by the coefficient itself
+/// by the coefficient itself. Fails if the Huffman table has no code for the size, which
+/// can only happen if the coefficients we are asked to encode didn't come from this JPEG.
 #[inline(always)]
-fn write_coef(huffw: &mut BitWriter, coef: i16, z: u8, tbl: &HuffCodes) {
+fn write_coef(huffw: &mut BitWriter, coef: i16, z: u8, tbl: &HuffCodes) -> Result<()> {
     // vli encode
     let (n, s) = envli(coef);
+
+    // the size has to fit into the lower nibble of the code and have been assigned a code
+    if s > 15 || tbl.c_len[usize::from(((z & 0xf) << 4) + s)] == 0 {
+        return err_exit_code(
+            ExitCode::CannotReencode,
+            format!(
+                "coefficient {0} after {1} zeros cannot be encoded with the Huffman table",
+                coef, z
+            )
+            .as_str(),
+        );
+    }
+
     let hc = ((z & 0xf) << 4) + s;
 
     // write to huffman writer (combine into single write)
-    let val = (u32::from(tbl.c_val[usize::from(hc)]) << s) | u32::from(n);
-    let new_bits = u32::from(tbl.c_len[usize::from(hc)]) + u32::from(s);
-    huffw.write(val, new_bits);
+    let val = (u64::from(tbl.c_val[usize::from(hc)]) << s) | u64::from(n);
+    huffw.write_bits64(val, tbl.c_len[usize::from(hc)] as u8 + s);
+
+    Ok(())
 }
 
 /// progressive AC encoding (first pass)
@@ -429,7 +667,7 @@ fn encode_ac_prg_fs(
             }
 
             // vli encode
-            write_coef(huffw, tmp, z, actbl);
+            write_coef(huffw, tmp, z, actbl)?;
 
             // reset zeroes
             z = 0;
@@ -509,7 +747,7 @@ fn encode_ac_prg_sa(
         // if nonzero is encountered
         else if (tmp == 1) || (tmp == -1) {
             // vli encode
-            write_coef(huffw, tmp, z, actbl);
+            write_coef(huffw, tmp, z, actbl)?;
 
             // write correction bits
             encode_crbits(huffw, correction_bits);
@@ -565,11 +803,10 @@ fn encode_eobrun(huffw: &mut BitWriter, actbl: &HuffCodes, state: &mut JpegPosit
 
         let n = encode_eobrun_bits(s, state.eobrun);
         let hc = s << 4;
-        huffw.write(
-            actbl.c_val[usize::from(hc)].into(),
-            actbl.c_len[usize::from(hc)].into(),
+        huffw.write_bits64(
+            (u64::from(actbl.c_val[usize::from(hc)]) << s) | u64::from(n),
+            actbl.c_len[usize::from(hc)] as u8 + s,
         );
-        huffw.write(u32::from(n), u32::from(s));
         state.eobrun = 0;
     }
 }
@@ -608,3 +845,95 @@ fn envli(v: i16) -> (u16, u8) {
 fn encode_eobrun_bits(s: u8, v: u16) -> u16 {
     v - (1 << s)
 }
+
+#[cfg(test)]
+use std::io::Cursor;
+
+#[cfg(test)]
+use super::lepton_format::read_jpeg;
+
+#[cfg(test)]
+use crate::{enabled_features::EnabledFeatures, lepton_error::LeptonError};
+
+/// an AC coefficient of size 11 has no code in the standard Huffman tables and must be reported as an error instead of
+/// writing garbage or panicking
+#[test]
+fn oversized_coefficient_cannot_be_reencoded() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    let (lh, mut image_data) = read_jpeg(
+        &mut Cursor::new(&original),
+        &EnabledFeatures::all(),
+        8,
+        |_| {},
+    )
+    .unwrap();
+
+    // sanity check that the unmodified coefficients write out fine
+    let mut output = Vec::new();
+    jpeg_write_entire_scan(&mut output, &image_data, &lh).unwrap();
+
+    image_data[0]
+        .get_block_mut(0)
+        .set_coefficient_zigzag(1, 2047);
+
+    let e = jpeg_write_entire_scan(&mut Vec::new(), &image_data, &lh).unwrap_err();
+
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::CannotReencode
+    );
+}
+
+/// encoding the image in row ranges and stitching them together gives exactly the same scan as encoding it in one go
+#[test]
+fn encode_rows_stitched() {
+    for file in ["android", "trailingrst"] {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+
+        let (mut lh, image_data) = read_jpeg(
+            &mut Cursor::new(&original),
+            &EnabledFeatures::all(),
+            8,
+            |_| {},
+        )
+        .unwrap();
+
+        // reading advanced the scan counter past the scan we want to write
+        lh.scnc = 0;
+
+        let mut full = Vec::new();
+        jpeg_write_entire_scan(&mut full, &image_data, &lh).unwrap();
+
+        let mcuv = lh.truncate_components.mcu_count_vertical;
+        for rows_per_range in [1, 3, 7] {
+            let mut stitched = Vec::new();
+            let mut previous_end = RowBoundary {
+                last_dc: [0; 4],
+                overhang_byte: 0,
+                num_overhang_bits: 0,
+            };
+
+            for start in (0..mcuv).step_by(rows_per_range) {
+                let rows =
+                    encode_rows(&image_data, &lh, start..start + rows_per_range as i32).unwrap();
+
+                assert_eq!(rows.start, previous_end);
+                previous_end = rows.end;
+
+                stitched.extend_from_slice(&rows.data[..]);
+            }
+
+            assert_eq!(previous_end.num_overhang_bits, 0);
+            assert!(
+                stitched[..] == full[..],
+                "{file} with {rows_per_range} rows per range"
+            );
+        }
+    }
+}
@@ -0,0 +1,284 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+// Container that holds several lepton files with a table of contents in front of them, so that a series of
+// similar images can be stored together. Each entry is a complete lepton file, so the container is just
+// framing on top of the single image format:
+//
+// magic (4 bytes), number of entries (u32)
+// for each entry: offset from the start of the container (u64), length (u64), CRC32 of the name (u32)
+// the lepton files of all the entries
+
+use std::cmp;
+use std::io::{Cursor, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Context, Result};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use flate2::Crc;
+
+use crate::consts::LEPTON_CONTAINER_HEADER;
+use crate::enabled_features::EnabledFeatures;
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::structs::cpu_cores::resolve_max_threads;
+use crate::structs::lepton_format::{decode_lepton_wrapper, encode_lepton_wrapper};
+use crate::structs::thread_pool;
+
+/// size of each entry in the table of contents
+const CONTAINER_ENTRY_SIZE: usize = 20;
+
+/// location of one lepton file inside a container
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerEntry {
+    /// position of the lepton file counted from the start of the container
+    pub offset: u64,
+    pub length: u64,
+    /// CRC32 of the name of the original file (see hash_name), zero if it wasn't given
+    pub name_hash: u32,
+}
+
+impl ContainerEntry {
+    /// hash of the name of the original file as it is stored in the table of contents
+    pub fn hash_name(name: &str) -> u32 {
+        let mut crc = Crc::new();
+        crc.update(name.as_bytes());
+        crc.sum()
+    }
+}
+
+/// true if the data starts with the magic of a container rather than that of a single lepton file
+pub fn is_lepton_container(data: &[u8]) -> bool {
+    data.starts_with(&LEPTON_CONTAINER_HEADER)
+}
+
+/// encodes each of the jpegs, along with the name of the file they came from if there is one, and stores them
+/// in a single container. The entries are encoded at the same time by up to max_threads workers (0 for one per
+/// physical core), which share the threads among them, so that small images that can't be split don't leave the
+/// other threads idle.
+pub fn encode_many_wrapper(
+    inputs: &[(Option<&str>, &[u8])],
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Vec<u8>> {
+    let max_threads = resolve_max_threads(max_threads);
+    let num_workers = cmp::max(1, cmp::min(max_threads, inputs.len()));
+    let threads_per_entry = cmp::max(1, max_threads / num_workers);
+
+    let next_entry = AtomicUsize::new(0);
+
+    let mut encoded: Vec<Option<Vec<u8>>> = Vec::new();
+    encoded.resize(inputs.len(), None);
+
+    thread_pool::scope(max_threads, |s| -> Result<()> {
+        let mut workers = Vec::new();
+
+        for _i in 0..num_workers {
+            workers.push(s.spawn(|| -> Result<Vec<(usize, Vec<u8>)>> {
+                let mut results = Vec::new();
+
+                loop {
+                    let i = next_entry.fetch_add(1, Ordering::Relaxed);
+                    if i >= inputs.len() {
+                        return Ok(results);
+                    }
+
+                    let mut output = Vec::new();
+                    encode_lepton_wrapper(
+                        &mut Cursor::new(inputs[i].1),
+                        &mut Cursor::new(&mut output),
+                        threads_per_entry,
+                        enabled_features,
+                    )
+                    .with_context(|| format!("encoding entry {0} at {1}", i, here!()))?;
+
+                    results.push((i, output));
+                }
+            }));
+        }
+
+        for worker in workers {
+            for (i, output) in worker.join().unwrap().context(here!())? {
+                encoded[i] = Some(output);
+            }
+        }
+
+        Ok(())
+    })
+    .context(here!())?;
+
+    let mut container = Vec::new();
+    container.write_all(&LEPTON_CONTAINER_HEADER)?;
+    container.write_u32::<LittleEndian>(u32::try_from(inputs.len())?)?;
+
+    let mut offset =
+        (LEPTON_CONTAINER_HEADER.len() + 4 + inputs.len() * CONTAINER_ENTRY_SIZE) as u64;
+    for (input, output) in inputs.iter().zip(encoded.iter()) {
+        let length = output.as_ref().context(here!())?.len() as u64;
+
+        container.write_u64::<LittleEndian>(offset)?;
+        container.write_u64::<LittleEndian>(length)?;
+        container.write_u32::<LittleEndian>(input.0.map_or(0, ContainerEntry::hash_name))?;
+
+        offset += length;
+    }
+
+    for output in encoded {
+        container.write_all(&output.context(here!())?[..])?;
+    }
+
+    Ok(container)
+}
+
+/// reads the table of contents of a container, checking that all the entries are inside of it
+pub fn read_container_entries_wrapper(container: &[u8]) -> Result<Vec<ContainerEntry>> {
+    if !is_lepton_container(container) {
+        return err_exit_code(ExitCode::BadLeptonFile, "not a lepton container");
+    }
+
+    let mut reader = Cursor::new(&container[LEPTON_CONTAINER_HEADER.len()..]);
+    let count = reader.read_u32::<LittleEndian>()? as usize;
+
+    if count > container.len() / CONTAINER_ENTRY_SIZE {
+        return err_exit_code(
+            ExitCode::BadLeptonFile,
+            format!("container is too small for {0} entries", count).as_str(),
+        );
+    }
+
+    let mut entries = Vec::with_capacity(count);
+    for i in 0..count {
+        let entry = ContainerEntry {
+            offset: reader.read_u64::<LittleEndian>()?,
+            length: reader.read_u64::<LittleEndian>()?,
+            name_hash: reader.read_u32::<LittleEndian>()?,
+        };
+
+        if entry
+            .offset
+            .checked_add(entry.length)
+            .map_or(true, |end| end > container.len() as u64)
+        {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                format!("entry {0} extends beyond the end of the container", i).as_str(),
+            );
+        }
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// decodes a single entry of a container back into the original jpeg
+pub fn decode_entry_wrapper(container: &[u8], index: usize, num_threads: usize) -> Result<Vec<u8>> {
+    let entries = read_container_entries_wrapper(container).context(here!())?;
+
+    if index >= entries.len() {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            format!("container only has {0} entries", entries.len()).as_str(),
+        );
+    }
+
+    let entry = &entries[index];
+    let lepton = &container[entry.offset as usize..(entry.offset + entry.length) as usize];
+
+    let mut output = Vec::new();
+    decode_lepton_wrapper(&mut Cursor::new(lepton), &mut output, num_threads).context(here!())?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+use crate::structs::lepton_format::LeptonHeader;
+
+/// all the entries of a container decode back to their originals, and single image decoders reject it
+#[test]
+fn container_roundtrip() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    let names = [
+        "android.jpg",
+        "trailingrst.jpg",
+        "scancomment.jpg",
+        "android.jpg",
+    ];
+    let originals: Vec<Vec<u8>> = names
+        .iter()
+        .map(|name| std::fs::read(path.join(name)).unwrap())
+        .collect();
+
+    let inputs: Vec<(Option<&str>, &[u8])> = names
+        .iter()
+        .zip(originals.iter())
+        .enumerate()
+        .map(|(i, (name, data))| (if i == 1 { None } else { Some(*name) }, &data[..]))
+        .collect();
+
+    let container = encode_many_wrapper(&inputs[..], 8, &EnabledFeatures::all()).unwrap();
+    assert!(is_lepton_container(&container));
+
+    let entries = read_container_entries_wrapper(&container).unwrap();
+    assert_eq!(entries.len(), names.len());
+    assert_eq!(
+        entries[0].name_hash,
+        ContainerEntry::hash_name("android.jpg")
+    );
+    assert_eq!(entries[1].name_hash, 0);
+    assert_eq!(entries[0].name_hash, entries[3].name_hash);
+
+    for (i, original) in originals.iter().enumerate() {
+        assert_eq!(decode_entry_wrapper(&container, i, 8).unwrap(), *original);
+    }
+
+    let e = decode_entry_wrapper(&container, names.len(), 8).unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::SyntaxError
+    );
+
+    // a single image decoder sees an unknown version
+    let e = LeptonHeader::new()
+        .read_lepton_header(&mut Cursor::new(&container))
+        .unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::VersionUnsupported
+    );
+
+    // and a single lepton file isn't a container
+    assert!(!is_lepton_container(
+        &std::fs::read(path.join("android.lep")).unwrap()
+    ));
+}
+
+/// entries pointing outside of the container are rejected
+#[test]
+fn container_entry_out_of_bounds() {
+    let mut container = Vec::new();
+    container.extend_from_slice(&LEPTON_CONTAINER_HEADER);
+    container.write_u32::<LittleEndian>(1).unwrap();
+    container.write_u64::<LittleEndian>(28).unwrap();
+    container.write_u64::<LittleEndian>(100).unwrap();
+    container.write_u32::<LittleEndian>(0).unwrap();
+
+    let e = read_container_entries_wrapper(&container).unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::BadLeptonFile
+    );
+}
@@ -6,28 +6,38 @@
 
 use anyhow::{Context, Result};
 
-use default_boxed::DefaultBoxed;
-
 use std::cmp;
 use std::io::Read;
 
 use crate::consts::{
     ALIGNED_BLOCK_INDEX_AC_7X7_INDEX, LOG_TABLE_256, RASTER_TO_ALIGNED, UNZIGZAG_49,
 };
-use crate::helpers::{err_exit_code, here, u16_bit_length};
+use crate::enabled_features::CoefficientCodecKind;
+use crate::helpers::{
+    add_coefficients_position, err_cancelled, err_coefficient_out_of_range, err_exit_code, here,
+    u16_bit_length,
+};
 use crate::lepton_error::ExitCode;
 
 use crate::metrics::Metrics;
+#[cfg(feature = "experimental-codec")]
+use crate::structs::coefficient_codec::PositionalSignCodec;
+use crate::structs::model::MAX_COEFFICIENT;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::{NON_ZEROS_7X7, NON_ZEROS_COLUMN, NON_ZEROS_ROW};
 use crate::structs::{
-    block_based_image::BlockBasedImage, block_context::BlockContext, model::Model,
-    neighbor_summary::NeighborSummary, probability_tables::ProbabilityTables,
-    probability_tables_set::ProbabilityTablesSet, quantization_tables::QuantizationTables,
-    row_spec::RowSpec, truncate_components::*, vpx_bool_reader::VPXBoolReader,
+    block_based_image::BlockBasedImage, block_context::BlockContext,
+    coefficient_codec::CoefficientCodec, model::Model, neighbor_summary::NeighborSummary,
+    probability_tables::ProbabilityTables, probability_tables_set::ProbabilityTablesSet,
+    quantization_tables::QuantizationTables, row_spec::RowSpec, truncate_components::*,
+    vpx_bool_reader::VPXBoolReader,
 };
 
 // reads stream from reader and populates image_data with the decoded data
 
-#[inline(never)] // don't inline so that the profiler can get proper data
+/// decodes the rows of a thread segment with the codec the file was encoded with, see CoefficientCodec. With
+/// separate chroma models, the Cr component is decoded with cr_model. With strict_segment_end the data of the
+/// segment has to end right after the flush of the encoder, see VPXBoolReader::verify_end.
 pub fn lepton_decode_row_range<R: Read>(
     pts: &ProbabilityTablesSet,
     qt: &[QuantizationTables],
@@ -38,6 +48,68 @@ pub fn lepton_decode_row_range<R: Read>(
     max_y: i32,
     is_last_thread: bool,
     full_file_compression: bool,
+    model: &mut Model,
+    mut cr_model: Option<&mut Model>,
+    codec: CoefficientCodecKind,
+    strict_segment_end: bool,
+) -> Result<Metrics> {
+    // files can only ask for other codecs in builds that have them
+    debug_assert!(cfg!(feature = "experimental-codec") || codec == CoefficientCodecKind::Model);
+
+    model.begin_segment();
+    if let Some(cr_model) = cr_model.as_deref_mut() {
+        cr_model.begin_segment();
+    }
+
+    #[cfg(feature = "experimental-codec")]
+    if codec == CoefficientCodecKind::PositionalSign {
+        return decode_row_range(
+            pts,
+            qt,
+            trunc,
+            image_data,
+            reader,
+            min_y,
+            max_y,
+            is_last_thread,
+            full_file_compression,
+            &mut PositionalSignCodec::new(model),
+            cr_model.map(PositionalSignCodec::new).as_mut(),
+            strict_segment_end,
+        );
+    }
+
+    decode_row_range(
+        pts,
+        qt,
+        trunc,
+        image_data,
+        reader,
+        min_y,
+        max_y,
+        is_last_thread,
+        full_file_compression,
+        model,
+        cr_model,
+        strict_segment_end,
+    )
+}
+
+#[inline(never)] // don't inline so that the profiler can get proper data
+#[allow(clippy::too_many_arguments)]
+fn decode_row_range<R: Read, C: CoefficientCodec>(
+    pts: &ProbabilityTablesSet,
+    qt: &[QuantizationTables],
+    trunc: &TruncateComponents,
+    image_data: &mut [BlockBasedImage],
+    reader: &mut R,
+    min_y: i32,
+    max_y: i32,
+    is_last_thread: bool,
+    full_file_compression: bool,
+    model: &mut C,
+    mut cr_model: Option<&mut C>,
+    strict_segment_end: bool,
 ) -> Result<Metrics> {
     let component_size_in_blocks = trunc.get_component_sizes_in_blocks();
     let max_coded_heights = trunc.get_max_coded_heights();
@@ -57,8 +129,11 @@ pub fn lepton_decode_row_range<R: Read>(
         num_non_zeros.push(num_non_zero_list);
     }
 
-    let mut model = Model::default_boxed();
     let mut bool_reader = VPXBoolReader::new(reader)?;
+    #[cfg(feature = "experimental-tuning")]
+    bool_reader.set_tuning(model.model().get_tuning());
+    #[cfg(feature = "debug-symmetry")]
+    bool_reader.set_symmetry_log(model.model().new_symmetry_log(min_y));
 
     let mut decode_index = 0;
 
@@ -87,8 +162,21 @@ pub fn lepton_decode_row_range<R: Read>(
             continue;
         }
 
+        if model.model().is_cancelled() {
+            return err_cancelled();
+        }
+
+        #[cfg(feature = "stats")]
+        bool_reader.set_stats_component(cur_row.component);
+
+        // with separate chroma models, Cr has its own model instead of sharing the chroma model with Cb
+        let model = match cr_model.as_deref_mut() {
+            Some(cr_model) if cur_row.component == 2 => cr_model,
+            _ => &mut *model,
+        };
+
         decode_row_wrapper(
-            &mut model,
+            model,
             &mut bool_reader,
             pts,
             &mut image_data[cur_row.component],
@@ -99,14 +187,28 @@ pub fn lepton_decode_row_range<R: Read>(
             cur_row.component,
             cur_row.curr_y,
         )
+        .map_err(|e| {
+            add_coefficients_position(
+                e,
+                bool_reader.position_bits(),
+                cur_row.component,
+                cur_row.curr_y,
+            )
+        })
         .context(here!())?;
     }
-    Ok(bool_reader.drain_stats())
+
+    let metrics = bool_reader.drain_stats();
+    if strict_segment_end {
+        bool_reader.verify_end().context(here!())?;
+    }
+
+    Ok(metrics)
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn decode_row_wrapper<R: Read>(
-    model: &mut Model,
+fn decode_row_wrapper<R: Read, C: CoefficientCodec>(
+    model: &mut C,
     bool_reader: &mut VPXBoolReader<R>,
     pts: &ProbabilityTablesSet,
     image_data: &mut BlockBasedImage,
@@ -132,6 +234,7 @@ fn decode_row_wrapper<R: Read>(
             image_data,
             &mut context,
             num_non_zeros,
+            component,
             component_size_in_blocks[component],
         )
         .context(here!())?;
@@ -147,6 +250,7 @@ fn decode_row_wrapper<R: Read>(
             image_data,
             &mut context,
             num_non_zeros,
+            component,
             component_size_in_blocks[component],
         )
         .context(here!())?;
@@ -162,6 +266,7 @@ fn decode_row_wrapper<R: Read>(
             image_data,
             &mut context,
             num_non_zeros,
+            component,
             component_size_in_blocks[component],
         )
         .context(here!())?;
@@ -170,8 +275,8 @@ fn decode_row_wrapper<R: Read>(
     Ok(())
 }
 
-fn decode_row<R: Read>(
-    model: &mut Model,
+fn decode_row<R: Read, C: CoefficientCodec>(
+    model: &mut C,
     bool_reader: &mut VPXBoolReader<R>,
     qt: &QuantizationTables,
     left_model: &ProbabilityTables,
@@ -180,11 +285,17 @@ fn decode_row<R: Read>(
     image_data: &mut BlockBasedImage,
     block_context: &mut BlockContext,
     num_non_zeros: &mut [NeighborSummary],
+    component: usize,
     component_size_in_blocks: i32,
 ) -> Result<()> {
     let block_width = image_data.get_block_width();
     if block_width > 0 {
-        parse_token::<R, false>(
+        model
+            .model()
+            .call_snapshot_hook(component, block_context.get_here_index());
+        #[cfg(feature = "debug-symmetry")]
+        bool_reader.set_symmetry_block(component, block_context.get_here_index());
+        parse_token::<R, C, false>(
             model,
             bool_reader,
             image_data,
@@ -202,8 +313,13 @@ fn decode_row<R: Read>(
     }
 
     for _jpeg_x in 1..block_width - 1 {
+        model
+            .model()
+            .call_snapshot_hook(component, block_context.get_here_index());
+        #[cfg(feature = "debug-symmetry")]
+        bool_reader.set_symmetry_block(component, block_context.get_here_index());
         if middle_model.is_all_present() {
-            parse_token::<R, true>(
+            parse_token::<R, C, true>(
                 model,
                 bool_reader,
                 image_data,
@@ -214,7 +330,7 @@ fn decode_row<R: Read>(
             )
             .context(here!())?;
         } else {
-            parse_token::<R, false>(
+            parse_token::<R, C, false>(
                 model,
                 bool_reader,
                 image_data,
@@ -234,8 +350,13 @@ fn decode_row<R: Read>(
     }
 
     if block_width > 1 {
+        model
+            .model()
+            .call_snapshot_hook(component, block_context.get_here_index());
+        #[cfg(feature = "debug-symmetry")]
+        bool_reader.set_symmetry_block(component, block_context.get_here_index());
         if right_model.is_all_present() {
-            parse_token::<R, true>(
+            parse_token::<R, C, true>(
                 model,
                 bool_reader,
                 image_data,
@@ -246,7 +367,7 @@ fn decode_row<R: Read>(
             )
             .context(here!())?;
         } else {
-            parse_token::<R, false>(
+            parse_token::<R, C, false>(
                 model,
                 bool_reader,
                 image_data,
@@ -264,8 +385,8 @@ fn decode_row<R: Read>(
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn parse_token<R: Read, const ALL_PRESENT: bool>(
-    model: &mut Model,
+fn parse_token<R: Read, C: CoefficientCodec, const ALL_PRESENT: bool>(
+    model: &mut C,
     bool_reader: &mut VPXBoolReader<R>,
     image_data: &mut BlockBasedImage,
     context: &mut BlockContext,
@@ -275,6 +396,8 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
 ) -> Result<()> {
     debug_assert!(pt.is_all_present() == ALL_PRESENT);
 
+    #[cfg(feature = "debug-symmetry")]
+    bool_reader.set_symmetry_coefficient(NON_ZEROS_7X7);
     let num_non_zeros_7x7 = model
         .read_non_zero_7x7_count(
             bool_reader,
@@ -308,6 +431,8 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
             "this does the DC and the lower 7x7 AC"
         );
 
+        #[cfg(feature = "debug-symmetry")]
+        bool_reader.set_symmetry_coefficient(coord);
         let coef = model
             .read_coef(
                 bool_reader,
@@ -331,7 +456,7 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
         block[zz as usize + ALIGNED_BLOCK_INDEX_AC_7X7_INDEX] = coef;
     }
 
-    decode_edge::<R, ALL_PRESENT>(
+    decode_edge::<R, C, ALL_PRESENT>(
         model,
         bool_reader,
         image_data,
@@ -346,6 +471,8 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
     let predicted_dc = pt.adv_predict_dc_pix::<ALL_PRESENT>(image_data, qt, context, num_non_zeros);
     let block = context.here_mut(image_data);
 
+    #[cfg(feature = "debug-symmetry")]
+    bool_reader.set_symmetry_coefficient(0);
     let coef = model
         .read_dc(
             bool_reader,
@@ -355,11 +482,14 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
         )
         .context(here!())?;
 
-    block.set_dc(ProbabilityTables::adv_predict_or_unpredict_dc(
-        coef,
-        true,
-        predicted_dc.predicted_dc,
-    ) as i16);
+    // the prediction of a corrupt stream can be far enough off for the DC to be out of range, and it would
+    // otherwise be truncated to an i16 and taken as the context of the blocks that come after it
+    let dc = ProbabilityTables::adv_predict_or_unpredict_dc(coef, true, predicted_dc.predicted_dc);
+    if dc.abs() > MAX_COEFFICIENT {
+        return err_coefficient_out_of_range(pt.get_component(), context.get_here_index(), 0, dc);
+    }
+
+    block.set_dc(dc as i16);
 
     let here = context.neighbor_context_here(num_non_zeros);
     here.set_num_non_zeros(num_non_zeros_7x7);
@@ -380,8 +510,8 @@ fn parse_token<R: Read, const ALL_PRESENT: bool>(
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn decode_edge<R: Read, const ALL_PRESENT: bool>(
-    model: &mut Model,
+fn decode_edge<R: Read, C: CoefficientCodec, const ALL_PRESENT: bool>(
+    model: &mut C,
     bool_reader: &mut VPXBoolReader<R>,
     image_data: &mut BlockBasedImage,
     context: &BlockContext,
@@ -391,7 +521,7 @@ fn decode_edge<R: Read, const ALL_PRESENT: bool>(
     eob_x: u8,
     eob_y: u8,
 ) -> Result<()> {
-    decode_one_edge::<R, ALL_PRESENT, true>(
+    decode_one_edge::<R, C, ALL_PRESENT, true>(
         model,
         bool_reader,
         image_data,
@@ -401,7 +531,7 @@ fn decode_edge<R: Read, const ALL_PRESENT: bool>(
         num_non_zeros_7x7,
         eob_x,
     )?;
-    decode_one_edge::<R, ALL_PRESENT, false>(
+    decode_one_edge::<R, C, ALL_PRESENT, false>(
         model,
         bool_reader,
         image_data,
@@ -414,8 +544,13 @@ fn decode_edge<R: Read, const ALL_PRESENT: bool>(
     Ok(())
 }
 
-fn decode_one_edge<R: Read, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
-    model: &mut Model,
+fn decode_one_edge<
+    R: Read,
+    C: CoefficientCodec,
+    const ALL_PRESENT: bool,
+    const HORIZONTAL: bool,
+>(
+    model: &mut C,
     bool_reader: &mut VPXBoolReader<R>,
     image_data: &mut BlockBasedImage,
     block_context: &BlockContext,
@@ -424,6 +559,12 @@ fn decode_one_edge<R: Read, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
     num_non_zeros_7x7: u8,
     est_eob: u8,
 ) -> Result<()> {
+    #[cfg(feature = "debug-symmetry")]
+    bool_reader.set_symmetry_coefficient(if HORIZONTAL {
+        NON_ZEROS_ROW
+    } else {
+        NON_ZEROS_COLUMN
+    });
     let mut num_non_zeros_edge = model
         .read_non_zero_edge_count::<R, HORIZONTAL>(
             bool_reader,
@@ -485,6 +626,8 @@ fn decode_one_edge<R: Read, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
             num_non_zeros_edge,
         );
 
+        #[cfg(feature = "debug-symmetry")]
+        bool_reader.set_symmetry_coefficient(coord as u8);
         let coef = model.read_edge_coefficient(bool_reader, pt, qt, coord, zig15offset, &ptcc8)?;
 
         if coef != 0 {
@@ -502,3 +645,201 @@ fn decode_one_edge<R: Read, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
 
     Ok(())
 }
+
+/// a stream whose quantization table was damaged predicts DCs that are far off from what the encoder predicted,
+/// which the decoder refuses instead of truncating them
+#[test]
+fn damaged_stream_reconstructs_dc_out_of_range() {
+    use std::io::Cursor;
+
+    use default_boxed::DefaultBoxed;
+
+    use crate::enabled_features::EnabledFeatures;
+    use crate::lepton_error::LeptonError;
+    use crate::structs::jpeg_read::error_location_fields;
+    use crate::structs::lepton_encoder::lepton_encode_row_range;
+    use crate::structs::lepton_format::{get_quantization_tables, read_jpeg};
+
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("gray2sf.jpg"),
+    )
+    .unwrap();
+
+    let (lp, image_data) = read_jpeg(
+        &mut Cursor::new(&jpeg),
+        &EnabledFeatures::default(),
+        1,
+        |_jh| {},
+    )
+    .unwrap();
+    let pts = ProbabilityTablesSet::new(false, false);
+    let bcv = lp.jpeg_header.cmp_info[0].bcv;
+
+    let mut stream = Vec::new();
+    lepton_encode_row_range(
+        &pts,
+        &get_quantization_tables(&lp.jpeg_header).unwrap(),
+        &image_data,
+        &mut stream,
+        0,
+        &lp.truncate_components,
+        0,
+        bcv,
+        true,
+        true,
+        &mut Model::default_boxed(),
+        None,
+        CoefficientCodecKind::Model,
+    )
+    .unwrap();
+
+    // the DC is quantized much finer than it really was, so the predictions are scaled up
+    let mut damaged_header = lp.jpeg_header.clone();
+    for q in damaged_header.q_tables.iter_mut() {
+        q[0] = 1;
+    }
+
+    let mut decoded: Vec<BlockBasedImage> = (0..damaged_header.cmpc)
+        .map(|c| BlockBasedImage::new(&damaged_header, c, 0, bcv))
+        .collect();
+    let e = lepton_decode_row_range(
+        &pts,
+        &get_quantization_tables(&damaged_header).unwrap(),
+        &lp.truncate_components,
+        &mut decoded,
+        &mut Cursor::new(&stream),
+        0,
+        bcv,
+        true,
+        true,
+        &mut Model::default_boxed(),
+        None,
+        CoefficientCodecKind::Model,
+        true,
+    )
+    .unwrap_err();
+
+    let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+    assert_eq!(e.exit_code, ExitCode::CoefficientOutOfRange);
+    assert!(
+        e.message
+            .starts_with("CoefficientOutOfRange { component: 0, dpos: "),
+        "{0}",
+        e.message
+    );
+    assert!(e.message.contains("index: 0,"), "{0}", e.message);
+
+    // the position that the decoder had got to is in the coded data, in the row of the block
+    let dpos = error_location_fields(&e.message, "CoefficientOutOfRange {")[1];
+    let fields = error_location_fields(&e.message, "CorruptCoefficients {");
+    let position = fields[0] * 8 + fields[1];
+    assert!(
+        position > 0 && position <= stream.len() as i64 * 8,
+        "{0}",
+        e.message
+    );
+    assert_eq!(fields[2], 0);
+    assert_eq!(
+        fields[3],
+        dpos / i64::from(decoded[0].get_block_width()),
+        "{0}",
+        e.message
+    );
+}
+
+/// a thread segment that is cut short fails with UnexpectedEndOfSegment in the row that the data ran out in,
+/// instead of decoding the rest of the image from the zeros that the bool reader pads the data with
+#[test]
+fn truncated_segment_fails() {
+    use std::io::Cursor;
+
+    use default_boxed::DefaultBoxed;
+
+    use crate::enabled_features::EnabledFeatures;
+    use crate::lepton_error::LeptonError;
+    use crate::structs::jpeg_read::error_location_fields;
+    use crate::structs::lepton_encoder::lepton_encode_row_range;
+    use crate::structs::lepton_format::{get_quantization_tables, read_jpeg};
+
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("gray2sf.jpg"),
+    )
+    .unwrap();
+
+    let (lp, image_data) = read_jpeg(
+        &mut Cursor::new(&jpeg),
+        &EnabledFeatures::default(),
+        1,
+        |_jh| {},
+    )
+    .unwrap();
+    let pts = ProbabilityTablesSet::new(false, false);
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+    let bcv = lp.jpeg_header.cmp_info[0].bcv;
+
+    let mut stream = Vec::new();
+    lepton_encode_row_range(
+        &pts,
+        &qt,
+        &image_data,
+        &mut stream,
+        0,
+        &lp.truncate_components,
+        0,
+        bcv,
+        true,
+        true,
+        &mut Model::default_boxed(),
+        None,
+        CoefficientCodecKind::Model,
+    )
+    .unwrap();
+
+    let mut last_row = -1;
+    for len in [
+        0,
+        1,
+        100,
+        stream.len() / 2,
+        stream.len() - 100,
+        stream.len() - 2,
+    ] {
+        let mut decoded: Vec<BlockBasedImage> = (0..lp.jpeg_header.cmpc)
+            .map(|c| BlockBasedImage::new(&lp.jpeg_header, c, 0, bcv))
+            .collect();
+        let e = lepton_decode_row_range(
+            &pts,
+            &qt,
+            &lp.truncate_components,
+            &mut decoded,
+            &mut Cursor::new(&stream[..len]),
+            0,
+            bcv,
+            true,
+            true,
+            &mut Model::default_boxed(),
+            None,
+            CoefficientCodecKind::Model,
+            true,
+        )
+        .unwrap_err();
+
+        let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+        assert_eq!(e.exit_code, ExitCode::UnexpectedEndOfSegment, "{0}", e);
+
+        // the decoder got to the end of the data, and the later the data ends, the further it gets
+        let fields = error_location_fields(&e.message, "CorruptCoefficients {");
+        assert!(
+            fields[0] * 8 + fields[1] >= len as i64 * 8,
+            "{0}",
+            e.message
+        );
+        assert!(fields[3] >= last_row, "{0}", e.message);
+        last_row = fields[3];
+    }
+    assert!(last_row > 0);
+}
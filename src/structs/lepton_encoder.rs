@@ -10,35 +10,141 @@ use std::cmp;
 use std::io::Write;
 
 use crate::consts::*;
+use crate::enabled_features::CoefficientCodecKind;
 use crate::helpers::*;
 use crate::lepton_error::ExitCode;
 
 use crate::metrics::Metrics;
+#[cfg(feature = "experimental-codec")]
+use crate::structs::coefficient_codec::PositionalSignCodec;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::{NON_ZEROS_7X7, NON_ZEROS_COLUMN, NON_ZEROS_ROW};
 use crate::structs::{
-    block_based_image::BlockBasedImage, block_context::BlockContext, model::Model,
-    neighbor_summary::NeighborSummary, probability_tables::ProbabilityTables,
-    probability_tables_set::ProbabilityTablesSet, quantization_tables::QuantizationTables,
-    row_spec::RowSpec, truncate_components::*, vpx_bool_writer::VPXBoolWriter,
+    block_based_image::BlockBasedImage,
+    block_context::BlockContext,
+    coefficient_codec::CoefficientCodec,
+    model::Model,
+    neighbor_summary::NeighborSummary,
+    probability_tables::ProbabilityTables,
+    probability_tables_set::ProbabilityTablesSet,
+    quantization_tables::QuantizationTables,
+    row_spec::RowSpec,
+    truncate_components::*,
+    vpx_bool_writer::{BlockBits, VPXBoolWriter},
 };
 
-use default_boxed::DefaultBoxed;
-
-#[inline(never)] // don't inline so that the profiler can get proper data
+/// encodes the rows of a thread segment with the codec, see CoefficientCodec. With separate chroma models, the Cr
+/// component is coded with cr_model.
 pub fn lepton_encode_row_range<W: Write>(
     pts: &ProbabilityTablesSet,
     quantization_tables: &[QuantizationTables],
     image_data: &[BlockBasedImage],
     writer: &mut W,
-    _thread_id: i32,
+    thread_id: i32,
     colldata: &TruncateComponents,
     min_y: i32,
     max_y: i32,
     is_last_thread: bool,
     full_file_compression: bool,
+    model: &mut Model,
+    mut cr_model: Option<&mut Model>,
+    codec: CoefficientCodecKind,
 ) -> Result<Metrics> {
-    let mut model = Model::default_boxed();
+    // files can only ask for other codecs in builds that have them
+    debug_assert!(cfg!(feature = "experimental-codec") || codec == CoefficientCodecKind::Model);
+
+    model.begin_segment();
+    if let Some(cr_model) = cr_model.as_deref_mut() {
+        cr_model.begin_segment();
+    }
+
     let mut bool_writer = VPXBoolWriter::new(writer)?;
 
+    #[cfg(feature = "experimental-codec")]
+    if codec == CoefficientCodecKind::PositionalSign {
+        return encode_row_range(
+            pts,
+            quantization_tables,
+            image_data,
+            &mut bool_writer,
+            thread_id,
+            colldata,
+            min_y,
+            max_y,
+            is_last_thread,
+            full_file_compression,
+            &mut PositionalSignCodec::new(model),
+            cr_model.map(PositionalSignCodec::new).as_mut(),
+        );
+    }
+
+    encode_row_range(
+        pts,
+        quantization_tables,
+        image_data,
+        &mut bool_writer,
+        thread_id,
+        colldata,
+        min_y,
+        max_y,
+        is_last_thread,
+        full_file_compression,
+        model,
+        cr_model,
+    )
+}
+
+/// the bits that each block of the image costs when it is coded with model as a single thread segment, estimated
+/// from the probabilities of the branches without coding anything, see VPXBoolWriter::new_estimator
+pub fn lepton_estimate_block_bits(
+    pts: &ProbabilityTablesSet,
+    quantization_tables: &[QuantizationTables],
+    image_data: &[BlockBasedImage],
+    colldata: &TruncateComponents,
+    model: &mut Model,
+) -> Result<Vec<BlockBits>> {
+    let mut bool_writer = VPXBoolWriter::new_estimator()?;
+
+    encode_row_range(
+        pts,
+        quantization_tables,
+        image_data,
+        &mut bool_writer,
+        0,
+        colldata,
+        0,
+        i32::MAX,
+        true,
+        true,
+        model,
+        None,
+    )
+    .context(here!())?;
+
+    Ok(bool_writer.take_block_bits())
+}
+
+#[inline(never)] // don't inline so that the profiler can get proper data
+#[allow(clippy::too_many_arguments)]
+fn encode_row_range<W: Write, C: CoefficientCodec, const ESTIMATE: bool>(
+    pts: &ProbabilityTablesSet,
+    quantization_tables: &[QuantizationTables],
+    image_data: &[BlockBasedImage],
+    bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+    thread_id: i32,
+    colldata: &TruncateComponents,
+    min_y: i32,
+    max_y: i32,
+    is_last_thread: bool,
+    full_file_compression: bool,
+    model: &mut C,
+    mut cr_model: Option<&mut C>,
+) -> Result<Metrics> {
+    #[cfg(feature = "experimental-tuning")]
+    bool_writer.set_tuning(model.model().get_tuning());
+    #[cfg(feature = "debug-symmetry")]
+    bool_writer.set_symmetry_log(model.model().new_symmetry_log(min_y));
+
     let mut is_top_row = Vec::new();
     let mut num_non_zeros = Vec::new();
 
@@ -83,6 +189,10 @@ pub fn lepton_encode_row_range<W: Write>(
             continue;
         }
 
+        if model.model().is_cancelled() {
+            return err_cancelled();
+        }
+
         // Advance to next row to cache expended block data for current row. Should be called before getting block context.
         let bt = cur_row.component;
 
@@ -90,11 +200,17 @@ pub fn lepton_encode_row_range<W: Write>(
 
         let block_width = image_data[bt].get_block_width();
 
+        // with separate chroma models, Cr has its own model instead of sharing the chroma model with Cb
+        let model = match cr_model.as_deref_mut() {
+            Some(cr_model) if bt == 2 => cr_model,
+            _ => &mut *model,
+        };
+
         if is_top_row[bt] {
             is_top_row[bt] = false;
             process_row(
-                &mut model,
-                &mut bool_writer,
+                model,
+                bool_writer,
                 &image_data[bt],
                 &quantization_tables[bt],
                 &pts.corner[bt],
@@ -104,13 +220,14 @@ pub fn lepton_encode_row_range<W: Write>(
                 &mut block_context,
                 &mut num_non_zeros[bt][..],
                 block_width,
+                bt,
                 component_size_in_blocks[bt],
             )
             .context(here!())?;
         } else if block_width > 1 {
             process_row(
-                &mut model,
-                &mut bool_writer,
+                model,
+                bool_writer,
                 &image_data[bt],
                 &quantization_tables[bt],
                 &pts.mid_left[bt],
@@ -120,14 +237,15 @@ pub fn lepton_encode_row_range<W: Write>(
                 &mut block_context,
                 &mut num_non_zeros[bt][..],
                 block_width,
+                bt,
                 component_size_in_blocks[bt],
             )
             .context(here!())?;
         } else {
             assert!(block_width == 1, "block_width == 1");
             process_row(
-                &mut model,
-                &mut bool_writer,
+                model,
+                bool_writer,
                 &image_data[bt],
                 &quantization_tables[bt],
                 &pts.width_one[bt],
@@ -137,6 +255,7 @@ pub fn lepton_encode_row_range<W: Write>(
                 &mut block_context,
                 &mut num_non_zeros[bt][..],
                 block_width,
+                bt,
                 component_size_in_blocks[bt],
             )
             .context(here!())?;
@@ -164,13 +283,24 @@ pub fn lepton_encode_row_range<W: Write>(
 
     bool_writer.finish().context(here!())?;
 
-    Ok(bool_writer.drain_stats())
+    let mut metrics = bool_writer.drain_stats();
+    metrics.record_segment_bytes(thread_id as usize, bool_writer.bytes_flushed());
+
+    #[cfg(feature = "stats")]
+    {
+        metrics.record_branch_usage(model.model().branch_usage(thread_id, 0));
+        if let Some(cr_model) = cr_model {
+            metrics.record_branch_usage(cr_model.model().branch_usage(thread_id, 1));
+        }
+    }
+
+    Ok(metrics)
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn process_row<W: Write>(
-    model: &mut Model,
-    bool_writer: &mut VPXBoolWriter<W>,
+fn process_row<W: Write, C: CoefficientCodec, const ESTIMATE: bool>(
+    model: &mut C,
+    bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
     image_data: &BlockBasedImage,
     qt: &QuantizationTables,
     left_model: &ProbabilityTables,
@@ -180,14 +310,19 @@ fn process_row<W: Write>(
     state: &mut BlockContext,
     num_non_zeros: &mut [NeighborSummary],
     block_width: i32,
+    component: usize,
     component_size_in_block: i32,
 ) -> Result<()> {
     if block_width > 0 {
         state
             .neighbor_context_here(num_non_zeros)
             .set_num_non_zeros(state.here(image_data).get_count_of_non_zeros_7x7());
+        model
+            .model()
+            .call_snapshot_hook(component, state.get_here_index());
+        bool_writer.start_block(component, state.get_here_index());
 
-        serialize_tokens::<W, false>(
+        serialize_tokens::<W, C, false, ESTIMATE>(
             state,
             qt,
             left_model,
@@ -208,10 +343,14 @@ fn process_row<W: Write>(
         state
             .neighbor_context_here(num_non_zeros)
             .set_num_non_zeros(state.here(image_data).get_count_of_non_zeros_7x7());
+        model
+            .model()
+            .call_snapshot_hook(component, state.get_here_index());
+        bool_writer.start_block(component, state.get_here_index());
 
         // shortcut all the checks for the presence of left/right components by passing a constant generic parameter
         if middle_model.is_all_present() {
-            serialize_tokens::<W, true>(
+            serialize_tokens::<W, C, true, ESTIMATE>(
                 state,
                 qt,
                 middle_model,
@@ -222,7 +361,7 @@ fn process_row<W: Write>(
             )
             .context(here!())?;
         } else {
-            serialize_tokens::<W, false>(
+            serialize_tokens::<W, C, false, ESTIMATE>(
                 state,
                 qt,
                 middle_model,
@@ -245,9 +384,13 @@ fn process_row<W: Write>(
         state
             .neighbor_context_here(num_non_zeros)
             .set_num_non_zeros(state.here(image_data).get_count_of_non_zeros_7x7());
+        model
+            .model()
+            .call_snapshot_hook(component, state.get_here_index());
+        bool_writer.start_block(component, state.get_here_index());
 
         if right_model.is_all_present() {
-            serialize_tokens::<W, true>(
+            serialize_tokens::<W, C, true, ESTIMATE>(
                 state,
                 qt,
                 right_model,
@@ -258,7 +401,7 @@ fn process_row<W: Write>(
             )
             .context(here!())?;
         } else {
-            serialize_tokens::<W, false>(
+            serialize_tokens::<W, C, false, ESTIMATE>(
                 state,
                 qt,
                 right_model,
@@ -276,19 +419,37 @@ fn process_row<W: Write>(
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn serialize_tokens<W: Write, const ALL_PRESENT: bool>(
+pub fn serialize_tokens<
+    W: Write,
+    C: CoefficientCodec,
+    const ALL_PRESENT: bool,
+    const ESTIMATE: bool,
+>(
     context: &mut BlockContext,
     qt: &QuantizationTables,
     pt: &ProbabilityTables,
-    model: &mut Model,
+    model: &mut C,
     image_data: &BlockBasedImage,
     num_non_zeros: &mut [NeighborSummary],
-    bool_writer: &mut VPXBoolWriter<W>,
+    bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
 ) -> Result<()> {
     debug_assert!(ALL_PRESENT == pt.is_all_present());
 
+    // the contexts of the blocks that come after this one are derived from its coefficients, and a magnitude with
+    // more bits than the model has branches for would index past them
+    if let Some((index, value)) = context.here(image_data).find_coefficient_out_of_range() {
+        return err_coefficient_out_of_range(
+            pt.get_component(),
+            context.get_here_index(),
+            index,
+            value.into(),
+        );
+    }
+
     let num_non_zeros_7x7 = context.non_zeros_here(&num_non_zeros);
 
+    #[cfg(feature = "debug-symmetry")]
+    bool_writer.set_symmetry_coefficient(NON_ZEROS_7X7);
     model
         .write_non_zero_7x7_count(
             bool_writer,
@@ -325,6 +486,8 @@ fn serialize_tokens<W: Write, const ALL_PRESENT: bool>(
         let coef = block.get_coefficient(zig49);
         let coord = UNZIGZAG_49[zig49];
 
+        #[cfg(feature = "debug-symmetry")]
+        bool_writer.set_symmetry_coefficient(coord);
         model
             .write_coef(
                 bool_writer,
@@ -350,7 +513,7 @@ fn serialize_tokens<W: Write, const ALL_PRESENT: bool>(
         }
     }
 
-    encode_edge::<W, ALL_PRESENT>(
+    encode_edge::<W, C, ALL_PRESENT, ESTIMATE>(
         context,
         image_data,
         model,
@@ -383,6 +546,8 @@ fn serialize_tokens<W: Write, const ALL_PRESENT: bool>(
     }
 
     // do DC
+    #[cfg(feature = "debug-symmetry")]
+    bool_writer.set_symmetry_coefficient(0);
     model
         .write_dc(
             bool_writer,
@@ -411,18 +576,18 @@ fn serialize_tokens<W: Write, const ALL_PRESENT: bool>(
 }
 
 #[inline(never)] // don't inline so that the profiler can get proper data
-fn encode_edge<W: Write, const ALL_PRESENT: bool>(
+fn encode_edge<W: Write, C: CoefficientCodec, const ALL_PRESENT: bool, const ESTIMATE: bool>(
     context: &BlockContext,
     image_data: &BlockBasedImage,
-    model: &mut Model,
-    bool_writer: &mut VPXBoolWriter<W>,
+    model: &mut C,
+    bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
     qt: &QuantizationTables,
     pt: &ProbabilityTables,
     num_non_zeros_7x7: u8,
     eob_x: u8,
     eob_y: u8,
 ) -> Result<()> {
-    encode_one_edge::<W, ALL_PRESENT, true>(
+    encode_one_edge::<W, C, ALL_PRESENT, true, ESTIMATE>(
         context,
         image_data,
         model,
@@ -433,7 +598,7 @@ fn encode_edge<W: Write, const ALL_PRESENT: bool>(
         eob_x,
     )
     .context(here!())?;
-    encode_one_edge::<W, ALL_PRESENT, false>(
+    encode_one_edge::<W, C, ALL_PRESENT, false, ESTIMATE>(
         context,
         image_data,
         model,
@@ -455,11 +620,17 @@ fn count_non_zero(v: i16) -> u8 {
     }
 }
 
-fn encode_one_edge<W: Write, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
+fn encode_one_edge<
+    W: Write,
+    C: CoefficientCodec,
+    const ALL_PRESENT: bool,
+    const HORIZONTAL: bool,
+    const ESTIMATE: bool,
+>(
     block_context: &BlockContext,
     image_data: &BlockBasedImage,
-    model: &mut Model,
-    bool_writer: &mut VPXBoolWriter<W>,
+    model: &mut C,
+    bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
     qt: &QuantizationTables,
     pt: &ProbabilityTables,
     num_non_zeros_7x7: u8,
@@ -487,8 +658,14 @@ fn encode_one_edge<W: Write, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
             + count_non_zero(block.get_coefficient_raster(7 * 8));
     }
 
+    #[cfg(feature = "debug-symmetry")]
+    bool_writer.set_symmetry_coefficient(if HORIZONTAL {
+        NON_ZEROS_ROW
+    } else {
+        NON_ZEROS_COLUMN
+    });
     model
-        .write_non_zero_edge_count::<W, HORIZONTAL>(
+        .write_non_zero_edge_count::<W, HORIZONTAL, ESTIMATE>(
             bool_writer,
             pt.get_color_index(),
             est_eob,
@@ -544,6 +721,8 @@ fn encode_one_edge<W: Write, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
 
         let coef = block.get_coefficient((aligned_block_offset + (lane << log_edge_step)) as usize);
 
+        #[cfg(feature = "debug-symmetry")]
+        bool_writer.set_symmetry_coefficient(coord as u8);
         model
             .write_edge_coefficient(bool_writer, qt, pt, coef, coord, zig15offset, &ptcc8)
             .context(here!())?;
@@ -558,3 +737,67 @@ fn encode_one_edge<W: Write, const ALL_PRESENT: bool, const HORIZONTAL: bool>(
 
     Ok(())
 }
+
+/// a coefficient that the model can't code is refused before it is coded or taken as a context
+#[test]
+fn coefficient_out_of_range_is_refused() {
+    use std::io::{self, Cursor};
+
+    use default_boxed::DefaultBoxed;
+
+    use crate::enabled_features::EnabledFeatures;
+    use crate::lepton_error::LeptonError;
+    use crate::structs::lepton_format::{get_quantization_tables, read_jpeg};
+
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("tiny.jpg"),
+    )
+    .unwrap();
+
+    let (lp, mut image_data) = read_jpeg(
+        &mut Cursor::new(&jpeg),
+        &EnabledFeatures::default(),
+        1,
+        |_jh| {},
+    )
+    .unwrap();
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+
+    let encode = |image_data: &[BlockBasedImage]| {
+        lepton_encode_row_range(
+            &ProbabilityTablesSet::new(false, false),
+            &qt,
+            image_data,
+            &mut io::sink(),
+            0,
+            &lp.truncate_components,
+            0,
+            lp.jpeg_header.cmp_info[0].bcv,
+            true,
+            true,
+            &mut Model::default_boxed(),
+            None,
+            CoefficientCodecKind::Model,
+        )
+    };
+
+    // 2047 is the largest magnitude that fits, and zigzag index 4 is raster index 9
+    image_data[0]
+        .get_block_mut(1)
+        .set_coefficient_zigzag(4, -2047);
+    encode(&image_data).unwrap();
+
+    image_data[0]
+        .get_block_mut(1)
+        .set_coefficient_zigzag(4, 4096);
+    let e = encode(&image_data).unwrap_err();
+
+    let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+    assert_eq!(e.exit_code, ExitCode::CoefficientOutOfRange);
+    assert_eq!(
+        e.message,
+        "CoefficientOutOfRange { component: 0, dpos: 1, index: 9, value: 4096 }"
+    );
+}
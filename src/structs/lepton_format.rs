@@ -7,40 +7,66 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use cpu_time::ThreadTime;
 use log::{info, warn};
+use std::cell::Cell;
 use std::cmp;
-use std::io::{Cursor, ErrorKind, Read, Seek, SeekFrom, Write};
-use std::mem::swap;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Cursor, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::mem::{self, swap};
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc::Receiver;
-use std::sync::mpsc::{channel, Sender};
-use std::thread;
-use std::thread::ScopedJoinHandle;
+use std::sync::mpsc::{
+    channel, sync_channel, RecvError, Sender, SyncSender, TryRecvError, TrySendError,
+};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use anyhow::{Context, Result};
 
-use flate2::read::ZlibDecoder;
+use flate2::bufread::ZlibDecoder;
 use flate2::write::ZlibEncoder;
-use flate2::Compression;
+use flate2::{Compression, Crc};
 
 use crate::consts::*;
-use crate::enabled_features::EnabledFeatures;
+use crate::enabled_features::{
+    CoefficientCodecKind, EnabledFeatures, EncodeMode, LeptonVersion, MemoryBudgetPolicy,
+    ModelTuning,
+};
 use crate::helpers::*;
 use crate::jpeg_code;
 use crate::lepton_error::ExitCode;
 use crate::metrics::Metrics;
+use crate::multiplexer::format::{write_chunks, ChunkHeader};
+use crate::structs::bit_reader::IntervalSegments;
 use crate::structs::bit_writer::BitWriter;
 use crate::structs::block_based_image::BlockBasedImage;
+use crate::structs::chained_reader::ChainedReader;
+use crate::structs::chunk_writer::ChunkWriter;
+use crate::structs::cpu_cores::resolve_max_threads;
+use crate::structs::crc_reader::{CrcReader, CrcWriter};
+use crate::structs::huffman_optimizer::HuffmanFrequencies;
+use crate::structs::initial_probs::ModelInit;
 use crate::structs::jpeg_header::JPegHeader;
 use crate::structs::jpeg_write::jpeg_write_row_range;
 use crate::structs::lepton_decoder::lepton_decode_row_range;
 use crate::structs::lepton_encoder::lepton_encode_row_range;
+use crate::structs::memory_tracker::MemoryTracker;
+use crate::structs::model::{Model, ModelSnapshotHook, MAX_MODEL_MEMORY};
+use crate::structs::model_primer::ModelPrimer;
+use crate::structs::prefetch_reader::with_prefetch;
 use crate::structs::probability_tables_set::ProbabilityTablesSet;
 use crate::structs::quantization_tables::QuantizationTables;
+use crate::structs::read_ahead::ReadAheadBudget;
+use crate::structs::segment_verifier::SegmentVerifier;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::SymmetryRecorder;
 use crate::structs::thread_handoff::ThreadHandoff;
+use crate::structs::thread_lifecycle::{run_segment, ThreadLifecycleHook, WorkerKind};
+use crate::structs::thread_pool;
 use crate::structs::truncate_components::TruncateComponents;
 
 use super::jpeg_read::{read_progressive_scan, read_scan};
-use super::jpeg_write::jpeg_write_entire_scan;
+use super::jpeg_write::{compute_size, encode_rows_from, jpeg_write_entire_scan, EncodedRows};
 
 /// reads a lepton file and writes it out as a jpeg
 pub fn decode_lepton_wrapper<R: Read + Seek, W: Write>(
@@ -48,1618 +74,7451 @@ pub fn decode_lepton_wrapper<R: Read + Seek, W: Write>(
     writer: &mut W,
     num_threads: usize,
 ) -> Result<Metrics> {
-    // figure out how long the input is
-    let orig_pos = reader.stream_position()?;
-    let size = reader.seek(SeekFrom::End(0))?;
-    reader.seek(SeekFrom::Start(orig_pos))?;
+    decode_lepton_wrapper_with_features(reader, writer, num_threads, &EnabledFeatures::default())
+}
 
+/// same as decode_lepton_wrapper, but with the limits the decoder enforces (max_trailing_bytes and the
+/// features of format_version) taken from enabled_features
+pub fn decode_lepton_wrapper_with_features<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Metrics> {
     let mut lh = LeptonHeader::new();
+    lh.set_decoder_features(enabled_features);
 
     lh.read_lepton_header(reader).context(here!())?;
+    let remaining_size = get_remaining_size(reader).context(here!())?;
 
     let metrics = lh
-        .recode_jpeg(writer, reader, size, num_threads)
+        .recode_jpeg(writer, reader, Some(remaining_size), num_threads)
         .context(here!())?;
 
     return Ok(metrics);
 }
 
-/// reads a jpeg and writes it out as a lepton file
-pub fn encode_lepton_wrapper<R: Read + Seek, W: Write + Seek>(
+/// reads a lepton file from a stream and writes it out as a jpeg, without needing to know the size of the input.
+/// Each thread starts decoding as soon as the first chunks of its data arrive. For baseline images written with a
+/// segment index the output of a segment is written as soon as the next segment starts arriving, since only then
+/// are the segments stored one after the other rather than interleaved up to the end of the file.
+pub fn decode_lepton_wrapper_streaming<R: Read, W: Write>(
     reader: &mut R,
     writer: &mut W,
-    max_threads: usize,
-    enabled_features: &EnabledFeatures,
+    num_threads: usize,
 ) -> Result<Metrics> {
-    let (lp, image_data) = read_jpeg(reader, enabled_features, max_threads, |_jh| {})?;
+    let mut lh = LeptonHeader::new();
 
-    lp.write_lepton_header(writer).context(here!())?;
+    lh.read_lepton_header(reader).context(here!())?;
 
-    let metrics = run_lepton_encoder_threads(
-        &lp.jpeg_header,
-        &lp.truncate_components,
-        writer,
-        &lp.thread_handoff[..],
-        &image_data[..],
-    )
-    .context(here!())?;
+    lh.recode_jpeg(writer, reader, None, num_threads)
+        .context(here!())
+}
 
-    let final_file_size = writer.stream_position()? + 4;
+/// same as decode_lepton_wrapper_streaming, but a job of the thread pool keeps reading the input ahead while the
+/// decoder waits for the segments that are done to be written out, up to enabled_features.max_read_ahead bytes on
+/// top of what is held for the workers, so that a slow input doesn't stop arriving while the segments that already
+/// arrived are decoded. Otherwise the time it takes adds up to the time the input takes to arrive and the time the
+/// segments take to decode, rather than being about the time the input takes.
+pub fn decode_lepton_wrapper_prefetch<R: Read + Send, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<Metrics> {
+    with_prefetch(reader, enabled_features.max_read_ahead, |reader| {
+        let mut lh = LeptonHeader::new();
+        lh.set_decoder_features(enabled_features);
 
-    writer
-        .write_u32::<LittleEndian>(final_file_size as u32)
-        .context(here!())?;
+        lh.read_lepton_header(reader).context(here!())?;
 
-    Ok(metrics)
+        lh.recode_jpeg(writer, reader, None, num_threads)
+            .context(here!())
+    })
 }
 
-/// Encodes JPEG as compressed Lepton format, verifies roundtrip in buffer. Requires everything to be buffered
-/// since we need to pass through the data multiple times
-pub fn encode_lepton_wrapper_verify(
-    input_data: &[u8],
-    max_threads: usize,
-    enabled_features: &EnabledFeatures,
-) -> Result<(Vec<u8>, Metrics)> {
-    let mut output_data = Vec::with_capacity(input_data.len());
+/// reads just the header of a lepton file to find out the exact size of the original JPEG, if it was recorded
+pub fn read_original_file_size_wrapper<R: Read>(reader: &mut R) -> Result<Option<u64>> {
+    let mut lh = LeptonHeader::new();
 
-    info!("compressing to Lepton format");
+    lh.read_lepton_header(reader).context(here!())?;
 
-    let mut reader = Cursor::new(&input_data);
-    let mut writer = Cursor::new(&mut output_data);
+    Ok(lh.original_file_size)
+}
 
-    let mut metrics = encode_lepton_wrapper(
-        &mut reader,
-        &mut writer,
-        max_threads as usize,
-        &enabled_features,
-    )
-    .context(here!())?;
+/// returns the data that followed the end of the JPEG image in the original file (for example the video of a
+/// motion photo). It is stored in the header, so the thread segments after it aren't read at all.
+pub fn extract_trailing_data_wrapper<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut lh = LeptonHeader::new();
 
-    // decode and compare to original in order to enure we encoded correctly
+    lh.read_lepton_header(reader).context(here!())?;
 
-    let mut verify_buffer = Vec::with_capacity(input_data.len());
-    let mut verifyreader = Cursor::new(&output_data[..]);
+    lh.check_not_passthrough().context(here!())?;
 
-    info!("decompressing to verify contents");
+    // the EOI is usually stored along with the data after it, since the scan reader consumes it
+    if lh.garbage_data.starts_with(&EOI) {
+        lh.garbage_data.drain(..EOI.len());
+    }
 
-    metrics.merge_from(
-        decode_lepton_wrapper(&mut verifyreader, &mut verify_buffer, max_threads)
-            .context(here!())?,
-    );
+    Ok(lh.garbage_data)
+}
 
-    if input_data.len() != verify_buffer.len() {
+/// changes that rewrite_header can make to a lepton file. They only cover the parts of the file that check
+/// the output or help tools find their way around it, so the file still decodes to exactly the same JPEG.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeaderEdit {
+    /// recompute the CRC of the data of each thread segment and store them, adding the table if the file
+    /// doesn't have one yet
+    pub recompute_segment_checksums: bool,
+    /// store this as the size of the original file. It has to be the size of the JPEG the file decodes to,
+    /// so this fixes files that were written with a wrong size or without one.
+    pub original_file_size: Option<u64>,
+    /// remove the CRC of the original file
+    pub remove_original_file_crc: bool,
+    /// remove the size of the original file
+    pub remove_original_file_size: bool,
+    /// remove the segment index
+    pub remove_segment_index: bool,
+    /// remove the table of segment checksums
+    pub remove_segment_checksums: bool,
+    /// remove the record of the encoder build that wrote the file
+    pub remove_encoder_info: bool,
+}
+
+/// applies edit to the header of a lepton file without re-encoding anything. The multiplexed thread data
+/// (and the segment index unless it is removed) is copied to the new file byte for byte. Edits that would
+/// change what the file decodes to are refused.
+pub fn rewrite_header(input: &[u8], edit: HeaderEdit) -> Result<Vec<u8>> {
+    if edit.recompute_segment_checksums && edit.remove_segment_checksums {
         return err_exit_code(
-            ExitCode::VerificationLengthMismatch,
-            format!(
-                "ERROR mismatch input_len = {0}, decoded_len = {1}",
-                input_data.len(),
-                verify_buffer.len()
-            )
-            .as_str(),
+            ExitCode::SyntaxError,
+            "segment checksums can't be both recomputed and removed",
         );
     }
-
-    if input_data[..] != verify_buffer[..] {
+    if edit.original_file_size.is_some() && edit.remove_original_file_size {
         return err_exit_code(
-            ExitCode::VerificationContentMismatch,
-            "ERROR mismatching data (but same size)",
+            ExitCode::SyntaxError,
+            "original file size can't be both set and removed",
         );
     }
 
-    Ok((output_data, metrics))
-}
+    let mut reader = Cursor::new(input);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).context(here!())?;
 
-/// reads JPEG and returns corresponding header and image vector. This encapsulate all
-/// JPEG reading code, including baseline and progressive images.
-///
-/// The callback is called for each jpeg header that is parsed, which
-/// is currently only used by the dump utility for debugging purposes.
-pub fn read_jpeg<R: Read + Seek>(
-    reader: &mut R,
-    enabled_features: &EnabledFeatures,
-    max_threads: usize,
-    callback: fn(&JPegHeader),
-) -> Result<(LeptonHeader, Vec<BlockBasedImage>)> {
-    let mut startheader = [0u8; 2];
-    reader.read_exact(&mut startheader)?;
-    if startheader[0] != 0xFF || startheader[1] != jpeg_code::SOI {
-        return err_exit_code(ExitCode::UnsupportedJpeg, "header invalid");
-    }
+    let header_end = reader.position() as usize;
 
-    let mut lp = LeptonHeader::new();
-    if !prepare_to_decode_next_scan(&mut lp, reader, enabled_features).context(here!())? {
-        return err_exit_code(ExitCode::UnsupportedJpeg, "JPeg does not contain scans");
+    if let Some(size) = edit.original_file_size {
+        if size != lh.plain_text_size {
+            return err_exit_code(
+                ExitCode::SyntaxError,
+                format!(
+                    "file decodes to {0} bytes, so an original size of {1} bytes would make decoding fail",
+                    lh.plain_text_size, size
+                )
+                .as_str(),
+            );
+        }
     }
 
-    callback(&lp.jpeg_header);
-
-    if !enabled_features.progressive && lp.jpeg_header.jpeg_type == JPegType::Progressive {
+    let trailer_len = (lh.get_segment_checksums_len() + lh.get_file_size_len()) as usize;
+    if input.len() < header_end + trailer_len {
         return err_exit_code(
-            ExitCode::ProgressiveUnsupported,
-            "file is progressive, but this is disabled",
-        )
-        .context(here!());
+            ExitCode::BadLeptonFile,
+            "file is too short for the data after the header",
+        );
     }
+    let trailer_start = input.len() - trailer_len;
 
-    if lp.jpeg_header.cmpc > COLOR_CHANNEL_NUM_BLOCK_TYPES {
-        return err_exit_code(
-            ExitCode::Unsupported4Colors,
-            " can't support this kind of image",
+    // the multiplexed data ends where the segment index starts, if there is one
+    let data_end = match lh.segment_index_offset {
+        Some(offset) => {
+            let end = header_end as u64 + offset;
+            if end > trailer_start as u64 {
+                return err_exit_code(
+                    ExitCode::BadLeptonFile,
+                    "segment index starts after the end of the file",
+                );
+            }
+            end as usize
+        }
+        None => trailer_start,
+    };
+
+    let mut segment_checksums = if lh.segment_checksums {
+        Some(
+            lh.read_segment_checksums(&input[trailer_start..])
+                .context(here!())?,
         )
-        .context(here!());
-    }
+    } else {
+        None
+    };
 
-    lp.truncate_components.init(&lp.jpeg_header);
-    let mut image_data = Vec::<BlockBasedImage>::new();
-    for i in 0..lp.jpeg_header.cmpc {
-        // constructor takes height in proportion to the component[0]
-        image_data.push(BlockBasedImage::new(
-            &lp.jpeg_header,
-            i,
-            0,
-            lp.jpeg_header.cmp_info[0].bcv,
-        ));
-    }
+    if edit.recompute_segment_checksums {
+        lh.check_not_passthrough().context(here!())?;
 
-    let mut thread_handoff = Vec::<ThreadHandoff>::new();
-    let start_scan = reader.stream_position()? as i32;
-    read_scan(&mut lp, reader, &mut thread_handoff, &mut image_data[..]).context(here!())?;
-    lp.scnc += 1;
+        let mut crcs: Vec<Crc> = lh.thread_handoff.iter().map(|_| Crc::new()).collect();
 
-    let mut end_scan = reader.stream_position()? as i32;
+        let mut chunk_reader = ChunkReader::new(
+            &input[header_end..data_end],
+            Some((data_end - header_end) as u64),
+        );
+        while let Some((thread_id, buffer)) = chunk_reader.next_chunk().context(here!())? {
+            match crcs.get_mut(usize::from(thread_id)) {
+                Some(crc) => crc.update(&buffer),
+                None => return err_exit_code(ExitCode::BadLeptonFile, "invalid thread_id"),
+            }
+        }
 
-    // need at least two bytes of scan data
-    if start_scan + 2 > end_scan || thread_handoff.len() == 0 {
-        return err_exit_code(
-            ExitCode::UnsupportedJpeg,
-            "couldnt find any sections to encode",
-        )
-        .context(here!());
+        segment_checksums = Some(crcs.iter().map(|c| c.sum()).collect());
     }
 
-    for i in 0..thread_handoff.len() {
-        thread_handoff[i].segment_offset_in_file += start_scan;
+    if edit.remove_segment_checksums {
+        segment_checksums = None;
+    }
+    if edit.remove_segment_index {
+        lh.segment_index_offset = None;
+    }
+    if edit.remove_original_file_crc {
+        lh.original_file_crc = None;
+    }
+    if edit.remove_original_file_size {
+        lh.original_file_size = None;
+    }
+    if let Some(size) = edit.original_file_size {
+        lh.original_file_size = Some(size);
+    }
+    if edit.remove_encoder_info {
+        lh.encoder_info = None;
+    }
 
-        #[cfg(feature = "detailed_tracing")]
-        info!(
-            "Crystalize: s:{0} ls: {1} le: {2} o: {3} nb: {4}",
-            thread_handoff[i].segment_offset_in_file,
-            thread_handoff[i].luma_y_start,
-            thread_handoff[i].luma_y_end,
-            thread_handoff[i].overhang_byte,
-            thread_handoff[i].num_overhang_bits
-        );
+    lh.segment_checksums = segment_checksums.is_some();
+    lh.jpeg_file_size = lh.plain_text_size;
+
+    // the number of scans isn't stored, so keep the feature bit that the encoder derived from it
+    if input[8..10] == [b'M', b'S']
+        && u32::from_le_bytes(input[14..18].try_into()?) & LEPTON_FEATURE_MULTI_SCAN_SEQUENTIAL != 0
+    {
+        lh.scnc = 2;
     }
 
-    if lp.jpeg_header.jpeg_type == JPegType::Sequential {
-        if lp.early_eof_encountered {
-            lp.truncate_components
-                .set_truncation_bounds(&lp.jpeg_header, lp.max_dpos);
+    let mut output = Vec::with_capacity(input.len());
+    lh.write_lepton_header(&mut output).context(here!())?;
 
-            // If we got an early EOF, then seek backwards and capture the last two bytes and store them as garbage.
-            // This is necessary since the decoder will assume that zero garbage always means a properly terminated JPEG
-            // even if early EOF was set to true.
-            reader.seek(SeekFrom::Current(-2))?;
-            lp.garbage_data.resize(2, 0);
-            reader.read_exact(&mut lp.garbage_data)?;
+    output.extend_from_slice(&input[header_end..data_end]);
+    if lh.segment_index_offset.is_some() {
+        output.extend_from_slice(&input[data_end..trailer_start]);
+    }
 
-            // take these two last bytes off the last segment. For some reason the C++/CS version only chop of one byte
-            // and then fix up the broken file later in the decoder. The following logic will create a valid file
-            // that the C++ and CS version will still decode properly without the fixup logic.
-            let len = thread_handoff.len();
-            thread_handoff[len - 1].segment_size -= 2;
+    if let Some(segment_checksums) = segment_checksums {
+        for crc in segment_checksums {
+            output.write_u32::<LittleEndian>(crc)?;
         }
+    }
 
-        // rest of data is garbage data if it is a sequential jpeg (including EOI marker)
-        reader.read_to_end(&mut lp.garbage_data).context(here!())?;
-    } else {
-        assert!(lp.jpeg_header.jpeg_type == JPegType::Progressive);
+    let final_file_size = output.len() as u64 + lh.get_file_size_len();
+    lh.write_file_size(&mut output, final_file_size)
+        .context(here!())?;
 
-        if lp.early_eof_encountered {
-            return err_exit_code(
-                ExitCode::UnsupportedJpeg,
-                "truncation is only supported for baseline images",
-            )
-            .context(here!());
-        }
+    Ok(output)
+}
 
-        // for progressive images, loop around reading headers and decoding until we a complete image_data
-        while prepare_to_decode_next_scan(&mut lp, reader, enabled_features).context(here!())? {
-            callback(&lp.jpeg_header);
+/// reads just the header of a lepton file to find out which encoder build wrote it
+pub fn read_encoder_info_wrapper<R: Read>(reader: &mut R) -> Result<Option<EncoderInfo>> {
+    let mut lh = LeptonHeader::new();
 
-            read_progressive_scan(&mut lp, reader, &mut image_data[..]).context(here!())?;
-            lp.scnc += 1;
+    lh.read_lepton_header(reader).context(here!())?;
 
-            if lp.early_eof_encountered {
-                return err_exit_code(
-                    ExitCode::UnsupportedJpeg,
-                    "truncation is only supported for baseline images",
-                )
-                .context(here!());
-            }
-        }
+    Ok(lh.encoder_info)
+}
 
-        end_scan = reader.stream_position()? as i32;
+/// reads just the header of a lepton file to find out whether the JPEG is baseline or progressive and how
+/// many scans it has
+pub fn read_lepton_file_type_wrapper<R: Read>(reader: &mut R) -> Result<LeptonFileType> {
+    let mut lh = LeptonHeader::new();
 
-        // since prepare_to_decode_next_scan consumes the EOI,
-        // we need to add it to the beginning of the garbage data (if there is any)
-        lp.garbage_data = Vec::from(EOI);
+    lh.read_lepton_header(reader).context(here!())?;
 
-        // append the rest of the file to the buffer
-        if reader.read_to_end(&mut lp.garbage_data).context(here!())? == 0 {
-            // no need to record EOI garbage data if there wasn't anything read
-            lp.garbage_data.clear();
-        }
-    }
+    lh.check_not_passthrough().context(here!())?;
 
-    set_segment_size_in_row_thread_handoffs(&mut thread_handoff[..], end_scan as i32);
-    let merged_handoffs = split_row_handoffs_to_threads(&thread_handoff[..], max_threads);
-    lp.thread_handoff = merged_handoffs;
-    lp.jpeg_file_size = reader.stream_position().context(here!())? as u32;
-    Ok((lp, image_data))
+    match lh.file_type {
+        Some(file_type) => Ok(file_type),
+        None => err_exit_code(ExitCode::BadLeptonFile, "file type missing"),
+    }
 }
 
-fn run_lepton_decoder_threads<R: Read + Seek, P: Send>(
-    lh: &LeptonHeader,
+/// reads a lepton file and writes the jpeg into the output buffer. The size of the original file is known from
+/// the lepton header, so if the buffer is too small we fail before doing any decoding work. Either way result_size
+/// is set to the size of the jpeg so the caller can retry with a big enough buffer.
+pub fn decode_lepton_wrapper_into<R: Read + Seek>(
     reader: &mut R,
-    last_data_position: u64,
-    max_threads_to_use: usize,
-    process: fn(
-        thread_handoff: &ThreadHandoff,
-        image_data: Vec<BlockBasedImage>,
-        lh: &LeptonHeader,
-    ) -> Result<P>,
-) -> Result<(Metrics, Vec<P>)> {
-    let wall_time = Instant::now();
+    output: &mut [u8],
+    num_threads: usize,
+    result_size: &mut u64,
+) -> Result<Metrics> {
+    let mut lh = LeptonHeader::new();
 
-    let pts = ProbabilityTablesSet::new();
-    let mut qt = Vec::new();
-    for i in 0..lh.jpeg_header.cmpc {
-        let qtables = QuantizationTables::new(&lh.jpeg_header, i);
+    lh.read_lepton_header(reader).context(here!())?;
+    let remaining_size = get_remaining_size(reader).context(here!())?;
 
-        // check to see if quantitization table was properly initialized
-        // (table contains divisors for coefficients so it never should have a zero)
-        if qtables.get_quantization_table()[0] == 0 {
-            return err_exit_code(ExitCode::UnsupportedJpeg, "Quantization table is missing");
-        }
-        qt.push(qtables);
-    }
+    *result_size = lh.plain_text_size;
 
-    let r = thread::scope(|s| -> Result<(Metrics, Vec<P>)> {
-        let mut running_threads: Vec<ScopedJoinHandle<Result<(P, Metrics)>>> = Vec::new();
-        let mut channel_to_sender = Vec::new();
+    if lh.plain_text_size > output.len() as u64 {
+        return err_exit_code(
+            ExitCode::BufferTooSmall,
+            format!(
+                "output buffer of {0} bytes is too small for the {1} byte jpeg",
+                output.len(),
+                lh.plain_text_size
+            )
+            .as_str(),
+        );
+    }
 
-        let pts_ref = &pts;
-        let q_ref = &qt[..];
+    let mut writer = Cursor::new(output);
 
-        // don't use more threads than we need
-        let m = cmp::min(max_threads_to_use, lh.thread_handoff.len());
+    let metrics = lh
+        .recode_jpeg(&mut writer, reader, Some(remaining_size), num_threads)
+        .context(here!())?;
 
-        info!(
-            "decoding {0} multipexed streams with {1} threads",
-            lh.thread_handoff.len(),
-            m
-        );
+    *result_size = writer.position();
 
-        // ratio of threads to work items
-        let ratio = lh.thread_handoff.len() as f32 / m as f32;
+    Ok(metrics)
+}
 
-        for t in 0..m {
-            let start = (t as f32 * ratio) as usize;
-            let end = ((t + 1) as f32 * ratio) as usize;
+/// computes the exact size of the JPEG that decoding the lepton file would produce, without
+/// keeping any of the output. Useful when the caller needs to allocate the output buffer up front.
+pub fn compute_decoded_size_wrapper<R: Read + Seek>(
+    reader: &mut R,
+    num_threads: usize,
+) -> Result<u64> {
+    let mut lh = LeptonHeader::new();
 
-            let iter_per_thread = end - start;
+    lh.read_lepton_header(reader).context(here!())?;
+    let remaining_size = get_remaining_size(reader).context(here!())?;
 
-            let mut rx_channels = Vec::new();
-            for _k in 0..iter_per_thread {
-                let (tx, rx) = channel();
-                channel_to_sender.push(tx);
-                rx_channels.push(Some(rx));
-            }
+    compute_size(|mut writer| {
+        lh.recode_jpeg(&mut writer, reader, Some(remaining_size), num_threads)
+            .context(here!())?;
+        Ok(())
+    })
+}
 
-            running_threads.push(s.spawn(move || -> Result<(P, Metrics)> {
-                let cpu_time = ThreadTime::now();
+/// reads a lepton file and hands the recreated jpeg to the callback in chunks of chunk_size bytes (except
+/// for the last one), so that the caller doesn't need to keep the whole output in memory. If the callback
+/// fails, decoding stops and its error is returned.
+pub fn decode_lepton_wrapper_chunked<R: Read + Seek>(
+    reader: &mut R,
+    chunk_size: usize,
+    num_threads: usize,
+    callback: impl FnMut(&[u8]) -> Result<()>,
+) -> Result<Metrics> {
+    if chunk_size == 0 {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            "chunk size must be at least one byte",
+        );
+    }
 
-                // determine how much we are going to write in total to presize the buffer
-                let mut decoded_size = 0;
-                for thread_id in start..end {
-                    decoded_size += lh.thread_handoff[thread_id].segment_size as usize;
-                }
+    let mut lh = LeptonHeader::new();
 
-                // create a combined handoff that merges all the sections we have read so we process them in one go
-                let combined_thread_handoff = ThreadHandoff {
-                    luma_y_start: lh.thread_handoff[start].luma_y_start,
-                    luma_y_end: lh.thread_handoff[end - 1].luma_y_end,
-                    segment_offset_in_file: lh.thread_handoff[start].segment_offset_in_file,
-                    segment_size: decoded_size as i32,
-                    overhang_byte: lh.thread_handoff[start].overhang_byte,
-                    num_overhang_bits: lh.thread_handoff[start].num_overhang_bits,
-                    last_dc: lh.thread_handoff[start].last_dc.clone(),
-                };
+    lh.read_lepton_header(reader).context(here!())?;
+    let remaining_size = get_remaining_size(reader).context(here!())?;
 
-                let mut image_data = Vec::new();
-                for i in 0..lh.jpeg_header.cmpc {
-                    image_data.push(BlockBasedImage::new(
-                        &lh.jpeg_header,
-                        i,
-                        combined_thread_handoff.luma_y_start,
-                        if t == m - 1 {
-                            // if this is the last thead, then the image should extend all the way to the bottom
-                            lh.jpeg_header.cmp_info[0].bcv
-                        } else {
-                            combined_thread_handoff.luma_y_end
-                        },
-                    ));
-                }
+    let mut writer = ChunkWriter::new(chunk_size, callback);
 
-                let mut metrics = Metrics::default();
+    let r = lh.recode_jpeg(&mut writer, reader, Some(remaining_size), num_threads);
 
-                // now run the range of thread handoffs in the file that this thread is supposed to handle
-                for thread_id in start..end {
-                    // get the appropriate receiver so we can read out data from it
-                    let rx = rx_channels[thread_id - start].take().context(here!())?;
-                    let mut reader = MessageReceiver {
-                        thread_id: thread_id as u8,
-                        current_buffer: Cursor::new(Vec::new()),
-                        receiver: rx,
-                        end_of_file: false,
-                    };
+    // the callback error is more useful than the io error it caused on the way out
+    if let Some(e) = writer.take_error() {
+        return Err(e);
+    }
 
-                    metrics.merge_from(
-                        lepton_decode_row_range(
-                            pts_ref,
-                            q_ref,
-                            &lh.truncate_components,
-                            &mut image_data,
-                            &mut reader,
-                            lh.thread_handoff[thread_id].luma_y_start,
-                            lh.thread_handoff[thread_id].luma_y_end,
-                            thread_id == lh.thread_handoff.len() - 1,
-                            true,
-                        )
-                        .context(here!())?,
-                    );
-                }
+    let metrics = r.context(here!())?;
 
-                let process_result = process(&combined_thread_handoff, image_data, lh)?;
+    writer.finish().context(here!())?;
 
-                metrics.record_cpu_worker_time(cpu_time.elapsed());
+    Ok(metrics)
+}
 
-                Ok((process_result, metrics))
-            }));
-        }
+/// reads a lepton file and writes it out as a baseline jpeg with optimal Huffman tables. The coefficients are
+/// identical to the original, but the output is smaller and no longer a byte exact copy of the original file.
+pub fn decode_lepton_wrapper_optimize_huffman<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+) -> Result<Metrics> {
+    let mut lh = LeptonHeader::new();
 
-        // now that the threads are waiting for inptut, read the stream and send all the buffers to their respective readers
-        while reader.stream_position().context(here!())? < last_data_position - 4 {
-            let thread_marker = reader.read_u8().context(here!())?;
-            let thread_id = (thread_marker & 0xf) as u8;
+    lh.read_lepton_header(reader).context(here!())?;
+    let remaining_size = get_remaining_size(reader).context(here!())?;
 
-            if thread_id >= channel_to_sender.len() as u8 {
-                return err_exit_code(
-                    ExitCode::BadLeptonFile,
-                    format!(
-                        "invalid thread_id at {0} of {1} at {2}",
-                        reader.stream_position().unwrap(),
-                        last_data_position,
-                        here!()
-                    )
-                    .as_str(),
-                );
-            }
+    let metrics = lh
+        .recode_jpeg_optimize_huffman(writer, reader, Some(remaining_size), num_threads)
+        .context(here!())?;
 
-            let data_length = if thread_marker < 16 {
-                let b0 = reader.read_u8().context(here!())?;
-                let b1 = reader.read_u8().context(here!())?;
+    Ok(metrics)
+}
 
-                ((b1 as usize) << 8) + b0 as usize + 1
-            } else {
-                // This format is used by Lepton C++ to write encoded chunks with length of 4096, 16384 or 65536 bytes
-                let flags = (thread_marker >> 4) & 3;
+/// recreates the entropy coded scan data of the MCU rows in mcu_row_range, decoding only the thread segments that
+/// overlap them. Since each segment starts with the coder state stored in its thread handoff, the rows before it
+/// are not needed. Requires a file written with a segment index, and only supports single scan baseline images.
+pub fn decode_rows_wrapper<R: Read + Seek>(
+    reader: &mut R,
+    mcu_row_range: Range<i32>,
+) -> Result<EncodedRows> {
+    let mut lh = LeptonHeader::new();
 
-                1024 << (2 * flags)
-            };
+    lh.read_lepton_header(reader).context(here!())?;
 
-            //info!("offset {0} len {1}", reader.stream_position()?-2, data_length);
+    let data_start = reader.stream_position().context(here!())?;
 
-            let mut buffer = Vec::<u8>::new();
-            buffer.resize(data_length as usize, 0);
-            reader.read_exact(&mut buffer).with_context(|| {
-                format!(
-                    "reading {0} bytes at {1} of {2} at {3}",
-                    buffer.len(),
-                    reader.stream_position().unwrap(),
-                    last_data_position,
-                    here!()
-                )
-            })?;
+    lh.check_not_passthrough().context(here!())?;
 
-            channel_to_sender[thread_id as usize]
-                .send(Message::WriteBlock(thread_id, buffer))
-                .context(here!())?;
-        }
-        //info!("done sending!");
+    if lh.jpeg_header.jpeg_type == JPegType::Progressive || lh.has_additional_scans() {
+        return err_exit_code(
+            ExitCode::ProgressiveUnsupported,
+            "row ranges can only be decoded for single scan baseline images",
+        );
+    }
 
-        for c in channel_to_sender {
-            // ignore the result of send, since a thread may have already blown up with an error and we will get it when we join (rather than exiting with a useless channel broken message)
-            let _ = c.send(Message::Eof);
-        }
+    let mcuv = lh.truncate_components.mcu_count_vertical;
+    if mcu_row_range.start < 0
+        || mcu_row_range.start >= mcu_row_range.end
+        || mcu_row_range.end > mcuv
+    {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            format!(
+                "row range {0:?} is outside of the {1} MCU rows of the image",
+                mcu_row_range, mcuv
+            )
+            .as_str(),
+        );
+    }
 
-        let mut metrics = Metrics::default();
+    let segment_index = lh.read_segment_index(reader, data_start).context(here!())?;
 
-        let mut result = Vec::new();
-        for i in running_threads.drain(..) {
-            let thread_result = i.join().unwrap().context(here!())?;
+    // number of luma block rows in each MCU row, which is how the segments are split
+    let luma_rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv / mcuv;
 
-            metrics.merge_from(thread_result.1);
+    let mut result: Option<EncodedRows> = None;
+    for segment in 0..lh.thread_handoff.len() {
+        let thread_handoff = &lh.thread_handoff[segment];
+        let segment_end = if segment == lh.thread_handoff.len() - 1 {
+            // the last segment extends all the way to the bottom
+            mcuv
+        } else {
+            thread_handoff.luma_y_end / luma_rows_per_mcu
+        };
 
-            result.push(thread_result.0);
+        let rows = cmp::max(
+            thread_handoff.luma_y_start / luma_rows_per_mcu,
+            mcu_row_range.start,
+        )..cmp::min(segment_end, mcu_row_range.end);
+        if rows.start >= rows.end {
+            continue;
         }
 
-        info!(
-            "worker threads {0}ms of CPU time in {1}ms of wall time",
-            metrics.get_cpu_time_worker_time().as_millis(),
-            wall_time.elapsed().as_millis()
-        );
+        let (image_data, _metrics) = lh
+            .decode_segment(reader, data_start, &segment_index, segment)
+            .context(here!())?;
 
-        return Ok((metrics, result));
-    })
-    .context(here!())?;
+        let encoded = encode_rows_from(&image_data, &lh, thread_handoff, rows).context(here!())?;
 
-    Ok(r)
+        result = Some(match result {
+            None => encoded,
+            Some(mut previous) => {
+                previous.data.extend_from_slice(&encoded.data[..]);
+                previous.end = encoded.end;
+                previous
+            }
+        });
+    }
+
+    result.context(here!())
 }
 
-/// runs the encoding threads and returns the total amount of CPU time consumed (including worker threads)
-fn run_lepton_encoder_threads<W: Write + Seek>(
-    jpeg_header: &JPegHeader,
-    colldata: &TruncateComponents,
+/// reads a jpeg and writes it out as a lepton file
+pub fn encode_lepton_wrapper<R: Read + Seek, W: Write + Seek>(
+    reader: &mut R,
     writer: &mut W,
-    thread_handoffs: &[ThreadHandoff],
-    image_data: &[BlockBasedImage],
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
 ) -> Result<Metrics> {
-    let wall_time = Instant::now();
-
-    // Get number of threads. Verify that it is at most MAX_THREADS and fits in 4 bits for serialization.
-    let num_threads = thread_handoffs.len();
-    assert!(
-        num_threads <= MAX_THREADS && num_threads <= MAX_THREADS_SUPPORTED_BY_LEPTON_FORMAT,
-        "Too many thread handoffs"
-    );
+    check_format_version(
+        enabled_features.required_features(),
+        enabled_features.format_version,
+        "the enabled options",
+    )?;
+
+    if !enabled_features.get_model_tuning().is_valid() {
+        return err_exit_code(ExitCode::SyntaxError, "invalid model tuning");
+    }
 
-    // Prepare quantization tables
-    let pts = ProbabilityTablesSet::new();
-    let mut quantization_tables = Vec::new();
-    for i in 0..image_data.len() {
-        let qtables = QuantizationTables::new(jpeg_header, i);
+    if enabled_features.compression_effort > 2 {
+        return err_exit_code(ExitCode::SyntaxError, "compression effort must be 0 to 2");
+    }
 
-        // check to see if quantitization table was properly initialized
-        // (table contains divisors for coefficients so it never should have a zero)
-        if qtables.get_quantization_table()[0] == 0 {
-            return err_exit_code(ExitCode::UnsupportedJpeg, "Quantization table is missing");
-        }
-        quantization_tables.push(qtables);
+    // a passthrough file would contain everything that coefficients only mode drops
+    if !enabled_features.passthrough || enabled_features.encode_mode == EncodeMode::CoefficientsOnly
+    {
+        return encode_lepton_contents(
+            reader,
+            writer,
+            max_threads,
+            enabled_features,
+            EncoderInfo::current(enabled_features),
+        );
     }
 
-    let pts_ref = &pts;
-    let q_ref = &quantization_tables[..];
+    // we need to know the size of the lepton file before deciding which one to write
+    let start_position = reader.stream_position().context(here!())?;
 
-    let mut sizes = Vec::<u64>::new();
-    sizes.resize(thread_handoffs.len(), 0);
+    let mut lepton = Cursor::new(Vec::new());
+    let metrics = encode_lepton_contents(
+        reader,
+        &mut lepton,
+        max_threads,
+        enabled_features,
+        EncoderInfo::current(enabled_features),
+    )
+    .context(here!())?;
 
-    let mut merged_metrics = Metrics::default();
+    // the size of the original comes from the stream, so that it is only read again if it is stored as is, and
+    // then straight into the output rather than into a copy of it
+    let original_len = reader.seek(SeekFrom::End(0)).context(here!())? - start_position;
 
-    thread::scope(|s| -> Result<()> {
-        let (tx, rx) = channel();
+    if lepton.get_ref().len() as u64 + PASSTHROUGH_MARGIN > original_len {
+        info!(
+            "lepton file would be {0} bytes for a {1} byte jpeg, storing it as is",
+            lepton.get_ref().len(),
+            original_len
+        );
 
-        let mut running_threads = Vec::new();
+        reader
+            .seek(SeekFrom::Start(start_position))
+            .context(here!())?;
+        write_passthrough(reader, original_len, writer, enabled_features).context(here!())?;
+    } else {
+        writer.write_all(lepton.get_ref()).context(here!())?;
+    }
 
-        for i in 0..thread_handoffs.len() {
-            let cloned_sender = tx.clone();
+    Ok(metrics)
+}
 
-            running_threads.push(s.spawn(move || -> Result<Metrics> {
-                let cpu_time = ThreadTime::now();
+/// fails if a file that needs required_features can't be written in format_version. what says what needs
+/// them for the error message.
+fn check_format_version(
+    required_features: u32,
+    format_version: LeptonVersion,
+    what: &str,
+) -> Result<()> {
+    let missing_features = required_features & !format_version.supported_features();
+    if missing_features != 0 {
+        return err_exit_code(
+            ExitCode::FeatureRequiresNewerVersion,
+            format!(
+                "{0} need features {1:x} that format version {2:?} doesn't have",
+                what, missing_features, format_version
+            )
+            .as_str(),
+        );
+    }
 
-                let thread_id = i;
-                let mut thread_writer = MessageSender {
-                    thread_id: thread_id as u8,
-                    sender: cloned_sender,
-                    buffer: Vec::with_capacity(WRITE_BUFFER_SIZE),
-                };
+    Ok(())
+}
 
-                let mut range_metrics = lepton_encode_row_range(
-                    pts_ref,
-                    q_ref,
-                    image_data,
-                    &mut thread_writer,
-                    thread_id as i32,
-                    colldata,
-                    thread_handoffs[thread_id].luma_y_start,
-                    thread_handoffs[thread_id].luma_y_end,
-                    thread_id == thread_handoffs.len() - 1,
-                    true,
-                )
-                .context(here!())?;
+/// lepton files that don't save at least this many bytes over the original are replaced by a passthrough file
+/// if that is enabled, since it's not worth the decoding work
+const PASSTHROUGH_MARGIN: u64 = 64;
 
-                thread_writer.flush().context(here!())?;
+/// writes a lepton file that contains the original_len bytes of the original file from the position of reader as
+/// is after a header without any JPEG information. The original is read twice if its CRC is stored, since the CRC
+/// goes in front of it.
+fn write_passthrough<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    original_len: u64,
+    writer: &mut W,
+    enabled_features: &EnabledFeatures,
+) -> Result<()> {
+    let mut lh = LeptonHeader::new();
 
-                thread_writer.sender.send(Message::Eof).context(here!())?;
+    lh.passthrough_size = Some(original_len);
+    lh.jpeg_file_size = original_len;
 
-                range_metrics.record_cpu_worker_time(cpu_time.elapsed());
+    if enabled_features.checksum {
+        let start_position = reader.stream_position().context(here!())?;
 
-                Ok(range_metrics)
-            }));
-        }
+        let mut crc_writer = CrcWriter::new(io::sink());
+        copy_original(reader, original_len, &mut crc_writer).context(here!())?;
+        lh.original_file_crc = Some(crc_writer.crc().sum());
 
-        // drop the sender so that the channel breaks when all the threads exit
-        drop(tx);
+        reader
+            .seek(SeekFrom::Start(start_position))
+            .context(here!())?;
+    }
 
-        // wait to collect work and done messages from all the threads
-        let mut threads_left = thread_handoffs.len();
+    if enabled_features.original_size {
+        lh.original_file_size = Some(original_len);
+    }
 
-        while threads_left > 0 {
-            let value = rx.recv().context(here!());
-            match value {
-                Ok(Message::Eof) => {
-                    threads_left -= 1;
-                }
-                Ok(Message::WriteBlock(thread_id, b)) => {
-                    let l = b.len() - 1;
+    lh.encoder_info = Some(EncoderInfo::current(enabled_features));
 
-                    writer.write_u8(thread_id).context(here!())?;
-                    writer.write_u8((l & 0xff) as u8).context(here!())?;
-                    writer.write_u8(((l >> 8) & 0xff) as u8).context(here!())?;
-                    writer.write_all(&b[..]).context(here!())?;
+    let mut header = Vec::new();
+    lh.write_lepton_header(&mut header).context(here!())?;
 
-                    sizes[thread_id as usize] += b.len() as u64;
-                }
-                Err(x) => {
-                    // get the actual error that cause the channel to
-                    // prematurely close
-                    for result in running_threads.drain(..) {
-                        let r = result.join().unwrap();
-                        if let Err(e) = r {
-                            return Err(e.context(here!()));
-                        }
-                    }
+    // the file size at the end only gets wider if the whole file doesn't fit in 32 bits
+    if header.len() as u64 + original_len + 4 > u64::from(u32::MAX) {
+        lh.large_sizes = true;
 
-                    return Err(x);
-                }
-            }
-        }
+        header.clear();
+        lh.write_lepton_header(&mut header).context(here!())?;
+    }
 
-        for result in running_threads.drain(..) {
-            merged_metrics.merge_from(result.join().unwrap().unwrap());
-        }
+    writer.write_all(&header[..]).context(here!())?;
+    copy_original(reader, original_len, writer).context(here!())?;
 
-        return Ok(());
-    })
-    .context(here!())?;
+    let final_file_size = header.len() as u64 + original_len + lh.get_file_size_len();
+    lh.write_file_size(writer, final_file_size)
+        .context(here!())?;
 
-    info!(
-        "scan portion of JPEG uncompressed size = {0}",
-        sizes.iter().sum::<u64>()
-    );
+    Ok(())
+}
 
-    info!(
-        "worker threads {0}ms of CPU time in {1}ms of wall time",
-        merged_metrics.get_cpu_time_worker_time().as_millis(),
-        wall_time.elapsed().as_millis()
-    );
+/// copies original_len bytes from reader to writer, failing if the reader ends before them
+fn copy_original<R: Read, W: Write>(
+    reader: &mut R,
+    original_len: u64,
+    writer: &mut W,
+) -> Result<()> {
+    let copied = io::copy(&mut reader.take(original_len), writer).context(here!())?;
+    if copied != original_len {
+        return err_exit_code(
+            ExitCode::GeneralFailure,
+            format!(
+                "the original ended after {0} of its {1} bytes",
+                copied, original_len
+            )
+            .as_str(),
+        );
+    }
 
-    Ok(merged_metrics)
+    Ok(())
 }
 
-#[derive(Debug)]
-pub struct LeptonHeader {
-    /// raw jpeg header to be written back to the file when it is recreated
-    pub raw_jpeg_header: Vec<u8>,
-
-    /// how far we have read into the raw header, since the header is divided
-    /// into multiple chucks for each scan. For example, a progressive image
-    /// would start with the jpeg image segments, followed by a SOS (start of scan)
-    /// after which comes the encoded jpeg coefficients, and once thats over
-    /// we get another header segment until the next SOS, etc
-    pub raw_jpeg_header_read_index: usize,
+/// encodes the JPEG coefficients into a lepton file, which is what encode_lepton_wrapper writes unless it
+/// falls back to a passthrough file. encoder_info is what the header records about the encoder.
+pub fn encode_lepton_contents<R: Read + Seek, W: Write + Seek>(
+    reader: &mut R,
+    writer: &mut W,
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
+    encoder_info: EncoderInfo,
+) -> Result<Metrics> {
+    let max_threads = resolve_max_threads(max_threads);
 
-    pub thread_handoff: Vec<ThreadHandoff>,
+    // the CRC of the original file is calculated as we go, since read_jpeg reads all of it
+    let mut crc_reader = CrcReader::new(reader).context(here!())?;
 
-    pub jpeg_header: JPegHeader,
+    let (mut lp, image_data) = read_jpeg(&mut crc_reader, enabled_features, max_threads, |_jh| {})?;
 
-    /// information about how to truncate the image if it was partially written
-    pub truncate_components: TruncateComponents,
+    // the decoded file isn't the original, so there is nothing to check it against
+    if enabled_features.encode_mode == EncodeMode::CoefficientsOnly {
+        lp.keep_coefficients_only().context(here!())?;
+    } else {
+        if enabled_features.checksum {
+            lp.original_file_crc = Some(crc_reader.crc().sum());
+        }
 
-    pub rst_err: Vec<u8>,
+        if enabled_features.original_size {
+            lp.original_file_size = Some(crc_reader.amount());
+        }
+    }
 
-    /// A list containing one entry for each scan segment.  Each entry contains the number of restart intervals
-    /// within the corresponding scan segment.
-    pub rst_cnt: Vec<i32>,
+    // the trailers after the compressed header are only written for the versions that have them
+    let format_version = enabled_features.format_version;
+    if format_version >= LeptonVersion::V2 {
+        lp.encoder_info = Some(encoder_info);
+    }
+    if format_version >= LeptonVersion::V3 {
+        lp.file_type = Some(LeptonFileType {
+            progressive: lp.jpeg_header.jpeg_type == JPegType::Progressive,
+            scan_count: u32::try_from(lp.scnc)?,
+        });
+    }
+    lp.large_sizes = lp.needs_large_sizes();
+    lp.segment_checksums = enabled_features.segment_checksums;
+    lp.model_primer_id = enabled_features.model_primer.as_ref().map(|p| p.id());
+    lp.model_tuning = enabled_features.get_model_tuning();
+    lp.separate_chroma_models = enabled_features.get_separate_chroma_models();
+    lp.quantized_dc_prediction = enabled_features.get_quantized_dc_prediction();
+    lp.fast_dc_prediction = enabled_features.get_fast_dc_prediction();
+    lp.trained_initial_probs = enabled_features.trained_initial_probs;
+    lp.coefficient_codec = enabled_features.get_coefficient_codec();
+    lp.file_version = LeptonVersion::of_features(lp.get_required_features());
+
+    // the image itself may need features that the version doesn't have
+    check_format_version(lp.get_required_features(), format_version, "the image")?;
+    if format_version < LeptonVersion::V2 && !lp.pad_bit_exceptions.is_empty() {
+        return err_exit_code(
+            ExitCode::FeatureRequiresNewerVersion,
+            "the image has fill bits that differ from the pad bit, which format version V1 can't store",
+        );
+    }
 
-    /// the mask for padding out the bitstream when we get to the end of a reset block
-    pub pad_bit: Option<u8>,
+    let mut metrics = if enabled_features.verify_segments {
+        let verifier = SegmentVerifier::new(&lp, &image_data, enabled_features).context(here!())?;
+
+        // the verifier checks the file as it was written, so it is put together in memory first
+        let mut file = Cursor::new(Vec::new());
+        let mut metrics = write_lepton_file(
+            &mut lp,
+            &mut file,
+            &image_data[..],
+            enabled_features,
+            max_threads,
+            Some(&verifier),
+        )
+        .context(here!())?;
 
-    pub rst_cnt_set: bool,
+        metrics.merge_from(
+            verifier
+                .verify_file(file.get_ref(), crc_reader.crc().sum(), crc_reader.amount())
+                .context(here!())?,
+        );
 
-    /// garbage data (default value - empty segment - means no garbage data)
-    pub garbage_data: Vec<u8>,
+        writer.write_all(file.get_ref()).context(here!())?;
 
-    /// count of scans encountered so far
-    pub scnc: usize,
+        metrics
+    } else {
+        write_lepton_file(
+            &mut lp,
+            writer,
+            &image_data[..],
+            enabled_features,
+            max_threads,
+            None,
+        )
+        .context(here!())?
+    };
 
-    pub early_eof_encountered: bool,
+    metrics.record_max_threads(max_threads);
 
-    /// the maximum dpos in a truncated image
-    pub max_dpos: [i32; 4],
+    Ok(metrics)
+}
 
-    /// the maximum component in a truncated image
-    pub max_cmp: i32,
+/// writes the header, the encoded segments and the trailers of the lepton file for lp, handing the segments to
+/// verifier if there is one
+fn write_lepton_file<W: Write + Seek>(
+    lp: &mut LeptonHeader,
+    writer: &mut W,
+    image_data: &[BlockBasedImage],
+    enabled_features: &EnabledFeatures,
+    max_threads: usize,
+    verifier: Option<&SegmentVerifier>,
+) -> Result<Metrics> {
+    let (metrics, segment_checksums) = if enabled_features.segment_index {
+        // the header records where the index starts, so the segments have to be encoded before it is written
+        let mut segment_data = Cursor::new(Vec::new());
+
+        let (metrics, segment_index, segment_checksums) = run_lepton_encoder_threads(
+            lp,
+            &mut segment_data,
+            image_data,
+            enabled_features,
+            max_threads,
+            verifier,
+        )
+        .context(here!())?;
 
-    /// the maximum band in a truncated image
-    pub max_bpos: i32,
+        lp.segment_index_offset = Some(segment_data.get_ref().len() as u64);
 
-    /// the maximum bit in a truncated image
-    pub max_sah: u8,
+        lp.write_lepton_header(writer).context(here!())?;
+        writer.write_all(segment_data.get_ref()).context(here!())?;
+        SegmentIndexEntry::serialize(&segment_index, lp.large_sizes, writer).context(here!())?;
 
-    pub jpeg_file_size: u32,
+        (metrics, segment_checksums)
+    } else {
+        lp.write_lepton_header(writer).context(here!())?;
+
+        let (metrics, _, segment_checksums) = run_lepton_encoder_threads(
+            lp,
+            writer,
+            image_data,
+            enabled_features,
+            max_threads,
+            verifier,
+        )
+        .context(here!())?;
 
-    /// on decompression, plain-text size
-    pub plain_text_size: u32,
+        (metrics, segment_checksums)
+    };
 
-    /// on decompression, uncompressed lepton header size
-    pub uncompressed_lepton_header_size: u32,
+    // the table goes after the index, since the header that locates the index has to be written first
+    if lp.segment_checksums {
+        for crc in segment_checksums {
+            writer.write_u32::<LittleEndian>(crc).context(here!())?;
+        }
+    }
+
+    let final_file_size = writer.stream_position()? + lp.get_file_size_len();
+
+    lp.write_file_size(writer, final_file_size)
+        .context(here!())?;
+
+    Ok(metrics)
 }
 
-impl LeptonHeader {
-    pub fn new() -> Self {
-        return LeptonHeader {
-            max_dpos: [0; 4],
-            raw_jpeg_header: Vec::new(),
-            raw_jpeg_header_read_index: 0,
-            thread_handoff: Vec::new(),
-            jpeg_header: JPegHeader::new(),
-            truncate_components: TruncateComponents::new(),
-            rst_err: Vec::new(),
-            rst_cnt: Vec::new(),
-            pad_bit: None,
-            rst_cnt_set: false,
-            garbage_data: Vec::new(),
-            scnc: 0,
-            early_eof_encountered: false,
-            max_cmp: 0,
-            max_bpos: 0,
-            max_sah: 0,
-            jpeg_file_size: 0,
-            plain_text_size: 0,
-            uncompressed_lepton_header_size: 0,
-        };
-    }
+/// checks that two JPEGs contain the same coefficients and quantization tables, and therefore the same image
+fn verify_same_coefficients(original: &[u8], decoded: &[u8], max_threads: usize) -> Result<()> {
+    let read = |data: &[u8]| {
+        read_jpeg(
+            &mut Cursor::new(data),
+            &EnabledFeatures::all(),
+            max_threads,
+            |_jh| {},
+        )
+    };
 
-    fn recode_jpeg<R: Read + Seek, W: Write>(
-        &mut self,
-        writer: &mut W,
-        reader: &mut R,
-        last_data_position: u64,
-        num_threads: usize,
-    ) -> Result<Metrics, anyhow::Error> {
-        writer.write_all(&SOI)?;
+    let (original_header, original_image) = read(original).context(here!())?;
+    let (decoded_header, decoded_image) = read(decoded).context(here!())?;
 
-        // write the raw header as far as we've decoded it
-        writer
-            .write_all(&self.raw_jpeg_header[0..self.raw_jpeg_header_read_index])
-            .context(here!())?;
+    let q_tables = |lh: &LeptonHeader| {
+        lh.jpeg_header.cmp_info[..lh.jpeg_header.cmpc]
+            .iter()
+            .map(|ci| lh.jpeg_header.q_tables[usize::from(ci.q_table_index)])
+            .collect::<Vec<_>>()
+    };
 
-        let metrics = if self.jpeg_header.jpeg_type == JPegType::Progressive {
-            self.recode_progressive_jpeg(reader, last_data_position, writer, num_threads)
-                .context(here!())?
-        } else {
-            self.recode_baseline_jpeg(reader, last_data_position, writer, num_threads)
-                .context(here!())?
-        };
+    if q_tables(&original_header) != q_tables(&decoded_header)
+        || original_image.len() != decoded_image.len()
+    {
+        return err_exit_code(
+            ExitCode::VerificationContentMismatch,
+            "ERROR decoded JPEG has different components or quantization tables",
+        );
+    }
 
-        if !self.early_eof_encountered {
-            /* step 3: blit any trailing header data */
-            writer
-                .write_all(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..])
-                .context(here!())?;
+    for (component, (a, b)) in original_image.iter().zip(decoded_image.iter()).enumerate() {
+        let num_blocks = a.get_block_width() * a.get_original_height();
+        if b.get_block_width() * b.get_original_height() != num_blocks {
+            return err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                format!("ERROR component {0} has a different size", component).as_str(),
+            );
         }
 
-        writer.write_all(&self.garbage_data).context(here!())?;
-        Ok(metrics)
+        for dpos in 0..num_blocks {
+            if a.get_block(dpos).get_block() != b.get_block(dpos).get_block() {
+                return err_exit_code(
+                    ExitCode::VerificationContentMismatch,
+                    format!(
+                        "ERROR mismatching coefficients in block {0} of component {1}",
+                        dpos, component
+                    )
+                    .as_str(),
+                );
+            }
+        }
     }
 
-    /// decodes the entire image and merges the results into a single set of BlockBaseImage per component
-    pub fn decode_as_single_image<R: Read + Seek>(
-        &mut self,
-        reader: &mut R,
-        last_data_position: u64,
-        num_threads: usize,
-    ) -> Result<(Vec<BlockBasedImage>, Metrics)> {
-        // run the threads first, since we need everything before we can start decoding
-        let (metrics, mut results) = run_lepton_decoder_threads(
-            self,
-            reader,
-            last_data_position,
-            num_threads,
-            |_thread_handoff, image_data, _lh| {
-                // just return the image data directly to be merged together
-                return Ok(image_data);
-            },
-        )
-        .context(here!())?;
+    Ok(())
+}
 
-        // merge the corresponding components so that we get a single set of coefficient maps (since each thread did a piece of the work)
-        let mut merged = Vec::new();
+/// Encodes JPEG as compressed Lepton format, verifies roundtrip in buffer. Requires everything to be buffered
+/// since we need to pass through the data multiple times
+///
+/// The segments are decoded and checked while the others are still being encoded, see
+/// EnabledFeatures::verify_segments, so that verifying adds little to the time it takes. Coefficients only files
+/// decode to a different JPEG than the original and passthrough files don't have any segments, so those are
+/// decoded and compared once they are written.
+pub fn encode_lepton_wrapper_verify(
+    input_data: &[u8],
+    max_threads: usize,
+    enabled_features: &EnabledFeatures,
+) -> Result<(Vec<u8>, Metrics)> {
+    let mut output_data = Vec::with_capacity(input_data.len());
 
-        let num_components = results[0].len();
-        for i in 0..num_components {
-            merged.push(BlockBasedImage::merge(&mut results, i));
-        }
+    info!("compressing to Lepton format");
 
-        Ok((merged, metrics))
-    }
+    let mut reader = Cursor::new(&input_data);
+    let mut writer = Cursor::new(&mut output_data);
 
-    /// parses and advances to the next header segment out of raw_jpeg_header into the jpeg header
-    pub fn advance_next_header_segment(
-        &mut self,
-        enabled_features: &EnabledFeatures,
-    ) -> Result<bool> {
-        let mut header_cursor =
-            Cursor::new(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..]);
+    let verify_segments = enabled_features.encode_mode == EncodeMode::Exact;
 
-        let result = self
-            .jpeg_header
-            .parse(&mut header_cursor, enabled_features)
+    let mut metrics = encode_lepton_wrapper(
+        &mut reader,
+        &mut writer,
+        max_threads as usize,
+        &EnabledFeatures {
+            verify_segments,
+            ..enabled_features.clone()
+        },
+    )
+    .context(here!())?;
+
+    if verify_segments {
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut Cursor::new(&output_data[..]))
             .context(here!())?;
 
-        self.raw_jpeg_header_read_index += header_cursor.stream_position()? as usize;
+        if lh.passthrough_size.is_none() {
+            return Ok((output_data, metrics));
+        }
+    }
 
-        Ok(result)
+    // decode and compare to original in order to enure we encoded correctly
+
+    let mut verify_buffer = Vec::with_capacity(input_data.len());
+    let mut verifyreader = Cursor::new(&output_data[..]);
+
+    info!("decompressing to verify contents");
+
+    metrics.merge_from(
+        decode_lepton_wrapper_with_features(
+            &mut verifyreader,
+            &mut verify_buffer,
+            max_threads,
+            enabled_features,
+        )
+        .context(here!())?,
+    );
+
+    // the file decodes to a different JPEG with the same image, so compare what the two JPEGs decode to
+    if enabled_features.encode_mode == EncodeMode::CoefficientsOnly {
+        verify_same_coefficients(input_data, &verify_buffer[..], max_threads).context(here!())?;
+
+        return Ok((output_data, metrics));
     }
 
-    /// progressive decoder, requires that the entire lepton file is processed first
-    fn recode_progressive_jpeg<R: Read + Seek, W: Write>(
-        &mut self,
-        reader: &mut R,
-        last_data_position: u64,
-        writer: &mut W,
-        num_threads: usize,
-    ) -> Result<Metrics> {
-        // run the threads first, since we need everything before we can start decoding
-        let (merged, metrics) = self
-            .decode_as_single_image(reader, last_data_position, num_threads)
-            .context(here!())?;
+    if input_data.len() != verify_buffer.len() {
+        return err_exit_code(
+            ExitCode::VerificationLengthMismatch,
+            format!(
+                "ERROR mismatch input_len = {0}, decoded_len = {1}",
+                input_data.len(),
+                verify_buffer.len()
+            )
+            .as_str(),
+        );
+    }
 
-        loop {
-            // code another scan
-            jpeg_write_entire_scan(writer, &merged[..], self).context(here!())?;
+    if input_data[..] != verify_buffer[..] {
+        return err_exit_code(
+            ExitCode::VerificationContentMismatch,
+            "ERROR mismatching data (but same size)",
+        );
+    }
 
-            // read the next headers (DHT, etc) while mirroring it back to the writer
-            let old_pos = self.raw_jpeg_header_read_index;
-            let result = self
-                .advance_next_header_segment(&EnabledFeatures::all())
-                .context(here!())?;
+    Ok((output_data, metrics))
+}
 
-            writer
-                .write_all(&self.raw_jpeg_header[old_pos..self.raw_jpeg_header_read_index])
-                .context(here!())?;
+/// reads JPEG and returns corresponding header and image vector. This encapsulate all
+/// JPEG reading code, including baseline and progressive images.
+///
+/// The callback is called for each jpeg header that is parsed, which
+/// is currently only used by the dump utility for debugging purposes.
+pub fn read_jpeg<R: Read + Seek>(
+    reader: &mut R,
+    enabled_features: &EnabledFeatures,
+    max_threads: usize,
+    callback: fn(&JPegHeader),
+) -> Result<(LeptonHeader, Vec<BlockBasedImage>)> {
+    let max_threads = resolve_max_threads(max_threads);
+    let (mut lp, image_data, thread_handoff) =
+        read_jpeg_rows(reader, enabled_features, callback).context(here!())?;
+
+    // the segments are split by what their rows cost to encode rather than by their number, so that an image
+    // that is flat in one part and busy in another doesn't leave one thread with most of the work
+    let row_costs = estimate_row_costs(&thread_handoff[..], &lp.jpeg_header, &image_data);
+
+    let num_blocks: u64 = lp.jpeg_header.cmp_info[..lp.jpeg_header.cmpc]
+        .iter()
+        .map(|ci| (ci.bch as u64) * (ci.bcv as u64))
+        .sum();
+
+    // in deterministic mode the segments only depend on the image, so the output doesn't depend on the machine
+    lp.thread_handoff = split_row_handoffs_to_threads(
+        &thread_handoff[..],
+        Some(&row_costs[..]),
+        if num_blocks < u64::from(enabled_features.single_segment_max_blocks) {
+            1
+        } else if enabled_features.deterministic {
+            MAX_THREADS
+        } else {
+            max_threads
+        },
+        enabled_features.target_segments,
+    );
 
-            if !result {
-                break;
-            }
+    Ok((lp, image_data))
+}
 
-            // advance to next scan
-            self.scnc += 1;
-        }
+/// reads the JPEG like read_jpeg, but returns a handoff for each MCU row (with the size of its scan data)
+/// instead of splitting the rows into thread segments
+pub fn read_jpeg_rows<R: Read + Seek>(
+    reader: &mut R,
+    enabled_features: &EnabledFeatures,
+    callback: fn(&JPegHeader),
+) -> Result<(LeptonHeader, Vec<BlockBasedImage>, Vec<ThreadHandoff>)> {
+    let mut startheader = Vec::new();
+    reader
+        .by_ref()
+        .take(2)
+        .read_to_end(&mut startheader)
+        .context(here!())?;
+    if startheader.is_empty() {
+        return err_exit_code(ExitCode::NotAJpeg, "input is empty");
+    }
+    if startheader[..] != [0xFF, jpeg_code::SOI] {
+        return err_exit_code(
+            ExitCode::NotAJpeg,
+            "input doesn't start with a JPEG SOI marker",
+        );
+    }
 
-        Ok(metrics)
+    // a JPEG that ends before its first scan (for example just an SOI and EOI) has no image to encode
+    let mut lp = LeptonHeader::new();
+    if !prepare_to_decode_next_scan(&mut lp, reader, enabled_features).context(here!())? {
+        return err_exit_code(ExitCode::NoScanData, "JPeg does not contain scans");
     }
 
-    // baseline decoder can run the jpeg encoder inside the worker thread vs progressive encoding which needs to get the entire set of coefficients first
-    // since it runs throught it multiple times.
-    fn recode_baseline_jpeg<R: Read + Seek, W: Write>(
-        &mut self,
-        reader: &mut R,
-        last_data_position: u64,
-        writer: &mut W,
-        num_threads: usize,
-    ) -> Result<Metrics> {
-        // step 2: recode image data
-        let (metrics, results) = run_lepton_decoder_threads(
-            self,
-            reader,
-            last_data_position,
-            num_threads,
-            |thread_handoff, image_data, lh| {
-                let mut result_buffer = Vec::with_capacity(thread_handoff.segment_size as usize);
-                let mut cursor = Cursor::new(&mut result_buffer);
+    callback(&lp.jpeg_header);
+
+    if !enabled_features.progressive && lp.jpeg_header.jpeg_type == JPegType::Progressive {
+        return err_exit_code(
+            ExitCode::ProgressiveUnsupported,
+            "file is progressive, but this is disabled",
+        )
+        .context(here!());
+    }
+
+    if lp.jpeg_header.cmpc > COLOR_CHANNEL_NUM_BLOCK_TYPES {
+        return err_exit_code(
+            ExitCode::Unsupported4Colors,
+            " can't support this kind of image",
+        )
+        .context(here!());
+    }
+
+    lp.truncate_components.init(&lp.jpeg_header);
 
-                let mut huffw = BitWriter::new();
+    // the image has to fit into the memory budget by itself, so find out before allocating it
+    let image_memory: u64 = (0..lp.jpeg_header.cmpc)
+        .map(|i| BlockBasedImage::memory_for(&lp.jpeg_header, i))
+        .sum();
+    MemoryTracker::new(enabled_features.memory_budget)
+        .reserve(image_memory, "the blocks of the image")
+        .context(here!())?;
+
+    let mut image_data = Vec::<BlockBasedImage>::new();
+    for i in 0..lp.jpeg_header.cmpc {
+        // constructor takes height in proportion to the component[0]
+        image_data.push(BlockBasedImage::new(
+            &lp.jpeg_header,
+            i,
+            0,
+            lp.jpeg_header.cmp_info[0].bcv,
+        ));
+    }
 
-                let _start_size = cursor.position();
+    let mut thread_handoff = Vec::<ThreadHandoff>::new();
+    let start_scan = reader.stream_position()? as i64;
+    read_scan(&mut lp, reader, &mut thread_handoff, &mut image_data[..]).context(here!())?;
 
-                let max_coded_heights = lh.truncate_components.get_max_coded_heights();
+    let mut end_scan = reader.stream_position()? as i64;
+
+    // need at least two bytes of scan data
+    if start_scan + 2 > end_scan || thread_handoff.len() == 0 {
+        return err_exit_code(
+            ExitCode::UnsupportedJpeg,
+            "couldnt find any sections to encode",
+        )
+        .context(here!());
+    }
+
+    for i in 0..thread_handoff.len() {
+        thread_handoff[i].segment_offset_in_file += start_scan;
+
+        #[cfg(feature = "detailed_tracing")]
+        info!(
+            "Crystalize: s:{0} ls: {1} le: {2} o: {3} nb: {4}",
+            thread_handoff[i].segment_offset_in_file,
+            thread_handoff[i].luma_y_start,
+            thread_handoff[i].luma_y_end,
+            thread_handoff[i].overhang_byte,
+            thread_handoff[i].num_overhang_bits
+        );
+    }
+
+    // a sequential image whose first scan doesn't contain every component has the remaining components
+    // in subsequent scans, so these need to be read as well to get the complete image
+    let multi_scan_sequential = lp.jpeg_header.jpeg_type == JPegType::Sequential
+        && lp.jpeg_header.cs_cmpc < lp.jpeg_header.cmpc;
+
+    if lp.jpeg_header.jpeg_type == JPegType::Sequential && !multi_scan_sequential {
+        if lp.early_eof_encountered {
+            lp.truncate_components
+                .set_truncation_bounds(&lp.jpeg_header, lp.max_dpos);
+
+            // If we got an early EOF, then seek backwards and capture the last two bytes and store them as garbage.
+            // This is necessary since the decoder will assume that zero garbage always means a properly terminated JPEG
+            // even if early EOF was set to true.
+            reader.seek(SeekFrom::Current(-2))?;
+            lp.garbage_data.resize(2, 0);
+            reader.read_exact(&mut lp.garbage_data)?;
+
+            // take these two last bytes off the last segment. For some reason the C++/CS version only chop of one byte
+            // and then fix up the broken file later in the decoder. The following logic will create a valid file
+            // that the C++ and CS version will still decode properly without the fixup logic.
+            let len = thread_handoff.len();
+            thread_handoff[len - 1].segment_size -= 2;
+        }
+
+        // rest of data is garbage data if it is a sequential jpeg (including EOI marker)
+        reader.read_to_end(&mut lp.garbage_data).context(here!())?;
+    } else {
+        if lp.early_eof_encountered {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "truncation is only supported for single scan baseline images",
+            )
+            .context(here!());
+        }
+
+        // for progressive and multi-scan images, loop around reading headers and decoding until we a complete image_data
+        while prepare_to_decode_next_scan(&mut lp, reader, enabled_features).context(here!())? {
+            callback(&lp.jpeg_header);
+
+            if multi_scan_sequential {
+                // the handoffs are only collected for the first scan
+                read_scan(&mut lp, reader, &mut Vec::new(), &mut image_data[..])
+                    .context(here!())?;
+            } else {
+                read_progressive_scan(&mut lp, reader, &mut image_data[..]).context(here!())?;
+            }
+
+            if lp.early_eof_encountered {
+                return err_exit_code(
+                    ExitCode::UnsupportedJpeg,
+                    "truncation is only supported for single scan baseline images",
+                )
+                .context(here!());
+            }
+        }
+
+        end_scan = reader.stream_position()? as i64;
+
+        // since prepare_to_decode_next_scan consumes the EOI,
+        // we need to add it to the beginning of the garbage data (if there is any)
+        lp.garbage_data = Vec::from(EOI);
+
+        // append the rest of the file to the buffer
+        if reader.read_to_end(&mut lp.garbage_data).context(here!())? == 0 {
+            // no need to record EOI garbage data if there wasn't anything read
+            lp.garbage_data.clear();
+        }
+    }
+
+    if lp.garbage_data.len() as u64 > enabled_features.max_trailing_bytes {
+        return err_exit_code(
+            ExitCode::TrailingDataTooLarge,
+            format!(
+                "{0} bytes after the end of the image, but at most {1} are allowed",
+                lp.garbage_data.len(),
+                enabled_features.max_trailing_bytes
+            )
+            .as_str(),
+        );
+    }
+
+    set_segment_size_in_row_thread_handoffs(&mut thread_handoff[..], end_scan);
+    lp.jpeg_file_size = reader.stream_position().context(here!())?;
+    Ok((lp, image_data, thread_handoff))
+}
+
+/// what a decoder worker hands on for a thread segment once it is done with it: what process returned along with
+/// the metrics of decoding the segment, or why it failed
+type SegmentResult<P> = std::result::Result<(P, Metrics), SegmentError>;
+
+/// the queues of a thread segment, which the worker that takes the segment reads its data from and hands its
+/// output and then its result on through
+struct SegmentQueues<P> {
+    input: Receiver<Message>,
+    output: SyncSender<DecoderOutput<SegmentResult<P>>>,
+}
+
+/// a thread segment whose output the caller of run_lepton_decoder_threads hasn't got yet
+struct PendingSegment<P> {
+    segment: usize,
+    /// dropped to wake up the worker if it is waiting for room in the queue once it has been cancelled
+    output: Option<Receiver<DecoderOutput<SegmentResult<P>>>>,
+}
+
+/// what a decoder worker hands to the caller of run_lepton_decoder_threads, in order
+enum DecoderOutput<P> {
+    /// a piece of the output that process wrote
+    Data(Vec<u8>),
+    /// what process returned, after all of the output that it wrote
+    Result(P),
+}
+
+/// value of the index of the first worker (or thread segment for the decoder) that failed while none has
+const NO_FAILED_WORKER: usize = usize::MAX;
+
+/// why a decoder worker failed, along with the segment it was on so that the error can say where in the image the
+/// bad data is
+struct SegmentError {
+    segment_index: usize,
+    luma_row_range: Range<i32>,
+    source: anyhow::Error,
+}
+
+impl SegmentError {
+    /// the error with the segment added to its message, see add_segment_position
+    fn into_error(self) -> anyhow::Error {
+        add_segment_position(self.source, self.segment_index, self.luma_row_range)
+    }
+}
+
+/// the error of the segment that failed first, which is the last thing in its queue in pending. The other
+/// segments may have failed too, but only because they were cancelled once it did.
+fn first_segment_error<P>(
+    pending: &VecDeque<PendingSegment<P>>,
+    first_failed: &AtomicUsize,
+) -> anyhow::Error {
+    let segment = first_failed.load(Ordering::SeqCst);
+    let Some(rx) = pending
+        .iter()
+        .find(|p| p.segment == segment)
+        .and_then(|p| p.output.as_ref())
+    else {
+        return anyhow::anyhow!("segment {0} failed but isn't pending", segment);
+    };
+
+    // skip over the output that the segment wrote before it failed
+    loop {
+        match rx.recv() {
+            Ok(DecoderOutput::Data(_)) => {}
+            Ok(DecoderOutput::Result(Err(e))) => return e.into_error(),
+            Ok(DecoderOutput::Result(Ok(_))) => {
+                return anyhow::anyhow!("segment {0} failed but returned a result", segment)
+            }
+            Err(_) => return anyhow::anyhow!("segment {0} failed without an error", segment),
+        }
+    }
+}
+
+/// writes the scan data of the rows of a baseline image that a thread segment (or several following each other)
+/// covers, starting with the bits that the segment before it left in its last byte
+pub fn write_segment_jpeg(
+    thread_handoff: &ThreadHandoff,
+    image_data: &[BlockBasedImage],
+    lh: &LeptonHeader,
+    mut output: &mut dyn Write,
+) -> Result<()> {
+    let mut huffw = BitWriter::new();
+
+    let max_coded_heights = lh.truncate_components.get_max_coded_heights();
+
+    jpeg_write_row_range(
+        &mut output,
+        image_data,
+        lh.truncate_components.mcu_count_vertical,
+        thread_handoff,
+        &max_coded_heights[..],
+        &mut huffw,
+        lh,
+    )
+    .context(here!())?;
+
+    #[cfg(detailed_tracing)]
+    info!(
+        "ystart = {0}, segment_size = {1}, offset = {2}, ob = {3}, nb = {4}",
+        thread_handoff.luma_y_start,
+        thread_handoff.segment_size,
+        thread_handoff.segment_offset_in_file,
+        thread_handoff.overhang_byte,
+        thread_handoff.num_overhang_bits
+    );
+
+    Ok(())
+}
+
+/// merges the coefficients that the thread segments decoded, in order, into a single image per component
+pub fn merge_segment_images(
+    mut results: Vec<Vec<BlockBasedImage>>,
+) -> Result<Vec<BlockBasedImage>> {
+    let Some(num_components) = results.first().map(|r| r.len()) else {
+        return err_exit_code(ExitCode::StreamInconsistent, "no segments to merge");
+    };
+
+    let mut merged = Vec::new();
+    for i in 0..num_components {
+        merged.push(BlockBasedImage::merge(&mut results, i).context(here!())?);
+    }
+
+    Ok(merged)
+}
+
+/// runs the decoding threads, calling process inside a worker once the rows of a thread segment are decoded. The
+/// segments are decoded on at most max_threads_to_use workers, each of which takes the next segment in order once
+/// it is done with one, so that a file with more segments than threads is decoded in waves. The data of the
+/// segments that no worker has got to yet is held back, but the reader waits for the workers once lh.max_read_ahead
+/// bytes of it are held, see ReadAheadBudget. What process writes and then returns is handed to output in segment
+/// order as soon as it is ready, so that the caller can stream it out without waiting for the remaining segments.
+/// Each segment can have at most lh.max_output_buffers pieces of its output waiting to be handed on before its
+/// worker waits for the caller, so that a slow output doesn't let the decoded output pile up. Once a segment
+/// fails the others are cancelled, and its error is returned with the segment.
+fn run_lepton_decoder_threads<R: Read, P: Send>(
+    lh: &LeptonHeader,
+    reader: &mut R,
+    remaining_size: Option<u64>,
+    max_threads_to_use: usize,
+    process: fn(
+        thread_handoff: &ThreadHandoff,
+        image_data: Vec<BlockBasedImage>,
+        lh: &LeptonHeader,
+        output: &mut dyn Write,
+    ) -> Result<P>,
+    mut output: impl FnMut(DecoderOutput<P>) -> Result<()>,
+) -> Result<Metrics> {
+    let wall_time = Instant::now();
+    let max_threads_to_use = resolve_max_threads(max_threads_to_use);
+
+    // fail before any thread starts if the file needs a primer that we don't have
+    lh.check_model_primer().context(here!())?;
+
+    let pts = ProbabilityTablesSet::new(lh.quantized_dc_prediction, lh.fast_dc_prediction);
+    let qt = get_quantization_tables(&lh.jpeg_header).context(here!())?;
+
+    let mut chunk_reader = ChunkReader::for_header(reader, lh, remaining_size);
+
+    // without knowing the size up front, the input is a stream that might be arriving slowly
+    let streaming = remaining_size.is_none();
+
+    // set by the first segment that fails, along with the cancel flag that the other workers check to give up early
+    let first_failed = AtomicUsize::new(NO_FAILED_WORKER);
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    let read_ahead = ReadAheadBudget::new(lh.max_read_ahead, lh.thread_handoff.len());
+
+    // the queues of the segments that no worker has taken yet, and the next segment to take
+    let mut channel_to_sender = Vec::new();
+    let mut pending = VecDeque::new();
+    let mut queues = Vec::new();
+    for segment in 0..lh.thread_handoff.len() {
+        let (tx, rx) = channel();
+        let (output_tx, output_rx) = sync_channel(cmp::max(lh.max_output_buffers, 1));
+
+        channel_to_sender.push(tx);
+        pending.push_back(PendingSegment {
+            segment,
+            output: Some(output_rx),
+        });
+        queues.push(Mutex::new(Some(SegmentQueues {
+            input: rx,
+            output: output_tx,
+        })));
+    }
+    let next_segment = AtomicUsize::new(0);
+
+    let r = thread_pool::scope(max_threads_to_use, |s| -> Result<Metrics> {
+        // dropped when this returns early, which wakes up the workers that wait for data or room for their output
+        let channel_to_sender = channel_to_sender;
+        let mut pending = pending;
+
+        let pts_ref = &pts;
+        let q_ref = &qt[..];
+        let first_failed_ref = &first_failed;
+        let cancelled_ref = &cancelled;
+        let read_ahead_ref = &read_ahead;
+        let queues_ref = &queues[..];
+        let next_segment_ref = &next_segment;
+
+        // don't use more threads than we need
+        let m = cmp::min(max_threads_to_use, lh.thread_handoff.len());
+
+        info!(
+            "decoding {0} multipexed streams with {1} threads",
+            lh.thread_handoff.len(),
+            m
+        );
+
+        for _ in 0..m {
+            // counted from here rather than once the worker starts, so that the reader doesn't race ahead before
+            let working = read_ahead_ref.start_working();
+            s.spawn(move || {
+                let _working = working;
+
+                // the segments are taken in order, since that is the order in which the data of files whose
+                // segments follow each other arrives and in which the output is written
+                loop {
+                    let segment = next_segment_ref.fetch_add(1, Ordering::SeqCst);
+                    let Some(SegmentQueues { input, output }) = queues_ref
+                        .get(segment)
+                        .and_then(|q| q.lock().unwrap().take())
+                    else {
+                        break;
+                    };
+
+                    let handoff = &lh.thread_handoff[segment];
+
+                    let mut reader = MessageReceiver {
+                        thread_id: segment as u8,
+                        current_buffer: Cursor::new(Vec::new()),
+                        receiver: input,
+                        end_of_file: false,
+                        cancelled: cancelled_ref,
+                        read_ahead: read_ahead_ref,
+                    };
+
+                    // the output of a segment can't be longer than its scan data was
+                    let mut output = OutputSender {
+                        sender: output,
+                        buffer: Vec::new(),
+                        remaining: handoff.segment_size as u64,
+                        read_ahead: read_ahead_ref,
+                    };
+
+                    let result = run_segment(
+                        lh.thread_lifecycle_hook.as_ref(),
+                        WorkerKind::Decoder,
+                        segment,
+                        || {
+                            decode_segment_on_worker(
+                                lh,
+                                pts_ref,
+                                q_ref,
+                                segment,
+                                &mut reader,
+                                cancelled_ref,
+                                process,
+                                &mut output,
+                            )
+                        },
+                    )
+                    .map_err(|source| {
+                        // the first segment to fail cancels the others
+                        let _ = first_failed_ref.compare_exchange(
+                            NO_FAILED_WORKER,
+                            segment,
+                            Ordering::SeqCst,
+                            Ordering::SeqCst,
+                        );
+                        cancelled_ref.store(true, Ordering::SeqCst);
+
+                        SegmentError {
+                            segment_index: segment,
+                            luma_row_range: handoff.luma_y_start..handoff.luma_y_end,
+                            source,
+                        }
+                    });
+
+                    // nobody takes the result anymore if the output was cancelled
+                    let _ = output.send(DecoderOutput::Result(result));
+                }
+            });
+        }
+
+        let mut metrics = Metrics::default();
+
+        // the pieces of output that were taken, so that the reader can tell whether taking them let a worker go on
+        let pieces_taken = Cell::new(0usize);
+
+        // hands the output of the next segment in order to the caller, waiting for the segment to finish if wait
+        // is set, or otherwise only as far as it got. Returns whether the segment was done.
+        let mut output_next = |pending: &mut VecDeque<PendingSegment<P>>, wait: bool| {
+            let result = loop {
+                let piece = match &pending[0].output {
+                    Some(rx) if wait => rx.recv().ok(),
+                    Some(rx) => match rx.try_recv() {
+                        Ok(piece) => Some(piece),
+                        Err(TryRecvError::Empty) => return Ok(false),
+                        Err(TryRecvError::Disconnected) => None,
+                    },
+                    None => None,
+                };
+
+                if piece.is_some() {
+                    pieces_taken.set(pieces_taken.get() + 1);
+                }
+
+                match piece {
+                    Some(DecoderOutput::Data(piece)) => {
+                        output(DecoderOutput::Data(piece)).context(here!())?
+                    }
+                    Some(DecoderOutput::Result(result)) => break result,
+                    None => {
+                        return Err(anyhow::anyhow!(
+                            "segment {0} ended without a result",
+                            pending[0].segment
+                        ))
+                    }
+                }
+            };
+
+            let segment = pending.pop_front().unwrap().segment;
+            let (segment_result, segment_metrics) = match result {
+                Ok(r) => r,
+                Err(e) if segment == first_failed.load(Ordering::SeqCst) => {
+                    return Err(e.into_error());
+                }
+                Err(_) => return Err(first_segment_error(pending, &first_failed)),
+            };
+
+            metrics.merge_from(segment_metrics);
+
+            output(DecoderOutput::Result(segment_result)).context(here!())?;
+            Ok(true)
+        };
+
+        // in files with a segment index the segments are stored one after the other, so once a later segment
+        // starts, the earlier ones have all their data and can finish without waiting for the rest of the file
+        let contiguous_segments = lh.segment_index_offset.is_some();
+        let mut segments_ended = 0;
+
+        let mut segment_crcs: Vec<Crc> = lh.thread_handoff.iter().map(|_| Crc::new()).collect();
+
+        // the first segment that failed, in which case we keep going through the data if it has checksums, since
+        // a corrupt segment is the more useful thing to report than whatever the decoder tripped over
+        let mut thread_error = None;
+
+        // now that the threads are waiting for inptut, read the stream and send all the buffers to their respective readers
+        while let Some((thread_id, buffer)) = chunk_reader.next_chunk().context(here!())? {
+            if thread_id >= channel_to_sender.len() as u8 {
+                return err_exit_code(
+                    ExitCode::BadLeptonFile,
+                    format!(
+                        "invalid thread_id at {0} of the multiplexed data at {1}",
+                        chunk_reader.position,
+                        here!()
+                    )
+                    .as_str(),
+                );
+            }
+
+            segment_crcs[usize::from(thread_id)].update(&buffer);
+
+            if thread_error.is_some() {
+                continue;
+            }
+
+            if contiguous_segments {
+                while segments_ended < usize::from(thread_id) {
+                    let _ =
+                        channel_to_sender[segments_ended].send(Message::Eof(segments_ended as u8));
+                    read_ahead.handed_to(segments_ended);
+                    segments_ended += 1;
+                }
+            }
+
+            // wait for the workers if they have enough to work on. The ones that wait for room for their output
+            // wait for us, so take what is ready first, and only go over the budget if there is none, since then
+            // the output that is waiting is of a segment behind one that waits for data
+            let size = buffer.len() as u64;
+            let mut force = false;
+            while !read_ahead.reserve(size, force) {
+                let taken = pieces_taken.get();
+                if !pending.is_empty() && thread_error.is_none() {
+                    if let Err(e) = output_next(&mut pending, false) {
+                        thread_error = Some(e);
+                    }
+                }
+                force = pieces_taken.get() == taken;
+            }
+
+            // give the room back if the segment is done or has already failed, in which case we will get its error
+            // from its queue
+            if channel_to_sender[thread_id as usize]
+                .send(Message::WriteBlock(thread_id, buffer))
+                .is_err()
+            {
+                read_ahead.release(size);
+            }
+            read_ahead.handed_to(usize::from(thread_id));
+
+            // write out whatever is ready in order, so that the output doesn't have to wait for the whole input
+            while !pending.is_empty() && thread_error.is_none() {
+                match output_next(&mut pending, false) {
+                    Ok(true) => {}
+                    Ok(false) => break,
+                    Err(e) => {
+                        thread_error = Some(e);
+                        break;
+                    }
+                }
+            }
+
+            // when reading from a stream the next chunk may take a while to arrive, so first wait for the segments
+            // that already have all of their data
+            if streaming && thread_error.is_none() {
+                while pending
+                    .front()
+                    .map_or(false, |p| p.segment < segments_ended)
+                {
+                    if let Err(e) = output_next(&mut pending, true) {
+                        thread_error = Some(e);
+                        break;
+                    }
+                }
+            }
+
+            // a segment that failed further down the image stops the others before the ones in front of it finish
+            if thread_error.is_none() && first_failed.load(Ordering::SeqCst) != NO_FAILED_WORKER {
+                thread_error = Some(first_segment_error(&pending, &first_failed));
+            }
+
+            // cancel the workers, also when it was the output that failed, and wake up the ones that are waiting
+            // for data, which they won't get anymore
+            if thread_error.is_some() {
+                cancelled.store(true, Ordering::SeqCst);
+                for (thread_id, c) in channel_to_sender.iter().enumerate().skip(segments_ended) {
+                    let _ = c.send(Message::Eof(thread_id as u8));
+                }
+                segments_ended = channel_to_sender.len();
+
+                // as well as the ones that are waiting for room for their output
+                for p in pending.iter_mut() {
+                    p.output = None;
+                }
+            }
+
+            if thread_error.is_some() && !lh.segment_checksums {
+                break;
+            }
+        }
+        //info!("done sending!");
+
+        // check the data of every segment before waiting for the threads that are still decoding it
+        if lh.segment_checksums {
+            let mut trailer = Vec::new();
+            chunk_reader.read_to_end(&mut trailer).context(here!())?;
+
+            lh.verify_segment_checksums(&segment_crcs, &trailer)
+                .context(here!())?;
+        }
+
+        if let Some(e) = thread_error {
+            return Err(e);
+        }
+
+        for (thread_id, c) in channel_to_sender.iter().enumerate().skip(segments_ended) {
+            // ignore the result of send, since a thread may have already blown up with an error and we will get it when we join (rather than exiting with a useless channel broken message)
+            let _ = c.send(Message::Eof(thread_id as u8));
+        }
+
+        while !pending.is_empty() {
+            output_next(&mut pending, true)?;
+        }
+
+        metrics.record_read_ahead_peak(read_ahead.peak());
+        metrics.record_max_threads(max_threads_to_use);
+
+        info!(
+            "worker threads {0}ms of CPU time in {1}ms of wall time",
+            metrics.get_cpu_time_worker_time().as_millis(),
+            wall_time.elapsed().as_millis()
+        );
+
+        Ok(metrics)
+    })
+    .context(here!())?;
+
+    Ok(r)
+}
+
+/// decodes the rows of segment from reader on a worker of run_lepton_decoder_threads and calls process with them
+#[allow(clippy::too_many_arguments)]
+fn decode_segment_on_worker<P>(
+    lh: &LeptonHeader,
+    pts: &ProbabilityTablesSet,
+    qt: &[QuantizationTables],
+    segment: usize,
+    reader: &mut MessageReceiver,
+    cancelled: &Arc<AtomicBool>,
+    process: fn(
+        thread_handoff: &ThreadHandoff,
+        image_data: Vec<BlockBasedImage>,
+        lh: &LeptonHeader,
+        output: &mut dyn Write,
+    ) -> Result<P>,
+    output: &mut dyn Write,
+) -> Result<(P, Metrics)> {
+    let cpu_time = ThreadTime::now();
+
+    let handoff = &lh.thread_handoff[segment];
+    let last_segment = segment == lh.thread_handoff.len() - 1;
+
+    let mut image_data = Vec::new();
+    for i in 0..lh.jpeg_header.cmpc {
+        image_data.push(BlockBasedImage::new(
+            &lh.jpeg_header,
+            i,
+            handoff.luma_y_start,
+            if last_segment {
+                // the image of the last segment extends all the way to the bottom
+                lh.jpeg_header.cmp_info[0].bcv
+            } else {
+                handoff.luma_y_end
+            },
+        ));
+    }
+
+    let mut model = lh.initial_model()?;
+    model.set_cancel_flag(Some(cancelled.clone()));
+
+    let mut metrics = lepton_decode_row_range(
+        pts,
+        qt,
+        &lh.truncate_components,
+        &mut image_data,
+        reader,
+        handoff.luma_y_start,
+        handoff.luma_y_end,
+        last_segment,
+        true,
+        model.as_mut(),
+        lh.initial_cr_model()?.as_deref_mut(),
+        lh.coefficient_codec,
+        lh.strict_segment_end,
+    )
+    .context(here!())?;
+
+    let result = process(handoff, image_data, lh, output).context(here!())?;
+    output.flush().context(here!())?;
+
+    metrics.record_cpu_worker_time(cpu_time.elapsed());
+
+    Ok((result, metrics))
+}
+
+/// number of bytes left in the reader after the current position
+pub fn get_remaining_size<R: Seek>(reader: &mut R) -> Result<u64> {
+    let orig_pos = reader.stream_position()?;
+    let size = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(orig_pos))?;
+
+    Ok(size.saturating_sub(orig_pos))
+}
+
+/// reads the chunks of the multiplexed thread data one at a time. If the size of the data isn't known, which is the
+/// case when the file is still arriving through a stream, the data is taken to end when only the final file size
+/// is left, which is the case once there is no more than its size left to read.
+pub struct ChunkReader<R> {
+    reader: R,
+    /// number of bytes of multiplexed data, if known
+    data_size: Option<u64>,
+    /// how far into the multiplexed data we are
+    position: u64,
+    /// bytes that were read ahead to check that the data hasn't ended yet
+    lookahead: Vec<u8>,
+    /// number of bytes to read ahead, which is one more than what follows the multiplexed data takes
+    lookahead_size: usize,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R, data_size: Option<u64>) -> Self {
+        ChunkReader {
+            reader,
+            data_size,
+            position: 0,
+            lookahead: Vec::new(),
+            lookahead_size: 5,
+        }
+    }
+
+    /// reader for the multiplexed data that follows the header, which ends where the segment index starts, or
+    /// otherwise just before the segment checksums and the file size. remaining_size is the amount of data after
+    /// the header if known.
+    pub fn for_header(reader: R, lh: &LeptonHeader, remaining_size: Option<u64>) -> Self {
+        let trailer_len = lh.get_segment_checksums_len() + lh.get_file_size_len();
+
+        let data_size = match lh.segment_index_offset {
+            Some(offset) => Some(offset),
+            None => remaining_size.map(|r| r.saturating_sub(trailer_len)),
+        };
+
+        ChunkReader {
+            lookahead_size: trailer_len as usize + 1,
+            ..ChunkReader::new(reader, data_size)
+        }
+    }
+
+    /// returns the thread id and data of the next chunk, or None at the end of the multiplexed data
+    pub fn next_chunk(&mut self) -> Result<Option<(u8, Vec<u8>)>> {
+        match self.data_size {
+            Some(data_size) => {
+                if self.position >= data_size {
+                    return Ok(None);
+                }
+            }
+            None => {
+                while self.lookahead.len() < self.lookahead_size {
+                    let mut b = [0u8; 64];
+                    let wanted = cmp::min(b.len(), self.lookahead_size - self.lookahead.len());
+                    let n = match self.reader.read(&mut b[..wanted]) {
+                        Ok(n) => n,
+                        Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                        Err(e) => return Err(e).context(here!()),
+                    };
+                    if n == 0 {
+                        return Ok(None);
+                    }
+                    self.lookahead.extend_from_slice(&b[..n]);
+                }
+            }
+        }
+
+        let ChunkHeader {
+            thread_id,
+            length: data_length,
+        } = ChunkHeader::read(self).context(here!())?;
+
+        let mut buffer = Vec::<u8>::new();
+        buffer.resize(data_length, 0);
+        self.read_exact(&mut buffer).with_context(|| {
+            format!(
+                "reading {0} bytes at {1} of the multiplexed data at {2}",
+                data_length,
+                self.position,
+                here!()
+            )
+        })?;
+
+        Ok(Some((thread_id, buffer)))
+    }
+}
+
+impl<R: Read> Read for ChunkReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = if self.lookahead.is_empty() {
+            self.reader.read(buf)?
+        } else {
+            let n = cmp::min(buf.len(), self.lookahead.len());
+            buf[..n].copy_from_slice(&self.lookahead[..n]);
+            self.lookahead.drain(..n);
+            n
+        };
+
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+/// picks how much output each encoder thread collects before it is interleaved into the file. Bigger chunks mean
+/// less synchronization between the threads, but the decoder can only start on a thread once the first chunk of
+/// it has been read, so by default aim for a handful of chunks per thread.
+pub fn get_chunk_size(
+    enabled_features: &EnabledFeatures,
+    thread_handoffs: &[ThreadHandoff],
+) -> usize {
+    if let Some(chunk_size) = enabled_features.chunk_size {
+        return cmp::max(chunk_size, 1);
+    }
+
+    // the compressed output is smaller than the scan data of the segment, so this is an upper bound
+    let largest_segment = thread_handoffs
+        .iter()
+        .map(|th| th.segment_size as usize)
+        .max()
+        .unwrap_or(0);
+
+    (largest_segment / 8).clamp(MIN_DEFAULT_CHUNK_SIZE, MAX_DEFAULT_CHUNK_SIZE)
+}
+
+/// the most memory that encoding one of the thread segments allocates: its models and the buffer of its output
+fn get_segment_memory(
+    enabled_features: &EnabledFeatures,
+    thread_handoffs: &[ThreadHandoff],
+) -> u64 {
+    let models = if enabled_features.get_separate_chroma_models() {
+        2
+    } else {
+        1
+    };
+
+    (models * MAX_MODEL_MEMORY + get_chunk_size(enabled_features, thread_handoffs)) as u64
+}
+
+/// creates the quantization tables for each component
+pub fn get_quantization_tables(jpeg_header: &JPegHeader) -> Result<Vec<QuantizationTables>> {
+    let mut qt = Vec::new();
+    for i in 0..jpeg_header.cmpc {
+        let qtables = QuantizationTables::new(jpeg_header, i);
+
+        // check to see if quantitization table was properly initialized
+        // (table contains divisors for coefficients so it never should have a zero)
+        if qtables.get_quantization_table()[0] == 0 {
+            return err_exit_code(ExitCode::UnsupportedJpeg, "Quantization table is missing");
+        }
+        qt.push(qtables);
+    }
+
+    Ok(qt)
+}
+
+/// runs the encoding threads and returns the total amount of CPU time consumed (including worker threads).
+///
+/// If the segment index is enabled, the output of each thread is held back until all of them are done and then
+/// written one after the other, and the returned index says where each one ended up. The CRC32 of the data
+/// of each thread is returned as well, whether or not the file ends up storing them. The segments are encoded
+/// on at most max_threads workers, and handed to verifier as soon as they are done if there is one.
+fn run_lepton_encoder_threads<W: Write + Seek>(
+    lp: &LeptonHeader,
+    writer: &mut W,
+    image_data: &[BlockBasedImage],
+    enabled_features: &EnabledFeatures,
+    max_threads: usize,
+    verifier: Option<&SegmentVerifier>,
+) -> Result<(Metrics, Vec<SegmentIndexEntry>, Vec<u32>)> {
+    let jpeg_header = &lp.jpeg_header;
+    let thread_handoffs = &lp.thread_handoff[..];
+    let wall_time = Instant::now();
+
+    let contiguous_segments = enabled_features.segment_index;
+
+    // Get number of threads. Verify that it fits in 4 bits for serialization.
+    let num_threads = thread_handoffs.len();
+    assert!(
+        num_threads <= MAX_THREADS_SUPPORTED_BY_LEPTON_FORMAT,
+        "Too many thread handoffs"
+    );
+
+    // Prepare quantization tables
+    let pts = ProbabilityTablesSet::new(
+        enabled_features.get_quantized_dc_prediction(),
+        enabled_features.get_fast_dc_prediction(),
+    );
+    let mut quantization_tables = Vec::new();
+    for i in 0..image_data.len() {
+        let qtables = QuantizationTables::new(jpeg_header, i);
+
+        // check to see if quantitization table was properly initialized
+        // (table contains divisors for coefficients so it never should have a zero)
+        if qtables.get_quantization_table()[0] == 0 {
+            return err_exit_code(ExitCode::UnsupportedJpeg, "Quantization table is missing");
+        }
+        quantization_tables.push(qtables);
+    }
+
+    // the image stays allocated until all the segments are encoded
+    let memory = MemoryTracker::new(enabled_features.memory_budget);
+    let _image_memory = memory
+        .reserve(
+            (0..image_data.len())
+                .map(|i| BlockBasedImage::memory_for(jpeg_header, i))
+                .sum(),
+            "the blocks of the image",
+        )
+        .context(here!())?;
+
+    // a single segment is encoded on the calling thread, which writes the same chunks that a worker would have
+    // sent to it, but without starting a worker or going through a channel. The workers hand the segments to the
+    // verifier.
+    let EncodedSegments {
+        metrics: merged_metrics,
+        sizes,
+        segment_data,
+        segment_crcs,
+    } = if thread_handoffs.len() == 1 && verifier.is_none() {
+        let _segment_memory = memory
+            .reserve(
+                get_segment_memory(enabled_features, thread_handoffs),
+                "the thread segment",
+            )
+            .context(here!())?;
+
+        encode_segment_on_calling_thread(
+            lp,
+            writer,
+            image_data,
+            enabled_features,
+            &pts,
+            &quantization_tables,
+        )
+    } else {
+        encode_segments_on_workers(
+            lp,
+            writer,
+            image_data,
+            enabled_features,
+            &pts,
+            &quantization_tables,
+            max_threads,
+            &memory,
+            verifier,
+        )
+    }
+    .context(here!())?;
+
+    // what each bool writer says it wrote is what arrived from its thread
+    debug_assert_eq!(merged_metrics.get_segment_bytes(), &sizes[..]);
+
+    let mut segment_index = Vec::new();
+    if contiguous_segments {
+        let data_start = writer.stream_position().context(here!())?;
+
+        for (thread_handoff, data) in thread_handoffs.iter().zip(segment_data) {
+            segment_index.push(SegmentIndexEntry {
+                offset: writer.stream_position().context(here!())? - data_start,
+                length: data.len() as u64,
+                luma_y_start: thread_handoff.luma_y_start,
+                luma_y_end: thread_handoff.luma_y_end,
+            });
+
+            writer.write_all(&data[..]).context(here!())?;
+        }
+    }
+
+    info!(
+        "scan portion of JPEG uncompressed size = {0}",
+        sizes.iter().sum::<u64>()
+    );
+
+    info!(
+        "worker threads {0}ms of CPU time in {1}ms of wall time",
+        merged_metrics.get_cpu_time_worker_time().as_millis(),
+        wall_time.elapsed().as_millis()
+    );
+
+    Ok((
+        merged_metrics,
+        segment_index,
+        segment_crcs.iter().map(|c| c.sum()).collect(),
+    ))
+}
+
+/// what the encoder wrote for each thread segment
+struct EncodedSegments {
+    metrics: Metrics,
+    /// the size of the coded data of each segment, without the framing of its chunks
+    sizes: Vec<u64>,
+    /// the chunks of each segment when they are written after each other, see EnabledFeatures::segment_index
+    segment_data: Vec<Vec<u8>>,
+    segment_crcs: Vec<Crc>,
+}
+
+/// encodes the thread segments on up to max_threads workers of the thread pool, which send their output in chunks
+/// to the calling thread to write out. Each worker reserves the memory of a segment with memory before it takes
+/// one, and if there isn't enough left, either stops taking segments so that fewer run at the same time, or fails,
+/// depending on EnabledFeatures::memory_budget_policy. As soon as a segment is done, its chunks are handed to a
+/// job of its own that checks them with verifier, if there is one, while the other segments are still encoded.
+#[allow(clippy::too_many_arguments)]
+fn encode_segments_on_workers<W: Write>(
+    lp: &LeptonHeader,
+    writer: &mut W,
+    image_data: &[BlockBasedImage],
+    enabled_features: &EnabledFeatures,
+    pts: &ProbabilityTablesSet,
+    quantization_tables: &[QuantizationTables],
+    max_threads: usize,
+    memory: &MemoryTracker,
+    verifier: Option<&SegmentVerifier>,
+) -> Result<EncodedSegments> {
+    let thread_handoffs = &lp.thread_handoff[..];
+
+    let chunk_size = get_chunk_size(enabled_features, thread_handoffs);
+    let contiguous_segments = enabled_features.segment_index;
+    let interleave_in_turn = enabled_features.deterministic && !contiguous_segments;
+
+    let mut sizes = Vec::<u64>::new();
+    sizes.resize(thread_handoffs.len(), 0);
+
+    let mut segment_data = Vec::<Vec<u8>>::new();
+    segment_data.resize(thread_handoffs.len(), Vec::new());
+
+    let mut segment_crcs: Vec<Crc> = thread_handoffs.iter().map(|_| Crc::new()).collect();
+
+    let mut merged_metrics = Metrics::default();
+
+    #[cfg(feature = "test-utils")]
+    let chunk_delay = CHUNK_DELAY.with(|d| d.get());
+
+    // each worker takes the next segment that nobody has started on, so there can be more segments than workers
+    let next_segment = AtomicUsize::new(0);
+    let next_segment_ref = &next_segment;
+    let num_workers = cmp::max(1, cmp::min(max_threads, thread_handoffs.len()));
+
+    // set by the first worker that fails, along with the cancel flag that the other workers check before each row
+    let first_failed = AtomicUsize::new(NO_FAILED_WORKER);
+    let first_failed_ref = &first_failed;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let cancelled_ref = &cancelled;
+
+    // workers that haven't stopped taking segments, of which the last one has to go on even when another
+    // segment doesn't fit into the memory budget, since it has nobody to leave the remaining segments to
+    let segment_memory = get_segment_memory(enabled_features, thread_handoffs);
+    let active_workers = AtomicUsize::new(num_workers);
+    let active_workers_ref = &active_workers;
+
+    thread_pool::scope(num_workers, |s| -> Result<()> {
+        let (tx, rx) = channel();
+
+        let mut running_threads = Vec::new();
+
+        for i in 0..num_workers {
+            let cloned_sender = tx.clone();
+
+            running_threads.push(s.spawn(move || -> Result<Metrics> {
+                let cpu_time = ThreadTime::now();
+
+                let mut worker_metrics = Metrics::default();
+
+                // the first worker to fail cancels the others
+                let fail = |e: anyhow::Error| {
+                    let _ = first_failed_ref.compare_exchange(
+                        NO_FAILED_WORKER,
+                        i,
+                        Ordering::SeqCst,
+                        Ordering::SeqCst,
+                    );
+                    cancelled_ref.store(true, Ordering::SeqCst);
+
+                    Err(e)
+                };
+
+                loop {
+                    if cancelled_ref.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    // a worker that stops taking segments has to do so before it takes one, and releases what it
+                    // reserved before it stops, so that the last active worker has the budget to itself
+                    let reservation = match memory.try_reserve(segment_memory) {
+                        Some(r) => r,
+                        None if enabled_features.memory_budget_policy
+                            == MemoryBudgetPolicy::Serialize
+                            && active_workers_ref
+                                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                                    (n > 1).then_some(n - 1)
+                                })
+                                .is_ok() =>
+                        {
+                            info!("worker {0} stops to stay within the memory budget", i);
+
+                            worker_metrics.record_cpu_worker_time(cpu_time.elapsed());
+                            return Ok(worker_metrics);
+                        }
+                        None => match memory.reserve(segment_memory, "the thread segment") {
+                            Ok(r) => r,
+                            Err(e) => return fail(e.context(here!())),
+                        },
+                    };
+
+                    let thread_id = next_segment_ref.fetch_add(1, Ordering::Relaxed);
+                    if thread_id >= thread_handoffs.len() {
+                        break;
+                    }
+
+                    let mut thread_writer = MessageSender {
+                        thread_id: thread_id as u8,
+                        sender: cloned_sender.clone(),
+                        buffer: Vec::with_capacity(chunk_size),
+                        chunk_size,
+                        #[cfg(feature = "test-utils")]
+                        chunk_delay,
+                        #[cfg(feature = "test-utils")]
+                        chunks_sent: 0,
+                    };
+
+                    let segment_metrics = run_segment(
+                        enabled_features.thread_lifecycle_hook.as_ref(),
+                        WorkerKind::Encoder,
+                        thread_id,
+                        || {
+                            encode_segment(
+                                lp,
+                                image_data,
+                                enabled_features,
+                                pts,
+                                quantization_tables,
+                                thread_id,
+                                Some(cancelled_ref.clone()),
+                                &mut thread_writer,
+                            )
+                            .context(here!())
+                            .and_then(|m| {
+                                thread_writer.flush().context(here!())?;
+
+                                thread_writer
+                                    .sender
+                                    .send(Message::Eof(thread_id as u8))
+                                    .context(here!())?;
+                                Ok(m)
+                            })
+                        },
+                    );
+
+                    drop(reservation);
+
+                    match segment_metrics {
+                        Ok(m) => worker_metrics.merge_from(m),
+                        Err(e) => return fail(e),
+                    }
+                }
+
+                active_workers_ref.fetch_sub(1, Ordering::SeqCst);
+
+                worker_metrics.record_cpu_worker_time(cpu_time.elapsed());
+
+                Ok(worker_metrics)
+            }));
+        }
+
+        // drop the sender so that the channel breaks when all the threads exit
+        drop(tx);
+
+        // wait to collect work and done messages from all the threads
+        let mut threads_left = thread_handoffs.len();
+
+        // in deterministic mode the chunks are written in turn, one from each thread that still has data,
+        // rather than in whatever order they happen to arrive, so that their order only depends on the segment
+        // and the number of the chunk within it. The chunks that arrive before their turn are held here.
+        let mut pending: Vec<VecDeque<Vec<u8>>> =
+            thread_handoffs.iter().map(|_| VecDeque::new()).collect();
+        let mut finished = vec![false; thread_handoffs.len()];
+        let mut next_in_turn = 0;
+
+        // the chunks of each segment that the verifier gets once the segment is done
+        let mut verify_data: Vec<Vec<u8>> = thread_handoffs.iter().map(|_| Vec::new()).collect();
+        let mut verify_threads = Vec::new();
+
+        while threads_left > 0 {
+            let value = rx.recv().context(here!());
+            match value {
+                Ok(Message::Eof(thread_id)) => {
+                    threads_left -= 1;
+                    finished[thread_id as usize] = true;
+
+                    if let Some(verifier) = verifier {
+                        let chunks = mem::take(&mut verify_data[thread_id as usize]);
+                        verify_threads.push(
+                            s.spawn(move || verifier.verify_segment(thread_id as usize, &chunks)),
+                        );
+                    }
+                }
+                Ok(Message::WriteBlock(thread_id, b)) => {
+                    sizes[thread_id as usize] += b.len() as u64;
+                    segment_crcs[thread_id as usize].update(&b);
+
+                    if verifier.is_some() {
+                        write_chunks(&mut verify_data[thread_id as usize], thread_id, &b)
+                            .context(here!())?;
+                    }
+
+                    if interleave_in_turn {
+                        pending[thread_id as usize].push_back(b);
+                    } else if contiguous_segments {
+                        write_chunks(&mut segment_data[thread_id as usize], thread_id, &b)
+                            .context(here!())?;
+                    } else {
+                        write_chunks(writer, thread_id, &b).context(here!())?;
+                    }
+                }
+                Err(x) => {
+                    // get the actual error that cause the channel to prematurely close, which is the one of the
+                    // worker that failed first, since the others only failed because they were cancelled
+                    let first = first_failed.load(Ordering::SeqCst);
+                    for (i, result) in running_threads.drain(..).enumerate() {
+                        let r = result.join().unwrap();
+                        if let Err(e) = r {
+                            if i == first {
+                                return Err(e.context(here!()));
+                            }
+                        }
+                    }
+
+                    return Err(x);
+                }
+            }
+
+            if interleave_in_turn {
+                loop {
+                    // skip over the threads that are done and have been written out completely
+                    let mut skipped = 0;
+                    while skipped < finished.len()
+                        && finished[next_in_turn]
+                        && pending[next_in_turn].is_empty()
+                    {
+                        next_in_turn = (next_in_turn + 1) % finished.len();
+                        skipped += 1;
+                    }
+
+                    if skipped == finished.len() {
+                        break;
+                    }
+
+                    match pending[next_in_turn].pop_front() {
+                        Some(b) => {
+                            write_chunks(writer, next_in_turn as u8, &b).context(here!())?;
+                            next_in_turn = (next_in_turn + 1) % finished.len();
+                        }
+                        // wait for the thread whose turn it is
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        for result in running_threads.drain(..) {
+            merged_metrics.merge_from(result.join().unwrap().unwrap());
+        }
+
+        for result in verify_threads {
+            result.join().unwrap().context(here!())?;
+        }
+
+        return Ok(());
+    })
+    .context(here!())?;
+
+    Ok(EncodedSegments {
+        metrics: merged_metrics,
+        sizes,
+        segment_data,
+        segment_crcs,
+    })
+}
+
+/// encodes the only thread segment of the image on the calling thread, writing the same chunks as
+/// encode_segments_on_workers would
+fn encode_segment_on_calling_thread<W: Write>(
+    lp: &LeptonHeader,
+    writer: &mut W,
+    image_data: &[BlockBasedImage],
+    enabled_features: &EnabledFeatures,
+    pts: &ProbabilityTablesSet,
+    quantization_tables: &[QuantizationTables],
+) -> Result<EncodedSegments> {
+    assert!(lp.thread_handoff.len() == 1, "only one thread handoff");
+
+    let cpu_time = ThreadTime::now();
+
+    let chunk_size = get_chunk_size(enabled_features, &lp.thread_handoff);
+    let contiguous_segments = enabled_features.segment_index;
+
+    let mut size = 0;
+    let mut segment_data = Vec::new();
+    let mut segment_crc = Crc::new();
+
+    let mut write_chunk = |chunk: &[u8]| -> Result<()> {
+        size += chunk.len() as u64;
+        segment_crc.update(chunk);
+
+        if contiguous_segments {
+            write_chunks(&mut segment_data, 0, chunk).context(here!())
+        } else {
+            write_chunks(writer, 0, chunk).context(here!())
+        }
+    };
+
+    let mut chunk_writer = ChunkWriter::new(chunk_size, &mut write_chunk);
+
+    let r = encode_segment(
+        lp,
+        image_data,
+        enabled_features,
+        pts,
+        quantization_tables,
+        0,
+        None,
+        &mut chunk_writer,
+    );
+
+    // the error of writing the output is more useful than the io error it caused on the way out
+    if let Some(e) = chunk_writer.take_error() {
+        return Err(e);
+    }
+
+    let mut metrics = r.context(here!())?;
+    chunk_writer.finish().context(here!())?;
+
+    metrics.record_cpu_worker_time(cpu_time.elapsed());
+
+    Ok(EncodedSegments {
+        metrics,
+        sizes: vec![size],
+        segment_data: vec![segment_data],
+        segment_crcs: vec![segment_crc],
+    })
+}
+
+/// encodes the rows of thread segment thread_id into writer, starting from a fresh model. The segment is given up
+/// once cancel_flag is set, see Model::set_cancel_flag.
+#[allow(clippy::too_many_arguments)]
+fn encode_segment<W: Write>(
+    lp: &LeptonHeader,
+    image_data: &[BlockBasedImage],
+    enabled_features: &EnabledFeatures,
+    pts: &ProbabilityTablesSet,
+    quantization_tables: &[QuantizationTables],
+    thread_id: usize,
+    cancel_flag: Option<Arc<AtomicBool>>,
+    writer: &mut W,
+) -> Result<Metrics> {
+    let thread_handoffs = &lp.thread_handoff[..];
+    let model_init = ModelInit::for_version(lp.file_version);
+
+    let initial_model = || {
+        let mut model = Model::new_for_segment(
+            enabled_features.model_primer.as_deref(),
+            &enabled_features.get_model_tuning(),
+            model_init,
+            enabled_features.model_snapshot_hook.clone(),
+            enabled_features.low_latency,
+        );
+
+        #[cfg(feature = "debug-symmetry")]
+        model.set_symmetry_recorder(enabled_features.symmetry_recorder.clone());
+
+        model.set_cancel_flag(cancel_flag.clone());
+
+        model
+    };
+
+    let mut cr_model = if enabled_features.get_separate_chroma_models() {
+        Some(initial_model())
+    } else {
+        None
+    };
+
+    lepton_encode_row_range(
+        pts,
+        quantization_tables,
+        image_data,
+        writer,
+        thread_id as i32,
+        &lp.truncate_components,
+        thread_handoffs[thread_id].luma_y_start,
+        thread_handoffs[thread_id].luma_y_end,
+        thread_id == thread_handoffs.len() - 1,
+        true,
+        &mut initial_model(),
+        cr_model.as_deref_mut(),
+        enabled_features.get_coefficient_codec(),
+    )
+    .context(here!())
+}
+
+#[derive(Debug)]
+pub struct LeptonHeader {
+    /// raw jpeg header to be written back to the file when it is recreated
+    pub raw_jpeg_header: Vec<u8>,
+
+    /// how far we have read into the raw header, since the header is divided
+    /// into multiple chucks for each scan. For example, a progressive image
+    /// would start with the jpeg image segments, followed by a SOS (start of scan)
+    /// after which comes the encoded jpeg coefficients, and once thats over
+    /// we get another header segment until the next SOS, etc
+    pub raw_jpeg_header_read_index: usize,
+
+    pub thread_handoff: Vec<ThreadHandoff>,
+
+    pub jpeg_header: JPegHeader,
+
+    /// information about how to truncate the image if it was partially written
+    pub truncate_components: TruncateComponents,
+
+    pub rst_err: Vec<u8>,
+
+    /// A list containing one entry for each scan segment.  Each entry contains the number of restart intervals
+    /// within the corresponding scan segment.
+    pub rst_cnt: Vec<i32>,
+
+    /// the mask for padding out the bitstream when we get to the end of a reset block
+    pub pad_bit: Option<u8>,
+
+    /// positions where the encoder padded with something other than pad_bit
+    pub pad_bit_exceptions: Vec<PadBitException>,
+
+    /// restart points where the RST markers in the file weren't the single one we'd expect
+    pub restart_exceptions: Vec<RestartException>,
+
+    /// COM or APPn segments that were found between restart intervals inside the scan data
+    pub scan_segments: Vec<ScanSegment>,
+
+    pub rst_cnt_set: bool,
+
+    /// garbage data (default value - empty segment - means no garbage data)
+    pub garbage_data: Vec<u8>,
+
+    /// files whose header claims more garbage data than this are refused before it is allocated
+    pub max_trailing_bytes: u64,
+
+    /// files that need features this version of the format doesn't have are refused
+    pub format_version: LeptonVersion,
+
+    /// the version of the file, which is the oldest one that has all the features it needs. It picks how the
+    /// model starts out, see ModelInit.
+    pub file_version: LeptonVersion,
+
+    /// count of scans encountered so far
+    pub scnc: usize,
+
+    pub early_eof_encountered: bool,
+
+    /// CRC32 of the original JPEG file if the lepton file contains it, checked as the JPEG is recreated
+    pub original_file_crc: Option<u32>,
+
+    /// exact size of the original JPEG file if the lepton file contains it, checked once the JPEG is recreated
+    pub original_file_size: Option<u64>,
+
+    /// build of the encoder that wrote the file, if it recorded it
+    pub encoder_info: Option<EncoderInfo>,
+
+    /// baseline or progressive and the number of scans. Files written before this was recorded get it from the
+    /// JPEG header when it is read, and passthrough files don't have it.
+    pub file_type: Option<LeptonFileType>,
+
+    /// where the segment index starts, counted from the end of the header, if the file has one
+    pub segment_index_offset: Option<u64>,
+
+    /// the file stores the sizes and offsets that can go beyond 4GB with 64 bits (LEPTON_FEATURE_LARGE_SIZES).
+    /// This covers the size of the JPEG and of each thread segment (in the LSZ marker, since the fields for them
+    /// are 32 bits), the segment index offset and entries, the garbage data and the file size at the end.
+    pub large_sizes: bool,
+
+    /// the multiplexed data (and the segment index if there is one) is followed by the CRC32 of the data of each
+    /// thread segment, not counting the chunk framing (LEPTON_FEATURE_SEGMENT_CHECKSUMS)
+    pub segment_checksums: bool,
+
+    /// the file only keeps the coefficients and the header segments needed to decode them, so it decodes to a
+    /// clean JPEG with the same image rather than to the original file (LEPTON_FEATURE_COEFFICIENTS_ONLY)
+    pub coefficients_only: bool,
+
+    /// id of the primer that the model of each thread segment starts from, if the file was encoded with one
+    /// (LEPTON_FEATURE_MODEL_PRIMER)
+    pub model_primer_id: Option<u32>,
+
+    /// the primer that the decoder was given, which has to match model_primer_id
+    pub model_primer: Option<Arc<ModelPrimer>>,
+
+    /// how the branches of the model adapt, which is only recorded in the file if it isn't the default
+    /// (LEPTON_FEATURE_MODEL_TUNING)
+    pub model_tuning: ModelTuning,
+
+    /// the Cr component is coded with its own model, so that it doesn't share the chroma model with Cb
+    /// (LEPTON_FEATURE_SEPARATE_CHROMA_MODELS)
+    pub separate_chroma_models: bool,
+
+    /// the DC of each block is predicted in steps of its quantization, rounding to the nearest step, and the
+    /// uncertainty of the prediction that picks the branches is measured in these steps as well
+    /// (LEPTON_FEATURE_QUANTIZED_DC_PREDICTION)
+    pub quantized_dc_prediction: bool,
+
+    /// the DC of each block is predicted from the DCs of the neighbor blocks instead of the pixels at their
+    /// edges, which saves the IDCT of each block (LEPTON_FEATURE_FAST_DC_PREDICTION)
+    pub fast_dc_prediction: bool,
+
+    /// the model of each thread segment starts from the trained initial counts instead of the uniform ones
+    /// (LEPTON_FEATURE_TRAINED_INITIAL_PROBS)
+    pub trained_initial_probs: bool,
+
+    /// how the coefficients are coded (LEPTON_FEATURE_EXPERIMENTAL_CODEC if it isn't the model)
+    pub coefficient_codec: CoefficientCodecKind,
+
+    /// called with snapshots of the model while decoding, for debugging
+    pub model_snapshot_hook: Option<ModelSnapshotHook>,
+
+    /// called as the workers start and finish each thread segment, see EnabledFeatures::thread_lifecycle_hook
+    pub thread_lifecycle_hook: Option<ThreadLifecycleHook>,
+
+    /// logs the decisions of the decoder, for debugging
+    #[cfg(feature = "debug-symmetry")]
+    pub symmetry_recorder: Option<SymmetryRecorder>,
+
+    /// allocates all of the model of each thread before decoding, see EnabledFeatures::low_latency
+    pub low_latency: bool,
+
+    /// checks that the data of each thread segment ends right after its flush, see
+    /// EnabledFeatures::strict_segment_end
+    pub strict_segment_end: bool,
+
+    /// pieces of output each thread can have waiting to be written, see EnabledFeatures::max_output_buffers
+    pub max_output_buffers: usize,
+
+    /// bytes of data the decoder reads ahead of its workers, see EnabledFeatures::max_read_ahead
+    pub max_read_ahead: u64,
+
+    /// size of the original file if it is stored as is after the header instead of the coded thread segments,
+    /// in which case the header contains no information about the JPEG
+    pub passthrough_size: Option<u64>,
+
+    /// the maximum dpos in a truncated image
+    pub max_dpos: [i32; 4],
+
+    /// the maximum component in a truncated image
+    pub max_cmp: i32,
+
+    /// the maximum band in a truncated image
+    pub max_bpos: i32,
+
+    /// the maximum bit in a truncated image
+    pub max_sah: u8,
+
+    pub jpeg_file_size: u64,
+
+    /// on decompression, plain-text size
+    pub plain_text_size: u64,
+
+    /// on decompression, uncompressed lepton header size
+    pub uncompressed_lepton_header_size: u32,
+}
+
+/// fill bits used at the end of a given restart interval of a scan that didn't match the default pad bit
+#[derive(Debug, Clone, PartialEq)]
+pub struct PadBitException {
+    pub scan: u32,
+    pub interval: u32,
+    pub fill_bits: u8,
+}
+
+/// RST markers found at the end of a given restart interval of a scan if they weren't the single marker
+/// numbered after the interval. Empty if the marker was missing, or more than one for redundant markers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestartException {
+    pub scan: u32,
+    pub interval: u32,
+    pub markers: Vec<u8>,
+}
+
+/// marker segment found between two restart intervals of a scan, which has to be written back at the same spot
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanSegment {
+    pub scan: u32,
+    pub interval: u32,
+    /// number of RST markers after the interval that came before the segment
+    pub position: u32,
+    /// the entire segment including the marker and length
+    pub data: Vec<u8>,
+}
+
+/// where the data of one thread segment is in the lepton file, so that it can be decoded without reading
+/// the segments before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentIndexEntry {
+    /// offset of the segment's chunks counted from the end of the header
+    pub offset: u64,
+    /// size of the segment's chunks including their thread id and length prefixes
+    pub length: u64,
+    pub luma_y_start: i32,
+    pub luma_y_end: i32,
+}
+
+impl SegmentIndexEntry {
+    /// size of each entry in the file, where the offset and length take 64 bits in files with large sizes
+    pub fn serialized_size(large_sizes: bool) -> u64 {
+        if large_sizes {
+            24
+        } else {
+            16
+        }
+    }
+
+    pub fn deserialize<R: Read>(
+        num_segments: usize,
+        large_sizes: bool,
+        data: &mut R,
+    ) -> Result<Vec<SegmentIndexEntry>> {
+        let mut retval = Vec::with_capacity(num_segments);
+
+        for _i in 0..num_segments {
+            let (offset, length) = if large_sizes {
+                (
+                    data.read_u64::<LittleEndian>()?,
+                    data.read_u64::<LittleEndian>()?,
+                )
+            } else {
+                (
+                    u64::from(data.read_u32::<LittleEndian>()?),
+                    u64::from(data.read_u32::<LittleEndian>()?),
+                )
+            };
+
+            retval.push(SegmentIndexEntry {
+                offset,
+                length,
+                luma_y_start: data.read_i32::<LittleEndian>()?,
+                luma_y_end: data.read_i32::<LittleEndian>()?,
+            });
+        }
+
+        Ok(retval)
+    }
+
+    pub fn serialize<W: Write>(
+        index: &[SegmentIndexEntry],
+        large_sizes: bool,
+        data: &mut W,
+    ) -> Result<()> {
+        for entry in index {
+            if large_sizes {
+                data.write_u64::<LittleEndian>(entry.offset)?;
+                data.write_u64::<LittleEndian>(entry.length)?;
+            } else {
+                data.write_u32::<LittleEndian>(narrow_size(entry.offset, "segment offset")?)?;
+                data.write_u32::<LittleEndian>(narrow_size(entry.length, "segment length")?)?;
+            }
+            data.write_i32::<LittleEndian>(entry.luma_y_start)?;
+            data.write_i32::<LittleEndian>(entry.luma_y_end)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// identifies the build of the encoder that wrote a lepton file, to help track down which version
+/// produced a broken file
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncoderInfo {
+    /// crate version, at most ENCODER_INFO_VERSION_SIZE bytes
+    pub version: String,
+    /// git revision the encoder was built from (taken from the LEPTON_JPEG_GIT_REVISION environment
+    /// variable at compile time), at most ENCODER_INFO_GIT_REVISION_SIZE bytes. Empty if it wasn't set.
+    pub git_revision: String,
+    /// EnabledFeatures::to_bits of the options the file was encoded with
+    pub enabled_features: u32,
+}
+
+impl EncoderInfo {
+    /// information about this build
+    pub fn current(enabled_features: &EnabledFeatures) -> Self {
+        EncoderInfo {
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            git_revision: option_env!("LEPTON_JPEG_GIT_REVISION")
+                .unwrap_or("")
+                .to_owned(),
+            enabled_features: enabled_features.to_bits(),
+        }
+    }
+
+    /// writes the info as fixed size zero padded fields (cutting off anything that doesn't fit)
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&LEPTON_HEADER_ENCODER_INFO_MARKER)?;
+
+        for (s, size) in [
+            (&self.version, ENCODER_INFO_VERSION_SIZE),
+            (&self.git_revision, ENCODER_INFO_GIT_REVISION_SIZE),
+        ] {
+            let mut field = s.as_bytes().to_vec();
+            field.resize(size, 0);
+            writer.write_all(&field[..])?;
+        }
+
+        writer.write_u32::<LittleEndian>(self.enabled_features)?;
+
+        Ok(())
+    }
+
+    /// parses the info if it is there, ignoring anything we don't recognize
+    fn read(data: &[u8]) -> Option<Self> {
+        let mut reader = Cursor::new(data);
+
+        let mut marker = [0u8; 3];
+        reader.read_exact(&mut marker).ok()?;
+        if !buffer_prefix_matches_marker(marker, LEPTON_HEADER_ENCODER_INFO_MARKER) {
+            return None;
+        }
+
+        let mut read_field = |size| {
+            let mut field = vec![0u8; size];
+            reader.read_exact(&mut field).ok()?;
+
+            let len = field.iter().position(|&b| b == 0).unwrap_or(size);
+            Some(String::from_utf8_lossy(&field[..len]).into_owned())
+        };
+
+        let version = read_field(ENCODER_INFO_VERSION_SIZE)?;
+        let git_revision = read_field(ENCODER_INFO_GIT_REVISION_SIZE)?;
+
+        Some(EncoderInfo {
+            version,
+            git_revision,
+            enabled_features: reader.read_u32::<LittleEndian>().ok()?,
+        })
+    }
+}
+
+/// whether the JPEG in a lepton file is baseline or progressive and how many scans it has, which tells how
+/// much work decoding it will take
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeptonFileType {
+    pub progressive: bool,
+    /// number of scans in the JPEG, which is more than one for progressive images and for sequential
+    /// images that code the components in separate scans
+    pub scan_count: u32,
+}
+
+impl LeptonFileType {
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&LEPTON_HEADER_FILE_TYPE_MARKER)?;
+        writer.write_u8(u8::from(self.progressive))?;
+        writer.write_u32::<LittleEndian>(self.scan_count)?;
+
+        Ok(())
+    }
+
+    /// parses the type if it is there, files written before it was recorded don't have it
+    fn read(data: &[u8]) -> Option<Self> {
+        let mut reader = Cursor::new(data);
+
+        let mut marker = [0u8; 3];
+        reader.read_exact(&mut marker).ok()?;
+        if !buffer_prefix_matches_marker(marker, LEPTON_HEADER_FILE_TYPE_MARKER) {
+            return None;
+        }
+
+        Some(LeptonFileType {
+            progressive: reader.read_u8().ok()? != 0,
+            scan_count: reader.read_u32::<LittleEndian>().ok()?,
+        })
+    }
+}
+
+/// returns the segments of the raw JPEG header that are needed to decode the image, in their original order
+fn canonical_jpeg_header(raw_jpeg_header: &[u8]) -> Result<Vec<u8>> {
+    let mut canonical = Vec::with_capacity(raw_jpeg_header.len());
+    let mut pos = 0;
+
+    while pos < raw_jpeg_header.len() {
+        if pos + 4 > raw_jpeg_header.len() || raw_jpeg_header[pos] != 0xFF {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "header contains data outside of segments",
+            );
+        }
+
+        let marker = raw_jpeg_header[pos + 1];
+        let end = pos
+            + 2
+            + usize::from(u16::from_be_bytes([
+                raw_jpeg_header[pos + 2],
+                raw_jpeg_header[pos + 3],
+            ]));
+        if end > raw_jpeg_header.len() {
+            return err_exit_code(ExitCode::UnsupportedJpeg, "header segment is cut off");
+        }
+
+        // all the SOFn markers except for the ones that JPEG reuses for DHT, JPG and DAC
+        let is_sof = (0xC0..=0xCF).contains(&marker)
+            && marker != jpeg_code::DHT
+            && marker != 0xC8
+            && marker != 0xCC;
+
+        if is_sof
+            || marker == jpeg_code::DHT
+            || marker == jpeg_code::DQT
+            || marker == jpeg_code::DRI
+            || marker == jpeg_code::SOS
+        {
+            canonical.extend_from_slice(&raw_jpeg_header[pos..end]);
+        }
+
+        pos = end;
+    }
+
+    Ok(canonical)
+}
+
+/// counts the SOS segments in the raw JPEG header, which contains the header segments of all the scans
+fn count_scans(raw_jpeg_header: &[u8]) -> u32 {
+    let mut count = 0;
+    let mut pos = 0;
+
+    while pos + 1 < raw_jpeg_header.len() && raw_jpeg_header[pos] == 0xFF {
+        let marker = raw_jpeg_header[pos + 1];
+
+        // fill bytes and markers without a length
+        if marker == 0xFF {
+            pos += 1;
+            continue;
+        }
+        if marker == 0x01 || (jpeg_code::RST0..=jpeg_code::EOI).contains(&marker) {
+            pos += 2;
+            continue;
+        }
+
+        if marker == jpeg_code::SOS {
+            count += 1;
+        }
+
+        if pos + 4 > raw_jpeg_header.len() {
+            break;
+        }
+        let len = usize::from(u16::from_be_bytes([
+            raw_jpeg_header[pos + 2],
+            raw_jpeg_header[pos + 3],
+        ]));
+        pos += 2 + len;
+    }
+
+    count
+}
+
+impl LeptonHeader {
+    pub fn new() -> Self {
+        return LeptonHeader {
+            max_dpos: [0; 4],
+            raw_jpeg_header: Vec::new(),
+            raw_jpeg_header_read_index: 0,
+            thread_handoff: Vec::new(),
+            jpeg_header: JPegHeader::new(),
+            truncate_components: TruncateComponents::new(),
+            rst_err: Vec::new(),
+            rst_cnt: Vec::new(),
+            pad_bit: None,
+            pad_bit_exceptions: Vec::new(),
+            restart_exceptions: Vec::new(),
+            scan_segments: Vec::new(),
+            rst_cnt_set: false,
+            garbage_data: Vec::new(),
+            max_trailing_bytes: EnabledFeatures::default().max_trailing_bytes,
+            format_version: LeptonVersion::default(),
+            file_version: LeptonVersion::V1,
+            scnc: 0,
+            early_eof_encountered: false,
+            original_file_crc: None,
+            original_file_size: None,
+            encoder_info: None,
+            file_type: None,
+            segment_index_offset: None,
+            passthrough_size: None,
+            large_sizes: false,
+            segment_checksums: false,
+            coefficients_only: false,
+            model_primer_id: None,
+            model_primer: None,
+            model_tuning: ModelTuning::default(),
+            separate_chroma_models: false,
+            quantized_dc_prediction: false,
+            fast_dc_prediction: false,
+            trained_initial_probs: false,
+            coefficient_codec: CoefficientCodecKind::Model,
+            model_snapshot_hook: None,
+            thread_lifecycle_hook: None,
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_recorder: None,
+            low_latency: false,
+            strict_segment_end: cfg!(debug_assertions),
+            max_output_buffers: 64,
+            max_read_ahead: 64 * 1024 * 1024,
+            max_cmp: 0,
+            max_bpos: 0,
+            max_sah: 0,
+            jpeg_file_size: 0,
+            plain_text_size: 0,
+            uncompressed_lepton_header_size: 0,
+        };
+    }
+
+    /// takes the options of enabled_features that apply to decoding, which has to happen before the header is read
+    pub fn set_decoder_features(&mut self, enabled_features: &EnabledFeatures) {
+        self.max_trailing_bytes = enabled_features.max_trailing_bytes;
+        self.format_version = enabled_features.format_version;
+        self.model_primer = enabled_features.model_primer.clone();
+        self.model_snapshot_hook = enabled_features.model_snapshot_hook.clone();
+        self.thread_lifecycle_hook = enabled_features.thread_lifecycle_hook.clone();
+        #[cfg(feature = "debug-symmetry")]
+        {
+            self.symmetry_recorder = enabled_features.symmetry_recorder.clone();
+        }
+        self.low_latency = enabled_features.low_latency;
+        self.strict_segment_end = enabled_features.strict_segment_end;
+        self.max_output_buffers = enabled_features.max_output_buffers;
+        self.max_read_ahead = enabled_features.max_read_ahead;
+    }
+
+    fn recode_jpeg<R: Read, W: Write>(
+        &mut self,
+        writer: &mut W,
+        reader: &mut R,
+        remaining_size: Option<u64>,
+        num_threads: usize,
+    ) -> Result<Metrics, anyhow::Error> {
+        // keep a running CRC of everything we write so that the output can be checked against
+        // the original file without the caller having to buffer it
+        let mut writer = CrcWriter::new(writer);
+
+        if self.passthrough_size.is_some() {
+            self.copy_passthrough(reader, &mut writer)
+                .context(here!())?;
+            return Ok(Metrics::default());
+        }
+
+        self.write_jpeg_start(&mut writer).context(here!())?;
+
+        let metrics =
+            if self.jpeg_header.jpeg_type == JPegType::Progressive || self.has_additional_scans() {
+                self.recode_progressive_jpeg(reader, remaining_size, &mut writer, num_threads)
+                    .context(here!())?
+            } else {
+                self.recode_baseline_jpeg(reader, remaining_size, &mut writer, num_threads)
+                    .context(here!())?
+            };
+
+        self.write_jpeg_end(&mut writer).context(here!())?;
+
+        Ok(metrics)
+    }
+
+    /// copies the original file of a passthrough file from reader, checking it against its size and CRC
+    pub fn copy_passthrough<R: Read, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut CrcWriter<W>,
+    ) -> Result<()> {
+        let size = self.passthrough_size.unwrap_or(0);
+
+        let copied = std::io::copy(&mut reader.take(size), writer).context(here!())?;
+        if copied != size {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                format!(
+                    "file ends after {0} of the {1} bytes of the original",
+                    copied, size
+                )
+                .as_str(),
+            );
+        }
+
+        self.verify_original_file_size(copied).context(here!())?;
+        self.verify_original_file_crc(writer.crc().sum(), writer.amount())
+            .context(here!())?;
+
+        Ok(())
+    }
+
+    /// writes what comes in front of the scan data of the recreated JPEG, which is the SOI marker and the raw header
+    /// as far as we've decoded it
+    pub fn write_jpeg_start<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&SOI)?;
+
+        writer
+            .write_all(&self.raw_jpeg_header[0..self.raw_jpeg_header_read_index])
+            .context(here!())?;
+
+        Ok(())
+    }
+
+    /// writes what comes after the scan data of the recreated JPEG, and then checks everything that was written
+    /// against the size and the CRC of the original
+    pub fn write_jpeg_end<W: Write>(&self, writer: &mut CrcWriter<W>) -> Result<()> {
+        if !self.early_eof_encountered {
+            /* step 3: blit any trailing header data */
+            writer
+                .write_all(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..])
+                .context(here!())?;
+        }
+
+        writer.write_all(&self.garbage_data).context(here!())?;
+
+        self.verify_original_file_size(writer.amount())
+            .context(here!())?;
+
+        self.verify_original_file_crc(writer.crc().sum(), writer.amount())
+            .context(here!())?;
+
+        Ok(())
+    }
+
+    /// true if the JPEG or one of its thread segments is too big for the 32-bit fields of the regular format
+    pub fn needs_large_sizes(&self) -> bool {
+        self.jpeg_file_size > u64::from(u32::MAX)
+            || self
+                .thread_handoff
+                .iter()
+                .any(|th| th.segment_size > i64::from(i32::MAX))
+    }
+
+    /// number of bytes the file size at the end of the file takes
+    pub fn get_file_size_len(&self) -> u64 {
+        if self.large_sizes {
+            8
+        } else {
+            4
+        }
+    }
+
+    /// number of bytes the table of segment checksums takes, which comes just before the file size
+    pub fn get_segment_checksums_len(&self) -> u64 {
+        if self.segment_checksums {
+            4 * self.thread_handoff.len() as u64
+        } else {
+            0
+        }
+    }
+
+    /// checks the CRC of the data of each thread segment against the table of checksums, which is at the end of
+    /// trailer (the data that follows the multiplexed data up to the end of the file)
+    pub fn verify_segment_checksums(&self, segment_crcs: &[Crc], trailer: &[u8]) -> Result<()> {
+        let expected = self.read_segment_checksums(trailer)?;
+
+        for (index, (crc, expected)) in segment_crcs.iter().zip(expected).enumerate() {
+            if crc.sum() != expected {
+                return err_exit_code(
+                    ExitCode::CorruptSegment,
+                    format!(
+                        "CorruptSegment {{ index: {0}, expected: {1:08x}, actual: {2:08x} }}",
+                        index,
+                        expected,
+                        crc.sum()
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// reads the table of segment checksums from the end of trailer
+    pub fn read_segment_checksums(&self, trailer: &[u8]) -> Result<Vec<u32>> {
+        let table_len = self.get_segment_checksums_len() as usize;
+        let file_size_len = self.get_file_size_len() as usize;
+
+        if trailer.len() < table_len + file_size_len {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                "file is too short for the segment checksums",
+            );
+        }
+
+        let mut table = &trailer[trailer.len() - file_size_len - table_len..];
+        let mut checksums = Vec::with_capacity(self.thread_handoff.len());
+        for _ in 0..self.thread_handoff.len() {
+            checksums.push(table.read_u32::<LittleEndian>()?);
+        }
+
+        Ok(checksums)
+    }
+
+    /// writes the size of the whole lepton file that comes at its end
+    pub fn write_file_size<W: Write>(&self, writer: &mut W, file_size: u64) -> Result<()> {
+        if self.large_sizes {
+            writer.write_u64::<LittleEndian>(file_size)?;
+        } else {
+            writer.write_u32::<LittleEndian>(narrow_size(file_size, "file size")?)?;
+        }
+
+        Ok(())
+    }
+
+    /// reads the size of the whole lepton file from data, the little endian file size at the end of the file
+    pub fn read_file_size(&self, mut data: &[u8]) -> Result<u64> {
+        Ok(if self.large_sizes {
+            data.read_u64::<LittleEndian>()?
+        } else {
+            u64::from(data.read_u32::<LittleEndian>()?)
+        })
+    }
+
+    /// fails for files that store the original as is, since they don't contain any coefficients to work with
+    pub fn check_not_passthrough(&self) -> Result<()> {
+        if self.passthrough_size.is_some() {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "file stores the original as is without any JPEG information",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// drops everything that isn't needed to recreate the image: all the header segments other than SOF, DQT,
+    /// DHT, DRI and SOS, the segments found inside the scan data and the data after the end of the image
+    pub fn keep_coefficients_only(&mut self) -> Result<()> {
+        let canonical_header = canonical_jpeg_header(&self.raw_jpeg_header).context(here!())?;
+
+        // when there is no data after the image the decoder writes the EOI by itself
+        let garbage_len = cmp::max(self.garbage_data.len(), EOI.len());
+        if !self.early_eof_encountered {
+            self.garbage_data = Vec::from(EOI);
+        }
+
+        let scan_segments_len: usize = self.scan_segments.iter().map(|s| s.data.len()).sum();
+        self.scan_segments.clear();
+
+        // the decoded JPEG is smaller than the original by everything that was dropped
+        self.jpeg_file_size -= (self.raw_jpeg_header.len() - canonical_header.len() + garbage_len
+            - cmp::max(self.garbage_data.len(), EOI.len())
+            + scan_segments_len) as u64;
+
+        self.raw_jpeg_header = canonical_header;
+        self.coefficients_only = true;
+
+        Ok(())
+    }
+
+    /// true if the header segments of further scans follow the first scan, which is the case for
+    /// sequential images where each scan only contains some of the components. Older files stored
+    /// these scans as garbage data, in which case the raw header ends with the first scan.
+    pub fn has_additional_scans(&self) -> bool {
+        self.raw_jpeg_header_read_index < self.raw_jpeg_header.len()
+    }
+
+    /// checks the size of the recreated JPEG against the one of the original file, if we have it
+    fn verify_original_file_size(&self, size: u64) -> Result<()> {
+        if let Some(expected) = self.original_file_size {
+            if size != expected {
+                return err_exit_code(
+                    ExitCode::VerificationLengthMismatch,
+                    format!(
+                        "recreated JPEG is {0} bytes but the original was {1} bytes",
+                        size, expected
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// checks the CRC of the recreated JPEG against the one of the original file, if we have it
+    fn verify_original_file_crc(&self, crc: u32, size: u64) -> Result<()> {
+        if let Some(expected) = self.original_file_crc {
+            if crc != expected {
+                return err_exit_code(
+                    ExitCode::VerificationContentMismatch,
+                    format!(
+                        "recreated JPEG has CRC {0:08x} ({1} bytes) but the original had {2:08x} ({3} bytes)",
+                        crc,
+                        size,
+                        expected,
+                        self.plain_text_size
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// same as recode_jpeg, but replaces the Huffman tables of the scan with optimal ones computed from
+    /// the decoded coefficients. All other header segments are written out unchanged.
+    fn recode_jpeg_optimize_huffman<R: Read, W: Write>(
+        &mut self,
+        writer: &mut W,
+        reader: &mut R,
+        remaining_size: Option<u64>,
+        num_threads: usize,
+    ) -> Result<Metrics> {
+        self.check_not_passthrough().context(here!())?;
+
+        if self.jpeg_header.jpeg_type != JPegType::Sequential {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "huffman table optimization is only supported for baseline images",
+            );
+        }
+
+        // truncated images and additional scans stored as garbage are coded with the original tables
+        if self.early_eof_encountered
+            || self.has_additional_scans()
+            || self
+                .garbage_data
+                .windows(2)
+                .any(|w| w[0] == 0xFF && w[1] == jpeg_code::SOS)
+        {
+            return err_exit_code(
+                ExitCode::UnsupportedJpeg,
+                "huffman table optimization is not supported for truncated or multi-scan images",
+            );
+        }
+
+        let (merged, metrics) = self
+            .decode_as_single_image(reader, remaining_size, num_threads)
+            .context(here!())?;
+
+        let frequencies =
+            HuffmanFrequencies::collect(&merged[..], &self.jpeg_header).context(here!())?;
+
+        let mut header = frequencies
+            .replace_huffman_tables(
+                &self.raw_jpeg_header[0..self.raw_jpeg_header_read_index],
+                &self.jpeg_header,
+            )
+            .context(here!())?;
+
+        // reparse the new header so that the scan gets encoded with the optimized tables
+        self.jpeg_header = JPegHeader::new();
+        self.jpeg_header
+            .parse(&mut Cursor::new(&header), &EnabledFeatures::all())
+            .context(here!())?;
+
+        let header_len = header.len();
+        header.extend_from_slice(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..]);
+        self.raw_jpeg_header = header;
+        self.raw_jpeg_header_read_index = header_len;
+
+        writer.write_all(&SOI)?;
+        writer
+            .write_all(&self.raw_jpeg_header[0..self.raw_jpeg_header_read_index])
+            .context(here!())?;
+
+        jpeg_write_entire_scan(writer, &merged[..], self).context(here!())?;
+
+        self.write_trailing_rst_errors(writer).context(here!())?;
+
+        writer
+            .write_all(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..])
+            .context(here!())?;
+
+        writer.write_all(&self.garbage_data).context(here!())?;
+        Ok(metrics)
+    }
+
+    /// decodes the entire image and merges the results into a single set of BlockBaseImage per component. The
+    /// segments are handed on in order, so each one is moved into the image of its component as soon as it is
+    /// done, while the workers are still decoding the ones after it, rather than once all of them are there. That
+    /// way the blocks of a segment are only held twice while they are moved, and the time of merging is hidden
+    /// behind the slowest segment. The scans can only be written once all the rows are there, since each of them
+    /// covers the entire image.
+    pub fn decode_as_single_image<R: Read>(
+        &mut self,
+        reader: &mut R,
+        remaining_size: Option<u64>,
+        num_threads: usize,
+    ) -> Result<(Vec<BlockBasedImage>, Metrics)> {
+        self.check_not_passthrough().context(here!())?;
+
+        let mut merged = Vec::new();
+        for i in 0..self.jpeg_header.cmpc {
+            merged.push(BlockBasedImage::new(
+                &self.jpeg_header,
+                i,
+                0,
+                self.jpeg_header.cmp_info[0].bcv,
+            ));
+        }
+
+        let metrics = run_lepton_decoder_threads(
+            self,
+            reader,
+            remaining_size,
+            num_threads,
+            |_thread_handoff, image_data, _lh, _output| {
+                // just return the image data directly to be merged together
+                return Ok(image_data);
+            },
+            |output| {
+                if let DecoderOutput::Result(mut image_data) = output {
+                    for (i, (m, segment)) in merged.iter_mut().zip(&mut image_data).enumerate() {
+                        m.append(segment, i).context(here!())?;
+                    }
+                }
+                Ok(())
+            },
+        )
+        .context(here!())?;
+
+        Ok((merged, metrics))
+    }
+
+    /// reads the index of where each thread segment is, which follows the segments in files that were encoded
+    /// with one. data_start is the position in the reader just after the header.
+    pub fn read_segment_index<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        data_start: u64,
+    ) -> Result<Vec<SegmentIndexEntry>> {
+        let offset = match self.segment_index_offset {
+            Some(offset) => offset,
+            None => {
+                return err_exit_code(
+                    ExitCode::NoSegmentIndex,
+                    "file was encoded without a segment index",
+                )
+            }
+        };
+
+        reader
+            .seek(SeekFrom::Start(data_start + offset))
+            .context(here!())?;
+
+        let segment_index =
+            SegmentIndexEntry::deserialize(self.thread_handoff.len(), self.large_sizes, reader)
+                .context(here!())?;
+
+        self.validate_segment_index(&segment_index)
+            .context(here!())?;
+
+        Ok(segment_index)
+    }
+
+    /// checks that the rows of each entry of the segment index are the ones of its thread segment, which is
+    /// what keeps the segments that are decoded through the index from overlapping or leaving gaps. The end of
+    /// the last one is where the encoder saw the image end, which can be past the end of a truncated image.
+    /// Fails with CorruptHandoff at the first entry that doesn't match.
+    pub fn validate_segment_index(&self, segment_index: &[SegmentIndexEntry]) -> Result<()> {
+        for (i, (entry, th)) in segment_index.iter().zip(&self.thread_handoff).enumerate() {
+            let last = i == self.thread_handoff.len() - 1;
+            if entry.luma_y_start != th.luma_y_start
+                || (!last && entry.luma_y_end != th.luma_y_end)
+                || (last && entry.luma_y_end <= entry.luma_y_start)
+            {
+                return err_exit_code(
+                    ExitCode::CorruptHandoff,
+                    format!(
+                        "segment index has rows {0}..{1} for thread segment {2}, which has rows {3}..{4}",
+                        entry.luma_y_start, entry.luma_y_end, i, th.luma_y_start, th.luma_y_end
+                    )
+                    .as_str(),
+                )
+                .map_err(|e| add_segment_position(e, i, entry.luma_y_start..entry.luma_y_end));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// decodes the coefficients of a single thread segment, reading only that segment's data from the position
+    /// given by the segment index. The images for each component only contain the rows of the segment.
+    pub fn decode_segment<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        data_start: u64,
+        segment_index: &[SegmentIndexEntry],
+        segment: usize,
+    ) -> Result<(Vec<BlockBasedImage>, Metrics)> {
+        if segment >= segment_index.len() || segment >= self.thread_handoff.len() {
+            return err_exit_code(
+                ExitCode::SyntaxError,
+                format!("file only has {0} segments", segment_index.len()).as_str(),
+            );
+        }
+
+        let entry = &segment_index[segment];
+
+        reader
+            .seek(SeekFrom::Start(data_start + entry.offset))
+            .context(here!())?;
+
+        let mut chunks = Vec::new();
+        reader
+            .take(entry.length)
+            .read_to_end(&mut chunks)
+            .context(here!())?;
+        if chunks.len() as u64 != entry.length {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                "segment goes past the end of the file",
+            );
+        }
+
+        // the checksums follow the index, so the one for this segment can be found without going through the rest
+        let mut expected_checksum = None;
+        if let (true, Some(index_offset)) = (self.segment_checksums, self.segment_index_offset) {
+            reader
+                .seek(SeekFrom::Start(
+                    data_start
+                        + index_offset
+                        + segment_index.len() as u64
+                            * SegmentIndexEntry::serialized_size(self.large_sizes)
+                        + 4 * segment as u64,
+                ))
+                .context(here!())?;
+
+            expected_checksum = Some(reader.read_u32::<LittleEndian>().context(here!())?);
+        }
+
+        self.decode_segment_chunks(&chunks, segment, expected_checksum)
+    }
+
+    /// decodes the coefficients of a single thread segment from its chunks (including the thread id and length in
+    /// front of each one), checking the data against expected_checksum if there is one
+    pub fn decode_segment_chunks(
+        &self,
+        chunks: &[u8],
+        segment: usize,
+        expected_checksum: Option<u32>,
+    ) -> Result<(Vec<BlockBasedImage>, Metrics)> {
+        if segment >= self.thread_handoff.len() {
+            return err_exit_code(
+                ExitCode::SyntaxError,
+                format!("file only has {0} segments", self.thread_handoff.len()).as_str(),
+            );
+        }
+
+        // strip the thread id and length in front of each chunk, leaving the data where it is
+        let mut rest = chunks;
+        let mut data = Vec::new();
+        while !rest.is_empty() {
+            let ChunkHeader {
+                thread_id,
+                length: data_length,
+            } = ChunkHeader::read(&mut rest).context(here!())?;
+            if usize::from(thread_id) != segment {
+                return err_exit_code(
+                    ExitCode::BadLeptonFile,
+                    format!(
+                        "found data for thread {0} in segment {1}",
+                        thread_id, segment
+                    )
+                    .as_str(),
+                );
+            }
+
+            if data_length > rest.len() {
+                return err_exit_code(
+                    ExitCode::BadLeptonFile,
+                    format!(
+                        "chunk of {0} bytes goes past the end of segment {1}",
+                        data_length, segment
+                    )
+                    .as_str(),
+                );
+            }
+
+            let (chunk, r) = rest.split_at(data_length);
+            data.push(chunk);
+            rest = r;
+        }
+
+        if let Some(expected) = expected_checksum {
+            let mut crc = Crc::new();
+            for chunk in &data {
+                crc.update(chunk);
+            }
+            if crc.sum() != expected {
+                return err_exit_code(
+                    ExitCode::CorruptSegment,
+                    format!(
+                        "CorruptSegment {{ index: {0}, expected: {1:08x}, actual: {2:08x} }}",
+                        segment,
+                        expected,
+                        crc.sum()
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        let pts = ProbabilityTablesSet::new(self.quantized_dc_prediction, self.fast_dc_prediction);
+        let qt = get_quantization_tables(&self.jpeg_header).context(here!())?;
+
+        let thread_handoff = &self.thread_handoff[segment];
+        let is_last_segment = segment == self.thread_handoff.len() - 1;
+
+        let mut image_data = Vec::new();
+        for i in 0..self.jpeg_header.cmpc {
+            image_data.push(BlockBasedImage::new(
+                &self.jpeg_header,
+                i,
+                thread_handoff.luma_y_start,
+                if is_last_segment {
+                    // the last segment extends all the way to the bottom
+                    self.jpeg_header.cmp_info[0].bcv
+                } else {
+                    thread_handoff.luma_y_end
+                },
+            ));
+        }
+
+        let metrics = lepton_decode_row_range(
+            &pts,
+            &qt,
+            &self.truncate_components,
+            &mut image_data,
+            &mut ChainedReader::new(&data),
+            thread_handoff.luma_y_start,
+            thread_handoff.luma_y_end,
+            is_last_segment,
+            true,
+            self.initial_model()?.as_mut(),
+            self.initial_cr_model()?.as_deref_mut(),
+            self.coefficient_codec,
+            self.strict_segment_end,
+        )
+        .context(here!())?;
+
+        Ok((image_data, metrics))
+    }
+
+    /// parses and advances to the next header segment out of raw_jpeg_header into the jpeg header
+    pub fn advance_next_header_segment(
+        &mut self,
+        enabled_features: &EnabledFeatures,
+    ) -> Result<bool> {
+        let mut header_cursor =
+            Cursor::new(&self.raw_jpeg_header[self.raw_jpeg_header_read_index..]);
+
+        let result = self
+            .jpeg_header
+            .parse(&mut header_cursor, enabled_features)
+            .context(here!())?;
+
+        self.raw_jpeg_header_read_index += header_cursor.stream_position()? as usize;
+
+        Ok(result)
+    }
+
+    /// decoder for progressive and other multi-scan images, requires that the entire lepton file is processed first
+    fn recode_progressive_jpeg<R: Read, W: Write>(
+        &mut self,
+        reader: &mut R,
+        remaining_size: Option<u64>,
+        writer: &mut W,
+        num_threads: usize,
+    ) -> Result<Metrics> {
+        // run the threads first, since we need everything before we can start decoding
+        let (merged, mut metrics) = self
+            .decode_as_single_image(reader, remaining_size, num_threads)
+            .context(here!())?;
+
+        self.write_progressive_scans(&merged, writer, num_threads, &mut metrics)
+            .context(here!())?;
+
+        Ok(metrics)
+    }
+
+    /// writes the scans of a progressive or other multi-scan image from the coefficients of the entire image,
+    /// along with the parts of the raw header that follow each one. The scans are encoded on up to num_threads
+    /// threads, whose CPU time is added to metrics.
+    pub fn write_progressive_scans<W: Write>(
+        &mut self,
+        merged: &[BlockBasedImage],
+        writer: &mut W,
+        num_threads: usize,
+        metrics: &mut Metrics,
+    ) -> Result<()> {
+        // parse all the headers (DHT, etc) up front, remembering the state each scan needs to be encoded
+        // along with the range of the raw header that has to be written out after it
+        let mut scans = Vec::new();
+        loop {
+            let scan_header = self.clone_for_scan();
+
+            let old_pos = self.raw_jpeg_header_read_index;
+            let result = self
+                .advance_next_header_segment(&EnabledFeatures::all())
+                .context(here!())?;
+
+            scans.push((scan_header, old_pos..self.raw_jpeg_header_read_index));
+
+            if !result {
+                break;
+            }
+
+            // advance to next scan
+            self.scnc += 1;
+        }
+
+        let num_threads = resolve_max_threads(num_threads);
+
+        // with a single thread there is nothing to gain from handing the scans to the thread pool
+        if num_threads <= 1 {
+            for (scan_header, raw_range) in &scans {
+                jpeg_write_entire_scan(writer, merged, scan_header).context(here!())?;
+                writer
+                    .write_all(&self.raw_jpeg_header[raw_range.clone()])
+                    .context(here!())?;
+            }
+
+            return Ok(());
+        }
+
+        // since the scans only read the coefficients, they can be encoded in parallel and then written out in order
+        let raw_jpeg_header = &self.raw_jpeg_header;
+        let next_scan = AtomicUsize::new(0);
+
+        thread_pool::scope(num_threads, |s| -> Result<()> {
+            let (tx, rx) = channel();
+
+            let mut running_threads = Vec::new();
+            for _t in 0..cmp::min(cmp::max(num_threads, 1), scans.len()) {
+                let tx = tx.clone();
+                let scans = &scans;
+                let next_scan = &next_scan;
+
+                running_threads.push(s.spawn(move || {
+                    let cpu_time = ThreadTime::now();
+
+                    loop {
+                        let scan = next_scan.fetch_add(1, Ordering::Relaxed);
+                        if scan >= scans.len() {
+                            break;
+                        }
+
+                        let mut scan_buffer = Vec::new();
+                        let r = jpeg_write_entire_scan(&mut scan_buffer, merged, &scans[scan].0)
+                            .map(|_| scan_buffer);
+
+                        let failed = r.is_err();
+
+                        // ignore the result of send, the receiver only goes away if another scan failed
+                        let _ = tx.send((scan, r));
+
+                        if failed {
+                            break;
+                        }
+                    }
+
+                    cpu_time.elapsed()
+                }));
+            }
+
+            drop(tx);
+
+            // scans can finish out of order, so hold on to them until all the previous ones have been written
+            let mut completed: Vec<Option<Vec<u8>>> = scans.iter().map(|_| None).collect();
+            let mut next_to_write = 0;
+
+            for (scan, r) in rx {
+                completed[scan] = Some(r.context(here!())?);
+
+                while next_to_write < scans.len() {
+                    match completed[next_to_write].take() {
+                        Some(scan_buffer) => {
+                            writer.write_all(&scan_buffer[..]).context(here!())?;
+                            writer
+                                .write_all(&raw_jpeg_header[scans[next_to_write].1.clone()])
+                                .context(here!())?;
+                            next_to_write += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+
+            for t in running_threads {
+                metrics.record_cpu_worker_time(t.join().unwrap());
+            }
+
+            Ok(())
+        })
+        .context(here!())?;
+
+        Ok(())
+    }
+
+    /// copies the state that jpeg_write needs to encode the current scan, leaving out the raw header and garbage data
+    fn clone_for_scan(&self) -> LeptonHeader {
+        LeptonHeader {
+            max_dpos: self.max_dpos,
+            jpeg_header: self.jpeg_header.clone(),
+            truncate_components: self.truncate_components.clone(),
+            rst_cnt: self.rst_cnt.clone(),
+            pad_bit: self.pad_bit,
+            pad_bit_exceptions: self.pad_bit_exceptions.clone(),
+            restart_exceptions: self.restart_exceptions.clone(),
+            scan_segments: self.scan_segments.clone(),
+            rst_cnt_set: self.rst_cnt_set,
+            scnc: self.scnc,
+            early_eof_encountered: self.early_eof_encountered,
+            max_cmp: self.max_cmp,
+            max_bpos: self.max_bpos,
+            max_sah: self.max_sah,
+            ..LeptonHeader::new()
+        }
+    }
+
+    // baseline decoder can run the jpeg encoder inside the worker thread vs progressive encoding which needs to get the entire set of coefficients first
+    // since it runs throught it multiple times.
+    fn recode_baseline_jpeg<R: Read, W: Write>(
+        &mut self,
+        reader: &mut R,
+        remaining_size: Option<u64>,
+        writer: &mut W,
+        num_threads: usize,
+    ) -> Result<Metrics> {
+        // step 2: recode image data, writing out each thread's segment as soon as it (and all the ones before it) are done
+        let metrics = run_lepton_decoder_threads(
+            self,
+            reader,
+            remaining_size,
+            num_threads,
+            |thread_handoff, image_data, lh, output| {
+                write_segment_jpeg(thread_handoff, &image_data, lh, output)
+            },
+            |output| {
+                if let DecoderOutput::Data(piece) = output {
+                    writer.write_all(&piece[..]).context(here!())?;
+                }
+                Ok(())
+            },
+        )?;
+
+        if !self.early_eof_encountered {
+            self.write_trailing_rst_errors(writer).context(here!())?;
+        }
+
+        Ok(metrics)
+    }
+
+    /// Injection of restart codes for RST errors supports JPEGs with trailing RSTs
+    pub fn write_trailing_rst_errors<W: Write>(&self, writer: &mut W) -> Result<()> {
+        if self.rst_err.len() > 0 {
+            let cumulative_reset_markers = if self.jpeg_header.rsti != 0 {
+                ((self.jpeg_header.mcuh * self.jpeg_header.mcuv) - 1) / self.jpeg_header.rsti
+            } else {
+                0
+            } as u8;
+            for i in 0..self.rst_err[0] as u8 {
+                let rst = (jpeg_code::RST0 + ((cumulative_reset_markers + i) & 7)) as u8;
+                writer.write_u8(0xFF)?;
+                writer.write_u8(rst)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// returns the fill bits to use when padding the end of the given restart interval in the current scan
+    pub fn get_pad_bits(&self, interval: i32) -> u8 {
+        for e in &self.pad_bit_exceptions {
+            if e.scan as usize == self.scnc && e.interval as i32 == interval {
+                return e.fill_bits;
+            }
+        }
+
+        self.pad_bit.unwrap_or(0)
+    }
+
+    /// remembers the fill bits read at the end of the given restart interval if they didn't match the pad bit
+    pub fn record_pad_bits(&mut self, interval: i32, fill_bits: Option<u8>) {
+        if let Some(fill_bits) = fill_bits {
+            self.pad_bit_exceptions.push(PadBitException {
+                scan: self.scnc as u32,
+                interval: interval as u32,
+                fill_bits,
+            });
+        }
+    }
+
+    /// returns the RST markers that followed the given restart interval in the current scan if they
+    /// weren't the expected single marker
+    pub fn get_restart_exception(&self, interval: i32) -> Option<&[u8]> {
+        for e in &self.restart_exceptions {
+            if e.scan as usize == self.scnc && e.interval as i32 == interval {
+                return Some(&e.markers[..]);
+            }
+        }
+
+        None
+    }
+
+    /// remembers the RST markers read after the given restart interval if they weren't the expected single marker
+    pub fn record_restart_markers(&mut self, interval: i32, markers: Vec<u8>) {
+        if markers.len() != 1 || markers[0] != jpeg_code::RST0 + (interval & 7) as u8 {
+            self.restart_exceptions.push(RestartException {
+                scan: self.scnc as u32,
+                interval: interval as u32,
+                markers,
+            });
+        }
+    }
+
+    /// returns the segments that were found after the given restart interval in the current scan, following
+    /// the given number of RST markers
+    pub fn get_scan_segments(
+        &self,
+        interval: i32,
+        position: usize,
+    ) -> impl Iterator<Item = &[u8]> + '_ {
+        self.scan_segments
+            .iter()
+            .filter(move |e| {
+                e.scan as usize == self.scnc
+                    && e.interval as i32 == interval
+                    && e.position as usize == position
+            })
+            .map(|e| &e.data[..])
+    }
+
+    /// remembers the segments read between the RST markers after the given restart interval
+    pub fn record_scan_segments(&mut self, interval: i32, segments: IntervalSegments) {
+        for (position, data) in segments {
+            self.scan_segments.push(ScanSegment {
+                scan: self.scnc as u32,
+                interval: interval as u32,
+                position,
+                data,
+            });
+        }
+    }
+
+    /// returns the optional features the decoder needs to support to decode this file. Only the ones that
+    /// are actually used are set, so that older decoders can still read files that don't need them.
+    pub fn get_required_features(&self) -> u32 {
+        let mut features = 0;
+
+        if !self.restart_exceptions.is_empty() {
+            features |= LEPTON_FEATURE_RESTART_EXCEPTIONS;
+        }
+
+        if !self.scan_segments.is_empty() {
+            features |= LEPTON_FEATURE_SCAN_SEGMENTS;
+        }
+
+        if self.jpeg_header.jpeg_type == JPegType::Sequential && self.scnc > 1 {
+            features |= LEPTON_FEATURE_MULTI_SCAN_SEQUENTIAL;
+        }
+
+        if self.original_file_crc.is_some() {
+            features |= LEPTON_FEATURE_CHECKSUM;
+        }
+
+        if self.original_file_size.is_some() {
+            features |= LEPTON_FEATURE_ORIGINAL_SIZE;
+        }
+
+        if self.segment_index_offset.is_some() {
+            features |= LEPTON_FEATURE_SEGMENT_INDEX;
+        }
+
+        if self.passthrough_size.is_some() {
+            features |= LEPTON_FEATURE_PASSTHROUGH;
+        }
+
+        if self.large_sizes {
+            features |= LEPTON_FEATURE_LARGE_SIZES;
+        }
+
+        if self.segment_checksums {
+            features |= LEPTON_FEATURE_SEGMENT_CHECKSUMS;
+        }
+
+        if self.coefficients_only {
+            features |= LEPTON_FEATURE_COEFFICIENTS_ONLY;
+        }
+
+        if self.model_primer_id.is_some() {
+            features |= LEPTON_FEATURE_MODEL_PRIMER;
+        }
+
+        if self.model_tuning != ModelTuning::default() {
+            features |= LEPTON_FEATURE_MODEL_TUNING;
+        }
+
+        if self.separate_chroma_models {
+            features |= LEPTON_FEATURE_SEPARATE_CHROMA_MODELS;
+        }
+
+        if self.quantized_dc_prediction {
+            features |= LEPTON_FEATURE_QUANTIZED_DC_PREDICTION;
+        }
+
+        if self.fast_dc_prediction {
+            features |= LEPTON_FEATURE_FAST_DC_PREDICTION;
+        }
+
+        if self.trained_initial_probs {
+            features |= LEPTON_FEATURE_TRAINED_INITIAL_PROBS;
+        }
+
+        if self.coefficient_codec != CoefficientCodecKind::Model {
+            features |= LEPTON_FEATURE_EXPERIMENTAL_CODEC;
+        }
+
+        features
+    }
+
+    /// fails with MissingDictionary if the file was encoded with a primer and the decoder wasn't given the same one
+    pub fn check_model_primer(&self) -> Result<()> {
+        let Some(id) = self.model_primer_id else {
+            return Ok(());
+        };
+
+        match &self.model_primer {
+            Some(primer) if primer.id() == id => Ok(()),
+            Some(primer) => err_exit_code(
+                ExitCode::MissingDictionary,
+                format!(
+                    "file was encoded with primer {0:08x} but primer {1:08x} was given",
+                    id,
+                    primer.id()
+                )
+                .as_str(),
+            ),
+            None => err_exit_code(
+                ExitCode::MissingDictionary,
+                format!("file was encoded with primer {0:08x}", id).as_str(),
+            ),
+        }
+    }
+
+    /// the model that decoding each thread segment starts from, which comes from the primer if the file was
+    /// encoded with one
+    pub fn initial_model(&self) -> Result<Box<Model>> {
+        self.check_model_primer()?;
+
+        #[allow(unused_mut)]
+        let mut model = Model::new_for_segment(
+            if self.model_primer_id.is_some() {
+                self.model_primer.as_deref()
+            } else {
+                None
+            },
+            &self.model_tuning,
+            ModelInit::for_version(self.file_version),
+            self.model_snapshot_hook.clone(),
+            self.low_latency,
+        );
+
+        #[cfg(feature = "debug-symmetry")]
+        model.set_symmetry_recorder(self.symmetry_recorder.clone());
+
+        Ok(model)
+    }
+
+    /// the model that decoding the Cr component of each thread segment starts from if the file has separate
+    /// chroma models. It starts out the same as initial_model.
+    pub fn initial_cr_model(&self) -> Result<Option<Box<Model>>> {
+        if self.separate_chroma_models {
+            Ok(Some(self.initial_model()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// reads the start of the lepton file and parses the compressed header. Returns the raw JPEG header contents.
+    pub fn read_lepton_header<R: Read>(&mut self, reader: &mut R) -> Result<()> {
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(LEPTON_FILE_HEADER.len() as u64)
+            .read_to_end(&mut header)
+            .context(here!())?;
+
+        if header.is_empty() {
+            return err_exit_code(ExitCode::NotLepton, "input is empty");
+        }
+        if header[..] != LEPTON_FILE_HEADER[..] {
+            return err_exit_code(ExitCode::NotLepton, "header doesn't match");
+        }
+
+        // Files written by the C++ implementation use the same version and layout, except that the 12 bytes we use
+        // for the uncompressed header size contain its git revision, so they decode the same way as our own.
+        let version = reader.read_u8().context(here!())?;
+        if version != LEPTON_VERSION {
+            return err_exit_code(
+                ExitCode::VersionUnsupported,
+                format!("incompatible file with version {0}", version).as_str(),
+            );
+        }
+
+        let mut header = [0 as u8; 21];
+        reader.read_exact(&mut header).context(here!())?;
+
+        // Z = baseline non-progressive
+        // Y = chunked encoding of a slice of a JPEG (not supported yet)
+        // X = progressive
+        if header[0] == LEPTON_HEADER_CHUNKED_JPEG_TYPE[0] {
+            return err_exit_code(
+                ExitCode::VersionUnsupported,
+                "file contains a slice of a JPEG, which is not supported",
+            );
+        }
+
+        if header[0] != LEPTON_HEADER_BASELINE_JPEG_TYPE[0]
+            && header[0] != LEPTON_HEADER_PROGRESSIVE_JPEG_TYPE[0]
+        {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                format!("Unknown filetype in header {0}", header[0]).as_str(),
+            );
+        }
+
+        let mut c = Cursor::new(header);
+
+        // We use 12 bytes of git revision for our needs - mark that it's C# implementation and a not-compressed header size.
+        self.uncompressed_lepton_header_size = 0;
+        if header[5] == 'M' as u8 && header[6] == 'S' as u8 {
+            c.set_position(7);
+            self.uncompressed_lepton_header_size = c.read_u32::<LittleEndian>()?;
+
+            // refuse files that need features we don't know about before trying to decode them (older
+            // versions of our encoder always wrote zeros here)
+            let required_features = c.read_u32::<LittleEndian>()?;
+            self.large_sizes = required_features & LEPTON_FEATURE_LARGE_SIZES != 0;
+            self.segment_checksums = required_features & LEPTON_FEATURE_SEGMENT_CHECKSUMS != 0;
+            self.coefficients_only = required_features & LEPTON_FEATURE_COEFFICIENTS_ONLY != 0;
+            self.separate_chroma_models =
+                required_features & LEPTON_FEATURE_SEPARATE_CHROMA_MODELS != 0;
+            self.quantized_dc_prediction =
+                required_features & LEPTON_FEATURE_QUANTIZED_DC_PREDICTION != 0;
+            self.fast_dc_prediction = required_features & LEPTON_FEATURE_FAST_DC_PREDICTION != 0;
+            self.trained_initial_probs =
+                required_features & LEPTON_FEATURE_TRAINED_INITIAL_PROBS != 0;
+            self.file_version = LeptonVersion::of_features(required_features);
+            self.coefficient_codec = if required_features & LEPTON_FEATURE_EXPERIMENTAL_CODEC != 0 {
+                CoefficientCodecKind::PositionalSign
+            } else {
+                CoefficientCodecKind::Model
+            };
+
+            let supported_features = self.format_version.supported_features();
+            if required_features & !supported_features != 0 {
+                return err_exit_code(
+                    ExitCode::VersionUnsupported,
+                    format!(
+                        "file version {0} requires features {1:x} but only {2:x} are supported",
+                        version, required_features, supported_features
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        // full size of the original file, which is replaced by the one in the LSZ marker for files with large sizes
+        c.set_position(17);
+        self.plain_text_size = u64::from(c.read_u32::<LittleEndian>()?);
+
+        // now read the compressed header
+        let compressed_header_size = reader.read_u32::<LittleEndian>()? as usize;
+
+        if compressed_header_size > MAX_FILE_SIZE_BYTES as usize {
+            return err_exit_code(ExitCode::BadLeptonFile, "Too big compressed header");
+        }
+
+        // limit reading to the compressed header
+        let mut compressed_reader = BufReader::new(reader.take(compressed_header_size as u64));
+
+        self.raw_jpeg_header = self
+            .read_lepton_compressed_header(&mut compressed_reader)
+            .context(here!())?;
+
+        // the encoder info follows the compressed data, where decoders that don't know about it never look
+        let mut trailer = Vec::new();
+        compressed_reader
+            .read_to_end(&mut trailer)
+            .context(here!())?;
+        self.encoder_info = EncoderInfo::read(&trailer[..]);
+
+        // the file type follows the encoder info if there is one
+        let file_type_start = if self.encoder_info.is_some() {
+            ENCODER_INFO_SIZE
+        } else {
+            0
+        };
+        self.file_type = trailer
+            .get(file_type_start..)
+            .and_then(LeptonFileType::read);
+
+        // CMP marker
+        let mut current_lepton_marker = [0 as u8; 3];
+        reader.read_exact(&mut current_lepton_marker)?;
+        if !buffer_prefix_matches_marker(current_lepton_marker, LEPTON_HEADER_COMPLETION_MARKER) {
+            return err_exit_code(ExitCode::BadLeptonFile, "CMP marker not found");
+        }
+
+        self.raw_jpeg_header_read_index = 0;
+
+        // the original file follows as is, so there is no JPEG header to parse
+        if self.passthrough_size.is_some() {
+            return Ok(());
+        }
+
+        {
+            let mut header_data_cursor = Cursor::new(&self.raw_jpeg_header[..]);
+            self.jpeg_header
+                .parse(&mut header_data_cursor, &EnabledFeatures::all())
+                .context(here!())?;
+            self.raw_jpeg_header_read_index = header_data_cursor.position() as usize;
+        }
+
+        self.truncate_components.init(&self.jpeg_header);
+
+        if self.file_type.is_none() {
+            self.file_type = Some(LeptonFileType {
+                progressive: self.jpeg_header.jpeg_type == JPegType::Progressive,
+                scan_count: count_scans(&self.raw_jpeg_header),
+            });
+        }
+
+        if self.early_eof_encountered {
+            self.truncate_components
+                .set_truncation_bounds(&self.jpeg_header, self.max_dpos);
+        }
+
+        // our encoder always writes at least one segment, so a file without any is damaged
+        if self.thread_handoff.is_empty() {
+            return err_exit_code(ExitCode::BadLeptonFile, "file has no thread segments");
+        }
+
+        let num_threads = self.thread_handoff.len();
+
+        // luma_y_end of the last thread is not serialized/deserialized, fill it here
+        self.thread_handoff[num_threads - 1].luma_y_end =
+            self.truncate_components.get_block_height(0);
+
+        ThreadHandoff::validate(
+            &self.thread_handoff,
+            self.truncate_components.get_block_height(0),
+            self.jpeg_header.cmp_info[0].bcv / self.truncate_components.mcu_count_vertical,
+        )
+        .context(here!())?;
+
+        // if the last segment was too big to fit with the garbage data taken into account, shorten it
+        // (a bit of broken logic in the encoder, but can't change it without breaking the file format)
+        if self.early_eof_encountered {
+            let mut max_last_segment_size = i64::try_from(self.plain_text_size)?
+                - i64::try_from(self.garbage_data.len())?
+                - i64::try_from(self.raw_jpeg_header_read_index)?
+                - 2;
+
+            // subtract the segment sizes of all the previous segments (except for the last)
+            for i in 0..num_threads - 1 {
+                max_last_segment_size -= self.thread_handoff[i].segment_size;
+            }
+
+            let last = &mut self.thread_handoff[num_threads - 1];
+
+            if last.segment_size > max_last_segment_size {
+                // re-adjust the last segment size
+                last.segment_size = max_last_segment_size;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// helper for read_lepton_header. uncompresses and parses the contents of the compressed header. Returns the raw JPEG header.
+    fn read_lepton_compressed_header<R: BufRead>(&mut self, src: &mut R) -> Result<Vec<u8>> {
+        let mut header_reader = ZlibDecoder::new(src);
+
+        let mut hdr_buf: [u8; 3] = [0; 3];
+        header_reader.read_exact(&mut hdr_buf)?;
+
+        if !buffer_prefix_matches_marker(hdr_buf, LEPTON_HEADER_MARKER) {
+            return err_exit_code(ExitCode::BadLeptonFile, "HDR marker not found");
+        }
+
+        let hdrs = header_reader.read_u32::<LittleEndian>()? as usize;
+
+        let mut hdr_data = Vec::new();
+        hdr_data.resize(hdrs, 0);
+        header_reader.read_exact(&mut hdr_data)?;
+
+        if self.garbage_data.len() == 0 {
+            // if we don't have any garbage, assume FFD9 EOI
+
+            // kind of broken logic since this assumes a EOF even if there was a 0 byte garbage header
+            // in the file, but this is what the file format is.
+            self.garbage_data.extend(EOI);
+        }
+
+        // beginning here: recovery information (needed for exact JPEG recovery)
+        // read further recovery information if any
+        loop {
+            let mut current_lepton_marker = [0 as u8; 3];
+            match header_reader.read_exact(&mut current_lepton_marker) {
+                Ok(_) => {}
+                Err(e) => {
+                    if e.kind() == ErrorKind::UnexpectedEof {
+                        break;
+                    } else {
+                        return Err(anyhow::Error::new(e));
+                    }
+                }
+            }
+
+            if buffer_prefix_matches_marker(current_lepton_marker, LEPTON_HEADER_PAD_MARKER) {
+                self.pad_bit = Some(header_reader.read_u8()?);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_PAD_EXCEPTIONS_MARKER,
+            ) {
+                // PDX marker
+                let count = header_reader.read_u32::<LittleEndian>()?;
+
+                for _i in 0..count {
+                    self.pad_bit_exceptions.push(PadBitException {
+                        scan: header_reader.read_u32::<LittleEndian>()?,
+                        interval: header_reader.read_u32::<LittleEndian>()?,
+                        fill_bits: header_reader.read_u8()?,
+                    });
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_RESTART_EXCEPTIONS_MARKER,
+            ) {
+                // RSX marker
+                let count = header_reader.read_u32::<LittleEndian>()?;
+
+                for _i in 0..count {
+                    let scan = header_reader.read_u32::<LittleEndian>()?;
+                    let interval = header_reader.read_u32::<LittleEndian>()?;
+
+                    let mut markers = vec![0; usize::from(header_reader.read_u8()?)];
+                    header_reader.read_exact(&mut markers)?;
+
+                    self.restart_exceptions.push(RestartException {
+                        scan,
+                        interval,
+                        markers,
+                    });
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_SCAN_SEGMENTS_MARKER,
+            ) {
+                // SSG marker
+                let count = header_reader.read_u32::<LittleEndian>()?;
+
+                for _i in 0..count {
+                    let scan = header_reader.read_u32::<LittleEndian>()?;
+                    let interval = header_reader.read_u32::<LittleEndian>()?;
+                    let position = header_reader.read_u32::<LittleEndian>()?;
+
+                    // the length comes from the file, so read what is there instead of allocating what it claims
+                    let length = u64::from(header_reader.read_u32::<LittleEndian>()?);
+
+                    let mut data = Vec::new();
+                    let read = (&mut header_reader).take(length).read_to_end(&mut data)?;
+                    if read as u64 != length {
+                        return err_exit_code(
+                            ExitCode::BadLeptonFile,
+                            "header ends before the data of a scan segment",
+                        );
+                    }
+
+                    self.scan_segments.push(ScanSegment {
+                        scan,
+                        interval,
+                        position,
+                        data,
+                    });
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_CHECKSUM_MARKER,
+            ) {
+                // CRC marker
+                self.original_file_crc = Some(header_reader.read_u32::<LittleEndian>()?);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_ORIGINAL_SIZE_MARKER,
+            ) {
+                // OSZ marker
+                self.original_file_size = Some(header_reader.read_u64::<LittleEndian>()?);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_MODEL_PRIMER_MARKER,
+            ) {
+                // PRM marker
+                self.model_primer_id = Some(header_reader.read_u32::<LittleEndian>()?);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_MODEL_TUNING_MARKER,
+            ) {
+                // TUN marker
+                self.model_tuning = ModelTuning {
+                    adaptation_shift: header_reader.read_u8()?,
+                    max_count: header_reader.read_u8()?,
+                    initial_probability: header_reader.read_u8()?,
+                };
+
+                if !self.model_tuning.is_valid() {
+                    return err_exit_code(ExitCode::BadLeptonFile, "invalid model tuning");
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_SEGMENT_INDEX_MARKER,
+            ) {
+                // SIX marker
+                self.segment_index_offset = Some(if self.large_sizes {
+                    header_reader.read_u64::<LittleEndian>()?
+                } else {
+                    u64::from(header_reader.read_u32::<LittleEndian>()?)
+                });
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_LARGE_SIZES_MARKER,
+            ) {
+                // LSZ marker, which follows the thread handoffs whose sizes it replaces
+                self.plain_text_size = header_reader.read_u64::<LittleEndian>()?;
+
+                let count = usize::from(header_reader.read_u8()?);
+                if count != self.thread_handoff.len() {
+                    return err_exit_code(
+                        ExitCode::BadLeptonFile,
+                        format!(
+                            "{0} segment sizes for {1} thread segments",
+                            count,
+                            self.thread_handoff.len()
+                        )
+                        .as_str(),
+                    );
+                }
+
+                for th in &mut self.thread_handoff {
+                    th.segment_size = i64::try_from(header_reader.read_u64::<LittleEndian>()?)?;
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_PASSTHROUGH_MARKER,
+            ) {
+                // PST marker
+                self.passthrough_size = Some(header_reader.read_u64::<LittleEndian>()?);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_JPG_RESTARTS_MARKER,
+            ) {
+                // CRS marker
+                self.rst_cnt_set = true;
+                let rst_count = header_reader.read_u32::<LittleEndian>()?;
+
+                for _i in 0..rst_count {
+                    self.rst_cnt.push(header_reader.read_i32::<LittleEndian>()?);
+                }
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_LUMA_SPLIT_MARKER,
+            ) {
+                // HH markup
+                let mut thread_handoffs =
+                    ThreadHandoff::deserialize(current_lepton_marker[2], &mut header_reader)?;
+
+                self.thread_handoff.append(&mut thread_handoffs);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_JPG_RESTART_ERRORS_MARKER,
+            ) {
+                // Marker FRS
+                // read number of false set RST markers per scan from file
+                let rst_err_count = header_reader.read_u32::<LittleEndian>()? as usize;
+
+                let mut rst_err_data = Vec::<u8>::new();
+                rst_err_data.resize(rst_err_count, 0);
+
+                header_reader.read_exact(&mut rst_err_data)?;
+
+                self.rst_err.append(&mut rst_err_data);
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_GARBAGE_MARKER,
+            ) {
+                // GRB marker
+                // read garbage (data after end of JPG) from file
+                let garbage_size = if self.large_sizes {
+                    header_reader.read_u64::<LittleEndian>()?
+                } else {
+                    u64::from(header_reader.read_u32::<LittleEndian>()?)
+                };
+
+                // check the claimed size before allocating anything, since the compressed header is tiny
+                // compared to what it can claim
+                if garbage_size > self.max_trailing_bytes {
+                    return err_exit_code(
+                        ExitCode::TrailingDataTooLarge,
+                        format!(
+                            "file claims {0} bytes after the end of the image, but at most {1} are allowed",
+                            garbage_size, self.max_trailing_bytes
+                        )
+                        .as_str(),
+                    );
+                }
+
+                // files from our encoder record the size of the uncompressed header, which the garbage is part of
+                if self.uncompressed_lepton_header_size != 0
+                    && garbage_size
+                        > u64::from(self.uncompressed_lepton_header_size)
+                            .saturating_sub(header_reader.total_out())
+                {
+                    return err_exit_code(
+                        ExitCode::BadLeptonFile,
+                        format!(
+                            "file claims {0} bytes after the end of the image, which is more than the rest of the header",
+                            garbage_size
+                        )
+                        .as_str(),
+                    );
+                }
+
+                let mut garbage_data_array = Vec::<u8>::new();
+                let read = (&mut header_reader)
+                    .take(garbage_size)
+                    .read_to_end(&mut garbage_data_array)?;
+                if read as u64 != garbage_size {
+                    return err_exit_code(
+                        ExitCode::BadLeptonFile,
+                        "header ends before the data after the end of the image",
+                    );
+                }
+                self.garbage_data = garbage_data_array;
+            } else if buffer_prefix_matches_marker(
+                current_lepton_marker,
+                LEPTON_HEADER_EARLY_EOF_MARKER,
+            ) {
+                self.max_cmp = header_reader.read_i32::<LittleEndian>()?;
+                self.max_bpos = header_reader.read_i32::<LittleEndian>()?;
+                self.max_sah = u8::try_from(header_reader.read_i32::<LittleEndian>()?)?;
+                self.max_dpos[0] = header_reader.read_i32::<LittleEndian>()?;
+                self.max_dpos[1] = header_reader.read_i32::<LittleEndian>()?;
+                self.max_dpos[2] = header_reader.read_i32::<LittleEndian>()?;
+                self.max_dpos[3] = header_reader.read_i32::<LittleEndian>()?;
+                self.early_eof_encountered = true;
+            } else {
+                return err_exit_code(ExitCode::BadLeptonFile, "unknown data found");
+            }
+        }
+
+        // shouldn't be any more data
+        let mut remaining_buf = Vec::new();
+        let remaining = header_reader.read_to_end(&mut remaining_buf)?;
+        assert!(remaining == 0);
+
+        return Ok(hdr_data);
+    }
+
+    pub fn write_lepton_header<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let mut lepton_header = Vec::<u8>::new();
+
+        {
+            // Most of the Lepton header data that is compressed before storage
+            // The data contains recovery information (needed for exact JPEG recovery)
+            let mut mrw = Cursor::new(&mut lepton_header);
+
+            self.write_lepton_jpeg_header(&mut mrw)?;
+            self.write_lepton_pad_bit(&mut mrw)?;
+            self.write_lepton_pad_bit_exceptions_if_needed(&mut mrw)?;
+            self.write_lepton_restart_exceptions_if_needed(&mut mrw)?;
+            self.write_lepton_scan_segments_if_needed(&mut mrw)?;
+            self.write_lepton_checksum_if_needed(&mut mrw)?;
+            self.write_lepton_original_size_if_needed(&mut mrw)?;
+            self.write_lepton_segment_index_offset_if_needed(&mut mrw)?;
+            self.write_lepton_model_primer_if_needed(&mut mrw)?;
+            self.write_lepton_model_tuning_if_needed(&mut mrw)?;
+            self.write_lepton_passthrough_if_needed(&mut mrw)?;
+            self.write_lepton_luma_splits(&mut mrw)?;
+            self.write_lepton_large_sizes_if_needed(&mut mrw)?;
+            self.write_lepton_jpeg_restarts_if_needed(&mut mrw)?;
+            self.write_lepton_jpeg_restart_errors_if_needed(&mut mrw)?;
+            self.write_lepton_early_eof_truncation_data_if_needed(&mut mrw)?;
+            self.write_lepton_jpeg_garbage_if_needed(&mut mrw, false)?;
+        }
+
+        // we collect a zlib compressed version of the header here. This includes the raw JPEG header with all the
+        // APPn segments, so large EXIF or XMP metadata is compressed along with everything else.
+        let mut compressed_header = Vec::<u8>::new();
+        {
+            let mut c = Cursor::new(&mut compressed_header);
+            let mut encoder = ZlibEncoder::new(&mut c, Compression::default());
+
+            encoder.write_all(&lepton_header[..]).context(here!())?;
+            encoder.finish().context(here!())?;
+        }
+
+        if let Some(encoder_info) = &self.encoder_info {
+            encoder_info
+                .write(&mut compressed_header)
+                .context(here!())?;
+        }
+
+        if let Some(file_type) = &self.file_type {
+            file_type.write(&mut compressed_header).context(here!())?;
+        }
+
+        writer.write_all(&LEPTON_FILE_HEADER)?;
+        writer.write_u8(LEPTON_VERSION)?;
+
+        if self.jpeg_header.jpeg_type == JPegType::Progressive {
+            writer.write_all(&LEPTON_HEADER_PROGRESSIVE_JPEG_TYPE)?;
+        } else {
+            writer.write_all(&LEPTON_HEADER_BASELINE_JPEG_TYPE)?;
+        }
+
+        writer.write_u8(self.thread_handoff.len() as u8)?;
+        writer.write_all(&[0; 3])?;
+
+        // Original lepton format reserves 12 bytes for git revision. We use this space for additional info
+        // that our implementation needs - mark that it's MS implementation and a not-compressed header size.
+        writer.write_u8('M' as u8)?;
+        writer.write_u8('S' as u8)?;
+        writer.write_u32::<LittleEndian>(narrow_size(
+            lepton_header.len() as u64,
+            "uncompressed header size",
+        )?)?;
+        writer.write_u32::<LittleEndian>(self.get_required_features())?;
+        writer.write_all(&[0; 2])?;
+
+        if self.large_sizes {
+            // the exact size is in the LSZ marker
+            writer.write_u32::<LittleEndian>(u32::MAX)?;
+        } else {
+            writer.write_u32::<LittleEndian>(narrow_size(self.jpeg_file_size, "JPEG size")?)?;
+        }
+        writer.write_u32::<LittleEndian>(narrow_size(
+            compressed_header.len() as u64,
+            "compressed header size",
+        )?)?;
+        writer.write_all(&compressed_header[..])?;
+
+        writer.write_all(&LEPTON_HEADER_COMPLETION_MARKER)?;
+
+        Ok(())
+    }
+
+    fn write_lepton_jpeg_header<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        // write header to file
+        // marker: "HDR" + [size of header]
+        mrw.write_all(&LEPTON_HEADER_MARKER)?;
+
+        mrw.write_u32::<LittleEndian>(narrow_size(
+            self.raw_jpeg_header.len() as u64,
+            "JPEG header size",
+        )?)?;
+
+        // data: data from header
+        mrw.write_all(&self.raw_jpeg_header[..])?;
+
+        Ok(())
+    }
+
+    fn write_lepton_pad_bit<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        // marker: P0D
+        mrw.write_all(&LEPTON_HEADER_PAD_MARKER)?;
+
+        // data: this.padBit
+        mrw.write_u8(self.pad_bit.unwrap_or(0))?;
+
+        Ok(())
+    }
+
+    fn write_lepton_pad_bit_exceptions_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if !self.pad_bit_exceptions.is_empty() {
+            // marker: PDX
+            mrw.write_all(&LEPTON_HEADER_PAD_EXCEPTIONS_MARKER)?;
+
+            mrw.write_u32::<LittleEndian>(self.pad_bit_exceptions.len() as u32)?;
+
+            for e in &self.pad_bit_exceptions {
+                mrw.write_u32::<LittleEndian>(e.scan)?;
+                mrw.write_u32::<LittleEndian>(e.interval)?;
+                mrw.write_u8(e.fill_bits)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_restart_exceptions_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if !self.restart_exceptions.is_empty() {
+            // marker: RSX
+            mrw.write_all(&LEPTON_HEADER_RESTART_EXCEPTIONS_MARKER)?;
+
+            mrw.write_u32::<LittleEndian>(self.restart_exceptions.len() as u32)?;
+
+            for e in &self.restart_exceptions {
+                mrw.write_u32::<LittleEndian>(e.scan)?;
+                mrw.write_u32::<LittleEndian>(e.interval)?;
+                mrw.write_u8(u8::try_from(e.markers.len())?)?;
+                mrw.write_all(&e.markers[..])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_scan_segments_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if !self.scan_segments.is_empty() {
+            // marker: SSG
+            mrw.write_all(&LEPTON_HEADER_SCAN_SEGMENTS_MARKER)?;
+
+            mrw.write_u32::<LittleEndian>(self.scan_segments.len() as u32)?;
+
+            for e in &self.scan_segments {
+                mrw.write_u32::<LittleEndian>(e.scan)?;
+                mrw.write_u32::<LittleEndian>(e.interval)?;
+                mrw.write_u32::<LittleEndian>(e.position)?;
+                mrw.write_u32::<LittleEndian>(narrow_size(
+                    e.data.len() as u64,
+                    "scan segment size",
+                )?)?;
+                mrw.write_all(&e.data[..])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_checksum_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if let Some(crc) = self.original_file_crc {
+            // marker: CRC
+            mrw.write_all(&LEPTON_HEADER_CHECKSUM_MARKER)?;
+            mrw.write_u32::<LittleEndian>(crc)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_original_size_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if let Some(size) = self.original_file_size {
+            // marker: OSZ
+            mrw.write_all(&LEPTON_HEADER_ORIGINAL_SIZE_MARKER)?;
+            mrw.write_u64::<LittleEndian>(size)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_segment_index_offset_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if let Some(offset) = self.segment_index_offset {
+            // marker: SIX
+            mrw.write_all(&LEPTON_HEADER_SEGMENT_INDEX_MARKER)?;
+
+            if self.large_sizes {
+                mrw.write_u64::<LittleEndian>(offset)?;
+            } else {
+                mrw.write_u32::<LittleEndian>(narrow_size(offset, "segment index offset")?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_model_primer_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if let Some(id) = self.model_primer_id {
+            // marker: PRM
+            mrw.write_all(&LEPTON_HEADER_MODEL_PRIMER_MARKER)?;
+            mrw.write_u32::<LittleEndian>(id)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_model_tuning_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if self.model_tuning != ModelTuning::default() {
+            // marker: TUN
+            mrw.write_all(&LEPTON_HEADER_MODEL_TUNING_MARKER)?;
+            mrw.write_u8(self.model_tuning.adaptation_shift)?;
+            mrw.write_u8(self.model_tuning.max_count)?;
+            mrw.write_u8(self.model_tuning.initial_probability)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_large_sizes_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if self.large_sizes {
+            // marker: LSZ
+            mrw.write_all(&LEPTON_HEADER_LARGE_SIZES_MARKER)?;
+            mrw.write_u64::<LittleEndian>(self.jpeg_file_size)?;
+
+            mrw.write_u8(narrow_size(
+                self.thread_handoff.len() as u64,
+                "segment count",
+            )?)?;
+            for th in &self.thread_handoff {
+                mrw.write_u64::<LittleEndian>(u64::try_from(th.segment_size)?)?;
+            }
+        } else if self.needs_large_sizes() {
+            return err_exit_code(
+                ExitCode::SizeOverflow,
+                format!(
+                    "JPEG of {0} bytes needs a file with large sizes",
+                    self.jpeg_file_size
+                )
+                .as_str(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_passthrough_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if let Some(size) = self.passthrough_size {
+            // marker: PST
+            mrw.write_all(&LEPTON_HEADER_PASSTHROUGH_MARKER)?;
+            mrw.write_u64::<LittleEndian>(size)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_luma_splits<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        // write luma splits markup HH
+        mrw.write_all(&LEPTON_HEADER_LUMA_SPLIT_MARKER)?;
+
+        // data: serialized luma splits
+        ThreadHandoff::serialize(&self.thread_handoff, mrw)?;
+
+        Ok(())
+    }
+
+    fn write_lepton_jpeg_restarts_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        if self.rst_cnt.len() > 0 {
+            // marker: CRS
+            mrw.write_all(&LEPTON_HEADER_JPG_RESTARTS_MARKER)?;
+
+            mrw.write_u32::<LittleEndian>(self.rst_cnt.len() as u32)?;
+
+            for i in 0..self.rst_cnt.len() {
+                mrw.write_u32::<LittleEndian>(self.rst_cnt[i] as u32)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_jpeg_restart_errors_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
+        // write number of false set RST markers per scan (if available) to file
+        if self.rst_err.len() > 0 {
+            // marker: "FRS" + [number of scans]
+            mrw.write_all(&LEPTON_HEADER_JPG_RESTART_ERRORS_MARKER)?;
+
+            mrw.write_u32::<LittleEndian>(self.rst_err.len() as u32)?;
+
+            mrw.write_all(&self.rst_err[..])?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_early_eof_truncation_data_if_needed<W: Write>(
+        &self,
+        mrw: &mut W,
+    ) -> Result<()> {
+        if self.early_eof_encountered {
+            // EEE marker
+            mrw.write_all(&LEPTON_HEADER_EARLY_EOF_MARKER)?;
+
+            mrw.write_i32::<LittleEndian>(self.max_cmp)?;
+            mrw.write_i32::<LittleEndian>(self.max_bpos)?;
+            mrw.write_i32::<LittleEndian>(i32::from(self.max_sah))?;
+            mrw.write_i32::<LittleEndian>(self.max_dpos[0])?;
+            mrw.write_i32::<LittleEndian>(self.max_dpos[1])?;
+            mrw.write_i32::<LittleEndian>(self.max_dpos[2])?;
+            mrw.write_i32::<LittleEndian>(self.max_dpos[3])?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lepton_jpeg_garbage_if_needed<W: Write>(
+        &self,
+        mrw: &mut W,
+        prefix_garbage: bool,
+    ) -> Result<()> {
+        // write garbage (if any) to file
+        if self.garbage_data.len() > 0 {
+            // marker: "PGR/GRB" + [size of garbage]
+            if prefix_garbage {
+                mrw.write_all(&LEPTON_HEADER_PREFIX_GARBAGE_MARKER)?;
+            } else {
+                mrw.write_all(&LEPTON_HEADER_GARBAGE_MARKER)?;
+            }
+
+            if self.large_sizes {
+                mrw.write_u64::<LittleEndian>(self.garbage_data.len() as u64)?;
+            } else {
+                mrw.write_u32::<LittleEndian>(narrow_size(
+                    self.garbage_data.len() as u64,
+                    "garbage size",
+                )?)?;
+            }
+            mrw.write_all(&self.garbage_data[..])?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_jpeg_header<R: Read>(
+        &mut self,
+        reader: &mut R,
+        enabled_features: &EnabledFeatures,
+        start_offset: usize,
+    ) -> Result<bool> {
+        // the raw header in the lepton file can actually be spread across different sections
+        // seperated by the Start-of-Scan marker. We use the mirror to write out whatever
+        // data we parse until we hit the SOS
+
+        let mut output = Vec::new();
+        let mut output_cursor = Cursor::new(&mut output);
+
+        let mut mirror = Mirror::new(reader, &mut output_cursor);
+
+        if self
+            .jpeg_header
+            .parse_at(&mut mirror, enabled_features, start_offset)
+            .context(here!())?
+        {
+            // append the header if it was not the end of file marker
+            self.raw_jpeg_header.append(&mut output);
+            return Ok(true);
+        } else {
+            // if the output was more than 2 bytes then was a trailing header, so keep that around as well,
+            // but we don't want the EOI since that goes into the garbage data.
+            if output.len() > 2 {
+                self.raw_jpeg_header.extend(&output[0..output.len() - 2]);
+            }
+
+            return Ok(false);
+        }
+    }
+}
+
+/// what coding a block costs on top of its non-zero coefficients, in units of what coding a non-zero coefficient
+/// costs, which is about what the encoding times of the sample images work out to
+const ROW_COST_PER_BLOCK: u64 = 4;
+
+/// estimates what encoding the rows of each of the row handoffs costs from the number of their blocks in all the
+/// components and the non-zero coefficients in them, in the units of ROW_COST_PER_BLOCK
+pub fn estimate_row_costs(
+    thread_handoffs: &[ThreadHandoff],
+    jpeg_header: &JPegHeader,
+    image_data: &[BlockBasedImage],
+) -> Vec<u64> {
+    let luma_height = jpeg_header.cmp_info[0].bcv;
+
+    let mut row_costs = vec![0u64; thread_handoffs.len()];
+    for (component, image) in image_data.iter().enumerate() {
+        let block_width = image.get_block_width();
+        let block_height = jpeg_header.cmp_info[component].bcv;
+
+        for (i, handoff) in thread_handoffs.iter().enumerate() {
+            // the rows of the component that the encoder codes along with the luma rows of the handoff
+            let start = handoff.luma_y_start * block_height / luma_height;
+            let end = if i == thread_handoffs.len() - 1 {
+                block_height
+            } else {
+                handoff.luma_y_end * block_height / luma_height
+            };
+
+            for dpos in start * block_width..end * block_width {
+                let non_zeros: u64 = image
+                    .get_block(dpos)
+                    .get_block()
+                    .iter()
+                    .map(|c| u64::from(*c != 0))
+                    .sum();
+                row_costs[i] += ROW_COST_PER_BLOCK + non_zeros;
+            }
+        }
+    }
+
+    row_costs
+}
+
+/// combines the row handoffs into thread segments. With row_costs, see estimate_row_costs, the segments get rows
+/// that cost about the same to encode, otherwise they get the same number of rows.
+pub fn split_row_handoffs_to_threads(
+    thread_handoffs: &[ThreadHandoff],
+    row_costs: Option<&[u64]>,
+    max_threads_to_use: usize,
+    target_segments: Option<usize>,
+) -> Vec<ThreadHandoff> {
+    let last = thread_handoffs.last().unwrap();
+
+    let framebuffer_byte_size = ThreadHandoff::get_combine_thread_range_segment_size(
+        thread_handoffs.first().unwrap(),
+        last,
+    );
+
+    // determine how many threads we need for compression
+    let num_rows = thread_handoffs.len();
+    let num_threads = match target_segments {
+        // every segment needs at least one row, and the thread id has to fit into the chunk framing
+        Some(target) => target.clamp(
+            1,
+            cmp::min(num_rows, MAX_THREADS_SUPPORTED_BY_LEPTON_FORMAT),
+        ),
+        None => get_number_of_threads_for_encoding(
+            num_rows,
+            framebuffer_byte_size as usize,
+            max_threads_to_use,
+        ),
+    };
+
+    info!("Number of threads: {0}", num_threads);
+
+    let mut selected_splits = Vec::with_capacity(num_threads as usize);
+
+    if num_threads == 1 {
+        // Single thread execution - no split, run on the whole range
+        selected_splits.push(ThreadHandoff::combine_thread_ranges(
+            thread_handoffs.first().unwrap(),
+            last,
+        ));
+    } else {
+        let range_starts = match row_costs {
+            Some(row_costs) => balanced_range_starts(row_costs, num_threads),
+            None => even_range_starts(num_rows, num_threads),
+        };
+
+        for i in 0..num_threads {
+            let beginning_of_range = range_starts[i];
+            let end_of_range = if i == num_threads - 1 {
+                num_rows - 1
+            } else {
+                range_starts[i + 1] - 1
+            };
+            assert!(end_of_range < num_rows, "endOfRange < numRows");
+            selected_splits.push(ThreadHandoff::combine_thread_ranges(
+                &thread_handoffs[beginning_of_range],
+                &thread_handoffs[end_of_range],
+            ));
+        }
+    }
+
+    return selected_splits;
+}
+
+/// the first row of each of num_threads ranges of rows with about the same number of rows
+fn even_range_starts(num_rows: usize, num_threads: usize) -> Vec<usize> {
+    // gbrovman: simplified split logic
+    // Note: rowsPerThread is a floating point value to ensure equal splits
+    let rows_per_thread = num_rows as f32 / num_threads as f32;
+
+    assert!(rows_per_thread >= 1f32, "rowsPerThread >= 1");
+
+    // each range starts after the split point, but early enough to leave a row for each of the ranges
+    // after it, which only matters when there are fewer than two rows per thread
+    let mut range_starts = vec![0];
+    for i in 1..num_threads {
+        range_starts.push(cmp::min(
+            (rows_per_thread * i as f32) as usize + 1,
+            num_rows - (num_threads - i),
+        ));
+    }
+
+    range_starts
+}
+
+/// the first row of each of num_threads ranges of rows that cost about the same according to row_costs, each of
+/// them with at least one row. Everything is integer arithmetic, so the ranges only depend on the costs.
+fn balanced_range_starts(row_costs: &[u64], num_threads: usize) -> Vec<usize> {
+    let num_rows = row_costs.len();
+    assert!(num_threads <= num_rows, "num_threads <= num_rows");
+
+    // the cost of the rows before each row, and of all of them at the end
+    let mut cost_before = Vec::with_capacity(num_rows + 1);
+    cost_before.push(0u64);
+    for c in row_costs {
+        cost_before.push(cost_before.last().unwrap() + c);
+    }
+    let total = cost_before[num_rows];
+
+    let mut range_starts = vec![0];
+    for i in 1..num_threads {
+        let target = (u128::from(total) * i as u128 / num_threads as u128) as u64;
+
+        // the last row that starts at or before the target, or the one after it if that starts closer to it
+        let mut start = cost_before.partition_point(|c| *c <= target) - 1;
+        if start < num_rows && cost_before[start + 1] - target < target - cost_before[start] {
+            start += 1;
+        }
+
+        // leave a row for this range and each of the ones after it
+        range_starts.push(start.clamp(
+            range_starts.last().unwrap() + 1,
+            num_rows - (num_threads - i),
+        ));
+    }
+
+    range_starts
+}
+
+fn get_number_of_threads_for_encoding(
+    num_rows: usize,
+    framebuffer_byte_size: usize,
+    max_threads_to_use: usize,
+) -> usize {
+    let mut num_threads = cmp::min(max_threads_to_use, MAX_THREADS);
+
+    if num_rows / 2 < num_threads {
+        num_threads = cmp::max(num_rows / 2, 1);
+    }
+
+    if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD {
+        num_threads = 1;
+    } else if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD * 2 {
+        num_threads = cmp::min(2, num_threads);
+    } else if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD * 4 {
+        num_threads = cmp::min(4, num_threads);
+    }
+
+    return num_threads;
+}
+
+/// how long a worker waits before handing on a chunk of a thread segment, given the segment and the number of the
+/// chunk within it
+#[cfg(feature = "test-utils")]
+pub type ChunkDelay = fn(segment: usize, chunk: usize) -> std::time::Duration;
+
+#[cfg(feature = "test-utils")]
+thread_local! {
+    /// Testing aid, see testing::with_chunk_delay: the delay that the encoder workers wait before each chunk of a
+    /// thread segment is handed on. Taken from the thread that starts the encode.
+    pub static CHUNK_DELAY: Cell<Option<ChunkDelay>> = const { Cell::new(None) };
+}
+
+enum Message {
+    /// the thread with the given id has no more data
+    Eof(u8),
+    WriteBlock(u8, Vec<u8>),
+}
+
+struct MessageSender {
+    thread_id: u8,
+    sender: Sender<Message>,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    /// see CHUNK_DELAY
+    #[cfg(feature = "test-utils")]
+    chunk_delay: Option<ChunkDelay>,
+    #[cfg(feature = "test-utils")]
+    chunks_sent: usize,
+}
+
+/// writes a chunk of the output of a thread, prefixed by the thread id and its length. The length of each frame has
+/// to fit into 16 bits, so bigger chunks are written as several.
+/// bounds of the chunk size that get_chunk_size picks
+const MIN_DEFAULT_CHUNK_SIZE: usize = 16384;
+const MAX_DEFAULT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+impl Write for MessageSender {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut copy_start = 0;
+        while copy_start < buf.len() {
+            let amount_to_copy =
+                cmp::min(self.chunk_size - self.buffer.len(), buf.len() - copy_start);
+            self.buffer
+                .extend_from_slice(&buf[copy_start..copy_start + amount_to_copy]);
+
+            if self.buffer.len() == self.chunk_size {
+                self.flush()?;
+            }
+
+            copy_start += amount_to_copy;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.buffer.len() > 0 {
+            let mut new_buffer = Vec::with_capacity(self.chunk_size);
+            swap(&mut new_buffer, &mut self.buffer);
+
+            #[cfg(feature = "test-utils")]
+            {
+                if let Some(delay) = self.chunk_delay {
+                    std::thread::sleep(delay(usize::from(self.thread_id), self.chunks_sent));
+                }
+                self.chunks_sent += 1;
+            }
+
+            self.sender
+                .send(Message::WriteBlock(self.thread_id, new_buffer))
+                .unwrap();
+        }
+        Ok(())
+    }
+}
+
+/// size of the pieces that a decoder worker hands its output on in, see OutputSender
+const OUTPUT_BUFFER_SIZE: usize = 65536;
+
+/// used by a decoder worker to hand its output to the thread that writes it out, in pieces of OUTPUT_BUFFER_SIZE.
+/// The queue only has room for so many of them, after which the worker waits for the writer. Fails once the
+/// writer has stopped reading, which is how a worker that is waiting gets cancelled.
+struct OutputSender<'a, T> {
+    sender: SyncSender<DecoderOutput<T>>,
+    buffer: Vec<u8>,
+    /// number of bytes that can still be written, after which the rest is dropped
+    remaining: u64,
+    /// doesn't count the worker as working while it waits for room in the queue
+    read_ahead: &'a ReadAheadBudget,
+}
+
+impl<T> OutputSender<'_, T> {
+    /// hands piece on to the writer, waiting for room in the queue if there is none
+    fn send(&self, piece: DecoderOutput<T>) -> std::io::Result<()> {
+        let sent = match self.sender.try_send(piece) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(piece)) => self
+                .read_ahead
+                .while_output_waits(|| self.sender.send(piece))
+                .map_err(|_| ()),
+            Err(TrySendError::Disconnected(_)) => Err(()),
+        };
+
+        sent.map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "cancelled since the output is no longer written",
+            )
+        })
+    }
+}
+
+impl<T> Write for OutputSender<'_, T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let amount = cmp::min(buf.len() as u64, self.remaining) as usize;
+        if amount < buf.len() && self.remaining > 0 {
+            warn!("warning: truncating segment");
+        }
+        self.remaining -= amount as u64;
+
+        self.buffer.extend_from_slice(&buf[..amount]);
+        if self.buffer.len() >= OUTPUT_BUFFER_SIZE {
+            self.flush()?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if !self.buffer.is_empty() {
+            let piece = std::mem::take(&mut self.buffer);
+            self.send(DecoderOutput::Data(piece))?;
+        }
+        Ok(())
+    }
+}
+
+/// used by the worker thread to read data for the given thread from the
+/// receiver. The thread_id is used only to assert that we are only
+/// getting the data that we are expecting
+struct MessageReceiver<'a> {
+    /// the multiplexed thread stream we are processing
+    thread_id: u8,
+
+    /// the receiver part of the channel to get more buffers
+    receiver: Receiver<Message>,
+
+    /// what we are reading. When this returns zero, we try to
+    /// refill the buffer if we haven't reached the end of the stream
+    current_buffer: Cursor<Vec<u8>>,
+
+    /// once we get told we are at the end of the stream, we just
+    /// always return 0 bytes
+    end_of_file: bool,
+
+    /// set once a decoder worker failed, after which there is no point in reading on
+    cancelled: &'a AtomicBool,
+
+    /// gets back the room of the buffers that are taken, and doesn't count the worker as working while it waits
+    /// for the next one
+    read_ahead: &'a ReadAheadBudget,
+}
+
+impl Read for MessageReceiver<'_> {
+    /// fast path for reads. If we get zero bytes, take the slow path
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let amount_read = self.current_buffer.read(buf)?;
+        if amount_read > 0 {
+            return Ok(amount_read);
+        }
+
+        self.read_slow(buf)
+    }
+}
+
+impl MessageReceiver<'_> {
+    /// slow path for reads, try to get a new buffer or
+    /// return zero if at the end of the stream. Fails if another worker has failed, to cancel this one.
+    #[cold]
+    #[inline(never)]
+    fn read_slow(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "cancelled since another segment failed",
+            ));
+        }
+
+        while !self.end_of_file {
+            let amount_read = self.current_buffer.read(buf)?;
+            if amount_read > 0 {
+                return Ok(amount_read);
+            }
+
+            let message = match self.receiver.try_recv() {
+                Ok(r) => Ok(r),
+                Err(TryRecvError::Empty) => self
+                    .read_ahead
+                    .while_waiting(usize::from(self.thread_id), || self.receiver.recv()),
+                Err(TryRecvError::Disconnected) => Err(RecvError),
+            };
+
+            match message {
+                Ok(r) => match r {
+                    Message::Eof(_) => {
+                        self.end_of_file = true;
+                    }
+                    Message::WriteBlock(tid, block) => {
+                        debug_assert_eq!(
+                            tid, self.thread_id,
+                            "incoming thread must be equal to processing thread"
+                        );
+                        self.read_ahead.release(block.len() as u64);
+                        self.current_buffer = Cursor::new(block);
+                    }
+                },
+                Err(e) => {
+                    return Result::Err(std::io::Error::new(std::io::ErrorKind::Other, e));
+                }
+            }
+        }
+
+        // nothing if we reached the end of file
+        return Ok(0);
+    }
+}
+
+impl Drop for MessageReceiver<'_> {
+    /// gives back the room of the buffers that the worker didn't get to, such as the ones after the end of a
+    /// segment that isn't checked strictly
+    fn drop(&mut self) {
+        while let Ok(message) = self.receiver.try_recv() {
+            if let Message::WriteBlock(_, block) = message {
+                self.read_ahead.release(block.len() as u64);
+            }
+        }
+    }
+}
+
+// internal utility we use to collect the header that we read for later
+struct Mirror<'a, R, W> {
+    read: &'a mut R,
+    output: &'a mut W,
+    amount_written: usize,
+}
+
+impl<'a, R, W> Mirror<'a, R, W> {
+    pub fn new(read: &'a mut R, output: &'a mut W) -> Self {
+        Mirror {
+            read,
+            output,
+            amount_written: 0,
+        }
+    }
+}
+
+impl<R: Read, W: Write> Read for Mirror<'_, R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.read.read(buf)?;
+        self.output.write_all(&buf[..n])?;
+        self.amount_written += n;
+        Ok(n)
+    }
+}
+
+// false means we hit the end of file marker
+fn prepare_to_decode_next_scan<R: Read + Seek>(
+    lp: &mut LeptonHeader,
+    reader: &mut R,
+    enabled_features: &EnabledFeatures,
+) -> Result<bool> {
+    // parse the header and store it in the raw_jpeg_header
+    let start_offset = reader.stream_position()? as usize;
+    if !lp
+        .parse_jpeg_header(reader, enabled_features, start_offset)
+        .context(here!())?
+    {
+        return Ok(false);
+    }
+
+    lp.max_bpos = cmp::max(lp.max_bpos, lp.jpeg_header.cs_to as i32);
+
+    // FIXME: not sure why only first bit of csSah is examined but 4 bits of it are stored
+    lp.max_sah = cmp::max(
+        lp.max_sah,
+        cmp::max(lp.jpeg_header.cs_sal, lp.jpeg_header.cs_sah),
+    );
+
+    for i in 0..lp.jpeg_header.cs_cmpc {
+        lp.max_cmp = cmp::max(lp.max_cmp, lp.jpeg_header.cs_cmp[i] as i32);
+    }
+
+    return Ok(true);
+}
+
+fn set_segment_size_in_row_thread_handoffs(
+    thread_handoffs: &mut [ThreadHandoff],
+    entropy_data_end_offset_in_file: i64,
+) {
+    if thread_handoffs.len() != 0 {
+        for i in 0..thread_handoffs.len() - 1 {
+            thread_handoffs[i].segment_size = thread_handoffs[i + 1].segment_offset_in_file
+                - thread_handoffs[i].segment_offset_in_file;
+        }
+
+        thread_handoffs[thread_handoffs.len() - 1].segment_size = entropy_data_end_offset_in_file
+            - thread_handoffs[thread_handoffs.len() - 1].segment_offset_in_file;
+    }
+}
+
+#[cfg(test)]
+use super::jpeg_write::RowBoundary;
+
+// test serializing and deserializing header
+#[test]
+fn parse_and_write_header() {
+    // minimal jpeg that will pass the validity read tests
+    let min_jpeg = [
+        0xffu8, 0xe0, // APP0
+        0x00, 0x10, 0x4a, 0x46, 0x49, 0x46, 0x00, 0x01, 0x01, 0x01, 0x00, 0x48, 0x00, 0x48, 0x00,
+        0x00, 0xff, 0xdb, // DQT
+        0x00, 0x43, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x02, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03,
+        0x03, 0x03, 0x04, 0x06, 0x04, 0x04, 0x04, 0x04, 0x04, 0x08, 0x06, 0x06, 0x05, 0x06, 0x09,
+        0x08, 0x0a, 0x0a, 0x09, 0x08, 0x09, 0x09, 0x0a, 0x0c, 0x0f, 0x0c, 0x0a, 0x0b, 0x0e, 0x0b,
+        0x09, 0x09, 0x0d, 0x11, 0x0d, 0x0e, 0x0f, 0x10, 0x10, 0x11, 0x10, 0x0a, 0x0c, 0x12, 0x13,
+        0x12, 0x10, 0x13, 0x0f, 0x10, 0x10, 0x10, 0xff, 0xC1, 0x00, 0x0b, 0x08, 0x00,
+        0x10, // width
+        0x00, 0x10, // height
+        0x01, // cmpc
+        0x01, // Jid
+        0x11, // sfv / sfh
+        0x00, 0xff, 0xda, // SOS
+        0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3f, 0x00, 0xd2, 0xcf, 0x20, 0xff, 0xd9, // EOI
+    ];
+
+    let mut lh = LeptonHeader::new();
+    lh.jpeg_file_size = 123;
+
+    lh.parse_jpeg_header(&mut Cursor::new(min_jpeg), &EnabledFeatures::all(), 0)
+        .unwrap();
+    lh.thread_handoff.push(ThreadHandoff {
+        luma_y_start: 0,
+        luma_y_end: 1,
+        segment_offset_in_file: 0,
+        segment_size: 1000,
+        overhang_byte: 0,
+        num_overhang_bits: 1,
+        last_dc: [1, 2, 3, 4],
+    });
+
+    let mut serialized = Vec::new();
+    lh.write_lepton_header(&mut Cursor::new(&mut serialized))
+        .unwrap();
+
+    let mut other = LeptonHeader::new();
+    let mut other_reader = Cursor::new(&serialized);
+    other.read_lepton_header(&mut other_reader).unwrap();
+}
+
+/// only the features that are used are marked as required, and files requiring features we don't
+/// know about are rejected before anything else is read
+#[test]
+fn required_features() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    for (file, expected) in [
+        ("android", 0),
+        ("missingrst", LEPTON_FEATURE_RESTART_EXCEPTIONS),
+        ("scancomment", LEPTON_FEATURE_SCAN_SEGMENTS),
+    ] {
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                checksum: false,
+                original_size: false,
+                segment_checksums: false,
+                segment_index: false,
+                ..EnabledFeatures::all()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            u32::from_le_bytes(lepton[14..18].try_into().unwrap()),
+            expected,
+            "{file}"
+        );
+
+        // pretend the file needs a feature from the future
+        lepton[17] |= 0x80;
+
+        let mut lh = LeptonHeader::new();
+        let e = lh
+            .read_lepton_header(&mut Cursor::new(&lepton))
+            .unwrap_err();
+        assert_eq!(
+            e.root_cause()
+                .downcast_ref::<crate::lepton_error::LeptonError>()
+                .unwrap()
+                .exit_code,
+            ExitCode::VersionUnsupported
+        );
+    }
+}
+
+/// the encoder stores the CRC of the original file in the header unless disabled, and the decoder picks it up
+#[test]
+fn checksum_in_header() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    let mut crc = Crc::new();
+    crc.update(&original[..]);
+
+    for (checksum, expected) in [(true, Some(crc.sum())), (false, None)] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                checksum,
+                ..EnabledFeatures::all()
+            },
+        )
+        .unwrap();
+
+        let mut lh = LeptonHeader::new();
+        let mut reader = Cursor::new(&lepton);
+        lh.read_lepton_header(&mut reader).unwrap();
+        assert_eq!(lh.original_file_crc, expected);
+
+        let remaining_size = get_remaining_size(&mut reader).unwrap();
+
+        let mut output = Vec::new();
+        lh.recode_jpeg(&mut output, &mut reader, Some(remaining_size), 8)
+            .unwrap();
+        assert!(output[..] == original[..]);
+    }
+}
+
+/// the encoder stores the size of the original file in the header unless disabled, and the decoder checks
+/// the recreated JPEG against it
+#[test]
+fn original_file_size_in_header() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    for (original_size, expected) in [(true, Some(original.len() as u64)), (false, None)] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                original_size,
+                ..EnabledFeatures::all()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_original_file_size_wrapper(&mut Cursor::new(&lepton)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            u32::from_le_bytes(lepton[14..18].try_into().unwrap()) & LEPTON_FEATURE_ORIGINAL_SIZE
+                != 0,
+            original_size
+        );
+
+        let mut output = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+        assert!(output[..] == original[..]);
+    }
 
-                jpeg_write_row_range(
-                    &mut cursor,
-                    &image_data,
-                    lh.truncate_components.mcu_count_vertical,
-                    &thread_handoff,
-                    &max_coded_heights[..],
-                    &mut huffw,
-                    lh,
-                )
-                .context(here!())?;
+    // a size that doesn't match what the file decodes to is reported as such
+    let lepton = std::fs::read(path.join("android.lep")).unwrap();
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    lh.original_file_size = Some(original.len() as u64 + 1);
+
+    let remaining_size = get_remaining_size(&mut reader).unwrap();
+
+    let e = lh
+        .recode_jpeg(&mut Vec::new(), &mut reader, Some(remaining_size), 8)
+        .unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::VerificationLengthMismatch
+    );
 
-                #[cfg(detailed_tracing)]
-                info!("ystart = {0}, segment_size = {1}, amount = {2}, offset = {3}, ob = {4}, nb = {5}", 
-                combined_thread_handoff.luma_y_start,
-                combined_thread_handoff.segment_size,
-                cursor.position() - _start_size,
-                combined_thread_handoff.segment_offset_in_file,
-                combined_thread_handoff.overhang_byte,
-                combined_thread_handoff.num_overhang_bits
-            );
+    // files that predate it don't have it
+    assert_eq!(
+        read_original_file_size_wrapper(&mut Cursor::new(&lepton)).unwrap(),
+        None
+    );
+}
 
-                if result_buffer.len() > thread_handoff.segment_size as usize {
-                    warn!("warning: truncating segment");
-                    result_buffer.resize(thread_handoff.segment_size as usize, 0);
-                }
+/// files that don't get smaller are stored as is when passthrough is enabled, and decode back to the original
+#[test]
+fn passthrough_when_not_smaller() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    // a tiny image where the lepton header outweighs the savings, and the same with random data after it
+    let small = std::fs::read(path.join("colorswap.jpg")).unwrap();
+    let mut random = small.clone();
+    let mut x = 12345u32;
+    for _i in 0..16384 {
+        x = x.wrapping_mul(1103515245).wrapping_add(12345);
+        random.push((x >> 16) as u8);
+    }
 
-                return Ok(result_buffer);
+    for original in [small, random] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                passthrough: false,
+                ..EnabledFeatures::all()
             },
-        )?;
+        )
+        .unwrap();
+        assert!(lepton.len() as u64 + PASSTHROUGH_MARGIN > original.len() as u64);
+
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures::all(),
+        )
+        .unwrap();
 
-        // write all the buffers that we collected
-        for r in results {
-            writer.write_all(&r[..]).context(here!())?;
-        }
+        // the original is streamed from where the reader is rather than copied from the start of the stream
+        let mut prefixed = vec![0x55u8; 1000];
+        prefixed.extend_from_slice(&original);
+        let mut reader = Cursor::new(&prefixed);
+        reader.set_position(1000);
+        let mut prefixed_lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut reader,
+            &mut Cursor::new(&mut prefixed_lepton),
+            8,
+            &EnabledFeatures::all(),
+        )
+        .unwrap();
+        assert!(prefixed_lepton == lepton);
+
+        // the original follows the header as is, followed by the file size
+        let mut reader = Cursor::new(&lepton);
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut reader).unwrap();
+        assert_eq!(lh.passthrough_size, Some(original.len() as u64));
+        assert_eq!(
+            reader.position() as usize + original.len() + 4,
+            lepton.len()
+        );
+        assert!(lepton[reader.position() as usize..lepton.len() - 4] == original[..]);
+        assert_ne!(
+            u32::from_le_bytes(lepton[14..18].try_into().unwrap()) & LEPTON_FEATURE_PASSTHROUGH,
+            0
+        );
 
-        if !self.early_eof_encountered {
-            // Injection of restart codes for RST errors supports JPEGs with trailing RSTs
-            if self.rst_err.len() > 0 {
-                let cumulative_reset_markers = if self.jpeg_header.rsti != 0 {
-                    ((self.jpeg_header.mcuh * self.jpeg_header.mcuv) - 1) / self.jpeg_header.rsti
-                } else {
-                    0
-                } as u8;
-                for i in 0..self.rst_err[0] as u8 {
-                    let rst = (jpeg_code::RST0 + ((cumulative_reset_markers + i) & 7)) as u8;
-                    writer.write_u8(0xFF)?;
-                    writer.write_u8(rst)?;
-                }
-            }
-        }
+        let mut output = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+        assert!(output[..] == original[..]);
+
+        // the stored copy is still checked against the CRC of the original
+        let last = lepton.len() - 5;
+        lepton[last] ^= 1;
+        let e = decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut Vec::new(), 8).unwrap_err();
+        assert_eq!(
+            e.root_cause()
+                .downcast_ref::<crate::lepton_error::LeptonError>()
+                .unwrap()
+                .exit_code,
+            ExitCode::VerificationContentMismatch
+        );
 
-        Ok(metrics)
+        // and there are no coefficients to work with
+        let e =
+            decode_lepton_wrapper_optimize_huffman(&mut Cursor::new(&lepton), &mut Vec::new(), 8)
+                .unwrap_err();
+        assert_eq!(
+            e.root_cause()
+                .downcast_ref::<crate::lepton_error::LeptonError>()
+                .unwrap()
+                .exit_code,
+            ExitCode::UnsupportedJpeg
+        );
     }
 
-    /// reads the start of the lepton file and parses the compressed header. Returns the raw JPEG header contents.
-    pub fn read_lepton_header<R: Read>(&mut self, reader: &mut R) -> Result<()> {
-        let mut header = [0 as u8; LEPTON_FILE_HEADER.len()];
-
-        reader.read_exact(&mut header).context(here!())?;
-
-        if !buffer_prefix_matches_marker(header, LEPTON_FILE_HEADER) {
-            return err_exit_code(ExitCode::BadLeptonFile, "header doesn't match");
-        }
+    // images that compress well are encoded as usual
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-        // Complicated logic of version compatibility should be verified by the caller.
-        // Currently just matching the version version.
-        let version = reader.read_u8().context(here!())?;
-        if version != LEPTON_VERSION {
-            return err_exit_code(
-                ExitCode::VersionUnsupported,
-                format!("incompatible file with version {0}", version).as_str(),
-            );
-        }
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
+    assert_eq!(lh.passthrough_size, None);
+}
 
-        let mut header = [0 as u8; 21];
-        reader.read_exact(&mut header).context(here!())?;
+/// sizes beyond 4GB are stored in the wider fields of files with large sizes, and are refused by the regular ones
+#[test]
+fn large_sizes_in_header() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-        // Z = baseline non-progressive
-        // Y = chunked encoding of a slice of a JPEG (not supported yet)
-        // X = progressive
-        if header[0] != LEPTON_HEADER_BASELINE_JPEG_TYPE[0]
-            && header[0] != LEPTON_HEADER_PROGRESSIVE_JPEG_TYPE[0]
-        {
-            return err_exit_code(
-                ExitCode::BadLeptonFile,
-                format!("Unknown filetype in header {0}", header[0]).as_str(),
-            );
-        }
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
+    assert!(!lh.large_sizes && lh.thread_handoff.len() > 1);
+
+    // pretend to be a JPEG of just over 4GB without having one
+    lh.jpeg_file_size = (1 << 32) + 12345;
+    lh.thread_handoff[0].segment_size = (1 << 31) + 7;
+    lh.segment_index_offset = Some((1 << 32) + 99);
+    assert!(lh.needs_large_sizes());
+
+    let e = lh.write_lepton_header(&mut Vec::new()).unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::SizeOverflow
+    );
 
-        let mut c = Cursor::new(header);
+    lh.large_sizes = true;
+    let mut header = Vec::new();
+    lh.write_lepton_header(&mut header).unwrap();
+    assert_ne!(
+        u32::from_le_bytes(header[14..18].try_into().unwrap()) & LEPTON_FEATURE_LARGE_SIZES,
+        0
+    );
 
-        // We use 12 bytes of git revision for our needs - mark that it's C# implementation and a not-compressed header size.
-        self.uncompressed_lepton_header_size = 0;
-        if header[5] == 'M' as u8 && header[6] == 'S' as u8 {
-            c.set_position(7);
-            self.uncompressed_lepton_header_size = c.read_u32::<LittleEndian>()?;
-        }
+    let mut other = LeptonHeader::new();
+    other.read_lepton_header(&mut Cursor::new(&header)).unwrap();
+    assert!(other.large_sizes);
+    assert_eq!(other.plain_text_size, (1 << 32) + 12345);
+    assert_eq!(other.thread_handoff[0].segment_size, (1 << 31) + 7);
+    assert_eq!(
+        other.thread_handoff[1].segment_size,
+        lh.thread_handoff[1].segment_size
+    );
+    assert_eq!(other.segment_index_offset, Some((1 << 32) + 99));
 
-        // full size of the original file
-        c.set_position(17);
-        self.plain_text_size = c.read_u32::<LittleEndian>()?;
+    // the same goes for the segment index and the file size at the end
+    let index = [SegmentIndexEntry {
+        offset: (1 << 32) + 1,
+        length: (1 << 32) + 2,
+        luma_y_start: 0,
+        luma_y_end: 8,
+    }];
+    assert!(SegmentIndexEntry::serialize(&index, false, &mut Vec::new()).is_err());
+
+    let mut data = Vec::new();
+    SegmentIndexEntry::serialize(&index, true, &mut data).unwrap();
+    assert_eq!(data.len() as u64, SegmentIndexEntry::serialized_size(true));
+    assert_eq!(
+        SegmentIndexEntry::deserialize(1, true, &mut Cursor::new(&data)).unwrap(),
+        index
+    );
 
-        // now read the compressed header
-        let compressed_header_size = reader.read_u32::<LittleEndian>()? as usize;
+    assert!(LeptonHeader::new()
+        .write_file_size(&mut Vec::new(), 1 << 32)
+        .is_err());
+}
 
-        if compressed_header_size > MAX_FILE_SIZE_BYTES as usize {
-            return err_exit_code(ExitCode::BadLeptonFile, "Too big compressed header");
-        }
-        if self.plain_text_size > MAX_FILE_SIZE_BYTES as u32 {
-            return err_exit_code(ExitCode::BadLeptonFile, "Only support images < 128 megs");
+/// a scan segment that claims more data than the header has is refused without allocating what it claims
+#[test]
+fn test_scan_segment_length_bounded_by_header() {
+    let mut compressed = Vec::new();
+    {
+        let mut encoder = ZlibEncoder::new(&mut compressed, Compression::default());
+        encoder.write_all(&LEPTON_HEADER_MARKER).unwrap();
+        encoder.write_u32::<LittleEndian>(0).unwrap();
+        encoder
+            .write_all(&LEPTON_HEADER_SCAN_SEGMENTS_MARKER)
+            .unwrap();
+        encoder.write_u32::<LittleEndian>(1).unwrap();
+        for _i in 0..3 {
+            encoder.write_u32::<LittleEndian>(0).unwrap();
         }
+        encoder.write_u32::<LittleEndian>(u32::MAX).unwrap();
+        encoder.write_all(&[0; 100]).unwrap();
+        encoder.finish().unwrap();
+    }
 
-        // limit reading to the compressed header
-        let mut compressed_reader = reader.take(compressed_header_size as u64);
+    let e = LeptonHeader::new()
+        .read_lepton_compressed_header(&mut Cursor::new(&compressed))
+        .unwrap_err();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::BadLeptonFile
+    );
+}
 
-        self.raw_jpeg_header = self
-            .read_lepton_compressed_header(&mut compressed_reader)
-            .context(here!())?;
+/// files written with large sizes decode the same way, even though the real ones are all small
+#[test]
+fn decode_with_large_sizes() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    for segment_index in [false, true] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                segment_index,
+                segment_checksums: false,
+                ..EnabledFeatures::all()
+            },
+        )
+        .unwrap();
 
-        // CMP marker
-        let mut current_lepton_marker = [0 as u8; 3];
-        reader.read_exact(&mut current_lepton_marker)?;
-        if !buffer_prefix_matches_marker(current_lepton_marker, LEPTON_HEADER_COMPLETION_MARKER) {
-            return err_exit_code(ExitCode::BadLeptonFile, "CMP marker not found");
-        }
+        // rewrite the file with the wider fields
+        let mut reader = Cursor::new(&lepton);
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut reader).unwrap();
+        let data_start = reader.position() as usize;
+
+        let (data_end, index) = match lh.segment_index_offset {
+            Some(offset) => (
+                data_start + offset as usize,
+                lh.read_segment_index(&mut reader, data_start as u64)
+                    .unwrap(),
+            ),
+            None => (lepton.len() - 4, Vec::new()),
+        };
 
-        self.raw_jpeg_header_read_index = 0;
+        lh.large_sizes = true;
+        lh.jpeg_file_size = lh.plain_text_size;
 
-        {
-            let mut header_data_cursor = Cursor::new(&self.raw_jpeg_header[..]);
-            self.jpeg_header
-                .parse(&mut header_data_cursor, &EnabledFeatures::all())
-                .context(here!())?;
-            self.raw_jpeg_header_read_index = header_data_cursor.position() as usize;
+        let mut large = Vec::new();
+        lh.write_lepton_header(&mut large).unwrap();
+        large.extend_from_slice(&lepton[data_start..data_end]);
+        if segment_index {
+            SegmentIndexEntry::serialize(&index, true, &mut large).unwrap();
         }
+        let file_size = large.len() as u64 + 8;
+        lh.write_file_size(&mut large, file_size).unwrap();
 
-        self.truncate_components.init(&self.jpeg_header);
+        let mut output = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&large), &mut output, 8).unwrap();
+        assert!(output[..] == original[..]);
 
-        if self.early_eof_encountered {
-            self.truncate_components
-                .set_truncation_bounds(&self.jpeg_header, self.max_dpos);
-        }
+        // the stream decoder has to tell the longer file size apart from the last chunk
+        let mut output = Vec::new();
+        decode_lepton_wrapper_streaming(&mut Cursor::new(&large), &mut output, 8).unwrap();
+        assert!(output[..] == original[..]);
 
-        let num_threads = self.thread_handoff.len();
+        let layout =
+            crate::structs::lepton_layout::inspect_lepton_structure_wrapper(&large).unwrap();
+        assert!(!layout.truncated);
+        assert_eq!(layout.original_file_size, original.len() as u64);
+    }
+}
 
-        // luma_y_end of the last thread is not serialized/deserialized, fill it here
-        self.thread_handoff[num_threads - 1].luma_y_end =
-            self.truncate_components.get_block_height(0);
+/// the encoder records its build in the header, which decoders that don't know about it skip over
+#[test]
+fn encoder_info_in_header() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-        // if the last segment was too big to fit with the garbage data taken into account, shorten it
-        // (a bit of broken logic in the encoder, but can't change it without breaking the file format)
-        if self.early_eof_encountered {
-            let mut max_last_segment_size = i32::try_from(self.plain_text_size)?
-                - i32::try_from(self.garbage_data.len())?
-                - i32::try_from(self.raw_jpeg_header_read_index)?
-                - 2;
+    let info = read_encoder_info_wrapper(&mut Cursor::new(&lepton))
+        .unwrap()
+        .unwrap();
+    assert_eq!(info, EncoderInfo::current(&EnabledFeatures::all()));
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+
+    // older decoders read the compressed header through a zlib decoder that stops at the end of the
+    // compressed data, so they find the CMP marker after the encoder info without looking at it
+    let compressed_header_size = u32::from_le_bytes(lepton[24..28].try_into().unwrap()) as usize;
+    let mut reader = Cursor::new(&lepton[28..]);
+    let mut header = Vec::new();
+    flate2::read::ZlibDecoder::new((&mut reader).take(compressed_header_size as u64))
+        .read_to_end(&mut header)
+        .unwrap();
+    assert!(header.starts_with(&LEPTON_HEADER_MARKER));
+    assert_eq!(
+        lepton[28 + compressed_header_size..][..3],
+        LEPTON_HEADER_COMPLETION_MARKER
+    );
 
-            // subtract the segment sizes of all the previous segments (except for the last)
-            for i in 0..num_threads - 1 {
-                max_last_segment_size -= self.thread_handoff[i].segment_size;
-            }
+    // files that predate it don't have it
+    let lepton = std::fs::read(path.join("android.lep")).unwrap();
+    assert_eq!(
+        read_encoder_info_wrapper(&mut Cursor::new(&lepton)).unwrap(),
+        None
+    );
+}
 
-            let last = &mut self.thread_handoff[num_threads - 1];
+/// the image can be split into any number of segments independently of the number of threads, as long as
+/// each of them gets at least one MCU row
+#[test]
+fn target_segments() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    for (file, target, expected) in [
+        ("iphonecity", Some(1), Some(1)),
+        ("iphonecity", Some(16), Some(16)),
+        ("iphonecity", Some(0), Some(1)),
+        (
+            "iphonecity",
+            Some(100),
+            Some(MAX_THREADS_SUPPORTED_BY_LEPTON_FORMAT),
+        ),
+        ("tiny", Some(16), None),
+        ("iphoneprogressive", Some(12), Some(12)),
+    ] {
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            2,
+            &EnabledFeatures {
+                target_segments: target,
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
 
-            if last.segment_size > max_last_segment_size {
-                // re-adjust the last segment size
-                last.segment_size = max_last_segment_size;
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
+
+        // without an expected count, the image has fewer rows than segments were asked for
+        let mcuv = lh.truncate_components.mcu_count_vertical as usize;
+        match expected {
+            Some(expected) => assert_eq!(lh.thread_handoff.len(), expected, "{file}"),
+            None => {
+                assert!(mcuv < 16, "{file}");
+                assert_eq!(lh.thread_handoff.len(), mcuv, "{file}");
             }
         }
 
-        Ok(())
-    }
-
-    /// helper for read_lepton_header. uncompresses and parses the contents of the compressed header. Returns the raw JPEG header.
-    fn read_lepton_compressed_header<R: Read>(&mut self, src: &mut R) -> Result<Vec<u8>> {
-        let mut header_reader = ZlibDecoder::new(src);
-
-        let mut hdr_buf: [u8; 3] = [0; 3];
-        header_reader.read_exact(&mut hdr_buf)?;
-
-        if !buffer_prefix_matches_marker(hdr_buf, LEPTON_HEADER_MARKER) {
-            return err_exit_code(ExitCode::BadLeptonFile, "HDR marker not found");
+        // every segment has at least one MCU row
+        let luma_rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv as usize / mcuv;
+        for th in &lh.thread_handoff[..lh.thread_handoff.len() - 1] {
+            assert!(th.luma_y_end - th.luma_y_start >= luma_rows_per_mcu as i32);
         }
 
-        let hdrs = header_reader.read_u32::<LittleEndian>()? as usize;
-
-        let mut hdr_data = Vec::new();
-        hdr_data.resize(hdrs, 0);
-        header_reader.read_exact(&mut hdr_data)?;
+        // the decoder handles any number of segments whatever number of threads it has
+        for num_threads in [1, 3, 16] {
+            let mut output = Vec::new();
+            decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, num_threads).unwrap();
+            assert!(output[..] == original[..], "{file}");
+        }
+    }
+}
 
-        if self.garbage_data.len() == 0 {
-            // if we don't have any garbage, assume FFD9 EOI
+/// damaged data in a thread segment is reported as such along with which segment it is, whether or not
+/// the file has a segment index
+#[test]
+fn corrupt_segment_is_reported() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    for enabled_features in [EnabledFeatures::default(), EnabledFeatures::all()] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &enabled_features,
+        )
+        .unwrap();
 
-            // kind of broken logic since this assumes a EOF even if there was a 0 byte garbage header
-            // in the file, but this is what the file format is.
-            self.garbage_data.extend(EOI);
+        let mut reader = Cursor::new(&lepton);
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut reader).unwrap();
+        assert!(lh.segment_checksums && lh.thread_handoff.len() > 2);
+
+        // flip the start of the first chunk of a segment in the middle, leaving the framing alone
+        let damaged = lh.thread_handoff.len() / 2;
+        let start = loop {
+            let ChunkHeader {
+                thread_id,
+                length: data_length,
+            } = ChunkHeader::read(&mut reader).unwrap();
+            if usize::from(thread_id) == damaged {
+                break reader.position() as usize..reader.position() as usize + data_length;
+            }
+            reader.set_position(reader.position() + data_length as u64);
+        };
+        for b in &mut lepton[start.start..cmp::min(start.end, start.start + 32)] {
+            *b = !*b;
         }
 
-        // beginning here: recovery information (needed for exact JPEG recovery)
-        // read further recovery information if any
-        loop {
-            let mut current_lepton_marker = [0 as u8; 3];
-            match header_reader.read_exact(&mut current_lepton_marker) {
-                Ok(_) => {}
-                Err(e) => {
-                    if e.kind() == ErrorKind::UnexpectedEof {
-                        break;
-                    } else {
-                        return Err(anyhow::Error::new(e));
-                    }
-                }
-            }
+        let mut errors = vec![
+            decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut Vec::new(), 8).unwrap_err(),
+            decode_lepton_wrapper_streaming(&mut Cursor::new(&lepton), &mut Vec::new(), 8)
+                .unwrap_err(),
+        ];
 
-            if buffer_prefix_matches_marker(current_lepton_marker, LEPTON_HEADER_PAD_MARKER) {
-                self.pad_bit = Some(header_reader.read_u8()?);
-            } else if buffer_prefix_matches_marker(
-                current_lepton_marker,
-                LEPTON_HEADER_JPG_RESTARTS_MARKER,
-            ) {
-                // CRS marker
-                self.rst_cnt_set = true;
-                let rst_count = header_reader.read_u32::<LittleEndian>()?;
+        if enabled_features.segment_index {
+            let mcuv = lh.truncate_components.mcu_count_vertical;
+            let luma_rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv / mcuv;
+            let rows = lh.thread_handoff[damaged].luma_y_start / luma_rows_per_mcu;
 
-                for _i in 0..rst_count {
-                    self.rst_cnt.push(header_reader.read_i32::<LittleEndian>()?);
-                }
-            } else if buffer_prefix_matches_marker(
-                current_lepton_marker,
-                LEPTON_HEADER_LUMA_SPLIT_MARKER,
-            ) {
-                // HH markup
-                let mut thread_handoffs =
-                    ThreadHandoff::deserialize(current_lepton_marker[2], &mut header_reader)?;
+            errors
+                .push(decode_rows_wrapper(&mut Cursor::new(&lepton), rows..rows + 1).unwrap_err());
 
-                self.thread_handoff.append(&mut thread_handoffs);
-            } else if buffer_prefix_matches_marker(
-                current_lepton_marker,
-                LEPTON_HEADER_JPG_RESTART_ERRORS_MARKER,
-            ) {
-                // Marker FRS
-                // read number of false set RST markers per scan from file
-                let rst_err_count = header_reader.read_u32::<LittleEndian>()? as usize;
+            // the other segments are still fine
+            decode_rows_wrapper(&mut Cursor::new(&lepton), 0..1).unwrap();
+        }
 
-                let mut rst_err_data = Vec::<u8>::new();
-                rst_err_data.resize(rst_err_count, 0);
+        for e in errors {
+            assert_eq!(
+                e.root_cause()
+                    .downcast_ref::<crate::lepton_error::LeptonError>()
+                    .unwrap()
+                    .exit_code,
+                ExitCode::CorruptSegment
+            );
+            assert!(e
+                .root_cause()
+                .to_string()
+                .contains(&format!("index: {0},", damaged)));
+        }
+    }
+}
 
-                header_reader.read_exact(&mut rst_err_data)?;
+/// thread segments in the header that overlap, don't start at an MCU row or go past the bottom of the image, and
+/// entries of the segment index that leave a gap between them, are refused with the segment they are wrong in
+#[test]
+fn corrupt_handoffs_are_refused() {
+    use crate::lepton_error::LeptonError;
+    use crate::structs::jpeg_read::error_location_fields;
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            target_segments: Some(4),
+            segment_index: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
 
-                self.rst_err.append(&mut rst_err_data);
-            } else if buffer_prefix_matches_marker(
-                current_lepton_marker,
-                LEPTON_HEADER_GARBAGE_MARKER,
-            ) {
-                // GRB marker
-                // read garbage (data after end of JPG) from file
-                let garbage_size = header_reader.read_u32::<LittleEndian>()? as usize;
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let header_size = reader.position() as usize;
+
+    let height = lh.truncate_components.get_block_height(0);
+    let rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv / lh.truncate_components.mcu_count_vertical;
+
+    let check = |e: anyhow::Error, segment: i64| {
+        let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+        assert_eq!(e.exit_code, ExitCode::CorruptHandoff, "{e}");
+        assert_eq!(
+            error_location_fields(&e.message, "Segment {")[0],
+            segment,
+            "{e}"
+        );
+    };
+
+    let overlap = lh.thread_handoff[1].luma_y_start;
+    let misaligned = lh.thread_handoff[1].luma_y_start + 1;
+    for (segment, luma_y_start, expected) in [
+        (2, overlap, 1),
+        (1, misaligned, 1),
+        (3, height + rows_per_mcu, 2),
+        (0, rows_per_mcu, 0),
+    ] {
+        let mut damaged_lh = LeptonHeader::new();
+        damaged_lh
+            .read_lepton_header(&mut Cursor::new(&lepton))
+            .unwrap();
+        damaged_lh.thread_handoff[segment].luma_y_start = luma_y_start;
+
+        let mut damaged = Vec::new();
+        damaged_lh.write_lepton_header(&mut damaged).unwrap();
+        damaged.extend_from_slice(&lepton[header_size..]);
+
+        check(
+            decode_lepton_wrapper(&mut Cursor::new(&damaged), &mut Vec::new(), 4).unwrap_err(),
+            expected,
+        );
+    }
 
-                let mut garbage_data_array = Vec::<u8>::new();
-                garbage_data_array.resize(garbage_size, 0);
+    // a gap between segments 1 and 2 in the segment index
+    let entry_size = SegmentIndexEntry::serialized_size(lh.large_sizes) as usize;
+    let end_of_entry_1 =
+        header_size + lh.segment_index_offset.unwrap() as usize + 2 * entry_size - 4;
+    let mut damaged = lepton.clone();
+    damaged[end_of_entry_1..end_of_entry_1 + 4]
+        .copy_from_slice(&(lh.thread_handoff[1].luma_y_end - rows_per_mcu).to_le_bytes());
 
-                header_reader.read_exact(&mut garbage_data_array)?;
-                self.garbage_data = garbage_data_array;
-            } else if buffer_prefix_matches_marker(
-                current_lepton_marker,
-                LEPTON_HEADER_EARLY_EOF_MARKER,
-            ) {
-                self.max_cmp = header_reader.read_i32::<LittleEndian>()?;
-                self.max_bpos = header_reader.read_i32::<LittleEndian>()?;
-                self.max_sah = u8::try_from(header_reader.read_i32::<LittleEndian>()?)?;
-                self.max_dpos[0] = header_reader.read_i32::<LittleEndian>()?;
-                self.max_dpos[1] = header_reader.read_i32::<LittleEndian>()?;
-                self.max_dpos[2] = header_reader.read_i32::<LittleEndian>()?;
-                self.max_dpos[3] = header_reader.read_i32::<LittleEndian>()?;
-                self.early_eof_encountered = true;
-            } else {
-                return err_exit_code(ExitCode::BadLeptonFile, "unknown data found");
-            }
+    let mut reader = Cursor::new(&damaged);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    check(
+        lh.read_segment_index(&mut reader, header_size as u64)
+            .unwrap_err(),
+        1,
+    );
+}
+
+/// a decoder worker that fails on damaged data reports the segment and the rows it was decoding, whichever other
+/// segments the worker was decoding too
+#[test]
+fn failed_worker_reports_segment() {
+    use crate::lepton_error::LeptonError;
+    use crate::structs::jpeg_read::error_location_fields;
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    // without checksums the damage is only found by the decoder of the segment
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            target_segments: Some(4),
+            segment_checksums: false,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    assert_eq!(lh.thread_handoff.len(), 4);
+
+    // flip the start of the first chunk of segment 2, leaving the framing alone
+    const DAMAGED: usize = 2;
+    let start = loop {
+        let ChunkHeader {
+            thread_id,
+            length: data_length,
+        } = ChunkHeader::read(&mut reader).unwrap();
+        if usize::from(thread_id) == DAMAGED {
+            break reader.position() as usize..reader.position() as usize + data_length;
         }
+        reader.set_position(reader.position() + data_length as u64);
+    };
+    for b in &mut lepton[start.start..cmp::min(start.end, start.start + 32)] {
+        *b = !*b;
+    }
 
-        // shouldn't be any more data
-        let mut remaining_buf = Vec::new();
-        let remaining = header_reader.read_to_end(&mut remaining_buf)?;
-        assert!(remaining == 0);
+    let expected = [
+        DAMAGED as i64,
+        i64::from(lh.thread_handoff[DAMAGED].luma_y_start),
+        i64::from(lh.thread_handoff[DAMAGED].luma_y_end),
+    ];
 
-        return Ok(hdr_data);
+    for num_threads in [1, 2, 4] {
+        for e in [
+            decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut Vec::new(), num_threads)
+                .unwrap_err(),
+            decode_lepton_wrapper_streaming(
+                &mut Cursor::new(&lepton),
+                &mut Vec::new(),
+                num_threads,
+            )
+            .unwrap_err(),
+        ] {
+            let e = e.root_cause().downcast_ref::<LeptonError>().unwrap();
+            assert_eq!(
+                error_location_fields(&e.message, "Segment {"),
+                expected,
+                "{num_threads} {e}"
+            );
+        }
     }
+}
 
-    pub fn write_lepton_header<W: Write>(&self, writer: &mut W) -> Result<()> {
-        let mut lepton_header = Vec::<u8>::new();
+/// a decoder that writes its output faster than it is written out only gets max_output_buffers pieces ahead per
+/// thread, and still finishes
+#[test]
+fn slow_output_is_bounded() {
+    use std::sync::atomic::AtomicIsize;
+
+    // pieces that the workers have written but that haven't been written out yet, and the most there were
+    static IN_FLIGHT: AtomicIsize = AtomicIsize::new(0);
+    static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+    const PIECES_PER_THREAD: usize = 32;
+    const MAX_OUTPUT_BUFFERS: usize = 2;
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            target_segments: Some(4),
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
 
-        {
-            // Most of the Lepton header data that is compressed before storage
-            // The data contains recovery information (needed for exact JPEG recovery)
-            let mut mrw = Cursor::new(&mut lepton_header);
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    lh.max_output_buffers = MAX_OUTPUT_BUFFERS;
 
-            self.write_lepton_jpeg_header(&mut mrw)?;
-            self.write_lepton_pad_bit(&mut mrw)?;
-            self.write_lepton_luma_splits(&mut mrw)?;
-            self.write_lepton_jpeg_restarts_if_needed(&mut mrw)?;
-            self.write_lepton_jpeg_restart_errors_if_needed(&mut mrw)?;
-            self.write_lepton_early_eof_truncation_data_if_needed(&mut mrw)?;
-            self.write_lepton_jpeg_garbage_if_needed(&mut mrw, false)?;
-        }
+    // make room for a lot more output than the segments have
+    for h in lh.thread_handoff.iter_mut() {
+        h.segment_size = (PIECES_PER_THREAD * OUTPUT_BUFFER_SIZE) as i64;
+    }
 
-        let mut compressed_header = Vec::<u8>::new(); // we collect a zlib compressed version of the header here
-        {
-            let mut c = Cursor::new(&mut compressed_header);
-            let mut encoder = ZlibEncoder::new(&mut c, Compression::default());
+    let remaining_size = get_remaining_size(&mut reader).unwrap();
 
-            encoder.write_all(&lepton_header[..]).context(here!())?;
-            encoder.finish().context(here!())?;
-        }
+    let mut written = 0;
+    let mut results = 0;
+    run_lepton_decoder_threads(
+        &lh,
+        &mut reader,
+        Some(remaining_size),
+        4,
+        |_thread_handoff, _image_data, _lh, output| {
+            for _ in 0..PIECES_PER_THREAD {
+                output.write_all(&[0; OUTPUT_BUFFER_SIZE]).unwrap();
+                let in_flight = IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+                PEAK.fetch_max(in_flight, Ordering::SeqCst);
+            }
+            Ok(())
+        },
+        |output| {
+            match output {
+                DecoderOutput::Data(piece) => {
+                    std::thread::sleep(std::time::Duration::from_millis(2));
+                    IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+                    written += piece.len();
+                }
+                DecoderOutput::Result(()) => results += 1,
+            }
+            Ok(())
+        },
+    )
+    .unwrap();
 
-        writer.write_all(&LEPTON_FILE_HEADER)?;
-        writer.write_u8(LEPTON_VERSION)?;
+    assert_eq!(results, 4);
+    assert_eq!(written, 4 * PIECES_PER_THREAD * OUTPUT_BUFFER_SIZE);
 
-        if self.jpeg_header.jpeg_type == JPegType::Progressive {
-            writer.write_all(&LEPTON_HEADER_PROGRESSIVE_JPEG_TYPE)?;
-        } else {
-            writer.write_all(&LEPTON_HEADER_BASELINE_JPEG_TYPE)?;
-        }
+    // each thread can have a full queue plus the piece that it is waiting to send, and the one being written
+    let peak = PEAK.load(Ordering::SeqCst) as usize;
+    assert!(peak <= 4 * (MAX_OUTPUT_BUFFERS + 2), "{peak}");
 
-        writer.write_u8(self.thread_handoff.len() as u8)?;
-        writer.write_all(&[0; 3])?;
+    // the output failing cancels the threads that are waiting for room for theirs
+    let mut reader = Cursor::new(&lepton);
+    reader.set_position(lepton.len() as u64 - remaining_size);
+    let e = run_lepton_decoder_threads(
+        &lh,
+        &mut reader,
+        Some(remaining_size),
+        4,
+        |_thread_handoff, _image_data, _lh, output| {
+            for _ in 0..PIECES_PER_THREAD {
+                output.write_all(&[0; OUTPUT_BUFFER_SIZE])?;
+            }
+            Ok(())
+        },
+        |_output| err_exit_code(ExitCode::WrapperOutputWriteFailed, "output failed"),
+    )
+    .unwrap_err();
+    assert!(format!("{e:?}").contains("output failed"), "{e:?}");
+}
 
-        // Original lepton format reserves 12 bytes for git revision. We use this space for additional info
-        // that our implementation needs - mark that it's MS implementation and a not-compressed header size.
-        writer.write_u8('M' as u8)?;
-        writer.write_u8('S' as u8)?;
-        writer.write_u32::<LittleEndian>(lepton_header.len() as u32)?;
-        writer.write_all(&[0; 6])?;
+/// a file with more thread segments than threads is decoded in waves, and the data of the segments that no thread
+/// has got to yet is only read so far ahead of the threads
+#[test]
+fn more_segments_than_threads() {
+    const CHUNK_SIZE: usize = 16384;
+    const MAX_READ_AHEAD: u64 = 256 * 1024;
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecity.jpg")).unwrap();
+
+    // the segments of a file with a segment index follow each other, so the data of the later ones can wait
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            target_segments: Some(16),
+            segment_index: true,
+            chunk_size: Some(CHUNK_SIZE),
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
 
-        writer.write_u32::<LittleEndian>(self.jpeg_file_size)?;
-        writer.write_u32::<LittleEndian>(compressed_header.len() as u32)?;
-        writer.write_all(&compressed_header[..])?;
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
+    assert_eq!(lh.thread_handoff.len(), 16);
+    assert!(lepton.len() as u64 > 4 * MAX_READ_AHEAD);
+
+    let mut output = Vec::new();
+    let metrics = decode_lepton_wrapper_with_features(
+        &mut Cursor::new(&lepton),
+        &mut output,
+        2,
+        &EnabledFeatures {
+            max_read_ahead: MAX_READ_AHEAD,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
 
-        writer.write_all(&LEPTON_HEADER_COMPLETION_MARKER)?;
+    assert!(output == original);
 
-        Ok(())
-    }
+    // the reader can go past the budget by a chunk when a thread waits for it
+    let peak = metrics.get_read_ahead_peak();
+    assert!(peak <= MAX_READ_AHEAD + 2 * CHUNK_SIZE as u64, "{peak}");
+}
 
-    fn write_lepton_jpeg_header<W: Write>(&self, mrw: &mut W) -> Result<()> {
-        // write header to file
-        // marker: "HDR" + [size of header]
-        mrw.write_all(&LEPTON_HEADER_MARKER)?;
+/// a grayscale image of width by height blocks with pseudo-random coefficients, split into num_segments thread
+/// segments, for tests that need an image that takes a while to encode without reading a JPEG that large
+#[cfg(test)]
+fn synthetic_image(
+    width: i32,
+    height: i32,
+    num_segments: i32,
+) -> (LeptonHeader, Vec<BlockBasedImage>) {
+    let mut lp = LeptonHeader::new();
 
-        mrw.write_u32::<LittleEndian>(self.raw_jpeg_header.len() as u32)?;
+    let h = &mut lp.jpeg_header;
+    h.cmpc = 1;
+    h.img_width = width * 8;
+    h.img_height = height * 8;
+    h.jpeg_type = JPegType::Sequential;
+    h.sfhm = 1;
+    h.sfvm = 1;
+    h.mcuh = width;
+    h.mcuv = height;
+    h.mcuc = width * height;
+    h.q_tables[0] = [4; 64];
+
+    let ci = &mut h.cmp_info[0];
+    ci.q_table_index = 0;
+    ci.sfv = 1;
+    ci.sfh = 1;
+    ci.mbs = 1;
+    ci.bcv = height;
+    ci.bch = width;
+    ci.bc = width * height;
+    ci.ncv = height;
+    ci.nch = width;
+    ci.nc = width * height;
+    ci.jid = 1;
 
-        // data: data from header
-        mrw.write_all(&self.raw_jpeg_header[..])?;
+    lp.truncate_components.init(&lp.jpeg_header);
 
-        Ok(())
+    lp.thread_handoff = (0..num_segments)
+        .map(|i| ThreadHandoff {
+            luma_y_start: i * height / num_segments,
+            luma_y_end: (i + 1) * height / num_segments,
+            segment_offset_in_file: 0,
+            segment_size: 0,
+            overhang_byte: 0,
+            num_overhang_bits: 0,
+            last_dc: [0; 4],
+        })
+        .collect();
+
+    // xorshift, with smaller coefficients at the higher frequencies
+    let mut state = 1u32;
+    let mut image = BlockBasedImage::new(&lp.jpeg_header, 0, 0, height);
+    for dpos in 0..width * height {
+        let mut block = [0i16; 64];
+        for (i, c) in block.iter_mut().enumerate() {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *c = ((state % 33) as i16 - 16) / (i as i16 / 8 + 1);
+        }
+        image.set_block_data(dpos, &block);
     }
 
-    fn write_lepton_pad_bit<W: Write>(&self, mrw: &mut W) -> Result<()> {
-        // marker: P0D
-        mrw.write_all(&LEPTON_HEADER_PAD_MARKER)?;
+    (lp, vec![image])
+}
 
-        // data: this.padBit
-        mrw.write_u8(self.pad_bit.unwrap_or(0))?;
+/// once an encoder worker fails, the others give up at their next row, so that the error comes back long before
+/// the rest of the image would have been encoded
+#[test]
+fn failed_encoder_worker_cancels_the_others() {
+    let (lp, mut image_data) = synthetic_image(256, 256, 8);
+
+    let pts = ProbabilityTablesSet::new(false, false);
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+    let enabled_features = EnabledFeatures::default();
+
+    let begin = Instant::now();
+    encode_segments_on_workers(
+        &lp,
+        &mut Vec::new(),
+        &image_data,
+        &enabled_features,
+        &pts,
+        &qt,
+        8,
+        &MemoryTracker::new(None),
+        None,
+    )
+    .unwrap();
+    let full = begin.elapsed();
+
+    // a coefficient beyond what the model codes a quarter of the way into segment 0, by which time the other
+    // segments are well under way too
+    let mut block = [0i16; 64];
+    block[1] = i16::MAX;
+    let row = lp.thread_handoff[0].luma_y_end / 4;
+    image_data[0].set_block_data(row * lp.jpeg_header.cmp_info[0].bch, &block);
+
+    let begin = Instant::now();
+    let e = match encode_segments_on_workers(
+        &lp,
+        &mut Vec::new(),
+        &image_data,
+        &enabled_features,
+        &pts,
+        &qt,
+        8,
+        &MemoryTracker::new(None),
+        None,
+    ) {
+        Ok(_) => panic!("the coefficient out of range was encoded"),
+        Err(e) => e,
+    };
+    let failed = begin.elapsed();
+
+    // the error of the segment that failed rather than the one of a segment that was cancelled
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::CoefficientOutOfRange,
+        "{e:?}"
+    );
+    assert!(failed * 2 < full, "{0:?} against {1:?}", failed, full);
+}
 
-        Ok(())
-    }
+/// a budget that leaves room for only two of the eight segments at the same time makes the other workers stop
+/// rather than fail, and the image still encodes to the same file. With the Fail policy, it fails instead.
+#[test]
+fn memory_budget_reduces_concurrency() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("slrcity.jpg")).unwrap();
+
+    let enabled_features = EnabledFeatures {
+        target_segments: Some(8),
+        chunk_size: Some(65536),
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+
+    let (lp, image_data) =
+        read_jpeg(&mut Cursor::new(&original), &enabled_features, 8, |_jh| {}).unwrap();
+    assert_eq!(lp.thread_handoff.len(), 8);
+
+    let pts = ProbabilityTablesSet::new(false, false);
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+    let segment_memory = get_segment_memory(&enabled_features, &lp.thread_handoff);
+
+    let encode = |memory: &MemoryTracker, enabled_features: &EnabledFeatures| {
+        let mut output = Vec::new();
+        encode_segments_on_workers(
+            &lp,
+            &mut output,
+            &image_data,
+            enabled_features,
+            &pts,
+            &qt,
+            8,
+            memory,
+            None,
+        )
+        .map(|_| output)
+    };
+
+    let unlimited = MemoryTracker::new(None);
+    let expected = encode(&unlimited, &enabled_features).unwrap();
+    assert!(unlimited.peak() > 2 * segment_memory);
+
+    let tight = MemoryTracker::new(Some(2 * segment_memory + segment_memory / 2));
+    assert!(encode(&tight, &enabled_features).unwrap() == expected);
+    assert!(tight.peak() <= 2 * segment_memory);
+    assert_eq!(tight.used(), 0);
+
+    let check_error = |e: anyhow::Error| {
+        let e = e
+            .root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap();
+        assert_eq!(e.exit_code, ExitCode::ExceedsMemoryBudget, "{e}");
+    };
+
+    let fail = EnabledFeatures {
+        memory_budget_policy: MemoryBudgetPolicy::Fail,
+        ..enabled_features.clone()
+    };
+    check_error(encode(&MemoryTracker::new(Some(2 * segment_memory)), &fail).unwrap_err());
+
+    // not even a single segment fits
+    check_error(
+        encode(
+            &MemoryTracker::new(Some(segment_memory - 1)),
+            &enabled_features,
+        )
+        .unwrap_err(),
+    );
 
-    fn write_lepton_luma_splits<W: Write>(&self, mrw: &mut W) -> Result<()> {
-        // write luma splits markup HH
-        mrw.write_all(&LEPTON_HEADER_LUMA_SPLIT_MARKER)?;
+    // through the whole encoder, where the image takes its share of the budget
+    let image_memory: u64 = (0..image_data.len())
+        .map(|i| BlockBasedImage::memory_for(&lp.jpeg_header, i))
+        .sum();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            memory_budget: Some(image_memory + 2 * segment_memory),
+            ..enabled_features.clone()
+        },
+    )
+    .unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(output == original);
+
+    // the image by itself doesn't fit, which is found out before it is read
+    check_error(
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(Vec::new()),
+            8,
+            &EnabledFeatures {
+                memory_budget: Some(image_memory - 1),
+                ..enabled_features.clone()
+            },
+        )
+        .unwrap_err(),
+    );
+}
 
-        // data: serialized luma splits
-        ThreadHandoff::serialize(&self.thread_handoff, mrw)?;
+/// the verifier decodes each segment as soon as it is encoded and reports the first block that doesn't come back
+/// as it should, along with its segment
+#[test]
+fn verify_segments_reports_first_mismatch() {
+    use crate::structs::jpeg_read::error_location_fields;
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    let enabled_features = EnabledFeatures {
+        target_segments: Some(4),
+        ..EnabledFeatures::default()
+    };
+
+    let read = || read_jpeg(&mut Cursor::new(&original), &enabled_features, 8, |_jh| {}).unwrap();
+    let (lp, image_data) = read();
+
+    let pts = ProbabilityTablesSet::new(false, false);
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+
+    let encode = |image_data: &[BlockBasedImage], verifier: &SegmentVerifier| {
+        encode_segments_on_workers(
+            &lp,
+            &mut Vec::new(),
+            image_data,
+            &enabled_features,
+            &pts,
+            &qt,
+            8,
+            &MemoryTracker::new(None),
+            Some(verifier),
+        )
+    };
 
-        Ok(())
-    }
+    let verifier = SegmentVerifier::new(&lp, &image_data, &enabled_features).unwrap();
+    encode(&image_data, &verifier).unwrap();
 
-    fn write_lepton_jpeg_restarts_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
-        if self.rst_cnt.len() > 0 {
-            // marker: CRS
-            mrw.write_all(&LEPTON_HEADER_JPG_RESTARTS_MARKER)?;
+    let mut crc = Crc::new();
+    crc.update(&original);
+    verifier
+        .verify_jpeg(crc.sum(), original.len() as u64)
+        .unwrap();
 
-            mrw.write_u32::<LittleEndian>(self.rst_cnt.len() as u32)?;
+    // a block in the middle of segment 2 is encoded with another DC than the one the verifier expects
+    let (_, mut changed) = read();
+    let dpos = (lp.thread_handoff[2].luma_y_start + 1) * lp.jpeg_header.cmp_info[0].bch + 3;
+    let mut block = *changed[0].get_block(dpos).get_block();
+    block[ALIGNED_BLOCK_INDEX_DC_INDEX] += 1;
+    changed[0].set_block_data(dpos, &block);
+
+    let verifier = SegmentVerifier::new(&lp, &image_data, &enabled_features).unwrap();
+    let e = match encode(&changed, &verifier) {
+        Ok(_) => panic!("the changed block was verified"),
+        Err(e) => e,
+    };
+    let e = e
+        .root_cause()
+        .downcast_ref::<crate::lepton_error::LeptonError>()
+        .unwrap();
 
-            for i in 0..self.rst_cnt.len() {
-                mrw.write_u32::<LittleEndian>(self.rst_cnt[i] as u32)?;
-            }
+    assert_eq!(e.exit_code, ExitCode::VerificationContentMismatch, "{e}");
+    assert_eq!(
+        error_location_fields(&e.message, "BlockMismatch {"),
+        [0, i64::from(dpos)]
+    );
+    assert_eq!(error_location_fields(&e.message, "Segment {")[0], 2);
+}
+
+/// the verifier checks the file that was written against the segments it checked, including the segment index,
+/// the segment checksums and the file size that come after them
+#[test]
+fn verify_segments_checks_written_file() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    let enabled_features = EnabledFeatures {
+        target_segments: Some(4),
+        segment_index: true,
+        segment_checksums: true,
+        ..EnabledFeatures::default()
+    };
+
+    let mut crc = Crc::new();
+    crc.update(&original);
+
+    // writes the file, changes the byte at position from the end and checks it
+    let verify = |position: Option<usize>| {
+        let (mut lp, image_data) =
+            read_jpeg(&mut Cursor::new(&original), &enabled_features, 8, |_jh| {}).unwrap();
+        lp.segment_checksums = true;
+        let verifier = SegmentVerifier::new(&lp, &image_data, &enabled_features).unwrap();
+
+        let mut file = Cursor::new(Vec::new());
+        write_lepton_file(
+            &mut lp,
+            &mut file,
+            &image_data,
+            &enabled_features,
+            8,
+            Some(&verifier),
+        )
+        .unwrap();
+
+        let mut file = file.into_inner();
+        if let Some(position) = position {
+            let len = file.len();
+            file[len - position] ^= 1;
         }
 
-        Ok(())
+        verifier.verify_file(&file, crc.sum(), original.len() as u64)
+    };
+
+    verify(None).unwrap();
+
+    // the file size, a segment checksum, the offset of a segment in the index and the data of the last segment
+    for (position, exit_code) in [
+        (1, ExitCode::VerificationContentMismatch),
+        (5, ExitCode::CorruptSegment),
+        (4 + 16 + 3 * 16, ExitCode::VerificationContentMismatch),
+        (4 + 16 + 4 * 16 + 1, ExitCode::VerificationContentMismatch),
+    ] {
+        let e = verify(Some(position)).unwrap_err();
+        let e = e
+            .root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap();
+        assert_eq!(e.exit_code, exit_code, "{e}");
     }
+}
 
-    fn write_lepton_jpeg_restart_errors_if_needed<W: Write>(&self, mrw: &mut W) -> Result<()> {
-        // write number of false set RST markers per scan (if available) to file
-        if self.rst_err.len() > 0 {
-            // marker: "FRS" + [number of scans]
-            mrw.write_all(&LEPTON_HEADER_JPG_RESTART_ERRORS_MARKER)?;
+/// a segment can be decoded on its own using just the header and the segment index
+#[test]
+fn decode_segment_from_index() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecrop.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-            mrw.write_u32::<LittleEndian>(self.rst_err.len() as u32)?;
+    let (_, expected) = read_jpeg(
+        &mut Cursor::new(&original),
+        &EnabledFeatures::all(),
+        8,
+        |_jh| {},
+    )
+    .unwrap();
 
-            mrw.write_all(&self.rst_err[..])?;
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let data_start = reader.position();
+
+    let segment_index = lh.read_segment_index(&mut reader, data_start).unwrap();
+    assert!(segment_index.len() >= 3);
+
+    // wipe out all the other segments to make sure that they aren't needed
+    let segment = segment_index.len() / 2;
+    for (i, entry) in segment_index.iter().enumerate() {
+        if i != segment {
+            let start = data_start as usize + entry.offset as usize;
+            lepton[start..start + entry.length as usize].fill(0);
         }
-
-        Ok(())
     }
 
-    fn write_lepton_early_eof_truncation_data_if_needed<W: Write>(
-        &self,
-        mrw: &mut W,
-    ) -> Result<()> {
-        if self.early_eof_encountered {
-            // EEE marker
-            mrw.write_all(&LEPTON_HEADER_EARLY_EOF_MARKER)?;
+    let (image_data, _metrics) = lh
+        .decode_segment(
+            &mut Cursor::new(&lepton),
+            data_start,
+            &segment_index,
+            segment,
+        )
+        .unwrap();
 
-            mrw.write_i32::<LittleEndian>(self.max_cmp)?;
-            mrw.write_i32::<LittleEndian>(self.max_bpos)?;
-            mrw.write_i32::<LittleEndian>(i32::from(self.max_sah))?;
-            mrw.write_i32::<LittleEndian>(self.max_dpos[0])?;
-            mrw.write_i32::<LittleEndian>(self.max_dpos[1])?;
-            mrw.write_i32::<LittleEndian>(self.max_dpos[2])?;
-            mrw.write_i32::<LittleEndian>(self.max_dpos[3])?;
+    let bcv = lh.jpeg_header.cmp_info[0].bcv;
+    for (i, image) in image_data.iter().enumerate() {
+        let size = lh.jpeg_header.cmp_info[i].bch * lh.jpeg_header.cmp_info[i].bcv;
+        let start = size * segment_index[segment].luma_y_start / bcv;
+        let end = size * segment_index[segment].luma_y_end / bcv;
+        assert!(end > start);
+
+        for dpos in start..end {
+            assert_eq!(
+                image.get_block(dpos).get_block(),
+                expected[i].get_block(dpos).get_block(),
+                "component {i} block {dpos}"
+            );
         }
-
-        Ok(())
     }
+}
 
-    fn write_lepton_jpeg_garbage_if_needed<W: Write>(
-        &self,
-        mrw: &mut W,
-        prefix_garbage: bool,
-    ) -> Result<()> {
-        // write garbage (if any) to file
-        if self.garbage_data.len() > 0 {
-            // marker: "PGR/GRB" + [size of garbage]
-            if prefix_garbage {
-                mrw.write_all(&LEPTON_HEADER_PREFIX_GARBAGE_MARKER)?;
-            } else {
-                mrw.write_all(&LEPTON_HEADER_GARBAGE_MARKER)?;
-            }
+/// the bytes that the encoder counts for each thread segment are the data of the chunks of the segment in the file
+#[test]
+fn segment_bytes_match_segment_index() {
+    let original = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("iphonecrop.jpg"),
+    )
+    .unwrap();
+
+    let mut lepton = Vec::new();
+    let metrics = encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-            mrw.write_u32::<LittleEndian>(self.garbage_data.len() as u32)?;
-            mrw.write_all(&self.garbage_data[..])?;
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let data_start = reader.position();
+    let segment_index = lh.read_segment_index(&mut reader, data_start).unwrap();
+
+    assert!(segment_index.len() >= 3);
+
+    let mut lengths = Vec::new();
+    for entry in &segment_index {
+        let start = (data_start + entry.offset) as usize;
+        let mut rest = &lepton[start..start + entry.length as usize];
+        let mut length = 0;
+        while !rest.is_empty() {
+            let header = ChunkHeader::read(&mut rest).unwrap();
+            rest = &rest[header.length..];
+            length += header.length as u64;
         }
-
-        Ok(())
+        lengths.push(length);
     }
+    assert_eq!(metrics.get_segment_bytes(), &lengths[..]);
+}
 
-    fn parse_jpeg_header<R: Read>(
-        &mut self,
-        reader: &mut R,
-        enabled_features: &EnabledFeatures,
-    ) -> Result<bool> {
-        // the raw header in the lepton file can actually be spread across different sections
-        // seperated by the Start-of-Scan marker. We use the mirror to write out whatever
-        // data we parse until we hit the SOS
-
-        let mut output = Vec::new();
-        let mut output_cursor = Cursor::new(&mut output);
+/// the chunks of a segment are decoded where they are, and a chunk that goes past the end of the segment fails
+/// instead of being read from whatever follows it
+#[test]
+fn decode_segment_chunks_truncated() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecrop.jpg")).unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-        let mut mirror = Mirror::new(reader, &mut output_cursor);
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let data_start = reader.position();
+    let segment_index = lh.read_segment_index(&mut reader, data_start).unwrap();
 
-        if self
-            .jpeg_header
-            .parse(&mut mirror, enabled_features)
-            .context(here!())?
-        {
-            // append the header if it was not the end of file marker
-            self.raw_jpeg_header.append(&mut output);
-            return Ok(true);
-        } else {
-            // if the output was more than 2 bytes then was a trailing header, so keep that around as well,
-            // but we don't want the EOI since that goes into the garbage data.
-            if output.len() > 2 {
-                self.raw_jpeg_header.extend(&output[0..output.len() - 2]);
-            }
+    let entry = &segment_index[1];
+    let start = (data_start + entry.offset) as usize;
+    let chunks = &lepton[start..start + entry.length as usize];
 
-            return Ok(false);
+    let (image_data, _metrics) = lh.decode_segment_chunks(chunks, 1, None).unwrap();
+    let (expected, _metrics) = lh
+        .decode_segment(&mut Cursor::new(&lepton), data_start, &segment_index, 1)
+        .unwrap();
+    let bcv = lh.jpeg_header.cmp_info[0].bcv;
+    for (i, image) in image_data.iter().enumerate() {
+        let size = lh.jpeg_header.cmp_info[i].bch * lh.jpeg_header.cmp_info[i].bcv;
+        for dpos in size * entry.luma_y_start / bcv..size * entry.luma_y_end / bcv {
+            assert_eq!(
+                image.get_block(dpos).get_block(),
+                expected[i].get_block(dpos).get_block()
+            );
         }
     }
+
+    let e = lh
+        .decode_segment_chunks(&chunks[..chunks.len() - 1], 1, None)
+        .err()
+        .unwrap();
+    assert_eq!(
+        e.root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::BadLeptonFile
+    );
 }
 
-fn split_row_handoffs_to_threads(
-    thread_handoffs: &[ThreadHandoff],
-    max_threads_to_use: usize,
-) -> Vec<ThreadHandoff> {
-    let last = thread_handoffs.last().unwrap();
+/// decoding the image in row ranges and stitching them together gives exactly the scan of the full decode
+#[test]
+fn decode_rows_stitched() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    for file in ["iphonecrop", "trailingrst"] {
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures::all(),
+        )
+        .unwrap();
 
-    let framebuffer_byte_size = ThreadHandoff::get_combine_thread_range_segment_size(
-        thread_handoffs.first().unwrap(),
-        last,
-    );
+        let mut full = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut full, 8).unwrap();
 
-    // determine how many threads we need for compression
-    let num_rows = thread_handoffs.len();
-    let num_threads =
-        get_number_of_threads_for_encoding(num_rows, framebuffer_byte_size, max_threads_to_use);
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
 
-    info!("Number of threads: {0}", num_threads);
+        // the scan follows SOI and the header
+        let scan_start = SOI.len() + lh.raw_jpeg_header_read_index;
 
-    let mut selected_splits = Vec::with_capacity(num_threads as usize);
+        let mcuv = lh.truncate_components.mcu_count_vertical;
+        for rows_per_range in [5, 16] {
+            let mut stitched = Vec::new();
+            let mut previous_end = RowBoundary {
+                last_dc: [0; 4],
+                overhang_byte: 0,
+                num_overhang_bits: 0,
+            };
 
-    if num_threads == 1 {
-        // Single thread execution - no split, run on the whole range
-        selected_splits.push(ThreadHandoff::combine_thread_ranges(
-            thread_handoffs.first().unwrap(),
-            last,
-        ));
-    } else {
-        // gbrovman: simplified split logic
-        // Note: rowsPerThread is a floating point value to ensure equal splits
-        let rows_per_thread = num_rows as f32 / num_threads as f32;
+            for start in (0..mcuv).step_by(rows_per_range) {
+                let end = cmp::min(start + rows_per_range as i32, mcuv);
+                let rows = decode_rows_wrapper(&mut Cursor::new(&lepton), start..end).unwrap();
 
-        assert!(rows_per_thread >= 1f32, "rowsPerThread >= 1");
+                assert_eq!(rows.start, previous_end);
+                previous_end = rows.end;
 
-        let mut split_indices = Vec::new();
-        for i in 0..num_threads - 1 {
-            split_indices.push((rows_per_thread * (i as f32 + 1f32)) as usize);
-        }
+                stitched.extend_from_slice(&rows.data[..]);
+            }
 
-        for i in 0..num_threads {
-            let beginning_of_range = if i == 0 { 0 } else { split_indices[i - 1] + 1 };
-            let end_of_range = if i == num_threads - 1 {
-                num_rows - 1
-            } else {
-                split_indices[i]
-            };
-            assert!(end_of_range < num_rows, "endOfRange < numRows");
-            selected_splits.push(ThreadHandoff::combine_thread_ranges(
-                &thread_handoffs[beginning_of_range],
-                &thread_handoffs[end_of_range],
-            ));
+            assert_eq!(previous_end.num_overhang_bits, 0);
+            assert!(
+                stitched[..] == full[scan_start..scan_start + stitched.len()],
+                "{file} with {rows_per_range} rows per range"
+            );
         }
     }
 
-    return selected_splits;
-}
+    // files without a segment index and progressive files aren't supported
+    for (file, enabled_features, expected) in [
+        (
+            "iphonecrop",
+            EnabledFeatures::default(),
+            ExitCode::NoSegmentIndex,
+        ),
+        (
+            "iphoneprogressive",
+            EnabledFeatures::all(),
+            ExitCode::ProgressiveUnsupported,
+        ),
+    ] {
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &enabled_features,
+        )
+        .unwrap();
 
-fn get_number_of_threads_for_encoding(
-    num_rows: usize,
-    framebuffer_byte_size: usize,
-    max_threads_to_use: usize,
-) -> usize {
-    let mut num_threads = cmp::min(max_threads_to_use, MAX_THREADS);
+        let e = decode_rows_wrapper(&mut Cursor::new(&lepton), 0..1).unwrap_err();
+        assert_eq!(
+            e.root_cause()
+                .downcast_ref::<crate::lepton_error::LeptonError>()
+                .unwrap()
+                .exit_code,
+            expected
+        );
+    }
+}
 
-    if num_rows / 2 < num_threads {
-        num_threads = cmp::max(num_rows / 2, 1);
+/// metadata in the JPEG header is stored compressed, since it is part of the zlib compressed lepton header
+#[test]
+fn large_header_is_compressed() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+
+    // insert an XMP segment of about 60KB after SOI, like the ones written by photo editing software
+    let mut xmp =
+        b"http://ns.adobe.com/xap/1.0/\0<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"><rdf:RDF>".to_vec();
+    let mut i = 0;
+    while xmp.len() < 60000 {
+        xmp.extend_from_slice(
+            format!("<rdf:li stEvt:action=\"saved\" stEvt:instanceID=\"xmp.iid:{i:08x}\"/>")
+                .as_bytes(),
+        );
+        i += 1;
     }
+    xmp.extend_from_slice(b"</rdf:RDF></x:xmpmeta>");
+
+    let mut with_xmp = SOI.to_vec();
+    with_xmp.extend_from_slice(&[0xff, jpeg_code::APP0 + 1]);
+    with_xmp.extend_from_slice(&(xmp.len() as u16 + 2).to_be_bytes());
+    with_xmp.extend_from_slice(&xmp[..]);
+    with_xmp.extend_from_slice(&original[2..]);
+
+    let mut sizes = Vec::new();
+    for jpeg in [&original, &with_xmp] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(jpeg),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures::all(),
+        )
+        .unwrap();
 
-    if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD {
-        num_threads = 1;
-    } else if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD * 2 {
-        num_threads = cmp::min(2, num_threads);
-    } else if framebuffer_byte_size < SMALL_FILE_BYTES_PER_ENCDOING_THREAD * 4 {
-        num_threads = cmp::min(4, num_threads);
+        let mut output = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+        assert!(output == *jpeg);
+
+        sizes.push(lepton.len());
     }
 
-    return num_threads;
+    assert!(
+        sizes[1] - sizes[0] < xmp.len() / 5,
+        "{0} bytes of XMP took {1} bytes",
+        xmp.len(),
+        sizes[1] - sizes[0]
+    );
 }
 
-enum Message {
-    Eof,
-    WriteBlock(u8, Vec<u8>),
-}
+/// an undersized output buffer is rejected right after reading the header, before any of the scan data
+/// is touched, so no decoding threads are started for a call that can't succeed
+#[test]
+fn decode_into_buffer_too_small() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("android.jpg")).unwrap();
+    let lepton = std::fs::read(path.join("android.lep")).unwrap();
 
-struct MessageSender {
-    thread_id: u8,
-    sender: Sender<Message>,
-    buffer: Vec<u8>,
+    // cut the file off right after the header, so that the call would fail differently if it tried to decode
+    let mut lh = LeptonHeader::new();
+    let mut reader = Cursor::new(&lepton);
+    lh.read_lepton_header(&mut reader).unwrap();
+    let header_only = &lepton[..reader.position() as usize];
+
+    let mut output = vec![0u8; original.len() - 1];
+    let mut result_size = 0;
+    let r = decode_lepton_wrapper_into(
+        &mut Cursor::new(header_only),
+        &mut output[..],
+        8,
+        &mut result_size,
+    );
+
+    assert_eq!(
+        r.unwrap_err()
+            .root_cause()
+            .downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::BufferTooSmall
+    );
+    assert_eq!(result_size, original.len() as u64);
+
+    // with the exact size it succeeds
+    let mut output = vec![0u8; original.len()];
+    decode_lepton_wrapper_into(
+        &mut Cursor::new(&lepton),
+        &mut output[..],
+        8,
+        &mut result_size,
+    )
+    .unwrap();
+    assert_eq!(result_size, original.len() as u64);
+    assert!(output[..] == original[..]);
 }
 
-const WRITE_BUFFER_SIZE: usize = 65536;
+/// the recreated JPEG is checked against the CRC of the original file while it is being written out
+#[test]
+fn verify_original_file_crc() {
+    for file in ["android", "androidprogressive"] {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+        let original = std::fs::read(path.join(file.to_owned() + ".jpg")).unwrap();
+        let lepton = std::fs::read(path.join(file.to_owned() + ".lep")).unwrap();
 
-impl Write for MessageSender {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let mut copy_start = 0;
-        while copy_start < buf.len() {
-            let amount_to_copy = cmp::min(
-                WRITE_BUFFER_SIZE - self.buffer.len(),
-                buf.len() - copy_start,
-            );
-            self.buffer
-                .extend_from_slice(&buf[copy_start..copy_start + amount_to_copy]);
+        let mut crc = Crc::new();
+        crc.update(&original[..]);
 
-            if self.buffer.len() == WRITE_BUFFER_SIZE {
-                self.flush()?;
-            }
+        for (expected_crc, ok) in [(crc.sum(), true), (crc.sum() ^ 1, false)] {
+            let mut reader = Cursor::new(&lepton);
 
-            copy_start += amount_to_copy;
-        }
+            let mut lh = LeptonHeader::new();
+            lh.read_lepton_header(&mut reader).unwrap();
+            lh.original_file_crc = Some(expected_crc);
 
-        Ok(buf.len())
-    }
+            let remaining_size = get_remaining_size(&mut reader).unwrap();
 
-    fn flush(&mut self) -> std::io::Result<()> {
-        if self.buffer.len() > 0 {
-            let mut new_buffer = Vec::with_capacity(WRITE_BUFFER_SIZE);
-            swap(&mut new_buffer, &mut self.buffer);
+            let mut output = Vec::new();
+            let r = lh.recode_jpeg(&mut output, &mut reader, Some(remaining_size), 8);
 
-            self.sender
-                .send(Message::WriteBlock(self.thread_id, new_buffer))
-                .unwrap();
+            if ok {
+                r.unwrap();
+                assert!(output[..] == original[..]);
+            } else {
+                assert_eq!(
+                    r.unwrap_err()
+                        .root_cause()
+                        .downcast_ref::<crate::lepton_error::LeptonError>()
+                        .unwrap()
+                        .exit_code,
+                    ExitCode::VerificationContentMismatch
+                );
+            }
         }
-        Ok(())
     }
 }
 
-/// used by the worker thread to read data for the given thread from the
-/// receiver. The thread_id is used only to assert that we are only
-/// getting the data that we are expecting
-struct MessageReceiver {
-    /// the multiplexed thread stream we are processing
-    thread_id: u8,
+/// in a file with a segment index the output of the first segments is written while the rest of the file
+/// is still on its way
+#[test]
+fn decode_streaming_before_end_of_input() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
-    /// the receiver part of the channel to get more buffers
-    receiver: Receiver<Message>,
+    struct ChannelReader {
+        receiver: Receiver<Vec<u8>>,
+        current: Cursor<Vec<u8>>,
+    }
 
-    /// what we are reading. When this returns zero, we try to
-    /// refill the buffer if we haven't reached the end of the stream
-    current_buffer: Cursor<Vec<u8>>,
+    impl Read for ChannelReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            loop {
+                let n = self.current.read(buf)?;
+                if n > 0 {
+                    return Ok(n);
+                }
+                match self.receiver.recv() {
+                    Ok(data) => self.current = Cursor::new(data),
+                    Err(_) => return Ok(0),
+                }
+            }
+        }
+    }
 
-    /// once we get told we are at the end of the stream, we just
-    /// always return 0 bytes
-    end_of_file: bool,
-}
+    struct SharedWriter(Arc<Mutex<Vec<u8>>>);
 
-impl Read for MessageReceiver {
-    /// fast path for reads. If we get zero bytes, take the slow path
-    #[inline(always)]
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let amount_read = self.current_buffer.read(buf)?;
-        if amount_read > 0 {
-            return Ok(amount_read);
+    impl Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
         }
 
-        self.read_slow(buf)
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
     }
-}
 
-impl MessageReceiver {
-    /// slow path for reads, try to get a new buffer or
-    /// return zero if at the end of the stream
-    #[cold]
-    #[inline(never)]
-    fn read_slow(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        while !self.end_of_file {
-            let amount_read = self.current_buffer.read(buf)?;
-            if amount_read > 0 {
-                return Ok(amount_read);
-            }
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join("iphonecrop.jpg")).unwrap();
 
-            match self.receiver.recv() {
-                Ok(r) => match r {
-                    Message::Eof => {
-                        self.end_of_file = true;
-                    }
-                    Message::WriteBlock(tid, block) => {
-                        debug_assert_eq!(
-                            tid, self.thread_id,
-                            "incoming thread must be equal to processing thread"
-                        );
-                        self.current_buffer = Cursor::new(block);
-                    }
-                },
-                Err(e) => {
-                    return Result::Err(std::io::Error::new(std::io::ErrorKind::Other, e));
-                }
-            }
-        }
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
 
-        // nothing if we reached the end of file
-        return Ok(0);
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let data_start = reader.position();
+
+    let segment_index = lh.read_segment_index(&mut reader, data_start).unwrap();
+    assert!(segment_index.len() >= 3);
+
+    // everything up to the end of the first chunk of the third segment, which finishes the first two
+    let mut third_segment =
+        Cursor::new(&lepton[data_start as usize + segment_index[2].offset as usize..]);
+    let chunk_length = ChunkHeader::read(&mut third_segment).unwrap().length;
+    let available = data_start as usize
+        + segment_index[2].offset as usize
+        + third_segment.position() as usize
+        + chunk_length;
+    assert!(available < lepton.len());
+    let expected_output = 2
+        + lh.raw_jpeg_header_read_index
+        + (lh.thread_handoff[0].segment_size + lh.thread_handoff[1].segment_size) as usize;
+
+    let (sender, receiver) = channel();
+    let output = Arc::new(Mutex::new(Vec::new()));
+
+    let decoder = {
+        let output = output.clone();
+        std::thread::spawn(move || {
+            let mut reader = ChannelReader {
+                receiver,
+                current: Cursor::new(Vec::new()),
+            };
+            decode_lepton_wrapper_streaming(&mut reader, &mut SharedWriter(output), 8)
+        })
+    };
+
+    sender.send(lepton[..available].to_vec()).unwrap();
+
+    let wall_time = Instant::now();
+    while output.lock().unwrap().len() < expected_output {
+        assert!(
+            wall_time.elapsed() < Duration::from_secs(60),
+            "output didn't start before the end of the input"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    {
+        let output = output.lock().unwrap();
+        assert!(output[..] == original[..output.len()]);
     }
-}
 
-// internal utility we use to collect the header that we read for later
-struct Mirror<'a, R, W> {
-    read: &'a mut R,
-    output: &'a mut W,
-    amount_written: usize,
+    sender.send(lepton[available..].to_vec()).unwrap();
+    drop(sender);
+
+    decoder.join().unwrap().unwrap();
+    assert!(output.lock().unwrap()[..] == original[..]);
 }
 
-impl<'a, R, W> Mirror<'a, R, W> {
-    pub fn new(read: &'a mut R, output: &'a mut W) -> Self {
-        Mirror {
-            read,
-            output,
-            amount_written: 0,
+/// the rows are split so that the segments cost about the same, each of them with at least one row, and rows
+/// that cost the same are split evenly
+#[test]
+fn balanced_segments_split_by_cost() {
+    let segment_costs = |row_costs: &[u64], range_starts: &[usize]| -> Vec<u64> {
+        let mut ends = range_starts[1..].to_vec();
+        ends.push(row_costs.len());
+        range_starts
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| {
+                assert!(*start < end);
+                row_costs[*start..end].iter().sum()
+            })
+            .collect()
+    };
+
+    // a flat top and a busy bottom
+    let mut row_costs = vec![10u64; 60];
+    row_costs.extend_from_slice(&[1000u64; 40]);
+    let total: u64 = row_costs.iter().sum();
+
+    for num_threads in [2, 4, 8, 16] {
+        let balanced = segment_costs(&row_costs, &balanced_range_starts(&row_costs, num_threads));
+        let even = segment_costs(&row_costs, &even_range_starts(100, num_threads));
+
+        // no segment is off by more than a row from its share
+        let share = total / num_threads as u64;
+        for c in &balanced {
+            assert!(c.abs_diff(share) <= 1000, "{num_threads} {balanced:?}");
         }
+        assert!(balanced.iter().max() < even.iter().max());
     }
-}
 
-impl<R: Read, W: Write> Read for Mirror<'_, R, W> {
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let n = self.read.read(buf)?;
-        self.output.write_all(&buf[..n])?;
-        self.amount_written += n;
-        Ok(n)
-    }
+    assert_eq!(balanced_range_starts(&[7u64; 12], 4), vec![0, 3, 6, 9]);
+
+    // every segment gets a row even if all the cost is in one of them, or there is no cost at all
+    let mut row_costs = vec![0u64; 10];
+    row_costs[9] = 1000;
+    assert_eq!(balanced_range_starts(&row_costs, 4), vec![0, 7, 8, 9]);
+    assert_eq!(balanced_range_starts(&[0u64; 4], 4), vec![0, 1, 2, 3]);
+    assert_eq!(balanced_range_starts(&[5u64; 3], 1), vec![0]);
 }
 
-// false means we hit the end of file marker
-fn prepare_to_decode_next_scan<R: Read>(
-    lp: &mut LeptonHeader,
-    reader: &mut R,
-    enabled_features: &EnabledFeatures,
-) -> Result<bool> {
-    // parse the header and store it in the raw_jpeg_header
-    if !lp
-        .parse_jpeg_header(reader, enabled_features)
-        .context(here!())?
-    {
-        return Ok(false);
-    }
+/// the image with its top half made flat, and the splits of its rows into num_segments segments by number and by
+/// cost
+#[cfg(test)]
+fn flat_top_image(
+    file: &str,
+    num_segments: usize,
+) -> (
+    LeptonHeader,
+    Vec<BlockBasedImage>,
+    Vec<ThreadHandoff>,
+    Vec<ThreadHandoff>,
+) {
+    use crate::structs::block_based_image::AlignedBlock;
 
-    lp.max_bpos = cmp::max(lp.max_bpos, lp.jpeg_header.cs_to as i32);
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+    let original = std::fs::read(path.join(file)).unwrap();
 
-    // FIXME: not sure why only first bit of csSah is examined but 4 bits of it are stored
-    lp.max_sah = cmp::max(
-        lp.max_sah,
-        cmp::max(lp.jpeg_header.cs_sal, lp.jpeg_header.cs_sah),
-    );
+    let (lp, mut image_data, row_handoffs) = read_jpeg_rows(
+        &mut Cursor::new(&original),
+        &EnabledFeatures::default(),
+        |_jh| {},
+    )
+    .unwrap();
 
-    for i in 0..lp.jpeg_header.cs_cmpc {
-        lp.max_cmp = cmp::max(lp.max_cmp, lp.jpeg_header.cs_cmp[i] as i32);
+    for image in image_data.iter_mut() {
+        let blocks = image.get_block_width() * image.get_original_height();
+        for dpos in 0..blocks / 2 {
+            *image.get_block_mut(dpos) = AlignedBlock::default();
+        }
     }
 
-    return Ok(true);
+    let row_costs = estimate_row_costs(&row_handoffs, &lp.jpeg_header, &image_data);
+    let even = split_row_handoffs_to_threads(&row_handoffs, None, num_segments, Some(num_segments));
+    let balanced = split_row_handoffs_to_threads(
+        &row_handoffs,
+        Some(&row_costs),
+        num_segments,
+        Some(num_segments),
+    );
+
+    (lp, image_data, even, balanced)
 }
 
-fn set_segment_size_in_row_thread_handoffs(
-    thread_handoffs: &mut [ThreadHandoff],
-    entropy_data_end_offset_in_file: i32,
-) {
-    if thread_handoffs.len() != 0 {
-        for i in 0..thread_handoffs.len() - 1 {
-            thread_handoffs[i].segment_size = thread_handoffs[i + 1].segment_offset_in_file
-                - thread_handoffs[i].segment_offset_in_file;
-        }
+/// a flat top half costs next to nothing, so the segments split by cost put most of the rows into the segments
+/// that cover it
+#[test]
+fn flat_top_image_is_split_by_cost() {
+    let (lp, _image_data, even, balanced) = flat_top_image("iphonecity.jpg", 4);
 
-        thread_handoffs[thread_handoffs.len() - 1].segment_size = entropy_data_end_offset_in_file
-            - thread_handoffs[thread_handoffs.len() - 1].segment_offset_in_file;
+    let bcv = lp.jpeg_header.cmp_info[0].bcv;
+    assert!(even[0].luma_y_end <= bcv / 4 + 16);
+    assert!(balanced[0].luma_y_end > bcv / 2);
+
+    // the segments still cover the image without gaps
+    assert_eq!(balanced[0].luma_y_start, 0);
+    for pair in balanced.windows(2) {
+        assert_eq!(pair[0].luma_y_end, pair[1].luma_y_start);
     }
+    assert_eq!(balanced[3].luma_y_end, bcv);
 }
 
-// test serializing and deserializing header
+/// images with fewer blocks than single_segment_max_blocks are encoded as a single segment whatever the number of
+/// threads, unless the number of segments is given
 #[test]
-fn parse_and_write_header() {
-    // minimal jpeg that will pass the validity read tests
-    let min_jpeg = [
-        0xffu8, 0xe0, // APP0
-        0x00, 0x10, 0x4a, 0x46, 0x49, 0x46, 0x00, 0x01, 0x01, 0x01, 0x00, 0x48, 0x00, 0x48, 0x00,
-        0x00, 0xff, 0xdb, // DQT
-        0x00, 0x43, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x02, 0x03, 0x02, 0x02, 0x02, 0x03, 0x03,
-        0x03, 0x03, 0x04, 0x06, 0x04, 0x04, 0x04, 0x04, 0x04, 0x08, 0x06, 0x06, 0x05, 0x06, 0x09,
-        0x08, 0x0a, 0x0a, 0x09, 0x08, 0x09, 0x09, 0x0a, 0x0c, 0x0f, 0x0c, 0x0a, 0x0b, 0x0e, 0x0b,
-        0x09, 0x09, 0x0d, 0x11, 0x0d, 0x0e, 0x0f, 0x10, 0x10, 0x11, 0x10, 0x0a, 0x0c, 0x12, 0x13,
-        0x12, 0x10, 0x13, 0x0f, 0x10, 0x10, 0x10, 0xff, 0xC1, 0x00, 0x0b, 0x08, 0x00,
-        0x10, // width
-        0x00, 0x10, // height
-        0x01, // cmpc
-        0x01, // Jid
-        0x11, // sfv / sfh
-        0x00, 0xff, 0xda, // SOS
-        0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3f, 0x00, 0xd2, 0xcf, 0x20, 0xff, 0xd9, // EOI
-    ];
+fn single_segment_below_threshold() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    // enough scan data to be split across threads otherwise
+    let original = std::fs::read(path.join("iphonecrop.jpg")).unwrap();
+
+    let (lp, _image_data) = read_jpeg(
+        &mut Cursor::new(&original),
+        &EnabledFeatures {
+            single_segment_max_blocks: 0,
+            ..EnabledFeatures::default()
+        },
+        8,
+        |_jh| {},
+    )
+    .unwrap();
+    let segments = lp.thread_handoff.len();
+    assert!(segments > 1);
+
+    let num_blocks: u32 = lp.jpeg_header.cmp_info[..lp.jpeg_header.cmpc]
+        .iter()
+        .map(|ci| ci.bch * ci.bcv)
+        .sum::<i32>() as u32;
+    assert!(num_blocks >= EnabledFeatures::default().single_segment_max_blocks);
+
+    for (single_segment_max_blocks, target_segments, deterministic, expected) in [
+        (0, None, false, segments),
+        (num_blocks, None, false, segments),
+        (num_blocks + 1, None, false, 1),
+        (num_blocks + 1, None, true, 1),
+        (num_blocks + 1, Some(3), false, 3),
+        (
+            EnabledFeatures::default().single_segment_max_blocks,
+            None,
+            false,
+            segments,
+        ),
+    ] {
+        let mut lepton = Vec::new();
+        encode_lepton_wrapper(
+            &mut Cursor::new(&original),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                single_segment_max_blocks,
+                target_segments,
+                deterministic,
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
 
-    let mut lh = LeptonHeader::new();
-    lh.jpeg_file_size = 123;
+        let mut lh = LeptonHeader::new();
+        lh.read_lepton_header(&mut Cursor::new(&lepton)).unwrap();
+        assert_eq!(
+            lh.thread_handoff.len(),
+            expected,
+            "{single_segment_max_blocks} {target_segments:?}"
+        );
 
-    lh.parse_jpeg_header(&mut Cursor::new(min_jpeg), &EnabledFeatures::all())
-        .unwrap();
-    lh.thread_handoff.push(ThreadHandoff {
-        luma_y_start: 0,
-        luma_y_end: 1,
-        segment_offset_in_file: 0,
-        segment_size: 1000,
-        overhang_byte: 0,
-        num_overhang_bits: 1,
-        last_dc: [1, 2, 3, 4],
-    });
+        let mut output = Vec::new();
+        decode_lepton_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+        assert!(output == original);
+    }
+}
 
-    let mut serialized = Vec::new();
-    lh.write_lepton_header(&mut Cursor::new(&mut serialized))
-        .unwrap();
+/// encodes the image as a single segment on the calling thread and on a worker, which write the same
+#[cfg(test)]
+fn encode_single_segment_both_ways(
+    jpeg: &[u8],
+    enabled_features: &EnabledFeatures,
+) -> ((Vec<u8>, EncodedSegments), (Vec<u8>, EncodedSegments)) {
+    let (lp, image_data) = read_jpeg(
+        &mut Cursor::new(jpeg),
+        &EnabledFeatures {
+            target_segments: Some(1),
+            ..enabled_features.clone()
+        },
+        1,
+        |_jh| {},
+    )
+    .unwrap();
 
-    let mut other = LeptonHeader::new();
-    let mut other_reader = Cursor::new(&serialized);
-    other.read_lepton_header(&mut other_reader).unwrap();
+    let pts = ProbabilityTablesSet::new(
+        enabled_features.get_quantized_dc_prediction(),
+        enabled_features.get_fast_dc_prediction(),
+    );
+    let qt = get_quantization_tables(&lp.jpeg_header).unwrap();
+
+    let mut calling_thread_output = Vec::new();
+    let calling_thread = encode_segment_on_calling_thread(
+        &lp,
+        &mut calling_thread_output,
+        &image_data,
+        enabled_features,
+        &pts,
+        &qt,
+    )
+    .unwrap();
+
+    let mut workers_output = Vec::new();
+    let workers = encode_segments_on_workers(
+        &lp,
+        &mut workers_output,
+        &image_data,
+        enabled_features,
+        &pts,
+        &qt,
+        8,
+        &MemoryTracker::new(None),
+        None,
+    )
+    .unwrap();
+
+    (
+        (calling_thread_output, calling_thread),
+        (workers_output, workers),
+    )
+}
+
+/// a single segment encoded on the calling thread comes out in the same chunks as from a worker
+#[test]
+fn single_segment_on_calling_thread_matches_worker() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    for file in ["tiny.jpg", "android.jpg", "iphoneprogressive.jpg"] {
+        let original = std::fs::read(path.join(file)).unwrap();
+
+        for enabled_features in [
+            EnabledFeatures::default(),
+            EnabledFeatures::all(),
+            EnabledFeatures {
+                deterministic: true,
+                chunk_size: Some(1000),
+                ..EnabledFeatures::default()
+            },
+        ] {
+            let ((a_output, a), (b_output, b)) =
+                encode_single_segment_both_ways(&original, &enabled_features);
+
+            assert!(a_output == b_output, "{file}");
+            assert!(a.segment_data == b.segment_data, "{file}");
+            assert_eq!(a.sizes, b.sizes, "{file}");
+            assert_eq!(a.segment_crcs[0].sum(), b.segment_crcs[0].sum(), "{file}");
+            assert_eq!(a.metrics.get_segment_bytes(), &a.sizes[..], "{file}");
+
+            // the data of the segment is either written out right away or held back for the segment index
+            assert!(
+                !(a_output.is_empty() && a.segment_data[0].is_empty()),
+                "{file}"
+            );
+        }
+    }
 }
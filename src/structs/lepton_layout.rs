@@ -0,0 +1,271 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+
+use crate::consts::{EOI, LEPTON_FILE_HEADER, LEPTON_HEADER_COMPLETION_MARKER, LEPTON_VERSION};
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::multiplexer::format::ChunkHeader;
+use crate::structs::lepton_format::{LeptonHeader, SegmentIndexEntry};
+
+/// size of the fixed part of the header up to and including the compressed header size
+const FIXED_HEADER_SIZE: usize = 28;
+
+/// where the space in a lepton file goes, for debugging and storage accounting
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeptonLayout {
+    pub file_size: u64,
+    /// everything in front of the thread segments, including the compressed header
+    pub header_size: u64,
+    pub compressed_header_size: u64,
+    /// size of the JPEG the file decodes to
+    pub original_file_size: u64,
+    pub segments: Vec<SegmentLayout>,
+    /// data after the end of the JPEG image that is stored as is in the header
+    pub garbage_size: u64,
+    /// size of the segment index if the file has one
+    pub segment_index_size: u64,
+    /// size of the table of checksums of the thread segments if the file has one
+    pub segment_checksums_size: u64,
+    /// the file only keeps what is needed to recreate the image, so it doesn't decode to the original file
+    /// byte for byte (EncodeMode::CoefficientsOnly)
+    pub coefficients_only: bool,
+    /// the file ends before all of the above could be read, in which case the sizes cover what was there
+    pub truncated: bool,
+}
+
+/// compressed size of the output of one encoder thread
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SegmentLayout {
+    pub luma_y_start: i32,
+    pub luma_y_end: i32,
+    /// size of the original JPEG scan data that the segment decodes to
+    pub original_size: u64,
+    /// size of the arithmetic coded data of the segment, not including the framing
+    pub compressed_size: u64,
+    /// number of chunks the data is spread over in the multiplexed stream
+    pub chunks: u64,
+    /// size of the thread id and length in front of each chunk
+    pub framing_size: u64,
+}
+
+/// walks the header and the framing of the multiplexed thread segments without decoding any of the coded data.
+/// Truncated files are reported with what could be read, anything else that doesn't look like a lepton file
+/// is an error.
+pub fn inspect_lepton_structure_wrapper(data: &[u8]) -> Result<LeptonLayout> {
+    let mut layout = LeptonLayout {
+        file_size: data.len() as u64,
+        ..LeptonLayout::default()
+    };
+
+    // check what there is of the magic and version so that other files aren't mistaken for truncated ones
+    let mut magic = LEPTON_FILE_HEADER.to_vec();
+    magic.push(LEPTON_VERSION);
+    let prefix_len = data.len().min(magic.len());
+    if data.is_empty() || data[..prefix_len] != magic[..prefix_len] {
+        return err_exit_code(ExitCode::NotLepton, "not a lepton file");
+    }
+
+    if data.len() < FIXED_HEADER_SIZE {
+        layout.truncated = true;
+        return Ok(layout);
+    }
+
+    layout.compressed_header_size = u64::from(u32::from_le_bytes(
+        data[FIXED_HEADER_SIZE - 4..FIXED_HEADER_SIZE].try_into()?,
+    ));
+
+    let header_size = FIXED_HEADER_SIZE as u64
+        + layout.compressed_header_size
+        + LEPTON_HEADER_COMPLETION_MARKER.len() as u64;
+    if header_size > data.len() as u64 {
+        layout.truncated = true;
+        return Ok(layout);
+    }
+
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(data))
+        .context(here!())?;
+
+    layout.header_size = header_size;
+    layout.original_file_size = lh.plain_text_size;
+    layout.coefficients_only = lh.coefficients_only;
+    layout.garbage_size = if lh.garbage_data.starts_with(&EOI) {
+        lh.garbage_data.len() - EOI.len()
+    } else {
+        lh.garbage_data.len()
+    } as u64;
+
+    // the original follows the header as is, without any thread segments
+    if let Some(size) = lh.passthrough_size {
+        layout.truncated = header_size + size + lh.get_file_size_len() > data.len() as u64;
+        return Ok(layout);
+    }
+
+    for th in &lh.thread_handoff {
+        layout.segments.push(SegmentLayout {
+            luma_y_start: th.luma_y_start,
+            luma_y_end: th.luma_y_end,
+            original_size: th.segment_size as u64,
+            ..SegmentLayout::default()
+        });
+    }
+
+    // the file ends with its own size, which tells us if anything is missing
+    let file_size_len = lh.get_file_size_len() as usize;
+    let complete = data.len() >= file_size_len
+        && read_file_size(&data[data.len() - file_size_len..]) == data.len() as u64;
+
+    layout.segment_checksums_size = lh.get_segment_checksums_len();
+
+    let data_end = match lh.segment_index_offset {
+        Some(offset) => {
+            layout.segment_index_size =
+                SegmentIndexEntry::serialized_size(lh.large_sizes) * lh.thread_handoff.len() as u64;
+            header_size + offset
+        }
+        None if complete => {
+            ((data.len() - file_size_len) as u64).saturating_sub(layout.segment_checksums_size)
+        }
+        None => data.len() as u64,
+    };
+
+    layout.truncated = !complete || data_end > data.len() as u64;
+
+    let data_end = data_end.min(data.len() as u64);
+    let mut reader = Cursor::new(&data[..data_end as usize]);
+    reader.set_position(header_size);
+
+    while reader.position() < data_end {
+        let chunk_start = reader.position();
+        let ChunkHeader {
+            thread_id,
+            length: data_length,
+        } = match ChunkHeader::read(&mut reader) {
+            Ok(h) => h,
+            Err(_) => {
+                layout.truncated = true;
+                break;
+            }
+        };
+
+        let segment = match layout.segments.get_mut(usize::from(thread_id)) {
+            Some(segment) => segment,
+            None => {
+                return err_exit_code(
+                    ExitCode::BadLeptonFile,
+                    format!("invalid thread_id {0} at {1}", thread_id, reader.position()).as_str(),
+                );
+            }
+        };
+
+        segment.chunks += 1;
+        segment.framing_size += reader.position() - chunk_start;
+
+        if reader.position() + data_length as u64 > data_end {
+            // count the part of the chunk that is there
+            segment.compressed_size += data_end - reader.position();
+            layout.truncated = true;
+            break;
+        }
+
+        segment.compressed_size += data_length as u64;
+        reader.set_position(reader.position() + data_length as u64);
+    }
+
+    Ok(layout)
+}
+
+/// the little endian file size at the end of the file, which is 4 or 8 bytes long
+fn read_file_size(data: &[u8]) -> u64 {
+    data.iter()
+        .rev()
+        .fold(0, |size, &b| (size << 8) | u64::from(b))
+}
+
+#[cfg(test)]
+use crate::enabled_features::EnabledFeatures;
+#[cfg(test)]
+use crate::structs::lepton_format::encode_lepton_wrapper;
+
+/// all the bytes of a file are accounted for, and truncated files report what is there
+#[test]
+fn inspect_layout() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("images");
+
+    // written by the C++ implementation, with a trailing garbage test file that has several segments
+    for file in ["android", "iphonecity_with_16KGarbage"] {
+        let lepton = std::fs::read(path.join(file.to_owned() + ".lep")).unwrap();
+        let original_size = std::fs::metadata(path.join(file.to_owned() + ".jpg"))
+            .unwrap()
+            .len();
+
+        let layout = inspect_lepton_structure_wrapper(&lepton).unwrap();
+        assert!(!layout.truncated, "{file}");
+        assert_eq!(layout.original_file_size, original_size);
+
+        let framing: u64 = layout.segments.iter().map(|s| s.framing_size).sum();
+        let compressed: u64 = layout.segments.iter().map(|s| s.compressed_size).sum();
+        assert_eq!(
+            layout.header_size + framing + compressed + 4,
+            lepton.len() as u64,
+            "{file}"
+        );
+
+        if file == "iphonecity_with_16KGarbage" {
+            assert!(layout.segments.len() > 1);
+            assert_eq!(layout.garbage_size, 16 * 1024);
+        }
+
+        // cut off in the middle of the segments
+        let truncated = inspect_lepton_structure_wrapper(&lepton[..lepton.len() / 2]).unwrap();
+        assert!(truncated.truncated);
+        assert_eq!(truncated.header_size, layout.header_size);
+        let truncated_compressed: u64 = truncated.segments.iter().map(|s| s.compressed_size).sum();
+        assert!(truncated_compressed > 0 && truncated_compressed < compressed);
+
+        // cut off inside the header
+        let truncated = inspect_lepton_structure_wrapper(&lepton[..40]).unwrap();
+        assert!(truncated.truncated);
+        assert_eq!(
+            truncated.compressed_header_size,
+            layout.compressed_header_size
+        );
+        assert_eq!(truncated.segments.len(), 0);
+    }
+
+    // with a segment index after the segments
+    let original = std::fs::read(path.join("iphonecrop.jpg")).unwrap();
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
+
+    let layout = inspect_lepton_structure_wrapper(&lepton).unwrap();
+    assert!(!layout.truncated);
+
+    let framing: u64 = layout.segments.iter().map(|s| s.framing_size).sum();
+    let compressed: u64 = layout.segments.iter().map(|s| s.compressed_size).sum();
+    assert_eq!(
+        layout.header_size
+            + framing
+            + compressed
+            + layout.segment_index_size
+            + layout.segment_checksums_size
+            + 4,
+        lepton.len() as u64
+    );
+
+    // not a lepton file at all
+    assert!(inspect_lepton_structure_wrapper(&original).is_err());
+}
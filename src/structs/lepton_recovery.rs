@@ -0,0 +1,404 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::cmp;
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Context, Result};
+use flate2::Crc;
+
+use crate::consts::{JPegType, SOI};
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::structs::bit_writer::BitWriter;
+use crate::structs::block_based_image::BlockBasedImage;
+use crate::structs::crc_reader::CrcWriter;
+use crate::structs::jpeg_write::jpeg_write_row_range;
+use crate::structs::lepton_decoder::lepton_decode_row_range;
+use crate::structs::lepton_format::{
+    get_quantization_tables, get_remaining_size, ChunkReader, LeptonHeader,
+};
+use crate::structs::probability_tables_set::ProbabilityTablesSet;
+use crate::structs::quantization_tables::QuantizationTables;
+use crate::structs::thread_pool;
+
+/// the parts of the image that decode_lepton_lenient couldn't recover
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DamageReport {
+    /// MCU rows of the thread segments that failed to decode, which are filled with gray blocks in the output
+    pub damaged_mcu_rows: Vec<Range<i32>>,
+    /// whether the output matches the CRC of the original file, None if the file doesn't have one
+    pub checksum_matches: Option<bool>,
+    /// thread segments whose data doesn't match the checksum stored for it, which aren't decoded at all
+    pub corrupt_segments: Vec<usize>,
+}
+
+/// decodes a lepton file like decode_lepton_wrapper, but decodes each thread segment on its own so that a
+/// corrupted segment doesn't take the rest of the image with it. A segment fails if its data doesn't match its
+/// checksum (for files that have them), if the decoder reports an error or if its recreated scan data is shorter
+/// than the original, in which case its rows are
+/// written as gray blocks. The output of a failed segment is padded or cut to the original size so that the
+/// segments after it stay at their original position. Only single scan baseline images are supported.
+pub fn decode_lepton_lenient_wrapper<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    num_threads: usize,
+) -> Result<DamageReport> {
+    let mut lh = LeptonHeader::new();
+
+    lh.read_lepton_header(reader).context(here!())?;
+
+    lh.check_not_passthrough().context(here!())?;
+
+    if lh.jpeg_header.jpeg_type == JPegType::Progressive || lh.has_additional_scans() {
+        return err_exit_code(
+            ExitCode::ProgressiveUnsupported,
+            "lenient decoding only supports single scan baseline images",
+        );
+    }
+
+    let remaining_size = get_remaining_size(reader).context(here!())?;
+
+    let segment_data = demultiplex_segments(&lh, reader, remaining_size);
+
+    let mut report = DamageReport::default();
+
+    // segments that don't match their checksum are known to be bad, so don't waste time decoding them
+    if let Some(checksums) = read_segment_checksums(&lh, reader) {
+        for (segment, (data, expected)) in segment_data.iter().zip(checksums).enumerate() {
+            let mut crc = Crc::new();
+            crc.update(data);
+            if crc.sum() != expected {
+                report.corrupt_segments.push(segment);
+            }
+        }
+    }
+
+    let pts = ProbabilityTablesSet::new(lh.quantized_dc_prediction, lh.fast_dc_prediction);
+    let qt = get_quantization_tables(&lh.jpeg_header).context(here!())?;
+
+    // decode the segments on a pool of workers, each of which takes the next segment that nobody has started on
+    let next_segment = AtomicUsize::new(0);
+    let num_workers = cmp::max(1, cmp::min(num_threads, lh.thread_handoff.len()));
+
+    let mut decoded: Vec<Option<Vec<u8>>> = Vec::new();
+    decoded.resize(lh.thread_handoff.len(), None);
+
+    thread_pool::scope(num_workers, |s| {
+        let mut workers = Vec::new();
+
+        for _i in 0..num_workers {
+            workers.push(s.spawn(|| {
+                let mut results = Vec::new();
+
+                loop {
+                    let segment = next_segment.fetch_add(1, Ordering::Relaxed);
+                    if segment >= lh.thread_handoff.len() {
+                        return results;
+                    }
+
+                    if report.corrupt_segments.contains(&segment) {
+                        results.push((segment, None));
+                        continue;
+                    }
+
+                    // the decoder asserts on some kinds of inconsistent data, which is just another way to fail
+                    let result = catch_unwind(AssertUnwindSafe(|| {
+                        decode_segment_scan(&lh, &pts, &qt[..], segment, &segment_data[segment])
+                    }));
+
+                    results.push((segment, result.ok().and_then(|r| r.ok())));
+                }
+            }));
+        }
+
+        for worker in workers {
+            for (segment, result) in worker.join().unwrap() {
+                decoded[segment] = result;
+            }
+        }
+    });
+
+    let mut writer = CrcWriter::new(writer);
+
+    writer.write_all(&SOI)?;
+    writer
+        .write_all(&lh.raw_jpeg_header[0..lh.raw_jpeg_header_read_index])
+        .context(here!())?;
+
+    let mcuv = lh.truncate_components.mcu_count_vertical;
+    let luma_rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv / mcuv;
+
+    for (segment, scan) in decoded.into_iter().enumerate() {
+        let scan = match scan {
+            Some(scan) => scan,
+            None => {
+                let thread_handoff = &lh.thread_handoff[segment];
+                let rows = thread_handoff.luma_y_start / luma_rows_per_mcu
+                    ..if segment == lh.thread_handoff.len() - 1 {
+                        mcuv
+                    } else {
+                        thread_handoff.luma_y_end / luma_rows_per_mcu
+                    };
+
+                // merge with the previous range if they touch
+                match report.damaged_mcu_rows.last_mut() {
+                    Some(last) if last.end == rows.start => last.end = rows.end,
+                    _ => report.damaged_mcu_rows.push(rows),
+                }
+
+                write_gray_segment(&lh, segment).context(here!())?
+            }
+        };
+
+        writer.write_all(&scan[..]).context(here!())?;
+    }
+
+    if !lh.early_eof_encountered {
+        lh.write_trailing_rst_errors(&mut writer).context(here!())?;
+
+        writer
+            .write_all(&lh.raw_jpeg_header[lh.raw_jpeg_header_read_index..])
+            .context(here!())?;
+    }
+
+    writer.write_all(&lh.garbage_data).context(here!())?;
+
+    report.checksum_matches = lh.original_file_crc.map(|crc| crc == writer.crc().sum());
+
+    Ok(report)
+}
+
+/// reads the table of segment checksums from the end of the file, if it has one that can be read
+fn read_segment_checksums<R: Read + Seek>(lh: &LeptonHeader, reader: &mut R) -> Option<Vec<u32>> {
+    if !lh.segment_checksums {
+        return None;
+    }
+
+    let trailer_len = lh.get_segment_checksums_len() + lh.get_file_size_len();
+
+    let mut trailer = vec![0; trailer_len as usize];
+    reader.seek(SeekFrom::End(-(trailer_len as i64))).ok()?;
+    reader.read_exact(&mut trailer).ok()?;
+
+    lh.read_segment_checksums(&trailer).ok()
+}
+
+/// reads the multiplexed data into a buffer per thread segment. If the framing is damaged, we stop there
+/// and the segments that are missing data will fail to decode.
+fn demultiplex_segments<R: Read>(
+    lh: &LeptonHeader,
+    reader: &mut R,
+    remaining_size: u64,
+) -> Vec<Vec<u8>> {
+    let mut segment_data = Vec::new();
+    segment_data.resize(lh.thread_handoff.len(), Vec::new());
+
+    let mut chunk_reader = ChunkReader::for_header(reader, lh, Some(remaining_size));
+    while let Ok(Some((thread_id, buffer))) = chunk_reader.next_chunk() {
+        match segment_data.get_mut(usize::from(thread_id)) {
+            Some(data) => data.extend_from_slice(&buffer[..]),
+            None => break,
+        }
+    }
+
+    segment_data
+}
+
+/// decodes a single thread segment and recreates its scan data, failing if it comes out shorter than the
+/// original
+fn decode_segment_scan(
+    lh: &LeptonHeader,
+    pts: &ProbabilityTablesSet,
+    qt: &[QuantizationTables],
+    segment: usize,
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    let thread_handoff = &lh.thread_handoff[segment];
+    let is_last_segment = segment == lh.thread_handoff.len() - 1;
+
+    let mut image_data = new_segment_image(lh, segment);
+
+    lepton_decode_row_range(
+        pts,
+        qt,
+        &lh.truncate_components,
+        &mut image_data,
+        &mut Cursor::new(data),
+        thread_handoff.luma_y_start,
+        thread_handoff.luma_y_end,
+        is_last_segment,
+        true,
+        lh.initial_model()?.as_mut(),
+        lh.initial_cr_model()?.as_deref_mut(),
+        lh.coefficient_codec,
+        // what comes after the blocks doesn't matter for recovering them
+        false,
+    )
+    .context(here!())?;
+
+    let mut scan = write_segment_scan(lh, segment, &image_data).context(here!())?;
+
+    // segments can come out a little longer than the original, which the regular decoder cuts off too
+    let expected_size = get_scan_size(lh, segment);
+    scan.truncate(expected_size);
+
+    if scan.len() != expected_size {
+        return err_exit_code(
+            ExitCode::StreamInconsistent,
+            format!(
+                "segment {0} recreated {1} bytes instead of {2}",
+                segment,
+                scan.len(),
+                expected_size
+            )
+            .as_str(),
+        );
+    }
+
+    Ok(scan)
+}
+
+/// scan data for the rows of a segment with all the blocks set to zero, which is a flat gray, padded or cut to
+/// the size of the original segment
+fn write_gray_segment(lh: &LeptonHeader, segment: usize) -> Result<Vec<u8>> {
+    let image_data = new_segment_image(lh, segment);
+
+    let mut scan = write_segment_scan(lh, segment, &image_data).context(here!())?;
+    scan.resize(get_scan_size(lh, segment), 0);
+
+    Ok(scan)
+}
+
+/// size of the scan data that the segment recreates, which for the last segment doesn't include the
+/// restart markers at the end that are written separately by write_trailing_rst_errors
+fn get_scan_size(lh: &LeptonHeader, segment: usize) -> usize {
+    let segment_size = lh.thread_handoff[segment].segment_size as usize;
+
+    if segment == lh.thread_handoff.len() - 1 && !lh.early_eof_encountered {
+        segment_size.saturating_sub(2 * lh.rst_err.first().map_or(0, |&r| r as usize))
+    } else {
+        segment_size
+    }
+}
+
+/// empty coefficient images covering the rows of a segment
+fn new_segment_image(lh: &LeptonHeader, segment: usize) -> Vec<BlockBasedImage> {
+    let thread_handoff = &lh.thread_handoff[segment];
+
+    let mut image_data = Vec::new();
+    for i in 0..lh.jpeg_header.cmpc {
+        image_data.push(BlockBasedImage::new(
+            &lh.jpeg_header,
+            i,
+            thread_handoff.luma_y_start,
+            if segment == lh.thread_handoff.len() - 1 {
+                // the last segment extends all the way to the bottom
+                lh.jpeg_header.cmp_info[0].bcv
+            } else {
+                thread_handoff.luma_y_end
+            },
+        ));
+    }
+
+    image_data
+}
+
+/// recreates the scan data of the rows of a segment from its coefficients
+fn write_segment_scan(
+    lh: &LeptonHeader,
+    segment: usize,
+    image_data: &[BlockBasedImage],
+) -> Result<Vec<u8>> {
+    let thread_handoff = &lh.thread_handoff[segment];
+
+    let mut scan = Vec::with_capacity(thread_handoff.segment_size as usize);
+    let mut huffw = BitWriter::new();
+    let max_coded_heights = lh.truncate_components.get_max_coded_heights();
+
+    jpeg_write_row_range(
+        &mut Cursor::new(&mut scan),
+        image_data,
+        lh.truncate_components.mcu_count_vertical,
+        thread_handoff,
+        &max_coded_heights[..],
+        &mut huffw,
+        lh,
+    )
+    .context(here!())?;
+
+    Ok(scan)
+}
+
+#[cfg(test)]
+use crate::enabled_features::EnabledFeatures;
+#[cfg(test)]
+use crate::structs::lepton_format::encode_lepton_wrapper;
+
+/// a corrupted segment is reported and replaced by gray blocks of the same size, while the rest of the image
+/// comes out the same as the original
+#[test]
+fn decode_lenient_corrupted_segment() {
+    let original = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("iphonecity.jpg"),
+    )
+    .unwrap();
+
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(&original),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::all(),
+    )
+    .unwrap();
+
+    let mut reader = Cursor::new(&lepton);
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).unwrap();
+    let data_start = reader.position();
+    let index = lh.read_segment_index(&mut reader, data_start).unwrap();
+    assert!(index.len() > 2);
+
+    // overwrite the coded data of a segment in the middle, leaving the chunk framing alone
+    let damaged = index.len() / 2;
+    let start = (data_start + index[damaged].offset) as usize + 3;
+    for b in &mut lepton[start..start + 32] {
+        *b = !*b;
+    }
+
+    let mut output = Vec::new();
+    let report = decode_lepton_lenient_wrapper(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+
+    let luma_rows_per_mcu =
+        lh.jpeg_header.cmp_info[0].bcv / lh.truncate_components.mcu_count_vertical;
+    assert_eq!(
+        report.damaged_mcu_rows,
+        vec![
+            lh.thread_handoff[damaged].luma_y_start / luma_rows_per_mcu
+                ..lh.thread_handoff[damaged].luma_y_end / luma_rows_per_mcu
+        ]
+    );
+    assert_eq!(report.checksum_matches, Some(false));
+    assert_eq!(report.corrupt_segments, vec![damaged]);
+
+    // everything except the scan data of the damaged segment is where it was in the original
+    assert_eq!(output.len(), original.len());
+
+    let damaged_start = SOI.len()
+        + lh.raw_jpeg_header_read_index
+        + (0..damaged).map(|i| get_scan_size(&lh, i)).sum::<usize>();
+    let damaged_end = damaged_start + get_scan_size(&lh, damaged);
+
+    assert!(output[..damaged_start] == original[..damaged_start]);
+    assert!(output[damaged_end..] == original[damaged_end..]);
+    assert!(output[damaged_start..damaged_end] != original[damaged_start..damaged_end]);
+}
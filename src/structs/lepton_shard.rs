@@ -0,0 +1,429 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::cmp;
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::consts::JPegType;
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::multiplexer::format::ChunkHeader;
+use crate::structs::jpeg_write::{encode_rows_from, EncodedRows};
+use crate::structs::lepton_format::{LeptonHeader, SegmentIndexEntry};
+use crate::structs::thread_handoff::ThreadHandoff;
+
+/// one thread segment of a lepton file along with the header of the file, so that it can be decoded on its own
+/// (see decode_shard) and joined with the other shards of the file back into the original lepton file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeptonShard {
+    /// everything in front of the thread segments, which is the same for all the shards of a file
+    pub header: Vec<u8>,
+    /// which of the thread segments of the file this is
+    pub segment: usize,
+    /// the rows of the segment and the coder state at its start
+    pub thread_handoff: ThreadHandoff,
+    /// the chunks of the segment including the thread id and length in front of each one
+    pub chunks: Vec<u8>,
+    /// position of each of the chunks among the chunks of all the segments, since the segments are interleaved
+    /// in files without a segment index
+    pub chunk_positions: Vec<u32>,
+    /// CRC of the segment data if the file has segment checksums
+    pub checksum: Option<u32>,
+}
+
+/// splits a complete lepton file into one shard per thread segment
+pub fn split_lepton_wrapper(lepton: &[u8]) -> Result<Vec<LeptonShard>> {
+    let mut reader = Cursor::new(lepton);
+
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut reader).context(here!())?;
+    lh.check_not_passthrough().context(here!())?;
+
+    let header_size = reader.position() as usize;
+
+    let mut shards = find_segment_chunks(lepton, &lh, header_size).context(here!())?;
+    for shard in shards.iter_mut() {
+        shard.header = lepton[..header_size].to_vec();
+    }
+
+    Ok(shards)
+}
+
+/// finds the chunks of each of the thread segments of a complete lepton file whose header lh takes the first
+/// header_size bytes, returning one shard per segment without the header
+pub fn find_segment_chunks(
+    lepton: &[u8],
+    lh: &LeptonHeader,
+    header_size: usize,
+) -> Result<Vec<LeptonShard>> {
+    // the file ends with its own size, without which we can't tell where the data ends
+    let file_size_len = lh.get_file_size_len() as usize;
+    if lepton.len() < header_size + file_size_len
+        || lh.read_file_size(&lepton[lepton.len() - file_size_len..])? != lepton.len() as u64
+    {
+        return err_exit_code(
+            ExitCode::BadLeptonFile,
+            "file is truncated or has data after its end",
+        );
+    }
+
+    let num_segments = lh.thread_handoff.len();
+    let checksums_len = lh.get_segment_checksums_len() as usize;
+
+    // the index and the checksums follow the data, in that order
+    let (data_end, checksums_start) = match lh.segment_index_offset {
+        Some(offset) => {
+            let data_end = header_size + usize::try_from(offset)?;
+            let index_len =
+                SegmentIndexEntry::serialized_size(lh.large_sizes) as usize * num_segments;
+            (data_end, data_end + index_len)
+        }
+        None => {
+            let end = lepton.len() - file_size_len - checksums_len;
+            (end, end)
+        }
+    };
+
+    if data_end < header_size || checksums_start + checksums_len + file_size_len > lepton.len() {
+        return err_exit_code(
+            ExitCode::BadLeptonFile,
+            "segment data doesn't fit in the file",
+        );
+    }
+
+    let mut shards = Vec::with_capacity(num_segments);
+    for (segment, thread_handoff) in lh.thread_handoff.iter().enumerate() {
+        shards.push(LeptonShard {
+            header: Vec::new(),
+            segment,
+            thread_handoff: thread_handoff.clone(),
+            chunks: Vec::new(),
+            chunk_positions: Vec::new(),
+            checksum: None,
+        });
+    }
+
+    // the index records where the last segment ends as the encoder saw it
+    if lh.segment_index_offset.is_some() {
+        let index = SegmentIndexEntry::deserialize(
+            num_segments,
+            lh.large_sizes,
+            &mut Cursor::new(&lepton[data_end..checksums_start]),
+        )
+        .context(here!())?;
+
+        lh.validate_segment_index(&index).context(here!())?;
+
+        for (shard, entry) in shards.iter_mut().zip(index) {
+            shard.thread_handoff.luma_y_end = entry.luma_y_end;
+        }
+    }
+
+    if lh.segment_checksums {
+        let mut checksums = Cursor::new(&lepton[checksums_start..checksums_start + checksums_len]);
+        for shard in shards.iter_mut() {
+            shard.checksum = Some(checksums.read_u32::<LittleEndian>()?);
+        }
+    }
+
+    // hand out the chunks to the segments they belong to, keeping their framing as it is
+    let mut chunks = Cursor::new(&lepton[header_size..data_end]);
+    let mut position = 0;
+    while chunks.position() < chunks.get_ref().len() as u64 {
+        let chunk_start = chunks.position() as usize;
+        let ChunkHeader {
+            thread_id,
+            length: data_length,
+        } = ChunkHeader::read(&mut chunks).context(here!())?;
+        let chunk_end = chunks.position() as usize + data_length;
+
+        let Some(shard) = shards.get_mut(usize::from(thread_id)) else {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                format!("invalid thread_id {0} at {1}", thread_id, chunk_start).as_str(),
+            );
+        };
+
+        if chunk_end > chunks.get_ref().len() {
+            return err_exit_code(
+                ExitCode::BadLeptonFile,
+                "chunk goes past the end of the data",
+            );
+        }
+
+        shard
+            .chunks
+            .extend_from_slice(&chunks.get_ref()[chunk_start..chunk_end]);
+        shard.chunk_positions.push(position);
+
+        position += 1;
+        chunks.set_position(chunk_end as u64);
+    }
+
+    Ok(shards)
+}
+
+/// reassembles the lepton file that the shards were split from. The shards can be in any order, but all the
+/// segments of the file have to be there, each one once, and their rows have to follow each other.
+pub fn join_lepton_wrapper(shards: &[LeptonShard]) -> Result<Vec<u8>> {
+    let Some(first) = shards.first() else {
+        return err_exit_code(ExitCode::ShardMismatch, "no shards to join");
+    };
+
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&first.header))
+        .context(here!())?;
+
+    let num_segments = lh.thread_handoff.len();
+
+    let mut ordered: Vec<Option<&LeptonShard>> = vec![None; num_segments];
+    for shard in shards {
+        if shard.header != first.header {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                format!("shard {0} is from a different file", shard.segment).as_str(),
+            );
+        }
+
+        match ordered.get_mut(shard.segment) {
+            None => {
+                return err_exit_code(
+                    ExitCode::ShardMismatch,
+                    format!(
+                        "shard {0} is beyond the {1} segments of the file",
+                        shard.segment, num_segments
+                    )
+                    .as_str(),
+                );
+            }
+            Some(Some(_)) => {
+                return err_exit_code(
+                    ExitCode::ShardMismatch,
+                    format!("shard {0} is there more than once", shard.segment).as_str(),
+                );
+            }
+            Some(slot) => *slot = Some(shard),
+        }
+    }
+
+    // the rows of each segment have to start where the ones of the previous segment end
+    let mut ordered_shards = Vec::with_capacity(num_segments);
+    let mut luma_y = 0;
+    for (segment, shard) in ordered.into_iter().enumerate() {
+        let Some(shard) = shard else {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                format!("shard {0} is missing", segment).as_str(),
+            );
+        };
+
+        if shard.thread_handoff.luma_y_start != luma_y
+            || shard.thread_handoff.luma_y_start != lh.thread_handoff[segment].luma_y_start
+        {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                format!(
+                    "shard {0} starts at row {1} rather than at {2} where the previous one ended",
+                    segment, shard.thread_handoff.luma_y_start, luma_y
+                )
+                .as_str(),
+            );
+        }
+
+        if lh.segment_checksums != shard.checksum.is_some() {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                format!("shard {0} doesn't match the checksums of the file", segment).as_str(),
+            );
+        }
+
+        luma_y = shard.thread_handoff.luma_y_end;
+        ordered_shards.push(shard);
+    }
+
+    // put the chunks back where they were
+    let mut chunks: Vec<Option<&[u8]>> =
+        vec![None; ordered_shards.iter().map(|s| s.chunk_positions.len()).sum()];
+    for shard in &ordered_shards {
+        let mut reader = Cursor::new(&shard.chunks[..]);
+        for &position in &shard.chunk_positions {
+            let chunk_start = reader.position() as usize;
+            let data_length = ChunkHeader::read(&mut reader).context(here!())?.length;
+            let chunk_end = cmp::min(reader.position() as usize + data_length, shard.chunks.len());
+
+            match chunks.get_mut(position as usize) {
+                Some(slot @ None) => *slot = Some(&shard.chunks[chunk_start..chunk_end]),
+                _ => {
+                    return err_exit_code(
+                        ExitCode::ShardMismatch,
+                        format!(
+                            "shard {0} has a chunk at position {1}, which is taken or out of range",
+                            shard.segment, position
+                        )
+                        .as_str(),
+                    );
+                }
+            }
+
+            reader.set_position(chunk_end as u64);
+        }
+
+        if reader.position() != shard.chunks.len() as u64 {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                format!(
+                    "shard {0} has a different number of chunks than positions",
+                    shard.segment
+                )
+                .as_str(),
+            );
+        }
+    }
+
+    let mut lepton = first.header.clone();
+    let data_start = lepton.len();
+
+    for chunk in chunks {
+        // every position is taken, since there are as many chunks as positions and none of them is taken twice
+        let data = chunk.context(here!())?;
+        lepton.extend_from_slice(data);
+    }
+
+    if let Some(offset) = lh.segment_index_offset {
+        if offset != (lepton.len() - data_start) as u64 {
+            return err_exit_code(
+                ExitCode::ShardMismatch,
+                "the shards don't add up to the data in front of the segment index",
+            );
+        }
+
+        // the encoder writes the segments one after the other in files with an index
+        let mut index = Vec::with_capacity(num_segments);
+        let mut segment_offset = 0;
+        for shard in &ordered_shards {
+            index.push(SegmentIndexEntry {
+                offset: segment_offset,
+                length: shard.chunks.len() as u64,
+                luma_y_start: shard.thread_handoff.luma_y_start,
+                luma_y_end: shard.thread_handoff.luma_y_end,
+            });
+            segment_offset += shard.chunks.len() as u64;
+        }
+
+        SegmentIndexEntry::serialize(&index, lh.large_sizes, &mut lepton).context(here!())?;
+    }
+
+    for shard in &ordered_shards {
+        if let Some(checksum) = shard.checksum {
+            lepton.write_u32::<LittleEndian>(checksum)?;
+        }
+    }
+
+    let final_file_size = lepton.len() as u64 + lh.get_file_size_len();
+    lh.write_file_size(&mut lepton, final_file_size)
+        .context(here!())?;
+
+    Ok(lepton)
+}
+
+/// recreates the scan data of the rows of a shard, like decode_rows does for the rows of a segment. Only
+/// single scan baseline images are supported. Concatenating the output of all the shards of a file in order
+/// gives the entire scan.
+pub fn decode_shard_wrapper(shard: &LeptonShard) -> Result<EncodedRows> {
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&shard.header))
+        .context(here!())?;
+    lh.check_not_passthrough().context(here!())?;
+
+    if lh.jpeg_header.jpeg_type == JPegType::Progressive || lh.has_additional_scans() {
+        return err_exit_code(
+            ExitCode::ProgressiveUnsupported,
+            "shards can only be decoded for single scan baseline images",
+        );
+    }
+
+    if shard.segment >= lh.thread_handoff.len()
+        || shard.thread_handoff.luma_y_start != lh.thread_handoff[shard.segment].luma_y_start
+    {
+        return err_exit_code(
+            ExitCode::ShardMismatch,
+            format!("shard {0} doesn't match the header", shard.segment).as_str(),
+        );
+    }
+
+    let (image_data, _metrics) = lh
+        .decode_segment_chunks(&shard.chunks, shard.segment, shard.checksum)
+        .context(here!())?;
+
+    // number of luma block rows in each MCU row, which is how the segments are split
+    let mcuv = lh.truncate_components.mcu_count_vertical;
+    let luma_rows_per_mcu = lh.jpeg_header.cmp_info[0].bcv / mcuv;
+
+    let thread_handoff = &lh.thread_handoff[shard.segment];
+    let rows_end = if shard.segment == lh.thread_handoff.len() - 1 {
+        // the last segment extends all the way to the bottom
+        mcuv
+    } else {
+        thread_handoff.luma_y_end / luma_rows_per_mcu
+    };
+
+    encode_rows_from(
+        &image_data,
+        &lh,
+        thread_handoff,
+        thread_handoff.luma_y_start / luma_rows_per_mcu..rows_end,
+    )
+    .context(here!())
+}
+
+#[cfg(test)]
+use crate::structs::block_based_image::BlockBasedImage;
+
+/// the coefficients of shards decoded on their own merge into the image only if they follow each other
+#[test]
+fn merge_decoded_shards() {
+    let lepton = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("iphonecity.lep"),
+    )
+    .unwrap();
+
+    let shards = split_lepton_wrapper(&lepton).unwrap();
+    assert!(shards.len() > 2);
+
+    let mut lh = LeptonHeader::new();
+    lh.read_lepton_header(&mut Cursor::new(&shards[0].header))
+        .unwrap();
+
+    let decode = |shard: &LeptonShard| {
+        lh.decode_segment_chunks(&shard.chunks, shard.segment, shard.checksum)
+            .unwrap()
+            .0
+    };
+
+    let mut in_order: Vec<_> = shards.iter().map(decode).collect();
+    let merged = BlockBasedImage::merge(&mut in_order, 0).unwrap();
+    assert_eq!(merged.get_original_height(), lh.jpeg_header.cmp_info[0].bcv);
+
+    let mut missing: Vec<_> = shards.iter().skip(1).map(decode).collect();
+    let Err(e) = BlockBasedImage::merge(&mut missing, 0) else {
+        panic!("merged images with a gap");
+    };
+    assert_eq!(
+        e.downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::StreamInconsistent
+    );
+
+    let mut swapped: Vec<_> = shards.iter().map(decode).collect();
+    swapped.swap(0, 1);
+    assert!(BlockBasedImage::merge(&mut swapped, 0).is_err());
+}
@@ -0,0 +1,129 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Result;
+
+use crate::helpers::err_exit_code;
+use crate::lepton_error::ExitCode;
+
+/// counts the memory that the large allocations of a call (the block images, the model of each thread segment
+/// and the buffers of its output) have reserved between all of its threads, so that running more segments at
+/// the same time can't take the call over EnabledFeatures::memory_budget. The allocations reserve the most they
+/// can grow to before they are made, and release it once they are freed.
+pub struct MemoryTracker {
+    budget: u64,
+    used: AtomicU64,
+    peak: AtomicU64,
+}
+
+/// memory that was reserved with a MemoryTracker, which is released when this is dropped
+pub struct Reservation<'a> {
+    tracker: &'a MemoryTracker,
+    bytes: u64,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.tracker.used.fetch_sub(self.bytes, Ordering::SeqCst);
+    }
+}
+
+impl MemoryTracker {
+    /// a tracker that refuses reservations that would take the total over budget, or none if there is no budget
+    pub fn new(budget: Option<u64>) -> Self {
+        MemoryTracker {
+            budget: budget.unwrap_or(u64::MAX),
+            used: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    /// reserves bytes if that doesn't take the total over the budget
+    pub fn try_reserve(&self, bytes: u64) -> Option<Reservation<'_>> {
+        let mut used = self.used.load(Ordering::SeqCst);
+        loop {
+            let total = used.checked_add(bytes).filter(|t| *t <= self.budget)?;
+
+            match self
+                .used
+                .compare_exchange_weak(used, total, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => {
+                    self.peak.fetch_max(total, Ordering::SeqCst);
+                    return Some(Reservation {
+                        tracker: self,
+                        bytes,
+                    });
+                }
+                Err(current) => used = current,
+            }
+        }
+    }
+
+    /// reserves bytes for what, or fails with ExceedsMemoryBudget if that would take the total over the budget
+    pub fn reserve(&self, bytes: u64, what: &str) -> Result<Reservation<'_>> {
+        match self.try_reserve(bytes) {
+            Some(r) => Ok(r),
+            None => err_exit_code(
+                ExitCode::ExceedsMemoryBudget,
+                format!(
+                    "{0} needs {1} bytes, but only {2} of the memory budget of {3} bytes are left",
+                    what,
+                    bytes,
+                    self.budget.saturating_sub(self.used()),
+                    self.budget
+                )
+                .as_str(),
+            ),
+        }
+    }
+
+    /// bytes that are reserved right now
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::SeqCst)
+    }
+
+    /// the most bytes that were reserved at the same time, which the tests check the concurrency against
+    #[cfg(test)]
+    pub fn peak(&self) -> u64 {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+/// reservations add up to the budget and no further, and give their memory back when they are dropped
+#[test]
+fn reservations_stay_within_budget() {
+    let tracker = MemoryTracker::new(Some(100));
+
+    let a = tracker.reserve(60, "a").unwrap();
+    assert!(tracker.try_reserve(41).is_none());
+
+    let b = tracker.try_reserve(40).unwrap();
+    assert_eq!(tracker.used(), 100);
+
+    let e = tracker.reserve(1, "c").err().unwrap();
+    assert_eq!(
+        e.downcast_ref::<crate::lepton_error::LeptonError>()
+            .unwrap()
+            .exit_code,
+        ExitCode::ExceedsMemoryBudget
+    );
+
+    drop(a);
+    assert_eq!(tracker.used(), 40);
+    assert!(tracker.try_reserve(60).is_some());
+
+    drop(b);
+    assert_eq!(tracker.used(), 0);
+    assert_eq!(tracker.peak(), 100);
+
+    // without a budget only the total is counted
+    let unlimited = MemoryTracker::new(None);
+    let _big = unlimited.reserve(u64::MAX / 2, "big").unwrap();
+    assert_eq!(unlimited.peak(), u64::MAX / 2);
+}
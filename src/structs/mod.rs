@@ -7,26 +7,54 @@
 mod bit_reader;
 mod bit_writer;
 mod block_based_image;
+pub mod block_bits;
 mod block_context;
 mod branch;
+mod chained_reader;
+mod chunk_writer;
+mod coefficient_codec;
 mod component_info;
+pub mod compression_estimate;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+pub mod cpu_cores;
+mod crc_reader;
+pub mod decode_plan;
+mod huffman_optimizer;
 mod idct;
+pub mod initial_probs;
 mod jpeg_header;
 mod jpeg_position_state;
 mod jpeg_read;
-mod jpeg_write;
+pub mod jpeg_write;
+pub mod lepton_container;
 mod lepton_decoder;
 mod lepton_encoder;
 pub mod lepton_format;
-mod model;
+pub mod lepton_layout;
+pub mod lepton_recovery;
+pub mod lepton_shard;
+pub mod memory_tracker;
+pub mod model;
+#[cfg(feature = "test-utils")]
+pub mod model_fuzz;
+pub mod model_primer;
 mod neighbor_summary;
+mod prefetch_reader;
 mod probability_tables;
 mod probability_tables_coefficient_context;
 mod probability_tables_set;
 mod quantization_tables;
+mod read_ahead;
 mod row_spec;
+pub mod segment_verifier;
 mod simple_hash;
-mod thread_handoff;
+#[cfg(feature = "debug-symmetry")]
+pub mod symmetry_log;
+pub mod thread_handoff;
+pub mod thread_lifecycle;
+pub mod thread_pool;
+mod trained_initial_counts;
 mod truncate_components;
 mod vpx_bool_reader;
 mod vpx_bool_writer;
@@ -6,23 +6,38 @@
 
 use anyhow::{Context, Result};
 use std::cmp;
+use std::fmt;
 use std::io::{Read, Write};
+#[cfg(debug_assertions)]
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use crate::consts::*;
+use crate::enabled_features::ModelTuning;
 use crate::helpers::{calc_sign_index, err_exit_code, here, u16_bit_length};
 use crate::lepton_error::ExitCode;
+#[cfg(feature = "stats")]
+use crate::metrics::BranchUsage;
 use crate::metrics::{ModelComponent, ModelSubComponent};
 use crate::structs::branch::Branch;
 use default_boxed::DefaultBoxed;
 
+use super::coefficient_codec::CoefficientCodec;
+use super::initial_probs::ModelInit;
+use super::model_primer::ModelPrimer;
 use super::probability_tables::ProbabilityTables;
 use super::probability_tables_coefficient_context::ProbabilityTablesCoefficientContext;
 use super::quantization_tables::QuantizationTables;
 use super::vpx_bool_reader::VPXBoolReader;
 use super::vpx_bool_writer::VPXBoolWriter;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::{SymmetryLog, SymmetryRecorder};
 
 pub const MAX_EXPONENT: usize = 11;
-const BLOCK_TYPES: usize = 2; // setting this to 3 gives us ~1% savings.. 2/3 from BLOCK_TYPES=2
+/// largest magnitude of a coefficient that fits in MAX_EXPONENT bits
+pub const MAX_COEFFICIENT: i32 = (1 << MAX_EXPONENT) - 1;
+pub const BLOCK_TYPES: usize = 2; // setting this to 3 gives us ~1% savings.. 2/3 from BLOCK_TYPES=2
 pub const NUM_NON_ZERO_BINS: usize = 10;
 //const BsrBestPriorMax : usize = 11; // 1023 requires 11 bits to describe
 pub const BAND_DIVISOR: usize = 1;
@@ -45,6 +60,11 @@ type NumNonZerosCounts7x7T = [[[Branch; 32]; 6]; 26];
 
 type NumNonZerosCountsT = [[[[Branch; 4]; 3]; 8]; 8];
 
+/// the exponent branches of one coefficient for each bit length of the best prior
+type ExponentCountsT = LazyBranches<[Branch; MAX_EXPONENT], NUMERIC_LENGTH_MAX>;
+
+type ResidualThresholdCountsT = LazyBranches<Branch, RESIDUAL_THRESHOLD_COUNTS_D3>;
+
 pub const RESIDUAL_THRESHOLD_COUNTS_D1: usize = 1 << (1 + RESIDUAL_NOISE_FLOOR);
 pub const RESIDUAL_THRESHOLD_COUNTS_D2: usize = 1 + RESIDUAL_NOISE_FLOOR;
 pub const RESIDUAL_THRESHOLD_COUNTS_D3: usize = 1 << RESIDUAL_NOISE_FLOOR;
@@ -57,37 +77,601 @@ pub const RESIDUAL_NOISE_COUNTS_D2: usize = if NUM_NON_ZERO_BINS < 8 {
 };
 pub const RESIDUAL_NOISE_COUNTS_D3: usize = COEF_BITS;
 
+/// The tables are laid out in the order that the coder uses them rather than the order of for_each_branch: first
+/// the ones that every block uses for its 7x7 coefficients, which are most of the decisions, then the ones for
+/// the edges, then the DC, and the rest that is hardly touched while coding at the end. The assertions after the
+/// struct keep the hot tables next to each other.
 #[derive(DefaultBoxed)]
+#[repr(C)]
 pub struct Model {
     num_non_zeros_counts7x7: [NumNonZerosCounts7x7T; BLOCK_TYPES],
 
-    num_non_zeros_counts1x8: [NumNonZerosCountsT; BLOCK_TYPES],
+    /// these and the residual threshold counts are most of the memory of the model, so they are only allocated
+    /// in pieces as they are used
+    exponent_counts: [[[ExponentCountsT; 49]; NUM_NON_ZERO_BINS]; BLOCK_TYPES],
 
-    num_non_zeros_counts8x1: [NumNonZerosCountsT; BLOCK_TYPES],
+    sign_counts: [[[Branch; NUMERIC_LENGTH_MAX]; 4]; BLOCK_TYPES],
 
     residual_noise_counts: [[[[Branch; RESIDUAL_NOISE_COUNTS_D3]; RESIDUAL_NOISE_COUNTS_D2];
         RESIDUAL_NOISE_COUNTS_D1]; BLOCK_TYPES],
 
-    residual_threshold_counts: [[[[Branch; RESIDUAL_THRESHOLD_COUNTS_D3];
-        RESIDUAL_THRESHOLD_COUNTS_D2];
-        RESIDUAL_THRESHOLD_COUNTS_D1]; BLOCK_TYPES],
+    num_non_zeros_counts1x8: [NumNonZerosCountsT; BLOCK_TYPES],
 
-    exponent_counts:
-        [[[[[Branch; MAX_EXPONENT]; NUMERIC_LENGTH_MAX]; 49]; NUM_NON_ZERO_BINS]; BLOCK_TYPES],
+    num_non_zeros_counts8x1: [NumNonZerosCountsT; BLOCK_TYPES],
 
-    exponent_counts_x:
-        [[[[[Branch; MAX_EXPONENT]; NUMERIC_LENGTH_MAX]; 15]; NUM_NON_ZERO_BINS]; BLOCK_TYPES],
+    exponent_counts_x: [[[ExponentCountsT; 15]; NUM_NON_ZERO_BINS]; BLOCK_TYPES],
 
-    sign_counts: [[[Branch; NUMERIC_LENGTH_MAX]; 4]; BLOCK_TYPES],
+    residual_threshold_counts: [[[ResidualThresholdCountsT; RESIDUAL_THRESHOLD_COUNTS_D2];
+        RESIDUAL_THRESHOLD_COUNTS_D1]; BLOCK_TYPES],
 
     exponent_counts_dc: [[[Branch; MAX_EXPONENT]; 17]; EXPONENT_COUNT_DC_BINS],
 
     residual_noise_counts_dc: [[Branch; COEF_BITS]; NUMERIC_LENGTH_MAX],
+
+    /// how the branches adapt, which the bool coder needs to update them
+    #[cfg(feature = "experimental-tuning")]
+    tuning: ModelTuning,
+
+    /// called by the encoder and decoder before they code a block, see ModelSnapshotHook
+    snapshot_hook: Option<ModelSnapshotHook>,
+
+    /// set once another thread segment of the same image failed, which the encoder and decoder check before each
+    /// row so that they give up rather than finish a segment that nobody will use
+    cancel_flag: Option<Arc<AtomicBool>>,
+
+    /// where the bool coder of the thread segment logs its decisions, see SymmetryRecorder
+    #[cfg(feature = "debug-symmetry")]
+    symmetry_recorder: Option<SymmetryRecorder>,
+
+    /// the generation that new_for_segment gave the model, 0 for a model that was created some other way, and
+    /// whether a thread segment started coding with it, see begin_segment
+    #[cfg(debug_assertions)]
+    segment_generation: u64,
+    #[cfg(debug_assertions)]
+    segment_started: bool,
+}
+
+/// the generation of the next model that new_for_segment creates
+#[cfg(debug_assertions)]
+static NEXT_SEGMENT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+const fn size_of_pointee<T>(_: *const T) -> usize {
+    std::mem::size_of::<T>()
+}
+
+/// the most memory a model takes, once all the branches that are allocated as they are first used are, which is
+/// what the encoder reserves for each model against EnabledFeatures::memory_budget
+pub const MAX_MODEL_MEMORY: usize = std::mem::size_of::<Model>()
+    + BLOCK_TYPES
+        * NUM_NON_ZERO_BINS
+        * (49 + 15)
+        * std::mem::size_of::<[[Branch; MAX_EXPONENT]; NUMERIC_LENGTH_MAX]>()
+    + BLOCK_TYPES
+        * RESIDUAL_THRESHOLD_COUNTS_D1
+        * RESIDUAL_THRESHOLD_COUNTS_D2
+        * std::mem::size_of::<[Branch; RESIDUAL_THRESHOLD_COUNTS_D3]>();
+
+/// the offset in Model where field starts and where it ends, evaluated at compile time
+macro_rules! model_field_range {
+    ($field:ident) => {{
+        let model = std::mem::MaybeUninit::<Model>::uninit();
+        let base = model.as_ptr();
+        // only the address of the field is taken, the uninitialized model is never read
+        let field = unsafe { std::ptr::addr_of!((*base).$field) };
+        let start = unsafe { (field as *const u8).offset_from(base as *const u8) } as usize;
+        (start, start + size_of_pointee(field))
+    }};
+}
+
+/// the tables of the 7x7 coefficients come first and follow each other without a gap, and the edge tables come
+/// right after them
+const _: () = {
+    assert!(model_field_range!(num_non_zeros_counts7x7).0 == 0);
+    assert!(model_field_range!(num_non_zeros_counts7x7).1 == model_field_range!(exponent_counts).0);
+    assert!(model_field_range!(exponent_counts).1 == model_field_range!(sign_counts).0);
+    assert!(model_field_range!(sign_counts).1 == model_field_range!(residual_noise_counts).0);
+    assert!(
+        model_field_range!(residual_noise_counts).1
+            == model_field_range!(num_non_zeros_counts1x8).0
+    );
+};
+
+/// Debugging aid: the counts of every branch of the model at one point of the coding, in the order of
+/// Model::for_each_branch. Since the decoder has to update the model exactly like the encoder did, the snapshots
+/// that both sides take before the same block are equal for a healthy file, and the first block where they
+/// differ is where the two went out of sync.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModelSnapshot {
+    counts: Vec<u16>,
+}
+
+impl ModelSnapshot {
+    /// serializes the counts as little endian 16-bit values so that a snapshot can be dumped to a file
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.counts.iter().flat_map(|c| c.to_le_bytes()).collect()
+    }
+
+    /// reads the counts written by to_bytes
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() % 2 != 0 {
+            return err_exit_code(ExitCode::SyntaxError, "model snapshot has an odd length");
+        }
+
+        Ok(ModelSnapshot {
+            counts: data
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect(),
+        })
+    }
+
+    /// index of the first branch whose counts differ from those in other, if any
+    pub fn first_difference(&self, other: &ModelSnapshot) -> Option<usize> {
+        if let Some(i) = self
+            .counts
+            .iter()
+            .zip(&other.counts)
+            .position(|(a, b)| a != b)
+        {
+            return Some(i);
+        }
+
+        if self.counts.len() != other.counts.len() {
+            return Some(cmp::min(self.counts.len(), other.counts.len()));
+        }
+
+        None
+    }
+}
+
+/// Debugging aid: the callback is given the component, the dpos and a snapshot of the model right before the
+/// encoder or decoder codes the block at dpos (or every block if dpos is None). Each thread segment has its own
+/// model, so the callback can be called from several threads at once.
+#[derive(Clone)]
+pub struct ModelSnapshotHook {
+    pub dpos: Option<i32>,
+    pub callback: Arc<ModelSnapshotCallback>,
+}
+
+/// the callback of ModelSnapshotHook, given the component, the dpos and the snapshot
+pub type ModelSnapshotCallback = dyn Fn(usize, i32, &ModelSnapshot) + Send + Sync;
+
+impl fmt::Debug for ModelSnapshotHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModelSnapshotHook")
+            .field("dpos", &self.dpos)
+            .finish_non_exhaustive()
+    }
+}
+
+/// visits every branch of a (possibly nested) array of branches in memory order
+trait BranchArray {
+    fn visit(&mut self, f: &mut dyn FnMut(&mut Branch));
+
+    fn visit_ref(&self, f: &mut dyn FnMut(&Branch));
+
+    /// number of branches in the array
+    fn branch_count() -> u32
+    where
+        Self: Sized;
+
+    /// sets the branches whose index is at the start of counts to their counts and removes them from counts.
+    /// The branches of the array have the indices from first on, and counts is sorted by index.
+    fn load_counts(&mut self, first: u32, counts: &mut &[(u32, u16)]);
+
+    /// same as visit_ref, but also passes the indices of each branch within the array
+    #[cfg(feature = "stats")]
+    fn visit_indexed(&self, indices: &mut Vec<usize>, f: &mut dyn FnMut(&[usize], &Branch));
+}
+
+/// number of branches in array
+fn branch_count_of<A: BranchArray>(_array: &A) -> u32 {
+    A::branch_count()
+}
+
+impl BranchArray for Branch {
+    fn visit(&mut self, f: &mut dyn FnMut(&mut Branch)) {
+        f(self)
+    }
+
+    fn visit_ref(&self, f: &mut dyn FnMut(&Branch)) {
+        f(self)
+    }
+
+    fn branch_count() -> u32 {
+        1
+    }
+
+    fn load_counts(&mut self, first: u32, counts: &mut &[(u32, u16)]) {
+        if let Some(((index, c), rest)) = counts.split_first() {
+            if *index == first {
+                self.set_counts(*c);
+                *counts = rest;
+            }
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    fn visit_indexed(&self, indices: &mut Vec<usize>, f: &mut dyn FnMut(&[usize], &Branch)) {
+        f(indices, self)
+    }
+}
+
+impl<T: BranchArray, const N: usize> BranchArray for [T; N] {
+    fn visit(&mut self, f: &mut dyn FnMut(&mut Branch)) {
+        for b in self.iter_mut() {
+            b.visit(f);
+        }
+    }
+
+    fn visit_ref(&self, f: &mut dyn FnMut(&Branch)) {
+        for b in self.iter() {
+            b.visit_ref(f);
+        }
+    }
+
+    fn branch_count() -> u32 {
+        T::branch_count() * N as u32
+    }
+
+    fn load_counts(&mut self, first: u32, counts: &mut &[(u32, u16)]) {
+        let mut index = first;
+        for b in self.iter_mut() {
+            match counts.first() {
+                None => return,
+                Some((next, _)) if *next < index + T::branch_count() => {
+                    b.load_counts(index, counts)
+                }
+                _ => {}
+            }
+            index += T::branch_count();
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    fn visit_indexed(&self, indices: &mut Vec<usize>, f: &mut dyn FnMut(&[usize], &Branch)) {
+        for (i, b) in self.iter().enumerate() {
+            indices.push(i);
+            b.visit_indexed(indices, f);
+            indices.pop();
+        }
+    }
+}
+
+/// An array of N E (branches or arrays of them) that is only allocated when one of them is first used, since
+/// small images and images with few non-zero coefficients only use a fraction of the contexts. Until then the
+/// branches behave as if they had their initial counts.
+struct LazyBranches<E, const N: usize> {
+    branches: Option<Box<[E; N]>>,
+}
+
+impl<E, const N: usize> Default for LazyBranches<E, N> {
+    fn default() -> Self {
+        LazyBranches { branches: None }
+    }
+}
+
+impl<E: Default, const N: usize> LazyBranches<E, N> {
+    #[inline(always)]
+    fn get_mut(&mut self) -> &mut [E; N] {
+        self.branches
+            .get_or_insert_with(E::default_boxed_array::<N>)
+    }
+}
+
+impl<E: BranchArray + Default, const N: usize> BranchArray for LazyBranches<E, N> {
+    /// allocates the branches, since f may change them
+    fn visit(&mut self, f: &mut dyn FnMut(&mut Branch)) {
+        self.get_mut().visit(f);
+    }
+
+    fn visit_ref(&self, f: &mut dyn FnMut(&Branch)) {
+        match &self.branches {
+            Some(branches) => branches.visit_ref(f),
+            None => {
+                let initial = E::default();
+                for _ in 0..N {
+                    initial.visit_ref(f);
+                }
+            }
+        }
+    }
+
+    fn branch_count() -> u32 {
+        E::branch_count() * N as u32
+    }
+
+    /// only allocates the branches if some of them are in counts
+    fn load_counts(&mut self, first: u32, counts: &mut &[(u32, u16)]) {
+        if counts
+            .first()
+            .map_or(false, |(index, _)| *index < first + Self::branch_count())
+        {
+            self.get_mut().load_counts(first, counts);
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    fn visit_indexed(&self, indices: &mut Vec<usize>, f: &mut dyn FnMut(&[usize], &Branch)) {
+        match &self.branches {
+            Some(branches) => branches.visit_indexed(indices, f),
+            None => {
+                let initial = E::default();
+                for i in 0..N {
+                    indices.push(i);
+                    initial.visit_indexed(indices, f);
+                    indices.pop();
+                }
+            }
+        }
+    }
 }
 
 impl Model {
+    /// the model that the encoder and decoder of a thread segment start from. The counts of init (see ModelInit)
+    /// replace the initial counts of the tuning, and the counts of the primer replace both. With low_latency all of
+    /// the model is allocated up front.
+    ///
+    /// Every thread segment has to start from a model of its own that nothing was coded with yet, since the
+    /// decoder of a segment doesn't know anything about the segments before it and a file has to decode the same
+    /// however its segments are spread over threads or machines. In debug builds begin_segment checks that the
+    /// model isn't carried over to a second segment.
+    pub fn new_for_segment(
+        primer: Option<&ModelPrimer>,
+        tuning: &ModelTuning,
+        init: ModelInit,
+        snapshot_hook: Option<ModelSnapshotHook>,
+        low_latency: bool,
+    ) -> Box<Model> {
+        let mut model = Model::default_boxed();
+        model.set_tuning(tuning);
+        model.set_snapshot_hook(snapshot_hook);
+
+        init.apply(&mut model);
+
+        if let Some(primer) = primer {
+            primer.load_into(&mut model);
+        }
+
+        if low_latency {
+            model.allocate_all();
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            model.segment_generation = NEXT_SEGMENT_GENERATION.fetch_add(1, Ordering::Relaxed);
+        }
+
+        model
+    }
+
+    /// called by the encoder and decoder before they code a thread segment with the model. A model from
+    /// new_for_segment can only code a single segment, which debug builds assert. The models that are created some
+    /// other way, like the one that a primer is trained with over several images, are left alone.
+    #[inline(always)]
+    pub fn begin_segment(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            if self.segment_generation != 0 {
+                assert!(
+                    !self.segment_started,
+                    "the model of segment generation {0} was carried over to another thread segment",
+                    self.segment_generation
+                );
+                self.segment_started = true;
+            }
+        }
+    }
+
+    /// allocates all the branches that are otherwise only allocated when they are first used, so that coding
+    /// doesn't allocate anything
+    pub fn allocate_all(&mut self) {
+        self.for_each_branch(&mut |_| {});
+    }
+
+    /// calls f for each branch of the model, always in the same order, so that the state of a model can be
+    /// saved and restored. Allocates all the branches.
+    pub fn for_each_branch(&mut self, f: &mut dyn FnMut(&mut Branch)) {
+        self.num_non_zeros_counts7x7.visit(f);
+        self.num_non_zeros_counts1x8.visit(f);
+        self.num_non_zeros_counts8x1.visit(f);
+        self.residual_noise_counts.visit(f);
+        self.residual_threshold_counts.visit(f);
+        self.exponent_counts.visit(f);
+        self.exponent_counts_x.visit(f);
+        self.sign_counts.visit(f);
+        self.exponent_counts_dc.visit(f);
+        self.residual_noise_counts_dc.visit(f);
+    }
+
+    /// sets the branches at the indices of counts (in the order of for_each_branch) to their counts. Unlike
+    /// for_each_branch, only the branches that are allocated as they are used and have counts in the list are
+    /// allocated. counts has to be sorted by index.
+    pub fn load_counts(&mut self, mut counts: &[(u32, u16)]) {
+        let counts = &mut counts;
+        let mut first = 0;
+
+        self.num_non_zeros_counts7x7.load_counts(first, counts);
+        first += branch_count_of(&self.num_non_zeros_counts7x7);
+        self.num_non_zeros_counts1x8.load_counts(first, counts);
+        first += branch_count_of(&self.num_non_zeros_counts1x8);
+        self.num_non_zeros_counts8x1.load_counts(first, counts);
+        first += branch_count_of(&self.num_non_zeros_counts8x1);
+        self.residual_noise_counts.load_counts(first, counts);
+        first += branch_count_of(&self.residual_noise_counts);
+        self.residual_threshold_counts.load_counts(first, counts);
+        first += branch_count_of(&self.residual_threshold_counts);
+        self.exponent_counts.load_counts(first, counts);
+        first += branch_count_of(&self.exponent_counts);
+        self.exponent_counts_x.load_counts(first, counts);
+        first += branch_count_of(&self.exponent_counts_x);
+        self.sign_counts.load_counts(first, counts);
+        first += branch_count_of(&self.sign_counts);
+        self.exponent_counts_dc.load_counts(first, counts);
+        first += branch_count_of(&self.exponent_counts_dc);
+        self.residual_noise_counts_dc.load_counts(first, counts);
+    }
+
+    /// the counts of all the branches, see ModelSnapshot
+    pub fn snapshot(&self) -> ModelSnapshot {
+        let mut counts = Vec::new();
+        let mut f = |b: &Branch| counts.push(b.get_counts());
+
+        self.num_non_zeros_counts7x7.visit_ref(&mut f);
+        self.num_non_zeros_counts1x8.visit_ref(&mut f);
+        self.num_non_zeros_counts8x1.visit_ref(&mut f);
+        self.residual_noise_counts.visit_ref(&mut f);
+        self.residual_threshold_counts.visit_ref(&mut f);
+        self.exponent_counts.visit_ref(&mut f);
+        self.exponent_counts_x.visit_ref(&mut f);
+        self.sign_counts.visit_ref(&mut f);
+        self.exponent_counts_dc.visit_ref(&mut f);
+        self.residual_noise_counts_dc.visit_ref(&mut f);
+
+        ModelSnapshot { counts }
+    }
+
+    /// puts the branches back into the state of a snapshot taken of a model of the same layout. The model is
+    /// left untouched if the snapshot doesn't fit it.
+    pub fn restore(&mut self, snapshot: &ModelSnapshot) -> Result<()> {
+        let mut branch_count = 0;
+        let mut valid = true;
+        self.for_each_branch(&mut |_| {
+            valid &= snapshot
+                .counts
+                .get(branch_count)
+                .map_or(false, |c| Branch::new().set_counts(*c));
+            branch_count += 1;
+        });
+
+        if !valid || branch_count != snapshot.counts.len() {
+            return err_exit_code(
+                ExitCode::SyntaxError,
+                "model snapshot doesn't match the model",
+            );
+        }
+
+        let mut counts = snapshot.counts.iter();
+        self.for_each_branch(&mut |b| {
+            b.set_counts(*counts.next().unwrap());
+        });
+
+        Ok(())
+    }
+
+    /// how often each branch was used and its current probability, keyed by the table the branch is in and its
+    /// indices there, which are:
+    ///
+    /// - num_non_zeros_counts7x7: color (0 for luma, 1 for chroma), bin of the number of non-zeros of the
+    ///   neighbors, bit of the count, bits of the count coded so far
+    /// - num_non_zeros_counts1x8 and num_non_zeros_counts8x1: color, estimated end of block, bucket of the
+    ///   number of non-zeros in the 7x7, bit of the count, bits of the count coded so far
+    /// - residual_noise_counts: color, coefficient band, bin of the non-zeros left, bit
+    /// - residual_threshold_counts: color, bucket of the best prior, length above the noise threshold, bits
+    ///   coded so far
+    /// - exponent_counts: color, bin of the non-zeros left, zigzag index in the 7x7, bit length of the best
+    ///   prior, exponent
+    /// - exponent_counts_x: color, bin of the non-zeros left, index in the edges, bit length of the best prior,
+    ///   exponent
+    /// - sign_counts: color, sign context, bit length of the best prior
+    /// - exponent_counts_dc: bit length of the uncertainty, bit length of the offset to the closest edge,
+    ///   exponent
+    /// - residual_noise_counts_dc: bit length of the uncertainty, bit
+    #[cfg(feature = "stats")]
+    pub fn branch_usage(&self, segment: i32, model: u8) -> Vec<BranchUsage> {
+        let mut usage = Vec::new();
+        let mut add_table = |table: &'static str, array: &dyn BranchArray| {
+            array.visit_indexed(&mut Vec::new(), &mut |indices, b| {
+                usage.push(BranchUsage {
+                    segment,
+                    model,
+                    table,
+                    indices: indices.to_vec(),
+                    visits: b.get_visits(),
+                    probability: b.get_probability(),
+                })
+            });
+        };
+
+        add_table("num_non_zeros_counts7x7", &self.num_non_zeros_counts7x7);
+        add_table("num_non_zeros_counts1x8", &self.num_non_zeros_counts1x8);
+        add_table("num_non_zeros_counts8x1", &self.num_non_zeros_counts8x1);
+        add_table("residual_noise_counts", &self.residual_noise_counts);
+        add_table("residual_threshold_counts", &self.residual_threshold_counts);
+        add_table("exponent_counts", &self.exponent_counts);
+        add_table("exponent_counts_x", &self.exponent_counts_x);
+        add_table("sign_counts", &self.sign_counts);
+        add_table("exponent_counts_dc", &self.exponent_counts_dc);
+        add_table("residual_noise_counts_dc", &self.residual_noise_counts_dc);
+
+        usage
+    }
+
+    pub fn set_snapshot_hook(&mut self, hook: Option<ModelSnapshotHook>) {
+        self.snapshot_hook = hook;
+    }
+
+    pub fn set_cancel_flag(&mut self, flag: Option<Arc<AtomicBool>>) {
+        self.cancel_flag = flag;
+    }
+
+    /// true once the cancel flag of the model was set
+    #[inline(always)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .as_ref()
+            .map_or(false, |f| f.load(Ordering::Relaxed))
+    }
+
+    #[cfg(feature = "debug-symmetry")]
+    pub fn set_symmetry_recorder(&mut self, recorder: Option<SymmetryRecorder>) {
+        self.symmetry_recorder = recorder;
+    }
+
+    /// a log for the bool coder of the thread segment starting at luma row segment, if the model has a recorder
+    #[cfg(feature = "debug-symmetry")]
+    pub fn new_symmetry_log(&self, segment: i32) -> Option<SymmetryLog> {
+        self.symmetry_recorder.as_ref().map(|r| r.new_log(segment))
+    }
+
+    /// calls the snapshot hook if it wants the state of the model before the block at dpos of component is coded
+    #[inline(always)]
+    pub fn call_snapshot_hook(&self, component: usize, dpos: i32) {
+        if let Some(hook) = &self.snapshot_hook {
+            if hook.dpos.map_or(true, |d| d == dpos) {
+                (hook.callback)(component, dpos, &self.snapshot());
+            }
+        }
+    }
+
+    #[cfg(feature = "experimental-tuning")]
+    pub fn get_tuning(&self) -> &ModelTuning {
+        &self.tuning
+    }
+
+    /// sets how the branches adapt and starts them with the initial counts of tuning
+    pub fn set_tuning(&mut self, tuning: &ModelTuning) {
+        if tuning.initial_probability != ModelTuning::default().initial_probability {
+            let counts = tuning.initial_counts();
+            self.for_each_branch(&mut |b| {
+                b.set_counts(counts);
+            });
+        }
+
+        #[cfg(feature = "experimental-tuning")]
+        {
+            self.tuning = *tuning;
+        }
+    }
+}
+
+impl CoefficientCodec for Model {
+    fn model(&self) -> &Model {
+        self
+    }
+
     #[inline(never)]
-    pub fn read_coef<R: Read>(
+    fn read_coef<R: Read>(
         &mut self,
         bool_reader: &mut VPXBoolReader<R>,
         color_index: usize,
@@ -117,9 +701,9 @@ impl Model {
     }
 
     #[inline(never)]
-    pub fn write_coef<W: Write>(
+    fn write_coef<W: Write, const ESTIMATE: bool>(
         &mut self,
-        bool_writer: &mut VPXBoolWriter<W>,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
         color_index: usize,
         coef: i16,
         coord: usize,
@@ -148,38 +732,7 @@ impl Model {
         .context(here!());
     }
 
-    fn get_coef_branches(
-        &mut self,
-        coord: usize,
-        num_non_zeros_bin: usize,
-        color_index: usize,
-        zig49: usize,
-        best_prior_bit_len: usize,
-    ) -> (
-        &mut [Branch; MAX_EXPONENT],
-        &mut Branch,
-        &mut [Branch; MAX_EXPONENT - 1],
-    ) {
-        debug_assert!(
-            coord / BAND_DIVISOR < RESIDUAL_NOISE_COUNTS_D1,
-            "coord {0} too high",
-            coord
-        );
-        debug_assert!(
-            num_non_zeros_bin < RESIDUAL_NOISE_COUNTS_D2,
-            "num_non_zeros_bin {0} too high",
-            num_non_zeros_bin
-        );
-
-        let exp =
-            &mut self.exponent_counts[color_index][num_non_zeros_bin][zig49][best_prior_bit_len];
-        let sign = &mut self.sign_counts[color_index][0][0];
-        let bits =
-            &mut self.residual_noise_counts[color_index][coord / BAND_DIVISOR][num_non_zeros_bin];
-        (exp, sign, bits)
-    }
-
-    pub fn read_dc<R: Read>(
+    fn read_dc<R: Read>(
         &mut self,
         bool_reader: &mut VPXBoolReader<R>,
         color_index: usize,
@@ -200,9 +753,9 @@ impl Model {
         .context(here!());
     }
 
-    pub fn write_dc<W: Write>(
+    fn write_dc<W: Write, const ESTIMATE: bool>(
         &mut self,
-        bool_writer: &mut VPXBoolWriter<W>,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
         color_index: usize,
         coef: i16,
         uncertainty: i16,
@@ -223,41 +776,9 @@ impl Model {
         .context(here!());
     }
 
-    fn get_dc_branches(
-        &mut self,
-        uncertainty: i16,
-        uncertainty2: i16,
-        color_index: usize,
-    ) -> (&mut [Branch; MAX_EXPONENT], &mut Branch, &mut [Branch; 10]) {
-        let len_abs_mxm = u16_bit_length(uncertainty.unsigned_abs());
-        let len_abs_offset_to_closest_edge = u16_bit_length(uncertainty2.unsigned_abs());
-
-        let exp = &mut self.exponent_counts_dc
-            [cmp::min(len_abs_mxm as usize, self.exponent_counts_dc.len() - 1)][cmp::min(
-            len_abs_offset_to_closest_edge as usize,
-            self.exponent_counts_dc[0].len() - 1,
-        )];
-
-        let sign = &mut self.sign_counts[color_index][0][if uncertainty2 >= 0 {
-            if uncertainty2 == 0 {
-                3
-            } else {
-                2
-            }
-        } else {
-            1
-        }];
-
-        let bits = &mut self.residual_noise_counts_dc[cmp::min(
-            self.residual_noise_counts_dc.len() - 1,
-            len_abs_mxm as usize,
-        )];
-        (exp, sign, bits)
-    }
-
-    pub fn write_non_zero_7x7_count<W: Write>(
+    fn write_non_zero_7x7_count<W: Write, const ESTIMATE: bool>(
         &mut self,
-        bool_writer: &mut VPXBoolWriter<W>,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
         color_index: usize,
         num_non_zeros_context: u8,
         num_non_zeros_7x7: u8,
@@ -274,9 +795,9 @@ impl Model {
             .context(here!());
     }
 
-    pub fn write_non_zero_edge_count<W: Write, const HORIZONTAL: bool>(
+    fn write_non_zero_edge_count<W: Write, const HORIZONTAL: bool, const ESTIMATE: bool>(
         &mut self,
-        bool_writer: &mut VPXBoolWriter<W>,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
         color_index: usize,
         est_eob: u8,
         num_non_zeros_7x7: u8,
@@ -297,7 +818,7 @@ impl Model {
             .context(here!());
     }
 
-    pub fn read_non_zero_7x7_count<R: Read>(
+    fn read_non_zero_7x7_count<R: Read>(
         &mut self,
         bool_reader: &mut VPXBoolReader<R>,
         color_index: usize,
@@ -311,7 +832,7 @@ impl Model {
             .context(here!())? as u8);
     }
 
-    pub fn read_non_zero_edge_count<R: Read, const HORIZONTAL: bool>(
+    fn read_non_zero_edge_count<R: Read, const HORIZONTAL: bool>(
         &mut self,
         bool_reader: &mut VPXBoolReader<R>,
         color_index: usize,
@@ -329,101 +850,7 @@ impl Model {
             .context(here!())? as u8);
     }
 
-    fn read_length_sign_coef<const A: usize, const B: usize, R: Read>(
-        bool_reader: &mut VPXBoolReader<R>,
-        magnitude_branches: &mut [Branch; A],
-        sign_branch: &mut Branch,
-        bits_branch: &mut [Branch; B],
-        mag_cmp: ModelComponent,
-        sign_cmp: ModelComponent,
-        bits_cmp: ModelComponent,
-    ) -> Result<i16> {
-        assert!(
-            A - 1 <= B,
-            "A (max mag) should be not more than B+1 (max bits). A={0} B={1} from {2:?}",
-            A,
-            B,
-            mag_cmp
-        );
-
-        let length = bool_reader
-            .get_unary_encoded(magnitude_branches, mag_cmp)
-            .context(here!())?;
-
-        let mut coef: i16 = 0;
-        if length != 0 {
-            let neg = !bool_reader.get(sign_branch, sign_cmp)?;
-            if length > 1 {
-                coef = bool_reader
-                    .get_n_bits(length - 1, bits_branch, bits_cmp)
-                    .context(here!())? as i16;
-            }
-
-            coef |= (1 << (length - 1)) as i16;
-
-            if neg {
-                coef = -coef;
-            }
-        }
-
-        return Ok(coef);
-    }
-
-    fn write_length_sign_coef<const A: usize, const B: usize, W: Write>(
-        bool_writer: &mut VPXBoolWriter<W>,
-        coef: i16,
-        magnitude_branches: &mut [Branch; A],
-        sign_branch: &mut Branch,
-        bits_branch: &mut [Branch; B],
-        mag_cmp: ModelComponent,
-        sign_cmp: ModelComponent,
-        bits_cmp: ModelComponent,
-    ) -> Result<()> {
-        assert!(
-            A - 1 <= B,
-            "A (max mag) should be not more than B+1 (max bits). A={0} B={1} from {2:?}",
-            A,
-            B,
-            mag_cmp,
-        );
-
-        let abs_coef = coef.unsigned_abs();
-        let coef_bit_len = u16_bit_length(abs_coef);
-
-        if coef_bit_len > A as u8 {
-            return err_exit_code(
-                ExitCode::CoefficientOutOfRange,
-                "coefficient > MAX_EXPONENT",
-            );
-        }
-
-        bool_writer.put_unary_encoded(coef_bit_len as usize, magnitude_branches, mag_cmp)?;
-        if coef != 0 {
-            bool_writer.put(coef >= 0, sign_branch, sign_cmp)?;
-        }
-
-        if coef_bit_len > 1 {
-            assert!(
-                (abs_coef & (1 << (coef_bit_len - 1))) != 0,
-                "Biggest bit must be set"
-            );
-            assert!(
-                (abs_coef & (1 << coef_bit_len)) == 0,
-                "Beyond Biggest bit must be zero"
-            );
-
-            bool_writer.put_n_bits(
-                abs_coef as usize,
-                coef_bit_len as usize - 1,
-                bits_branch,
-                bits_cmp,
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn read_edge_coefficient<R: Read>(
+    fn read_edge_coefficient<R: Read>(
         &mut self,
         bool_reader: &mut VPXBoolReader<R>,
         pt: &ProbabilityTables,
@@ -433,7 +860,8 @@ impl Model {
         ptcc8: &ProbabilityTablesCoefficientContext,
     ) -> Result<i16> {
         let length_branches = &mut self.exponent_counts_x[pt.get_color_index()]
-            [ptcc8.num_non_zeros_bin as usize][zig15offset][ptcc8.best_prior_bit_len as usize];
+            [ptcc8.num_non_zeros_bin as usize][zig15offset]
+            .get_mut()[ptcc8.best_prior_bit_len as usize];
 
         let length = bool_reader
             .get_unary_encoded(
@@ -511,9 +939,9 @@ impl Model {
         Ok(coef)
     }
 
-    pub fn write_edge_coefficient<W: Write>(
+    fn write_edge_coefficient<W: Write, const ESTIMATE: bool>(
         &mut self,
-        bool_writer: &mut VPXBoolWriter<W>,
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
         qt: &QuantizationTables,
         pt: &ProbabilityTables,
         coef: i16,
@@ -522,7 +950,8 @@ impl Model {
         ptcc8: &ProbabilityTablesCoefficientContext,
     ) -> Result<()> {
         let exp_array = &mut self.exponent_counts_x[pt.get_color_index()]
-            [ptcc8.num_non_zeros_bin as usize][zig15offset][ptcc8.best_prior_bit_len as usize];
+            [ptcc8.num_non_zeros_bin as usize][zig15offset]
+            .get_mut()[ptcc8.best_prior_bit_len as usize];
 
         let abs_coef = coef.unsigned_abs();
         let length = u16_bit_length(abs_coef) as usize;
@@ -607,6 +1036,170 @@ impl Model {
 
         Ok(())
     }
+}
+
+impl Model {
+    pub fn get_coef_branches(
+        &mut self,
+        coord: usize,
+        num_non_zeros_bin: usize,
+        color_index: usize,
+        zig49: usize,
+        best_prior_bit_len: usize,
+    ) -> (
+        &mut [Branch; MAX_EXPONENT],
+        &mut Branch,
+        &mut [Branch; MAX_EXPONENT - 1],
+    ) {
+        debug_assert!(
+            coord / BAND_DIVISOR < RESIDUAL_NOISE_COUNTS_D1,
+            "coord {0} too high",
+            coord
+        );
+        debug_assert!(
+            num_non_zeros_bin < RESIDUAL_NOISE_COUNTS_D2,
+            "num_non_zeros_bin {0} too high",
+            num_non_zeros_bin
+        );
+
+        let exp = &mut self.exponent_counts[color_index][num_non_zeros_bin][zig49].get_mut()
+            [best_prior_bit_len];
+        let sign = &mut self.sign_counts[color_index][0][0];
+        let bits =
+            &mut self.residual_noise_counts[color_index][coord / BAND_DIVISOR][num_non_zeros_bin];
+        (exp, sign, bits)
+    }
+
+    fn get_dc_branches(
+        &mut self,
+        uncertainty: i16,
+        uncertainty2: i16,
+        color_index: usize,
+    ) -> (&mut [Branch; MAX_EXPONENT], &mut Branch, &mut [Branch; 10]) {
+        let len_abs_mxm = u16_bit_length(uncertainty.unsigned_abs());
+        let len_abs_offset_to_closest_edge = u16_bit_length(uncertainty2.unsigned_abs());
+
+        let exp = &mut self.exponent_counts_dc
+            [cmp::min(len_abs_mxm as usize, self.exponent_counts_dc.len() - 1)][cmp::min(
+            len_abs_offset_to_closest_edge as usize,
+            self.exponent_counts_dc[0].len() - 1,
+        )];
+
+        let sign = &mut self.sign_counts[color_index][0][if uncertainty2 >= 0 {
+            if uncertainty2 == 0 {
+                3
+            } else {
+                2
+            }
+        } else {
+            1
+        }];
+
+        let bits = &mut self.residual_noise_counts_dc[cmp::min(
+            self.residual_noise_counts_dc.len() - 1,
+            len_abs_mxm as usize,
+        )];
+        (exp, sign, bits)
+    }
+
+    pub fn read_length_sign_coef<const A: usize, const B: usize, R: Read>(
+        bool_reader: &mut VPXBoolReader<R>,
+        magnitude_branches: &mut [Branch; A],
+        sign_branch: &mut Branch,
+        bits_branch: &mut [Branch; B],
+        mag_cmp: ModelComponent,
+        sign_cmp: ModelComponent,
+        bits_cmp: ModelComponent,
+    ) -> Result<i16> {
+        assert!(
+            A - 1 <= B,
+            "A (max mag) should be not more than B+1 (max bits). A={0} B={1} from {2:?}",
+            A,
+            B,
+            mag_cmp
+        );
+
+        let length = bool_reader
+            .get_unary_encoded(magnitude_branches, mag_cmp)
+            .context(here!())?;
+
+        let mut coef: i16 = 0;
+        if length != 0 {
+            let neg = !bool_reader.get(sign_branch, sign_cmp)?;
+            if length > 1 {
+                coef = bool_reader
+                    .get_n_bits(length - 1, bits_branch, bits_cmp)
+                    .context(here!())? as i16;
+            }
+
+            coef |= (1 << (length - 1)) as i16;
+
+            if neg {
+                coef = -coef;
+            }
+        }
+
+        return Ok(coef);
+    }
+
+    pub fn write_length_sign_coef<
+        const A: usize,
+        const B: usize,
+        W: Write,
+        const ESTIMATE: bool,
+    >(
+        bool_writer: &mut VPXBoolWriter<W, ESTIMATE>,
+        coef: i16,
+        magnitude_branches: &mut [Branch; A],
+        sign_branch: &mut Branch,
+        bits_branch: &mut [Branch; B],
+        mag_cmp: ModelComponent,
+        sign_cmp: ModelComponent,
+        bits_cmp: ModelComponent,
+    ) -> Result<()> {
+        assert!(
+            A - 1 <= B,
+            "A (max mag) should be not more than B+1 (max bits). A={0} B={1} from {2:?}",
+            A,
+            B,
+            mag_cmp,
+        );
+
+        let abs_coef = coef.unsigned_abs();
+        let coef_bit_len = u16_bit_length(abs_coef);
+
+        if coef_bit_len > A as u8 {
+            return err_exit_code(
+                ExitCode::CoefficientOutOfRange,
+                "coefficient > MAX_EXPONENT",
+            );
+        }
+
+        bool_writer.put_unary_encoded(coef_bit_len as usize, magnitude_branches, mag_cmp)?;
+        if coef != 0 {
+            bool_writer.put(coef >= 0, sign_branch, sign_cmp)?;
+        }
+
+        if coef_bit_len > 1 {
+            assert!(
+                (abs_coef & (1 << (coef_bit_len - 1))) != 0,
+                "Biggest bit must be set"
+            );
+            assert!(
+                (abs_coef & (1 << coef_bit_len)) == 0,
+                "Beyond Biggest bit must be zero"
+            );
+
+            bool_writer.put_n_bits(
+                abs_coef as usize,
+                coef_bit_len as usize - 1,
+                bits_branch,
+                bits_cmp,
+            )?;
+        }
+
+        Ok(())
+    }
 
     fn get_residual_threshold_counts_mut(
         &mut self,
@@ -615,13 +1208,14 @@ impl Model {
         min_threshold: i32,
         length: i32,
     ) -> &mut [Branch; RESIDUAL_THRESHOLD_COUNTS_D3] {
-        return &mut self.residual_threshold_counts[pt.get_color_index()][cmp::min(
+        self.residual_threshold_counts[pt.get_color_index()][cmp::min(
             (ptcc8.best_prior.abs() >> min_threshold) as usize,
             self.residual_threshold_counts[0].len() - 1,
         )][cmp::min(
             (length - min_threshold) as usize,
             self.residual_threshold_counts[0][0].len() - 1,
-        )];
+        )]
+        .get_mut()
     }
 
     fn get_non_zero_counts_edge_mut<const HORIZONTAL: bool>(
@@ -630,12 +1224,11 @@ impl Model {
         est_eob: u8,
         num_nonzeros: u8,
     ) -> &mut [[Branch; 4]; 3] {
+        let bin = ProbabilityTables::num_non_zeros_to_edge_bin(num_nonzeros) as usize;
         if HORIZONTAL {
-            return &mut self.num_non_zeros_counts8x1[color_index][est_eob as usize]
-                [(num_nonzeros as usize + 3) / 7];
+            &mut self.num_non_zeros_counts8x1[color_index][est_eob as usize][bin]
         } else {
-            return &mut self.num_non_zeros_counts1x8[color_index][est_eob as usize]
-                [(num_nonzeros as usize + 3) / 7];
+            &mut self.num_non_zeros_counts1x8[color_index][est_eob as usize][bin]
         }
     }
 
@@ -648,3 +1241,139 @@ impl Model {
             [ptcc8.best_prior_bit_len as usize]
     }
 }
+
+/// the exponent and residual threshold branches are only allocated once they are used, and until then look like
+/// they have their initial counts
+#[test]
+fn lazy_allocation() {
+    let allocated = |model: &Model| {
+        model
+            .exponent_counts
+            .iter()
+            .flatten()
+            .flatten()
+            .chain(model.exponent_counts_x.iter().flatten().flatten())
+            .filter(|b| b.branches.is_some())
+            .count()
+            + model
+                .residual_threshold_counts
+                .iter()
+                .flatten()
+                .flatten()
+                .filter(|b| b.branches.is_some())
+                .count()
+    };
+
+    let mut model = Model::default_boxed();
+    assert_eq!(allocated(&model), 0);
+
+    let mut eager = Model::default_boxed();
+    eager.allocate_all();
+    assert_eq!(
+        allocated(&eager),
+        BLOCK_TYPES
+            * (NUM_NON_ZERO_BINS * (49 + 15)
+                + RESIDUAL_THRESHOLD_COUNTS_D1 * RESIDUAL_THRESHOLD_COUNTS_D2)
+    );
+    assert_eq!(model.snapshot(), eager.snapshot());
+
+    let (exp, _, _) = model.get_coef_branches(9, 3, 1, 4, 2);
+    exp[0].record_and_update_true_obs();
+    assert_eq!(allocated(&model), 1);
+
+    let (exp, _, _) = eager.get_coef_branches(9, 3, 1, 4, 2);
+    exp[0].record_and_update_true_obs();
+    assert_eq!(model.snapshot(), eager.snapshot());
+}
+
+/// a model restored from a snapshot is in the same state, and snapshots that don't fit the model are refused
+#[test]
+fn snapshot_restore() {
+    let mut model = Model::default_boxed();
+    let mut i = 0u32;
+    model.for_each_branch(&mut |b| {
+        for _ in 0..i % 7 {
+            b.record_and_update_true_obs();
+        }
+        i += 1;
+    });
+
+    let snapshot = model.snapshot();
+    assert_eq!(
+        ModelSnapshot::from_bytes(&snapshot.to_bytes()).unwrap(),
+        snapshot
+    );
+
+    let mut restored = Model::default_boxed();
+    assert_eq!(restored.snapshot().first_difference(&snapshot), Some(1));
+    restored.restore(&snapshot).unwrap();
+    assert_eq!(restored.snapshot(), snapshot);
+
+    let mut truncated = snapshot.clone();
+    truncated.counts.pop();
+    assert!(restored.restore(&truncated).is_err());
+    assert_eq!(
+        snapshot.first_difference(&truncated),
+        Some(truncated.counts.len())
+    );
+
+    let mut invalid = snapshot.clone();
+    invalid.counts[0] = 0;
+    assert!(restored.restore(&invalid).is_err());
+    assert_eq!(restored.snapshot(), snapshot);
+}
+
+/// every model of a segment starts out the same, whatever was coded with the models before it
+#[test]
+fn segment_models_start_pristine() {
+    let new_model = || {
+        Model::new_for_segment(
+            None,
+            &ModelTuning::default(),
+            ModelInit::TrainedV7,
+            None,
+            false,
+        )
+    };
+
+    let mut first = new_model();
+    let pristine = first.snapshot();
+    first.begin_segment();
+    first.for_each_branch(&mut |b| b.record_and_update_true_obs());
+
+    let mut second = new_model();
+    assert_eq!(second.snapshot(), pristine);
+    second.begin_segment();
+
+    #[cfg(debug_assertions)]
+    assert!(first.segment_generation != second.segment_generation);
+}
+
+/// a model from new_for_segment that is used for a second thread segment is caught in debug builds
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "was carried over to another thread segment")]
+fn segment_model_is_not_reused() {
+    let mut model = Model::new_for_segment(
+        None,
+        &ModelTuning::default(),
+        ModelInit::Uniform,
+        None,
+        false,
+    );
+    model.begin_segment();
+    model.begin_segment();
+}
+
+/// MAX_MODEL_MEMORY covers all the branches of a model that has allocated all of them, and not much more
+#[test]
+fn max_model_memory_covers_all_branches() {
+    let mut model = Model::default_boxed();
+
+    let mut branches = 0;
+    model.for_each_branch(&mut |_| branches += 1);
+
+    let branch_memory = branches * std::mem::size_of::<Branch>();
+    assert!(branch_memory <= MAX_MODEL_MEMORY);
+    assert!(branch_memory + std::mem::size_of::<Model>() >= MAX_MODEL_MEMORY);
+}
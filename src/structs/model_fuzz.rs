@@ -0,0 +1,453 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Test utility for the test-utils feature: codes pseudo-random blocks with the per-block encoder and decoder,
+//! without the JPEG layers around them, so that a fuzzer or a property test can look for coefficients that the
+//! decoder doesn't get back. The same seed always generates the same image, so a failure can be replayed. The
+//! decisions of the bool coder itself can be roundtripped the same way with roundtrip_decisions_wrapper.
+
+use std::fmt;
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use default_boxed::DefaultBoxed;
+
+use crate::consts::{JPegType, FREQ_MAX, RASTER_TO_ALIGNED, RASTER_TO_JPEG_ZIGZAG};
+use crate::enabled_features::CoefficientCodecKind;
+use crate::helpers::*;
+use crate::lepton_error::LeptonError;
+use crate::metrics::ModelComponent;
+use crate::structs::block_based_image::{AlignedBlock, BlockBasedImage};
+use crate::structs::branch::Branch;
+use crate::structs::jpeg_header::JPegHeader;
+use crate::structs::lepton_decoder::lepton_decode_row_range;
+use crate::structs::lepton_encoder::lepton_encode_row_range;
+use crate::structs::lepton_format::get_quantization_tables;
+use crate::structs::model::Model;
+use crate::structs::probability_tables_set::ProbabilityTablesSet;
+use crate::structs::truncate_components::TruncateComponents;
+use crate::structs::vpx_bool_reader::VPXBoolReader;
+use crate::structs::vpx_bool_writer::VPXBoolWriter;
+
+/// the widest image in blocks that is generated
+const MAX_WIDTH: u64 = 24;
+
+/// the first place where the decoder didn't get back what the encoder coded
+#[derive(Debug)]
+pub enum Divergence {
+    /// a coefficient that was decoded with another value, at the raster index of the block (0 is the DC)
+    Coefficient {
+        component: usize,
+        dpos: i32,
+        index: usize,
+        encoded: i16,
+        decoded: i16,
+    },
+    /// a decision of the bool coder that was decoded the other way, in the order they were coded
+    Decision { index: usize, encoded: bool },
+    /// the encoder or the decoder failed, which for the decoder usually means that it lost track of the stream
+    Failed(LeptonError),
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Divergence::Coefficient {
+                component,
+                dpos,
+                index,
+                encoded,
+                decoded,
+            } => write!(
+                f,
+                "component {0} dpos {1} coefficient {2}: encoded {3} decoded {4}",
+                component, dpos, index, encoded, decoded
+            ),
+            Divergence::Decision { index, encoded } => {
+                write!(f, "decision {0}: encoded {1}", index, encoded)
+            }
+            Divergence::Failed(e) => write!(f, "failed: {0}", e),
+        }
+    }
+}
+
+/// splitmix64, which is good enough to generate test data and doesn't need a dependency
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// a number in min..=max
+    fn range(&mut self, min: i32, max: i32) -> i32 {
+        min + (self.next() % (max - min + 1) as u64) as i32
+    }
+
+    /// true with a probability of numerator / 256
+    fn chance(&mut self, numerator: u32) -> bool {
+        ((self.next() & 0xff) as u32) < numerator
+    }
+}
+
+/// generates an image of at least blocks blocks from seed, codes it as a single thread segment and decodes it
+/// again, and returns the first coefficient that the decoder got wrong, in the order that the blocks are coded.
+/// The seed also picks the width of the image, the number of components, the quantization tables and the
+/// options of the DC prediction.
+pub fn roundtrip_blocks_wrapper(seed: u64, blocks: usize) -> Result<Option<Divergence>> {
+    let mut rng = SplitMix64::new(seed);
+
+    let width = (rng.next() % MAX_WIDTH) as i32 + 1;
+    let blocks = i32::try_from(blocks.max(1))?;
+    let height = (blocks + width - 1) / width;
+    let cmpc = if rng.chance(64) { 1 } else { 3 };
+    let quantized_dc_prediction = rng.chance(128);
+    let fast_dc_prediction = rng.chance(128);
+    let separate_chroma_models = cmpc == 3 && rng.chance(64);
+
+    let header = synthetic_header(&mut rng, width, height, cmpc);
+
+    let mut truncate_components = TruncateComponents::new();
+    truncate_components.init(&header);
+    let quantization_tables = get_quantization_tables(&header).context(here!())?;
+
+    let mut image_data = Vec::new();
+    for (c, qt) in quantization_tables.iter().enumerate() {
+        let mut image = BlockBasedImage::new(&header, c, 0, height);
+        let q = qt.get_quantization_table();
+
+        // the DC is a random walk so that the prediction from the neighbors is about as good as for a photo
+        let max_dc = 1023 / i32::from(q[0]);
+        let mut dc = rng.range(-max_dc, max_dc);
+
+        for dpos in 0..width * height {
+            let mut block = [0i16; 64];
+
+            dc = (dc + rng.range(-max_dc / 8 - 1, max_dc / 8 + 1)).clamp(-max_dc, max_dc);
+            AlignedBlock::set_coefficient_zigzag_block(&mut block, 0, dc as i16);
+
+            // the higher frequencies are less likely to be non-zero and smaller when they are, and some blocks
+            // are flat
+            let density = if rng.chance(32) { 0 } else { rng.range(1, 255) };
+            for raster in 1..64 {
+                let zigzag = i32::from(RASTER_TO_JPEG_ZIGZAG[raster]);
+                if !rng.chance((density * 2 / (zigzag + 1)) as u32) {
+                    continue;
+                }
+
+                let max_value =
+                    i32::from(FREQ_MAX[raster]) / i32::from(q[raster]) / (zigzag / 4 + 1);
+                let magnitude = rng.range(1, max_value.max(1));
+                block[usize::from(RASTER_TO_ALIGNED[raster])] = if rng.chance(128) {
+                    magnitude as i16
+                } else {
+                    -magnitude as i16
+                };
+            }
+
+            image.set_block_data(dpos, &block);
+        }
+
+        image_data.push(image);
+    }
+
+    let pts = ProbabilityTablesSet::new(quantized_dc_prediction, fast_dc_prediction);
+
+    let mut encoded = Vec::new();
+    lepton_encode_row_range(
+        &pts,
+        &quantization_tables,
+        &image_data,
+        &mut encoded,
+        0,
+        &truncate_components,
+        0,
+        height,
+        true,
+        true,
+        &mut Model::default_boxed(),
+        separate_chroma_models
+            .then(Model::default_boxed)
+            .as_deref_mut(),
+        CoefficientCodecKind::Model,
+    )
+    .context(here!())?;
+
+    let mut decoded_data: Vec<BlockBasedImage> = (0..cmpc)
+        .map(|c| BlockBasedImage::new(&header, c, 0, height))
+        .collect();
+    lepton_decode_row_range(
+        &pts,
+        &quantization_tables,
+        &truncate_components,
+        &mut decoded_data,
+        &mut Cursor::new(encoded),
+        0,
+        height,
+        true,
+        true,
+        &mut Model::default_boxed(),
+        separate_chroma_models
+            .then(Model::default_boxed)
+            .as_deref_mut(),
+        CoefficientCodecKind::Model,
+        true,
+    )
+    .context(here!())?;
+
+    // with all components at the same resolution, each row of blocks is coded one component after the other
+    for y in 0..height {
+        for c in 0..cmpc {
+            for x in 0..width {
+                let dpos = y * width + x;
+                let encoded = image_data[c].get_block(dpos);
+                let decoded = decoded_data[c].get_block(dpos);
+
+                for index in 0..64 {
+                    if encoded.get_coefficient_raster(index)
+                        != decoded.get_coefficient_raster(index)
+                    {
+                        return Ok(Some(Divergence::Coefficient {
+                            component: c,
+                            dpos,
+                            index,
+                            encoded: encoded.get_coefficient_raster(index),
+                            decoded: decoded.get_coefficient_raster(index),
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// what coding a sequence of decisions with the bool coder took
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BoolCoderStats {
+    pub decisions: usize,
+    /// the size of the coded data, including the flush at the end
+    pub bytes: usize,
+    /// the sum of -log2 of the probability of each decision, which is about what an ideal coder would spend on
+    /// them. The coder gives every decision a share of at least one out of its range of 128 to 255, which this
+    /// counts as one out of 256, so the coder can spend less than this on unlikely decisions.
+    pub ideal_bits: f64,
+}
+
+/// codes the decisions with the bool writer, each one with a probability out of 256 that it is false, decodes
+/// them again with the bool reader, and returns the first one that the reader got wrong. The probabilities don't
+/// adapt, so a sequence can be made to keep the coder in any state it can get into, like a long run of 0xFF bytes
+/// that a carry can still go through.
+pub fn roundtrip_decisions_wrapper(
+    decisions: &[(u8, bool)],
+) -> Result<(BoolCoderStats, Option<Divergence>)> {
+    let mut encoded = Vec::new();
+    let mut writer = VPXBoolWriter::new(&mut encoded).context(here!())?;
+    let mut ideal_bits = 0.0;
+    for (probability, bit) in decisions {
+        let branch = &mut Branch::with_probability(*probability);
+        let share = if *bit {
+            256 - u32::from(branch.get_probability())
+        } else {
+            u32::from(branch.get_probability())
+        };
+        ideal_bits += (256.0 / f64::from(share.max(1))).log2();
+
+        writer
+            .put(*bit, branch, ModelComponent::Dummy)
+            .context(here!())?;
+    }
+    writer.finish().context(here!())?;
+
+    let stats = BoolCoderStats {
+        decisions: decisions.len(),
+        bytes: encoded.len(),
+        ideal_bits,
+    };
+
+    let mut reader = VPXBoolReader::new(&encoded[..]).context(here!())?;
+    for (index, (probability, bit)) in decisions.iter().enumerate() {
+        let decoded = reader
+            .get(
+                &mut Branch::with_probability(*probability),
+                ModelComponent::Dummy,
+            )
+            .context(here!())?;
+        if decoded != *bit {
+            return Ok((
+                stats,
+                Some(Divergence::Decision {
+                    index,
+                    encoded: *bit,
+                }),
+            ));
+        }
+    }
+
+    Ok((stats, None))
+}
+
+/// generates decisions decisions from seed and roundtrips them with roundtrip_decisions_wrapper. They come in
+/// stretches of up to 4096 of one kind: bits that mostly follow their probabilities like those of a model that
+/// predicts well, the extreme probabilities 1 and 255 with the bits they predict and now and then the other
+/// one, a run of the same bit with the same probability, and bits that have nothing to do with their
+/// probabilities.
+pub fn bool_coder_roundtrip_wrapper(
+    seed: u64,
+    decisions: usize,
+) -> Result<(BoolCoderStats, Option<Divergence>)> {
+    let mut rng = SplitMix64::new(seed);
+
+    let mut sequence = Vec::with_capacity(decisions);
+    while sequence.len() < decisions {
+        let stretch = ((rng.next() % 4096) as usize + 1).min(decisions - sequence.len());
+
+        match rng.next() % 4 {
+            0 => {
+                for _ in 0..stretch {
+                    let probability = rng.next() as u8;
+                    sequence.push((probability, !rng.chance(u32::from(probability))));
+                }
+            }
+            1 => {
+                for _ in 0..stretch {
+                    let probability = if rng.chance(128) { 1 } else { 255 };
+                    sequence.push((probability, (probability == 1) != rng.chance(16)));
+                }
+            }
+            2 => {
+                let decision = (rng.next() as u8, rng.chance(128));
+                sequence.extend(std::iter::repeat(decision).take(stretch));
+            }
+            _ => {
+                for _ in 0..stretch {
+                    sequence.push((rng.next() as u8, rng.chance(128)));
+                }
+            }
+        }
+    }
+
+    roundtrip_decisions_wrapper(&sequence)
+}
+
+/// a sequential header of width by height blocks with cmpc components that are all at full resolution, each with
+/// its own random quantization table
+fn synthetic_header(rng: &mut SplitMix64, width: i32, height: i32, cmpc: usize) -> JPegHeader {
+    let mut header = JPegHeader::new();
+
+    header.cmpc = cmpc;
+    header.img_width = width * 8;
+    header.img_height = height * 8;
+    header.jpeg_type = JPegType::Sequential;
+    header.sfhm = 1;
+    header.sfvm = 1;
+    header.mcuh = width;
+    header.mcuv = height;
+    header.mcuc = width * height;
+
+    for c in 0..cmpc {
+        // from close to lossless to heavily quantized, coarser for the higher frequencies like a real table
+        let base = rng.range(1, 16);
+        let slope = rng.range(0, 8);
+        for zigzag in 0..64 {
+            header.q_tables[c][zigzag] = (base + slope * zigzag as i32 / 4).min(255) as u16;
+        }
+
+        let ci = &mut header.cmp_info[c];
+        ci.q_table_index = c as u8;
+        ci.sfv = 1;
+        ci.sfh = 1;
+        ci.mbs = 1;
+        ci.bcv = height;
+        ci.bch = width;
+        ci.bc = width * height;
+        ci.ncv = height;
+        ci.nch = width;
+        ci.nc = width * height;
+        ci.sid = c as i32;
+        ci.jid = c as u8 + 1;
+    }
+
+    header
+}
+
+/// the harness finds no divergence in the coder as it is
+#[test]
+fn roundtrip_blocks_is_symmetric() {
+    for seed in 0..32 {
+        let divergence = roundtrip_blocks_wrapper(seed, 100).unwrap();
+        assert!(
+            divergence.is_none(),
+            "seed {0}: {1}",
+            seed,
+            divergence.unwrap()
+        );
+    }
+
+    // a single block
+    assert!(roundtrip_blocks_wrapper(1, 1).unwrap().is_none());
+}
+
+/// the harness finds no divergence for decisions that are random or all the same
+#[test]
+fn roundtrip_decisions_is_symmetric() {
+    let mut rng = SplitMix64::new(5);
+
+    let random: Vec<(u8, bool)> = (0..10000)
+        .map(|_| (rng.next() as u8, rng.chance(128)))
+        .collect();
+    assert!(roundtrip_decisions_wrapper(&random).unwrap().1.is_none());
+
+    for bit in [false, true] {
+        for probability in [0, 1, 128, 255] {
+            let same = vec![(probability, bit); 1000];
+            assert!(roundtrip_decisions_wrapper(&same).unwrap().1.is_none());
+        }
+    }
+
+    let (stats, divergence) = roundtrip_decisions_wrapper(&[]).unwrap();
+    assert!(divergence.is_none());
+    assert_eq!(stats.decisions, 0);
+}
+
+/// the generated decisions roundtrip, the same seed always gives the same statistics, and the coder spends about
+/// what an ideal coder would on them
+#[test]
+fn bool_coder_roundtrip_is_symmetric() {
+    for seed in 0..16 {
+        let (stats, divergence) = bool_coder_roundtrip_wrapper(seed, 20000).unwrap();
+        assert!(
+            divergence.is_none(),
+            "seed {0}: {1}",
+            seed,
+            divergence.unwrap()
+        );
+        assert_eq!(stats.decisions, 20000);
+        assert!(
+            (stats.bytes * 8) as f64 <= stats.ideal_bits * 1.02 + 64.0,
+            "seed {0}: {1:?}",
+            seed,
+            stats
+        );
+
+        assert_eq!(bool_coder_roundtrip_wrapper(seed, 20000).unwrap().0, stats);
+    }
+
+    assert_eq!(bool_coder_roundtrip_wrapper(1, 0).unwrap().0.bytes, {
+        roundtrip_decisions_wrapper(&[]).unwrap().0.bytes
+    });
+}
@@ -0,0 +1,254 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::{Cursor, Read};
+
+use anyhow::{Context, Result};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use default_boxed::DefaultBoxed;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+
+use crate::enabled_features::{CoefficientCodecKind, EnabledFeatures};
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::structs::lepton_encoder::lepton_encode_row_range;
+use crate::structs::lepton_format::{get_quantization_tables, read_jpeg};
+use crate::structs::model::Model;
+use crate::structs::probability_tables_set::ProbabilityTablesSet;
+
+/// start of a serialized primer, with the last byte being the version of the layout
+const MODEL_PRIMER_MAGIC: [u8; 4] = *b"LMP\x01";
+
+/// largest count a primer starts a branch with. The counts of a trained model are as confident as a branch
+/// gets, which keeps it from adapting to the image being encoded, so they are scaled down to this.
+const PRIMER_MAX_COUNT: u32 = 16;
+
+/// Experimental: the state of the adaptive model after encoding a set of training images, which the encoder
+/// and decoder of each thread segment start from instead of the default model. Images that are similar to the
+/// training images compress better, since the model doesn't have to learn their statistics from scratch. The
+/// file records the id of the primer, and can only be decoded with the same primer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelPrimer {
+    /// counts of each branch of the model, in the order of Model::for_each_branch
+    counts: Vec<u16>,
+    /// CRC32 of the counts, which identifies the primer in the files encoded with it
+    id: u32,
+}
+
+impl ModelPrimer {
+    fn from_model(model: &mut Model) -> Self {
+        let mut counts = Vec::new();
+        model.for_each_branch(&mut |b| counts.push(scale_counts(b.get_counts())));
+
+        let id = counts_crc(&counts);
+
+        ModelPrimer { counts, id }
+    }
+
+    /// the id that files encoded with this primer record in their header
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// serializes the primer so that it can be stored along with the files that need it
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&MODEL_PRIMER_MAGIC);
+        data.write_u32::<LittleEndian>(self.counts.len() as u32)
+            .unwrap();
+        data.write_u32::<LittleEndian>(self.id).unwrap();
+
+        let mut encoder = ZlibEncoder::new(data, Compression::default());
+        for c in &self.counts {
+            encoder.write_u16::<LittleEndian>(*c).unwrap();
+        }
+
+        encoder.finish().unwrap()
+    }
+
+    /// a model that starts from the counts of the primer
+    pub fn new_model(&self) -> Box<Model> {
+        let mut model = Model::default_boxed();
+        self.load_into(&mut model);
+
+        model
+    }
+
+    /// sets the counts of every branch of model to the ones of the primer
+    pub fn load_into(&self, model: &mut Model) {
+        let mut counts = self.counts.iter();
+        model.for_each_branch(&mut |b| {
+            // the counts were checked when the primer was created
+            b.set_counts(*counts.next().unwrap());
+        });
+    }
+}
+
+/// scales the true and false counts down to at most PRIMER_MAX_COUNT, keeping their ratio
+fn scale_counts(counts: u16) -> u16 {
+    // the all trues corner case stays as it is
+    if counts == 0x00ff {
+        return counts;
+    }
+
+    let (high, low) = (u32::from(counts >> 8), u32::from(counts & 0xff));
+    let max = high.max(low);
+    if max <= PRIMER_MAX_COUNT {
+        return counts;
+    }
+
+    let scale = |c: u32| ((c * PRIMER_MAX_COUNT + max / 2) / max).max(1);
+
+    ((scale(high) << 8) | scale(low)) as u16
+}
+
+fn counts_crc(counts: &[u16]) -> u32 {
+    let mut crc = Crc::new();
+    for c in counts {
+        crc.update(&c.to_le_bytes());
+    }
+
+    crc.sum()
+}
+
+/// reads a primer written by ModelPrimer::to_bytes, failing if it is damaged or was created for a different
+/// model
+pub fn primer_from_bytes_wrapper(data: &[u8]) -> Result<ModelPrimer> {
+    let mut reader = Cursor::new(data);
+
+    let mut magic = [0u8; 4];
+    if reader.read_exact(&mut magic).is_err() || magic != MODEL_PRIMER_MAGIC {
+        return err_exit_code(ExitCode::SyntaxError, "data isn't a model primer");
+    }
+
+    let mut model = Model::default_boxed();
+    let mut branch_count = 0;
+    model.for_each_branch(&mut |_| branch_count += 1);
+
+    let count = reader.read_u32::<LittleEndian>().context(here!())? as usize;
+    if count != branch_count {
+        return err_exit_code(
+            ExitCode::SyntaxError,
+            format!(
+                "primer has {0} branches but the model has {1}",
+                count, branch_count
+            )
+            .as_str(),
+        );
+    }
+
+    let id = reader.read_u32::<LittleEndian>().context(here!())?;
+
+    let mut decoder = ZlibDecoder::new(reader);
+    let mut counts = Vec::with_capacity(count);
+    for _ in 0..count {
+        counts.push(decoder.read_u16::<LittleEndian>().context(here!())?);
+    }
+
+    if counts_crc(&counts) != id {
+        return err_exit_code(ExitCode::SyntaxError, "model primer is damaged");
+    }
+
+    let mut valid = true;
+    let mut c = counts.iter();
+    model.for_each_branch(&mut |b| valid &= b.set_counts(*c.next().unwrap()));
+    if !valid {
+        return err_exit_code(ExitCode::SyntaxError, "model primer has invalid counts");
+    }
+
+    Ok(ModelPrimer { counts, id })
+}
+
+/// creates a primer by encoding the JPEGs one after the other with a single model, so that it ends up with the
+/// statistics of the training images. Each image is encoded as a single thread segment and the output is
+/// discarded.
+pub fn train_primer_wrapper(jpegs: &[&[u8]]) -> Result<ModelPrimer> {
+    if jpegs.is_empty() {
+        return err_exit_code(ExitCode::SyntaxError, "no images to train the primer on");
+    }
+
+    let pts = ProbabilityTablesSet::new(false, false);
+    let mut model = Model::default_boxed();
+
+    for jpeg in jpegs {
+        let (lp, image_data) = read_jpeg(
+            &mut Cursor::new(jpeg),
+            &EnabledFeatures::default(),
+            1,
+            |_jh| {},
+        )
+        .context(here!())?;
+
+        let quantization_tables = get_quantization_tables(&lp.jpeg_header).context(here!())?;
+
+        lepton_encode_row_range(
+            &pts,
+            &quantization_tables,
+            &image_data,
+            &mut std::io::sink(),
+            0,
+            &lp.truncate_components,
+            0,
+            lp.jpeg_header.cmp_info[0].bcv,
+            true,
+            true,
+            &mut model,
+            None,
+            CoefficientCodecKind::Model,
+        )
+        .context(here!())?;
+    }
+
+    Ok(ModelPrimer::from_model(&mut model))
+}
+
+#[cfg(test)]
+use crate::structs::branch::Branch;
+
+/// only the states that observations can lead to are accepted as counts
+#[test]
+fn branch_counts_validity() {
+    let mut b = Branch::new();
+    assert!(b.set_counts(0x0101));
+    assert!(b.set_counts(0x00ff));
+    assert!(b.set_counts(0xff01));
+    assert!(!b.set_counts(0));
+    assert!(!b.set_counts(0x0100));
+    assert!(!b.set_counts(0x0001));
+    assert_eq!(b.get_counts(), 0xff01);
+}
+
+/// a primer reads back the same, and damage to it is detected
+#[test]
+fn primer_round_trip() {
+    let jpeg = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("images")
+            .join("tiny.jpg"),
+    )
+    .unwrap();
+    let primer = train_primer_wrapper(&[&jpeg[..]]).unwrap();
+    assert_ne!(
+        primer.counts,
+        ModelPrimer::from_model(&mut Model::default_boxed()).counts
+    );
+
+    let data = primer.to_bytes();
+    assert_eq!(primer_from_bytes_wrapper(&data).unwrap(), primer);
+
+    // the id covers the counts
+    let mut damaged = data.clone();
+    damaged[8] ^= 1;
+    assert!(primer_from_bytes_wrapper(&damaged).is_err());
+
+    assert!(primer_from_bytes_wrapper(&data[..data.len() - 10]).is_err());
+    assert!(primer_from_bytes_wrapper(b"LMP").is_err());
+
+    // a model created from the primer has the same state
+    assert_eq!(ModelPrimer::from_model(&mut primer.new_model()), primer);
+}
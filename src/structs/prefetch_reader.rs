@@ -0,0 +1,185 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::cmp;
+use std::io::{Cursor, ErrorKind, Read, Result};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+
+use crate::structs::thread_pool;
+
+/// the most that the prefetcher reads at a time, which is also how it counts the blocks against its budget
+const PREFETCH_BLOCK_SIZE: usize = 65536;
+
+/// reads what the prefetcher of with_prefetch has read ahead of it
+pub struct PrefetchReader {
+    receiver: Receiver<Result<Vec<u8>>>,
+    current: Cursor<Vec<u8>>,
+}
+
+impl Read for PrefetchReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        loop {
+            let n = self.current.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                return Ok(n);
+            }
+
+            match self.receiver.recv() {
+                Ok(block) => self.current = Cursor::new(block?),
+                // the prefetcher got to the end of the input
+                Err(_) => return Ok(0),
+            }
+        }
+    }
+}
+
+/// runs f with a reader that gets the data of reader, which a job of the thread pool keeps reading while f is
+/// busy with what it already got, until max_read_ahead bytes are waiting for f. This way a slow input, such as a
+/// download, keeps arriving while the thread that reads it is held up by something else, such as writing out the
+/// segments that are done. The prefetcher stops once f returns, but only after the read it is in the middle of
+/// returns, and with_prefetch waits for that since the prefetcher borrows reader. If reader blocks, so does
+/// with_prefetch, even when f is done.
+pub fn with_prefetch<R: Read + Send, T>(
+    reader: &mut R,
+    max_read_ahead: u64,
+    f: impl FnOnce(&mut PrefetchReader) -> T,
+) -> T {
+    let blocks = cmp::max(1, max_read_ahead / PREFETCH_BLOCK_SIZE as u64) as usize;
+    let (sender, receiver) = sync_channel(blocks);
+
+    thread_pool::scope(1, |s| {
+        s.spawn(move || prefetch(reader, sender));
+
+        // dropped when f returns, which stops the prefetcher
+        let mut prefetch_reader = PrefetchReader {
+            receiver,
+            current: Cursor::new(Vec::new()),
+        };
+
+        f(&mut prefetch_reader)
+    })
+}
+
+/// reads reader to the end, or until the error that it returns, and sends what it read to sender until nobody
+/// takes it anymore
+fn prefetch<R: Read>(reader: &mut R, sender: SyncSender<Result<Vec<u8>>>) {
+    loop {
+        let mut block = vec![0; PREFETCH_BLOCK_SIZE];
+        let block = match reader.read(&mut block) {
+            Ok(0) => return,
+            Ok(n) => {
+                block.truncate(n);
+                Ok(block)
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => Err(e),
+        };
+
+        let failed = block.is_err();
+        if sender.send(block).is_err() || failed {
+            return;
+        }
+    }
+}
+
+/// the data comes through unchanged, as does the error that ends it, and the prefetcher stops reading once the
+/// reader is dropped
+#[test]
+fn prefetch_reader_passes_data_through() {
+    use std::io::Error;
+
+    let data: Vec<u8> = (0..1_000_000u32).map(|i| (i % 251) as u8).collect();
+
+    let mut copy = Vec::new();
+    with_prefetch(&mut Cursor::new(&data), 100_000, |r| {
+        r.read_to_end(&mut copy).unwrap()
+    });
+    assert!(copy == data);
+
+    struct FailingReader(usize);
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            if self.0 == 0 {
+                return Err(Error::new(ErrorKind::Other, "broken"));
+            }
+            self.0 -= 1;
+            buf[0] = 1;
+            Ok(1)
+        }
+    }
+
+    let mut copy = Vec::new();
+    let e = with_prefetch(&mut FailingReader(3), 0, |r| r.read_to_end(&mut copy)).unwrap_err();
+    assert_eq!(e.to_string(), "broken");
+    assert_eq!(copy, [1, 1, 1]);
+
+    // f stops reading early, so the prefetcher has to stop too for with_prefetch to return
+    let mut endless = std::io::repeat(7);
+    let first = with_prefetch(&mut endless, 0, |r| {
+        let mut buf = [0; 10];
+        r.read_exact(&mut buf).unwrap();
+        buf
+    });
+    assert_eq!(first, [7; 10]);
+}
+
+/// a read that blocks keeps with_prefetch from returning after f is done, until the read returns
+#[test]
+fn prefetch_waits_for_blocked_read() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::channel;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// hands out what is sent through the channel, and blocks until something is or the sender is gone
+    struct ChannelReader(Receiver<Vec<u8>>);
+
+    impl Read for ChannelReader {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            match self.0.recv() {
+                Ok(data) => {
+                    buf[..data.len()].copy_from_slice(&data);
+                    Ok(data.len())
+                }
+                Err(_) => Ok(0),
+            }
+        }
+    }
+
+    let (sender, receiver) = channel();
+    sender.send(vec![1, 2, 3]).unwrap();
+
+    let f_done = Arc::new(AtomicBool::new(false));
+    let released = Arc::new(AtomicBool::new(false));
+
+    // ends the blocked read a while after f is done
+    let releaser = {
+        let f_done = f_done.clone();
+        let released = released.clone();
+        std::thread::spawn(move || {
+            while !f_done.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            std::thread::sleep(Duration::from_millis(50));
+
+            released.store(true, Ordering::SeqCst);
+            drop(sender);
+        })
+    };
+
+    let first = with_prefetch(&mut ChannelReader(receiver), 0, |r| {
+        let mut buf = [0; 3];
+        r.read_exact(&mut buf).unwrap();
+        f_done.store(true, Ordering::SeqCst);
+        buf
+    });
+
+    assert_eq!(first, [1, 2, 3]);
+    assert!(released.load(Ordering::SeqCst));
+
+    releaser.join().unwrap();
+}
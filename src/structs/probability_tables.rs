@@ -18,13 +18,43 @@ use super::block_context::BlockContext;
 use super::neighbor_summary::NeighborSummary;
 use super::probability_tables_coefficient_context::ProbabilityTablesCoefficientContext;
 
-use wide::i16x8;
+use wide::{i16x8, i32x8};
+
+/// the bin of the branches for a number of non-zeros, NON_ZERO_TO_BIN for the bins the model has. It is padded to
+/// 64 entries so that a count masked to 6 bits indexes it without a bounds check.
+const NUM_NON_ZEROS_BIN: [u8; 64] = {
+    let mut table = [(NUM_NON_ZERO_BINS - 1) as u8; 64];
+    let mut i = 0;
+    while i < 50 {
+        table[i] = NON_ZERO_TO_BIN[NUM_NON_ZERO_BINS - 1][i];
+        i += 1;
+    }
+    table
+};
+
+/// the bin of the edge non-zero count branches for the number of non-zeros of the 7x7, padded to 64 entries like
+/// NUM_NON_ZEROS_BIN
+const NUM_NON_ZEROS_EDGE_BIN: [u8; 64] = {
+    let mut table = [7; 64];
+    let mut i = 0;
+    while i < 50 {
+        table[i] = ((i + 3) / 7) as u8;
+        i += 1;
+    }
+    table
+};
 
 pub struct ProbabilityTables {
     left_present: bool,
     above_present: bool,
     all_present: bool,
+    /// how many times the non-zeros of the block above and on the left count for the context of the number of
+    /// non-zeros, see calc_non_zero_counts_context_7x7
+    above_weight: u8,
+    left_weight: u8,
     color: usize,
+    quantized_dc_prediction: bool,
+    fast_dc_prediction: bool,
 }
 
 pub struct PredictDCResult {
@@ -35,12 +65,22 @@ pub struct PredictDCResult {
 }
 
 impl ProbabilityTables {
-    pub fn new(kcolor: usize, in_left_present: bool, in_above_present: bool) -> ProbabilityTables {
+    pub fn new(
+        kcolor: usize,
+        in_left_present: bool,
+        in_above_present: bool,
+        quantized_dc_prediction: bool,
+        fast_dc_prediction: bool,
+    ) -> ProbabilityTables {
         return ProbabilityTables {
             left_present: in_left_present,
             above_present: in_above_present,
             all_present: in_left_present && in_above_present,
+            above_weight: u8::from(in_above_present) * (2 - u8::from(in_left_present)),
+            left_weight: u8::from(in_left_present) * (2 - u8::from(in_above_present)),
             color: kcolor,
+            quantized_dc_prediction,
+            fast_dc_prediction,
         };
     }
 
@@ -77,41 +117,41 @@ impl ProbabilityTables {
         return retval;
     }
 
+    pub fn get_component(&self) -> usize {
+        self.color
+    }
+
     pub fn get_color_index(&self) -> usize {
         return if self.color == 0 { 0 } else { 1 };
     }
 
     pub fn num_non_zeros_to_bin(num_non_zeros: u8) -> u8 {
-        return NON_ZERO_TO_BIN[NUM_NON_ZERO_BINS - 1][num_non_zeros as usize];
+        NUM_NON_ZEROS_BIN[usize::from(num_non_zeros & 63)]
     }
 
+    /// the bin of the edge non-zero count branches for num_non_zeros_7x7
+    pub fn num_non_zeros_to_edge_bin(num_non_zeros_7x7: u8) -> u8 {
+        NUM_NON_ZEROS_EDGE_BIN[usize::from(num_non_zeros_7x7 & 63)]
+    }
+
+    /// the average of the non-zeros of the blocks above and on the left, or the non-zeros of the one of them
+    /// that is present, or zero if neither is. This is on the path of every block, so both are always read and
+    /// a missing block is weighted out instead of branching on which of them are present.
     pub fn calc_non_zero_counts_context_7x7<const ALL_PRESENT: bool>(
         &self,
         block: &BlockContext,
         num_non_zeros: &[NeighborSummary],
     ) -> u8 {
-        let mut num_non_zeros_above = 0;
-        let mut num_non_zeros_left = 0;
-        if ALL_PRESENT || self.above_present {
-            num_non_zeros_above = block.get_non_zeros_above(num_non_zeros);
-        }
-
-        if ALL_PRESENT || self.left_present {
-            num_non_zeros_left = block.get_non_zeros_left(num_non_zeros);
-        }
-
-        let num_non_zeros_context;
-        if (!ALL_PRESENT) && self.above_present && !self.left_present {
-            num_non_zeros_context = (num_non_zeros_above + 1) / 2;
-        } else if (!ALL_PRESENT) && self.left_present && !self.above_present {
-            num_non_zeros_context = (num_non_zeros_left + 1) / 2;
-        } else if ALL_PRESENT || (self.left_present && self.above_present) {
-            num_non_zeros_context = (num_non_zeros_above + num_non_zeros_left + 2) / 4;
+        let (above_weight, left_weight) = if ALL_PRESENT {
+            (1, 1)
         } else {
-            num_non_zeros_context = 0;
-        }
+            (self.above_weight, self.left_weight)
+        };
+
+        let above = u32::from(block.get_non_zeros_above(num_non_zeros));
+        let left = u32::from(block.get_non_zeros_left(num_non_zeros));
 
-        return num_non_zeros_context;
+        ((above * u32::from(above_weight) + left * u32::from(left_weight) + 2) >> 2) as u8
     }
 
     // calculates the average of the prior values from their corresponding value in the left, above and above/left block
@@ -187,8 +227,7 @@ impl ProbabilityTables {
                 compute_lak_coeffs_a[i] = (sign * above[cur_coef]).into();
             }
 
-            coef_idct =
-                &qt.get_icos_idct_edge8192_dequantized_x()[coefficient * 8..(coefficient + 1) * 8];
+            coef_idct = qt.get_icos_idct_edge8192_dequantized_x(coefficient);
         } else if !HORIZONTAL && (ALL_PRESENT || self.left_present) {
             assert!(coefficient <= 56); // avoid bounds check later
 
@@ -206,7 +245,7 @@ impl ProbabilityTables {
                 compute_lak_coeffs_a[i] = (sign * left[cur_coef]).into();
             }
 
-            coef_idct = &qt.get_icos_idct_edge8192_dequantized_y()[coefficient..coefficient + 8];
+            coef_idct = qt.get_icos_idct_edge8192_dequantized_y(coefficient >> 3);
         } else {
             return ProbabilityTablesCoefficientContext {
                 best_prior: 0,
@@ -215,17 +254,9 @@ impl ProbabilityTables {
             };
         }
 
-        let mut best_prior: i32 = 0;
-        for i in 0..8 {
-            // some extreme coefficents can cause this to overflow, but since this is just a predictor, no need to panic
-            best_prior = best_prior.wrapping_add(
-                coef_idct[i]
-                    .wrapping_mul(compute_lak_coeffs_a[i].wrapping_sub(compute_lak_coeffs_x[i])),
-            );
-            // rounding towards zero before adding coeffs_a[0] helps ratio slightly, but this is cheaper
-        }
-
-        best_prior /= coef_idct[0];
+        // rounding towards zero before adding coeffs_a[0] helps ratio slightly, but this is cheaper
+        let best_prior =
+            edge_prior_dot(coef_idct, &compute_lak_coeffs_a, &compute_lak_coeffs_x) / coef_idct[0];
 
         return ProbabilityTablesCoefficientContext {
             best_prior,
@@ -254,11 +285,17 @@ impl ProbabilityTables {
         block_context: &BlockContext,
         num_non_zeros: &[NeighborSummary],
     ) -> PredictDCResult {
+        let q = qt.get_quantization_table();
+        let q0 = i32::from(q[0]);
+
+        if self.fast_dc_prediction {
+            return self.predict_dc_from_neighbor_dcs::<ALL_PRESENT>(image_data, q0, block_context);
+        }
+
         let mut uncertainty_val: i16 = 0;
         let mut uncertainty2_val: i16 = 0;
 
         let mut pixels_sans_dc = [0i16; 64];
-        let q = qt.get_quantization_table();
 
         let mut avgmed = 0;
 
@@ -325,16 +362,80 @@ impl ProbabilityTables {
             }
 
             uncertainty2_val = (far_afield_value >> 3) as i16;
+
+            uncertainty_val = self.scale_dc_uncertainty(uncertainty_val, q0);
+            uncertainty2_val = self.scale_dc_uncertainty(uncertainty2_val, q0);
         }
 
         return PredictDCResult {
-            predicted_dc: ((avgmed / i32::from(q[0])) + 4) >> 3,
+            predicted_dc: ((avgmed / q0) + 4) >> 3,
             uncertainty: uncertainty_val,
             uncertainty2: uncertainty2_val,
             advanced_predict_dc_pixels_sans_dc: pixels_sans_dc,
         };
     }
 
+    /// the cheap DC prediction of fast_dc_prediction, which skips the IDCT of the block and predicts the DC from
+    /// the DCs of the neighbor blocks with the median edge detector of LOCO-I: left + above - above left, clamped
+    /// to between left and above. The uncertainties come from how far left and above are apart and are on the
+    /// same scale as the ones of adv_predict_dc_pix. The edge pixels for the NeighborSummary are left at zero,
+    /// since nothing reads them with this prediction.
+    fn predict_dc_from_neighbor_dcs<const ALL_PRESENT: bool>(
+        &self,
+        image_data: &BlockBasedImage,
+        q0: i32,
+        block_context: &BlockContext,
+    ) -> PredictDCResult {
+        let mut predicted_dc = 0;
+        let mut uncertainty_val: i16 = 0;
+        let mut uncertainty2_val: i16 = 0;
+
+        if ALL_PRESENT || (self.left_present && self.above_present) {
+            let left = i32::from(block_context.left(image_data).get_dc());
+            let above = i32::from(block_context.above(image_data).get_dc());
+            let above_left = i32::from(block_context.above_left(image_data).get_dc());
+
+            predicted_dc = (left + above - above_left).clamp(min(left, above), max(left, above));
+
+            let offset_left = left - predicted_dc;
+            let offset_above = above - predicted_dc;
+            let far_afield_value = if offset_left.abs() < offset_above.abs() {
+                offset_left
+            } else {
+                offset_above
+            };
+
+            let saturate = |v: i32| v.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+            uncertainty_val = saturate(((left - above).abs() * q0) >> 3);
+            uncertainty2_val = saturate(far_afield_value * q0);
+
+            uncertainty_val = self.scale_dc_uncertainty(uncertainty_val, q0);
+            uncertainty2_val = self.scale_dc_uncertainty(uncertainty2_val, q0);
+        } else if self.left_present {
+            predicted_dc = i32::from(block_context.left(image_data).get_dc());
+        } else if self.above_present {
+            predicted_dc = i32::from(block_context.above(image_data).get_dc());
+        }
+
+        PredictDCResult {
+            predicted_dc,
+            uncertainty: uncertainty_val,
+            uncertainty2: uncertainty2_val,
+            advanced_predict_dc_pixels_sans_dc: [0; 64],
+        }
+    }
+
+    /// with quantized DC prediction, measures an uncertainty of the DC prediction in steps of the quantization of
+    /// the DC rather than in pixels (a quantization of 16 keeps the scale), so that finely quantized DCs get the
+    /// branches for uncertain predictions
+    fn scale_dc_uncertainty(&self, uncertainty: i16, q0: i32) -> i16 {
+        if self.quantized_dc_prediction {
+            (i32::from(uncertainty) * 16 / q0) as i16
+        } else {
+            uncertainty
+        }
+    }
+
     fn estimate_dir_average(dc_estimates: &[i16; 8], min_dc: &mut i16, max_dc: &mut i16) -> i32 {
         let mut dir_average: i32 = 0;
         for i in 0..8 {
@@ -373,3 +474,242 @@ impl ProbabilityTables {
         return dir_average;
     }
 }
+
+/// the sum of the weights times the differences between a and x as a multiply and a reduction of 8 lanes, which
+/// are a single instruction each with AVX2, two with SSE4.1 and are emulated by wide lane by lane without them.
+/// Some extreme coefficents can cause this to overflow, but since this is just a predictor, it wraps around like
+/// edge_prior_dot_scalar instead of panicking.
+#[inline(always)]
+fn edge_prior_dot(weights: &[i32; 8], a: &[i32; 8], x: &[i32; 8]) -> i32 {
+    let products = i32x8::new(*weights) * (i32x8::new(*a) - i32x8::new(*x));
+
+    // adding the halves of the lanes is the order of a horizontal reduction, wrapping addition doesn't depend on it
+    let p = products.to_array();
+    let quads = [
+        p[0].wrapping_add(p[4]),
+        p[1].wrapping_add(p[5]),
+        p[2].wrapping_add(p[6]),
+        p[3].wrapping_add(p[7]),
+    ];
+    quads[0]
+        .wrapping_add(quads[2])
+        .wrapping_add(quads[1].wrapping_add(quads[3]))
+}
+
+/// the prediction of the edge coefficients as a scalar loop the way it was calculated before edge_prior_dot
+#[cfg(test)]
+fn edge_prior_dot_scalar(weights: &[i32; 8], a: &[i32; 8], x: &[i32; 8]) -> i32 {
+    let mut best_prior: i32 = 0;
+    for i in 0..8 {
+        best_prior = best_prior.wrapping_add(weights[i].wrapping_mul(a[i].wrapping_sub(x[i])));
+    }
+    best_prior
+}
+
+/// the bin of the context of the number of non-zeros as it was calculated before it was made branch free, see
+/// calc_non_zero_counts_context_7x7
+#[cfg(test)]
+fn calc_non_zero_counts_bin_7x7_reference(
+    pt: &ProbabilityTables,
+    block: &BlockContext,
+    num_non_zeros: &[NeighborSummary],
+) -> u8 {
+    let mut num_non_zeros_above = 0;
+    let mut num_non_zeros_left = 0;
+    if pt.above_present {
+        num_non_zeros_above = block.get_non_zeros_above(num_non_zeros);
+    }
+
+    if pt.left_present {
+        num_non_zeros_left = block.get_non_zeros_left(num_non_zeros);
+    }
+
+    let num_non_zeros_context;
+    if pt.above_present && !pt.left_present {
+        num_non_zeros_context = (num_non_zeros_above + 1) / 2;
+    } else if pt.left_present && !pt.above_present {
+        num_non_zeros_context = (num_non_zeros_left + 1) / 2;
+    } else if pt.left_present && pt.above_present {
+        num_non_zeros_context = (num_non_zeros_above + num_non_zeros_left + 2) / 4;
+    } else {
+        num_non_zeros_context = 0;
+    }
+
+    NON_ZERO_TO_BIN[NUM_NON_ZERO_BINS - 1][num_non_zeros_context as usize]
+}
+
+#[cfg(test)]
+fn calc_non_zero_counts_bin_7x7(
+    pt: &ProbabilityTables,
+    block: &BlockContext,
+    num_non_zeros: &[NeighborSummary],
+) -> u8 {
+    let context = if pt.is_all_present() {
+        pt.calc_non_zero_counts_context_7x7::<true>(block, num_non_zeros)
+    } else {
+        pt.calc_non_zero_counts_context_7x7::<false>(block, num_non_zeros)
+    };
+
+    ProbabilityTables::num_non_zeros_to_bin(context)
+}
+
+/// the second block of the second row of an image that is two blocks wide, with above as the non-zeros of the
+/// block above it and left as the ones of the block on its left
+#[cfg(test)]
+fn non_zeros_neighbors(above: u8, left: u8) -> (BlockBasedImage, Vec<NeighborSummary>) {
+    let image = BlockBasedImage::from_blocks(2, &[&Default::default(); 4]);
+    let mut num_non_zeros = vec![NeighborSummary::new(); 4];
+    num_non_zeros[1].set_num_non_zeros(above);
+    num_non_zeros[2].set_num_non_zeros(left);
+    (image, num_non_zeros)
+}
+
+/// the tables give the same bins as the comparisons did for every count that a block can have
+#[test]
+fn non_zero_counts_context_matches_reference() {
+    for above in 0..=49 {
+        for left in 0..=49 {
+            let (image, num_non_zeros) = non_zeros_neighbors(above, left);
+            let mut context = image.off_y(1);
+            context.next(true);
+
+            for (left_present, above_present) in
+                [(false, false), (true, false), (false, true), (true, true)]
+            {
+                let pt = ProbabilityTables::new(0, left_present, above_present, false, false);
+                assert_eq!(
+                    calc_non_zero_counts_bin_7x7(&pt, &context, &num_non_zeros),
+                    calc_non_zero_counts_bin_7x7_reference(&pt, &context, &num_non_zeros)
+                );
+            }
+
+            // the first block of the image doesn't read before the start for the left neighbor it doesn't have
+            let pt = ProbabilityTables::new(0, false, false, false, false);
+            assert_eq!(
+                calc_non_zero_counts_bin_7x7(&pt, &image.off_y(0), &num_non_zeros),
+                0
+            );
+        }
+
+        assert_eq!(
+            ProbabilityTables::num_non_zeros_to_edge_bin(above),
+            (above + 3) / 7
+        );
+    }
+}
+
+/// the prediction of an edge coefficient with the weights multiplied out of the quantization table in the flat
+/// layout that they had before they were rows of 8, and the scalar loop
+#[cfg(test)]
+fn calc_edge_prior_reference(
+    q: &[u16; 64],
+    coefficient: usize,
+    here: &[i16; 64],
+    neighbor: &[i16; 64],
+) -> i32 {
+    let horizontal = coefficient < 8;
+
+    let mut weights = [0i32; 64];
+    for pixel_row in 0..8 {
+        for i in 0..8 {
+            let q = if horizontal {
+                q[(i * 8) + pixel_row]
+            } else {
+                q[(pixel_row * 8) + i]
+            };
+            weights[(pixel_row * 8) + i] = ICOS_BASED_8192_SCALED[i * 8] * i32::from(q);
+        }
+    }
+
+    let (coef_idct, stride) = if horizontal {
+        (&weights[coefficient * 8..(coefficient + 1) * 8], 8)
+    } else {
+        (&weights[coefficient..coefficient + 8], 1)
+    };
+
+    let mut x = [0i32; 8];
+    let mut a = [0i32; 8];
+    for i in 0..8 {
+        let cur_coef = usize::from(RASTER_TO_ALIGNED[coefficient + (i * stride)]);
+        let sign = if (i & 1) != 0 { -1 } else { 1 };
+
+        x[i] = if i != 0 { here[cur_coef].into() } else { 0 };
+        a[i] = (sign * neighbor[cur_coef]).into();
+    }
+
+    edge_prior_dot_scalar(coef_idct.try_into().unwrap(), &a, &x) / coef_idct[0]
+}
+
+#[cfg(test)]
+fn coefficient_block(values: &[i16]) -> [i16; 64] {
+    values.try_into().unwrap()
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    /// the multiply and reduction give the same sum as the scalar loop, also where it wraps around
+    #[test]
+    fn edge_prior_dot_matches_scalar(
+        weights in proptest::array::uniform8(proptest::num::i32::ANY),
+        a in proptest::array::uniform8(proptest::num::i32::ANY),
+        x in proptest::array::uniform8(proptest::num::i32::ANY),
+    ) {
+        proptest::prop_assert_eq!(
+            edge_prior_dot(&weights, &a, &x),
+            edge_prior_dot_scalar(&weights, &a, &x)
+        );
+    }
+
+    /// the prediction of every edge coefficient from neighbor blocks with coefficients in range is the one of the
+    /// flat weights and the scalar loop
+    #[test]
+    fn edge_prior_matches_reference(
+        q in proptest::collection::vec(1u16..=255, 64),
+        here in proptest::collection::vec(-(MAX_COEFFICIENT as i16)..=(MAX_COEFFICIENT as i16), 64),
+        above in proptest::collection::vec(-(MAX_COEFFICIENT as i16)..=(MAX_COEFFICIENT as i16), 64),
+        left in proptest::collection::vec(-(MAX_COEFFICIENT as i16)..=(MAX_COEFFICIENT as i16), 64),
+    ) {
+        let mut jpeg_header = crate::structs::jpeg_header::JPegHeader::new();
+        jpeg_header.q_tables[0].copy_from_slice(&q);
+        jpeg_header.cmp_info[0].q_table_index = 0;
+        let qt = QuantizationTables::new(&jpeg_header, 0);
+        let q = qt.get_quantization_table();
+
+        let (here, above, left) = (
+            coefficient_block(&here),
+            coefficient_block(&above),
+            coefficient_block(&left),
+        );
+        let pt = ProbabilityTables::new(0, true, true, false, false);
+
+        for coefficient in 1..8 {
+            let context = pt.calc_coefficient_context8_lak::<true, true>(
+                &qt,
+                coefficient,
+                &here,
+                &above,
+                &left,
+                0,
+            );
+            proptest::prop_assert_eq!(
+                context.best_prior,
+                calc_edge_prior_reference(q, coefficient, &here, &above)
+            );
+        }
+
+        for coefficient in (8..64).step_by(8) {
+            let context = pt.calc_coefficient_context8_lak::<true, false>(
+                &qt,
+                coefficient,
+                &here,
+                &above,
+                &left,
+                0,
+            );
+            proptest::prop_assert_eq!(
+                context.best_prior,
+                calc_edge_prior_reference(q, coefficient, &here, &left)
+            );
+        }
+    }
+}
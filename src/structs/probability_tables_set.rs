@@ -20,23 +20,35 @@ pub struct ProbabilityTablesSet {
 fn make_probability_tables_tuple(
     left: bool,
     above: bool,
+    quantized_dc_prediction: bool,
+    fast_dc_prediction: bool,
 ) -> [ProbabilityTables; COLOR_CHANNEL_NUM_BLOCK_TYPES] {
-    return [
-        ProbabilityTables::new(0, left, above),
-        ProbabilityTables::new(1, left, above),
-        ProbabilityTables::new(2, left, above),
-    ];
+    let new = |color| {
+        ProbabilityTables::new(
+            color,
+            left,
+            above,
+            quantized_dc_prediction,
+            fast_dc_prediction,
+        )
+    };
+    [new(0), new(1), new(2)]
 }
 
 impl ProbabilityTablesSet {
-    pub fn new() -> Self {
+    /// quantized_dc_prediction and fast_dc_prediction pick how the DC is predicted, see the fields of the same
+    /// name in LeptonHeader
+    pub fn new(quantized_dc_prediction: bool, fast_dc_prediction: bool) -> Self {
+        let tables = |left, above| {
+            make_probability_tables_tuple(left, above, quantized_dc_prediction, fast_dc_prediction)
+        };
         return ProbabilityTablesSet {
-            corner: make_probability_tables_tuple(false, false),
-            top: make_probability_tables_tuple(true, false),
-            mid_left: make_probability_tables_tuple(false, true),
-            middle: make_probability_tables_tuple(true, true),
-            mid_right: make_probability_tables_tuple(true, true),
-            width_one: make_probability_tables_tuple(false, true),
+            corner: tables(false, false),
+            top: tables(true, false),
+            mid_left: tables(false, true),
+            middle: tables(true, true),
+            mid_right: tables(true, true),
+            width_one: tables(false, true),
         };
     }
 }
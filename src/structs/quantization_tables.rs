@@ -10,8 +10,9 @@ use crate::helpers::*;
 use super::jpeg_header::JPegHeader;
 
 pub struct QuantizationTables {
-    icos_idct_edge8192_dequantized_x: [i32; 64],
-    icos_idct_edge8192_dequantized_y: [i32; 64],
+    /// the weights of the prediction of the edge coefficients, one contiguous row of 8 for each coefficient
+    icos_idct_edge8192_dequantized_x: [[i32; 8]; 8],
+    icos_idct_edge8192_dequantized_y: [[i32; 8]; 8],
     icos_idct_linear8192_dequantized: [i32; 64],
     quantization_table: [u16; 64],
     freq_max: [u16; 64],
@@ -22,8 +23,8 @@ pub struct QuantizationTables {
 impl QuantizationTables {
     pub fn new(jpeg_header: &JPegHeader, component: usize) -> Self {
         let mut retval = QuantizationTables {
-            icos_idct_edge8192_dequantized_x: [0; 64],
-            icos_idct_edge8192_dequantized_y: [0; 64],
+            icos_idct_edge8192_dequantized_x: [[0; 8]; 8],
+            icos_idct_edge8192_dequantized_y: [[0; 8]; 8],
             icos_idct_linear8192_dequantized: [0; 64],
             quantization_table: [0; 64],
             freq_max: [0; 64],
@@ -49,10 +50,10 @@ impl QuantizationTables {
                 self.icos_idct_linear8192_dequantized[(pixel_row * 8) + i] =
                     ICOS_IDCT_LINEAR_8192_SCALED[(pixel_row * 8) + i]
                         * (self.quantization_table[i] as i32);
-                self.icos_idct_edge8192_dequantized_x[(pixel_row * 8) + i] = ICOS_BASED_8192_SCALED
+                self.icos_idct_edge8192_dequantized_x[pixel_row][i] = ICOS_BASED_8192_SCALED
                     [i * 8]
                     * (self.quantization_table[(i * 8) + pixel_row] as i32);
-                self.icos_idct_edge8192_dequantized_y[(pixel_row * 8) + i] = ICOS_BASED_8192_SCALED
+                self.icos_idct_edge8192_dequantized_y[pixel_row][i] = ICOS_BASED_8192_SCALED
                     [i * 8]
                     * (self.quantization_table[(pixel_row * 8) + i] as i32);
             }
@@ -72,12 +73,14 @@ impl QuantizationTables {
         }
     }
 
-    pub fn get_icos_idct_edge8192_dequantized_x(&self) -> &[i32] {
-        &self.icos_idct_edge8192_dequantized_x
+    /// the weights of the prediction of the coefficient in column x of the first row
+    pub fn get_icos_idct_edge8192_dequantized_x(&self, x: usize) -> &[i32; 8] {
+        &self.icos_idct_edge8192_dequantized_x[x]
     }
 
-    pub fn get_icos_idct_edge8192_dequantized_y(&self) -> &[i32] {
-        &self.icos_idct_edge8192_dequantized_y
+    /// the weights of the prediction of the coefficient in row y of the first column
+    pub fn get_icos_idct_edge8192_dequantized_y(&self, y: usize) -> &[i32; 8] {
+        &self.icos_idct_edge8192_dequantized_y[y]
     }
 
     pub fn get_quantization_table(&self) -> &[u16; 64] {
@@ -0,0 +1,208 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::sync::{Condvar, Mutex};
+
+/// holds back the thread that reads the multiplexed data of the decoder once the chunks it has handed to the
+/// workers and that they haven't taken yet add up to EnabledFeatures::max_read_ahead. The reader only waits while
+/// one of the workers is busy with the data it already has, since the chunk that a worker waits for can be behind
+/// the ones that are held for later segments, so the limit can be passed by a worker that would otherwise never
+/// get its data. A worker that waits for data counts as working again as soon as the reader hands it some, rather
+/// than once it wakes up, so that the reader doesn't run ahead in the meantime. Workers that wait for room for
+/// their output wait for the reader, which takes their output before it reads further.
+pub struct ReadAheadBudget {
+    limit: u64,
+    state: Mutex<ReadAheadState>,
+    changed: Condvar,
+}
+
+struct ReadAheadState {
+    /// bytes that were handed to the workers and that they haven't taken yet
+    buffered: u64,
+    /// the most bytes that were buffered at the same time
+    peak: u64,
+    /// workers that are running and not waiting for data or for room for their output
+    working: usize,
+    /// workers that are waiting for room for their output
+    output_waiting: usize,
+    /// the segments whose worker waits for data and hasn't been handed any since
+    waiting_for_data: Vec<bool>,
+}
+
+/// a worker that counts as working until this is dropped
+pub struct Working<'a> {
+    budget: &'a ReadAheadBudget,
+}
+
+impl Drop for Working<'_> {
+    fn drop(&mut self) {
+        self.budget.update(|s| s.working -= 1);
+    }
+}
+
+impl ReadAheadBudget {
+    /// a budget that holds back the reader once limit bytes are buffered for the workers of num_segments segments
+    pub fn new(limit: u64, num_segments: usize) -> Self {
+        ReadAheadBudget {
+            limit,
+            state: Mutex::new(ReadAheadState {
+                buffered: 0,
+                peak: 0,
+                working: 0,
+                output_waiting: 0,
+                waiting_for_data: vec![false; num_segments],
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    /// waits until bytes more fit into the budget, or until none of the workers is left to make room, and then
+    /// counts them as buffered. Returns false without counting them if they don't fit and a worker waits for room
+    /// for its output, which the caller has to take first. With force they are counted anyway, for when the output
+    /// that is waiting can't be taken before a worker that waits for data gets it.
+    pub fn reserve(&self, bytes: u64, force: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        while state.buffered > 0 && state.buffered + bytes > self.limit && state.working > 0 {
+            state = self.changed.wait(state).unwrap();
+        }
+
+        if !force
+            && state.buffered > 0
+            && state.buffered + bytes > self.limit
+            && state.output_waiting > 0
+        {
+            return false;
+        }
+
+        state.buffered += bytes;
+        state.peak = state.peak.max(state.buffered);
+        true
+    }
+
+    /// the reader has handed data or the end of its data to segment, whose worker counts as working from now on
+    /// if it was waiting for it
+    pub fn handed_to(&self, segment: usize) {
+        self.update(|s| {
+            if s.waiting_for_data[segment] {
+                s.waiting_for_data[segment] = false;
+                s.working += 1;
+            }
+        });
+    }
+
+    /// a worker has taken bytes that were buffered
+    pub fn release(&self, bytes: u64) {
+        self.update(|s| s.buffered -= bytes);
+    }
+
+    /// counts the calling worker as working until the result is dropped
+    pub fn start_working(&self) -> Working<'_> {
+        self.update(|s| s.working += 1);
+        Working { budget: self }
+    }
+
+    /// runs wait, in which a worker that is counted as working waits for the data of segment, without counting it
+    /// until the reader hands it some
+    pub fn while_waiting<T>(&self, segment: usize, wait: impl FnOnce() -> T) -> T {
+        self.update(|s| {
+            s.working -= 1;
+            s.waiting_for_data[segment] = true;
+        });
+        let r = wait();
+        self.update(|s| {
+            if s.waiting_for_data[segment] {
+                s.waiting_for_data[segment] = false;
+                s.working += 1;
+            }
+        });
+        r
+    }
+
+    /// runs wait, in which a worker that is counted as working waits for room for its output, without counting it
+    pub fn while_output_waits<T>(&self, wait: impl FnOnce() -> T) -> T {
+        self.update(|s| {
+            s.working -= 1;
+            s.output_waiting += 1;
+        });
+        let r = wait();
+        self.update(|s| {
+            s.working += 1;
+            s.output_waiting -= 1;
+        });
+        r
+    }
+
+    /// the most bytes that were buffered at the same time
+    pub fn peak(&self) -> u64 {
+        self.state.lock().unwrap().peak
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ReadAheadState)) {
+        f(&mut self.state.lock().unwrap());
+        self.changed.notify_all();
+    }
+}
+
+/// the reader waits while a worker is busy and the budget is full, and goes on once the worker has taken the data
+/// or waits for data itself
+#[test]
+fn reader_waits_for_busy_workers() {
+    use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
+
+    let budget = ReadAheadBudget::new(100, 1);
+
+    // a single chunk goes through even if it is larger than the budget
+    assert!(budget.reserve(150, false));
+    budget.release(150);
+
+    assert!(budget.reserve(60, false));
+    let (tx, rx) = channel();
+    let (output_tx, output_rx) = channel();
+
+    thread::scope(|s| {
+        let working = budget.start_working();
+
+        s.spawn(|| {
+            assert!(budget.reserve(60, false));
+            tx.send(()).unwrap();
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(rx.try_recv().is_err(), "the reader didn't wait");
+
+        budget.release(60);
+        rx.recv().unwrap();
+
+        // a worker that waits for data lets the reader go over the budget, but counts as working again once it
+        // is handed some, before it wakes up
+        s.spawn(|| {
+            assert!(budget.reserve(60, false));
+            budget.handed_to(0);
+            tx.send(()).unwrap();
+        });
+
+        budget.while_waiting(0, || {
+            rx.recv().unwrap();
+            assert_eq!(budget.state.lock().unwrap().working, 1);
+        });
+        assert_eq!(budget.state.lock().unwrap().working, 1);
+
+        // but one that waits for room for its output has the reader take the output first
+        s.spawn(|| {
+            assert!(!budget.reserve(60, false));
+            output_tx.send(()).unwrap();
+        });
+
+        budget.while_output_waits(|| output_rx.recv().unwrap());
+        drop(working);
+    });
+
+    assert!(budget.reserve(60, true));
+    assert_eq!(budget.peak(), 180);
+    assert_eq!(budget.state.lock().unwrap().buffered, 180);
+}
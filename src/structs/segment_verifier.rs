@@ -0,0 +1,279 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::io::{self, Cursor};
+use std::mem;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use cpu_time::ThreadTime;
+use flate2::Crc;
+
+use crate::consts::LEPTON_FEATURE_SEGMENT_INDEX;
+use crate::enabled_features::{EnabledFeatures, EncodeMode};
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::metrics::Metrics;
+use crate::structs::block_based_image::BlockBasedImage;
+use crate::structs::crc_reader::CrcWriter;
+use crate::structs::decode_plan::{assemble_segments, SegmentOutput};
+use crate::structs::lepton_format::{ChunkReader, LeptonHeader};
+
+/// checks the thread segments of an image while the encoder is still working on the others, see
+/// EnabledFeatures::verify_segments. Each segment is decoded from its chunks with the header that the decoder is
+/// going to read, and its coefficients are compared with the ones that were encoded. Once all of them are there,
+/// the JPEG is recreated from the decoded segments the way the decoder does it and checked against the original.
+/// verify_file also checks that the lepton file that was written contains the segments that were checked.
+pub struct SegmentVerifier<'a> {
+    lh: LeptonHeader,
+    /// the features the decoder reads the header of the written file with
+    enabled_features: EnabledFeatures,
+    /// the coefficients that the encoder encoded
+    image_data: &'a [BlockBasedImage],
+    /// a coefficients only file decodes to another JPEG than the original, so only its coefficients are checked
+    check_jpeg: bool,
+    /// what the segments that were checked so far decode to, in the order they were checked
+    outputs: Mutex<Vec<SegmentOutput>>,
+    /// the CRC of the data of each segment that was checked, without the framing of its chunks
+    segment_crcs: Mutex<Vec<Option<u32>>>,
+}
+
+impl<'a> SegmentVerifier<'a> {
+    /// a verifier for the segments that lp encodes from image_data. The header is written and read back, so that
+    /// the segments are decoded with exactly what the decoder gets to see.
+    pub fn new(
+        lp: &LeptonHeader,
+        image_data: &'a [BlockBasedImage],
+        enabled_features: &EnabledFeatures,
+    ) -> Result<Self> {
+        let mut header = Vec::new();
+        lp.write_lepton_header(&mut header).context(here!())?;
+
+        let mut lh = LeptonHeader::new();
+        lh.set_decoder_features(enabled_features);
+        lh.read_lepton_header(&mut &header[..]).context(here!())?;
+
+        Ok(SegmentVerifier {
+            segment_crcs: Mutex::new(vec![None; lh.thread_handoff.len()]),
+            lh,
+            enabled_features: enabled_features.clone(),
+            image_data,
+            check_jpeg: enabled_features.encode_mode == EncodeMode::Exact,
+            outputs: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// decodes segment from its chunks (with the thread id and length in front of each one) and compares its
+    /// coefficients with the ones that were encoded. Fails with VerificationContentMismatch at the first block
+    /// that differs.
+    pub fn verify_segment(&self, segment: usize, chunks: &[u8]) -> Result<()> {
+        let cpu_time = ThreadTime::now();
+
+        let thread_handoff = &self.lh.thread_handoff[segment];
+        let luma_rows = thread_handoff.luma_y_start..thread_handoff.luma_y_end;
+
+        let (decoded, metrics) = self
+            .lh
+            .decode_segment_chunks(chunks, segment, None)
+            .map_err(|e| add_segment_position(e, segment, luma_rows.clone()))
+            .context(here!())?;
+
+        // the last segment goes all the way to the bottom, whatever its end says
+        let luma_y_end = if segment == self.lh.thread_handoff.len() - 1 {
+            self.lh.jpeg_header.cmp_info[0].bcv
+        } else {
+            thread_handoff.luma_y_end
+        };
+
+        for (component, (encoded, decoded)) in self.image_data.iter().zip(&decoded).enumerate() {
+            let jpeg_header = &self.lh.jpeg_header;
+            let start = BlockBasedImage::dpos_of_luma_row(jpeg_header, component, luma_rows.start);
+            let end = BlockBasedImage::dpos_of_luma_row(jpeg_header, component, luma_y_end);
+
+            for dpos in start..end {
+                if encoded.get_block(dpos).get_block() != decoded.get_block(dpos).get_block() {
+                    return err_block_mismatch(component, dpos)
+                        .map_err(|e| add_segment_position(e, segment, luma_rows));
+                }
+            }
+        }
+
+        let mut output = SegmentOutput::from_coefficients(&self.lh, segment, decoded, metrics)
+            .context(here!())?;
+        output.metrics.record_cpu_worker_time(cpu_time.elapsed());
+
+        self.outputs.lock().unwrap().push(output);
+        self.segment_crcs.lock().unwrap()[segment] = Some(segment_crc(chunks, segment)?.sum());
+
+        Ok(())
+    }
+
+    /// checks file, the lepton file that was written with the segments that were checked, which has to be all of
+    /// them. Its header has to describe the same segments, the multiplexed data has to contain the data of each
+    /// segment that was checked, and the segment index, the segment checksums and the file size at the end have to
+    /// match it. The JPEG is then recreated with the header of the file, see verify_jpeg.
+    pub fn verify_file(
+        mut self,
+        file: &[u8],
+        original_crc: u32,
+        original_size: u64,
+    ) -> Result<Metrics> {
+        let mut reader = Cursor::new(file);
+        let mut lh = LeptonHeader::new();
+        lh.set_decoder_features(&self.enabled_features);
+        lh.read_lepton_header(&mut reader).context(here!())?;
+        let header_end = reader.position() as usize;
+
+        // the segment index is the only thing that the header of the file has that the checked one doesn't
+        if lh.thread_handoff != self.lh.thread_handoff
+            || lh.get_required_features() & !LEPTON_FEATURE_SEGMENT_INDEX
+                != self.lh.get_required_features()
+        {
+            return err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                "header of the file doesn't match the one the segments were checked with",
+            );
+        }
+
+        let file_size_len = lh.get_file_size_len() as usize;
+        let trailer_len = lh.get_segment_checksums_len() as usize + file_size_len;
+        if file.len() < header_end + trailer_len
+            || lh.read_file_size(&file[file.len() - file_size_len..])? != file.len() as u64
+        {
+            return err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                "file size at the end of the file doesn't match its length",
+            );
+        }
+        let trailer_start = file.len() - trailer_len;
+
+        let expected_crcs = mem::take(&mut *self.segment_crcs.lock().unwrap());
+        let check_crc = |segment: usize, crc: &Crc| match expected_crcs[segment] {
+            Some(expected) if expected == crc.sum() => Ok(()),
+            _ => err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                format!(
+                    "data of segment {0} in the file isn't the data that was checked",
+                    segment
+                )
+                .as_str(),
+            ),
+        };
+
+        let data_end = match lh.segment_index_offset {
+            Some(offset) => header_end as u64 + offset,
+            None => trailer_start as u64,
+        };
+        if data_end > trailer_start as u64 {
+            return err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                "segment index starts after the end of the file",
+            );
+        }
+        let data = &file[header_end..data_end as usize];
+
+        let mut crcs: Vec<Crc> = lh.thread_handoff.iter().map(|_| Crc::new()).collect();
+        let mut chunk_reader = ChunkReader::new(data, Some(data.len() as u64));
+        while let Some((thread_id, buffer)) = chunk_reader.next_chunk().context(here!())? {
+            match crcs.get_mut(usize::from(thread_id)) {
+                Some(crc) => crc.update(&buffer),
+                None => {
+                    return err_exit_code(
+                        ExitCode::VerificationContentMismatch,
+                        format!("found data for thread {0} in the file", thread_id).as_str(),
+                    )
+                }
+            }
+        }
+        for (segment, crc) in crcs.iter().enumerate() {
+            check_crc(segment, crc)?;
+        }
+
+        if lh.segment_index_offset.is_some() {
+            let segment_index = lh
+                .read_segment_index(&mut Cursor::new(file), header_end as u64)
+                .context(here!())?;
+
+            for (segment, entry) in segment_index.iter().enumerate() {
+                let chunks = usize::try_from(entry.offset)
+                    .ok()
+                    .zip(usize::try_from(entry.length).ok())
+                    .and_then(|(offset, length)| data.get(offset..offset.checked_add(length)?));
+                match chunks {
+                    Some(chunks) => check_crc(segment, &segment_crc(chunks, segment)?)?,
+                    None => {
+                        return err_exit_code(
+                            ExitCode::VerificationContentMismatch,
+                            format!(
+                                "segment index entry {0} points outside of the multiplexed data",
+                                segment
+                            )
+                            .as_str(),
+                        )
+                    }
+                }
+            }
+        }
+
+        if lh.segment_checksums {
+            lh.verify_segment_checksums(&crcs, &file[trailer_start..])
+                .context(here!())?;
+        }
+
+        self.lh = lh;
+        self.verify_jpeg(original_crc, original_size)
+    }
+
+    /// recreates the JPEG from the segments that were checked, which has to be all of them, and checks it against
+    /// the size and CRC of the original. Returns the statistics and CPU time of decoding the segments.
+    pub fn verify_jpeg(mut self, original_crc: u32, original_size: u64) -> Result<Metrics> {
+        let mut outputs = self.outputs.into_inner().unwrap();
+
+        let mut metrics = Metrics::default();
+        for output in &mut outputs {
+            metrics.merge_from(mem::take(&mut output.metrics));
+        }
+
+        if self.check_jpeg {
+            self.lh.original_file_size = Some(original_size);
+            self.lh.original_file_crc = Some(original_crc);
+
+            let num_segments = self.lh.thread_handoff.len();
+            assemble_segments(
+                &mut self.lh,
+                num_segments,
+                outputs,
+                &mut CrcWriter::new(io::sink()),
+            )
+            .context(here!())?;
+        }
+
+        Ok(metrics)
+    }
+}
+
+/// the CRC of the data in chunks, which all have to belong to segment, without the thread id and length in front
+/// of each one
+fn segment_crc(chunks: &[u8], segment: usize) -> Result<Crc> {
+    let mut crc = Crc::new();
+
+    let mut chunk_reader = ChunkReader::new(chunks, Some(chunks.len() as u64));
+    while let Some((thread_id, buffer)) = chunk_reader.next_chunk().context(here!())? {
+        if usize::from(thread_id) != segment {
+            return err_exit_code(
+                ExitCode::VerificationContentMismatch,
+                format!(
+                    "found data for thread {0} in segment {1}",
+                    thread_id, segment
+                )
+                .as_str(),
+            );
+        }
+        crc.update(&buffer);
+    }
+
+    Ok(crc)
+}
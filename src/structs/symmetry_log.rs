@@ -0,0 +1,483 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+//! Debugging aid for the debug-symmetry feature: the encoder and decoder have to pick the same branch of the
+//! model for each decision, or the decoder goes out of sync without noticing. With a SymmetryRecorder in the
+//! EnabledFeatures, the bool coder of each thread segment logs the context and the symbol of each decision in a
+//! ring buffer, so that the logs of encoding and decoding the same file can be compared.
+//!
+//! The context of a decision is the table of the model it was coded with and the counts that its branch had.
+//! Picking the wrong branch shows up as soon as the wrong branch has other counts than the right one, which for
+//! branches that are still at their initial counts can be a few decisions later.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+
+use crate::enabled_features::EnabledFeatures;
+use crate::helpers::*;
+use crate::lepton_error::ExitCode;
+use crate::metrics::ModelComponent;
+use crate::structs::lepton_format::{decode_lepton_wrapper_with_features, encode_lepton_wrapper};
+
+/// the coefficient index of the decisions that code the number of non-zeros of the 7x7 coefficients
+pub const NON_ZEROS_7X7: u8 = 64;
+/// the coefficient index of the decisions that code the number of non-zeros of the first row
+pub const NON_ZEROS_ROW: u8 = 65;
+/// the coefficient index of the decisions that code the number of non-zeros of the first column
+pub const NON_ZEROS_COLUMN: u8 = 66;
+
+/// the number of decisions that each thread segment keeps in its log by default
+pub const DEFAULT_CAPACITY: usize = 1 << 16;
+
+/// one decision of the bool coder of a thread segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SymmetryEntry {
+    /// position of the decision among the decisions of the thread segment
+    pub index: u64,
+    pub component: usize,
+    pub dpos: i32,
+    /// raster index of the coefficient that the decision belongs to, or one of the NON_ZEROS_* constants
+    pub coefficient: u8,
+    /// the table of the model and the counts of the branch that the decision was coded with
+    table: ModelComponent,
+    counts: u16,
+    pub symbol: bool,
+    /// hash of all the decisions of the thread segment before this one
+    prefix_hash: u64,
+}
+
+impl SymmetryEntry {
+    /// whether the decisions are the same, regardless of what came before them
+    fn same_decision(&self, other: &SymmetryEntry) -> bool {
+        self.component == other.component
+            && self.dpos == other.dpos
+            && self.coefficient == other.coefficient
+            && self.table == other.table
+            && self.counts == other.counts
+            && self.symbol == other.symbol
+    }
+}
+
+impl fmt::Display for SymmetryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component {0} dpos {1} coefficient {2}: {3:?} with counts {4:04x} coded {5}",
+            self.component,
+            self.dpos,
+            self.coefficient,
+            self.table,
+            self.counts,
+            u8::from(self.symbol)
+        )
+    }
+}
+
+/// the log of one thread segment once its coder is done
+#[derive(Debug, Clone, Default)]
+struct SegmentLog {
+    /// the first luma row of the segment, which is the same for the encoder and the decoder
+    segment: i32,
+    /// number of decisions that were logged, including the ones that no longer fit in entries
+    count: u64,
+    /// the last decisions up to count
+    entries: Vec<SymmetryEntry>,
+}
+
+/// collects the logs of the thread segments that are coded with it. Only the decisions with an index below
+/// record_before are logged, and each segment keeps the last capacity of them.
+#[derive(Clone)]
+pub struct SymmetryRecorder {
+    capacity: usize,
+    record_before: u64,
+    logs: Arc<Mutex<Vec<SegmentLog>>>,
+}
+
+impl fmt::Debug for SymmetryRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SymmetryRecorder")
+            .field("capacity", &self.capacity)
+            .field("record_before", &self.record_before)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SymmetryRecorder {
+    pub fn new(capacity: usize, record_before: u64) -> Self {
+        SymmetryRecorder {
+            capacity: capacity.max(1),
+            record_before,
+            logs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// a log for the bool coder of the thread segment that starts at luma row segment
+    pub fn new_log(&self, segment: i32) -> SymmetryLog {
+        SymmetryLog {
+            recorder: self.clone(),
+            segment,
+            entries: VecDeque::with_capacity(self.capacity.min(DEFAULT_CAPACITY)),
+            count: 0,
+            prefix_hash: 0,
+            component: 0,
+            dpos: 0,
+            coefficient: 0,
+        }
+    }
+
+    /// the logs of the segments that are done, in the order of the segments
+    fn take_logs(&self) -> Vec<SegmentLog> {
+        let mut logs = std::mem::take(&mut *self.logs.lock().unwrap());
+        logs.sort_by_key(|l| l.segment);
+        logs
+    }
+}
+
+/// the ring buffer of a bool coder, which hands its decisions to the recorder when it is dropped, so that the
+/// log of a decoder that fails is kept as well
+pub struct SymmetryLog {
+    recorder: SymmetryRecorder,
+    segment: i32,
+    entries: VecDeque<SymmetryEntry>,
+    count: u64,
+    prefix_hash: u64,
+    component: usize,
+    dpos: i32,
+    coefficient: u8,
+}
+
+impl SymmetryLog {
+    /// the decisions from now on belong to the block at dpos of component
+    pub fn set_block(&mut self, component: usize, dpos: i32) {
+        self.component = component;
+        self.dpos = dpos;
+    }
+
+    /// the decisions from now on belong to the coefficient with the raster index, or one of NON_ZEROS_*
+    pub fn set_coefficient(&mut self, coefficient: u8) {
+        self.coefficient = coefficient;
+    }
+
+    /// logs a decision coded with a branch of table that had counts before it was updated
+    pub fn record(&mut self, table: ModelComponent, counts: u16, symbol: bool) {
+        // the bits that start and end the stream don't belong to the model
+        if table == ModelComponent::Dummy || self.count >= self.recorder.record_before {
+            return;
+        }
+
+        let entry = SymmetryEntry {
+            index: self.count,
+            component: self.component,
+            dpos: self.dpos,
+            coefficient: self.coefficient,
+            table,
+            counts,
+            symbol,
+            prefix_hash: self.prefix_hash,
+        };
+
+        let mut hasher = DefaultHasher::new();
+        entry.hash(&mut hasher);
+        self.prefix_hash = hasher.finish();
+
+        if self.entries.len() == self.recorder.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+        self.count += 1;
+    }
+}
+
+impl Drop for SymmetryLog {
+    fn drop(&mut self) {
+        let log = SegmentLog {
+            segment: self.segment,
+            count: self.count,
+            entries: self.entries.drain(..).collect(),
+        };
+
+        if let Ok(mut logs) = self.recorder.logs.lock() {
+            logs.push(log);
+        }
+    }
+}
+
+/// the first decision where the decoder didn't do what the encoder did
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// the first luma row of the thread segment
+    pub segment: i32,
+    /// position of the decision among the decisions of the thread segment
+    pub index: u64,
+    /// what the encoder coded, None if it coded fewer decisions
+    pub encoder: Option<SymmetryEntry>,
+    /// what the decoder decoded, None if it decoded fewer decisions
+    pub decoder: Option<SymmetryEntry>,
+}
+
+impl Divergence {
+    /// the dpos of the block where the coders diverged, taken from the encoder since the decoder may have
+    /// lost track of where it is
+    pub fn dpos(&self) -> i32 {
+        self.encoder.or(self.decoder).map_or(0, |e| e.dpos)
+    }
+
+    /// the coefficient where the coders diverged, see SymmetryEntry::coefficient
+    pub fn coefficient(&self) -> u8 {
+        self.encoder.or(self.decoder).map_or(0, |e| e.coefficient)
+    }
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |e: &Option<SymmetryEntry>| match e {
+            Some(e) => e.to_string(),
+            None => "nothing".to_owned(),
+        };
+
+        write!(
+            f,
+            "segment at row {0} diverged at decision {1}: encoder {2}, decoder {3}",
+            self.segment,
+            self.index,
+            describe(&self.encoder),
+            describe(&self.decoder)
+        )
+    }
+}
+
+/// how the logs of a segment compare
+#[derive(Debug, PartialEq)]
+enum SegmentComparison {
+    Same,
+    Diverged(Divergence),
+    /// the logs already differ before the decision with this index, which they don't go back to
+    Earlier(u64),
+}
+
+/// compares the logs that the encoder and decoder left for a segment
+fn compare_segment(segment: i32, encoder: &SegmentLog, decoder: &SegmentLog) -> SegmentComparison {
+    let window_start = |log: &SegmentLog| log.count - log.entries.len() as u64;
+    let start = window_start(encoder).max(window_start(decoder));
+    let end = encoder.count.min(decoder.count);
+
+    let entry = |log: &SegmentLog, index: u64| {
+        log.entries
+            .get((index - window_start(log)) as usize)
+            .copied()
+    };
+
+    if start < end {
+        // the hash of the decisions before the first one that both logs have tells whether everything before
+        // it was the same
+        if entry(encoder, start).unwrap().prefix_hash != entry(decoder, start).unwrap().prefix_hash
+        {
+            return SegmentComparison::Earlier(start);
+        }
+
+        for index in start..end {
+            let (e, d) = (
+                entry(encoder, index).unwrap(),
+                entry(decoder, index).unwrap(),
+            );
+            if !e.same_decision(&d) {
+                return SegmentComparison::Diverged(Divergence {
+                    segment,
+                    index,
+                    encoder: Some(e),
+                    decoder: Some(d),
+                });
+            }
+        }
+    } else if end > 0 {
+        // the logs don't overlap, so there is nothing to compare
+        return SegmentComparison::Earlier(end);
+    }
+
+    if encoder.count != decoder.count {
+        return SegmentComparison::Diverged(Divergence {
+            segment,
+            index: end,
+            encoder: if end < encoder.count {
+                entry(encoder, end)
+            } else {
+                None
+            },
+            decoder: if end < decoder.count {
+                entry(decoder, end)
+            } else {
+                None
+            },
+        });
+    }
+
+    SegmentComparison::Same
+}
+
+/// encodes and decodes jpeg in process with the recorders set, and returns the logs of both
+fn record(
+    jpeg: &[u8],
+    enabled_features: &EnabledFeatures,
+    capacity: usize,
+    record_before: u64,
+) -> Result<(Vec<SegmentLog>, Vec<SegmentLog>, Result<Vec<u8>>)> {
+    let encoder = SymmetryRecorder::new(capacity, record_before);
+    let mut lepton = Vec::new();
+    encode_lepton_wrapper(
+        &mut Cursor::new(jpeg),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            symmetry_recorder: Some(encoder.clone()),
+            ..enabled_features.clone()
+        },
+    )
+    .context(here!())?;
+
+    let decoder = SymmetryRecorder::new(capacity, record_before);
+    let mut output = Vec::new();
+    let decoded = decode_lepton_wrapper_with_features(
+        &mut Cursor::new(&lepton),
+        &mut output,
+        8,
+        &EnabledFeatures {
+            symmetry_recorder: Some(decoder.clone()),
+            ..enabled_features.clone()
+        },
+    )
+    .map(|_| output);
+
+    Ok((encoder.take_logs(), decoder.take_logs(), decoded))
+}
+
+/// encodes jpeg with enabled_features and decodes the result in process, and returns the first decision of a
+/// thread segment where the decoder didn't do what the encoder did, or None if they did the same thing all the
+/// way and the decoder recreated jpeg. Each segment keeps the last capacity decisions, and if the coders
+/// already diverged before those, the image is coded again logging only the decisions before them.
+pub fn find_divergence(
+    jpeg: &[u8],
+    enabled_features: &EnabledFeatures,
+    capacity: usize,
+) -> Result<Option<Divergence>> {
+    let (encoder, decoder, decoded) = record(jpeg, enabled_features, capacity, u64::MAX)?;
+
+    let empty = SegmentLog::default();
+    for log in &encoder {
+        let decoder_log = decoder
+            .iter()
+            .find(|l| l.segment == log.segment)
+            .unwrap_or(&empty);
+
+        // the decisions before lo are known to be the same and the ones before hi are known to differ
+        let mut lo = 0;
+        let mut hi = match compare_segment(log.segment, log, decoder_log) {
+            SegmentComparison::Same => continue,
+            SegmentComparison::Diverged(divergence) => return Ok(Some(divergence)),
+            SegmentComparison::Earlier(hi) => hi,
+        };
+
+        loop {
+            let record_before = if hi - lo > capacity as u64 {
+                lo + (hi - lo) / 2
+            } else {
+                hi
+            };
+
+            let (encoder, decoder, _) = record(jpeg, enabled_features, capacity, record_before)?;
+            let find = |logs: &[SegmentLog]| {
+                logs.iter()
+                    .find(|l| l.segment == log.segment)
+                    .cloned()
+                    .unwrap_or_default()
+            };
+
+            match compare_segment(log.segment, &find(&encoder), &find(&decoder)) {
+                SegmentComparison::Same if record_before < hi => lo = record_before,
+                SegmentComparison::Same => {
+                    return err_exit_code(
+                        ExitCode::GeneralFailure,
+                        "the coders don't diverge the same way each time",
+                    )
+                }
+                SegmentComparison::Diverged(divergence) => return Ok(Some(divergence)),
+                SegmentComparison::Earlier(earlier) => hi = earlier,
+            }
+        }
+    }
+
+    // the decoder may have failed for other reasons than the model
+    let output = decoded.context(here!())?;
+    if output[..] != jpeg[..] {
+        return err_exit_code(
+            ExitCode::VerificationContentMismatch,
+            "the coders made the same decisions but the decoder didn't recreate the JPEG",
+        );
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+fn test_log(segment: i32, decisions: &[(i32, u8, bool)], capacity: usize) -> SegmentLog {
+    let recorder = SymmetryRecorder::new(capacity, u64::MAX);
+    {
+        let mut log = recorder.new_log(segment);
+        for (dpos, coefficient, symbol) in decisions {
+            log.set_block(0, *dpos);
+            log.set_coefficient(*coefficient);
+            log.record(ModelComponent::NonZero7x7Count, 0x0101, *symbol);
+        }
+    }
+
+    recorder.take_logs().pop().unwrap()
+}
+
+/// the comparison reports the first decision that differs, and asks for the decisions before the logs if they
+/// already differ where the logs start
+#[test]
+fn compare_finds_first_divergence() {
+    let encoded = [(0, 1, true), (0, 2, false), (1, 1, true), (1, 9, true)];
+
+    let same = test_log(0, &encoded, 16);
+    assert_eq!(compare_segment(0, &same, &same), SegmentComparison::Same);
+
+    let mut decoded = encoded;
+    decoded[2].2 = false;
+    decoded[3].1 = 8;
+    match compare_segment(0, &same, &test_log(0, &decoded, 16)) {
+        SegmentComparison::Diverged(d) => {
+            assert_eq!(d.index, 2);
+            assert_eq!(d.dpos(), 1);
+            assert_eq!(d.coefficient(), 1);
+            assert!(d.encoder.unwrap().symbol);
+            assert!(!d.decoder.unwrap().symbol);
+        }
+        c => panic!("{0:?}", c),
+    }
+
+    // a decoder that stops early
+    match compare_segment(0, &same, &test_log(0, &encoded[..3], 16)) {
+        SegmentComparison::Diverged(d) => {
+            assert_eq!(d.index, 3);
+            assert_eq!(d.decoder, None);
+        }
+        c => panic!("{0:?}", c),
+    }
+
+    // with room for only the last two decisions, the difference at index 1 is before the logs
+    let mut decoded = encoded;
+    decoded[1].2 = true;
+    assert_eq!(
+        compare_segment(0, &test_log(0, &encoded, 2), &test_log(0, &decoded, 2)),
+        SegmentComparison::Earlier(2)
+    );
+}
@@ -9,13 +9,31 @@ use std::io::{Read, Result, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::consts::COLOR_CHANNEL_NUM_BLOCK_TYPES;
-
-#[derive(Debug)]
+use crate::helpers::{add_segment_position, err_exit_code};
+use crate::lepton_error::ExitCode;
+
+/// where a thread segment starts in the image and in the JPEG, and the state the JPEG writer starts it with.
+///
+/// In the lepton file the segments follow the HH marker and a byte with their number, 16 bytes each:
+///
+/// | bytes | field |
+/// |-------|-------|
+/// | 2     | luma_y_start as u16 |
+/// | 4     | segment_size as i32, or i32::MAX if it is stored with the large sizes |
+/// | 1     | overhang_byte |
+/// | 1     | num_overhang_bits |
+/// | 8     | last_dc as 4 i16, of which only the first 3 are used |
+///
+/// All values are little endian. luma_y_end is the luma_y_start of the next segment, or the height of the image for
+/// the last one, and segment_offset_in_file is the sum of the sizes of the segments before it. The layout is the
+/// one of the original C++ encoder, so it doesn't have a version of its own: anything that changes it needs a new
+/// feature flag, like LEPTON_FEATURE_LARGE_SIZES, which the decoder then checks against the format version.
+#[derive(Debug, Clone, PartialEq)]
 pub struct ThreadHandoff {
     pub luma_y_start: i32,
     pub luma_y_end: i32,
-    pub segment_offset_in_file: i32,
-    pub segment_size: i32,
+    pub segment_offset_in_file: i64,
+    pub segment_size: i64,
     pub overhang_byte: u8,
     pub num_overhang_bits: u8,
     pub last_dc: [i16; 4],
@@ -30,7 +48,7 @@ impl ThreadHandoff {
                 luma_y_start: data.read_u16::<LittleEndian>()? as i32,
                 luma_y_end: 0,             // filled in later
                 segment_offset_in_file: 0, // not serialized
-                segment_size: data.read_i32::<LittleEndian>()?,
+                segment_size: i64::from(data.read_i32::<LittleEndian>()?),
                 overhang_byte: data.read_u8()?,
                 num_overhang_bits: data.read_u8()?,
                 last_dc: [0; 4],
@@ -59,8 +77,9 @@ impl ThreadHandoff {
 
         for th in data {
             retval.write_u16::<LittleEndian>(th.luma_y_start as u16)?;
-            // SegmentOffsetInFile is not serialized to preserve compatibility with original Lepton format
-            retval.write_i32::<LittleEndian>(th.segment_size as i32)?;
+            // SegmentOffsetInFile is not serialized to preserve compatibility with original Lepton format.
+            // Sizes that don't fit are stored separately in files with LEPTON_FEATURE_LARGE_SIZES.
+            retval.write_i32::<LittleEndian>(i32::try_from(th.segment_size).unwrap_or(i32::MAX))?;
             retval.write_u8(th.overhang_byte)?;
             retval.write_u8(th.num_overhang_bits)?;
 
@@ -75,14 +94,45 @@ impl ThreadHandoff {
         return Ok(());
     }
 
+    /// checks that the segments read from a file start with the first row of the image and follow each other in
+    /// whole MCU rows down to luma_height, since the decoder would otherwise decode the rows of the image more
+    /// than once or not at all. Fails with CorruptHandoff at the first segment that doesn't.
+    pub fn validate(
+        handoffs: &[ThreadHandoff],
+        luma_height: i32,
+        luma_rows_per_mcu: i32,
+    ) -> anyhow::Result<()> {
+        for (i, th) in handoffs.iter().enumerate() {
+            let problem = if i == 0 && th.luma_y_start != 0 {
+                "doesn't start at the top of the image"
+            } else if th.luma_y_start >= luma_height || th.luma_y_end > luma_height {
+                "goes past the bottom of the image"
+            } else if th.luma_y_start >= th.luma_y_end {
+                "doesn't end after it starts"
+            } else if th.luma_y_start % luma_rows_per_mcu != 0 {
+                "doesn't start at an MCU row"
+            } else {
+                continue;
+            };
+
+            return err_exit_code(
+                ExitCode::CorruptHandoff,
+                format!(
+                    "thread segment {0} {1} (the image has {2} rows of luma blocks)",
+                    i, problem, luma_height
+                )
+                .as_str(),
+            )
+            .map_err(|e| add_segment_position(e, i, th.luma_y_start..th.luma_y_end));
+        }
+
+        Ok(())
+    }
+
     // Combine two ThreadHandoff objects into a range, starting with the "from" segment, and
     // continuing until the end of the "to" segment [from, to]
-    pub fn get_combine_thread_range_segment_size(
-        from: &ThreadHandoff,
-        to: &ThreadHandoff,
-    ) -> usize {
-        return (to.segment_offset_in_file - from.segment_offset_in_file + to.segment_size)
-            as usize;
+    pub fn get_combine_thread_range_segment_size(from: &ThreadHandoff, to: &ThreadHandoff) -> i64 {
+        to.segment_offset_in_file - from.segment_offset_in_file + to.segment_size
     }
 
     pub fn combine_thread_ranges(from: &ThreadHandoff, to: &ThreadHandoff) -> ThreadHandoff {
@@ -92,7 +142,7 @@ impl ThreadHandoff {
             overhang_byte: from.overhang_byte,
             num_overhang_bits: from.num_overhang_bits,
             luma_y_end: to.luma_y_end,
-            segment_size: ThreadHandoff::get_combine_thread_range_segment_size(from, to) as i32,
+            segment_size: ThreadHandoff::get_combine_thread_range_segment_size(from, to),
             last_dc: from.last_dc,
         };
 
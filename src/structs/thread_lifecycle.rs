@@ -0,0 +1,148 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::structs::thread_pool::{set_current_thread_name, WORKER_THREAD_NAME};
+
+/// which coder a worker thread works for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WorkerKind {
+    Encoder,
+    Decoder,
+}
+
+impl WorkerKind {
+    /// the name of the thread of a worker while it works on segment, lepton-enc-{segment} or lepton-dec-{segment}
+    pub fn thread_name(self, segment: usize) -> String {
+        match self {
+            WorkerKind::Encoder => format!("lepton-enc-{0}", segment),
+            WorkerKind::Decoder => format!("lepton-dec-{0}", segment),
+        }
+    }
+}
+
+/// what a ThreadLifecycleHook is told about
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreadLifecycleEvent {
+    /// a worker starts on a thread segment
+    Started { kind: WorkerKind, segment: usize },
+    /// a worker is done with a thread segment after duration, whether it succeeded or not
+    Finished {
+        kind: WorkerKind,
+        segment: usize,
+        duration: Duration,
+    },
+}
+
+/// Instrumentation: the callback is called on the thread of each worker of the encoder and the decoder as it
+/// starts and finishes a thread segment, so that it can enter a tracing span or set the priority or affinity of
+/// the thread. The workers run at the same time, so the callback can be called from several threads at once, but
+/// never while the coder holds one of its locks.
+#[derive(Clone)]
+pub struct ThreadLifecycleHook {
+    pub callback: Arc<dyn Fn(&ThreadLifecycleEvent) + Send + Sync>,
+}
+
+impl fmt::Debug for ThreadLifecycleHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadLifecycleHook")
+            .finish_non_exhaustive()
+    }
+}
+
+/// gives the worker thread its name back once it is done with a segment, also if the segment panicked
+struct SegmentThreadName;
+
+impl Drop for SegmentThreadName {
+    fn drop(&mut self) {
+        set_current_thread_name(WORKER_THREAD_NAME);
+    }
+}
+
+/// runs the work of a worker on segment, with the thread named after the segment, and tells hook about it if
+/// there is one
+pub fn run_segment<T>(
+    hook: Option<&ThreadLifecycleHook>,
+    kind: WorkerKind,
+    segment: usize,
+    work: impl FnOnce() -> T,
+) -> T {
+    set_current_thread_name(&kind.thread_name(segment));
+    let _name = SegmentThreadName;
+
+    if let Some(hook) = hook {
+        (hook.callback)(&ThreadLifecycleEvent::Started { kind, segment });
+    }
+
+    let start = Instant::now();
+    let r = work();
+
+    if let Some(hook) = hook {
+        (hook.callback)(&ThreadLifecycleEvent::Finished {
+            kind,
+            segment,
+            duration: start.elapsed(),
+        });
+    }
+
+    r
+}
+
+/// the thread is named after the segment while it works on it, and gets the name of the pool back afterwards. The
+/// hook is told before and after the work, on the same thread.
+#[cfg(target_os = "linux")]
+#[test]
+fn run_segment_names_the_thread() {
+    use crate::structs::thread_pool;
+    use std::sync::Mutex;
+
+    fn thread_name() -> String {
+        std::fs::read_to_string("/proc/thread-self/comm")
+            .unwrap()
+            .trim_end()
+            .to_string()
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let hook = ThreadLifecycleHook {
+        callback: {
+            let events = events.clone();
+            Arc::new(move |e| events.lock().unwrap().push((*e, thread_name())))
+        },
+    };
+
+    let (during, after) = thread_pool::scope(1, |s| {
+        s.spawn(|| {
+            let during = run_segment(Some(&hook), WorkerKind::Decoder, 12, thread_name);
+            (during, thread_name())
+        })
+        .join()
+        .unwrap()
+    });
+
+    assert_eq!(during, "lepton-dec-12");
+    assert_eq!(after, WORKER_THREAD_NAME);
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[0],
+        (
+            ThreadLifecycleEvent::Started {
+                kind: WorkerKind::Decoder,
+                segment: 12
+            },
+            "lepton-dec-12".to_string()
+        )
+    );
+    assert!(matches!(
+        events[1],
+        (ThreadLifecycleEvent::Finished { segment: 12, .. }, ref name) if name == "lepton-dec-12"
+    ));
+}
@@ -0,0 +1,399 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// the name of the worker threads of the pool while they aren't working on a thread segment, see
+/// set_current_thread_name
+pub const WORKER_THREAD_NAME: &str = "lepton-worker";
+
+/// a job of the pool, which catches its own panics so that they don't take the worker down with them
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+struct PoolState {
+    /// jobs that one of the idle workers is going to pick up
+    jobs: VecDeque<Job>,
+    /// workers that wait for a job, less the jobs that are queued for them
+    idle: usize,
+    /// how many idle workers are kept for the next call rather than exiting, which is the most threads that a
+    /// call has asked for so far
+    keep_idle: usize,
+    shutdown: bool,
+    workers: Vec<JoinHandle<()>>,
+}
+
+/// worker threads that are kept around between the calls of the library, so that encoding and decoding a small
+/// image doesn't spend more time starting threads than working. A job never waits for a worker: if none is idle,
+/// a new one is started, so that the calls that run at the same time, or a call that runs inside the job of
+/// another one like the entries of a container, get as many threads as they would have started themselves.
+struct ThreadPool {
+    state: Mutex<PoolState>,
+    job_queued: Condvar,
+}
+
+/// the pool that the calls share, which is created by the first call that needs it and taken down by shutdown
+static POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+impl ThreadPool {
+    /// the shared pool, which keeps at least max_threads idle workers from now on
+    fn global(max_threads: usize) -> Arc<ThreadPool> {
+        let mut pool = POOL.lock().unwrap();
+        let pool = pool.get_or_insert_with(|| {
+            Arc::new(ThreadPool {
+                state: Mutex::new(PoolState {
+                    jobs: VecDeque::new(),
+                    idle: 0,
+                    keep_idle: 0,
+                    shutdown: false,
+                    workers: Vec::new(),
+                }),
+                job_queued: Condvar::new(),
+            })
+        });
+
+        let mut state = pool.state.lock().unwrap();
+        state.keep_idle = cmp::max(state.keep_idle, max_threads);
+
+        pool.clone()
+    }
+
+    /// runs job on an idle worker, or on a new one if all of them are busy
+    fn execute(self: &Arc<Self>, job: Job) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.idle > 0 && !state.shutdown {
+            state.idle -= 1;
+            state.jobs.push_back(job);
+            drop(state);
+
+            self.job_queued.notify_one();
+        } else {
+            state.workers.retain(|w| !w.is_finished());
+
+            let pool = self.clone();
+            state.workers.push(
+                thread::Builder::new()
+                    .name(WORKER_THREAD_NAME.to_string())
+                    .spawn(move || pool.run_worker(job))
+                    .expect("failed to start a worker thread"),
+            );
+        }
+    }
+
+    /// runs job and then the jobs that are queued for the worker, until there are enough idle workers without it
+    fn run_worker(self: Arc<Self>, mut job: Job) {
+        loop {
+            job();
+
+            let mut state = self.state.lock().unwrap();
+            if state.shutdown || state.idle >= state.keep_idle {
+                return;
+            }
+
+            state.idle += 1;
+            loop {
+                // a job that was queued for a worker runs even if the pool is shutting down, since its call
+                // waits for it
+                if let Some(next) = state.jobs.pop_front() {
+                    job = next;
+                    break;
+                }
+
+                if state.shutdown {
+                    state.idle -= 1;
+                    return;
+                }
+
+                state = self.job_queued.wait(state).unwrap();
+            }
+        }
+    }
+
+    /// stops the idle workers and waits for the busy ones to finish their jobs
+    fn shutdown(&self) {
+        self.state.lock().unwrap().shutdown = true;
+        self.job_queued.notify_all();
+
+        // a call that is still running can start workers while we wait, which exit after their job
+        loop {
+            let workers = mem::take(&mut self.state.lock().unwrap().workers);
+            if workers.is_empty() {
+                break;
+            }
+
+            for w in workers {
+                let _ = w.join();
+            }
+        }
+    }
+}
+
+/// stops the worker threads of the pool and waits for them to exit. The calls that are still running finish
+/// first, and the next call starts a new pool. The idle workers don't hold on to anything, so there is no need to
+/// call this before the process exits.
+pub fn shutdown() {
+    let pool = POOL.lock().unwrap().take();
+    if let Some(pool) = pool {
+        pool.shutdown();
+    }
+}
+
+/// renames the calling thread as the operating system shows it to debuggers and profilers, since the workers of
+/// the pool are reused for different work. Linux keeps the first 15 bytes of the name. Does nothing on other
+/// platforms, where the threads keep the name they were started with.
+pub fn set_current_thread_name(name: &str) {
+    #[cfg(target_os = "linux")]
+    if let Ok(name) = std::ffi::CString::new(name) {
+        // SAFETY: PR_SET_NAME reads a nul terminated string, which it copies
+        unsafe {
+            libc::prctl(libc::PR_SET_NAME, name.as_ptr());
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = name;
+}
+
+/// what the jobs of a scope share with it
+struct ScopeData {
+    /// the jobs that haven't finished yet, which the scope waits for before it returns
+    running: Mutex<usize>,
+    all_finished: Condvar,
+    /// a job panicked and nobody joined it to find out
+    a_job_panicked: AtomicBool,
+}
+
+/// runs jobs on the workers of the pool that can borrow from the caller, like std::thread::Scope
+pub struct Scope<'scope, 'env: 'scope> {
+    pool: Arc<ThreadPool>,
+    data: Arc<ScopeData>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+/// the result of a job and whether it is there yet
+struct Packet<'scope, T> {
+    result: Mutex<Option<thread::Result<T>>>,
+    finished: Condvar,
+    scope: Arc<ScopeData>,
+    _marker: PhantomData<&'scope ()>,
+}
+
+impl<T> Drop for Packet<'_, T> {
+    fn drop(&mut self) {
+        // the panic of a job that wasn't joined makes the scope panic, as it would with std::thread::scope
+        if let Ok(Some(Err(_))) = self.result.get_mut().map(|r| r.take()) {
+            self.scope.a_job_panicked.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// waits for a job of a scope, like std::thread::ScopedJoinHandle
+pub struct ScopedJoinHandle<'scope, T> {
+    packet: Arc<Packet<'scope, T>>,
+}
+
+impl<T> ScopedJoinHandle<'_, T> {
+    /// waits for the job to finish and returns its result, or the payload of its panic
+    pub fn join(self) -> thread::Result<T> {
+        let mut result = self.packet.result.lock().unwrap();
+        loop {
+            if let Some(r) = result.take() {
+                return r;
+            }
+
+            result = self.packet.finished.wait(result).unwrap();
+        }
+    }
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// runs f on a worker of the pool
+    pub fn spawn<F, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let packet = Arc::new(Packet {
+            result: Mutex::new(None),
+            finished: Condvar::new(),
+            scope: self.data.clone(),
+            _marker: PhantomData,
+        });
+
+        let job_packet = packet.clone();
+        let data = self.data.clone();
+
+        *data.running.lock().unwrap() += 1;
+
+        let job: Box<dyn FnOnce() + Send + 'scope> = Box::new(move || {
+            let result = catch_unwind(AssertUnwindSafe(f));
+
+            *job_packet.result.lock().unwrap() = Some(result);
+            job_packet.finished.notify_all();
+
+            // the result has to be dropped before the scope can end if nobody is going to join the job
+            drop(job_packet);
+
+            let mut running = data.running.lock().unwrap();
+            *running -= 1;
+            if *running == 0 {
+                data.all_finished.notify_all();
+            }
+        });
+
+        // SAFETY: the job may borrow anything that outlives 'scope, which the transmute hides from the pool. scope
+        // doesn't return before every job that was spawned in it has finished, even if f or a job panics: it
+        // catches the panic of f, waits for running to drop to zero and only then resumes the panic. A job counts
+        // itself out of running as the last thing it does, after f has been consumed, which catch_unwind makes
+        // sure of when f panics too. The result of the job is left in the packet, which after that only the
+        // ScopedJoinHandle keeps alive, and the handle can't outlive 'scope either.
+        let job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'scope>, Job>(job) };
+
+        self.pool.execute(job);
+
+        ScopedJoinHandle { packet }
+    }
+}
+
+/// like std::thread::scope, but the jobs run on the workers of the pool instead of new threads. max_threads is
+/// how many threads the call uses at most, which the pool keeps around for the next call.
+pub fn scope<'env, F, T>(max_threads: usize, f: F) -> T
+where
+    F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+{
+    let scope = Scope {
+        pool: ThreadPool::global(max_threads),
+        data: Arc::new(ScopeData {
+            running: Mutex::new(0),
+            all_finished: Condvar::new(),
+            a_job_panicked: AtomicBool::new(false),
+        }),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| f(&scope)));
+
+    let mut running = scope.data.running.lock().unwrap();
+    while *running > 0 {
+        running = scope.data.all_finished.wait(running).unwrap();
+    }
+    drop(running);
+
+    match result {
+        Err(e) => resume_unwind(e),
+        Ok(_) if scope.data.a_job_panicked.load(Ordering::Relaxed) => {
+            panic!("a job of the thread pool panicked")
+        }
+        Ok(result) => result,
+    }
+}
+
+/// the jobs borrow from the caller and return their results through the handles, and the workers are reused by
+/// the next scope
+#[test]
+fn scope_runs_jobs_on_reused_workers() {
+    use std::collections::HashSet;
+    use std::sync::atomic::AtomicUsize;
+
+    let data: Vec<usize> = (0..1000).collect();
+    let total = AtomicUsize::new(0);
+
+    let mut thread_ids = HashSet::new();
+    for _ in 0..20 {
+        let ids = scope(4, |s| {
+            let handles: Vec<_> = data
+                .chunks(100)
+                .map(|chunk| {
+                    let total = &total;
+                    s.spawn(move || {
+                        total.fetch_add(chunk.iter().sum::<usize>(), Ordering::Relaxed);
+                        (chunk.len(), thread::current().id())
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| {
+                    let (len, id) = h.join().unwrap();
+                    assert_eq!(len, 100);
+                    id
+                })
+                .collect::<Vec<_>>()
+        });
+
+        thread_ids.extend(ids);
+    }
+
+    assert_eq!(total.load(Ordering::Relaxed), 20 * 999 * 1000 / 2);
+
+    // other tests use the pool at the same time, but it doesn't start a new thread for each job
+    assert!(thread_ids.len() < 200);
+}
+
+/// jobs that wait for each other all get a worker, so that calls that run at the same time or inside each other
+/// can't starve
+#[test]
+fn scope_never_queues_behind_busy_workers() {
+    use std::sync::Barrier;
+
+    let barrier = Barrier::new(16);
+    scope(1, |s| {
+        for _ in 0..16 {
+            s.spawn(|| {
+                barrier.wait();
+            });
+        }
+    });
+
+    // a scope inside the job of another one
+    let result = scope(1, |s| {
+        s.spawn(|| scope(1, |inner| inner.spawn(|| 42).join().unwrap()))
+            .join()
+            .unwrap()
+    });
+    assert_eq!(result, 42);
+}
+
+/// a panic reaches the caller through join like with std::thread::scope, makes the scope panic if nobody joined
+/// the job, and leaves the workers working
+#[test]
+fn scope_reports_panics() {
+    let r = scope(2, |s| s.spawn(|| panic!("joined")).join());
+    assert!(r.is_err());
+
+    let r = catch_unwind(|| {
+        scope(2, |s| {
+            s.spawn(|| panic!("not joined"));
+        })
+    });
+    assert!(r.is_err());
+
+    // the scope waits for its jobs even when its closure panics
+    let finished = AtomicBool::new(false);
+    let r = catch_unwind(AssertUnwindSafe(|| {
+        scope(2, |s| {
+            s.spawn(|| {
+                thread::sleep(std::time::Duration::from_millis(50));
+                finished.store(true, Ordering::Relaxed);
+            });
+            panic!("closure");
+        })
+    }));
+    assert!(r.is_err());
+    assert!(finished.load(Ordering::Relaxed));
+
+    assert_eq!(scope(2, |s| s.spawn(|| 1).join().unwrap()), 1);
+}
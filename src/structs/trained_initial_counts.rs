@@ -0,0 +1,2679 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the Apache License, Version 2.0. See LICENSE.txt in the project root for license information.
+ *  This software incorporates material from third parties. See NOTICE.txt for details.
+ *--------------------------------------------------------------------------------------------*/
+
+// Generated by train_initial_probs from 6 images, do not edit.
+
+/// the index of each branch in the order of Model::for_each_branch with its initial counts, see
+/// load_trained_initial_counts
+#[rustfmt::skip]
+pub static TRAINED_INITIAL_COUNTS: [(u32, u16); 21328] = [
+    (0, 0x0402), (1, 0x0404), (2, 0x0402), (32, 0x0403), (33, 0x0401), (64, 0x0401), (96, 0x0401), (128, 0x0401),
+    (160, 0x0401), (192, 0x0204), (193, 0x0304), (194, 0x0403), (195, 0x0402), (196, 0x0402), (224, 0x0104), (225, 0x0401),
+    (226, 0x0401), (256, 0x0304), (257, 0x0401), (288, 0x0401), (320, 0x0401), (352, 0x0401), (384, 0x0104), (385, 0x0204),
+    (386, 0x0404), (387, 0x0403), (388, 0x0402), (389, 0x0402), (390, 0x0402), (391, 0x0403), (416, 0x0104), (417, 0x0404),
+    (418, 0x0401), (419, 0x0402), (448, 0x0104), (449, 0x0401), (450, 0x0403), (480, 0x0401), (481, 0x0401), (512, 0x0401),
+    (544, 0x0401), (576, 0x0104), (577, 0x0204), (578, 0x0204), (579, 0x0404), (580, 0x0403), (581, 0x0402), (582, 0x0402),
+    (583, 0x0403), (584, 0x0402), (585, 0x0404), (608, 0x0104), (609, 0x0204), (610, 0x0402), (611, 0x0401), (612, 0x0402),
+    (613, 0x0403), (640, 0x0104), (641, 0x0401), (642, 0x0402), (672, 0x0403), (673, 0x0401), (704, 0x0401), (736, 0x0401),
+    (768, 0x0304), (769, 0x0104), (770, 0x0204), (771, 0x0304), (772, 0x0404), (773, 0x0403), (774, 0x0403), (775, 0x0402),
+    (776, 0x0402), (777, 0x0402), (778, 0x0403), (779, 0x0403), (780, 0x0403), (781, 0x0403), (782, 0x0403), (783, 0x0403),
+    (800, 0x0104), (801, 0x0104), (802, 0x0403), (803, 0x0402), (804, 0x0402), (805, 0x0402), (806, 0x0403), (807, 0x0403),
+    (832, 0x0104), (833, 0x0401), (834, 0x0401), (835, 0x0402), (836, 0x0401), (864, 0x0204), (865, 0x0401), (866, 0x0401),
+    (896, 0x0401), (897, 0x0401), (928, 0x0401), (960, 0x0401), (961, 0x0204), (962, 0x0204), (963, 0x0204), (964, 0x0304),
+    (965, 0x0404), (966, 0x0404), (967, 0x0403), (968, 0x0403), (969, 0x0403), (970, 0x0403), (971, 0x0403), (972, 0x0403),
+    (973, 0x0403), (974, 0x0403), (975, 0x0403), (976, 0x0403), (977, 0x0402), (992, 0x0104), (993, 0x0104), (994, 0x0304),
+    (995, 0x0403), (996, 0x0402), (997, 0x0402), (998, 0x0402), (999, 0x0402), (1000, 0x0402), (1001, 0x0401), (1024, 0x0104),
+    (1025, 0x0404), (1026, 0x0401), (1027, 0x0401), (1028, 0x0401), (1056, 0x0104), (1057, 0x0401), (1058, 0x0401), (1088, 0x0402),
+    (1089, 0x0401), (1120, 0x0401), (1153, 0x0104), (1154, 0x0204), (1155, 0x0204), (1156, 0x0204), (1157, 0x0304), (1158, 0x0304),
+    (1159, 0x0404), (1160, 0x0404), (1161, 0x0404), (1162, 0x0403), (1163, 0x0403), (1164, 0x0403), (1165, 0x0403), (1166, 0x0403),
+    (1167, 0x0403), (1168, 0x0403), (1169, 0x0403), (1170, 0x0403), (1171, 0x0403), (1172, 0x0402), (1184, 0x0304), (1185, 0x0104),
+    (1186, 0x0204), (1187, 0x0304), (1188, 0x0404), (1189, 0x0403), (1190, 0x0403), (1191, 0x0402), (1192, 0x0402), (1193, 0x0402),
+    (1194, 0x0402), (1216, 0x0104), (1217, 0x0104), (1218, 0x0403), (1219, 0x0402), (1220, 0x0401), (1221, 0x0401), (1248, 0x0104),
+    (1249, 0x0402), (1250, 0x0401), (1280, 0x0204), (1281, 0x0401), (1312, 0x0401), (1347, 0x0304), (1348, 0x0304), (1349, 0x0304),
+    (1350, 0x0304), (1351, 0x0304), (1352, 0x0304), (1353, 0x0304), (1354, 0x0304), (1355, 0x0304), (1356, 0x0304), (1357, 0x0304),
+    (1358, 0x0404), (1359, 0x0404), (1360, 0x0404), (1361, 0x0404), (1362, 0x0403), (1363, 0x0403), (1364, 0x0403), (1365, 0x0403),
+    (1366, 0x0402), (1367, 0x0401), (1368, 0x0401), (1377, 0x0204), (1378, 0x0204), (1379, 0x0304), (1380, 0x0304), (1381, 0x0304),
+    (1382, 0x0304), (1383, 0x0404), (1384, 0x0403), (1385, 0x0402), (1386, 0x0402), (1387, 0x0401), (1388, 0x0401), (1408, 0x0104),
+    (1409, 0x0204), (1410, 0x0204), (1411, 0x0304), (1412, 0x0402), (1413, 0x0401), (1414, 0x0401), (1440, 0x0104), (1441, 0x0204),
+    (1442, 0x0401), (1443, 0x0401), (1472, 0x0104), (1473, 0x0401), (1504, 0x0402), (1550, 0x0204), (1551, 0x0204), (1552, 0x0204),
+    (1553, 0x0204), (1554, 0x0204), (1555, 0x0304), (1556, 0x0304), (1557, 0x0404), (1558, 0x0403), (1559, 0x0402), (1560, 0x0401),
+    (1575, 0x0204), (1576, 0x0104), (1577, 0x0204), (1578, 0x0304), (1579, 0x0402), (1580, 0x0401), (1603, 0x0104), (1604, 0x0104),
+    (1605, 0x0402), (1606, 0x0401), (1633, 0x0104), (1634, 0x0204), (1635, 0x0401), (1664, 0x0104), (1665, 0x0401), (1696, 0x0104),
+    (4992, 0x0402), (4993, 0x0401), (4994, 0x0401), (4995, 0x0402), (5024, 0x0401), (5025, 0x0401), (5056, 0x0401), (5088, 0x0401),
+    (5120, 0x0401), (5152, 0x0401), (5184, 0x0404), (5185, 0x0402), (5186, 0x0401), (5187, 0x0401), (5188, 0x0402), (5189, 0x0403),
+    (5190, 0x0403), (5216, 0x0402), (5217, 0x0401), (5218, 0x0401), (5219, 0x0402), (5248, 0x0401), (5249, 0x0401), (5280, 0x0401),
+    (5312, 0x0401), (5344, 0x0401), (5376, 0x0204), (5377, 0x0304), (5378, 0x0402), (5379, 0x0402), (5380, 0x0402), (5381, 0x0402),
+    (5382, 0x0402), (5383, 0x0403), (5384, 0x0402), (5385, 0x0404), (5408, 0x0304), (5409, 0x0401), (5410, 0x0401), (5411, 0x0402),
+    (5412, 0x0402), (5413, 0x0402), (5440, 0x0402), (5441, 0x0401), (5442, 0x0401), (5472, 0x0401), (5473, 0x0401), (5504, 0x0401),
+    (5536, 0x0401), (5568, 0x0204), (5569, 0x0204), (5570, 0x0404), (5571, 0x0402), (5572, 0x0402), (5573, 0x0402), (5574, 0x0403),
+    (5575, 0x0403), (5576, 0x0403), (5577, 0x0403), (5578, 0x0402), (5579, 0x0403), (5580, 0x0403), (5600, 0x0104), (5601, 0x0402),
+    (5602, 0x0401), (5603, 0x0402), (5604, 0x0402), (5605, 0x0402), (5606, 0x0402), (5632, 0x0204), (5633, 0x0401), (5634, 0x0401),
+    (5635, 0x0401), (5664, 0x0401), (5665, 0x0401), (5696, 0x0401), (5728, 0x0401), (5760, 0x0104), (5761, 0x0204), (5762, 0x0304),
+    (5763, 0x0404), (5764, 0x0403), (5765, 0x0403), (5766, 0x0403), (5767, 0x0403), (5768, 0x0403), (5769, 0x0403), (5770, 0x0403),
+    (5771, 0x0403), (5772, 0x0403), (5773, 0x0403), (5774, 0x0403), (5775, 0x0403), (5792, 0x0104), (5793, 0x0404), (5794, 0x0402),
+    (5795, 0x0402), (5796, 0x0402), (5797, 0x0402), (5798, 0x0402), (5799, 0x0402), (5824, 0x0104), (5825, 0x0401), (5826, 0x0401),
+    (5827, 0x0401), (5856, 0x0402), (5857, 0x0401), (5888, 0x0401), (5920, 0x0401), (5952, 0x0104), (5953, 0x0204), (5954, 0x0204),
+    (5955, 0x0404), (5956, 0x0404), (5957, 0x0404), (5958, 0x0404), (5959, 0x0404), (5960, 0x0403), (5961, 0x0404), (5962, 0x0403),
+    (5963, 0x0403), (5964, 0x0403), (5965, 0x0403), (5966, 0x0403), (5967, 0x0403), (5968, 0x0403), (5984, 0x0104), (5985, 0x0204),
+    (5986, 0x0404), (5987, 0x0404), (5988, 0x0403), (5989, 0x0402), (5990, 0x0403), (5991, 0x0402), (5992, 0x0401), (6016, 0x0104),
+    (6017, 0x0403), (6018, 0x0402), (6019, 0x0401), (6020, 0x0401), (6048, 0x0204), (6049, 0x0401), (6050, 0x0401), (6080, 0x0401),
+    (6081, 0x0401), (6112, 0x0401), (6145, 0x0104), (6146, 0x0204), (6147, 0x0304), (6148, 0x0304), (6149, 0x0304), (6150, 0x0404),
+    (6151, 0x0404), (6152, 0x0404), (6153, 0x0304), (6154, 0x0404), (6155, 0x0403), (6156, 0x0404), (6157, 0x0403), (6158, 0x0403),
+    (6159, 0x0403), (6160, 0x0403), (6161, 0x0403), (6162, 0x0402), (6176, 0x0104), (6177, 0x0204), (6178, 0x0304), (6179, 0x0304),
+    (6180, 0x0404), (6181, 0x0403), (6182, 0x0403), (6183, 0x0402), (6184, 0x0402), (6185, 0x0401), (6208, 0x0104), (6209, 0x0204),
+    (6210, 0x0403), (6211, 0x0401), (6212, 0x0401), (6240, 0x0104), (6241, 0x0401), (6242, 0x0401), (6272, 0x0304), (6273, 0x0401),
+    (6304, 0x0401), (6339, 0x0304), (6340, 0x0204), (6341, 0x0304), (6342, 0x0304), (6343, 0x0304), (6344, 0x0304), (6345, 0x0304),
+    (6346, 0x0404), (6347, 0x0304), (6348, 0x0404), (6349, 0x0404), (6350, 0x0404), (6351, 0x0403), (6352, 0x0403), (6353, 0x0403),
+    (6354, 0x0403), (6355, 0x0403), (6369, 0x0104), (6370, 0x0304), (6371, 0x0304), (6372, 0x0304), (6373, 0x0304), (6374, 0x0404),
+    (6375, 0x0403), (6376, 0x0402), (6377, 0x0401), (6400, 0x0104), (6401, 0x0204), (6402, 0x0204), (6403, 0x0402), (6404, 0x0401),
+    (6432, 0x0104), (6433, 0x0404), (6434, 0x0401), (6464, 0x0104), (6465, 0x0401), (6496, 0x0401), (9984, 0x0401), (9985, 0x0402),
+    (9988, 0x0401), (9992, 0x0401), (10080, 0x0104), (10081, 0x0403), (10082, 0x0401), (10084, 0x0204), (10085, 0x0401), (10088, 0x0401),
+    (10093, 0x0404), (10096, 0x0104), (10100, 0x0401), (10176, 0x0104), (10177, 0x0404), (10178, 0x0401), (10180, 0x0104), (10181, 0x0401),
+    (10184, 0x0401), (10188, 0x0104), (10189, 0x0304), (10190, 0x0401), (10191, 0x0401), (10192, 0x0104), (10193, 0x0401), (10196, 0x0402),
+    (10272, 0x0104), (10273, 0x0404), (10274, 0x0401), (10276, 0x0104), (10277, 0x0401), (10280, 0x0401), (10284, 0x0104), (10285, 0x0204),
+    (10286, 0x0401), (10287, 0x0401), (10288, 0x0104), (10289, 0x0401), (10292, 0x0403), (10296, 0x0104), (10297, 0x0104), (10298, 0x0402),
+    (10299, 0x0401), (10300, 0x0104), (10301, 0x0401), (10304, 0x0404), (10368, 0x0104), (10369, 0x0304), (10370, 0x0401), (10372, 0x0104),
+    (10373, 0x0401), (10376, 0x0401), (10380, 0x0104), (10381, 0x0104), (10382, 0x0402), (10383, 0x0401), (10384, 0x0104), (10385, 0x0401),
+    (10388, 0x0404), (10392, 0x0104), (10393, 0x0104), (10394, 0x0402), (10395, 0x0401), (10396, 0x0104), (10397, 0x0401), (10400, 0x0204),
+    (10405, 0x0104), (10406, 0x0403), (10407, 0x0401), (10408, 0x0104), (10409, 0x0401), (10412, 0x0104), (10464, 0x0104), (10465, 0x0304),
+    (10466, 0x0402), (10468, 0x0104), (10469, 0x0401), (10472, 0x0402), (10476, 0x0104), (10477, 0x0104), (10478, 0x0402), (10479, 0x0401),
+    (10480, 0x0104), (10481, 0x0401), (10484, 0x0304), (10488, 0x0104), (10489, 0x0104), (10490, 0x0403), (10491, 0x0401), (10492, 0x0104),
+    (10493, 0x0401), (10496, 0x0104), (10501, 0x0104), (10502, 0x0304), (10503, 0x0401), (10504, 0x0104), (10505, 0x0402), (10508, 0x0104),
+    (10513, 0x0104), (10514, 0x0204), (10515, 0x0401), (10516, 0x0104), (10517, 0x0402), (10520, 0x0104), (10572, 0x0104), (10573, 0x0104),
+    (10574, 0x0403), (10575, 0x0401), (10576, 0x0104), (10577, 0x0401), (10580, 0x0204), (10584, 0x0104), (10585, 0x0104), (10586, 0x0304),
+    (10587, 0x0401), (10588, 0x0104), (10589, 0x0402), (10592, 0x0104), (10597, 0x0104), (10598, 0x0204), (10599, 0x0401), (10600, 0x0104),
+    (10601, 0x0403), (10604, 0x0104), (10609, 0x0104), (10610, 0x0204), (10611, 0x0402), (10612, 0x0104), (10613, 0x0304), (10616, 0x0104),
+    (10622, 0x0104), (10623, 0x0402), (10625, 0x0204), (10628, 0x0104), (10669, 0x0204), (10670, 0x0403), (10671, 0x0403), (10672, 0x0104),
+    (10673, 0x0402), (10676, 0x0104), (10681, 0x0104), (10682, 0x0304), (10683, 0x0402), (10684, 0x0104), (10685, 0x0403), (10688, 0x0104),
+    (10693, 0x0104), (10694, 0x0204), (10695, 0x0402), (10696, 0x0104), (10697, 0x0304), (10700, 0x0104), (10705, 0x0104), (10706, 0x0104),
+    (10707, 0x0403), (10708, 0x0104), (10709, 0x0204), (10712, 0x0104), (10717, 0x0104), (10718, 0x0104), (10719, 0x0404), (10720, 0x0104),
+    (10721, 0x0104), (10724, 0x0104), (10730, 0x0104), (10731, 0x0304), (10733, 0x0104), (10736, 0x0104), (10742, 0x0104), (10743, 0x0204),
+    (10745, 0x0104), (10748, 0x0104), (10752, 0x0404), (10753, 0x0401), (10754, 0x0402), (10756, 0x0401), (10757, 0x0401), (10760, 0x0401),
+    (10848, 0x0104), (10849, 0x0401), (10850, 0x0401), (10851, 0x0401), (10852, 0x0402), (10853, 0x0401), (10856, 0x0401), (10860, 0x0104),
+    (10861, 0x0404), (10864, 0x0104), (10868, 0x0401), (10944, 0x0104), (10945, 0x0402), (10946, 0x0401), (10947, 0x0401), (10948, 0x0404),
+    (10949, 0x0401), (10952, 0x0401), (10956, 0x0104), (10957, 0x0403), (10958, 0x0401), (10959, 0x0401), (10960, 0x0104), (10961, 0x0401),
+    (10964, 0x0401), (11040, 0x0104), (11041, 0x0304), (11042, 0x0401), (11043, 0x0401), (11044, 0x0104), (11045, 0x0401), (11048, 0x0401),
+    (11052, 0x0104), (11053, 0x0204), (11054, 0x0401), (11055, 0x0401), (11056, 0x0104), (11057, 0x0401), (11060, 0x0401), (11065, 0x0204),
+    (11066, 0x0401), (11068, 0x0104), (11069, 0x0401), (11072, 0x0401), (11136, 0x0104), (11137, 0x0404), (11138, 0x0401), (11140, 0x0104),
+    (11141, 0x0401), (11144, 0x0402), (11148, 0x0104), (11149, 0x0204), (11150, 0x0401), (11151, 0x0401), (11152, 0x0104), (11153, 0x0401),
+    (11156, 0x0402), (11160, 0x0104), (11161, 0x0104), (11162, 0x0401), (11163, 0x0401), (11164, 0x0104), (11165, 0x0401), (11168, 0x0403),
+    (11174, 0x0402), (11177, 0x0401), (11180, 0x0304), (11232, 0x0104), (11233, 0x0403), (11234, 0x0401), (11236, 0x0104), (11237, 0x0401),
+    (11240, 0x0401), (11244, 0x0104), (11245, 0x0204), (11246, 0x0402), (11247, 0x0401), (11248, 0x0104), (11249, 0x0401), (11252, 0x0404),
+    (11256, 0x0104), (11257, 0x0204), (11258, 0x0402), (11259, 0x0401), (11260, 0x0104), (11261, 0x0401), (11264, 0x0404), (11269, 0x0104),
+    (11270, 0x0403), (11271, 0x0401), (11272, 0x0104), (11273, 0x0401), (11276, 0x0204), (11329, 0x0403), (11332, 0x0104), (11336, 0x0402),
+    (11340, 0x0104), (11341, 0x0204), (11342, 0x0402), (11343, 0x0401), (11344, 0x0104), (11345, 0x0402), (11348, 0x0304), (11352, 0x0104),
+    (11353, 0x0204), (11354, 0x0403), (11355, 0x0401), (11356, 0x0104), (11357, 0x0401), (11360, 0x0304), (11365, 0x0104), (11366, 0x0404),
+    (11367, 0x0401), (11368, 0x0104), (11369, 0x0401), (11372, 0x0104), (11377, 0x0104), (11378, 0x0304), (11379, 0x0401), (11380, 0x0104),
+    (11381, 0x0402), (11384, 0x0104), (11432, 0x0403), (11436, 0x0104), (11437, 0x0204), (11438, 0x0403), (11439, 0x0402), (11440, 0x0104),
+    (11441, 0x0402), (11444, 0x0304), (11448, 0x0104), (11449, 0x0104), (11450, 0x0403), (11451, 0x0401), (11452, 0x0104), (11453, 0x0402),
+    (11456, 0x0204), (11461, 0x0104), (11462, 0x0403), (11463, 0x0401), (11464, 0x0104), (11465, 0x0401), (11468, 0x0104), (11473, 0x0104),
+    (11474, 0x0304), (11475, 0x0401), (11476, 0x0104), (11477, 0x0402), (11480, 0x0104), (11485, 0x0104), (11486, 0x0204), (11487, 0x0402),
+    (11488, 0x0104), (11489, 0x0304), (11492, 0x0104), (11499, 0x0404), (11501, 0x0104), (11504, 0x0104), (11520, 0x0401), (11521, 0x0402),
+    (11524, 0x0401), (11528, 0x0401), (11616, 0x0104), (11617, 0x0403), (11618, 0x0401), (11620, 0x0204), (11621, 0x0401), (11624, 0x0401),
+    (11628, 0x0104), (11629, 0x0403), (11632, 0x0204), (11636, 0x0401), (11712, 0x0104), (11713, 0x0404), (11714, 0x0401), (11716, 0x0104),
+    (11717, 0x0401), (11720, 0x0401), (11724, 0x0104), (11725, 0x0204), (11726, 0x0401), (11728, 0x0104), (11729, 0x0401), (11732, 0x0402),
+    (11808, 0x0104), (11809, 0x0304), (11810, 0x0401), (11812, 0x0104), (11813, 0x0401), (11816, 0x0401), (11820, 0x0104), (11821, 0x0204),
+    (11822, 0x0401), (11823, 0x0401), (11824, 0x0104), (11825, 0x0401), (11828, 0x0403), (11832, 0x0104), (11833, 0x0104), (11834, 0x0402),
+    (11835, 0x0401), (11836, 0x0104), (11837, 0x0401), (11840, 0x0304), (11904, 0x0104), (11905, 0x0404), (11906, 0x0401), (11908, 0x0104),
+    (11909, 0x0401), (11912, 0x0401), (11916, 0x0104), (11917, 0x0104), (11918, 0x0402), (11919, 0x0401), (11920, 0x0104), (11921, 0x0401),
+    (11924, 0x0404), (11928, 0x0104), (11929, 0x0104), (11930, 0x0402), (11931, 0x0401), (11932, 0x0104), (11933, 0x0401), (11936, 0x0204),
+    (11941, 0x0104), (11942, 0x0403), (11943, 0x0401), (11944, 0x0104), (11945, 0x0401), (11948, 0x0104), (12012, 0x0104), (12013, 0x0104),
+    (12014, 0x0402), (12015, 0x0401), (12016, 0x0104), (12017, 0x0401), (12020, 0x0304), (12024, 0x0104), (12025, 0x0104), (12026, 0x0403),
+    (12027, 0x0401), (12028, 0x0104), (12029, 0x0401), (12032, 0x0104), (12037, 0x0104), (12038, 0x0304), (12039, 0x0401), (12040, 0x0104),
+    (12041, 0x0401), (12044, 0x0104), (12049, 0x0104), (12050, 0x0204), (12051, 0x0401), (12052, 0x0104), (12053, 0x0402), (12056, 0x0104),
+    (12108, 0x0104), (12109, 0x0104), (12110, 0x0402), (12111, 0x0401), (12112, 0x0104), (12113, 0x0401), (12116, 0x0204), (12120, 0x0104),
+    (12121, 0x0104), (12122, 0x0404), (12123, 0x0401), (12124, 0x0104), (12125, 0x0401), (12128, 0x0104), (12133, 0x0104), (12134, 0x0204),
+    (12135, 0x0401), (12136, 0x0104), (12137, 0x0402), (12140, 0x0104), (12145, 0x0104), (12146, 0x0204), (12147, 0x0401), (12148, 0x0104),
+    (12149, 0x0304), (12152, 0x0104), (12158, 0x0104), (12159, 0x0402), (12161, 0x0204), (12164, 0x0104), (12171, 0x0304), (12173, 0x0104),
+    (12176, 0x0104), (12204, 0x0104), (12205, 0x0204), (12206, 0x0402), (12207, 0x0401), (12208, 0x0104), (12209, 0x0401), (12212, 0x0204),
+    (12216, 0x0104), (12217, 0x0104), (12218, 0x0404), (12219, 0x0402), (12220, 0x0104), (12221, 0x0402), (12224, 0x0104), (12229, 0x0104),
+    (12230, 0x0204), (12231, 0x0402), (12232, 0x0104), (12233, 0x0404), (12236, 0x0104), (12241, 0x0104), (12242, 0x0104), (12243, 0x0403),
+    (12244, 0x0104), (12245, 0x0204), (12248, 0x0104), (12253, 0x0104), (12254, 0x0104), (12255, 0x0403), (12256, 0x0104), (12257, 0x0104),
+    (12260, 0x0104), (12266, 0x0104), (12267, 0x0304), (12269, 0x0104), (12272, 0x0104), (12278, 0x0104), (12279, 0x0204), (12281, 0x0104),
+    (12284, 0x0104), (12288, 0x0404), (12289, 0x0401), (12290, 0x0402), (12291, 0x0401), (12292, 0x0401), (12293, 0x0401), (12296, 0x0401),
+    (12384, 0x0104), (12385, 0x0401), (12386, 0x0401), (12387, 0x0401), (12388, 0x0402), (12389, 0x0401), (12392, 0x0401), (12396, 0x0104),
+    (12397, 0x0402), (12400, 0x0104), (12404, 0x0401), (12480, 0x0104), (12481, 0x0401), (12482, 0x0401), (12483, 0x0401), (12484, 0x0404),
+    (12485, 0x0401), (12488, 0x0401), (12492, 0x0104), (12493, 0x0402), (12494, 0x0401), (12495, 0x0401), (12496, 0x0104), (12497, 0x0401),
+    (12500, 0x0401), (12576, 0x0104), (12577, 0x0304), (12578, 0x0401), (12579, 0x0401), (12580, 0x0104), (12581, 0x0401), (12584, 0x0401),
+    (12588, 0x0104), (12589, 0x0204), (12590, 0x0401), (12591, 0x0401), (12592, 0x0104), (12593, 0x0401), (12596, 0x0401), (12600, 0x0104),
+    (12601, 0x0204), (12602, 0x0401), (12604, 0x0104), (12605, 0x0401), (12608, 0x0402), (12672, 0x0104), (12673, 0x0304), (12674, 0x0401),
+    (12676, 0x0104), (12677, 0x0401), (12680, 0x0401), (12684, 0x0104), (12685, 0x0204), (12686, 0x0401), (12687, 0x0401), (12688, 0x0104),
+    (12689, 0x0401), (12692, 0x0401), (12696, 0x0104), (12697, 0x0104), (12698, 0x0401), (12699, 0x0401), (12700, 0x0104), (12701, 0x0401),
+    (12704, 0x0404), (12709, 0x0204), (12710, 0x0402), (12712, 0x0104), (12713, 0x0401), (12716, 0x0304), (12769, 0x0403), (12772, 0x0104),
+    (12776, 0x0401), (12780, 0x0104), (12781, 0x0204), (12782, 0x0401), (12783, 0x0401), (12784, 0x0104), (12785, 0x0401), (12788, 0x0403),
+    (12792, 0x0104), (12793, 0x0204), (12794, 0x0402), (12795, 0x0401), (12796, 0x0104), (12797, 0x0401), (12800, 0x0404), (12805, 0x0104),
+    (12806, 0x0403), (12807, 0x0401), (12808, 0x0104), (12809, 0x0401), (12812, 0x0204), (12865, 0x0403), (12868, 0x0104), (12872, 0x0401),
+    (12876, 0x0104), (12877, 0x0204), (12878, 0x0402), (12879, 0x0401), (12880, 0x0104), (12881, 0x0401), (12884, 0x0404), (12888, 0x0104),
+    (12889, 0x0104), (12890, 0x0403), (12891, 0x0401), (12892, 0x0104), (12893, 0x0401), (12896, 0x0304), (12901, 0x0104), (12902, 0x0404),
+    (12903, 0x0401), (12904, 0x0104), (12905, 0x0401), (12908, 0x0104), (12913, 0x0104), (12914, 0x0404), (12915, 0x0401), (12916, 0x0104),
+    (12917, 0x0402), (12920, 0x0104), (12961, 0x0204), (12964, 0x0104), (12968, 0x0401), (12972, 0x0104), (12973, 0x0204), (12974, 0x0402),
+    (12975, 0x0401), (12976, 0x0104), (12977, 0x0401), (12980, 0x0404), (12984, 0x0104), (12985, 0x0104), (12986, 0x0403), (12987, 0x0401),
+    (12988, 0x0104), (12989, 0x0401), (12992, 0x0204), (12997, 0x0104), (12998, 0x0404), (12999, 0x0401), (13000, 0x0104), (13001, 0x0401),
+    (13004, 0x0104), (13009, 0x0104), (13010, 0x0204), (13011, 0x0401), (13012, 0x0104), (13013, 0x0402), (13016, 0x0104), (13021, 0x0104),
+    (13022, 0x0204), (13023, 0x0402), (13024, 0x0104), (13025, 0x0404), (13028, 0x0104), (13035, 0x0404), (13037, 0x0204), (13040, 0x0104),
+    (13166, 0x0403), (13167, 0x0402), (13168, 0x0402), (13176, 0x0404), (13177, 0x0402), (13178, 0x0402), (13186, 0x0403), (13187, 0x0403),
+    (13188, 0x0402), (13196, 0x0404), (13197, 0x0403), (13198, 0x0403), (13206, 0x0404), (13207, 0x0403), (13208, 0x0403), (13216, 0x0404),
+    (13217, 0x0404), (13218, 0x0403), (13226, 0x0404), (13227, 0x0404), (13228, 0x0404), (13266, 0x0403), (13267, 0x0402), (13268, 0x0401),
+    (13276, 0x0403), (13277, 0x0402), (13278, 0x0402), (13286, 0x0403), (13287, 0x0403), (13288, 0x0402), (13296, 0x0403), (13297, 0x0403),
+    (13298, 0x0403), (13306, 0x0404), (13307, 0x0403), (13308, 0x0403), (13316, 0x0404), (13317, 0x0404), (13318, 0x0403), (13366, 0x0402),
+    (13367, 0x0401), (13368, 0x0402), (13376, 0x0403), (13377, 0x0402), (13378, 0x0402), (13386, 0x0403), (13387, 0x0402), (13388, 0x0403),
+    (13396, 0x0403), (13397, 0x0403), (13398, 0x0403), (13406, 0x0404), (13407, 0x0403), (13408, 0x0403), (13466, 0x0401), (13467, 0x0401),
+    (13468, 0x0401), (13476, 0x0402), (13477, 0x0401), (13478, 0x0402), (13486, 0x0403), (13487, 0x0402), (13488, 0x0402), (13496, 0x0403),
+    (13497, 0x0403), (13498, 0x0403), (13566, 0x0401), (13567, 0x0401), (13568, 0x0401), (13576, 0x0402), (13577, 0x0402), (13578, 0x0401),
+    (13586, 0x0403), (13587, 0x0403), (13588, 0x0402), (13666, 0x0401), (13667, 0x0401), (13668, 0x0401), (13676, 0x0402), (13677, 0x0402),
+    (13678, 0x0401), (13766, 0x0401), (13767, 0x0401), (13866, 0x0403), (13867, 0x0402), (13868, 0x0402), (13876, 0x0403), (13877, 0x0402),
+    (13878, 0x0402), (13886, 0x0403), (13887, 0x0403), (13888, 0x0402), (13896, 0x0404), (13897, 0x0403), (13898, 0x0403), (13906, 0x0404),
+    (13907, 0x0403), (13908, 0x0403), (13916, 0x0404), (13917, 0x0404), (13918, 0x0404), (13926, 0x0404), (13927, 0x0404), (13928, 0x0404),
+    (13966, 0x0401), (13976, 0x0402), (13977, 0x0401), (13986, 0x0402), (13987, 0x0401), (13988, 0x0401), (13996, 0x0402), (13997, 0x0402),
+    (13998, 0x0401), (13999, 0x0401), (14006, 0x0403), (14007, 0x0402), (14008, 0x0401), (14009, 0x0401), (14016, 0x0403), (14017, 0x0402),
+    (14018, 0x0402), (14019, 0x0401), (14020, 0x0401), (14021, 0x0401), (14026, 0x0404), (14027, 0x0403), (14028, 0x0403), (14029, 0x0402),
+    (14030, 0x0401), (14031, 0x0401), (14032, 0x0401), (14036, 0x0404), (14037, 0x0403), (14038, 0x0403), (14039, 0x0403), (14040, 0x0402),
+    (14041, 0x0402), (14042, 0x0401), (14043, 0x0401), (14046, 0x0404), (14047, 0x0404), (14048, 0x0404), (14049, 0x0403), (14050, 0x0403),
+    (14051, 0x0402), (14052, 0x0401), (14053, 0x0401), (14066, 0x0401), (14076, 0x0401), (14077, 0x0401), (14086, 0x0401), (14087, 0x0401),
+    (14096, 0x0402), (14097, 0x0401), (14098, 0x0401), (14106, 0x0402), (14107, 0x0401), (14108, 0x0401), (14109, 0x0401), (14116, 0x0403),
+    (14117, 0x0402), (14118, 0x0401), (14119, 0x0401), (14126, 0x0403), (14127, 0x0403), (14128, 0x0402), (14129, 0x0401), (14130, 0x0401),
+    (14131, 0x0401), (14136, 0x0404), (14137, 0x0403), (14138, 0x0403), (14139, 0x0402), (14140, 0x0402), (14141, 0x0401), (14142, 0x0401),
+    (14146, 0x0404), (14147, 0x0404), (14148, 0x0403), (14149, 0x0403), (14150, 0x0403), (14151, 0x0401), (14152, 0x0401), (14153, 0x0401),
+    (14166, 0x0401), (14167, 0x0401), (14176, 0x0401), (14177, 0x0401), (14186, 0x0401), (14187, 0x0401), (14196, 0x0401), (14197, 0x0401),
+    (14198, 0x0401), (14206, 0x0402), (14207, 0x0401), (14208, 0x0401), (14216, 0x0403), (14217, 0x0401), (14218, 0x0401), (14219, 0x0401),
+    (14226, 0x0403), (14227, 0x0402), (14228, 0x0401), (14229, 0x0401), (14230, 0x0401), (14231, 0x0401), (14236, 0x0404), (14237, 0x0403),
+    (14238, 0x0402), (14239, 0x0402), (14240, 0x0401), (14241, 0x0401), (14246, 0x0404), (14247, 0x0403), (14248, 0x0403), (14249, 0x0403),
+    (14250, 0x0402), (14251, 0x0401), (14252, 0x0401), (14266, 0x0401), (14276, 0x0401), (14286, 0x0401), (14287, 0x0401), (14296, 0x0401),
+    (14297, 0x0401), (14306, 0x0401), (14307, 0x0401), (14308, 0x0401), (14316, 0x0402), (14317, 0x0401), (14318, 0x0401), (14319, 0x0401),
+    (14326, 0x0403), (14327, 0x0401), (14328, 0x0401), (14329, 0x0401), (14330, 0x0401), (14336, 0x0403), (14337, 0x0403), (14338, 0x0402),
+    (14339, 0x0401), (14340, 0x0401), (14346, 0x0404), (14347, 0x0403), (14348, 0x0402), (14349, 0x0401), (14350, 0x0401), (14351, 0x0401),
+    (14352, 0x0401), (14366, 0x0401), (14376, 0x0401), (14386, 0x0401), (14396, 0x0401), (14397, 0x0401), (14406, 0x0401), (14407, 0x0401),
+    (14416, 0x0402), (14417, 0x0401), (14418, 0x0401), (14426, 0x0402), (14427, 0x0401), (14428, 0x0401), (14429, 0x0401), (14436, 0x0403),
+    (14437, 0x0402), (14438, 0x0401), (14439, 0x0401), (14440, 0x0401), (14441, 0x0401), (14446, 0x0403), (14447, 0x0403), (14448, 0x0402),
+    (14449, 0x0402), (14450, 0x0401), (14451, 0x0401), (14486, 0x0401), (14496, 0x0401), (14506, 0x0401), (14507, 0x0401), (14516, 0x0401),
+    (14517, 0x0401), (14526, 0x0401), (14527, 0x0401), (14528, 0x0401), (14536, 0x0403), (14537, 0x0401), (14538, 0x0401), (14539, 0x0401),
+    (14540, 0x0401), (14546, 0x0404), (14547, 0x0403), (14548, 0x0402), (14549, 0x0402), (14550, 0x0401), (14566, 0x0401), (14576, 0x0401),
+    (14586, 0x0401), (14596, 0x0401), (14597, 0x0401), (14606, 0x0401), (14607, 0x0401), (14616, 0x0401), (14617, 0x0401), (14626, 0x0402),
+    (14627, 0x0401), (14628, 0x0401), (14629, 0x0401), (14636, 0x0403), (14637, 0x0402), (14638, 0x0401), (14639, 0x0401), (14666, 0x0403),
+    (14667, 0x0402), (14668, 0x0401), (14676, 0x0403), (14677, 0x0402), (14678, 0x0401), (14686, 0x0403), (14687, 0x0403), (14688, 0x0402),
+    (14696, 0x0403), (14697, 0x0403), (14698, 0x0403), (14706, 0x0404), (14707, 0x0404), (14708, 0x0403), (14716, 0x0404), (14717, 0x0404),
+    (14718, 0x0404), (14766, 0x0401), (14767, 0x0401), (14776, 0x0401), (14777, 0x0401), (14786, 0x0401), (14787, 0x0401), (14788, 0x0401),
+    (14796, 0x0402), (14797, 0x0401), (14798, 0x0401), (14806, 0x0402), (14807, 0x0401), (14808, 0x0401), (14809, 0x0401), (14816, 0x0403),
+    (14817, 0x0402), (14818, 0x0401), (14819, 0x0401), (14820, 0x0401), (14826, 0x0403), (14827, 0x0403), (14828, 0x0402), (14829, 0x0401),
+    (14830, 0x0401), (14831, 0x0401), (14836, 0x0404), (14837, 0x0403), (14838, 0x0403), (14839, 0x0402), (14840, 0x0402), (14841, 0x0401),
+    (14842, 0x0401), (14846, 0x0404), (14847, 0x0404), (14848, 0x0403), (14849, 0x0403), (14850, 0x0402), (14851, 0x0401), (14852, 0x0401),
+    (14853, 0x0401), (14866, 0x0401), (14876, 0x0401), (14877, 0x0401), (14886, 0x0401), (14887, 0x0401), (14896, 0x0401), (14897, 0x0401),
+    (14898, 0x0401), (14906, 0x0402), (14907, 0x0401), (14908, 0x0401), (14916, 0x0403), (14917, 0x0402), (14918, 0x0401), (14919, 0x0401),
+    (14926, 0x0403), (14927, 0x0403), (14928, 0x0402), (14929, 0x0401), (14930, 0x0401), (14936, 0x0404), (14937, 0x0403), (14938, 0x0403),
+    (14939, 0x0402), (14940, 0x0401), (14941, 0x0401), (14946, 0x0404), (14947, 0x0404), (14948, 0x0403), (14949, 0x0403), (14950, 0x0402),
+    (14951, 0x0401), (14952, 0x0401), (14966, 0x0401), (14976, 0x0401), (14977, 0x0401), (14986, 0x0401), (14987, 0x0401), (14996, 0x0401),
+    (14997, 0x0401), (15006, 0x0401), (15007, 0x0401), (15008, 0x0401), (15016, 0x0402), (15017, 0x0401), (15018, 0x0401), (15019, 0x0401),
+    (15026, 0x0403), (15027, 0x0402), (15028, 0x0401), (15029, 0x0401), (15030, 0x0401), (15036, 0x0404), (15037, 0x0403), (15038, 0x0402),
+    (15039, 0x0401), (15040, 0x0401), (15041, 0x0401), (15046, 0x0404), (15047, 0x0404), (15048, 0x0403), (15049, 0x0402), (15050, 0x0401),
+    (15051, 0x0401), (15052, 0x0401), (15066, 0x0401), (15076, 0x0401), (15086, 0x0401), (15096, 0x0401), (15097, 0x0401), (15106, 0x0401),
+    (15107, 0x0401), (15116, 0x0402), (15117, 0x0401), (15118, 0x0401), (15126, 0x0403), (15127, 0x0402), (15128, 0x0401), (15129, 0x0401),
+    (15136, 0x0403), (15137, 0x0403), (15138, 0x0402), (15139, 0x0401), (15140, 0x0401), (15141, 0x0401), (15146, 0x0404), (15147, 0x0403),
+    (15148, 0x0403), (15149, 0x0402), (15150, 0x0401), (15151, 0x0401), (15152, 0x0401), (15176, 0x0401), (15186, 0x0401), (15196, 0x0401),
+    (15206, 0x0401), (15207, 0x0401), (15216, 0x0402), (15217, 0x0401), (15226, 0x0402), (15227, 0x0401), (15228, 0x0401), (15229, 0x0401),
+    (15236, 0x0403), (15237, 0x0402), (15238, 0x0402), (15239, 0x0401), (15240, 0x0401), (15241, 0x0401), (15246, 0x0403), (15247, 0x0403),
+    (15248, 0x0403), (15249, 0x0402), (15250, 0x0401), (15251, 0x0401), (15276, 0x0401), (15286, 0x0401), (15296, 0x0401), (15306, 0x0401),
+    (15307, 0x0401), (15316, 0x0401), (15317, 0x0401), (15318, 0x0401), (15326, 0x0402), (15327, 0x0401), (15328, 0x0401), (15329, 0x0401),
+    (15336, 0x0403), (15337, 0x0402), (15338, 0x0401), (15339, 0x0401), (15366, 0x0401), (15376, 0x0401), (15386, 0x0401), (15396, 0x0401),
+    (15406, 0x0401), (15407, 0x0401), (15416, 0x0401), (15417, 0x0401), (15418, 0x0401), (15426, 0x0401), (15427, 0x0401), (15428, 0x0401),
+    (15429, 0x0401), (15436, 0x0403), (15437, 0x0402), (15438, 0x0401), (15439, 0x0401), (15466, 0x0402), (15467, 0x0401), (15476, 0x0402),
+    (15477, 0x0401), (15478, 0x0402), (15486, 0x0403), (15487, 0x0402), (15488, 0x0403), (15496, 0x0403), (15497, 0x0403), (15498, 0x0403),
+    (15506, 0x0404), (15507, 0x0403), (15508, 0x0403), (15566, 0x0401), (15576, 0x0401), (15586, 0x0401), (15587, 0x0401), (15596, 0x0401),
+    (15597, 0x0401), (15606, 0x0401), (15607, 0x0401), (15608, 0x0401), (15616, 0x0402), (15617, 0x0401), (15618, 0x0401), (15619, 0x0401),
+    (15626, 0x0403), (15627, 0x0402), (15628, 0x0401), (15629, 0x0401), (15630, 0x0401), (15636, 0x0404), (15637, 0x0403), (15638, 0x0403),
+    (15639, 0x0402), (15640, 0x0401), (15641, 0x0401), (15646, 0x0404), (15647, 0x0403), (15648, 0x0403), (15649, 0x0402), (15650, 0x0401),
+    (15651, 0x0401), (15652, 0x0401), (15666, 0x0401), (15676, 0x0401), (15677, 0x0401), (15686, 0x0401), (15687, 0x0401), (15696, 0x0401),
+    (15697, 0x0401), (15706, 0x0402), (15707, 0x0401), (15708, 0x0401), (15716, 0x0402), (15717, 0x0401), (15718, 0x0401), (15719, 0x0401),
+    (15726, 0x0403), (15727, 0x0402), (15728, 0x0402), (15729, 0x0401), (15730, 0x0401), (15736, 0x0404), (15737, 0x0403), (15738, 0x0402),
+    (15739, 0x0401), (15740, 0x0401), (15741, 0x0401), (15746, 0x0404), (15747, 0x0404), (15748, 0x0403), (15749, 0x0402), (15750, 0x0401),
+    (15751, 0x0401), (15752, 0x0401), (15766, 0x0401), (15776, 0x0401), (15777, 0x0401), (15786, 0x0401), (15787, 0x0401), (15796, 0x0401),
+    (15797, 0x0401), (15806, 0x0401), (15807, 0x0401), (15816, 0x0402), (15817, 0x0401), (15818, 0x0401), (15826, 0x0403), (15827, 0x0402),
+    (15828, 0x0401), (15829, 0x0401), (15836, 0x0403), (15837, 0x0403), (15838, 0x0402), (15839, 0x0401), (15840, 0x0401), (15841, 0x0401),
+    (15846, 0x0404), (15847, 0x0403), (15848, 0x0403), (15849, 0x0402), (15850, 0x0401), (15851, 0x0401), (15852, 0x0401), (15866, 0x0401),
+    (15876, 0x0401), (15886, 0x0401), (15896, 0x0401), (15906, 0x0401), (15907, 0x0401), (15916, 0x0402), (15917, 0x0401), (15918, 0x0401),
+    (15926, 0x0403), (15927, 0x0402), (15928, 0x0401), (15929, 0x0401), (15936, 0x0403), (15937, 0x0402), (15938, 0x0402), (15939, 0x0401),
+    (15940, 0x0401), (15941, 0x0401), (15946, 0x0404), (15947, 0x0404), (15948, 0x0403), (15949, 0x0403), (15950, 0x0402), (15951, 0x0401),
+    (15976, 0x0401), (15986, 0x0401), (15996, 0x0401), (16006, 0x0401), (16007, 0x0401), (16016, 0x0401), (16017, 0x0401), (16018, 0x0401),
+    (16026, 0x0402), (16027, 0x0401), (16028, 0x0401), (16029, 0x0401), (16030, 0x0401), (16036, 0x0403), (16037, 0x0402), (16038, 0x0401),
+    (16039, 0x0401), (16040, 0x0401), (16076, 0x0401), (16086, 0x0401), (16096, 0x0401), (16106, 0x0401), (16107, 0x0401), (16116, 0x0401),
+    (16117, 0x0401), (16118, 0x0401), (16126, 0x0402), (16127, 0x0401), (16128, 0x0401), (16129, 0x0401), (16166, 0x0401), (16176, 0x0401),
+    (16186, 0x0401), (16187, 0x0401), (16196, 0x0401), (16197, 0x0401), (16206, 0x0401), (16207, 0x0401), (16208, 0x0401), (16216, 0x0402),
+    (16217, 0x0401), (16218, 0x0401), (16266, 0x0401), (16267, 0x0401), (16268, 0x0402), (16276, 0x0402), (16277, 0x0402), (16278, 0x0402),
+    (16286, 0x0403), (16287, 0x0402), (16288, 0x0402), (16296, 0x0404), (16297, 0x0403), (16298, 0x0403), (16366, 0x0401), (16376, 0x0401),
+    (16377, 0x0401), (16386, 0x0401), (16387, 0x0401), (16396, 0x0401), (16397, 0x0401), (16398, 0x0401), (16406, 0x0401), (16407, 0x0401),
+    (16408, 0x0401), (16416, 0x0402), (16417, 0x0401), (16418, 0x0401), (16419, 0x0401), (16426, 0x0403), (16427, 0x0402), (16428, 0x0402),
+    (16429, 0x0401), (16430, 0x0401), (16436, 0x0403), (16437, 0x0403), (16438, 0x0402), (16439, 0x0401), (16440, 0x0401), (16441, 0x0401),
+    (16446, 0x0404), (16447, 0x0403), (16448, 0x0403), (16449, 0x0401), (16450, 0x0401), (16451, 0x0401), (16452, 0x0401), (16466, 0x0401),
+    (16476, 0x0401), (16486, 0x0401), (16496, 0x0401), (16497, 0x0401), (16506, 0x0401), (16507, 0x0401), (16516, 0x0401), (16517, 0x0401),
+    (16518, 0x0401), (16526, 0x0402), (16527, 0x0402), (16528, 0x0401), (16529, 0x0401), (16536, 0x0403), (16537, 0x0402), (16538, 0x0402),
+    (16539, 0x0401), (16540, 0x0401), (16541, 0x0401), (16546, 0x0404), (16547, 0x0403), (16548, 0x0403), (16549, 0x0401), (16550, 0x0401),
+    (16551, 0x0401), (16552, 0x0401), (16566, 0x0401), (16576, 0x0401), (16586, 0x0401), (16596, 0x0401), (16606, 0x0401), (16607, 0x0401),
+    (16616, 0x0402), (16617, 0x0401), (16618, 0x0401), (16626, 0x0403), (16627, 0x0401), (16628, 0x0401), (16629, 0x0401), (16630, 0x0401),
+    (16636, 0x0403), (16637, 0x0403), (16638, 0x0402), (16639, 0x0401), (16640, 0x0401), (16641, 0x0401), (16666, 0x0401), (16676, 0x0401),
+    (16686, 0x0401), (16696, 0x0401), (16706, 0x0401), (16707, 0x0401), (16716, 0x0402), (16717, 0x0401), (16718, 0x0401), (16726, 0x0402),
+    (16727, 0x0401), (16728, 0x0401), (16729, 0x0401), (16730, 0x0401), (16736, 0x0403), (16737, 0x0402), (16738, 0x0401), (16739, 0x0401),
+    (16740, 0x0401), (16776, 0x0401), (16786, 0x0401), (16796, 0x0401), (16797, 0x0401), (16806, 0x0401), (16807, 0x0401), (16816, 0x0401),
+    (16817, 0x0401), (16818, 0x0401), (16826, 0x0402), (16827, 0x0402), (16828, 0x0401), (16829, 0x0401), (16830, 0x0401), (16866, 0x0401),
+    (16876, 0x0401), (16886, 0x0401), (16896, 0x0401), (16897, 0x0401), (16906, 0x0401), (16907, 0x0401), (16908, 0x0401), (16916, 0x0402),
+    (16917, 0x0401), (16918, 0x0401), (16919, 0x0401), (16966, 0x0401), (16976, 0x0401), (16986, 0x0401), (16987, 0x0401), (16996, 0x0401),
+    (16997, 0x0401), (17006, 0x0401), (17007, 0x0401), (17008, 0x0401), (17016, 0x0402), (17017, 0x0401), (17018, 0x0401), (17066, 0x0401),
+    (17067, 0x0402), (17068, 0x0401), (17076, 0x0402), (17077, 0x0402), (17078, 0x0401), (17086, 0x0403), (17087, 0x0403), (17088, 0x0402),
+    (17166, 0x0401), (17176, 0x0401), (17186, 0x0401), (17196, 0x0401), (17197, 0x0401), (17206, 0x0401), (17207, 0x0401), (17216, 0x0401),
+    (17217, 0x0401), (17218, 0x0401), (17226, 0x0402), (17227, 0x0402), (17228, 0x0401), (17229, 0x0401), (17236, 0x0403), (17237, 0x0402),
+    (17238, 0x0401), (17239, 0x0401), (17240, 0x0401), (17241, 0x0401), (17246, 0x0404), (17247, 0x0403), (17248, 0x0402), (17249, 0x0401),
+    (17250, 0x0401), (17251, 0x0401), (17266, 0x0401), (17276, 0x0401), (17286, 0x0401), (17296, 0x0401), (17306, 0x0401), (17307, 0x0401),
+    (17316, 0x0402), (17317, 0x0401), (17318, 0x0401), (17326, 0x0402), (17327, 0x0401), (17328, 0x0401), (17329, 0x0401), (17330, 0x0401),
+    (17336, 0x0403), (17337, 0x0402), (17338, 0x0402), (17339, 0x0401), (17340, 0x0401), (17341, 0x0401), (17376, 0x0401), (17386, 0x0401),
+    (17396, 0x0401), (17406, 0x0401), (17407, 0x0401), (17416, 0x0401), (17417, 0x0401), (17418, 0x0401), (17426, 0x0402), (17427, 0x0401),
+    (17428, 0x0401), (17429, 0x0401), (17436, 0x0403), (17437, 0x0402), (17438, 0x0401), (17439, 0x0401), (17440, 0x0401), (17466, 0x0401),
+    (17476, 0x0401), (17486, 0x0401), (17496, 0x0401), (17497, 0x0401), (17506, 0x0401), (17507, 0x0401), (17508, 0x0401), (17516, 0x0401),
+    (17517, 0x0401), (17518, 0x0401), (17519, 0x0401), (17526, 0x0402), (17527, 0x0402), (17528, 0x0401), (17529, 0x0401), (17530, 0x0401),
+    (17566, 0x0401), (17576, 0x0401), (17586, 0x0401), (17596, 0x0401), (17597, 0x0401), (17606, 0x0401), (17607, 0x0401), (17608, 0x0401),
+    (17616, 0x0401), (17617, 0x0401), (17618, 0x0401), (17619, 0x0401), (17626, 0x0403), (17627, 0x0402), (17628, 0x0401), (17666, 0x0401),
+    (17676, 0x0401), (17686, 0x0401), (17687, 0x0401), (17696, 0x0401), (17697, 0x0401), (17706, 0x0401), (17707, 0x0401), (17708, 0x0401),
+    (17716, 0x0402), (17717, 0x0401), (17718, 0x0401), (17766, 0x0401), (17767, 0x0401), (17776, 0x0401), (17777, 0x0401), (17786, 0x0401),
+    (17787, 0x0401), (17788, 0x0401), (17796, 0x0401), (17797, 0x0401), (17798, 0x0401), (17866, 0x0401), (17867, 0x0401), (17868, 0x0401),
+    (17876, 0x0402), (17877, 0x0401), (17878, 0x0401), (17966, 0x0401), (17976, 0x0401), (17986, 0x0401), (17996, 0x0401), (17997, 0x0401),
+    (18006, 0x0401), (18007, 0x0401), (18008, 0x0401), (18016, 0x0402), (18017, 0x0401), (18018, 0x0401), (18026, 0x0402), (18027, 0x0401),
+    (18028, 0x0401), (18029, 0x0401), (18030, 0x0401), (18036, 0x0403), (18037, 0x0402), (18038, 0x0401), (18039, 0x0401), (18040, 0x0401),
+    (18041, 0x0401), (18076, 0x0401), (18086, 0x0401), (18096, 0x0401), (18106, 0x0401), (18107, 0x0401), (18116, 0x0401), (18117, 0x0401),
+    (18126, 0x0401), (18127, 0x0401), (18128, 0x0401), (18129, 0x0401), (18136, 0x0402), (18137, 0x0402), (18138, 0x0401), (18139, 0x0401),
+    (18140, 0x0401), (18166, 0x0401), (18176, 0x0401), (18186, 0x0401), (18196, 0x0401), (18197, 0x0401), (18206, 0x0401), (18207, 0x0401),
+    (18208, 0x0401), (18216, 0x0402), (18217, 0x0401), (18218, 0x0401), (18219, 0x0401), (18226, 0x0403), (18227, 0x0402), (18228, 0x0401),
+    (18229, 0x0401), (18276, 0x0401), (18286, 0x0401), (18296, 0x0401), (18297, 0x0401), (18306, 0x0401), (18307, 0x0401), (18308, 0x0401),
+    (18316, 0x0402), (18317, 0x0401), (18318, 0x0401), (18326, 0x0403), (18327, 0x0402), (18328, 0x0401), (18366, 0x0401), (18376, 0x0401),
+    (18386, 0x0401), (18387, 0x0401), (18396, 0x0401), (18397, 0x0401), (18398, 0x0401), (18406, 0x0401), (18407, 0x0401), (18408, 0x0401),
+    (18466, 0x0401), (18476, 0x0401), (18477, 0x0401), (18486, 0x0401), (18487, 0x0401), (18496, 0x0401), (18497, 0x0401), (18498, 0x0401),
+    (18566, 0x0401), (18567, 0x0401), (18576, 0x0401), (18577, 0x0401), (18586, 0x0401), (18587, 0x0401), (18588, 0x0401), (18666, 0x0401),
+    (18667, 0x0401), (18766, 0x0401), (18776, 0x0401), (18786, 0x0401), (18796, 0x0401), (18797, 0x0401), (18806, 0x0401), (18807, 0x0401),
+    (18816, 0x0401), (18817, 0x0401), (18826, 0x0401), (18827, 0x0401), (18828, 0x0401), (18829, 0x0401), (18836, 0x0402), (18837, 0x0401),
+    (18838, 0x0401), (18839, 0x0401), (18866, 0x0401), (18876, 0x0401), (18886, 0x0401), (18896, 0x0401), (18897, 0x0401), (18906, 0x0401),
+    (18907, 0x0401), (18908, 0x0401), (18916, 0x0401), (18917, 0x0401), (18918, 0x0401), (18919, 0x0401), (18926, 0x0402), (18927, 0x0402),
+    (18928, 0x0401), (18929, 0x0401), (18976, 0x0401), (18986, 0x0401), (18996, 0x0401), (18997, 0x0401), (19006, 0x0401), (19007, 0x0401),
+    (19016, 0x0401), (19017, 0x0401), (19018, 0x0401), (19026, 0x0402), (19027, 0x0401), (19028, 0x0401), (19066, 0x0401), (19076, 0x0401),
+    (19077, 0x0401), (19086, 0x0401), (19087, 0x0401), (19096, 0x0401), (19097, 0x0401), (19098, 0x0401), (19106, 0x0401), (19107, 0x0401),
+    (19108, 0x0401), (19166, 0x0401), (19176, 0x0401), (19177, 0x0401), (19186, 0x0401), (19187, 0x0401), (19196, 0x0401), (19197, 0x0401),
+    (19198, 0x0401), (19206, 0x0401), (19207, 0x0401), (19208, 0x0401), (19266, 0x0401), (19267, 0x0401), (19276, 0x0401), (19277, 0x0401),
+    (19278, 0x0401), (19366, 0x0401), (19367, 0x0401), (19368, 0x0401), (19566, 0x0401), (19567, 0x0401), (19568, 0x0401), (19576, 0x0402),
+    (19577, 0x0402), (19578, 0x0402), (19586, 0x0404), (19587, 0x0403), (19588, 0x0403), (19596, 0x0404), (19597, 0x0404), (19598, 0x0403),
+    (19606, 0x0404), (19607, 0x0404), (19608, 0x0403), (19616, 0x0404), (19617, 0x0404), (19618, 0x0403), (19626, 0x0404), (19627, 0x0404),
+    (19628, 0x0404), (19666, 0x0401), (19667, 0x0401), (19668, 0x0401), (19676, 0x0402), (19677, 0x0402), (19678, 0x0401), (19686, 0x0404),
+    (19687, 0x0403), (19688, 0x0402), (19696, 0x0404), (19697, 0x0403), (19698, 0x0403), (19706, 0x0404), (19707, 0x0404), (19708, 0x0403),
+    (19716, 0x0404), (19717, 0x0403), (19718, 0x0403), (19766, 0x0401), (19767, 0x0401), (19776, 0x0402), (19777, 0x0401), (19786, 0x0403),
+    (19787, 0x0401), (19796, 0x0404), (19797, 0x0402), (19806, 0x0403), (19807, 0x0402), (19866, 0x0401), (19867, 0x0401), (19876, 0x0401),
+    (19877, 0x0401), (19886, 0x0401), (19887, 0x0401), (19896, 0x0402), (19897, 0x0401), (19966, 0x0401), (19976, 0x0401), (19977, 0x0401),
+    (19986, 0x0401), (19987, 0x0401), (20066, 0x0401), (20076, 0x0401), (20166, 0x0401), (20266, 0x0402), (20267, 0x0401), (20268, 0x0401),
+    (20276, 0x0402), (20277, 0x0402), (20278, 0x0402), (20286, 0x0403), (20287, 0x0403), (20288, 0x0402), (20296, 0x0404), (20297, 0x0404),
+    (20298, 0x0403), (20306, 0x0404), (20307, 0x0404), (20308, 0x0403), (20316, 0x0404), (20317, 0x0404), (20318, 0x0404), (20326, 0x0404),
+    (20327, 0x0403), (20328, 0x0403), (20366, 0x0401), (20367, 0x0401), (20376, 0x0401), (20377, 0x0401), (20378, 0x0401), (20386, 0x0402),
+    (20387, 0x0401), (20388, 0x0401), (20389, 0x0401), (20396, 0x0403), (20397, 0x0402), (20398, 0x0401), (20399, 0x0401), (20406, 0x0404),
+    (20407, 0x0403), (20408, 0x0402), (20409, 0x0401), (20410, 0x0401), (20416, 0x0404), (20417, 0x0403), (20418, 0x0402), (20419, 0x0401),
+    (20420, 0x0401), (20426, 0x0404), (20427, 0x0403), (20428, 0x0403), (20429, 0x0401), (20430, 0x0401), (20436, 0x0404), (20437, 0x0403),
+    (20438, 0x0402), (20439, 0x0401), (20440, 0x0401), (20446, 0x0404), (20447, 0x0404), (20448, 0x0403), (20449, 0x0402), (20450, 0x0401),
+    (20466, 0x0401), (20467, 0x0401), (20476, 0x0401), (20477, 0x0401), (20478, 0x0401), (20486, 0x0402), (20487, 0x0401), (20488, 0x0401),
+    (20496, 0x0403), (20497, 0x0402), (20498, 0x0401), (20499, 0x0401), (20506, 0x0403), (20507, 0x0402), (20508, 0x0401), (20509, 0x0401),
+    (20516, 0x0403), (20517, 0x0403), (20518, 0x0402), (20519, 0x0401), (20526, 0x0404), (20527, 0x0403), (20528, 0x0402), (20529, 0x0401),
+    (20536, 0x0404), (20537, 0x0403), (20538, 0x0402), (20539, 0x0401), (20540, 0x0401), (20546, 0x0404), (20547, 0x0404), (20548, 0x0403),
+    (20549, 0x0402), (20550, 0x0401), (20566, 0x0401), (20567, 0x0401), (20576, 0x0401), (20577, 0x0401), (20586, 0x0401), (20587, 0x0401),
+    (20596, 0x0402), (20597, 0x0401), (20606, 0x0402), (20607, 0x0401), (20616, 0x0402), (20617, 0x0401), (20618, 0x0401), (20626, 0x0402),
+    (20627, 0x0401), (20628, 0x0401), (20636, 0x0403), (20637, 0x0402), (20638, 0x0401), (20646, 0x0403), (20647, 0x0402), (20676, 0x0401),
+    (20686, 0x0401), (20696, 0x0401), (20706, 0x0401), (20716, 0x0401), (20717, 0x0401), (20726, 0x0401), (20727, 0x0401), (20736, 0x0401),
+    (20737, 0x0401), (20746, 0x0402), (20776, 0x0401), (20786, 0x0401), (20796, 0x0401), (20806, 0x0401), (20816, 0x0401), (20826, 0x0401),
+    (20827, 0x0401), (20836, 0x0401), (20906, 0x0401), (20916, 0x0401), (20926, 0x0401), (20936, 0x0401), (21006, 0x0401), (21016, 0x0401),
+    (21026, 0x0401), (21066, 0x0401), (21067, 0x0401), (21068, 0x0401), (21076, 0x0403), (21077, 0x0402), (21078, 0x0401), (21086, 0x0404),
+    (21087, 0x0403), (21088, 0x0402), (21096, 0x0404), (21097, 0x0403), (21098, 0x0403), (21106, 0x0404), (21107, 0x0404), (21108, 0x0403),
+    (21116, 0x0404), (21117, 0x0404), (21118, 0x0403), (21166, 0x0401), (21167, 0x0401), (21168, 0x0401), (21176, 0x0402), (21177, 0x0401),
+    (21178, 0x0401), (21186, 0x0402), (21187, 0x0401), (21188, 0x0401), (21196, 0x0403), (21197, 0x0402), (21198, 0x0401), (21199, 0x0401),
+    (21206, 0x0403), (21207, 0x0402), (21208, 0x0401), (21209, 0x0401), (21216, 0x0403), (21217, 0x0403), (21218, 0x0402), (21219, 0x0401),
+    (21226, 0x0403), (21227, 0x0403), (21228, 0x0402), (21229, 0x0401), (21230, 0x0401), (21236, 0x0403), (21237, 0x0403), (21238, 0x0402),
+    (21239, 0x0401), (21240, 0x0401), (21246, 0x0404), (21247, 0x0403), (21248, 0x0403), (21249, 0x0402), (21250, 0x0401), (21266, 0x0401),
+    (21276, 0x0401), (21277, 0x0401), (21286, 0x0401), (21287, 0x0401), (21296, 0x0401), (21297, 0x0401), (21306, 0x0402), (21307, 0x0401),
+    (21316, 0x0402), (21317, 0x0401), (21318, 0x0401), (21326, 0x0403), (21327, 0x0401), (21328, 0x0401), (21336, 0x0403), (21337, 0x0402),
+    (21338, 0x0401), (21346, 0x0403), (21347, 0x0403), (21348, 0x0402), (21366, 0x0401), (21376, 0x0401), (21386, 0x0401), (21396, 0x0401),
+    (21406, 0x0401), (21407, 0x0401), (21416, 0x0401), (21417, 0x0401), (21426, 0x0401), (21427, 0x0401), (21436, 0x0402), (21437, 0x0401),
+    (21446, 0x0402), (21476, 0x0401), (21486, 0x0401), (21496, 0x0401), (21506, 0x0401), (21516, 0x0401), (21517, 0x0401), (21526, 0x0401),
+    (21527, 0x0401), (21536, 0x0402), (21537, 0x0401), (21596, 0x0401), (21606, 0x0401), (21616, 0x0401), (21626, 0x0401), (21636, 0x0401),
+    (21696, 0x0401), (21706, 0x0401), (21716, 0x0401), (21726, 0x0401), (21806, 0x0401), (21816, 0x0401), (21826, 0x0401), (21866, 0x0401),
+    (21867, 0x0401), (21876, 0x0402), (21877, 0x0401), (21886, 0x0403), (21887, 0x0402), (21896, 0x0403), (21897, 0x0403), (21906, 0x0403),
+    (21907, 0x0403), (21966, 0x0401), (21976, 0x0401), (21986, 0x0401), (21987, 0x0401), (21996, 0x0401), (21997, 0x0401), (22006, 0x0401),
+    (22007, 0x0401), (22016, 0x0402), (22017, 0x0401), (22018, 0x0401), (22026, 0x0402), (22027, 0x0401), (22028, 0x0401), (22036, 0x0403),
+    (22037, 0x0401), (22038, 0x0401), (22046, 0x0403), (22047, 0x0402), (22048, 0x0401), (22066, 0x0401), (22076, 0x0401), (22086, 0x0401),
+    (22096, 0x0401), (22106, 0x0401), (22107, 0x0401), (22116, 0x0401), (22117, 0x0401), (22126, 0x0401), (22127, 0x0401), (22136, 0x0402),
+    (22137, 0x0401), (22166, 0x0401), (22176, 0x0401), (22186, 0x0401), (22196, 0x0401), (22206, 0x0401), (22216, 0x0401), (22217, 0x0401),
+    (22226, 0x0401), (22227, 0x0401), (22236, 0x0402), (22237, 0x0401), (22286, 0x0401), (22296, 0x0401), (22306, 0x0401), (22316, 0x0401),
+    (22326, 0x0401), (22327, 0x0401), (22336, 0x0402), (22396, 0x0401), (22406, 0x0401), (22416, 0x0401), (22426, 0x0401), (22496, 0x0401),
+    (22506, 0x0401), (22516, 0x0401), (22526, 0x0401), (22596, 0x0401), (22606, 0x0401), (22666, 0x0401), (22667, 0x0401), (22676, 0x0401),
+    (22677, 0x0401), (22686, 0x0401), (22687, 0x0401), (22696, 0x0402), (22697, 0x0401), (22766, 0x0401), (22776, 0x0401), (22786, 0x0401),
+    (22796, 0x0401), (22806, 0x0401), (22807, 0x0401), (22816, 0x0401), (22817, 0x0401), (22826, 0x0401), (22827, 0x0401), (22836, 0x0401),
+    (22837, 0x0401), (22876, 0x0401), (22886, 0x0401), (22896, 0x0401), (22906, 0x0401), (22916, 0x0401), (22917, 0x0401), (22926, 0x0401),
+    (22927, 0x0401), (22936, 0x0402), (22937, 0x0401), (22976, 0x0401), (22986, 0x0401), (22996, 0x0401), (23006, 0x0401), (23016, 0x0401),
+    (23026, 0x0401), (23027, 0x0401), (23036, 0x0402), (23086, 0x0401), (23096, 0x0401), (23106, 0x0401), (23116, 0x0401), (23126, 0x0401),
+    (23196, 0x0401), (23206, 0x0401), (23216, 0x0401), (23226, 0x0401), (23286, 0x0401), (23296, 0x0401), (23306, 0x0401), (23396, 0x0401),
+    (23406, 0x0401), (23466, 0x0401), (23476, 0x0401), (23477, 0x0401), (23486, 0x0401), (23487, 0x0401), (23576, 0x0401), (23586, 0x0401),
+    (23596, 0x0401), (23606, 0x0401), (23616, 0x0401), (23626, 0x0401), (23627, 0x0401), (23636, 0x0401), (23637, 0x0401), (23676, 0x0401),
+    (23686, 0x0401), (23696, 0x0401), (23706, 0x0401), (23716, 0x0401), (23726, 0x0401), (23736, 0x0401), (23796, 0x0401), (23806, 0x0401),
+    (23816, 0x0401), (23826, 0x0401), (23886, 0x0401), (23896, 0x0401), (23906, 0x0401), (23916, 0x0401), (23926, 0x0401), (23996, 0x0401),
+    (24006, 0x0401), (24016, 0x0401), (24096, 0x0401), (24106, 0x0401), (24266, 0x0401), (24276, 0x0401), (24277, 0x0401), (24376, 0x0401),
+    (24386, 0x0401), (24396, 0x0401), (24406, 0x0401), (24416, 0x0401), (24426, 0x0401), (24496, 0x0401), (24506, 0x0401), (24516, 0x0401),
+    (24526, 0x0401), (24586, 0x0401), (24596, 0x0401), (24606, 0x0401), (24616, 0x0401), (24696, 0x0401), (24706, 0x0401), (24716, 0x0401),
+    (24796, 0x0401), (25066, 0x0401), (25206, 0x0401), (25216, 0x0401), (25226, 0x0401), (25296, 0x0401), (25306, 0x0401), (25316, 0x0401),
+    (25396, 0x0401), (25406, 0x0401), (25416, 0x0401), (25476, 0x0401), (25486, 0x0401), (25496, 0x0401), (25596, 0x0401), (25676, 0x0401),
+    (25766, 0x0401), (26113, 0x0401), (26241, 0x0401), (26242, 0x0402), (26243, 0x0402), (26369, 0x0401), (26370, 0x0402), (26371, 0x0402),
+    (26372, 0x0403), (26373, 0x0403), (26374, 0x0403), (26375, 0x0403), (26497, 0x0401), (26498, 0x0402), (26499, 0x0402), (26500, 0x0402),
+    (26501, 0x0402), (26502, 0x0403), (26503, 0x0402), (26504, 0x0403), (26505, 0x0403), (26506, 0x0403), (26507, 0x0403), (26508, 0x0403),
+    (26509, 0x0404), (26510, 0x0403), (26625, 0x0401), (26626, 0x0401), (26628, 0x0402), (26632, 0x0403), (27137, 0x0401), (27265, 0x0401),
+    (27266, 0x0402), (27267, 0x0402), (27393, 0x0401), (27394, 0x0402), (27395, 0x0402), (27396, 0x0403), (27397, 0x0403), (27398, 0x0403),
+    (27399, 0x0403), (27521, 0x0401), (27522, 0x0402), (27523, 0x0402), (27524, 0x0403), (27525, 0x0403), (27526, 0x0402), (27527, 0x0402),
+    (27528, 0x0403), (27529, 0x0404), (27530, 0x0403), (27531, 0x0404), (27532, 0x0404), (27533, 0x0403), (27534, 0x0404), (27649, 0x0401),
+    (27650, 0x0401), (27652, 0x0402), (28161, 0x0402), (28289, 0x0401), (28290, 0x0402), (28291, 0x0402), (28417, 0x0401), (28418, 0x0401),
+    (28419, 0x0402), (28420, 0x0402), (28421, 0x0402), (28422, 0x0403), (28423, 0x0403), (28545, 0x0401), (28546, 0x0401), (28547, 0x0402),
+    (28548, 0x0402), (28549, 0x0403), (28550, 0x0402), (28551, 0x0403), (28552, 0x0403), (28553, 0x0403), (28554, 0x0403), (28555, 0x0403),
+    (28556, 0x0403), (28557, 0x0403), (28558, 0x0403), (28673, 0x0401), (28674, 0x0401), (28676, 0x0402), (29185, 0x0403), (29313, 0x0401),
+    (29314, 0x0402), (29315, 0x0402), (29441, 0x0401), (29442, 0x0401), (29443, 0x0402), (29444, 0x0402), (29445, 0x0403), (29446, 0x0403),
+    (29447, 0x0403), (29569, 0x0401), (29570, 0x0401), (29571, 0x0402), (29572, 0x0403), (29573, 0x0402), (29574, 0x0403), (29575, 0x0403),
+    (29576, 0x0403), (29577, 0x0403), (29578, 0x0403), (29579, 0x0403), (29580, 0x0403), (29581, 0x0403), (29582, 0x0403), (29697, 0x0401),
+    (29698, 0x0401), (29700, 0x0402), (30209, 0x0404), (30337, 0x0401), (30338, 0x0402), (30339, 0x0402), (30465, 0x0401), (30466, 0x0401),
+    (30467, 0x0402), (30468, 0x0402), (30469, 0x0402), (30470, 0x0403), (30471, 0x0403), (30593, 0x0401), (30594, 0x0402), (30595, 0x0402),
+    (30596, 0x0403), (30597, 0x0402), (30598, 0x0403), (30599, 0x0403), (30600, 0x0403), (30601, 0x0403), (30602, 0x0403), (30603, 0x0403),
+    (30604, 0x0403), (30605, 0x0404), (30606, 0x0404), (30721, 0x0401), (30722, 0x0401), (31233, 0x0404), (31361, 0x0402), (31362, 0x0404),
+    (31363, 0x0402), (31489, 0x0401), (31490, 0x0401), (31491, 0x0402), (31492, 0x0402), (31493, 0x0402), (31494, 0x0403), (31495, 0x0403),
+    (31617, 0x0401), (31618, 0x0401), (31619, 0x0402), (31620, 0x0402), (31621, 0x0403), (31622, 0x0403), (31623, 0x0403), (31624, 0x0403),
+    (31625, 0x0403), (31626, 0x0403), (31627, 0x0403), (31628, 0x0403), (31629, 0x0403), (31630, 0x0404), (31631, 0x0402), (31745, 0x0401),
+    (31746, 0x0401), (32257, 0x0404), (32385, 0x0404), (32386, 0x0404), (32387, 0x0403), (32513, 0x0401), (32514, 0x0401), (32515, 0x0402),
+    (32516, 0x0402), (32517, 0x0402), (32518, 0x0403), (32519, 0x0403), (32641, 0x0401), (32642, 0x0402), (32643, 0x0402), (32644, 0x0402),
+    (32645, 0x0403), (32646, 0x0403), (32647, 0x0402), (32648, 0x0403), (32649, 0x0403), (32650, 0x0403), (32651, 0x0403), (32652, 0x0403),
+    (32653, 0x0404), (32654, 0x0403), (32769, 0x0401), (32770, 0x0401), (32772, 0x0401), (33281, 0x0404), (33409, 0x0304), (33410, 0x0304),
+    (33411, 0x0404), (33537, 0x0401), (33538, 0x0401), (33539, 0x0402), (33540, 0x0402), (33541, 0x0402), (33542, 0x0403), (33543, 0x0403),
+    (33665, 0x0401), (33666, 0x0402), (33667, 0x0402), (33668, 0x0402), (33669, 0x0402), (33670, 0x0403), (33671, 0x0403), (33672, 0x0403),
+    (33673, 0x0403), (33674, 0x0403), (33675, 0x0403), (33676, 0x0403), (33677, 0x0404), (33678, 0x0403), (33793, 0x0401), (33794, 0x0401),
+    (33796, 0x0402), (34305, 0x0404), (34433, 0x0304), (34434, 0x0304), (34435, 0x0304), (34561, 0x0401), (34562, 0x0402), (34563, 0x0402),
+    (34564, 0x0403), (34565, 0x0402), (34566, 0x0402), (34567, 0x0403), (34689, 0x0401), (34690, 0x0401), (34691, 0x0402), (34692, 0x0402),
+    (34693, 0x0403), (34694, 0x0402), (34695, 0x0402), (34696, 0x0403), (34697, 0x0403), (34698, 0x0404), (34699, 0x0403), (34700, 0x0403),
+    (34701, 0x0403), (34702, 0x0404), (34703, 0x0403), (34817, 0x0401), (34818, 0x0401), (34820, 0x0401), (34824, 0x0402), (35329, 0x0404),
+    (35457, 0x0304), (35458, 0x0404), (35459, 0x0304), (35585, 0x0401), (35586, 0x0402), (35587, 0x0402), (35588, 0x0404), (35589, 0x0403),
+    (35590, 0x0402), (35591, 0x0402), (35713, 0x0401), (35714, 0x0401), (35715, 0x0402), (35716, 0x0402), (35717, 0x0403), (35718, 0x0403),
+    (35719, 0x0403), (35720, 0x0403), (35721, 0x0403), (35722, 0x0403), (35723, 0x0403), (35724, 0x0403), (35725, 0x0403), (35726, 0x0403),
+    (35727, 0x0404), (35841, 0x0401), (35842, 0x0401), (35844, 0x0401), (35848, 0x0403), (36353, 0x0404), (36481, 0x0304), (36482, 0x0404),
+    (36483, 0x0404), (36609, 0x0401), (36610, 0x0404), (36611, 0x0401), (36612, 0x0304), (36613, 0x0403), (36614, 0x0403), (36615, 0x0403),
+    (36737, 0x0401), (36738, 0x0401), (36739, 0x0402), (36740, 0x0402), (36741, 0x0402), (36742, 0x0402), (36743, 0x0403), (36744, 0x0403),
+    (36745, 0x0404), (36746, 0x0403), (36747, 0x0403), (36748, 0x0403), (36749, 0x0403), (36750, 0x0403), (36751, 0x0403), (36865, 0x0401),
+    (36866, 0x0401), (36868, 0x0402), (36872, 0x0403), (37377, 0x0404), (37505, 0x0304), (37506, 0x0404), (37507, 0x0304), (37633, 0x0402),
+    (37634, 0x0304), (37635, 0x0402), (37636, 0x0304), (37637, 0x0404), (37638, 0x0403), (37639, 0x0403), (37761, 0x0401), (37762, 0x0401),
+    (37763, 0x0402), (37764, 0x0402), (37765, 0x0402), (37766, 0x0402), (37767, 0x0403), (37768, 0x0403), (37769, 0x0403), (37770, 0x0403),
+    (37771, 0x0403), (37772, 0x0403), (37773, 0x0403), (37774, 0x0404), (37775, 0x0404), (37889, 0x0401), (37890, 0x0401), (37892, 0x0402),
+    (37896, 0x0403), (38401, 0x0403), (38529, 0x0304), (38530, 0x0404), (38531, 0x0304), (38657, 0x0404), (38658, 0x0304), (38659, 0x0402),
+    (38660, 0x0304), (38661, 0x0304), (38662, 0x0403), (38663, 0x0402), (38785, 0x0401), (38786, 0x0401), (38787, 0x0402), (38788, 0x0402),
+    (38789, 0x0402), (38790, 0x0403), (38791, 0x0403), (38792, 0x0403), (38793, 0x0403), (38794, 0x0403), (38795, 0x0403), (38796, 0x0404),
+    (38797, 0x0403), (38798, 0x0404), (38799, 0x0403), (38913, 0x0401), (38914, 0x0401), (38916, 0x0402), (38920, 0x0403), (39425, 0x0404),
+    (39553, 0x0404), (39554, 0x0404), (39555, 0x0404), (39681, 0x0304), (39682, 0x0304), (39683, 0x0403), (39684, 0x0304), (39685, 0x0304),
+    (39686, 0x0404), (39687, 0x0403), (39809, 0x0401), (39810, 0x0401), (39811, 0x0402), (39812, 0x0402), (39813, 0x0402), (39814, 0x0403),
+    (39815, 0x0402), (39816, 0x0403), (39817, 0x0403), (39818, 0x0403), (39819, 0x0403), (39820, 0x0403), (39821, 0x0403), (39822, 0x0403),
+    (39823, 0x0403), (39937, 0x0401), (39938, 0x0401), (39940, 0x0401), (39944, 0x0403), (39952, 0x0404), (40449, 0x0403), (40577, 0x0404),
+    (40578, 0x0404), (40579, 0x0404), (40705, 0x0204), (40706, 0x0304), (40707, 0x0404), (40708, 0x0404), (40709, 0x0304), (40710, 0x0304),
+    (40711, 0x0403), (40833, 0x0401), (40834, 0x0401), (40835, 0x0402), (40836, 0x0402), (40837, 0x0402), (40838, 0x0403), (40839, 0x0403),
+    (40840, 0x0402), (40841, 0x0403), (40842, 0x0403), (40843, 0x0403), (40844, 0x0403), (40845, 0x0403), (40846, 0x0404), (40847, 0x0403),
+    (40961, 0x0401), (40962, 0x0401), (40964, 0x0401), (40968, 0x0403), (40976, 0x0403), (41473, 0x0403), (41601, 0x0404), (41602, 0x0403),
+    (41603, 0x0404), (41729, 0x0204), (41730, 0x0304), (41731, 0x0304), (41732, 0x0404), (41733, 0x0304), (41734, 0x0304), (41735, 0x0304),
+    (41857, 0x0401), (41858, 0x0401), (41859, 0x0402), (41860, 0x0402), (41861, 0x0402), (41862, 0x0403), (41863, 0x0402), (41864, 0x0403),
+    (41865, 0x0403), (41866, 0x0403), (41867, 0x0403), (41868, 0x0403), (41869, 0x0403), (41870, 0x0404), (41871, 0x0403), (41985, 0x0401),
+    (41986, 0x0401), (41988, 0x0402), (41992, 0x0403), (41993, 0x0402), (42000, 0x0304), (42497, 0x0403), (42625, 0x0403), (42626, 0x0403),
+    (42627, 0x0404), (42753, 0x0204), (42754, 0x0304), (42755, 0x0204), (42756, 0x0404), (42757, 0x0404), (42758, 0x0304), (42759, 0x0304),
+    (42881, 0x0401), (42882, 0x0401), (42883, 0x0402), (42884, 0x0402), (42885, 0x0402), (42886, 0x0403), (42887, 0x0402), (42888, 0x0403),
+    (42889, 0x0403), (42890, 0x0403), (42891, 0x0403), (42892, 0x0403), (42893, 0x0404), (42894, 0x0402), (42895, 0x0404), (43009, 0x0401),
+    (43010, 0x0401), (43012, 0x0402), (43016, 0x0403), (43024, 0x0403), (43521, 0x0403), (43649, 0x0403), (43650, 0x0403), (43651, 0x0404),
+    (43777, 0x0204), (43778, 0x0404), (43779, 0x0304), (43780, 0x0404), (43781, 0x0304), (43782, 0x0304), (43783, 0x0304), (43905, 0x0401),
+    (43906, 0x0401), (43907, 0x0401), (43908, 0x0403), (43909, 0x0402), (43910, 0x0402), (43911, 0x0403), (43912, 0x0304), (43913, 0x0403),
+    (43914, 0x0403), (43915, 0x0403), (43916, 0x0403), (43917, 0x0403), (43918, 0x0403), (43919, 0x0403), (44033, 0x0401), (44034, 0x0401),
+    (44036, 0x0402), (44040, 0x0403), (44041, 0x0404), (44048, 0x0403), (44545, 0x0404), (44673, 0x0404), (44674, 0x0403), (44675, 0x0404),
+    (44801, 0x0204), (44802, 0x0404), (44803, 0x0204), (44804, 0x0404), (44805, 0x0404), (44806, 0x0404), (44807, 0x0304), (44929, 0x0401),
+    (44930, 0x0402), (44931, 0x0401), (44932, 0x0304), (44933, 0x0402), (44934, 0x0402), (44935, 0x0402), (44936, 0x0304), (44937, 0x0403),
+    (44938, 0x0403), (44939, 0x0403), (44940, 0x0403), (44941, 0x0403), (44942, 0x0403), (44943, 0x0403), (45057, 0x0401), (45058, 0x0401),
+    (45060, 0x0402), (45064, 0x0402), (45072, 0x0403), (45569, 0x0403), (45697, 0x0403), (45698, 0x0404), (45699, 0x0404), (45825, 0x0204),
+    (45826, 0x0304), (45827, 0x0304), (45828, 0x0404), (45829, 0x0404), (45830, 0x0404), (45831, 0x0304), (45953, 0x0401), (45954, 0x0403),
+    (45955, 0x0401), (45956, 0x0204), (45957, 0x0402), (45958, 0x0402), (45959, 0x0403), (45960, 0x0304), (45961, 0x0304), (45962, 0x0403),
+    (45963, 0x0403), (45964, 0x0403), (45965, 0x0403), (45966, 0x0403), (45967, 0x0403), (46081, 0x0401), (46082, 0x0401), (46084, 0x0402),
+    (46088, 0x0403), (46089, 0x0403), (46096, 0x0403), (46097, 0x0404), (46593, 0x0403), (46721, 0x0403), (46722, 0x0404), (46723, 0x0403),
+    (46849, 0x0304), (46850, 0x0404), (46851, 0x0304), (46852, 0x0403), (46853, 0x0404), (46854, 0x0404), (46855, 0x0404), (46977, 0x0401),
+    (46978, 0x0304), (46979, 0x0401), (46980, 0x0204), (46981, 0x0402), (46982, 0x0402), (46983, 0x0402), (46984, 0x0304), (46985, 0x0304),
+    (46986, 0x0404), (46987, 0x0403), (46988, 0x0403), (46989, 0x0403), (46990, 0x0403), (46991, 0x0403), (47105, 0x0401), (47106, 0x0401),
+    (47108, 0x0402), (47112, 0x0402), (47113, 0x0402), (47120, 0x0404), (47121, 0x0404), (47122, 0x0404), (47617, 0x0404), (47745, 0x0403),
+    (47746, 0x0404), (47747, 0x0403), (47873, 0x0404), (47874, 0x0404), (47875, 0x0404), (47876, 0x0404), (47877, 0x0404), (47878, 0x0404),
+    (47879, 0x0404), (48001, 0x0401), (48002, 0x0204), (48003, 0x0401), (48004, 0x0304), (48005, 0x0403), (48006, 0x0402), (48007, 0x0403),
+    (48008, 0x0304), (48009, 0x0304), (48010, 0x0304), (48011, 0x0403), (48012, 0x0403), (48013, 0x0403), (48014, 0x0403), (48015, 0x0403),
+    (48129, 0x0401), (48130, 0x0401), (48132, 0x0402), (48136, 0x0403), (48137, 0x0403), (48144, 0x0404), (48145, 0x0403), (48641, 0x0403),
+    (48769, 0x0403), (48770, 0x0404), (48771, 0x0404), (48897, 0x0404), (48898, 0x0404), (48899, 0x0404), (48900, 0x0403), (48901, 0x0404),
+    (48902, 0x0404), (48903, 0x0404), (49025, 0x0402), (49026, 0x0204), (49027, 0x0401), (49028, 0x0304), (49029, 0x0304), (49030, 0x0402),
+    (49031, 0x0402), (49032, 0x0404), (49033, 0x0304), (49034, 0x0304), (49035, 0x0404), (49036, 0x0403), (49037, 0x0403), (49038, 0x0403),
+    (49039, 0x0404), (49153, 0x0401), (49154, 0x0401), (49156, 0x0402), (49160, 0x0402), (49161, 0x0402), (49168, 0x0403), (49169, 0x0404),
+    (49170, 0x0404), (49665, 0x0404), (49793, 0x0404), (49794, 0x0404), (49795, 0x0404), (49921, 0x0404), (49922, 0x0404), (49923, 0x0404),
+    (49924, 0x0403), (49925, 0x0404), (49926, 0x0304), (49927, 0x0304), (50049, 0x0403), (50050, 0x0204), (50051, 0x0401), (50052, 0x0304),
+    (50053, 0x0204), (50054, 0x0402), (50055, 0x0402), (50056, 0x0404), (50057, 0x0404), (50058, 0x0304), (50059, 0x0304), (50060, 0x0403),
+    (50061, 0x0403), (50062, 0x0404), (50063, 0x0403), (50177, 0x0401), (50178, 0x0401), (50180, 0x0402), (50181, 0x0402), (50184, 0x0402),
+    (50185, 0x0403), (50192, 0x0403), (50193, 0x0403), (50194, 0x0404), (50689, 0x0404), (50817, 0x0403), (50818, 0x0403), (50819, 0x0404),
+    (50945, 0x0404), (50946, 0x0403), (50947, 0x0404), (50948, 0x0403), (50949, 0x0404), (50950, 0x0404), (50951, 0x0304), (51073, 0x0304),
+    (51074, 0x0204), (51075, 0x0401), (51076, 0x0404), (51077, 0x0204), (51078, 0x0402), (51079, 0x0402), (51080, 0x0404), (51081, 0x0304),
+    (51082, 0x0304), (51083, 0x0304), (51084, 0x0403), (51085, 0x0403), (51086, 0x0403), (51087, 0x0403), (51201, 0x0401), (51202, 0x0401),
+    (51204, 0x0402), (51205, 0x0402), (51208, 0x0403), (51209, 0x0402), (51216, 0x0403), (51217, 0x0403), (51218, 0x0402), (51713, 0x0404),
+    (51841, 0x0404), (51842, 0x0403), (51843, 0x0404), (51969, 0x0404), (51970, 0x0403), (51971, 0x0404), (51972, 0x0404), (51973, 0x0403),
+    (51974, 0x0404), (51975, 0x0404), (52097, 0x0204), (52098, 0x0204), (52099, 0x0401), (52100, 0x0404), (52101, 0x0204), (52102, 0x0403),
+    (52103, 0x0402), (52104, 0x0404), (52105, 0x0404), (52106, 0x0304), (52107, 0x0304), (52108, 0x0304), (52109, 0x0403), (52110, 0x0403),
+    (52111, 0x0403), (52225, 0x0401), (52226, 0x0401), (52228, 0x0402), (52229, 0x0402), (52232, 0x0403), (52233, 0x0403), (52240, 0x0403),
+    (52241, 0x0402), (52242, 0x0403), (52243, 0x0403), (52737, 0x0404), (52865, 0x0403), (52866, 0x0404), (52867, 0x0404), (52993, 0x0403),
+    (52994, 0x0404), (52995, 0x0404), (52996, 0x0404), (52997, 0x0404), (52998, 0x0404), (52999, 0x0404), (53121, 0x0204), (53122, 0x0304),
+    (53123, 0x0402), (53124, 0x0404), (53125, 0x0304), (53126, 0x0304), (53127, 0x0402), (53128, 0x0404), (53129, 0x0404), (53130, 0x0404),
+    (53131, 0x0404), (53132, 0x0304), (53133, 0x0403), (53134, 0x0403), (53135, 0x0404), (53249, 0x0401), (53250, 0x0401), (53252, 0x0402),
+    (53253, 0x0402), (53256, 0x0402), (53257, 0x0403), (53258, 0x0402), (53264, 0x0403), (53265, 0x0403), (53266, 0x0404), (53761, 0x0404),
+    (53889, 0x0404), (53890, 0x0404), (53891, 0x0304), (54017, 0x0403), (54018, 0x0403), (54019, 0x0403), (54020, 0x0404), (54021, 0x0404),
+    (54022, 0x0404), (54023, 0x0404), (54145, 0x0104), (54146, 0x0304), (54147, 0x0403), (54148, 0x0404), (54149, 0x0304), (54150, 0x0204),
+    (54151, 0x0402), (54152, 0x0404), (54153, 0x0404), (54154, 0x0404), (54155, 0x0404), (54156, 0x0304), (54157, 0x0304), (54158, 0x0403),
+    (54159, 0x0403), (54273, 0x0401), (54274, 0x0401), (54276, 0x0402), (54277, 0x0402), (54280, 0x0403), (54281, 0x0402), (54282, 0x0402),
+    (54288, 0x0404), (54289, 0x0403), (54290, 0x0304), (54291, 0x0403), (54785, 0x0404), (54913, 0x0403), (54914, 0x0404), (54915, 0x0403),
+    (55041, 0x0404), (55042, 0x0403), (55043, 0x0404), (55044, 0x0403), (55045, 0x0403), (55046, 0x0404), (55047, 0x0404), (55169, 0x0104),
+    (55170, 0x0304), (55171, 0x0304), (55172, 0x0404), (55173, 0x0304), (55174, 0x0204), (55175, 0x0402), (55176, 0x0403), (55177, 0x0404),
+    (55178, 0x0404), (55179, 0x0404), (55180, 0x0304), (55181, 0x0304), (55182, 0x0404), (55183, 0x0403), (55297, 0x0401), (55298, 0x0401),
+    (55300, 0x0402), (55301, 0x0402), (55304, 0x0403), (55305, 0x0402), (55306, 0x0403), (55312, 0x0404), (55313, 0x0403), (55314, 0x0403),
+    (55315, 0x0404), (55809, 0x0404), (55937, 0x0404), (55938, 0x0404), (55939, 0x0403), (56065, 0x0403), (56066, 0x0404), (56067, 0x0404),
+    (56068, 0x0404), (56069, 0x0404), (56070, 0x0404), (56071, 0x0404), (56193, 0x0104), (56194, 0x0404), (56195, 0x0204), (56196, 0x0404),
+    (56197, 0x0404), (56198, 0x0204), (56199, 0x0403), (56200, 0x0403), (56201, 0x0404), (56202, 0x0404), (56203, 0x0404), (56204, 0x0304),
+    (56205, 0x0404), (56206, 0x0404), (56207, 0x0403), (56321, 0x0401), (56322, 0x0401), (56324, 0x0402), (56325, 0x0402), (56328, 0x0402),
+    (56329, 0x0402), (56330, 0x0403), (56336, 0x0404), (56337, 0x0403), (56338, 0x0404), (56339, 0x0403), (56833, 0x0404), (56961, 0x0404),
+    (56962, 0x0404), (56963, 0x0404), (57089, 0x0403), (57090, 0x0404), (57091, 0x0403), (57092, 0x0404), (57093, 0x0404), (57094, 0x0404),
+    (57095, 0x0404), (57217, 0x0104), (57218, 0x0404), (57219, 0x0204), (57220, 0x0404), (57221, 0x0404), (57222, 0x0304), (57223, 0x0304),
+    (57224, 0x0404), (57225, 0x0404), (57226, 0x0404), (57227, 0x0404), (57228, 0x0404), (57229, 0x0304), (57230, 0x0304), (57231, 0x0403),
+    (57345, 0x0401), (57346, 0x0401), (57348, 0x0401), (57349, 0x0402), (57352, 0x0402), (57353, 0x0403), (57354, 0x0403), (57360, 0x0403),
+    (57361, 0x0404), (57362, 0x0403), (57363, 0x0402), (57857, 0x0403), (57985, 0x0403), (57986, 0x0403), (57987, 0x0404), (58113, 0x0404),
+    (58114, 0x0404), (58115, 0x0404), (58116, 0x0404), (58117, 0x0404), (58118, 0x0404), (58119, 0x0403), (58241, 0x0104), (58242, 0x0404),
+    (58243, 0x0204), (58244, 0x0404), (58245, 0x0304), (58246, 0x0404), (58247, 0x0204), (58248, 0x0404), (58249, 0x0403), (58250, 0x0404),
+    (58251, 0x0404), (58252, 0x0404), (58253, 0x0204), (58254, 0x0304), (58255, 0x0304), (58369, 0x0401), (58370, 0x0401), (58372, 0x0401),
+    (58373, 0x0402), (58376, 0x0402), (58377, 0x0402), (58378, 0x0403), (58384, 0x0402), (58385, 0x0403), (58386, 0x0403), (58387, 0x0404),
+    (58388, 0x0404), (58881, 0x0404), (59009, 0x0403), (59010, 0x0404), (59011, 0x0404), (59137, 0x0403), (59138, 0x0404), (59139, 0x0403),
+    (59140, 0x0403), (59141, 0x0404), (59142, 0x0403), (59143, 0x0404), (59265, 0x0204), (59266, 0x0404), (59267, 0x0204), (59268, 0x0404),
+    (59269, 0x0404), (59270, 0x0404), (59271, 0x0304), (59272, 0x0404), (59273, 0x0404), (59274, 0x0404), (59275, 0x0403), (59276, 0x0404),
+    (59277, 0x0404), (59278, 0x0304), (59279, 0x0304), (59393, 0x0401), (59394, 0x0401), (59396, 0x0401), (59397, 0x0402), (59400, 0x0402),
+    (59401, 0x0402), (59402, 0x0403), (59408, 0x0404), (59409, 0x0403), (59410, 0x0403), (59411, 0x0404), (59412, 0x0404), (59905, 0x0403),
+    (60033, 0x0403), (60034, 0x0404), (60035, 0x0304), (60161, 0x0403), (60162, 0x0404), (60163, 0x0403), (60164, 0x0403), (60165, 0x0403),
+    (60166, 0x0404), (60167, 0x0403), (60289, 0x0204), (60290, 0x0404), (60291, 0x0204), (60292, 0x0403), (60293, 0x0403), (60294, 0x0304),
+    (60295, 0x0204), (60296, 0x0404), (60297, 0x0403), (60298, 0x0404), (60299, 0x0403), (60300, 0x0304), (60301, 0x0304), (60302, 0x0304),
+    (60303, 0x0304), (60417, 0x0401), (60418, 0x0401), (60420, 0x0401), (60421, 0x0402), (60424, 0x0403), (60425, 0x0403), (60426, 0x0402),
+    (60432, 0x0304), (60433, 0x0402), (60434, 0x0403), (60435, 0x0403), (60436, 0x0403), (60929, 0x0404), (61057, 0x0404), (61058, 0x0403),
+    (61059, 0x0404), (61185, 0x0404), (61186, 0x0404), (61187, 0x0403), (61188, 0x0403), (61189, 0x0404), (61190, 0x0404), (61191, 0x0404),
+    (61313, 0x0304), (61314, 0x0404), (61315, 0x0304), (61316, 0x0404), (61317, 0x0404), (61318, 0x0404), (61319, 0x0304), (61320, 0x0404),
+    (61321, 0x0403), (61322, 0x0404), (61323, 0x0403), (61324, 0x0403), (61325, 0x0404), (61326, 0x0304), (61327, 0x0404), (61441, 0x0401),
+    (61442, 0x0401), (61444, 0x0402), (61445, 0x0402), (61448, 0x0304), (61449, 0x0402), (61450, 0x0403), (61456, 0x0304), (61457, 0x0403),
+    (61458, 0x0403), (61459, 0x0403), (61460, 0x0403), (61953, 0x0404), (62081, 0x0404), (62082, 0x0404), (62083, 0x0404), (62209, 0x0404),
+    (62210, 0x0404), (62211, 0x0403), (62212, 0x0404), (62213, 0x0404), (62214, 0x0403), (62215, 0x0403), (62337, 0x0404), (62338, 0x0404),
+    (62339, 0x0304), (62340, 0x0304), (62341, 0x0404), (62342, 0x0404), (62343, 0x0304), (62344, 0x0304), (62345, 0x0403), (62346, 0x0304),
+    (62347, 0x0403), (62348, 0x0404), (62349, 0x0404), (62350, 0x0304), (62351, 0x0404), (62465, 0x0401), (62466, 0x0401), (62468, 0x0403),
+    (62469, 0x0402), (62472, 0x0204), (62473, 0x0402), (62474, 0x0402), (62475, 0x0403), (62480, 0x0204), (62481, 0x0304), (62482, 0x0403),
+    (62483, 0x0404), (62484, 0x0404), (62485, 0x0403), (62977, 0x0404), (63105, 0x0404), (63106, 0x0404), (63107, 0x0404), (63233, 0x0404),
+    (63234, 0x0404), (63235, 0x0404), (63236, 0x0403), (63237, 0x0404), (63238, 0x0403), (63239, 0x0404), (63361, 0x0404), (63362, 0x0403),
+    (63363, 0x0304), (63364, 0x0403), (63365, 0x0404), (63366, 0x0404), (63367, 0x0304), (63368, 0x0403), (63369, 0x0404), (63370, 0x0403),
+    (63371, 0x0304), (63372, 0x0404), (63373, 0x0404), (63374, 0x0304), (63375, 0x0304), (63489, 0x0401), (63490, 0x0401), (63492, 0x0304),
+    (63493, 0x0402), (63496, 0x0204), (63497, 0x0402), (63498, 0x0402), (63499, 0x0402), (63504, 0x0304), (63505, 0x0204), (63506, 0x0403),
+    (63507, 0x0403), (63508, 0x0403), (63509, 0x0404), (64001, 0x0403), (64129, 0x0403), (64130, 0x0402), (64131, 0x0404), (64257, 0x0403),
+    (64258, 0x0403), (64259, 0x0304), (64260, 0x0404), (64261, 0x0404), (64262, 0x0304), (64263, 0x0404), (64385, 0x0304), (64386, 0x0404),
+    (64387, 0x0304), (64388, 0x0404), (64389, 0x0403), (64390, 0x0403), (64391, 0x0304), (64392, 0x0404), (64393, 0x0404), (64394, 0x0404),
+    (64395, 0x0304), (64396, 0x0304), (64397, 0x0404), (64398, 0x0403), (64399, 0x0304), (64513, 0x0401), (64514, 0x0401), (64515, 0x0401),
+    (64516, 0x0204), (64517, 0x0401), (64520, 0x0304), (64521, 0x0404), (64522, 0x0402), (64523, 0x0403), (64528, 0x0304), (64529, 0x0304),
+    (64530, 0x0404), (64531, 0x0403), (64532, 0x0403), (64533, 0x0403), (65025, 0x0403), (65153, 0x0403), (65154, 0x0404), (65155, 0x0404),
+    (65281, 0x0404), (65282, 0x0404), (65283, 0x0404), (65284, 0x0403), (65285, 0x0404), (65286, 0x0404), (65287, 0x0404), (65409, 0x0404),
+    (65410, 0x0404), (65411, 0x0304), (65412, 0x0403), (65413, 0x0404), (65414, 0x0403), (65415, 0x0304), (65416, 0x0404), (65417, 0x0404),
+    (65418, 0x0304), (65419, 0x0403), (65420, 0x0404), (65422, 0x0304), (65423, 0x0403), (65537, 0x0401), (65538, 0x0402), (65540, 0x0104),
+    (65541, 0x0401), (65544, 0x0304), (65545, 0x0304), (65546, 0x0402), (65547, 0x0402), (65552, 0x0404), (65553, 0x0304), (65554, 0x0304),
+    (65555, 0x0404), (65556, 0x0402), (65557, 0x0403), (65558, 0x0403), (66049, 0x0404), (66177, 0x0403), (66178, 0x0404), (66179, 0x0404),
+    (66305, 0x0404), (66306, 0x0404), (66307, 0x0404), (66308, 0x0402), (66309, 0x0404), (66310, 0x0403), (66311, 0x0403), (66433, 0x0404),
+    (66434, 0x0404), (66435, 0x0404), (66436, 0x0404), (66437, 0x0403), (66438, 0x0404), (66439, 0x0304), (66442, 0x0404), (66561, 0x0401),
+    (66562, 0x0403), (66563, 0x0401), (66564, 0x0204), (66565, 0x0401), (66566, 0x0401), (66568, 0x0304), (66569, 0x0204), (66570, 0x0402),
+    (66571, 0x0403), (66576, 0x0404), (66577, 0x0304), (66578, 0x0404), (66579, 0x0304), (66580, 0x0403), (66581, 0x0304), (66582, 0x0404),
+    (67073, 0x0403), (67201, 0x0404), (67202, 0x0304), (67203, 0x0403), (67329, 0x0404), (67330, 0x0404), (67331, 0x0403), (67332, 0x0403),
+    (67334, 0x0403), (67335, 0x0304), (67457, 0x0404), (67458, 0x0403), (67459, 0x0403), (67460, 0x0403), (67461, 0x0403), (67462, 0x0404),
+    (67463, 0x0404), (67585, 0x0401), (67586, 0x0304), (67587, 0x0401), (67588, 0x0204), (67589, 0x0401), (67590, 0x0401), (67592, 0x0404),
+    (67593, 0x0204), (67594, 0x0402), (67595, 0x0402), (67600, 0x0204), (67601, 0x0304), (67602, 0x0304), (67603, 0x0304), (67604, 0x0404),
+    (67605, 0x0403), (67606, 0x0402), (68097, 0x0404), (68225, 0x0404), (68226, 0x0404), (68227, 0x0404), (68353, 0x0404), (68354, 0x0404),
+    (68355, 0x0404), (68356, 0x0404), (68481, 0x0404), (68482, 0x0403), (68483, 0x0403), (68484, 0x0404), (68485, 0x0404), (68486, 0x0304),
+    (68487, 0x0404), (68609, 0x0401), (68610, 0x0204), (68611, 0x0401), (68612, 0x0204), (68613, 0x0401), (68614, 0x0401), (68616, 0x0304),
+    (68617, 0x0204), (68618, 0x0403), (68619, 0x0402), (68620, 0x0402), (68626, 0x0404), (68627, 0x0304), (68628, 0x0304), (68629, 0x0403),
+    (68630, 0x0402), (69121, 0x0404), (69249, 0x0404), (69250, 0x0403), (69251, 0x0304), (69377, 0x0403), (69378, 0x0403), (69379, 0x0304),
+    (69505, 0x0404), (69506, 0x0404), (69507, 0x0304), (69508, 0x0404), (69509, 0x0404), (69510, 0x0404), (69511, 0x0403), (69633, 0x0401),
+    (69634, 0x0104), (69635, 0x0401), (69636, 0x0204), (69637, 0x0402), (69638, 0x0401), (69640, 0x0404), (69641, 0x0204), (69642, 0x0404),
+    (69643, 0x0402), (69644, 0x0403), (69651, 0x0204), (69652, 0x0204), (69653, 0x0403), (69654, 0x0403), (69655, 0x0402), (70145, 0x0404),
+    (70273, 0x0404), (70401, 0x0403), (70402, 0x0404), (70529, 0x0404), (70530, 0x0404), (70531, 0x0304), (70532, 0x0404), (70533, 0x0404),
+    (70535, 0x0404), (70657, 0x0401), (70658, 0x0104), (70659, 0x0401), (70660, 0x0304), (70661, 0x0403), (70662, 0x0402), (70665, 0x0304),
+    (70666, 0x0204), (70667, 0x0402), (70668, 0x0403), (70676, 0x0304), (70677, 0x0404), (70678, 0x0403), (70679, 0x0402), (71297, 0x0304),
+    (71425, 0x0404), (71426, 0x0403), (71427, 0x0204), (71553, 0x0404), (71554, 0x0404), (71555, 0x0403), (71681, 0x0401), (71682, 0x0104),
+    (71683, 0x0401), (71684, 0x0304), (71685, 0x0304), (71686, 0x0402), (71689, 0x0304), (71690, 0x0204), (71691, 0x0402), (71692, 0x0402),
+    (71700, 0x0304), (71701, 0x0204), (71702, 0x0403), (71703, 0x0403), (72321, 0x0404), (72449, 0x0403), (72577, 0x0404), (72578, 0x0404),
+    (72579, 0x0403), (72705, 0x0401), (72706, 0x0104), (72707, 0x0401), (72708, 0x0304), (72709, 0x0204), (72710, 0x0402), (72714, 0x0204),
+    (72715, 0x0403), (72716, 0x0402), (72725, 0x0304), (72726, 0x0304), (72727, 0x0403), (72728, 0x0403), (73345, 0x0403), (73473, 0x0404),
+    (73601, 0x0404), (73602, 0x0403), (73603, 0x0404), (73729, 0x0402), (73730, 0x0104), (73731, 0x0401), (73732, 0x0404), (73733, 0x0104),
+    (73734, 0x0402), (73738, 0x0204), (73739, 0x0304), (73740, 0x0402), (73749, 0x0304), (73750, 0x0304), (73751, 0x0403), (73752, 0x0402),
+    (74369, 0x0404), (74497, 0x0404), (74625, 0x0403), (74626, 0x0403), (74753, 0x0402), (74754, 0x0104), (74755, 0x0401), (74756, 0x0204),
+    (74757, 0x0104), (74758, 0x0401), (74762, 0x0404), (74763, 0x0204), (74764, 0x0402), (74774, 0x0404), (74775, 0x0304), (74776, 0x0403),
+    (75521, 0x0403), (75649, 0x0403), (75777, 0x0304), (75778, 0x0104), (75779, 0x0401), (75780, 0x0304), (75781, 0x0104), (75782, 0x0401),
+    (75787, 0x0204), (75788, 0x0402), (75799, 0x0304), (75800, 0x0404), (75801, 0x0403), (76545, 0x0403), (76673, 0x0402), (76674, 0x0404),
+    (76801, 0x0204), (76802, 0x0104), (76803, 0x0401), (76805, 0x0204), (76806, 0x0401), (76811, 0x0204), (76812, 0x0404), (76813, 0x0403),
+    (76823, 0x0304), (76824, 0x0204), (76825, 0x0403), (77569, 0x0404), (77697, 0x0402), (77825, 0x0104), (77826, 0x0104), (77827, 0x0401),
+    (77829, 0x0304), (77830, 0x0402), (77836, 0x0304), (77837, 0x0402), (77848, 0x0204), (77849, 0x0403), (77850, 0x0403), (78721, 0x0404),
+    (78849, 0x0104), (78850, 0x0204), (78851, 0x0401), (78853, 0x0304), (78854, 0x0403), (78860, 0x0204), (78861, 0x0402), (78872, 0x0304),
+    (78873, 0x0404), (78874, 0x0403), (79745, 0x0404), (79873, 0x0104), (79874, 0x0304), (79875, 0x0401), (79878, 0x0304), (79879, 0x0402),
+    (79884, 0x0204), (79885, 0x0402), (79897, 0x0304), (79898, 0x0304), (80897, 0x0104), (80898, 0x0304), (80899, 0x0401), (80902, 0x0204),
+    (80903, 0x0401), (80908, 0x0204), (80909, 0x0404), (80922, 0x0304), (80923, 0x0403), (81921, 0x0104), (81923, 0x0402), (81926, 0x0104),
+    (81927, 0x0401), (81933, 0x0304), (81934, 0x0403), (81946, 0x0204), (81947, 0x0404), (82945, 0x0104), (82947, 0x0403), (82950, 0x0104),
+    (82951, 0x0401), (82957, 0x0304), (82958, 0x0402), (82971, 0x0304), (82972, 0x0403), (83969, 0x0104), (83971, 0x0304), (83974, 0x0104),
+    (83975, 0x0401), (83981, 0x0104), (83982, 0x0402), (83995, 0x0204), (83996, 0x0403), (84993, 0x0104), (84995, 0x0104), (84998, 0x0104),
+    (84999, 0x0401), (85006, 0x0403), (85020, 0x0304), (85021, 0x0403), (86017, 0x0104), (86019, 0x0104), (86023, 0x0402), (86030, 0x0304),
+    (86031, 0x0402), (86045, 0x0403), (87041, 0x0104), (87043, 0x0104), (87047, 0x0403), (87054, 0x0204), (87055, 0x0401), (87069, 0x0304),
+    (87070, 0x0402), (88065, 0x0104), (88067, 0x0104), (88071, 0x0204), (88079, 0x0401), (88094, 0x0404), (89089, 0x0104), (89091, 0x0104),
+    (89095, 0x0104), (89103, 0x0403), (89118, 0x0404), (89119, 0x0402), (90113, 0x0104), (90115, 0x0104), (90119, 0x0104), (90127, 0x0304),
+    (90142, 0x0204), (90143, 0x0404), (91137, 0x0104), (91139, 0x0104), (91143, 0x0104), (91151, 0x0204), (91167, 0x0204), (91265, 0x0401),
+    (91266, 0x0401), (91268, 0x0401), (91272, 0x0401), (91280, 0x0401), (91296, 0x0403), (92161, 0x0104), (92163, 0x0104), (92167, 0x0104),
+    (92175, 0x0104), (92191, 0x0304), (92289, 0x0401), (92290, 0x0401), (92292, 0x0401), (92296, 0x0401), (92304, 0x0401), (92320, 0x0404),
+    (93313, 0x0401), (93314, 0x0401), (93316, 0x0401), (93320, 0x0401), (93328, 0x0402), (93344, 0x0304), (94337, 0x0401), (94338, 0x0401),
+    (94340, 0x0401), (94344, 0x0401), (94352, 0x0304), (94369, 0x0404), (95361, 0x0401), (95362, 0x0401), (95364, 0x0401), (95368, 0x0403),
+    (95376, 0x0104), (95377, 0x0401), (95393, 0x0304), (96385, 0x0401), (96386, 0x0401), (96388, 0x0401), (96392, 0x0204), (96401, 0x0401),
+    (96418, 0x0403), (97409, 0x0401), (97410, 0x0401), (97412, 0x0401), (97416, 0x0104), (97425, 0x0402), (97442, 0x0104), (98433, 0x0401),
+    (98434, 0x0401), (98436, 0x0401), (98440, 0x0104), (98449, 0x0204), (98467, 0x0403), (99457, 0x0401), (99458, 0x0401), (99460, 0x0402),
+    (99464, 0x0104), (99473, 0x0104), (99491, 0x0304), (100481, 0x0401), (100482, 0x0401), (100484, 0x0204), (100489, 0x0401), (100498, 0x0401),
+    (100516, 0x0402), (101505, 0x0401), (101506, 0x0401), (101508, 0x0104), (101513, 0x0401), (101522, 0x0403), (102529, 0x0401), (102530, 0x0401),
+    (102532, 0x0104), (102537, 0x0401), (102546, 0x0204), (102565, 0x0403), (103553, 0x0401), (103554, 0x0401), (103556, 0x0104), (103561, 0x0402),
+    (103570, 0x0104), (103589, 0x0304), (104577, 0x0401), (104578, 0x0401), (104580, 0x0104), (104585, 0x0204), (104595, 0x0401), (105601, 0x0401),
+    (105602, 0x0401), (105604, 0x0104), (105609, 0x0104), (105619, 0x0403), (106625, 0x0401), (106626, 0x0401), (106628, 0x0104), (106633, 0x0104),
+    (106643, 0x0204), (107649, 0x0401), (107650, 0x0402), (108673, 0x0401), (108674, 0x0204), (109697, 0x0401), (109698, 0x0104), (288257, 0x0401),
+    (288385, 0x0401), (288386, 0x0401), (288387, 0x0402), (289281, 0x0401), (289409, 0x0401), (289410, 0x0401), (289411, 0x0401), (290305, 0x0401),
+    (290433, 0x0401), (290434, 0x0401), (290435, 0x0401), (290561, 0x0401), (290562, 0x0401), (291329, 0x0304), (291457, 0x0401), (291458, 0x0401),
+    (291459, 0x0401), (291585, 0x0401), (291586, 0x0401), (291588, 0x0402), (292353, 0x0204), (292481, 0x0401), (292482, 0x0402), (292483, 0x0401),
+    (292609, 0x0401), (292610, 0x0401), (292612, 0x0401), (293377, 0x0204), (293505, 0x0402), (293506, 0x0204), (293507, 0x0401), (293633, 0x0401),
+    (293634, 0x0401), (293636, 0x0401), (294401, 0x0304), (294529, 0x0204), (294530, 0x0104), (294531, 0x0402), (294657, 0x0401), (294658, 0x0401),
+    (294660, 0x0401), (294661, 0x0401), (295425, 0x0304), (295553, 0x0104), (295554, 0x0304), (295555, 0x0204), (295681, 0x0401), (295682, 0x0401),
+    (295684, 0x0401), (295685, 0x0402), (296449, 0x0304), (296577, 0x0104), (296578, 0x0204), (296579, 0x0204), (296705, 0x0401), (296706, 0x0401),
+    (296708, 0x0402), (296709, 0x0401), (297473, 0x0304), (297601, 0x0204), (297602, 0x0304), (297603, 0x0204), (297729, 0x0401), (297730, 0x0402),
+    (297731, 0x0402), (297732, 0x0204), (297733, 0x0401), (297734, 0x0402), (298625, 0x0204), (298627, 0x0404), (298753, 0x0401), (298754, 0x0204),
+    (298755, 0x0401), (298756, 0x0104), (298757, 0x0402), (298758, 0x0402), (299649, 0x0204), (299777, 0x0402), (299778, 0x0104), (299779, 0x0401),
+    (299780, 0x0304), (299781, 0x0204), (299782, 0x0402), (300801, 0x0204), (300802, 0x0104), (300803, 0x0401), (300805, 0x0104), (300806, 0x0403),
+    (300807, 0x0403), (301825, 0x0104), (301827, 0x0402), (301830, 0x0204), (301831, 0x0402), (302849, 0x0104), (302851, 0x0204), (302854, 0x0204),
+    (302855, 0x0402), (302977, 0x0401), (302978, 0x0401), (302980, 0x0401), (303873, 0x0104), (303875, 0x0104), (303879, 0x0204), (304001, 0x0401),
+    (304002, 0x0401), (304004, 0x0401), (304008, 0x0402), (304897, 0x0104), (305025, 0x0401), (305026, 0x0401), (305028, 0x0401), (305032, 0x0402),
+    (306049, 0x0401), (306050, 0x0401), (306052, 0x0403), (306056, 0x0104), (306057, 0x0403), (307073, 0x0401), (307074, 0x0401), (307076, 0x0204),
+    (307081, 0x0402), (308097, 0x0401), (308098, 0x0403), (308100, 0x0104), (308105, 0x0204), (309121, 0x0401), (309122, 0x0204), (309125, 0x0401),
+    (309130, 0x0402), (310145, 0x0401), (310146, 0x0104), (310149, 0x0403), (311169, 0x0401), (311170, 0x0104), (311173, 0x0204), (556612, 0x0401),
+    (556613, 0x0401), (556614, 0x0401), (556623, 0x0402), (556624, 0x0401), (556625, 0x0401), (556634, 0x0402), (556635, 0x0403), (556744, 0x0401),
+    (556745, 0x0401), (556746, 0x0401), (556755, 0x0402), (556756, 0x0401), (556757, 0x0401), (556766, 0x0402), (556767, 0x0403), (556777, 0x0403),
+    (556788, 0x0404), (556876, 0x0402), (556877, 0x0401), (556878, 0x0401), (556887, 0x0402), (556888, 0x0401), (556889, 0x0401), (556898, 0x0404),
+    (556899, 0x0404), (556900, 0x0401), (556909, 0x0304), (556910, 0x0404), (556911, 0x0401), (556920, 0x0204), (556921, 0x0402), (557008, 0x0401),
+    (557009, 0x0401), (557010, 0x0401), (557019, 0x0401), (557020, 0x0401), (557021, 0x0401), (557030, 0x0401), (557031, 0x0401), (557041, 0x0401),
+    (557140, 0x0403), (557141, 0x0401), (557142, 0x0401), (557151, 0x0403), (557152, 0x0401), (557153, 0x0401), (557162, 0x0403), (557163, 0x0401),
+    (557164, 0x0401), (557173, 0x0402), (557174, 0x0401), (557184, 0x0403), (557272, 0x0403), (557273, 0x0401), (557274, 0x0401), (557283, 0x0403),
+    (557284, 0x0401), (557285, 0x0401), (557294, 0x0403), (557295, 0x0402), (557296, 0x0401), (557305, 0x0404), (557306, 0x0401), (557316, 0x0403),
+    (557317, 0x0401), (557404, 0x0401), (557405, 0x0401), (557406, 0x0401), (557415, 0x0401), (557416, 0x0401), (557417, 0x0401), (557426, 0x0401),
+    (557427, 0x0401), (557437, 0x0401), (557536, 0x0401), (557537, 0x0401), (557538, 0x0401), (557547, 0x0401), (557548, 0x0401), (557549, 0x0401),
+    (557558, 0x0401), (557559, 0x0401), (557569, 0x0401), (557668, 0x0402), (557669, 0x0401), (557670, 0x0401), (557679, 0x0402), (557680, 0x0401),
+    (557681, 0x0401), (557690, 0x0402), (557691, 0x0401), (557692, 0x0401), (557701, 0x0401), (557800, 0x0402), (557801, 0x0401), (557802, 0x0401),
+    (557811, 0x0402), (557812, 0x0401), (557813, 0x0401), (557822, 0x0402), (557823, 0x0401), (557824, 0x0401), (557833, 0x0402), (557834, 0x0401),
+    (557844, 0x0402), (557932, 0x0401), (557933, 0x0401), (557943, 0x0401), (557944, 0x0401), (557954, 0x0401), (557965, 0x0401), (558064, 0x0401),
+    (558065, 0x0401), (558066, 0x0401), (558075, 0x0401), (558076, 0x0401), (558086, 0x0401), (558087, 0x0401), (558097, 0x0402), (558196, 0x0403),
+    (558197, 0x0401), (558198, 0x0401), (558207, 0x0403), (558208, 0x0401), (558209, 0x0401), (558218, 0x0403), (558219, 0x0401), (558220, 0x0401),
+    (558229, 0x0402), (558328, 0x0404), (558329, 0x0401), (558330, 0x0401), (558339, 0x0403), (558340, 0x0401), (558341, 0x0401), (558350, 0x0403),
+    (558351, 0x0401), (558361, 0x0204), (558362, 0x0401), (558372, 0x0204), (558460, 0x0402), (558461, 0x0401), (558462, 0x0401), (558471, 0x0402),
+    (558472, 0x0401), (558473, 0x0401), (558482, 0x0402), (558483, 0x0401), (558493, 0x0402), (558592, 0x0401), (558593, 0x0401), (558603, 0x0401),
+    (558604, 0x0401), (558614, 0x0401), (558724, 0x0401), (558725, 0x0401), (558735, 0x0401), (558736, 0x0401), (558746, 0x0401), (558856, 0x0401),
+    (558857, 0x0401), (558858, 0x0401), (558867, 0x0401), (558868, 0x0401), (558878, 0x0401), (558988, 0x0402), (558989, 0x0401), (558990, 0x0401),
+    (558999, 0x0402), (559000, 0x0401), (559010, 0x0402), (559011, 0x0401), (559120, 0x0401), (559121, 0x0401), (559122, 0x0401), (559131, 0x0402),
+    (559132, 0x0401), (559142, 0x0401), (559252, 0x0401), (559253, 0x0401), (559254, 0x0401), (559263, 0x0401), (559264, 0x0401), (559274, 0x0401),
+    (559384, 0x0401), (559385, 0x0401), (559386, 0x0401), (559395, 0x0401), (559396, 0x0401), (559406, 0x0401), (559516, 0x0401), (559517, 0x0401),
+    (559527, 0x0401), (559528, 0x0401), (559538, 0x0401), (559648, 0x0401), (559649, 0x0401), (559659, 0x0401), (559660, 0x0401), (559670, 0x0401),
+    (559780, 0x0403), (559781, 0x0401), (559782, 0x0401), (559791, 0x0402), (559792, 0x0401), (559802, 0x0401), (559912, 0x0403), (559913, 0x0401),
+    (559923, 0x0402), (559924, 0x0401), (559934, 0x0402), (560044, 0x0401), (560045, 0x0401), (560055, 0x0401), (560056, 0x0401), (560066, 0x0401),
+    (560176, 0x0401), (560177, 0x0401), (560178, 0x0401), (560187, 0x0401), (560188, 0x0401), (560198, 0x0402), (560308, 0x0401), (560309, 0x0401),
+    (560310, 0x0401), (560319, 0x0401), (560320, 0x0401), (560440, 0x0401), (560441, 0x0401), (560451, 0x0401), (560452, 0x0401), (560572, 0x0401),
+    (560573, 0x0401), (560583, 0x0401), (560584, 0x0401), (560704, 0x0402), (560705, 0x0401), (560715, 0x0402), (560716, 0x0401), (560836, 0x0402),
+    (560837, 0x0401), (560847, 0x0401), (560848, 0x0401), (560858, 0x0402), (560968, 0x0401), (560969, 0x0401), (560970, 0x0401), (560979, 0x0402),
+    (560980, 0x0401), (560990, 0x0402), (561100, 0x0401), (561101, 0x0401), (561111, 0x0401), (561112, 0x0401), (561232, 0x0401), (561233, 0x0401),
+    (561243, 0x0401), (561244, 0x0401), (561364, 0x0402), (561365, 0x0401), (561366, 0x0401), (561375, 0x0401), (561376, 0x0401), (561386, 0x0401),
+    (561496, 0x0401), (561497, 0x0401), (561507, 0x0401), (561508, 0x0401), (561518, 0x0401), (561628, 0x0402), (561629, 0x0401), (561630, 0x0401),
+    (561639, 0x0402), (561640, 0x0401), (561641, 0x0401), (561650, 0x0402), (561760, 0x0401), (561761, 0x0401), (561762, 0x0401), (561771, 0x0402),
+    (561772, 0x0401), (561782, 0x0401), (561892, 0x0401), (561893, 0x0401), (561894, 0x0401), (561903, 0x0401), (561904, 0x0401), (561914, 0x0401),
+    (562024, 0x0401), (562025, 0x0401), (562026, 0x0401), (562035, 0x0401), (562036, 0x0401), (562037, 0x0401), (562046, 0x0401), (562047, 0x0401),
+    (562156, 0x0402), (562157, 0x0401), (562158, 0x0401), (562167, 0x0402), (562168, 0x0401), (562169, 0x0401), (562178, 0x0402), (562179, 0x0403),
+    (562180, 0x0401), (562288, 0x0401), (562289, 0x0401), (562290, 0x0401), (562299, 0x0401), (562300, 0x0401), (562301, 0x0401), (562310, 0x0402),
+    (562311, 0x0401), (562420, 0x0402), (562421, 0x0401), (562422, 0x0401), (562431, 0x0402), (562432, 0x0401), (562433, 0x0401), (562442, 0x0402),
+    (562443, 0x0402), (562444, 0x0401), (562552, 0x0404), (562553, 0x0401), (562554, 0x0401), (562563, 0x0304), (562564, 0x0401), (562565, 0x0401),
+    (562566, 0x0401), (562574, 0x0403), (562575, 0x0402), (562576, 0x0401), (562585, 0x0402), (562684, 0x0403), (562685, 0x0401), (562686, 0x0401),
+    (562687, 0x0401), (562695, 0x0403), (562696, 0x0401), (562697, 0x0401), (562698, 0x0401), (562706, 0x0402), (562707, 0x0402), (562708, 0x0401),
+    (562709, 0x0401), (562717, 0x0402), (562816, 0x0304), (562817, 0x0401), (562818, 0x0401), (562819, 0x0401), (562827, 0x0204), (562828, 0x0401),
+    (562829, 0x0401), (562830, 0x0401), (562838, 0x0204), (562839, 0x0403), (562840, 0x0401), (562841, 0x0401), (562849, 0x0304), (562850, 0x0304),
+    (562851, 0x0402), (562948, 0x0104), (562949, 0x0401), (562950, 0x0401), (562951, 0x0401), (562952, 0x0401), (562959, 0x0104), (562960, 0x0401),
+    (562961, 0x0401), (562962, 0x0401), (562963, 0x0401), (562970, 0x0104), (562971, 0x0304), (562972, 0x0402), (562973, 0x0401), (562974, 0x0401),
+    (562981, 0x0104), (562982, 0x0204), (562983, 0x0403), (562984, 0x0401), (563080, 0x0404), (563081, 0x0402), (563082, 0x0401), (563091, 0x0403),
+    (563092, 0x0403), (563093, 0x0401), (563094, 0x0401), (563102, 0x0404), (563103, 0x0304), (563104, 0x0402), (563105, 0x0401), (563113, 0x0304),
+    (563114, 0x0204), (563115, 0x0403), (563116, 0x0401), (563124, 0x0304), (563125, 0x0304), (563135, 0x0204), (563212, 0x0404), (563213, 0x0401),
+    (563214, 0x0401), (563223, 0x0404), (563224, 0x0402), (563225, 0x0401), (563226, 0x0401), (563234, 0x0304), (563235, 0x0404), (563236, 0x0401),
+    (563237, 0x0401), (563245, 0x0304), (563246, 0x0304), (563247, 0x0401), (563256, 0x0304), (563257, 0x0404), (563267, 0x0204), (563344, 0x0304),
+    (563345, 0x0402), (563346, 0x0401), (563347, 0x0401), (563355, 0x0304), (563356, 0x0402), (563357, 0x0401), (563358, 0x0401), (563366, 0x0304),
+    (563367, 0x0404), (563368, 0x0401), (563369, 0x0401), (563377, 0x0204), (563378, 0x0304), (563379, 0x0401), (563380, 0x0401), (563388, 0x0104),
+    (563389, 0x0304), (563390, 0x0401), (563399, 0x0204), (563400, 0x0403), (563476, 0x0402), (563477, 0x0401), (563478, 0x0401), (563487, 0x0402),
+    (563488, 0x0401), (563489, 0x0401), (563498, 0x0402), (563499, 0x0401), (563500, 0x0401), (563509, 0x0402), (563510, 0x0401), (563520, 0x0403),
+    (563608, 0x0304), (563609, 0x0401), (563610, 0x0401), (563619, 0x0304), (563620, 0x0402), (563621, 0x0401), (563622, 0x0401), (563630, 0x0304),
+    (563631, 0x0402), (563632, 0x0401), (563633, 0x0401), (563641, 0x0304), (563642, 0x0402), (563643, 0x0401), (563652, 0x0404), (563740, 0x0304),
+    (563741, 0x0401), (563742, 0x0401), (563743, 0x0401), (563751, 0x0404), (563752, 0x0402), (563753, 0x0401), (563754, 0x0401), (563762, 0x0404),
+    (563763, 0x0403), (563764, 0x0401), (563765, 0x0401), (563773, 0x0204), (563774, 0x0402), (563775, 0x0401), (563784, 0x0404), (563785, 0x0402),
+    (563795, 0x0402), (563872, 0x0402), (563873, 0x0401), (563874, 0x0401), (563883, 0x0402), (563884, 0x0401), (563885, 0x0401), (563894, 0x0402),
+    (563895, 0x0401), (563896, 0x0401), (563905, 0x0402), (563906, 0x0401), (563916, 0x0402), (564004, 0x0403), (564005, 0x0401), (564006, 0x0401),
+    (564015, 0x0403), (564016, 0x0401), (564017, 0x0401), (564026, 0x0403), (564027, 0x0401), (564028, 0x0401), (564037, 0x0403), (564038, 0x0401),
+    (564048, 0x0402), (564136, 0x0404), (564137, 0x0401), (564138, 0x0401), (564147, 0x0403), (564148, 0x0401), (564149, 0x0401), (564158, 0x0403),
+    (564159, 0x0401), (564160, 0x0401), (564169, 0x0403), (564170, 0x0401), (564180, 0x0403), (564268, 0x0404), (564269, 0x0401), (564270, 0x0401),
+    (564279, 0x0404), (564280, 0x0401), (564281, 0x0401), (564290, 0x0404), (564291, 0x0401), (564292, 0x0401), (564301, 0x0404), (564302, 0x0401),
+    (564303, 0x0401), (564312, 0x0403), (564400, 0x0401), (564401, 0x0401), (564402, 0x0401), (564411, 0x0401), (564412, 0x0401), (564413, 0x0401),
+    (564422, 0x0401), (564423, 0x0401), (564433, 0x0401), (564532, 0x0402), (564533, 0x0401), (564534, 0x0401), (564543, 0x0403), (564544, 0x0401),
+    (564545, 0x0401), (564554, 0x0403), (564555, 0x0401), (564565, 0x0403), (564664, 0x0304), (564665, 0x0401), (564666, 0x0401), (564675, 0x0304),
+    (564676, 0x0401), (564677, 0x0401), (564686, 0x0304), (564687, 0x0401), (564688, 0x0401), (564697, 0x0204), (564698, 0x0401), (564796, 0x0304),
+    (564797, 0x0401), (564798, 0x0401), (564807, 0x0304), (564808, 0x0401), (564809, 0x0401), (564818, 0x0304), (564819, 0x0401), (564820, 0x0401),
+    (564829, 0x0204), (564830, 0x0401), (564928, 0x0403), (564929, 0x0401), (564930, 0x0401), (564939, 0x0403), (564940, 0x0401), (564941, 0x0401),
+    (564950, 0x0403), (564951, 0x0401), (564961, 0x0403), (565060, 0x0401), (565061, 0x0401), (565071, 0x0401), (565072, 0x0401), (565082, 0x0401),
+    (565192, 0x0402), (565193, 0x0401), (565194, 0x0401), (565203, 0x0402), (565204, 0x0401), (565214, 0x0401), (565215, 0x0401), (565324, 0x0403),
+    (565325, 0x0401), (565326, 0x0401), (565335, 0x0402), (565336, 0x0401), (565337, 0x0401), (565346, 0x0402), (565347, 0x0401), (565456, 0x0403),
+    (565457, 0x0401), (565458, 0x0401), (565467, 0x0403), (565468, 0x0401), (565478, 0x0402), (565479, 0x0401), (565588, 0x0403), (565589, 0x0401),
+    (565590, 0x0401), (565599, 0x0403), (565600, 0x0401), (565601, 0x0401), (565610, 0x0403), (565611, 0x0401), (565720, 0x0401), (565721, 0x0401),
+    (565722, 0x0401), (565731, 0x0402), (565732, 0x0401), (565733, 0x0401), (565742, 0x0402), (565743, 0x0401), (565852, 0x0401), (565853, 0x0401),
+    (565854, 0x0401), (565863, 0x0402), (565864, 0x0401), (565874, 0x0402), (565984, 0x0401), (565985, 0x0401), (565995, 0x0402), (565996, 0x0401),
+    (566116, 0x0402), (566117, 0x0401), (566118, 0x0401), (566127, 0x0402), (566128, 0x0401), (566138, 0x0402), (566248, 0x0404), (566249, 0x0401),
+    (566250, 0x0401), (566259, 0x0403), (566260, 0x0401), (566270, 0x0403), (566271, 0x0401), (566380, 0x0403), (566381, 0x0401), (566382, 0x0401),
+    (566391, 0x0402), (566392, 0x0401), (566402, 0x0403), (566512, 0x0402), (566513, 0x0401), (566514, 0x0401), (566523, 0x0402), (566524, 0x0401),
+    (566534, 0x0403), (566644, 0x0401), (566645, 0x0401), (566646, 0x0401), (566655, 0x0403), (566656, 0x0401), (566657, 0x0401), (566666, 0x0204),
+    (566667, 0x0403), (566776, 0x0401), (566777, 0x0401), (566778, 0x0401), (566787, 0x0402), (566788, 0x0401), (566798, 0x0304), (566908, 0x0402),
+    (566909, 0x0401), (566910, 0x0401), (566919, 0x0402), (566920, 0x0401), (567040, 0x0402), (567041, 0x0401), (567042, 0x0401), (567051, 0x0402),
+    (567052, 0x0401), (567062, 0x0403), (567172, 0x0404), (567173, 0x0401), (567174, 0x0401), (567183, 0x0403), (567184, 0x0401), (567185, 0x0401),
+    (567194, 0x0403), (567195, 0x0402), (567304, 0x0403), (567305, 0x0401), (567306, 0x0401), (567315, 0x0403), (567316, 0x0401), (567317, 0x0401),
+    (567326, 0x0403), (567436, 0x0401), (567437, 0x0401), (567438, 0x0401), (567447, 0x0403), (567448, 0x0401), (567449, 0x0401), (567458, 0x0403),
+    (567459, 0x0402), (567568, 0x0401), (567569, 0x0401), (567570, 0x0401), (567579, 0x0401), (567580, 0x0401), (567590, 0x0402), (567700, 0x0401),
+    (567701, 0x0401), (567702, 0x0401), (567711, 0x0402), (567712, 0x0401), (567722, 0x0402), (567832, 0x0402), (567833, 0x0401), (567834, 0x0401),
+    (567843, 0x0402), (567844, 0x0401), (567845, 0x0401), (567854, 0x0402), (567855, 0x0401), (567964, 0x0402), (567965, 0x0401), (567966, 0x0401),
+    (567975, 0x0402), (567976, 0x0401), (567977, 0x0401), (567986, 0x0402), (567987, 0x0401), (568096, 0x0403), (568097, 0x0401), (568098, 0x0401),
+    (568107, 0x0404), (568108, 0x0401), (568109, 0x0401), (568118, 0x0403), (568119, 0x0402), (568228, 0x0402), (568229, 0x0401), (568230, 0x0401),
+    (568239, 0x0403), (568240, 0x0401), (568241, 0x0401), (568250, 0x0402), (568251, 0x0402), (568360, 0x0402), (568361, 0x0401), (568362, 0x0401),
+    (568371, 0x0403), (568372, 0x0401), (568373, 0x0401), (568382, 0x0403), (568383, 0x0401), (568492, 0x0402), (568493, 0x0401), (568494, 0x0401),
+    (568503, 0x0403), (568504, 0x0401), (568505, 0x0401), (568514, 0x0402), (568515, 0x0402), (568516, 0x0401), (568624, 0x0403), (568625, 0x0401),
+    (568626, 0x0401), (568635, 0x0403), (568636, 0x0401), (568637, 0x0401), (568638, 0x0401), (568646, 0x0304), (568647, 0x0403), (568648, 0x0401),
+    (568649, 0x0401), (568657, 0x0304), (568658, 0x0404), (568756, 0x0402), (568757, 0x0401), (568758, 0x0401), (568767, 0x0403), (568768, 0x0401),
+    (568769, 0x0401), (568770, 0x0401), (568778, 0x0404), (568779, 0x0403), (568780, 0x0401), (568781, 0x0401), (568789, 0x0403), (568888, 0x0404),
+    (568889, 0x0401), (568890, 0x0401), (568891, 0x0401), (568899, 0x0304), (568900, 0x0401), (568901, 0x0401), (568902, 0x0401), (568910, 0x0404),
+    (568911, 0x0403), (568912, 0x0401), (568913, 0x0401), (568921, 0x0404), (568922, 0x0304), (569020, 0x0204), (569021, 0x0401), (569022, 0x0401),
+    (569023, 0x0401), (569031, 0x0204), (569032, 0x0401), (569033, 0x0401), (569034, 0x0401), (569042, 0x0204), (569043, 0x0403), (569044, 0x0401),
+    (569045, 0x0401), (569053, 0x0304), (569054, 0x0304), (569055, 0x0402), (569056, 0x0401), (569152, 0x0204), (569153, 0x0401), (569154, 0x0401),
+    (569155, 0x0401), (569163, 0x0204), (569164, 0x0401), (569165, 0x0401), (569166, 0x0401), (569174, 0x0204), (569175, 0x0403), (569176, 0x0401),
+    (569177, 0x0401), (569185, 0x0204), (569186, 0x0204), (569187, 0x0402), (569188, 0x0401), (569284, 0x0104), (569285, 0x0401), (569286, 0x0401),
+    (569287, 0x0401), (569295, 0x0104), (569296, 0x0401), (569297, 0x0401), (569298, 0x0401), (569299, 0x0401), (569306, 0x0104), (569307, 0x0404),
+    (569308, 0x0401), (569309, 0x0401), (569310, 0x0401), (569317, 0x0104), (569318, 0x0204), (569319, 0x0403), (569320, 0x0401), (569548, 0x0304),
+    (569549, 0x0403), (569550, 0x0401), (569551, 0x0401), (569559, 0x0304), (569560, 0x0403), (569561, 0x0401), (569562, 0x0401), (569570, 0x0204),
+    (569571, 0x0304), (569572, 0x0402), (569573, 0x0401), (569581, 0x0204), (569582, 0x0204), (569583, 0x0403), (569584, 0x0401), (569592, 0x0104),
+    (569593, 0x0204), (569594, 0x0403), (569595, 0x0401), (569603, 0x0104), (569604, 0x0204), (569605, 0x0403), (569614, 0x0104), (569680, 0x0304),
+    (569681, 0x0402), (569682, 0x0401), (569683, 0x0401), (569691, 0x0304), (569692, 0x0403), (569693, 0x0401), (569694, 0x0401), (569702, 0x0204),
+    (569703, 0x0404), (569704, 0x0401), (569705, 0x0401), (569713, 0x0204), (569714, 0x0204), (569715, 0x0402), (569716, 0x0401), (569724, 0x0204),
+    (569725, 0x0204), (569726, 0x0401), (569735, 0x0104), (569736, 0x0404), (569812, 0x0204), (569813, 0x0402), (569814, 0x0401), (569815, 0x0401),
+    (569823, 0x0204), (569824, 0x0403), (569825, 0x0401), (569826, 0x0401), (569834, 0x0204), (569835, 0x0404), (569836, 0x0401), (569837, 0x0401),
+    (569845, 0x0204), (569846, 0x0304), (569847, 0x0402), (569848, 0x0401), (569856, 0x0104), (569857, 0x0204), (569858, 0x0403), (569859, 0x0401),
+    (569867, 0x0204), (569868, 0x0304), (569869, 0x0402), (569944, 0x0404), (569945, 0x0401), (569946, 0x0401), (569955, 0x0404), (569956, 0x0401),
+    (569957, 0x0401), (569966, 0x0403), (569967, 0x0402), (569968, 0x0401), (569977, 0x0304), (569978, 0x0402), (569979, 0x0401), (569988, 0x0404),
+    (569989, 0x0401), (570076, 0x0204), (570077, 0x0402), (570078, 0x0401), (570079, 0x0401), (570087, 0x0204), (570088, 0x0402), (570089, 0x0401),
+    (570090, 0x0401), (570098, 0x0204), (570099, 0x0403), (570100, 0x0401), (570101, 0x0401), (570109, 0x0204), (570110, 0x0403), (570111, 0x0401),
+    (570120, 0x0204), (570121, 0x0403), (570208, 0x0304), (570209, 0x0402), (570210, 0x0401), (570211, 0x0401), (570219, 0x0304), (570220, 0x0402),
+    (570221, 0x0401), (570222, 0x0401), (570230, 0x0304), (570231, 0x0403), (570232, 0x0401), (570233, 0x0401), (570241, 0x0304), (570242, 0x0404),
+    (570243, 0x0401), (570244, 0x0401), (570252, 0x0304), (570253, 0x0402), (570254, 0x0401), (570263, 0x0403), (570340, 0x0404), (570341, 0x0401),
+    (570342, 0x0401), (570351, 0x0403), (570352, 0x0401), (570353, 0x0401), (570362, 0x0404), (570363, 0x0401), (570364, 0x0401), (570373, 0x0403),
+    (570374, 0x0401), (570384, 0x0403), (570385, 0x0401), (570472, 0x0304), (570473, 0x0401), (570474, 0x0401), (570475, 0x0401), (570483, 0x0304),
+    (570484, 0x0401), (570485, 0x0401), (570486, 0x0401), (570494, 0x0404), (570495, 0x0402), (570496, 0x0401), (570505, 0x0404), (570506, 0x0402),
+    (570507, 0x0401), (570516, 0x0404), (570517, 0x0401), (570527, 0x0304), (570604, 0x0304), (570605, 0x0401), (570606, 0x0401), (570607, 0x0401),
+    (570615, 0x0304), (570616, 0x0401), (570617, 0x0401), (570618, 0x0401), (570626, 0x0304), (570627, 0x0402), (570628, 0x0401), (570629, 0x0401),
+    (570637, 0x0304), (570638, 0x0401), (570639, 0x0401), (570648, 0x0404), (570649, 0x0402), (570736, 0x0204), (570737, 0x0401), (570738, 0x0401),
+    (570739, 0x0401), (570747, 0x0204), (570748, 0x0401), (570749, 0x0401), (570750, 0x0401), (570758, 0x0204), (570759, 0x0402), (570760, 0x0401),
+    (570761, 0x0401), (570769, 0x0304), (570770, 0x0402), (570771, 0x0401), (570780, 0x0304), (570781, 0x0402), (570868, 0x0402), (570869, 0x0401),
+    (570870, 0x0401), (570879, 0x0403), (570880, 0x0401), (570881, 0x0401), (570890, 0x0404), (570891, 0x0401), (570901, 0x0403), (571000, 0x0404),
+    (571001, 0x0401), (571002, 0x0401), (571011, 0x0404), (571012, 0x0401), (571013, 0x0401), (571022, 0x0304), (571023, 0x0401), (571024, 0x0401),
+    (571033, 0x0404), (571132, 0x0204), (571133, 0x0401), (571134, 0x0401), (571135, 0x0401), (571143, 0x0204), (571144, 0x0401), (571145, 0x0401),
+    (571146, 0x0401), (571154, 0x0204), (571155, 0x0401), (571156, 0x0401), (571165, 0x0204), (571166, 0x0401), (571264, 0x0204), (571265, 0x0401),
+    (571266, 0x0401), (571275, 0x0204), (571276, 0x0401), (571277, 0x0401), (571286, 0x0304), (571287, 0x0401), (571288, 0x0401), (571297, 0x0204),
+    (571298, 0x0401), (571396, 0x0404), (571397, 0x0401), (571398, 0x0401), (571407, 0x0404), (571408, 0x0401), (571409, 0x0401), (571418, 0x0404),
+    (571419, 0x0401), (571420, 0x0401), (571429, 0x0403), (571528, 0x0401), (571529, 0x0401), (571530, 0x0401), (571539, 0x0401), (571540, 0x0401),
+    (571550, 0x0402), (571660, 0x0403), (571661, 0x0401), (571662, 0x0401), (571671, 0x0402), (571672, 0x0401), (571682, 0x0403), (571683, 0x0401),
+    (571792, 0x0404), (571793, 0x0401), (571794, 0x0401), (571803, 0x0403), (571804, 0x0401), (571805, 0x0401), (571814, 0x0403), (571815, 0x0401),
+    (571924, 0x0404), (571925, 0x0401), (571926, 0x0401), (571935, 0x0404), (571936, 0x0401), (571937, 0x0401), (571946, 0x0403), (571947, 0x0401),
+    (572056, 0x0404), (572057, 0x0401), (572058, 0x0401), (572067, 0x0404), (572068, 0x0401), (572069, 0x0401), (572078, 0x0403), (572079, 0x0401),
+    (572188, 0x0402), (572189, 0x0401), (572190, 0x0401), (572199, 0x0403), (572200, 0x0401), (572201, 0x0401), (572210, 0x0404), (572211, 0x0402),
+    (572212, 0x0401), (572320, 0x0401), (572321, 0x0401), (572322, 0x0401), (572331, 0x0403), (572332, 0x0401), (572333, 0x0401), (572342, 0x0404),
+    (572452, 0x0401), (572453, 0x0401), (572454, 0x0401), (572463, 0x0402), (572464, 0x0401), (572474, 0x0401), (572584, 0x0403), (572585, 0x0401),
+    (572586, 0x0401), (572595, 0x0403), (572596, 0x0401), (572606, 0x0404), (572607, 0x0401), (572716, 0x0304), (572717, 0x0401), (572718, 0x0401),
+    (572727, 0x0404), (572728, 0x0401), (572729, 0x0401), (572738, 0x0404), (572739, 0x0401), (572848, 0x0404), (572849, 0x0401), (572850, 0x0401),
+    (572859, 0x0403), (572860, 0x0401), (572870, 0x0404), (572980, 0x0403), (572981, 0x0401), (572982, 0x0401), (572991, 0x0403), (572992, 0x0401),
+    (572993, 0x0401), (573002, 0x0404), (573112, 0x0402), (573113, 0x0401), (573114, 0x0401), (573123, 0x0404), (573124, 0x0401), (573125, 0x0401),
+    (573134, 0x0304), (573135, 0x0404), (573244, 0x0401), (573245, 0x0401), (573246, 0x0401), (573255, 0x0403), (573256, 0x0401), (573257, 0x0401),
+    (573266, 0x0304), (573376, 0x0402), (573377, 0x0401), (573378, 0x0401), (573387, 0x0403), (573388, 0x0401), (573508, 0x0403), (573509, 0x0401),
+    (573510, 0x0401), (573519, 0x0403), (573520, 0x0401), (573521, 0x0401), (573530, 0x0403), (573531, 0x0404), (573640, 0x0304), (573641, 0x0401),
+    (573642, 0x0401), (573651, 0x0404), (573652, 0x0401), (573653, 0x0401), (573662, 0x0404), (573663, 0x0403), (573664, 0x0401), (573772, 0x0404),
+    (573773, 0x0401), (573774, 0x0401), (573783, 0x0404), (573784, 0x0401), (573785, 0x0401), (573794, 0x0404), (573795, 0x0403), (573796, 0x0401),
+    (573904, 0x0402), (573905, 0x0401), (573906, 0x0401), (573915, 0x0404), (573916, 0x0401), (573917, 0x0401), (573926, 0x0404), (573927, 0x0403),
+    (573928, 0x0401), (574036, 0x0401), (574037, 0x0401), (574038, 0x0401), (574047, 0x0403), (574048, 0x0401), (574049, 0x0401), (574058, 0x0402),
+    (574168, 0x0402), (574169, 0x0401), (574170, 0x0401), (574179, 0x0403), (574180, 0x0401), (574181, 0x0401), (574190, 0x0403), (574191, 0x0402),
+    (574300, 0x0404), (574301, 0x0401), (574302, 0x0401), (574311, 0x0403), (574312, 0x0401), (574313, 0x0401), (574322, 0x0403), (574323, 0x0402),
+    (574324, 0x0401), (574432, 0x0403), (574433, 0x0401), (574434, 0x0401), (574443, 0x0404), (574444, 0x0401), (574445, 0x0401), (574454, 0x0404),
+    (574455, 0x0402), (574456, 0x0401), (574564, 0x0404), (574565, 0x0401), (574566, 0x0401), (574575, 0x0304), (574576, 0x0401), (574577, 0x0401),
+    (574586, 0x0404), (574587, 0x0402), (574588, 0x0401), (574696, 0x0403), (574697, 0x0401), (574698, 0x0401), (574707, 0x0304), (574708, 0x0401),
+    (574709, 0x0401), (574718, 0x0404), (574719, 0x0403), (574720, 0x0401), (574828, 0x0403), (574829, 0x0401), (574830, 0x0401), (574839, 0x0404),
+    (574840, 0x0401), (574841, 0x0401), (574850, 0x0404), (574851, 0x0402), (574852, 0x0401), (574960, 0x0304), (574961, 0x0401), (574962, 0x0401),
+    (574963, 0x0401), (574971, 0x0304), (574972, 0x0401), (574973, 0x0401), (574974, 0x0401), (574982, 0x0304), (574983, 0x0403), (574984, 0x0401),
+    (574985, 0x0401), (574993, 0x0404), (574994, 0x0304), (575092, 0x0404), (575093, 0x0401), (575094, 0x0401), (575095, 0x0401), (575103, 0x0304),
+    (575104, 0x0402), (575105, 0x0401), (575106, 0x0401), (575114, 0x0204), (575115, 0x0404), (575116, 0x0401), (575117, 0x0401), (575125, 0x0204),
+    (575126, 0x0204), (575127, 0x0402), (575128, 0x0401), (575224, 0x0404), (575225, 0x0401), (575226, 0x0401), (575227, 0x0401), (575235, 0x0304),
+    (575236, 0x0401), (575237, 0x0401), (575238, 0x0401), (575246, 0x0204), (575247, 0x0403), (575248, 0x0401), (575249, 0x0401), (575257, 0x0304),
+    (575258, 0x0304), (575259, 0x0401), (575356, 0x0204), (575357, 0x0401), (575358, 0x0401), (575359, 0x0401), (575367, 0x0204), (575368, 0x0401),
+    (575369, 0x0401), (575370, 0x0401), (575378, 0x0204), (575379, 0x0403), (575380, 0x0401), (575381, 0x0401), (575389, 0x0204), (575390, 0x0204),
+    (575391, 0x0402), (575392, 0x0401), (575488, 0x0104), (575489, 0x0401), (575490, 0x0401), (575491, 0x0401), (575499, 0x0104), (575500, 0x0402),
+    (575501, 0x0401), (575502, 0x0401), (575503, 0x0401), (575510, 0x0104), (575511, 0x0404), (575512, 0x0401), (575513, 0x0401), (575514, 0x0401),
+    (575521, 0x0104), (575522, 0x0204), (575523, 0x0403), (575524, 0x0401), (575620, 0x0104), (575621, 0x0401), (575622, 0x0401), (575623, 0x0401),
+    (575631, 0x0104), (575632, 0x0402), (575633, 0x0401), (575634, 0x0401), (575635, 0x0401), (575642, 0x0104), (575643, 0x0304), (575644, 0x0402),
+    (575645, 0x0401), (575646, 0x0401), (575653, 0x0104), (575654, 0x0204), (575655, 0x0403), (575656, 0x0401), (576016, 0x0104), (576017, 0x0404),
+    (576018, 0x0401), (576019, 0x0401), (576027, 0x0204), (576028, 0x0304), (576029, 0x0402), (576030, 0x0401), (576031, 0x0401), (576038, 0x0104),
+    (576039, 0x0204), (576040, 0x0403), (576041, 0x0401), (576042, 0x0401), (576049, 0x0104), (576050, 0x0204), (576051, 0x0404), (576052, 0x0401),
+    (576053, 0x0401), (576060, 0x0104), (576061, 0x0204), (576062, 0x0304), (576063, 0x0402), (576064, 0x0401), (576071, 0x0104), (576072, 0x0204),
+    (576073, 0x0304), (576074, 0x0402), (576075, 0x0401), (576082, 0x0104), (576083, 0x0204), (576084, 0x0404), (576085, 0x0401), (576148, 0x0204),
+    (576149, 0x0403), (576150, 0x0401), (576151, 0x0401), (576159, 0x0204), (576160, 0x0404), (576161, 0x0401), (576162, 0x0401), (576163, 0x0401),
+    (576170, 0x0204), (576171, 0x0304), (576172, 0x0401), (576173, 0x0401), (576174, 0x0401), (576181, 0x0204), (576182, 0x0304), (576183, 0x0402),
+    (576184, 0x0401), (576185, 0x0401), (576192, 0x0204), (576193, 0x0204), (576194, 0x0403), (576195, 0x0401), (576203, 0x0104), (576204, 0x0304),
+    (576205, 0x0402), (576206, 0x0401), (576214, 0x0204), (576215, 0x0304), (576216, 0x0402), (576280, 0x0104), (576281, 0x0403), (576282, 0x0401),
+    (576283, 0x0401), (576291, 0x0204), (576292, 0x0404), (576293, 0x0401), (576294, 0x0401), (576295, 0x0401), (576302, 0x0104), (576303, 0x0304),
+    (576304, 0x0401), (576305, 0x0401), (576306, 0x0401), (576313, 0x0204), (576314, 0x0304), (576315, 0x0402), (576316, 0x0401), (576317, 0x0401),
+    (576324, 0x0104), (576325, 0x0204), (576326, 0x0403), (576327, 0x0401), (576328, 0x0401), (576335, 0x0104), (576336, 0x0204), (576337, 0x0403),
+    (576338, 0x0401), (576346, 0x0104), (576347, 0x0204), (576348, 0x0403), (576412, 0x0304), (576413, 0x0402), (576414, 0x0401), (576415, 0x0401),
+    (576423, 0x0304), (576424, 0x0402), (576425, 0x0401), (576426, 0x0401), (576434, 0x0304), (576435, 0x0403), (576436, 0x0401), (576437, 0x0401),
+    (576445, 0x0204), (576446, 0x0403), (576447, 0x0401), (576448, 0x0401), (576456, 0x0304), (576457, 0x0403), (576458, 0x0401), (576467, 0x0204),
+    (576544, 0x0204), (576545, 0x0403), (576546, 0x0401), (576547, 0x0401), (576555, 0x0104), (576556, 0x0403), (576557, 0x0401), (576558, 0x0401),
+    (576566, 0x0104), (576567, 0x0404), (576568, 0x0401), (576569, 0x0401), (576577, 0x0104), (576578, 0x0404), (576579, 0x0401), (576580, 0x0401),
+    (576588, 0x0204), (576589, 0x0404), (576590, 0x0401), (576599, 0x0204), (576600, 0x0403), (576676, 0x0204), (576677, 0x0403), (576678, 0x0401),
+    (576679, 0x0401), (576687, 0x0204), (576688, 0x0403), (576689, 0x0401), (576690, 0x0401), (576698, 0x0104), (576699, 0x0404), (576700, 0x0401),
+    (576701, 0x0401), (576702, 0x0401), (576709, 0x0104), (576710, 0x0404), (576711, 0x0401), (576712, 0x0401), (576720, 0x0104), (576721, 0x0403),
+    (576722, 0x0401), (576731, 0x0204), (576732, 0x0402), (576733, 0x0401), (576742, 0x0204), (576743, 0x0402), (576808, 0x0204), (576809, 0x0401),
+    (576810, 0x0401), (576811, 0x0401), (576819, 0x0204), (576820, 0x0401), (576821, 0x0401), (576822, 0x0401), (576830, 0x0304), (576831, 0x0401),
+    (576832, 0x0401), (576833, 0x0401), (576841, 0x0204), (576842, 0x0402), (576843, 0x0401), (576852, 0x0204), (576853, 0x0402), (576854, 0x0401),
+    (576863, 0x0204), (576864, 0x0402), (576940, 0x0204), (576941, 0x0402), (576942, 0x0401), (576943, 0x0401), (576951, 0x0204), (576952, 0x0402),
+    (576953, 0x0401), (576954, 0x0401), (576962, 0x0204), (576963, 0x0402), (576964, 0x0401), (576965, 0x0401), (576973, 0x0204), (576974, 0x0403),
+    (576975, 0x0401), (576976, 0x0401), (576984, 0x0204), (576985, 0x0402), (576986, 0x0401), (576995, 0x0204), (576996, 0x0402), (577072, 0x0204),
+    (577073, 0x0402), (577074, 0x0401), (577075, 0x0401), (577083, 0x0204), (577084, 0x0402), (577085, 0x0401), (577086, 0x0401), (577094, 0x0204),
+    (577095, 0x0403), (577096, 0x0401), (577097, 0x0401), (577105, 0x0204), (577106, 0x0403), (577107, 0x0401), (577108, 0x0401), (577116, 0x0204),
+    (577117, 0x0403), (577118, 0x0401), (577204, 0x0204), (577205, 0x0401), (577206, 0x0401), (577207, 0x0401), (577215, 0x0204), (577216, 0x0401),
+    (577217, 0x0401), (577218, 0x0401), (577226, 0x0104), (577227, 0x0403), (577228, 0x0401), (577229, 0x0401), (577237, 0x0204), (577238, 0x0403),
+    (577239, 0x0402), (577240, 0x0401), (577248, 0x0104), (577249, 0x0403), (577250, 0x0402), (577336, 0x0403), (577337, 0x0401), (577338, 0x0401),
+    (577347, 0x0404), (577348, 0x0401), (577349, 0x0401), (577358, 0x0304), (577359, 0x0401), (577360, 0x0401), (577369, 0x0403), (577370, 0x0401),
+    (577468, 0x0304), (577469, 0x0401), (577470, 0x0401), (577479, 0x0304), (577480, 0x0401), (577481, 0x0401), (577490, 0x0304), (577491, 0x0401),
+    (577492, 0x0401), (577501, 0x0204), (577502, 0x0402), (577600, 0x0104), (577601, 0x0401), (577602, 0x0401), (577603, 0x0401), (577611, 0x0204),
+    (577612, 0x0402), (577613, 0x0401), (577614, 0x0401), (577622, 0x0204), (577623, 0x0402), (577624, 0x0401), (577625, 0x0401), (577633, 0x0104),
+    (577634, 0x0402), (577635, 0x0401), (577732, 0x0204), (577733, 0x0401), (577734, 0x0401), (577743, 0x0204), (577744, 0x0401), (577745, 0x0401),
+    (577746, 0x0401), (577754, 0x0204), (577755, 0x0402), (577756, 0x0401), (577765, 0x0104), (577766, 0x0402), (577767, 0x0401), (577776, 0x0104),
+    (577777, 0x0402), (577864, 0x0304), (577865, 0x0401), (577866, 0x0401), (577867, 0x0401), (577875, 0x0304), (577876, 0x0401), (577877, 0x0401),
+    (577878, 0x0401), (577886, 0x0304), (577887, 0x0402), (577888, 0x0401), (577889, 0x0401), (577897, 0x0204), (577898, 0x0403), (577899, 0x0402),
+    (577996, 0x0401), (577997, 0x0401), (577998, 0x0401), (578007, 0x0402), (578008, 0x0401), (578009, 0x0401), (578018, 0x0404), (578019, 0x0403),
+    (578020, 0x0401), (578128, 0x0404), (578129, 0x0401), (578130, 0x0401), (578139, 0x0404), (578140, 0x0401), (578141, 0x0401), (578150, 0x0404),
+    (578151, 0x0401), (578260, 0x0304), (578261, 0x0401), (578262, 0x0401), (578271, 0x0304), (578272, 0x0401), (578273, 0x0401), (578282, 0x0304),
+    (578283, 0x0401), (578293, 0x0304), (578392, 0x0304), (578393, 0x0401), (578394, 0x0401), (578403, 0x0304), (578404, 0x0401), (578405, 0x0401),
+    (578414, 0x0304), (578415, 0x0401), (578416, 0x0401), (578425, 0x0204), (578524, 0x0304), (578525, 0x0401), (578526, 0x0401), (578535, 0x0304),
+    (578536, 0x0401), (578537, 0x0401), (578546, 0x0304), (578547, 0x0402), (578548, 0x0401), (578656, 0x0403), (578657, 0x0401), (578658, 0x0401),
+    (578659, 0x0401), (578667, 0x0404), (578668, 0x0401), (578669, 0x0401), (578670, 0x0401), (578678, 0x0204), (578679, 0x0403), (578680, 0x0402),
+    (578681, 0x0401), (578689, 0x0204), (578690, 0x0204), (578788, 0x0401), (578789, 0x0401), (578790, 0x0401), (578799, 0x0403), (578800, 0x0401),
+    (578801, 0x0401), (578810, 0x0304), (578811, 0x0404), (578812, 0x0402), (578920, 0x0402), (578921, 0x0401), (578922, 0x0401), (578931, 0x0403),
+    (578932, 0x0401), (578933, 0x0401), (578942, 0x0404), (578943, 0x0401), (579052, 0x0304), (579053, 0x0401), (579054, 0x0401), (579063, 0x0404),
+    (579064, 0x0401), (579065, 0x0401), (579074, 0x0304), (579075, 0x0401), (579184, 0x0304), (579185, 0x0401), (579186, 0x0401), (579195, 0x0304),
+    (579196, 0x0401), (579197, 0x0401), (579206, 0x0304), (579207, 0x0401), (579208, 0x0401), (579316, 0x0304), (579317, 0x0401), (579318, 0x0401),
+    (579327, 0x0304), (579328, 0x0401), (579329, 0x0401), (579338, 0x0304), (579339, 0x0402), (579340, 0x0401), (579448, 0x0404), (579449, 0x0401),
+    (579450, 0x0401), (579459, 0x0304), (579460, 0x0401), (579461, 0x0401), (579470, 0x0204), (579471, 0x0402), (579472, 0x0401), (579580, 0x0403),
+    (579581, 0x0401), (579582, 0x0401), (579591, 0x0404), (579592, 0x0401), (579593, 0x0401), (579602, 0x0204), (579603, 0x0404), (579604, 0x0402),
+    (579712, 0x0402), (579713, 0x0401), (579714, 0x0401), (579723, 0x0403), (579724, 0x0401), (579725, 0x0401), (579734, 0x0404), (579735, 0x0404),
+    (579844, 0x0403), (579845, 0x0401), (579846, 0x0401), (579855, 0x0404), (579856, 0x0401), (579857, 0x0401), (579866, 0x0404), (579867, 0x0402),
+    (579868, 0x0401), (579976, 0x0304), (579977, 0x0401), (579978, 0x0401), (579987, 0x0404), (579988, 0x0401), (579989, 0x0401), (579990, 0x0401),
+    (579998, 0x0304), (579999, 0x0403), (580000, 0x0401), (580009, 0x0204), (580010, 0x0304), (580108, 0x0304), (580109, 0x0401), (580110, 0x0401),
+    (580111, 0x0401), (580119, 0x0304), (580120, 0x0401), (580121, 0x0401), (580122, 0x0401), (580130, 0x0204), (580131, 0x0404), (580132, 0x0401),
+    (580133, 0x0401), (580141, 0x0204), (580142, 0x0404), (580143, 0x0401), (580240, 0x0304), (580241, 0x0401), (580242, 0x0401), (580243, 0x0401),
+    (580251, 0x0204), (580252, 0x0401), (580253, 0x0401), (580254, 0x0401), (580262, 0x0304), (580263, 0x0404), (580264, 0x0401), (580265, 0x0401),
+    (580273, 0x0204), (580274, 0x0304), (580275, 0x0402), (580372, 0x0403), (580373, 0x0401), (580374, 0x0401), (580375, 0x0401), (580383, 0x0404),
+    (580384, 0x0401), (580385, 0x0401), (580386, 0x0401), (580394, 0x0304), (580395, 0x0403), (580396, 0x0401), (580397, 0x0401), (580405, 0x0204),
+    (580406, 0x0404), (580407, 0x0402), (580504, 0x0402), (580505, 0x0401), (580506, 0x0401), (580515, 0x0403), (580516, 0x0401), (580517, 0x0401),
+    (580526, 0x0404), (580527, 0x0402), (580528, 0x0401), (580636, 0x0404), (580637, 0x0401), (580638, 0x0401), (580647, 0x0404), (580648, 0x0401),
+    (580649, 0x0401), (580658, 0x0304), (580659, 0x0402), (580660, 0x0401), (580661, 0x0401), (580669, 0x0304), (580670, 0x0403), (580768, 0x0304),
+    (580769, 0x0401), (580770, 0x0401), (580771, 0x0401), (580779, 0x0304), (580780, 0x0401), (580781, 0x0401), (580782, 0x0401), (580790, 0x0304),
+    (580791, 0x0403), (580792, 0x0401), (580793, 0x0401), (580801, 0x0404), (580802, 0x0404), (580900, 0x0304), (580901, 0x0401), (580902, 0x0401),
+    (580903, 0x0401), (580911, 0x0304), (580912, 0x0401), (580913, 0x0401), (580914, 0x0401), (580922, 0x0204), (580923, 0x0403), (580924, 0x0401),
+    (580925, 0x0401), (580933, 0x0204), (580934, 0x0304), (580935, 0x0401), (581032, 0x0304), (581033, 0x0401), (581034, 0x0401), (581035, 0x0401),
+    (581043, 0x0304), (581044, 0x0401), (581045, 0x0401), (581046, 0x0401), (581054, 0x0204), (581055, 0x0403), (581056, 0x0401), (581057, 0x0401),
+    (581065, 0x0204), (581066, 0x0404), (581067, 0x0401), (581164, 0x0404), (581165, 0x0401), (581166, 0x0401), (581167, 0x0401), (581175, 0x0304),
+    (581176, 0x0401), (581177, 0x0401), (581178, 0x0401), (581186, 0x0204), (581187, 0x0404), (581188, 0x0401), (581189, 0x0401), (581197, 0x0204),
+    (581198, 0x0304), (581199, 0x0402), (581296, 0x0304), (581297, 0x0401), (581298, 0x0401), (581299, 0x0401), (581307, 0x0204), (581308, 0x0401),
+    (581309, 0x0401), (581310, 0x0401), (581318, 0x0204), (581319, 0x0403), (581320, 0x0401), (581321, 0x0401), (581329, 0x0304), (581330, 0x0304),
+    (581331, 0x0402), (581332, 0x0401), (581428, 0x0204), (581429, 0x0401), (581430, 0x0401), (581431, 0x0401), (581439, 0x0204), (581440, 0x0402),
+    (581441, 0x0401), (581442, 0x0401), (581450, 0x0104), (581451, 0x0304), (581452, 0x0401), (581453, 0x0401), (581454, 0x0401), (581461, 0x0104),
+    (581462, 0x0204), (581463, 0x0402), (581464, 0x0401), (581560, 0x0204), (581561, 0x0401), (581562, 0x0401), (581563, 0x0401), (581564, 0x0401),
+    (581571, 0x0104), (581572, 0x0402), (581573, 0x0401), (581574, 0x0401), (581575, 0x0401), (581582, 0x0104), (581583, 0x0304), (581584, 0x0402),
+    (581585, 0x0401), (581586, 0x0401), (581593, 0x0104), (581594, 0x0104), (581595, 0x0403), (581596, 0x0401), (581597, 0x0401), (581604, 0x0104),
+    (581605, 0x0104), (581692, 0x0204), (581693, 0x0401), (581694, 0x0401), (581695, 0x0401), (581703, 0x0104), (581704, 0x0402), (581705, 0x0401),
+    (581706, 0x0401), (581707, 0x0401), (581714, 0x0104), (581715, 0x0404), (581716, 0x0401), (581717, 0x0401), (581718, 0x0401), (581725, 0x0104),
+    (581726, 0x0204), (581727, 0x0403), (581728, 0x0401), (581824, 0x0104), (581825, 0x0401), (581826, 0x0401), (581827, 0x0401), (581828, 0x0401),
+    (581835, 0x0104), (581836, 0x0402), (581837, 0x0401), (581838, 0x0401), (581839, 0x0401), (581846, 0x0104), (581847, 0x0404), (581848, 0x0401),
+    (581849, 0x0401), (581850, 0x0401), (581857, 0x0104), (581858, 0x0204), (581859, 0x0403), (581860, 0x0401), (581861, 0x0401), (581956, 0x0104),
+    (581957, 0x0401), (581958, 0x0401), (581959, 0x0401), (581967, 0x0104), (581968, 0x0403), (581969, 0x0401), (581970, 0x0401), (581971, 0x0401),
+    (581978, 0x0104), (581979, 0x0304), (581980, 0x0402), (581981, 0x0401), (581982, 0x0401), (581989, 0x0104), (581990, 0x0204), (581991, 0x0403),
+    (581992, 0x0401), (581993, 0x0401), (582484, 0x0104), (582485, 0x0304), (582486, 0x0402), (582487, 0x0401), (582488, 0x0401), (582495, 0x0104),
+    (582496, 0x0204), (582497, 0x0404), (582498, 0x0401), (582499, 0x0401), (582506, 0x0104), (582507, 0x0204), (582508, 0x0404), (582509, 0x0401),
+    (582510, 0x0401), (582511, 0x0401), (582517, 0x0104), (582518, 0x0104), (582519, 0x0304), (582520, 0x0402), (582521, 0x0401), (582522, 0x0401),
+    (582528, 0x0104), (582529, 0x0104), (582530, 0x0304), (582531, 0x0403), (582532, 0x0401), (582533, 0x0401), (582539, 0x0104), (582540, 0x0104),
+    (582541, 0x0204), (582542, 0x0404), (582543, 0x0402), (582544, 0x0401), (582550, 0x0104), (582551, 0x0104), (582552, 0x0204), (582553, 0x0403),
+    (582554, 0x0401), (582561, 0x0104), (582562, 0x0104), (582563, 0x0304), (582564, 0x0403), (582616, 0x0104), (582617, 0x0304), (582618, 0x0401),
+    (582619, 0x0401), (582620, 0x0401), (582627, 0x0104), (582628, 0x0304), (582629, 0x0402), (582630, 0x0401), (582631, 0x0401), (582638, 0x0104),
+    (582639, 0x0204), (582640, 0x0403), (582641, 0x0401), (582642, 0x0401), (582649, 0x0104), (582650, 0x0204), (582651, 0x0403), (582652, 0x0401),
+    (582653, 0x0401), (582660, 0x0104), (582661, 0x0204), (582662, 0x0403), (582663, 0x0402), (582664, 0x0401), (582671, 0x0104), (582672, 0x0204),
+    (582673, 0x0404), (582674, 0x0401), (582675, 0x0401), (582682, 0x0104), (582683, 0x0204), (582684, 0x0403), (582685, 0x0402), (582693, 0x0104),
+    (582694, 0x0304), (582748, 0x0104), (582749, 0x0304), (582750, 0x0402), (582751, 0x0401), (582752, 0x0401), (582759, 0x0104), (582760, 0x0204),
+    (582761, 0x0402), (582762, 0x0401), (582763, 0x0401), (582770, 0x0104), (582771, 0x0204), (582772, 0x0403), (582773, 0x0401), (582774, 0x0401),
+    (582781, 0x0104), (582782, 0x0204), (582783, 0x0404), (582784, 0x0401), (582785, 0x0401), (582786, 0x0401), (582792, 0x0104), (582793, 0x0204),
+    (582794, 0x0404), (582795, 0x0402), (582796, 0x0401), (582797, 0x0401), (582803, 0x0104), (582804, 0x0204), (582805, 0x0404), (582806, 0x0402),
+    (582807, 0x0401), (582814, 0x0104), (582815, 0x0104), (582816, 0x0404), (582817, 0x0401), (582825, 0x0104), (582880, 0x0104), (582881, 0x0403),
+    (582882, 0x0401), (582883, 0x0401), (582891, 0x0104), (582892, 0x0403), (582893, 0x0401), (582894, 0x0401), (582895, 0x0401), (582902, 0x0104),
+    (582903, 0x0404), (582904, 0x0401), (582905, 0x0401), (582906, 0x0401), (582913, 0x0104), (582914, 0x0304), (582915, 0x0401), (582916, 0x0401),
+    (582917, 0x0401), (582924, 0x0104), (582925, 0x0404), (582926, 0x0402), (582927, 0x0402), (582935, 0x0204), (582936, 0x0304), (582937, 0x0401),
+    (583012, 0x0104), (583013, 0x0304), (583014, 0x0401), (583015, 0x0401), (583023, 0x0104), (583024, 0x0304), (583025, 0x0401), (583026, 0x0401),
+    (583027, 0x0401), (583034, 0x0104), (583035, 0x0304), (583036, 0x0401), (583037, 0x0401), (583038, 0x0401), (583045, 0x0104), (583046, 0x0204),
+    (583047, 0x0402), (583048, 0x0401), (583049, 0x0401), (583056, 0x0104), (583057, 0x0304), (583058, 0x0402), (583059, 0x0401), (583067, 0x0104),
+    (583068, 0x0404), (583069, 0x0401), (583078, 0x0104), (583079, 0x0403), (583144, 0x0104), (583145, 0x0404), (583146, 0x0401), (583147, 0x0401),
+    (583148, 0x0401), (583155, 0x0104), (583156, 0x0304), (583157, 0x0401), (583158, 0x0401), (583159, 0x0401), (583166, 0x0104), (583167, 0x0304),
+    (583168, 0x0401), (583169, 0x0401), (583170, 0x0401), (583177, 0x0104), (583178, 0x0204), (583179, 0x0402), (583180, 0x0401), (583181, 0x0401),
+    (583188, 0x0104), (583189, 0x0204), (583190, 0x0402), (583191, 0x0401), (583192, 0x0401), (583199, 0x0104), (583200, 0x0404), (583201, 0x0401),
+    (583202, 0x0402), (583210, 0x0104), (583211, 0x0304), (583212, 0x0401), (583276, 0x0104), (583277, 0x0402), (583278, 0x0401), (583279, 0x0401),
+    (583287, 0x0104), (583288, 0x0402), (583289, 0x0401), (583290, 0x0401), (583298, 0x0104), (583299, 0x0403), (583300, 0x0401), (583301, 0x0401),
+    (583309, 0x0104), (583310, 0x0403), (583311, 0x0402), (583312, 0x0401), (583320, 0x0104), (583321, 0x0403), (583322, 0x0401), (583323, 0x0401),
+    (583331, 0x0104), (583332, 0x0404), (583333, 0x0401), (583408, 0x0104), (583409, 0x0403), (583410, 0x0401), (583411, 0x0401), (583419, 0x0104),
+    (583420, 0x0403), (583421, 0x0401), (583422, 0x0401), (583423, 0x0401), (583430, 0x0104), (583431, 0x0404), (583432, 0x0401), (583433, 0x0401),
+    (583434, 0x0401), (583441, 0x0104), (583442, 0x0304), (583443, 0x0401), (583444, 0x0401), (583452, 0x0104), (583453, 0x0404), (583454, 0x0401),
+    (583455, 0x0401), (583463, 0x0104), (583464, 0x0304), (583465, 0x0401), (583474, 0x0104), (583540, 0x0104), (583541, 0x0403), (583542, 0x0401),
+    (583543, 0x0401), (583551, 0x0104), (583552, 0x0403), (583553, 0x0401), (583554, 0x0401), (583555, 0x0401), (583562, 0x0104), (583563, 0x0404),
+    (583564, 0x0401), (583565, 0x0401), (583566, 0x0401), (583573, 0x0104), (583574, 0x0304), (583575, 0x0402), (583576, 0x0401), (583577, 0x0401),
+    (583584, 0x0104), (583585, 0x0304), (583586, 0x0403), (583587, 0x0401), (583595, 0x0104), (583596, 0x0204), (583672, 0x0104), (583673, 0x0402),
+    (583674, 0x0401), (583675, 0x0401), (583683, 0x0104), (583684, 0x0403), (583685, 0x0401), (583686, 0x0401), (583687, 0x0401), (583694, 0x0104),
+    (583695, 0x0404), (583696, 0x0401), (583697, 0x0401), (583698, 0x0401), (583705, 0x0104), (583706, 0x0304), (583707, 0x0402), (583708, 0x0401),
+    (583709, 0x0401), (583716, 0x0104), (583717, 0x0204), (583718, 0x0304), (583719, 0x0402), (583720, 0x0401), (583804, 0x0304), (583805, 0x0401),
+    (583806, 0x0401), (583807, 0x0401), (583815, 0x0204), (583816, 0x0401), (583817, 0x0401), (583818, 0x0401), (583826, 0x0204), (583827, 0x0402),
+    (583828, 0x0401), (583829, 0x0401), (583837, 0x0204), (583838, 0x0402), (583839, 0x0402), (583936, 0x0204), (583937, 0x0401), (583938, 0x0401),
+    (583939, 0x0401), (583947, 0x0204), (583948, 0x0401), (583949, 0x0401), (583950, 0x0401), (583958, 0x0204), (583959, 0x0402), (583960, 0x0401),
+    (583961, 0x0401), (583969, 0x0204), (583970, 0x0403), (583971, 0x0401), (584068, 0x0104), (584069, 0x0403), (584070, 0x0401), (584071, 0x0401),
+    (584079, 0x0104), (584080, 0x0403), (584081, 0x0401), (584082, 0x0401), (584090, 0x0104), (584091, 0x0403), (584092, 0x0401), (584093, 0x0401),
+    (584101, 0x0104), (584102, 0x0404), (584103, 0x0401), (584104, 0x0401), (584112, 0x0104), (584200, 0x0104), (584201, 0x0402), (584202, 0x0401),
+    (584203, 0x0401), (584211, 0x0104), (584212, 0x0402), (584213, 0x0401), (584214, 0x0401), (584222, 0x0104), (584223, 0x0403), (584224, 0x0401),
+    (584225, 0x0401), (584233, 0x0104), (584234, 0x0404), (584235, 0x0402), (584236, 0x0401), (584244, 0x0104), (584245, 0x0204), (584246, 0x0403),
+    (584332, 0x0204), (584333, 0x0401), (584334, 0x0401), (584335, 0x0401), (584343, 0x0204), (584344, 0x0402), (584345, 0x0401), (584346, 0x0401),
+    (584354, 0x0204), (584355, 0x0404), (584356, 0x0401), (584357, 0x0401), (584365, 0x0104), (584366, 0x0204), (584367, 0x0402), (584368, 0x0401),
+    (584464, 0x0403), (584465, 0x0401), (584466, 0x0401), (584475, 0x0404), (584476, 0x0401), (584477, 0x0401), (584486, 0x0304), (584487, 0x0403),
+    (584488, 0x0401), (584596, 0x0204), (584597, 0x0401), (584598, 0x0401), (584599, 0x0401), (584607, 0x0304), (584608, 0x0401), (584609, 0x0401),
+    (584618, 0x0204), (584619, 0x0402), (584620, 0x0401), (584629, 0x0304), (584728, 0x0204), (584729, 0x0401), (584730, 0x0401), (584739, 0x0204),
+    (584740, 0x0401), (584741, 0x0401), (584750, 0x0204), (584751, 0x0402), (584752, 0x0401), (584761, 0x0204), (584762, 0x0403), (584763, 0x0401),
+    (584860, 0x0204), (584861, 0x0401), (584862, 0x0401), (584863, 0x0401), (584871, 0x0204), (584872, 0x0402), (584873, 0x0401), (584874, 0x0401),
+    (584882, 0x0204), (584883, 0x0403), (584884, 0x0401), (584885, 0x0401), (584893, 0x0104), (584894, 0x0204), (584895, 0x0402), (584992, 0x0204),
+    (584993, 0x0401), (584994, 0x0401), (584995, 0x0401), (585003, 0x0204), (585004, 0x0402), (585005, 0x0401), (585006, 0x0401), (585014, 0x0204),
+    (585015, 0x0404), (585016, 0x0401), (585017, 0x0401), (585025, 0x0104), (585026, 0x0304), (585027, 0x0402), (585124, 0x0404), (585125, 0x0401),
+    (585126, 0x0401), (585127, 0x0401), (585135, 0x0204), (585136, 0x0402), (585137, 0x0401), (585138, 0x0401), (585146, 0x0204), (585147, 0x0304),
+    (585148, 0x0402), (585149, 0x0401), (585157, 0x0104), (585158, 0x0104), (585159, 0x0304), (585160, 0x0401), (585256, 0x0401), (585257, 0x0401),
+    (585258, 0x0401), (585259, 0x0401), (585267, 0x0404), (585268, 0x0401), (585269, 0x0401), (585270, 0x0401), (585278, 0x0204), (585279, 0x0304),
+    (585280, 0x0402), (585281, 0x0401), (585388, 0x0404), (585389, 0x0401), (585390, 0x0401), (585399, 0x0304), (585400, 0x0401), (585401, 0x0401),
+    (585410, 0x0204), (585411, 0x0402), (585412, 0x0401), (585520, 0x0204), (585521, 0x0401), (585522, 0x0401), (585531, 0x0204), (585532, 0x0401),
+    (585533, 0x0401), (585534, 0x0401), (585542, 0x0204), (585543, 0x0403), (585544, 0x0401), (585553, 0x0204), (585554, 0x0404), (585652, 0x0204),
+    (585653, 0x0401), (585654, 0x0401), (585655, 0x0401), (585663, 0x0204), (585664, 0x0402), (585665, 0x0401), (585666, 0x0401), (585674, 0x0204),
+    (585675, 0x0403), (585676, 0x0401), (585677, 0x0401), (585685, 0x0204), (585686, 0x0404), (585687, 0x0402), (585784, 0x0204), (585785, 0x0401),
+    (585786, 0x0401), (585787, 0x0401), (585795, 0x0204), (585796, 0x0402), (585797, 0x0401), (585798, 0x0401), (585806, 0x0204), (585807, 0x0403),
+    (585808, 0x0401), (585809, 0x0401), (585817, 0x0204), (585818, 0x0304), (585819, 0x0401), (585916, 0x0304), (585917, 0x0401), (585918, 0x0401),
+    (585919, 0x0401), (585927, 0x0204), (585928, 0x0401), (585929, 0x0401), (585930, 0x0401), (585938, 0x0204), (585939, 0x0403), (585940, 0x0401),
+    (585941, 0x0401), (585949, 0x0204), (585950, 0x0403), (586048, 0x0404), (586049, 0x0401), (586050, 0x0401), (586051, 0x0401), (586059, 0x0304),
+    (586060, 0x0401), (586061, 0x0401), (586062, 0x0401), (586070, 0x0304), (586071, 0x0402), (586072, 0x0402), (586081, 0x0304), (586180, 0x0403),
+    (586181, 0x0401), (586182, 0x0401), (586191, 0x0404), (586192, 0x0401), (586193, 0x0401), (586194, 0x0401), (586202, 0x0304), (586203, 0x0403),
+    (586204, 0x0401), (586213, 0x0204), (586214, 0x0404), (586312, 0x0304), (586313, 0x0401), (586314, 0x0401), (586315, 0x0401), (586323, 0x0204),
+    (586324, 0x0402), (586325, 0x0401), (586326, 0x0401), (586334, 0x0204), (586335, 0x0404), (586336, 0x0401), (586337, 0x0401), (586345, 0x0204),
+    (586346, 0x0304), (586347, 0x0401), (586444, 0x0204), (586445, 0x0401), (586446, 0x0401), (586447, 0x0401), (586455, 0x0204), (586456, 0x0402),
+    (586457, 0x0401), (586458, 0x0401), (586466, 0x0204), (586467, 0x0404), (586468, 0x0401), (586469, 0x0401), (586477, 0x0104), (586478, 0x0304),
+    (586479, 0x0402), (586480, 0x0401), (586576, 0x0204), (586577, 0x0402), (586578, 0x0401), (586579, 0x0401), (586587, 0x0204), (586588, 0x0402),
+    (586589, 0x0401), (586590, 0x0401), (586598, 0x0104), (586599, 0x0404), (586600, 0x0401), (586601, 0x0401), (586609, 0x0104), (586610, 0x0204),
+    (586611, 0x0402), (586612, 0x0401), (586620, 0x0104), (586621, 0x0204), (586708, 0x0204), (586709, 0x0401), (586710, 0x0401), (586711, 0x0401),
+    (586719, 0x0204), (586720, 0x0402), (586721, 0x0401), (586722, 0x0401), (586730, 0x0104), (586731, 0x0304), (586732, 0x0401), (586733, 0x0401),
+    (586734, 0x0401), (586741, 0x0104), (586742, 0x0204), (586743, 0x0403), (586744, 0x0401), (586745, 0x0401), (586752, 0x0104), (586753, 0x0104),
+    (586754, 0x0404), (586840, 0x0404), (586841, 0x0401), (586842, 0x0401), (586843, 0x0401), (586851, 0x0204), (586852, 0x0402), (586853, 0x0401),
+    (586854, 0x0401), (586862, 0x0204), (586863, 0x0404), (586864, 0x0401), (586865, 0x0401), (586866, 0x0401), (586873, 0x0104), (586874, 0x0204),
+    (586875, 0x0403), (586876, 0x0401), (586877, 0x0401), (586884, 0x0104), (586885, 0x0104), (586886, 0x0304), (586972, 0x0404), (586973, 0x0401),
+    (586974, 0x0401), (586975, 0x0401), (586983, 0x0304), (586984, 0x0401), (586985, 0x0401), (586986, 0x0401), (586994, 0x0204), (586995, 0x0403),
+    (586996, 0x0401), (586997, 0x0401), (587005, 0x0204), (587006, 0x0204), (587007, 0x0402), (587008, 0x0401), (587104, 0x0304), (587105, 0x0401),
+    (587106, 0x0401), (587107, 0x0401), (587115, 0x0204), (587116, 0x0401), (587117, 0x0401), (587118, 0x0401), (587126, 0x0204), (587127, 0x0404),
+    (587128, 0x0401), (587129, 0x0401), (587137, 0x0204), (587138, 0x0204), (587139, 0x0402), (587140, 0x0401), (587236, 0x0204), (587237, 0x0401),
+    (587238, 0x0401), (587239, 0x0401), (587247, 0x0204), (587248, 0x0402), (587249, 0x0401), (587250, 0x0401), (587258, 0x0104), (587259, 0x0304),
+    (587260, 0x0401), (587261, 0x0401), (587262, 0x0401), (587269, 0x0104), (587270, 0x0204), (587271, 0x0402), (587272, 0x0401), (587368, 0x0204),
+    (587369, 0x0401), (587370, 0x0401), (587371, 0x0401), (587379, 0x0104), (587380, 0x0402), (587381, 0x0401), (587382, 0x0401), (587383, 0x0401),
+    (587390, 0x0104), (587391, 0x0304), (587392, 0x0401), (587393, 0x0401), (587394, 0x0401), (587401, 0x0104), (587402, 0x0204), (587403, 0x0402),
+    (587404, 0x0401), (587405, 0x0401), (587412, 0x0204), (587500, 0x0204), (587501, 0x0401), (587502, 0x0401), (587503, 0x0401), (587504, 0x0401),
+    (587511, 0x0104), (587512, 0x0402), (587513, 0x0401), (587514, 0x0401), (587515, 0x0401), (587522, 0x0104), (587523, 0x0304), (587524, 0x0401),
+    (587525, 0x0401), (587526, 0x0401), (587533, 0x0104), (587534, 0x0204), (587535, 0x0403), (587536, 0x0401), (587537, 0x0401), (587544, 0x0104),
+    (587545, 0x0204), (587632, 0x0204), (587633, 0x0401), (587634, 0x0401), (587635, 0x0401), (587636, 0x0401), (587643, 0x0104), (587644, 0x0402),
+    (587645, 0x0401), (587646, 0x0401), (587647, 0x0401), (587654, 0x0104), (587655, 0x0404), (587656, 0x0401), (587657, 0x0401), (587658, 0x0401),
+    (587665, 0x0104), (587666, 0x0204), (587667, 0x0402), (587668, 0x0401), (587669, 0x0401), (587676, 0x0104), (587677, 0x0104), (587678, 0x0304),
+    (587764, 0x0104), (587765, 0x0401), (587766, 0x0401), (587767, 0x0401), (587768, 0x0401), (587775, 0x0104), (587776, 0x0403), (587777, 0x0401),
+    (587778, 0x0401), (587779, 0x0401), (587786, 0x0104), (587787, 0x0304), (587788, 0x0401), (587789, 0x0401), (587790, 0x0401), (587797, 0x0104),
+    (587798, 0x0204), (587799, 0x0403), (587800, 0x0401), (587801, 0x0401), (587896, 0x0104), (587897, 0x0401), (587898, 0x0401), (587899, 0x0401),
+    (587900, 0x0401), (587907, 0x0104), (587908, 0x0404), (587909, 0x0401), (587910, 0x0401), (587911, 0x0401), (587918, 0x0104), (587919, 0x0304),
+    (587920, 0x0402), (587921, 0x0401), (587922, 0x0401), (587929, 0x0104), (587930, 0x0104), (587931, 0x0403), (587932, 0x0401), (587933, 0x0401),
+    (587940, 0x0104), (588028, 0x0104), (588029, 0x0401), (588030, 0x0401), (588031, 0x0401), (588032, 0x0401), (588039, 0x0104), (588040, 0x0404),
+    (588041, 0x0401), (588042, 0x0401), (588043, 0x0401), (588050, 0x0104), (588051, 0x0204), (588052, 0x0402), (588053, 0x0401), (588054, 0x0401),
+    (588061, 0x0104), (588062, 0x0104), (588063, 0x0404), (588064, 0x0401), (588065, 0x0401), (588072, 0x0104), (588073, 0x0104), (588074, 0x0204),
+    (588075, 0x0401), (588160, 0x0104), (588161, 0x0402), (588162, 0x0401), (588163, 0x0401), (588171, 0x0104), (588172, 0x0404), (588173, 0x0401),
+    (588174, 0x0401), (588175, 0x0401), (588182, 0x0104), (588183, 0x0204), (588184, 0x0402), (588185, 0x0401), (588186, 0x0401), (588193, 0x0104),
+    (588194, 0x0204), (588195, 0x0403), (588196, 0x0401), (588952, 0x0104), (588953, 0x0204), (588954, 0x0404), (588955, 0x0402), (588956, 0x0401),
+    (588957, 0x0401), (588963, 0x0104), (588964, 0x0204), (588965, 0x0304), (588966, 0x0402), (588967, 0x0401), (588968, 0x0401), (588974, 0x0104),
+    (588975, 0x0104), (588976, 0x0204), (588977, 0x0403), (588978, 0x0401), (588979, 0x0401), (588980, 0x0401), (588985, 0x0104), (588986, 0x0104),
+    (588987, 0x0204), (588988, 0x0403), (588989, 0x0401), (588990, 0x0401), (588991, 0x0401), (588996, 0x0104), (588997, 0x0104), (588998, 0x0104),
+    (588999, 0x0404), (589000, 0x0402), (589001, 0x0401), (589002, 0x0401), (589007, 0x0104), (589008, 0x0104), (589009, 0x0104), (589010, 0x0204),
+    (589011, 0x0403), (589012, 0x0401), (589013, 0x0401), (589018, 0x0104), (589019, 0x0104), (589020, 0x0204), (589021, 0x0204), (589022, 0x0404),
+    (589023, 0x0402), (589024, 0x0401), (589029, 0x0104), (589030, 0x0104), (589031, 0x0204), (589032, 0x0404), (589033, 0x0403), (589034, 0x0403),
+    (589040, 0x0104), (589041, 0x0104), (589084, 0x0104), (589085, 0x0204), (589086, 0x0403), (589087, 0x0401), (589088, 0x0401), (589095, 0x0104),
+    (589096, 0x0204), (589097, 0x0403), (589098, 0x0401), (589099, 0x0401), (589100, 0x0401), (589106, 0x0104), (589107, 0x0204), (589108, 0x0404),
+    (589109, 0x0402), (589110, 0x0401), (589111, 0x0401), (589117, 0x0104), (589118, 0x0104), (589119, 0x0304), (589120, 0x0402), (589121, 0x0401),
+    (589122, 0x0401), (589128, 0x0104), (589129, 0x0104), (589130, 0x0204), (589131, 0x0403), (589132, 0x0401), (589133, 0x0401), (589139, 0x0104),
+    (589140, 0x0104), (589141, 0x0204), (589142, 0x0403), (589143, 0x0401), (589144, 0x0401), (589150, 0x0104), (589151, 0x0104), (589152, 0x0304),
+    (589153, 0x0403), (589154, 0x0401), (589161, 0x0104), (589162, 0x0104), (589163, 0x0403), (589164, 0x0401), (589216, 0x0104), (589217, 0x0204),
+    (589218, 0x0403), (589219, 0x0401), (589220, 0x0401), (589227, 0x0104), (589228, 0x0204), (589229, 0x0403), (589230, 0x0401), (589231, 0x0401),
+    (589232, 0x0401), (589238, 0x0104), (589239, 0x0104), (589240, 0x0404), (589241, 0x0401), (589242, 0x0401), (589243, 0x0401), (589249, 0x0104),
+    (589250, 0x0104), (589251, 0x0304), (589252, 0x0402), (589253, 0x0401), (589254, 0x0401), (589260, 0x0104), (589261, 0x0104), (589262, 0x0204),
+    (589263, 0x0403), (589264, 0x0401), (589265, 0x0401), (589271, 0x0104), (589272, 0x0104), (589273, 0x0204), (589274, 0x0404), (589275, 0x0402),
+    (589276, 0x0401), (589282, 0x0104), (589283, 0x0104), (589284, 0x0204), (589285, 0x0404), (589286, 0x0402), (589287, 0x0401), (589293, 0x0104),
+    (589294, 0x0104), (589295, 0x0204), (589296, 0x0403), (589348, 0x0104), (589349, 0x0304), (589350, 0x0401), (589351, 0x0401), (589352, 0x0401),
+    (589359, 0x0104), (589360, 0x0304), (589361, 0x0401), (589362, 0x0401), (589363, 0x0401), (589370, 0x0104), (589371, 0x0204), (589372, 0x0402),
+    (589373, 0x0401), (589374, 0x0401), (589381, 0x0104), (589382, 0x0104), (589383, 0x0403), (589384, 0x0401), (589385, 0x0401), (589386, 0x0401),
+    (589392, 0x0104), (589393, 0x0104), (589394, 0x0404), (589395, 0x0402), (589396, 0x0401), (589397, 0x0401), (589403, 0x0104), (589404, 0x0104),
+    (589405, 0x0404), (589406, 0x0403), (589407, 0x0402), (589480, 0x0104), (589481, 0x0304), (589482, 0x0402), (589483, 0x0401), (589484, 0x0401),
+    (589491, 0x0104), (589492, 0x0204), (589493, 0x0403), (589494, 0x0401), (589495, 0x0401), (589502, 0x0104), (589503, 0x0204), (589504, 0x0403),
+    (589505, 0x0401), (589506, 0x0401), (589507, 0x0401), (589513, 0x0104), (589514, 0x0104), (589515, 0x0404), (589516, 0x0401), (589517, 0x0401),
+    (589518, 0x0401), (589524, 0x0104), (589525, 0x0104), (589526, 0x0304), (589527, 0x0402), (589528, 0x0401), (589529, 0x0401), (589535, 0x0104),
+    (589536, 0x0104), (589537, 0x0404), (589538, 0x0402), (589539, 0x0401), (589546, 0x0104), (589547, 0x0204), (589548, 0x0404), (589612, 0x0104),
+    (589613, 0x0304), (589614, 0x0402), (589615, 0x0401), (589616, 0x0401), (589623, 0x0104), (589624, 0x0204), (589625, 0x0402), (589626, 0x0401),
+    (589627, 0x0401), (589634, 0x0104), (589635, 0x0204), (589636, 0x0403), (589637, 0x0401), (589638, 0x0401), (589639, 0x0401), (589645, 0x0104),
+    (589646, 0x0104), (589647, 0x0404), (589648, 0x0401), (589649, 0x0401), (589650, 0x0401), (589656, 0x0104), (589657, 0x0104), (589658, 0x0304),
+    (589659, 0x0403), (589660, 0x0401), (589661, 0x0401), (589667, 0x0104), (589668, 0x0104), (589669, 0x0304), (589670, 0x0403), (589671, 0x0402),
+    (589672, 0x0401), (589678, 0x0104), (589679, 0x0104), (589680, 0x0304), (589681, 0x0403), (589744, 0x0104), (589745, 0x0403), (589746, 0x0401),
+    (589747, 0x0401), (589748, 0x0401), (589755, 0x0104), (589756, 0x0404), (589757, 0x0401), (589758, 0x0401), (589759, 0x0401), (589766, 0x0104),
+    (589767, 0x0304), (589768, 0x0402), (589769, 0x0401), (589770, 0x0401), (589777, 0x0104), (589778, 0x0204), (589779, 0x0403), (589780, 0x0401),
+    (589781, 0x0401), (589788, 0x0104), (589789, 0x0204), (589790, 0x0404), (589791, 0x0403), (589792, 0x0402), (589799, 0x0104), (589800, 0x0104),
+    (589801, 0x0204), (589802, 0x0404), (589803, 0x0401), (589876, 0x0104), (589877, 0x0304), (589878, 0x0401), (589879, 0x0401), (589880, 0x0401),
+    (589887, 0x0104), (589888, 0x0304), (589889, 0x0402), (589890, 0x0401), (589891, 0x0401), (589898, 0x0104), (589899, 0x0204), (589900, 0x0402),
+    (589901, 0x0401), (589902, 0x0401), (589909, 0x0104), (589910, 0x0204), (589911, 0x0403), (589912, 0x0401), (589913, 0x0401), (589920, 0x0104),
+    (589921, 0x0204), (589922, 0x0404), (589923, 0x0402), (589924, 0x0401), (589931, 0x0104), (589932, 0x0104), (589933, 0x0204), (589934, 0x0402),
+    (589942, 0x0104), (589943, 0x0104), (590008, 0x0104), (590009, 0x0304), (590010, 0x0401), (590011, 0x0401), (590012, 0x0401), (590019, 0x0104),
+    (590020, 0x0204), (590021, 0x0402), (590022, 0x0401), (590023, 0x0401), (590030, 0x0104), (590031, 0x0204), (590032, 0x0402), (590033, 0x0401),
+    (590034, 0x0401), (590041, 0x0104), (590042, 0x0204), (590043, 0x0403), (590044, 0x0401), (590045, 0x0401), (590046, 0x0401), (590052, 0x0104),
+    (590053, 0x0104), (590054, 0x0304), (590055, 0x0403), (590056, 0x0401), (590057, 0x0401), (590063, 0x0104), (590064, 0x0104), (590065, 0x0204),
+    (590066, 0x0404), (590067, 0x0401), (590140, 0x0104), (590141, 0x0404), (590142, 0x0401), (590143, 0x0401), (590144, 0x0401), (590151, 0x0104),
+    (590152, 0x0304), (590153, 0x0402), (590154, 0x0401), (590155, 0x0401), (590162, 0x0104), (590163, 0x0304), (590164, 0x0402), (590165, 0x0401),
+    (590166, 0x0401), (590173, 0x0104), (590174, 0x0204), (590175, 0x0404), (590176, 0x0402), (590177, 0x0401), (590178, 0x0401), (590184, 0x0104),
+    (590185, 0x0104), (590186, 0x0204), (590187, 0x0404), (590188, 0x0401), (590189, 0x0401), (590195, 0x0104), (590196, 0x0104), (590197, 0x0104),
+    (590198, 0x0204), (590199, 0x0402), (590272, 0x0204), (590273, 0x0401), (590274, 0x0401), (590275, 0x0401), (590283, 0x0204), (590284, 0x0402),
+    (590285, 0x0401), (590286, 0x0401), (590294, 0x0104), (590295, 0x0404), (590296, 0x0402), (590297, 0x0401), (590298, 0x0401), (590305, 0x0104),
+    (590306, 0x0204), (590307, 0x0403), (590308, 0x0401), (590309, 0x0401), (590316, 0x0104), (590317, 0x0104), (590404, 0x0104), (590405, 0x0402),
+    (590406, 0x0401), (590407, 0x0401), (590415, 0x0104), (590416, 0x0403), (590417, 0x0401), (590418, 0x0401), (590426, 0x0104), (590427, 0x0404),
+    (590428, 0x0401), (590429, 0x0401), (590430, 0x0401), (590437, 0x0104), (590438, 0x0304), (590439, 0x0402), (590440, 0x0401), (590448, 0x0104),
+    (590449, 0x0104), (590450, 0x0403), (590536, 0x0104), (590537, 0x0404), (590538, 0x0401), (590539, 0x0401), (590547, 0x0104), (590548, 0x0304),
+    (590549, 0x0401), (590550, 0x0401), (590558, 0x0104), (590559, 0x0304), (590560, 0x0401), (590561, 0x0401), (590562, 0x0401), (590569, 0x0104),
+    (590570, 0x0204), (590571, 0x0402), (590572, 0x0401), (590573, 0x0401), (590580, 0x0104), (590581, 0x0104), (590582, 0x0403), (590583, 0x0401),
+    (590668, 0x0104), (590669, 0x0403), (590670, 0x0401), (590671, 0x0401), (590679, 0x0104), (590680, 0x0404), (590681, 0x0401), (590682, 0x0401),
+    (590683, 0x0401), (590690, 0x0104), (590691, 0x0304), (590692, 0x0402), (590693, 0x0401), (590694, 0x0401), (590701, 0x0104), (590702, 0x0204),
+    (590703, 0x0403), (590704, 0x0401), (590705, 0x0401), (590712, 0x0104), (590713, 0x0104), (590714, 0x0204), (590715, 0x0402), (590800, 0x0104),
+    (590801, 0x0402), (590802, 0x0401), (590803, 0x0401), (590811, 0x0104), (590812, 0x0403), (590813, 0x0401), (590814, 0x0401), (590822, 0x0104),
+    (590823, 0x0304), (590824, 0x0402), (590825, 0x0401), (590826, 0x0401), (590833, 0x0104), (590834, 0x0204), (590835, 0x0404), (590836, 0x0401),
+    (590837, 0x0401), (590932, 0x0304), (590933, 0x0401), (590934, 0x0401), (590935, 0x0401), (590943, 0x0304), (590944, 0x0402), (590945, 0x0401),
+    (590946, 0x0401), (590954, 0x0204), (590955, 0x0404), (590956, 0x0401), (590957, 0x0401), (590965, 0x0204), (590966, 0x0304), (591064, 0x0204),
+    (591065, 0x0402), (591066, 0x0401), (591067, 0x0401), (591075, 0x0204), (591076, 0x0403), (591077, 0x0401), (591078, 0x0401), (591086, 0x0104),
+    (591087, 0x0404), (591088, 0x0401), (591089, 0x0401), (591097, 0x0104), (591098, 0x0204), (591099, 0x0402), (591100, 0x0401), (591196, 0x0104),
+    (591197, 0x0402), (591198, 0x0401), (591199, 0x0401), (591207, 0x0104), (591208, 0x0403), (591209, 0x0401), (591210, 0x0401), (591218, 0x0104),
+    (591219, 0x0304), (591220, 0x0402), (591221, 0x0401), (591229, 0x0104), (591230, 0x0204), (591231, 0x0403), (591232, 0x0401), (591240, 0x0104),
+    (591241, 0x0104), (591242, 0x0404), (591328, 0x0104), (591329, 0x0403), (591330, 0x0401), (591331, 0x0401), (591339, 0x0104), (591340, 0x0404),
+    (591341, 0x0401), (591342, 0x0401), (591350, 0x0104), (591351, 0x0404), (591352, 0x0402), (591353, 0x0401), (591354, 0x0401), (591361, 0x0104),
+    (591362, 0x0104), (591363, 0x0404), (591364, 0x0401), (591365, 0x0401), (591372, 0x0104), (591373, 0x0104), (591374, 0x0204), (591375, 0x0403),
+    (591376, 0x0401), (591460, 0x0104), (591461, 0x0403), (591462, 0x0401), (591463, 0x0401), (591471, 0x0104), (591472, 0x0404), (591473, 0x0401),
+    (591474, 0x0401), (591475, 0x0401), (591482, 0x0104), (591483, 0x0304), (591484, 0x0402), (591485, 0x0401), (591486, 0x0401), (591493, 0x0104),
+    (591494, 0x0204), (591495, 0x0404), (591496, 0x0401), (591497, 0x0401), (591504, 0x0104), (591505, 0x0104), (591506, 0x0204), (591507, 0x0402),
+    (591508, 0x0401), (591592, 0x0204), (591593, 0x0402), (591594, 0x0401), (591595, 0x0401), (591603, 0x0204), (591604, 0x0403), (591605, 0x0401),
+    (591606, 0x0401), (591614, 0x0104), (591615, 0x0304), (591616, 0x0402), (591617, 0x0401), (591618, 0x0401), (591625, 0x0104), (591626, 0x0104),
+    (591627, 0x0404), (591628, 0x0401), (591629, 0x0401), (591636, 0x0104), (591637, 0x0104), (591638, 0x0204), (591639, 0x0404), (591640, 0x0401),
+    (591724, 0x0403), (591725, 0x0401), (591726, 0x0401), (591727, 0x0401), (591735, 0x0304), (591736, 0x0401), (591737, 0x0401), (591738, 0x0401),
+    (591746, 0x0204), (591747, 0x0404), (591748, 0x0402), (591749, 0x0401), (591757, 0x0104), (591758, 0x0204), (591759, 0x0404), (591856, 0x0204),
+    (591857, 0x0401), (591858, 0x0401), (591859, 0x0401), (591867, 0x0204), (591868, 0x0402), (591869, 0x0401), (591870, 0x0401), (591878, 0x0204),
+    (591879, 0x0404), (591880, 0x0401), (591881, 0x0401), (591889, 0x0104), (591890, 0x0204), (591891, 0x0403), (591892, 0x0401), (591988, 0x0104),
+    (591989, 0x0402), (591990, 0x0401), (591991, 0x0401), (591999, 0x0104), (592000, 0x0403), (592001, 0x0401), (592002, 0x0401), (592010, 0x0104),
+    (592011, 0x0404), (592012, 0x0401), (592013, 0x0401), (592021, 0x0104), (592022, 0x0204), (592023, 0x0402), (592024, 0x0401), (592032, 0x0204),
+    (592033, 0x0204), (592120, 0x0104), (592121, 0x0403), (592122, 0x0401), (592123, 0x0401), (592131, 0x0104), (592132, 0x0403), (592133, 0x0401),
+    (592134, 0x0401), (592142, 0x0104), (592143, 0x0304), (592144, 0x0401), (592145, 0x0401), (592146, 0x0401), (592153, 0x0104), (592154, 0x0204),
+    (592155, 0x0403), (592156, 0x0401), (592157, 0x0401), (592164, 0x0104), (592165, 0x0204), (592166, 0x0404), (592252, 0x0104), (592253, 0x0402),
+    (592254, 0x0401), (592255, 0x0401), (592263, 0x0104), (592264, 0x0403), (592265, 0x0401), (592266, 0x0401), (592274, 0x0104), (592275, 0x0404),
+    (592276, 0x0401), (592277, 0x0401), (592278, 0x0401), (592285, 0x0104), (592286, 0x0204), (592287, 0x0402), (592288, 0x0401), (592296, 0x0104),
+    (592297, 0x0204), (592298, 0x0402), (592384, 0x0204), (592385, 0x0401), (592386, 0x0401), (592387, 0x0401), (592395, 0x0104), (592396, 0x0402),
+    (592397, 0x0401), (592398, 0x0401), (592406, 0x0104), (592407, 0x0404), (592408, 0x0401), (592409, 0x0401), (592417, 0x0104), (592418, 0x0204),
+    (592419, 0x0402), (592420, 0x0401), (592428, 0x0104), (592516, 0x0304), (592517, 0x0401), (592518, 0x0401), (592519, 0x0401), (592527, 0x0204),
+    (592528, 0x0402), (592529, 0x0401), (592530, 0x0401), (592538, 0x0104), (592539, 0x0404), (592540, 0x0401), (592541, 0x0401), (592549, 0x0104),
+    (592550, 0x0304), (592551, 0x0402), (592552, 0x0401), (592648, 0x0304), (592649, 0x0401), (592650, 0x0401), (592651, 0x0401), (592659, 0x0204),
+    (592660, 0x0402), (592661, 0x0401), (592662, 0x0401), (592670, 0x0104), (592671, 0x0404), (592672, 0x0401), (592673, 0x0401), (592681, 0x0104),
+    (592682, 0x0204), (592683, 0x0403), (592684, 0x0401), (592780, 0x0204), (592781, 0x0402), (592782, 0x0401), (592783, 0x0401), (592791, 0x0104),
+    (592792, 0x0402), (592793, 0x0401), (592794, 0x0401), (592795, 0x0401), (592802, 0x0104), (592803, 0x0304), (592804, 0x0401), (592805, 0x0401),
+    (592806, 0x0401), (592813, 0x0104), (592814, 0x0204), (592815, 0x0403), (592816, 0x0401), (592817, 0x0401), (592824, 0x0104), (592825, 0x0104),
+    (592826, 0x0404), (592827, 0x0402), (592912, 0x0104), (592913, 0x0402), (592914, 0x0401), (592915, 0x0401), (592916, 0x0401), (592923, 0x0104),
+    (592924, 0x0403), (592925, 0x0401), (592926, 0x0401), (592927, 0x0401), (592934, 0x0104), (592935, 0x0304), (592936, 0x0402), (592937, 0x0401),
+    (592938, 0x0401), (592945, 0x0104), (592946, 0x0104), (592947, 0x0404), (592948, 0x0401), (592949, 0x0401), (592956, 0x0104), (592957, 0x0104),
+    (592958, 0x0204), (592959, 0x0402), (592960, 0x0401), (593044, 0x0104), (593045, 0x0402), (593046, 0x0401), (593047, 0x0401), (593048, 0x0401),
+    (593055, 0x0104), (593056, 0x0403), (593057, 0x0401), (593058, 0x0401), (593059, 0x0401), (593066, 0x0104), (593067, 0x0204), (593068, 0x0402),
+    (593069, 0x0401), (593070, 0x0401), (593077, 0x0104), (593078, 0x0204), (593079, 0x0404), (593080, 0x0401), (593081, 0x0401), (593082, 0x0401),
+    (593088, 0x0104), (593089, 0x0104), (593090, 0x0204), (593091, 0x0402), (593092, 0x0401), (593176, 0x0104), (593177, 0x0402), (593178, 0x0401),
+    (593179, 0x0401), (593180, 0x0401), (593187, 0x0104), (593188, 0x0403), (593189, 0x0401), (593190, 0x0401), (593191, 0x0401), (593198, 0x0104),
+    (593199, 0x0304), (593200, 0x0402), (593201, 0x0401), (593202, 0x0401), (593203, 0x0401), (593209, 0x0104), (593210, 0x0104), (593211, 0x0404),
+    (593212, 0x0401), (593213, 0x0401), (593214, 0x0401), (593220, 0x0104), (593221, 0x0104), (593222, 0x0204), (593223, 0x0403), (593224, 0x0401),
+    (593225, 0x0401), (593308, 0x0204), (593309, 0x0401), (593310, 0x0401), (593311, 0x0401), (593312, 0x0401), (593319, 0x0104), (593320, 0x0403),
+    (593321, 0x0401), (593322, 0x0401), (593323, 0x0401), (593330, 0x0104), (593331, 0x0204), (593332, 0x0402), (593333, 0x0401), (593334, 0x0401),
+    (593335, 0x0401), (593341, 0x0104), (593342, 0x0104), (593343, 0x0304), (593344, 0x0401), (593345, 0x0401), (593346, 0x0401), (593352, 0x0104),
+    (593353, 0x0104), (593354, 0x0204), (593355, 0x0403), (593356, 0x0401), (593440, 0x0204), (593441, 0x0401), (593442, 0x0401), (593443, 0x0401),
+    (593444, 0x0401), (593451, 0x0204), (593452, 0x0403), (593453, 0x0401), (593454, 0x0401), (593455, 0x0401), (593462, 0x0104), (593463, 0x0304),
+    (593464, 0x0402), (593465, 0x0401), (593466, 0x0401), (593473, 0x0104), (593474, 0x0104), (593475, 0x0404), (593476, 0x0401), (593477, 0x0401),
+    (593484, 0x0104), (593485, 0x0104), (593486, 0x0304), (593487, 0x0401), (593572, 0x0104), (593573, 0x0401), (593574, 0x0401), (593575, 0x0401),
+    (593576, 0x0401), (593583, 0x0104), (593584, 0x0403), (593585, 0x0401), (593586, 0x0401), (593587, 0x0401), (593594, 0x0104), (593595, 0x0204),
+    (593596, 0x0402), (593597, 0x0401), (593598, 0x0401), (593605, 0x0104), (593606, 0x0104), (593607, 0x0404), (593608, 0x0401), (593609, 0x0401),
+    (593616, 0x0104), (593617, 0x0104), (593618, 0x0304), (593619, 0x0401), (593620, 0x0401), (593704, 0x0104), (593705, 0x0402), (593706, 0x0401),
+    (593707, 0x0401), (593708, 0x0401), (593715, 0x0104), (593716, 0x0404), (593717, 0x0401), (593718, 0x0401), (593719, 0x0401), (593726, 0x0104),
+    (593727, 0x0204), (593728, 0x0402), (593729, 0x0401), (593730, 0x0401), (593737, 0x0104), (593738, 0x0104), (593739, 0x0404), (593740, 0x0401),
+    (593741, 0x0401), (593748, 0x0104), (593749, 0x0104), (593750, 0x0204), (593751, 0x0402), (593752, 0x0401), (593836, 0x0104), (593837, 0x0402),
+    (593838, 0x0401), (593839, 0x0401), (593840, 0x0401), (593847, 0x0104), (593848, 0x0404), (593849, 0x0401), (593850, 0x0401), (593851, 0x0401),
+    (593858, 0x0104), (593859, 0x0204), (593860, 0x0402), (593861, 0x0401), (593862, 0x0401), (593869, 0x0104), (593870, 0x0104), (593871, 0x0404),
+    (593872, 0x0401), (593873, 0x0401), (593880, 0x0104), (593881, 0x0104), (593882, 0x0204), (593883, 0x0402), (593884, 0x0401), (593968, 0x0104),
+    (593969, 0x0402), (593970, 0x0401), (593971, 0x0401), (593972, 0x0401), (593979, 0x0104), (593980, 0x0404), (593981, 0x0401), (593982, 0x0401),
+    (593983, 0x0401), (593990, 0x0104), (593991, 0x0304), (593992, 0x0402), (593993, 0x0401), (593994, 0x0401), (594001, 0x0104), (594002, 0x0204),
+    (594003, 0x0404), (594004, 0x0401), (594005, 0x0401), (594012, 0x0104), (594013, 0x0104), (594014, 0x0204), (594015, 0x0401), (594100, 0x0104),
+    (594101, 0x0402), (594102, 0x0401), (594103, 0x0401), (594104, 0x0401), (594111, 0x0104), (594112, 0x0404), (594113, 0x0401), (594114, 0x0401),
+    (594115, 0x0401), (594122, 0x0104), (594123, 0x0304), (594124, 0x0402), (594125, 0x0401), (594126, 0x0401), (594133, 0x0104), (594134, 0x0104),
+    (594135, 0x0404), (594136, 0x0401), (594137, 0x0401), (594144, 0x0104), (594145, 0x0104), (594146, 0x0204), (594232, 0x0104), (594233, 0x0304),
+    (594234, 0x0402), (594235, 0x0401), (594243, 0x0104), (594244, 0x0304), (594245, 0x0402), (594246, 0x0401), (594247, 0x0401), (594254, 0x0104),
+    (594255, 0x0204), (594256, 0x0403), (594257, 0x0401), (594258, 0x0401), (594265, 0x0104), (594266, 0x0104), (594267, 0x0404), (594268, 0x0401),
+    (594269, 0x0401), (595420, 0x0104), (595421, 0x0104), (595422, 0x0304), (595423, 0x0403), (595424, 0x0402), (595425, 0x0402), (595426, 0x0402),
+    (595431, 0x0104), (595432, 0x0104), (595433, 0x0204), (595434, 0x0404), (595435, 0x0402), (595436, 0x0401), (595437, 0x0401), (595442, 0x0104),
+    (595443, 0x0104), (595444, 0x0204), (595445, 0x0404), (595446, 0x0402), (595447, 0x0401), (595448, 0x0401), (595449, 0x0401), (595453, 0x0104),
+    (595454, 0x0104), (595455, 0x0104), (595456, 0x0304), (595457, 0x0403), (595458, 0x0401), (595459, 0x0401), (595460, 0x0401), (595464, 0x0104),
+    (595465, 0x0104), (595466, 0x0104), (595467, 0x0204), (595468, 0x0404), (595469, 0x0401), (595470, 0x0401), (595471, 0x0401), (595475, 0x0104),
+    (595476, 0x0104), (595477, 0x0104), (595478, 0x0104), (595479, 0x0304), (595480, 0x0402), (595481, 0x0401), (595482, 0x0401), (595486, 0x0104),
+    (595487, 0x0104), (595488, 0x0104), (595489, 0x0104), (595490, 0x0204), (595491, 0x0404), (595492, 0x0401), (595493, 0x0401), (595497, 0x0104),
+    (595498, 0x0104), (595499, 0x0104), (595500, 0x0104), (595501, 0x0204), (595502, 0x0304), (595503, 0x0403), (595504, 0x0401), (595508, 0x0104),
+    (595509, 0x0104), (595510, 0x0104), (595511, 0x0204), (595512, 0x0204), (595552, 0x0104), (595553, 0x0104), (595554, 0x0304), (595555, 0x0402),
+    (595556, 0x0401), (595557, 0x0401), (595563, 0x0104), (595564, 0x0104), (595565, 0x0304), (595566, 0x0403), (595567, 0x0401), (595568, 0x0401),
+    (595569, 0x0401), (595574, 0x0104), (595575, 0x0104), (595576, 0x0204), (595577, 0x0403), (595578, 0x0401), (595579, 0x0401), (595580, 0x0401),
+    (595585, 0x0104), (595586, 0x0104), (595587, 0x0104), (595588, 0x0404), (595589, 0x0401), (595590, 0x0401), (595591, 0x0401), (595596, 0x0104),
+    (595597, 0x0104), (595598, 0x0104), (595599, 0x0304), (595600, 0x0402), (595601, 0x0401), (595602, 0x0401), (595607, 0x0104), (595608, 0x0104),
+    (595609, 0x0104), (595610, 0x0204), (595611, 0x0404), (595612, 0x0402), (595613, 0x0401), (595614, 0x0401), (595618, 0x0104), (595619, 0x0104),
+    (595620, 0x0104), (595621, 0x0104), (595622, 0x0304), (595623, 0x0403), (595624, 0x0401), (595625, 0x0401), (595629, 0x0104), (595630, 0x0104),
+    (595631, 0x0104), (595632, 0x0204), (595633, 0x0404), (595634, 0x0403), (595635, 0x0402), (595684, 0x0104), (595685, 0x0104), (595686, 0x0204),
+    (595687, 0x0402), (595688, 0x0402), (595689, 0x0401), (595695, 0x0104), (595696, 0x0104), (595697, 0x0204), (595698, 0x0403), (595699, 0x0401),
+    (595700, 0x0401), (595701, 0x0401), (595706, 0x0104), (595707, 0x0104), (595708, 0x0204), (595709, 0x0403), (595710, 0x0401), (595711, 0x0401),
+    (595712, 0x0401), (595717, 0x0104), (595718, 0x0104), (595719, 0x0104), (595720, 0x0404), (595721, 0x0402), (595722, 0x0401), (595723, 0x0401),
+    (595728, 0x0104), (595729, 0x0104), (595730, 0x0104), (595731, 0x0304), (595732, 0x0403), (595733, 0x0401), (595734, 0x0401), (595735, 0x0401),
+    (595739, 0x0104), (595740, 0x0104), (595741, 0x0104), (595742, 0x0204), (595743, 0x0304), (595744, 0x0402), (595745, 0x0401), (595746, 0x0401),
+    (595750, 0x0104), (595751, 0x0104), (595752, 0x0104), (595753, 0x0104), (595754, 0x0304), (595755, 0x0403), (595756, 0x0401), (595757, 0x0401),
+    (595761, 0x0104), (595762, 0x0104), (595763, 0x0104), (595764, 0x0104), (595765, 0x0204), (595766, 0x0404), (595767, 0x0402), (595816, 0x0104),
+    (595817, 0x0204), (595818, 0x0403), (595819, 0x0401), (595820, 0x0401), (595821, 0x0401), (595827, 0x0104), (595828, 0x0104), (595829, 0x0403),
+    (595830, 0x0401), (595831, 0x0401), (595832, 0x0401), (595838, 0x0104), (595839, 0x0104), (595840, 0x0404), (595841, 0x0401), (595842, 0x0401),
+    (595843, 0x0401), (595849, 0x0104), (595850, 0x0104), (595851, 0x0304), (595852, 0x0402), (595853, 0x0401), (595854, 0x0401), (595855, 0x0401),
+    (595860, 0x0104), (595861, 0x0104), (595862, 0x0204), (595863, 0x0404), (595864, 0x0402), (595865, 0x0401), (595866, 0x0401), (595871, 0x0104),
+    (595872, 0x0104), (595873, 0x0104), (595874, 0x0204), (595875, 0x0404), (595876, 0x0401), (595877, 0x0401), (595882, 0x0104), (595883, 0x0104),
+    (595884, 0x0104), (595885, 0x0104), (595886, 0x0204), (595887, 0x0402), (595888, 0x0401), (595948, 0x0104), (595949, 0x0104), (595950, 0x0404),
+    (595951, 0x0402), (595952, 0x0401), (595953, 0x0401), (595959, 0x0104), (595960, 0x0104), (595961, 0x0304), (595962, 0x0402), (595963, 0x0401),
+    (595964, 0x0401), (595970, 0x0104), (595971, 0x0104), (595972, 0x0304), (595973, 0x0402), (595974, 0x0401), (595975, 0x0401), (595981, 0x0104),
+    (595982, 0x0104), (595983, 0x0204), (595984, 0x0403), (595985, 0x0401), (595986, 0x0401), (595987, 0x0401), (595992, 0x0104), (595993, 0x0104),
+    (595994, 0x0204), (595995, 0x0404), (595996, 0x0402), (595997, 0x0401), (595998, 0x0401), (596003, 0x0104), (596004, 0x0104), (596005, 0x0204),
+    (596006, 0x0304), (596007, 0x0403), (596008, 0x0401), (596009, 0x0401), (596014, 0x0104), (596015, 0x0104), (596016, 0x0104), (596017, 0x0204),
+    (596018, 0x0304), (596019, 0x0401), (596080, 0x0104), (596081, 0x0104), (596082, 0x0403), (596083, 0x0402), (596084, 0x0402), (596085, 0x0401),
+    (596091, 0x0104), (596092, 0x0104), (596093, 0x0404), (596094, 0x0402), (596095, 0x0401), (596096, 0x0401), (596102, 0x0104), (596103, 0x0104),
+    (596104, 0x0304), (596105, 0x0402), (596106, 0x0401), (596107, 0x0401), (596108, 0x0401), (596113, 0x0104), (596114, 0x0104), (596115, 0x0204),
+    (596116, 0x0403), (596117, 0x0401), (596118, 0x0401), (596119, 0x0401), (596124, 0x0104), (596125, 0x0104), (596126, 0x0104), (596127, 0x0304),
+    (596128, 0x0402), (596129, 0x0401), (596130, 0x0401), (596135, 0x0104), (596136, 0x0104), (596137, 0x0104), (596138, 0x0204), (596139, 0x0404),
+    (596140, 0x0401), (596141, 0x0401), (596146, 0x0104), (596147, 0x0104), (596148, 0x0104), (596149, 0x0104), (596150, 0x0204), (596151, 0x0403),
+    (596152, 0x0401), (596157, 0x0104), (596158, 0x0104), (596159, 0x0104), (596160, 0x0104), (596161, 0x0104), (596162, 0x0204), (596212, 0x0104),
+    (596213, 0x0304), (596214, 0x0402), (596215, 0x0401), (596216, 0x0401), (596217, 0x0401), (596223, 0x0104), (596224, 0x0304), (596225, 0x0402),
+    (596226, 0x0401), (596227, 0x0401), (596228, 0x0401), (596234, 0x0104), (596235, 0x0204), (596236, 0x0403), (596237, 0x0401), (596238, 0x0401),
+    (596239, 0x0401), (596245, 0x0104), (596246, 0x0104), (596247, 0x0304), (596248, 0x0402), (596249, 0x0401), (596250, 0x0401), (596251, 0x0401),
+    (596256, 0x0104), (596257, 0x0104), (596258, 0x0204), (596259, 0x0404), (596260, 0x0402), (596261, 0x0401), (596262, 0x0401), (596267, 0x0104),
+    (596268, 0x0104), (596269, 0x0104), (596270, 0x0204), (596271, 0x0404), (596272, 0x0402), (596273, 0x0401), (596278, 0x0104), (596279, 0x0104),
+    (596280, 0x0104), (596281, 0x0104), (596282, 0x0204), (596283, 0x0403), (596344, 0x0104), (596345, 0x0204), (596346, 0x0403), (596347, 0x0401),
+    (596348, 0x0401), (596349, 0x0401), (596355, 0x0104), (596356, 0x0204), (596357, 0x0403), (596358, 0x0401), (596359, 0x0401), (596360, 0x0401),
+    (596366, 0x0104), (596367, 0x0104), (596368, 0x0404), (596369, 0x0401), (596370, 0x0401), (596371, 0x0401), (596377, 0x0104), (596378, 0x0104),
+    (596379, 0x0304), (596380, 0x0402), (596381, 0x0401), (596382, 0x0401), (596388, 0x0104), (596389, 0x0104), (596390, 0x0204), (596391, 0x0404),
+    (596392, 0x0401), (596393, 0x0401), (596394, 0x0401), (596399, 0x0104), (596400, 0x0104), (596401, 0x0104), (596402, 0x0204), (596403, 0x0403),
+    (596404, 0x0401), (596405, 0x0401), (596410, 0x0104), (596411, 0x0104), (596412, 0x0104), (596413, 0x0104), (596414, 0x0304), (596415, 0x0402),
+    (596476, 0x0104), (596477, 0x0204), (596478, 0x0403), (596479, 0x0401), (596480, 0x0401), (596487, 0x0104), (596488, 0x0204), (596489, 0x0403),
+    (596490, 0x0401), (596491, 0x0401), (596492, 0x0401), (596498, 0x0104), (596499, 0x0104), (596500, 0x0404), (596501, 0x0401), (596502, 0x0401),
+    (596503, 0x0401), (596509, 0x0104), (596510, 0x0104), (596511, 0x0304), (596512, 0x0403), (596513, 0x0401), (596514, 0x0401), (596515, 0x0401),
+    (596520, 0x0104), (596521, 0x0104), (596522, 0x0204), (596523, 0x0404), (596524, 0x0401), (596525, 0x0401), (596526, 0x0401), (596531, 0x0104),
+    (596532, 0x0104), (596533, 0x0104), (596534, 0x0204), (596535, 0x0402), (596536, 0x0401), (596537, 0x0401), (596542, 0x0104), (596543, 0x0104),
+    (596544, 0x0104), (596545, 0x0204), (596546, 0x0304), (596608, 0x0104), (596609, 0x0304), (596610, 0x0402), (596611, 0x0401), (596612, 0x0401),
+    (596619, 0x0104), (596620, 0x0204), (596621, 0x0403), (596622, 0x0401), (596623, 0x0401), (596624, 0x0401), (596630, 0x0104), (596631, 0x0104),
+    (596632, 0x0404), (596633, 0x0402), (596634, 0x0401), (596635, 0x0401), (596641, 0x0104), (596642, 0x0104), (596643, 0x0204), (596644, 0x0403),
+    (596645, 0x0401), (596646, 0x0401), (596647, 0x0401), (596652, 0x0104), (596653, 0x0104), (596654, 0x0104), (596655, 0x0304), (596656, 0x0402),
+    (596657, 0x0401), (596658, 0x0401), (596663, 0x0104), (596664, 0x0104), (596665, 0x0104), (596666, 0x0204), (596667, 0x0404), (596668, 0x0401),
+    (596669, 0x0401), (596674, 0x0104), (596675, 0x0104), (596676, 0x0104), (596677, 0x0104), (596678, 0x0304), (596740, 0x0104), (596741, 0x0403),
+    (596742, 0x0401), (596743, 0x0401), (596744, 0x0401), (596751, 0x0104), (596752, 0x0404), (596753, 0x0402), (596754, 0x0401), (596755, 0x0401),
+    (596762, 0x0104), (596763, 0x0204), (596764, 0x0403), (596765, 0x0401), (596766, 0x0401), (596773, 0x0104), (596774, 0x0104), (596775, 0x0404),
+    (596776, 0x0401), (596777, 0x0401), (596784, 0x0104), (596785, 0x0104), (596786, 0x0204), (596787, 0x0403), (596788, 0x0401), (596795, 0x0104),
+    (596796, 0x0104), (596797, 0x0104), (596872, 0x0104), (596873, 0x0304), (596874, 0x0401), (596875, 0x0401), (596876, 0x0401), (596883, 0x0104),
+    (596884, 0x0304), (596885, 0x0402), (596886, 0x0401), (596887, 0x0401), (596894, 0x0104), (596895, 0x0204), (596896, 0x0403), (596897, 0x0401),
+    (596898, 0x0401), (596905, 0x0104), (596906, 0x0104), (596907, 0x0304), (596908, 0x0402), (596909, 0x0401), (596910, 0x0401), (596916, 0x0104),
+    (596917, 0x0104), (596918, 0x0204), (596919, 0x0403), (596920, 0x0401), (596921, 0x0401), (596927, 0x0104), (596928, 0x0104), (596929, 0x0104),
+    (596930, 0x0404), (596931, 0x0401), (597004, 0x0104), (597005, 0x0204), (597006, 0x0402), (597007, 0x0401), (597008, 0x0401), (597015, 0x0104),
+    (597016, 0x0204), (597017, 0x0403), (597018, 0x0401), (597019, 0x0401), (597026, 0x0104), (597027, 0x0104), (597028, 0x0404), (597029, 0x0401),
+    (597030, 0x0401), (597031, 0x0401), (597037, 0x0104), (597038, 0x0104), (597039, 0x0304), (597040, 0x0402), (597041, 0x0401), (597042, 0x0401),
+    (597048, 0x0104), (597049, 0x0104), (597050, 0x0204), (597051, 0x0404), (597052, 0x0401), (597053, 0x0401), (597059, 0x0104), (597060, 0x0104),
+    (597061, 0x0104), (597062, 0x0304), (597063, 0x0402), (597136, 0x0104), (597137, 0x0204), (597138, 0x0403), (597139, 0x0401), (597140, 0x0401),
+    (597147, 0x0104), (597148, 0x0204), (597149, 0x0403), (597150, 0x0401), (597151, 0x0401), (597158, 0x0104), (597159, 0x0104), (597160, 0x0404),
+    (597161, 0x0401), (597162, 0x0401), (597163, 0x0401), (597169, 0x0104), (597170, 0x0104), (597171, 0x0204), (597172, 0x0402), (597173, 0x0401),
+    (597174, 0x0401), (597180, 0x0104), (597181, 0x0104), (597182, 0x0204), (597183, 0x0404), (597184, 0x0401), (597185, 0x0401), (597191, 0x0104),
+    (597192, 0x0104), (597193, 0x0204), (597194, 0x0403), (597195, 0x0401), (597268, 0x0104), (597269, 0x0404), (597270, 0x0401), (597271, 0x0401),
+    (597272, 0x0401), (597279, 0x0104), (597280, 0x0304), (597281, 0x0402), (597282, 0x0401), (597283, 0x0401), (597290, 0x0104), (597291, 0x0204),
+    (597292, 0x0403), (597293, 0x0401), (597294, 0x0401), (597301, 0x0104), (597302, 0x0104), (597303, 0x0304), (597304, 0x0401), (597305, 0x0401),
+    (597312, 0x0104), (597313, 0x0104), (597314, 0x0204), (597315, 0x0403), (597316, 0x0401), (597323, 0x0104), (597324, 0x0104), (597325, 0x0104),
+    (597326, 0x0403), (597327, 0x0401), (597400, 0x0204), (597401, 0x0402), (597402, 0x0401), (597403, 0x0401), (597411, 0x0204), (597412, 0x0403),
+    (597413, 0x0401), (597414, 0x0401), (597415, 0x0401), (597422, 0x0104), (597423, 0x0304), (597424, 0x0402), (597425, 0x0401), (597426, 0x0401),
+    (597433, 0x0104), (597434, 0x0204), (597435, 0x0404), (597436, 0x0401), (597437, 0x0401), (597444, 0x0104), (597445, 0x0104), (597446, 0x0304),
+    (597447, 0x0402), (597448, 0x0401), (597532, 0x0104), (597533, 0x0404), (597534, 0x0401), (597535, 0x0401), (597536, 0x0401), (597543, 0x0104),
+    (597544, 0x0404), (597545, 0x0402), (597546, 0x0401), (597547, 0x0401), (597554, 0x0104), (597555, 0x0204), (597556, 0x0403), (597557, 0x0401),
+    (597558, 0x0401), (597565, 0x0104), (597566, 0x0104), (597567, 0x0404), (597568, 0x0401), (597569, 0x0401), (597570, 0x0401), (597576, 0x0104),
+    (597577, 0x0104), (597578, 0x0204), (597579, 0x0403), (597580, 0x0401), (597581, 0x0401), (597587, 0x0104), (597588, 0x0104), (597589, 0x0204),
+    (597590, 0x0403), (597591, 0x0401), (597664, 0x0104), (597665, 0x0304), (597666, 0x0402), (597667, 0x0401), (597668, 0x0401), (597675, 0x0104),
+    (597676, 0x0304), (597677, 0x0402), (597678, 0x0401), (597679, 0x0401), (597686, 0x0104), (597687, 0x0204), (597688, 0x0403), (597689, 0x0401),
+    (597690, 0x0401), (597691, 0x0401), (597697, 0x0104), (597698, 0x0104), (597699, 0x0304), (597700, 0x0402), (597701, 0x0401), (597702, 0x0401),
+    (597708, 0x0104), (597709, 0x0104), (597710, 0x0204), (597711, 0x0404), (597712, 0x0401), (597713, 0x0401), (597719, 0x0104), (597720, 0x0104),
+    (597721, 0x0104), (597722, 0x0304), (597723, 0x0401), (597724, 0x0401), (597730, 0x0104), (597796, 0x0104), (597797, 0x0304), (597798, 0x0402),
+    (597799, 0x0401), (597800, 0x0401), (597807, 0x0104), (597808, 0x0204), (597809, 0x0403), (597810, 0x0401), (597811, 0x0401), (597818, 0x0104),
+    (597819, 0x0204), (597820, 0x0404), (597821, 0x0401), (597822, 0x0401), (597823, 0x0401), (597829, 0x0104), (597830, 0x0104), (597831, 0x0304),
+    (597832, 0x0402), (597833, 0x0401), (597834, 0x0401), (597840, 0x0104), (597841, 0x0104), (597842, 0x0104), (597843, 0x0404), (597844, 0x0401),
+    (597845, 0x0401), (597851, 0x0104), (597852, 0x0104), (597853, 0x0104), (597854, 0x0204), (597855, 0x0402), (597856, 0x0401), (597862, 0x0104),
+    (597863, 0x0104), (597864, 0x0104), (597865, 0x0204), (597928, 0x0104), (597929, 0x0304), (597930, 0x0402), (597931, 0x0401), (597932, 0x0401),
+    (597939, 0x0104), (597940, 0x0304), (597941, 0x0402), (597942, 0x0401), (597943, 0x0401), (597944, 0x0401), (597950, 0x0104), (597951, 0x0204),
+    (597952, 0x0403), (597953, 0x0401), (597954, 0x0401), (597955, 0x0401), (597961, 0x0104), (597962, 0x0104), (597963, 0x0304), (597964, 0x0402),
+    (597965, 0x0401), (597966, 0x0401), (597972, 0x0104), (597973, 0x0104), (597974, 0x0204), (597975, 0x0403), (597976, 0x0401), (597977, 0x0401),
+    (597978, 0x0401), (597983, 0x0104), (597984, 0x0104), (597985, 0x0104), (597986, 0x0204), (597987, 0x0403), (597988, 0x0401), (597989, 0x0401),
+    (597994, 0x0104), (597995, 0x0104), (597996, 0x0104), (597997, 0x0204), (597998, 0x0304), (598060, 0x0104), (598061, 0x0403), (598062, 0x0401),
+    (598063, 0x0401), (598064, 0x0401), (598071, 0x0104), (598072, 0x0304), (598073, 0x0401), (598074, 0x0401), (598075, 0x0401), (598082, 0x0104),
+    (598083, 0x0204), (598084, 0x0402), (598085, 0x0401), (598086, 0x0401), (598087, 0x0401), (598093, 0x0104), (598094, 0x0104), (598095, 0x0304),
+    (598096, 0x0401), (598097, 0x0401), (598098, 0x0401), (598104, 0x0104), (598105, 0x0104), (598106, 0x0204), (598107, 0x0403), (598108, 0x0401),
+    (598109, 0x0401), (598110, 0x0401), (598115, 0x0104), (598116, 0x0104), (598117, 0x0104), (598118, 0x0204), (598119, 0x0404), (598120, 0x0401),
+    (598121, 0x0401), (598126, 0x0104), (598127, 0x0104), (598128, 0x0104), (598129, 0x0204), (598192, 0x0304), (598193, 0x0401), (598194, 0x0401),
+    (598195, 0x0401), (598203, 0x0204), (598204, 0x0402), (598205, 0x0401), (598206, 0x0401), (598207, 0x0401), (598214, 0x0104), (598215, 0x0304),
+    (598216, 0x0401), (598217, 0x0401), (598218, 0x0401), (598225, 0x0104), (598226, 0x0204), (598227, 0x0403), (598228, 0x0402), (598229, 0x0401),
+    (598236, 0x0104), (598237, 0x0104), (598238, 0x0204), (598239, 0x0404), (598240, 0x0402), (598324, 0x0104), (598325, 0x0402), (598326, 0x0401),
+    (598327, 0x0401), (598328, 0x0401), (598335, 0x0104), (598336, 0x0403), (598337, 0x0401), (598338, 0x0401), (598339, 0x0401), (598346, 0x0104),
+    (598347, 0x0304), (598348, 0x0402), (598349, 0x0401), (598350, 0x0401), (598351, 0x0401), (598357, 0x0104), (598358, 0x0204), (598359, 0x0403),
+    (598360, 0x0401), (598361, 0x0401), (598362, 0x0401), (598368, 0x0104), (598369, 0x0104), (598370, 0x0204), (598371, 0x0403), (598372, 0x0401),
+    (598373, 0x0401), (598379, 0x0104), (598380, 0x0104), (598456, 0x0104), (598457, 0x0404), (598458, 0x0401), (598459, 0x0401), (598460, 0x0401),
+    (598467, 0x0104), (598468, 0x0304), (598469, 0x0402), (598470, 0x0401), (598471, 0x0401), (598478, 0x0104), (598479, 0x0204), (598480, 0x0402),
+    (598481, 0x0401), (598482, 0x0401), (598483, 0x0401), (598489, 0x0104), (598490, 0x0104), (598491, 0x0404), (598492, 0x0401), (598493, 0x0401),
+    (598494, 0x0401), (598500, 0x0104), (598501, 0x0104), (598502, 0x0204), (598503, 0x0403), (598504, 0x0401), (598505, 0x0401), (598511, 0x0104),
+    (598512, 0x0104), (598513, 0x0104), (598514, 0x0304), (598515, 0x0402), (598588, 0x0104), (598589, 0x0304), (598590, 0x0401), (598591, 0x0401),
+    (598592, 0x0401), (598599, 0x0104), (598600, 0x0204), (598601, 0x0402), (598602, 0x0401), (598603, 0x0401), (598604, 0x0401), (598610, 0x0104),
+    (598611, 0x0204), (598612, 0x0403), (598613, 0x0401), (598614, 0x0401), (598615, 0x0401), (598621, 0x0104), (598622, 0x0104), (598623, 0x0304),
+    (598624, 0x0401), (598625, 0x0401), (598626, 0x0401), (598632, 0x0104), (598633, 0x0104), (598634, 0x0204), (598635, 0x0403), (598636, 0x0401),
+    (598637, 0x0401), (598643, 0x0104), (598644, 0x0104), (598645, 0x0104), (598646, 0x0204), (598647, 0x0402), (598648, 0x0401), (598720, 0x0104),
+    (598721, 0x0404), (598722, 0x0401), (598723, 0x0401), (598724, 0x0401), (598731, 0x0104), (598732, 0x0304), (598733, 0x0401), (598734, 0x0401),
+    (598735, 0x0401), (598736, 0x0401), (598742, 0x0104), (598743, 0x0204), (598744, 0x0403), (598745, 0x0401), (598746, 0x0401), (598747, 0x0401),
+    (598753, 0x0104), (598754, 0x0104), (598755, 0x0304), (598756, 0x0401), (598757, 0x0401), (598758, 0x0401), (598764, 0x0104), (598765, 0x0104),
+    (598766, 0x0204), (598767, 0x0403), (598768, 0x0401), (598769, 0x0401), (598775, 0x0104), (598776, 0x0104), (598777, 0x0104), (598778, 0x0304),
+    (598779, 0x0402), (598780, 0x0401), (598852, 0x0104), (598853, 0x0403), (598854, 0x0401), (598855, 0x0401), (598856, 0x0401), (598863, 0x0104),
+    (598864, 0x0404), (598865, 0x0401), (598866, 0x0401), (598867, 0x0401), (598868, 0x0401), (598874, 0x0104), (598875, 0x0204), (598876, 0x0402),
+    (598877, 0x0401), (598878, 0x0401), (598879, 0x0401), (598885, 0x0104), (598886, 0x0104), (598887, 0x0404), (598888, 0x0401), (598889, 0x0401),
+    (598890, 0x0401), (598896, 0x0104), (598897, 0x0104), (598898, 0x0204), (598899, 0x0403), (598900, 0x0401), (598901, 0x0401), (598907, 0x0104),
+    (598908, 0x0104), (598909, 0x0104), (598910, 0x0204), (598911, 0x0402), (598984, 0x0104), (598985, 0x0402), (598986, 0x0401), (598987, 0x0401),
+    (598988, 0x0401), (598995, 0x0104), (598996, 0x0403), (598997, 0x0401), (598998, 0x0401), (598999, 0x0401), (599006, 0x0104), (599007, 0x0304),
+    (599008, 0x0401), (599009, 0x0401), (599010, 0x0401), (599011, 0x0401), (599017, 0x0104), (599018, 0x0204), (599019, 0x0403), (599020, 0x0401),
+    (599021, 0x0401), (599022, 0x0401), (599028, 0x0104), (599029, 0x0104), (599030, 0x0204), (599031, 0x0403), (599032, 0x0401), (599116, 0x0204),
+    (599117, 0x0402), (599118, 0x0401), (599119, 0x0401), (599120, 0x0401), (599127, 0x0104), (599128, 0x0403), (599129, 0x0401), (599130, 0x0401),
+    (599131, 0x0401), (599138, 0x0104), (599139, 0x0204), (599140, 0x0402), (599141, 0x0401), (599142, 0x0401), (599143, 0x0401), (599149, 0x0104),
+    (599150, 0x0104), (599151, 0x0404), (599152, 0x0401), (599153, 0x0401), (599154, 0x0401), (599160, 0x0104), (599161, 0x0104), (599162, 0x0204),
+    (599163, 0x0403), (599164, 0x0401), (599165, 0x0401), (599248, 0x0104), (599249, 0x0403), (599250, 0x0401), (599251, 0x0401), (599252, 0x0401),
+    (599259, 0x0104), (599260, 0x0404), (599261, 0x0402), (599262, 0x0401), (599263, 0x0401), (599264, 0x0401), (599270, 0x0104), (599271, 0x0104),
+    (599272, 0x0403), (599273, 0x0401), (599274, 0x0401), (599275, 0x0401), (599281, 0x0104), (599282, 0x0104), (599283, 0x0404), (599284, 0x0401),
+    (599285, 0x0401), (599286, 0x0401), (599292, 0x0104), (599293, 0x0104), (599294, 0x0204), (599295, 0x0404), (599296, 0x0401), (599297, 0x0401),
+    (599303, 0x0104), (599304, 0x0104), (599305, 0x0104), (599306, 0x0204), (599307, 0x0402), (599380, 0x0104), (599381, 0x0404), (599382, 0x0401),
+    (599383, 0x0401), (599384, 0x0401), (599391, 0x0104), (599392, 0x0204), (599393, 0x0402), (599394, 0x0401), (599395, 0x0401), (599396, 0x0401),
+    (599402, 0x0104), (599403, 0x0104), (599404, 0x0404), (599405, 0x0401), (599406, 0x0401), (599407, 0x0401), (599413, 0x0104), (599414, 0x0104),
+    (599415, 0x0204), (599416, 0x0402), (599417, 0x0401), (599418, 0x0401), (599424, 0x0104), (599425, 0x0104), (599426, 0x0104), (599427, 0x0404),
+    (599428, 0x0401), (599429, 0x0401), (599435, 0x0104), (599436, 0x0104), (599437, 0x0104), (599438, 0x0204), (599439, 0x0402), (599440, 0x0401),
+    (599512, 0x0104), (599513, 0x0304), (599514, 0x0402), (599515, 0x0401), (599516, 0x0401), (599523, 0x0104), (599524, 0x0204), (599525, 0x0402),
+    (599526, 0x0401), (599527, 0x0401), (599528, 0x0401), (599534, 0x0104), (599535, 0x0104), (599536, 0x0304), (599537, 0x0401), (599538, 0x0401),
+    (599539, 0x0401), (599545, 0x0104), (599546, 0x0104), (599547, 0x0204), (599548, 0x0402), (599549, 0x0401), (599550, 0x0401), (599556, 0x0104),
+    (599557, 0x0104), (599558, 0x0204), (599559, 0x0404), (599560, 0x0401), (599561, 0x0401), (599567, 0x0104), (599568, 0x0104), (599569, 0x0104),
+    (599570, 0x0204), (599571, 0x0402), (599572, 0x0401), (599644, 0x0104), (599645, 0x0304), (599646, 0x0402), (599647, 0x0401), (599648, 0x0401),
+    (599655, 0x0104), (599656, 0x0204), (599657, 0x0403), (599658, 0x0401), (599659, 0x0401), (599660, 0x0401), (599666, 0x0104), (599667, 0x0204),
+    (599668, 0x0404), (599669, 0x0401), (599670, 0x0401), (599671, 0x0401), (599677, 0x0104), (599678, 0x0104), (599679, 0x0204), (599680, 0x0402),
+    (599681, 0x0401), (599682, 0x0401), (599688, 0x0104), (599689, 0x0104), (599690, 0x0204), (599691, 0x0404), (599692, 0x0401), (599693, 0x0401),
+    (599699, 0x0104), (599700, 0x0104), (599701, 0x0104), (599702, 0x0304), (599703, 0x0401), (599776, 0x0104), (599777, 0x0404), (599778, 0x0401),
+    (599779, 0x0401), (599780, 0x0401), (599787, 0x0104), (599788, 0x0304), (599789, 0x0402), (599790, 0x0401), (599791, 0x0401), (599792, 0x0401),
+    (599798, 0x0104), (599799, 0x0104), (599800, 0x0403), (599801, 0x0401), (599802, 0x0401), (599803, 0x0401), (599809, 0x0104), (599810, 0x0104),
+    (599811, 0x0204), (599812, 0x0402), (599813, 0x0401), (599814, 0x0401), (599820, 0x0104), (599821, 0x0104), (599822, 0x0204), (599823, 0x0403),
+    (599824, 0x0401), (599825, 0x0401), (599831, 0x0104), (599832, 0x0104), (599908, 0x0104), (599909, 0x0403), (599910, 0x0401), (599911, 0x0401),
+    (599912, 0x0401), (599919, 0x0104), (599920, 0x0404), (599921, 0x0402), (599922, 0x0401), (599923, 0x0401), (599930, 0x0104), (599931, 0x0204),
+    (599932, 0x0403), (599933, 0x0401), (599934, 0x0401), (599941, 0x0104), (599942, 0x0104), (599943, 0x0304), (599944, 0x0401), (599945, 0x0401),
+    (599952, 0x0104), (599953, 0x0104), (599954, 0x0204), (599955, 0x0402), (600040, 0x0104), (600041, 0x0304), (600042, 0x0402), (600043, 0x0401),
+    (600044, 0x0401), (600051, 0x0104), (600052, 0x0204), (600053, 0x0402), (600054, 0x0401), (600055, 0x0401), (600062, 0x0104), (600063, 0x0204),
+    (600064, 0x0403), (600065, 0x0401), (600066, 0x0401), (600073, 0x0104), (600074, 0x0104), (600075, 0x0304), (600076, 0x0401), (600077, 0x0401),
+    (600084, 0x0104), (600085, 0x0104), (600086, 0x0204), (600087, 0x0402), (600088, 0x0401), (600172, 0x0104), (600173, 0x0204), (600174, 0x0403),
+    (600175, 0x0401), (600183, 0x0104), (600184, 0x0204), (600185, 0x0404), (600186, 0x0401), (600187, 0x0401), (600194, 0x0104), (600195, 0x0104),
+    (600196, 0x0304), (600197, 0x0401), (600198, 0x0401), (600205, 0x0104), (600206, 0x0104), (600207, 0x0204), (600208, 0x0402), (600209, 0x0401),
+    (600216, 0x0104), (600217, 0x0104), (600218, 0x0204), (600219, 0x0402), (601888, 0x0104), (601889, 0x0104), (601890, 0x0104), (601891, 0x0204),
+    (601892, 0x0204), (601893, 0x0304), (601894, 0x0403), (601899, 0x0104), (601900, 0x0104), (601901, 0x0104), (601902, 0x0204), (601903, 0x0304),
+    (601904, 0x0404), (601905, 0x0404), (601906, 0x0401), (601910, 0x0104), (601911, 0x0104), (601912, 0x0104), (601913, 0x0204), (601914, 0x0404),
+    (601915, 0x0404), (601916, 0x0403), (601917, 0x0401), (601918, 0x0401), (601921, 0x0104), (601922, 0x0104), (601923, 0x0104), (601924, 0x0104),
+    (601925, 0x0304), (601926, 0x0402), (601927, 0x0402), (601928, 0x0401), (601929, 0x0401), (601932, 0x0104), (601933, 0x0104), (601934, 0x0104),
+    (601935, 0x0104), (601936, 0x0304), (601937, 0x0403), (601938, 0x0401), (601939, 0x0401), (601940, 0x0401), (601943, 0x0104), (601944, 0x0104),
+    (601945, 0x0104), (601946, 0x0104), (601947, 0x0104), (601948, 0x0404), (601949, 0x0401), (601950, 0x0401), (601951, 0x0401), (601954, 0x0104),
+    (601955, 0x0104), (601956, 0x0104), (601957, 0x0104), (601958, 0x0104), (601959, 0x0304), (601960, 0x0402), (601961, 0x0401), (601962, 0x0401),
+    (601965, 0x0104), (601966, 0x0104), (601967, 0x0104), (601968, 0x0104), (601969, 0x0104), (601970, 0x0204), (601971, 0x0304), (601972, 0x0402),
+    (601973, 0x0401), (601976, 0x0104), (601977, 0x0104), (601978, 0x0104), (601979, 0x0104), (601980, 0x0104), (601981, 0x0104), (601982, 0x0104),
+    (601983, 0x0304), (601984, 0x0401), (602020, 0x0104), (602021, 0x0104), (602022, 0x0104), (602023, 0x0304), (602024, 0x0304), (602025, 0x0403),
+    (602026, 0x0401), (602031, 0x0104), (602032, 0x0104), (602033, 0x0204), (602034, 0x0304), (602035, 0x0403), (602036, 0x0403), (602037, 0x0402),
+    (602038, 0x0401), (602042, 0x0104), (602043, 0x0104), (602044, 0x0104), (602045, 0x0204), (602046, 0x0403), (602047, 0x0402), (602048, 0x0402),
+    (602049, 0x0401), (602053, 0x0104), (602054, 0x0104), (602055, 0x0104), (602056, 0x0204), (602057, 0x0403), (602058, 0x0402), (602059, 0x0401),
+    (602060, 0x0401), (602064, 0x0104), (602065, 0x0104), (602066, 0x0104), (602067, 0x0104), (602068, 0x0404), (602069, 0x0402), (602070, 0x0401),
+    (602071, 0x0401), (602075, 0x0104), (602076, 0x0104), (602077, 0x0104), (602078, 0x0104), (602079, 0x0204), (602080, 0x0403), (602081, 0x0401),
+    (602082, 0x0401), (602083, 0x0401), (602086, 0x0104), (602087, 0x0104), (602088, 0x0104), (602089, 0x0104), (602090, 0x0204), (602091, 0x0304),
+    (602092, 0x0402), (602093, 0x0401), (602094, 0x0401), (602097, 0x0104), (602098, 0x0104), (602099, 0x0104), (602100, 0x0104), (602101, 0x0104),
+    (602102, 0x0104), (602103, 0x0304), (602104, 0x0401), (602105, 0x0401), (602108, 0x0104), (602109, 0x0104), (602110, 0x0104), (602111, 0x0104),
+    (602112, 0x0104), (602113, 0x0104), (602114, 0x0104), (602115, 0x0401), (602152, 0x0104), (602153, 0x0104), (602154, 0x0204), (602155, 0x0304),
+    (602156, 0x0404), (602157, 0x0403), (602158, 0x0401), (602163, 0x0104), (602164, 0x0104), (602165, 0x0104), (602166, 0x0304), (602167, 0x0404),
+    (602168, 0x0403), (602169, 0x0401), (602174, 0x0104), (602175, 0x0104), (602176, 0x0104), (602177, 0x0304), (602178, 0x0404), (602179, 0x0402),
+    (602180, 0x0401), (602181, 0x0401), (602185, 0x0104), (602186, 0x0104), (602187, 0x0104), (602188, 0x0204), (602189, 0x0404), (602190, 0x0402),
+    (602191, 0x0401), (602192, 0x0401), (602196, 0x0104), (602197, 0x0104), (602198, 0x0104), (602199, 0x0104), (602200, 0x0304), (602201, 0x0402),
+    (602202, 0x0401), (602203, 0x0401), (602207, 0x0104), (602208, 0x0104), (602209, 0x0104), (602210, 0x0104), (602211, 0x0204), (602212, 0x0403),
+    (602213, 0x0401), (602214, 0x0401), (602215, 0x0401), (602218, 0x0104), (602219, 0x0104), (602220, 0x0104), (602221, 0x0104), (602222, 0x0104),
+    (602223, 0x0304), (602224, 0x0402), (602225, 0x0401), (602226, 0x0401), (602229, 0x0104), (602230, 0x0104), (602231, 0x0104), (602232, 0x0104),
+    (602233, 0x0104), (602234, 0x0204), (602235, 0x0404), (602236, 0x0401), (602237, 0x0401), (602240, 0x0104), (602241, 0x0104), (602242, 0x0104),
+    (602243, 0x0104), (602244, 0x0104), (602245, 0x0104), (602246, 0x0204), (602284, 0x0104), (602285, 0x0104), (602286, 0x0204), (602287, 0x0403),
+    (602288, 0x0402), (602289, 0x0401), (602295, 0x0104), (602296, 0x0104), (602297, 0x0204), (602298, 0x0404), (602299, 0x0401), (602300, 0x0401),
+    (602301, 0x0401), (602306, 0x0104), (602307, 0x0104), (602308, 0x0204), (602309, 0x0404), (602310, 0x0402), (602311, 0x0401), (602312, 0x0401),
+    (602317, 0x0104), (602318, 0x0104), (602319, 0x0104), (602320, 0x0204), (602321, 0x0402), (602322, 0x0401), (602323, 0x0401), (602328, 0x0104),
+    (602329, 0x0104), (602330, 0x0104), (602331, 0x0204), (602332, 0x0403), (602333, 0x0401), (602334, 0x0401), (602335, 0x0401), (602339, 0x0104),
+    (602340, 0x0104), (602341, 0x0104), (602342, 0x0104), (602343, 0x0304), (602344, 0x0402), (602345, 0x0401), (602346, 0x0401), (602350, 0x0104),
+    (602351, 0x0104), (602352, 0x0104), (602353, 0x0104), (602354, 0x0204), (602355, 0x0403), (602356, 0x0401), (602357, 0x0401), (602361, 0x0104),
+    (602362, 0x0104), (602363, 0x0104), (602364, 0x0104), (602365, 0x0104), (602366, 0x0204), (602416, 0x0104), (602417, 0x0104), (602418, 0x0204),
+    (602419, 0x0404), (602420, 0x0403), (602421, 0x0402), (602422, 0x0401), (602427, 0x0104), (602428, 0x0104), (602429, 0x0204), (602430, 0x0404),
+    (602431, 0x0402), (602432, 0x0402), (602433, 0x0401), (602438, 0x0104), (602439, 0x0104), (602440, 0x0104), (602441, 0x0304), (602442, 0x0402),
+    (602443, 0x0401), (602444, 0x0401), (602449, 0x0104), (602450, 0x0104), (602451, 0x0104), (602452, 0x0204), (602453, 0x0403), (602454, 0x0401),
+    (602455, 0x0401), (602456, 0x0401), (602460, 0x0104), (602461, 0x0104), (602462, 0x0104), (602463, 0x0204), (602464, 0x0404), (602465, 0x0401),
+    (602466, 0x0401), (602467, 0x0401), (602471, 0x0104), (602472, 0x0104), (602473, 0x0104), (602474, 0x0104), (602475, 0x0304), (602476, 0x0402),
+    (602477, 0x0401), (602478, 0x0401), (602482, 0x0104), (602483, 0x0104), (602484, 0x0104), (602485, 0x0104), (602486, 0x0204), (602487, 0x0403),
+    (602488, 0x0401), (602489, 0x0401), (602493, 0x0104), (602494, 0x0104), (602495, 0x0104), (602496, 0x0104), (602497, 0x0104), (602498, 0x0402),
+    (602548, 0x0104), (602549, 0x0104), (602550, 0x0204), (602551, 0x0404), (602552, 0x0403), (602553, 0x0402), (602554, 0x0401), (602559, 0x0104),
+    (602560, 0x0104), (602561, 0x0204), (602562, 0x0404), (602563, 0x0402), (602564, 0x0402), (602565, 0x0401), (602570, 0x0104), (602571, 0x0104),
+    (602572, 0x0104), (602573, 0x0404), (602574, 0x0402), (602575, 0x0401), (602576, 0x0401), (602581, 0x0104), (602582, 0x0104), (602583, 0x0104),
+    (602584, 0x0304), (602585, 0x0403), (602586, 0x0401), (602587, 0x0401), (602588, 0x0401), (602592, 0x0104), (602593, 0x0104), (602594, 0x0104),
+    (602595, 0x0204), (602596, 0x0404), (602597, 0x0401), (602598, 0x0401), (602599, 0x0401), (602603, 0x0104), (602604, 0x0104), (602605, 0x0104),
+    (602606, 0x0104), (602607, 0x0304), (602608, 0x0402), (602609, 0x0401), (602610, 0x0401), (602614, 0x0104), (602615, 0x0104), (602616, 0x0104),
+    (602617, 0x0104), (602618, 0x0204), (602619, 0x0404), (602620, 0x0401), (602621, 0x0401), (602625, 0x0104), (602626, 0x0104), (602627, 0x0104),
+    (602628, 0x0104), (602629, 0x0104), (602630, 0x0204), (602631, 0x0403), (602680, 0x0104), (602681, 0x0104), (602682, 0x0304), (602683, 0x0402),
+    (602684, 0x0401), (602685, 0x0401), (602691, 0x0104), (602692, 0x0104), (602693, 0x0304), (602694, 0x0402), (602695, 0x0401), (602696, 0x0401),
+    (602702, 0x0104), (602703, 0x0104), (602704, 0x0204), (602705, 0x0402), (602706, 0x0401), (602707, 0x0401), (602708, 0x0401), (602713, 0x0104),
+    (602714, 0x0104), (602715, 0x0104), (602716, 0x0404), (602717, 0x0401), (602718, 0x0401), (602719, 0x0401), (602724, 0x0104), (602725, 0x0104),
+    (602726, 0x0104), (602727, 0x0304), (602728, 0x0402), (602729, 0x0401), (602730, 0x0401), (602735, 0x0104), (602736, 0x0104), (602737, 0x0104),
+    (602738, 0x0104), (602739, 0x0404), (602740, 0x0401), (602741, 0x0401), (602746, 0x0104), (602747, 0x0104), (602748, 0x0104), (602749, 0x0104),
+    (602750, 0x0204), (602751, 0x0402), (602752, 0x0401), (602812, 0x0104), (602813, 0x0104), (602814, 0x0204), (602815, 0x0403), (602816, 0x0402),
+    (602817, 0x0402), (602823, 0x0104), (602824, 0x0104), (602825, 0x0204), (602826, 0x0403), (602827, 0x0402), (602828, 0x0401), (602829, 0x0401),
+    (602834, 0x0104), (602835, 0x0104), (602836, 0x0204), (602837, 0x0403), (602838, 0x0402), (602839, 0x0401), (602840, 0x0401), (602845, 0x0104),
+    (602846, 0x0104), (602847, 0x0104), (602848, 0x0304), (602849, 0x0402), (602850, 0x0401), (602851, 0x0401), (602856, 0x0104), (602857, 0x0104),
+    (602858, 0x0104), (602859, 0x0204), (602860, 0x0403), (602861, 0x0401), (602862, 0x0401), (602867, 0x0104), (602868, 0x0104), (602869, 0x0104),
+    (602870, 0x0104), (602871, 0x0404), (602872, 0x0402), (602873, 0x0401), (602878, 0x0104), (602879, 0x0104), (602880, 0x0104), (602881, 0x0104),
+    (602882, 0x0304), (602883, 0x0403), (602884, 0x0401), (602889, 0x0104), (602890, 0x0104), (602891, 0x0104), (602944, 0x0104), (602945, 0x0104),
+    (602946, 0x0204), (602947, 0x0403), (602948, 0x0401), (602949, 0x0401), (602955, 0x0104), (602956, 0x0104), (602957, 0x0204), (602958, 0x0403),
+    (602959, 0x0401), (602960, 0x0401), (602966, 0x0104), (602967, 0x0104), (602968, 0x0104), (602969, 0x0404), (602970, 0x0402), (602971, 0x0401),
+    (602972, 0x0401), (602977, 0x0104), (602978, 0x0104), (602979, 0x0104), (602980, 0x0204), (602981, 0x0402), (602982, 0x0401), (602983, 0x0401),
+    (602988, 0x0104), (602989, 0x0104), (602990, 0x0104), (602991, 0x0204), (602992, 0x0404), (602993, 0x0401), (602994, 0x0401), (602999, 0x0104),
+    (603000, 0x0104), (603001, 0x0104), (603002, 0x0104), (603003, 0x0304), (603004, 0x0401), (603005, 0x0401), (603006, 0x0401), (603010, 0x0104),
+    (603011, 0x0104), (603012, 0x0104), (603013, 0x0104), (603014, 0x0204), (603015, 0x0403), (603016, 0x0401), (603021, 0x0104), (603022, 0x0104),
+    (603023, 0x0104), (603024, 0x0104), (603025, 0x0204), (603026, 0x0403), (603076, 0x0104), (603077, 0x0104), (603078, 0x0204), (603079, 0x0403),
+    (603080, 0x0401), (603081, 0x0401), (603087, 0x0104), (603088, 0x0104), (603089, 0x0204), (603090, 0x0403), (603091, 0x0401), (603092, 0x0401),
+    (603098, 0x0104), (603099, 0x0104), (603100, 0x0104), (603101, 0x0404), (603102, 0x0401), (603103, 0x0401), (603104, 0x0401), (603109, 0x0104),
+    (603110, 0x0104), (603111, 0x0104), (603112, 0x0304), (603113, 0x0401), (603114, 0x0401), (603115, 0x0401), (603120, 0x0104), (603121, 0x0104),
+    (603122, 0x0104), (603123, 0x0204), (603124, 0x0403), (603125, 0x0401), (603126, 0x0401), (603127, 0x0401), (603131, 0x0104), (603132, 0x0104),
+    (603133, 0x0104), (603134, 0x0204), (603135, 0x0304), (603136, 0x0401), (603137, 0x0401), (603138, 0x0401), (603142, 0x0104), (603143, 0x0104),
+    (603144, 0x0104), (603145, 0x0104), (603146, 0x0204), (603147, 0x0403), (603148, 0x0401), (603149, 0x0401), (603153, 0x0104), (603154, 0x0104),
+    (603155, 0x0104), (603156, 0x0104), (603157, 0x0204), (603158, 0x0304), (603208, 0x0104), (603209, 0x0204), (603210, 0x0403), (603211, 0x0401),
+    (603212, 0x0401), (603219, 0x0104), (603220, 0x0204), (603221, 0x0403), (603222, 0x0401), (603223, 0x0401), (603224, 0x0401), (603230, 0x0104),
+    (603231, 0x0104), (603232, 0x0404), (603233, 0x0401), (603234, 0x0401), (603235, 0x0401), (603241, 0x0104), (603242, 0x0104), (603243, 0x0204),
+    (603244, 0x0402), (603245, 0x0401), (603246, 0x0401), (603247, 0x0401), (603252, 0x0104), (603253, 0x0104), (603254, 0x0104), (603255, 0x0404),
+    (603256, 0x0401), (603257, 0x0401), (603258, 0x0401), (603263, 0x0104), (603264, 0x0104), (603265, 0x0104), (603266, 0x0204), (603267, 0x0403),
+    (603268, 0x0401), (603269, 0x0401), (603270, 0x0401), (603274, 0x0104), (603275, 0x0104), (603276, 0x0104), (603277, 0x0104), (603278, 0x0204),
+    (603279, 0x0403), (603280, 0x0401), (603340, 0x0104), (603341, 0x0104), (603342, 0x0304), (603343, 0x0402), (603344, 0x0401), (603351, 0x0104),
+    (603352, 0x0104), (603353, 0x0304), (603354, 0x0402), (603355, 0x0401), (603356, 0x0401), (603362, 0x0104), (603363, 0x0104), (603364, 0x0304),
+    (603365, 0x0402), (603366, 0x0401), (603367, 0x0401), (603368, 0x0401), (603373, 0x0104), (603374, 0x0104), (603375, 0x0104), (603376, 0x0404),
+    (603377, 0x0401), (603378, 0x0401), (603379, 0x0401), (603384, 0x0104), (603385, 0x0104), (603386, 0x0104), (603387, 0x0204), (603388, 0x0402),
+    (603389, 0x0401), (603390, 0x0401), (603391, 0x0401), (603395, 0x0104), (603396, 0x0104), (603397, 0x0104), (603398, 0x0204), (603399, 0x0403),
+    (603400, 0x0401), (603401, 0x0401), (603402, 0x0401), (603406, 0x0104), (603407, 0x0104), (603408, 0x0104), (603409, 0x0104), (603410, 0x0204),
+    (603411, 0x0403), (603412, 0x0401), (603413, 0x0401), (603417, 0x0104), (603418, 0x0104), (603419, 0x0104), (603420, 0x0104), (603421, 0x0204),
+    (603422, 0x0404), (603472, 0x0104), (603473, 0x0104), (603474, 0x0304), (603475, 0x0402), (603476, 0x0401), (603477, 0x0401), (603483, 0x0104),
+    (603484, 0x0104), (603485, 0x0204), (603486, 0x0403), (603487, 0x0401), (603488, 0x0401), (603494, 0x0104), (603495, 0x0104), (603496, 0x0204),
+    (603497, 0x0403), (603498, 0x0401), (603499, 0x0401), (603500, 0x0401), (603505, 0x0104), (603506, 0x0104), (603507, 0x0104), (603508, 0x0304),
+    (603509, 0x0401), (603510, 0x0401), (603511, 0x0401), (603516, 0x0104), (603517, 0x0104), (603518, 0x0104), (603519, 0x0204), (603520, 0x0402),
+    (603521, 0x0401), (603522, 0x0401), (603523, 0x0401), (603527, 0x0104), (603528, 0x0104), (603529, 0x0104), (603530, 0x0204), (603531, 0x0403),
+    (603532, 0x0401), (603533, 0x0401), (603534, 0x0401), (603538, 0x0104), (603539, 0x0104), (603540, 0x0104), (603541, 0x0104), (603542, 0x0204),
+    (603543, 0x0403), (603544, 0x0401), (603545, 0x0401), (603549, 0x0104), (603550, 0x0104), (603551, 0x0104), (603552, 0x0104), (603553, 0x0104),
+    (603554, 0x0404), (603555, 0x0402), (603604, 0x0104), (603605, 0x0104), (603606, 0x0304), (603607, 0x0402), (603608, 0x0401), (603609, 0x0401),
+    (603615, 0x0104), (603616, 0x0104), (603617, 0x0304), (603618, 0x0402), (603619, 0x0401), (603620, 0x0401), (603626, 0x0104), (603627, 0x0104),
+    (603628, 0x0204), (603629, 0x0403), (603630, 0x0401), (603631, 0x0401), (603632, 0x0401), (603637, 0x0104), (603638, 0x0104), (603639, 0x0104),
+    (603640, 0x0404), (603641, 0x0401), (603642, 0x0401), (603643, 0x0401), (603648, 0x0104), (603649, 0x0104), (603650, 0x0104), (603651, 0x0304),
+    (603652, 0x0402), (603653, 0x0401), (603654, 0x0401), (603655, 0x0401), (603659, 0x0104), (603660, 0x0104), (603661, 0x0104), (603662, 0x0204),
+    (603663, 0x0404), (603664, 0x0401), (603665, 0x0401), (603666, 0x0401), (603670, 0x0104), (603671, 0x0104), (603672, 0x0104), (603673, 0x0104),
+    (603674, 0x0204), (603675, 0x0403), (603676, 0x0401), (603677, 0x0401), (603681, 0x0104), (603682, 0x0104), (603683, 0x0104), (603684, 0x0104),
+    (603685, 0x0104), (603686, 0x0304), (603687, 0x0401), (603736, 0x0104), (603737, 0x0204), (603738, 0x0403), (603739, 0x0401), (603740, 0x0401),
+    (603741, 0x0401), (603747, 0x0104), (603748, 0x0204), (603749, 0x0404), (603750, 0x0401), (603751, 0x0401), (603752, 0x0401), (603758, 0x0104),
+    (603759, 0x0104), (603760, 0x0304), (603761, 0x0401), (603762, 0x0401), (603763, 0x0401), (603764, 0x0401), (603769, 0x0104), (603770, 0x0104),
+    (603771, 0x0204), (603772, 0x0402), (603773, 0x0401), (603774, 0x0401), (603775, 0x0401), (603780, 0x0104), (603781, 0x0104), (603782, 0x0104),
+    (603783, 0x0304), (603784, 0x0401), (603785, 0x0401), (603786, 0x0401), (603787, 0x0401), (603791, 0x0104), (603792, 0x0104), (603793, 0x0104),
+    (603794, 0x0204), (603795, 0x0403), (603796, 0x0401), (603797, 0x0401), (603798, 0x0401), (603802, 0x0104), (603803, 0x0104), (603804, 0x0104),
+    (603805, 0x0104), (603806, 0x0204), (603807, 0x0403), (603808, 0x0401), (603809, 0x0401), (603813, 0x0104), (603814, 0x0104), (603815, 0x0104),
+    (603816, 0x0104), (603868, 0x0104), (603869, 0x0403), (603870, 0x0401), (603871, 0x0401), (603872, 0x0401), (603873, 0x0401), (603879, 0x0104),
+    (603880, 0x0304), (603881, 0x0402), (603882, 0x0401), (603883, 0x0401), (603884, 0x0401), (603890, 0x0104), (603891, 0x0204), (603892, 0x0404),
+    (603893, 0x0401), (603894, 0x0401), (603895, 0x0401), (603896, 0x0401), (603901, 0x0104), (603902, 0x0104), (603903, 0x0304), (603904, 0x0402),
+    (603905, 0x0401), (603906, 0x0401), (603907, 0x0401), (603912, 0x0104), (603913, 0x0104), (603914, 0x0104), (603915, 0x0404), (603916, 0x0401),
+    (603917, 0x0401), (603918, 0x0401), (603923, 0x0104), (603924, 0x0104), (603925, 0x0104), (603926, 0x0204), (603927, 0x0404), (603928, 0x0401),
+    (603929, 0x0401), (603934, 0x0104), (603935, 0x0104), (603936, 0x0104), (603937, 0x0104), (603938, 0x0204), (603939, 0x0402), (604000, 0x0104),
+    (604001, 0x0204), (604002, 0x0404), (604003, 0x0401), (604004, 0x0401), (604005, 0x0401), (604011, 0x0104), (604012, 0x0204), (604013, 0x0403),
+    (604014, 0x0401), (604015, 0x0401), (604016, 0x0401), (604022, 0x0104), (604023, 0x0104), (604024, 0x0404), (604025, 0x0401), (604026, 0x0401),
+    (604027, 0x0401), (604028, 0x0401), (604033, 0x0104), (604034, 0x0104), (604035, 0x0204), (604036, 0x0404), (604037, 0x0401), (604038, 0x0401),
+    (604039, 0x0401), (604044, 0x0104), (604045, 0x0104), (604046, 0x0104), (604047, 0x0304), (604048, 0x0401), (604049, 0x0401), (604050, 0x0401),
+    (604051, 0x0401), (604055, 0x0104), (604056, 0x0104), (604057, 0x0104), (604058, 0x0104), (604059, 0x0403), (604060, 0x0401), (604061, 0x0401),
+    (604062, 0x0401), (604066, 0x0104), (604067, 0x0104), (604068, 0x0104), (604069, 0x0104), (604070, 0x0204), (604071, 0x0403), (604072, 0x0401),
+    (604073, 0x0401), (604132, 0x0104), (604133, 0x0104), (604134, 0x0304), (604135, 0x0403), (604136, 0x0402), (604137, 0x0401), (604143, 0x0104),
+    (604144, 0x0104), (604145, 0x0404), (604146, 0x0403), (604147, 0x0401), (604148, 0x0401), (604149, 0x0401), (604154, 0x0104), (604155, 0x0104),
+    (604156, 0x0204), (604157, 0x0402), (604158, 0x0401), (604159, 0x0401), (604160, 0x0401), (604165, 0x0104), (604166, 0x0104), (604167, 0x0204),
+    (604168, 0x0404), (604169, 0x0401), (604170, 0x0401), (604171, 0x0401), (604172, 0x0401), (604176, 0x0104), (604177, 0x0104), (604178, 0x0104),
+    (604179, 0x0304), (604180, 0x0402), (604181, 0x0401), (604182, 0x0401), (604183, 0x0401), (604187, 0x0104), (604188, 0x0104), (604189, 0x0104),
+    (604190, 0x0104), (604191, 0x0404), (604192, 0x0401), (604193, 0x0401), (604194, 0x0401), (604198, 0x0104), (604199, 0x0104), (604200, 0x0104),
+    (604201, 0x0104), (604202, 0x0104), (604203, 0x0404), (604204, 0x0401), (604205, 0x0401), (604209, 0x0104), (604210, 0x0104), (604211, 0x0104),
+    (604212, 0x0104), (604213, 0x0104), (604214, 0x0304), (604215, 0x0402), (604264, 0x0104), (604265, 0x0104), (604266, 0x0204), (604267, 0x0404),
+    (604268, 0x0402), (604269, 0x0401), (604275, 0x0104), (604276, 0x0104), (604277, 0x0304), (604278, 0x0403), (604279, 0x0401), (604280, 0x0401),
+    (604281, 0x0401), (604286, 0x0104), (604287, 0x0104), (604288, 0x0204), (604289, 0x0403), (604290, 0x0401), (604291, 0x0401), (604292, 0x0401),
+    (604297, 0x0104), (604298, 0x0104), (604299, 0x0104), (604300, 0x0304), (604301, 0x0401), (604302, 0x0401), (604303, 0x0401), (604304, 0x0401),
+    (604308, 0x0104), (604309, 0x0104), (604310, 0x0104), (604311, 0x0204), (604312, 0x0402), (604313, 0x0401), (604314, 0x0401), (604315, 0x0401),
+    (604319, 0x0104), (604320, 0x0104), (604321, 0x0104), (604322, 0x0204), (604323, 0x0403), (604324, 0x0401), (604325, 0x0401), (604326, 0x0401),
+    (604330, 0x0104), (604331, 0x0104), (604332, 0x0104), (604333, 0x0104), (604334, 0x0104), (604335, 0x0404), (604336, 0x0401), (604337, 0x0401),
+    (604341, 0x0104), (604342, 0x0104), (604343, 0x0104), (604344, 0x0104), (604345, 0x0104), (604346, 0x0204), (604347, 0x0401), (604396, 0x0104),
+    (604397, 0x0104), (604398, 0x0304), (604399, 0x0403), (604400, 0x0402), (604401, 0x0401), (604407, 0x0104), (604408, 0x0104), (604409, 0x0304),
+    (604410, 0x0403), (604411, 0x0401), (604412, 0x0401), (604413, 0x0401), (604418, 0x0104), (604419, 0x0104), (604420, 0x0204), (604421, 0x0403),
+    (604422, 0x0401), (604423, 0x0401), (604424, 0x0401), (604429, 0x0104), (604430, 0x0104), (604431, 0x0204), (604432, 0x0304), (604433, 0x0401),
+    (604434, 0x0401), (604435, 0x0401), (604436, 0x0401), (604440, 0x0104), (604441, 0x0104), (604442, 0x0104), (604443, 0x0204), (604444, 0x0402),
+    (604445, 0x0401), (604446, 0x0401), (604447, 0x0401), (604451, 0x0104), (604452, 0x0104), (604453, 0x0104), (604454, 0x0104), (604455, 0x0404),
+    (604456, 0x0401), (604457, 0x0401), (604458, 0x0401), (604462, 0x0104), (604463, 0x0104), (604464, 0x0104), (604465, 0x0104), (604466, 0x0104),
+    (604467, 0x0403), (604468, 0x0401), (604469, 0x0401), (604473, 0x0104), (604474, 0x0104), (604475, 0x0104), (604476, 0x0104), (604477, 0x0104),
+    (604478, 0x0304), (604528, 0x0104), (604529, 0x0204), (604530, 0x0404), (604531, 0x0402), (604532, 0x0401), (604533, 0x0401), (604539, 0x0104),
+    (604540, 0x0204), (604541, 0x0404), (604542, 0x0401), (604543, 0x0401), (604544, 0x0401), (604550, 0x0104), (604551, 0x0104), (604552, 0x0304),
+    (604553, 0x0401), (604554, 0x0401), (604555, 0x0401), (604556, 0x0401), (604561, 0x0104), (604562, 0x0104), (604563, 0x0204), (604564, 0x0403),
+    (604565, 0x0401), (604566, 0x0401), (604567, 0x0401), (604572, 0x0104), (604573, 0x0104), (604574, 0x0104), (604575, 0x0304), (604576, 0x0401),
+    (604577, 0x0401), (604578, 0x0401), (604579, 0x0401), (604583, 0x0104), (604584, 0x0104), (604585, 0x0104), (604586, 0x0204), (604587, 0x0404),
+    (604588, 0x0401), (604589, 0x0401), (604590, 0x0401), (604594, 0x0104), (604595, 0x0104), (604596, 0x0104), (604597, 0x0104), (604598, 0x0204),
+    (604599, 0x0402), (604600, 0x0401), (604660, 0x0104), (604661, 0x0403), (604662, 0x0401), (604663, 0x0401), (604664, 0x0401), (604671, 0x0104),
+    (604672, 0x0404), (604673, 0x0401), (604674, 0x0401), (604675, 0x0401), (604676, 0x0401), (604682, 0x0104), (604683, 0x0204), (604684, 0x0402),
+    (604685, 0x0401), (604686, 0x0401), (604687, 0x0401), (604693, 0x0104), (604694, 0x0104), (604695, 0x0404), (604696, 0x0401), (604697, 0x0401),
+    (604698, 0x0401), (604704, 0x0104), (604705, 0x0104), (604706, 0x0204), (604707, 0x0403), (604708, 0x0401), (604709, 0x0401), (604715, 0x0104),
+    (604716, 0x0104), (604717, 0x0104), (604792, 0x0104), (604793, 0x0304), (604794, 0x0402), (604795, 0x0401), (604796, 0x0401), (604803, 0x0104),
+    (604804, 0x0204), (604805, 0x0403), (604806, 0x0401), (604807, 0x0401), (604808, 0x0401), (604814, 0x0104), (604815, 0x0104), (604816, 0x0404),
+    (604817, 0x0401), (604818, 0x0401), (604819, 0x0401), (604825, 0x0104), (604826, 0x0104), (604827, 0x0304), (604828, 0x0402), (604829, 0x0401),
+    (604830, 0x0401), (604836, 0x0104), (604837, 0x0104), (604838, 0x0104), (604839, 0x0304), (604840, 0x0401), (604841, 0x0401), (604847, 0x0104),
+    (604848, 0x0104), (604849, 0x0104), (604850, 0x0304), (604851, 0x0402), (604852, 0x0401), (604924, 0x0104), (604925, 0x0204), (604926, 0x0304),
+    (604927, 0x0402), (604928, 0x0401), (604929, 0x0401), (604935, 0x0104), (604936, 0x0104), (604937, 0x0304), (604938, 0x0401), (604939, 0x0401),
+    (604940, 0x0401), (604946, 0x0104), (604947, 0x0104), (604948, 0x0304), (604949, 0x0401), (604950, 0x0401), (604951, 0x0401), (604957, 0x0104),
+    (604958, 0x0104), (604959, 0x0204), (604960, 0x0403), (604961, 0x0401), (604962, 0x0401), (604963, 0x0401), (604968, 0x0104), (604969, 0x0104),
+    (604970, 0x0104), (604971, 0x0304), (604972, 0x0401), (604973, 0x0401), (604974, 0x0401), (604979, 0x0104), (604980, 0x0104), (604981, 0x0104),
+    (604982, 0x0204), (604983, 0x0403), (604984, 0x0401), (604985, 0x0401), (605056, 0x0104), (605057, 0x0104), (605058, 0x0204), (605059, 0x0402),
+    (605060, 0x0401), (605067, 0x0104), (605068, 0x0104), (605069, 0x0304), (605070, 0x0402), (605071, 0x0401), (605072, 0x0401), (605078, 0x0104),
+    (605079, 0x0104), (605080, 0x0204), (605081, 0x0402), (605082, 0x0401), (605083, 0x0401), (605089, 0x0104), (605090, 0x0104), (605091, 0x0104),
+    (605092, 0x0403), (605093, 0x0401), (605094, 0x0401), (605095, 0x0401), (605100, 0x0104), (605101, 0x0104), (605102, 0x0104), (605103, 0x0304),
+    (605104, 0x0401), (605105, 0x0401), (605106, 0x0401), (605111, 0x0104), (605112, 0x0104), (605113, 0x0104), (605114, 0x0104), (605115, 0x0404),
+    (605116, 0x0401), (605117, 0x0401), (605122, 0x0104), (605123, 0x0104), (605124, 0x0104), (605125, 0x0104), (605188, 0x0104), (605189, 0x0104),
+    (605190, 0x0204), (605191, 0x0402), (605192, 0x0401), (605199, 0x0104), (605200, 0x0104), (605201, 0x0304), (605202, 0x0402), (605203, 0x0401),
+    (605204, 0x0401), (605210, 0x0104), (605211, 0x0104), (605212, 0x0204), (605213, 0x0402), (605214, 0x0401), (605215, 0x0401), (605221, 0x0104),
+    (605222, 0x0104), (605223, 0x0204), (605224, 0x0403), (605225, 0x0401), (605226, 0x0401), (605227, 0x0401), (605232, 0x0104), (605233, 0x0104),
+    (605234, 0x0104), (605235, 0x0304), (605236, 0x0401), (605237, 0x0401), (605238, 0x0401), (605243, 0x0104), (605244, 0x0104), (605245, 0x0104),
+    (605246, 0x0104), (605247, 0x0403), (605248, 0x0401), (605320, 0x0104), (605321, 0x0104), (605322, 0x0304), (605323, 0x0401), (605324, 0x0401),
+    (605331, 0x0104), (605332, 0x0104), (605333, 0x0404), (605334, 0x0402), (605335, 0x0401), (605336, 0x0401), (605342, 0x0104), (605343, 0x0104),
+    (605344, 0x0304), (605345, 0x0401), (605346, 0x0401), (605347, 0x0401), (605353, 0x0104), (605354, 0x0104), (605355, 0x0204), (605356, 0x0402),
+    (605357, 0x0401), (605358, 0x0401), (605364, 0x0104), (605365, 0x0104), (605366, 0x0104), (605367, 0x0304), (605368, 0x0401), (605369, 0x0401),
+    (605375, 0x0104), (605376, 0x0104), (605377, 0x0104), (605378, 0x0204), (605379, 0x0403), (605380, 0x0401), (605452, 0x0104), (605453, 0x0204),
+    (605454, 0x0403), (605455, 0x0401), (605456, 0x0401), (605463, 0x0104), (605464, 0x0204), (605465, 0x0403), (605466, 0x0401), (605467, 0x0401),
+    (605474, 0x0104), (605475, 0x0204), (605476, 0x0304), (605477, 0x0401), (605478, 0x0401), (605479, 0x0401), (605485, 0x0104), (605486, 0x0104),
+    (605487, 0x0204), (605488, 0x0402), (605489, 0x0401), (605490, 0x0401), (605496, 0x0104), (605497, 0x0104), (605498, 0x0104), (605499, 0x0403),
+    (605500, 0x0401), (605584, 0x0104), (605585, 0x0104), (605586, 0x0404), (605587, 0x0401), (605595, 0x0104), (605596, 0x0104), (605597, 0x0404),
+    (605598, 0x0401), (605599, 0x0401), (605606, 0x0104), (605607, 0x0104), (605608, 0x0304), (605609, 0x0402), (605610, 0x0401), (605617, 0x0104),
+    (605618, 0x0104), (605619, 0x0204), (605620, 0x0403), (605621, 0x0401), (605628, 0x0104), (605629, 0x0104), (605630, 0x0204), (605631, 0x0404),
+    (605632, 0x0401), (608356, 0x0104), (608357, 0x0104), (608358, 0x0204), (608359, 0x0304), (608360, 0x0304), (608367, 0x0104), (608368, 0x0104),
+    (608369, 0x0304), (608370, 0x0404), (608371, 0x0404), (608372, 0x0404), (608373, 0x0403), (608378, 0x0104), (608379, 0x0104), (608380, 0x0104),
+    (608381, 0x0204), (608382, 0x0304), (608383, 0x0404), (608384, 0x0402), (608385, 0x0401), (608389, 0x0104), (608390, 0x0104), (608391, 0x0104),
+    (608392, 0x0104), (608393, 0x0204), (608394, 0x0304), (608395, 0x0402), (608396, 0x0401), (608397, 0x0401), (608400, 0x0104), (608401, 0x0104),
+    (608402, 0x0104), (608403, 0x0104), (608404, 0x0104), (608405, 0x0304), (608406, 0x0403), (608407, 0x0401), (608408, 0x0401), (608411, 0x0104),
+    (608412, 0x0104), (608413, 0x0104), (608414, 0x0104), (608415, 0x0104), (608416, 0x0204), (608417, 0x0403), (608418, 0x0401), (608419, 0x0401),
+    (608422, 0x0104), (608423, 0x0104), (608424, 0x0104), (608425, 0x0104), (608426, 0x0104), (608427, 0x0104), (608428, 0x0404), (608429, 0x0401),
+    (608430, 0x0401), (608431, 0x0401), (608433, 0x0104), (608434, 0x0104), (608435, 0x0104), (608436, 0x0104), (608437, 0x0104), (608438, 0x0104),
+    (608439, 0x0204), (608440, 0x0403), (608441, 0x0401), (608442, 0x0401), (608444, 0x0104), (608445, 0x0104), (608446, 0x0104), (608447, 0x0104),
+    (608448, 0x0104), (608449, 0x0104), (608450, 0x0104), (608451, 0x0404), (608452, 0x0401), (608453, 0x0401), (608488, 0x0104), (608489, 0x0104),
+    (608490, 0x0204), (608491, 0x0304), (608492, 0x0404), (608493, 0x0404), (608499, 0x0104), (608500, 0x0104), (608501, 0x0104), (608502, 0x0304),
+    (608503, 0x0403), (608504, 0x0402), (608505, 0x0402), (608510, 0x0104), (608511, 0x0104), (608512, 0x0104), (608513, 0x0104), (608514, 0x0304),
+    (608515, 0x0402), (608516, 0x0402), (608517, 0x0401), (608521, 0x0104), (608522, 0x0104), (608523, 0x0104), (608524, 0x0104), (608525, 0x0204),
+    (608526, 0x0404), (608527, 0x0401), (608528, 0x0401), (608532, 0x0104), (608533, 0x0104), (608534, 0x0104), (608535, 0x0104), (608536, 0x0204),
+    (608537, 0x0404), (608538, 0x0402), (608539, 0x0401), (608540, 0x0401), (608543, 0x0104), (608544, 0x0104), (608545, 0x0104), (608546, 0x0104),
+    (608547, 0x0104), (608548, 0x0304), (608549, 0x0402), (608550, 0x0401), (608551, 0x0401), (608554, 0x0104), (608555, 0x0104), (608556, 0x0104),
+    (608557, 0x0104), (608558, 0x0104), (608559, 0x0204), (608560, 0x0403), (608561, 0x0401), (608562, 0x0401), (608565, 0x0104), (608566, 0x0104),
+    (608567, 0x0104), (608568, 0x0104), (608569, 0x0104), (608570, 0x0104), (608571, 0x0304), (608572, 0x0402), (608573, 0x0401), (608576, 0x0104),
+    (608577, 0x0104), (608578, 0x0104), (608579, 0x0104), (608580, 0x0104), (608581, 0x0104), (608582, 0x0204), (608583, 0x0402), (608584, 0x0401),
+    (608620, 0x0104), (608621, 0x0104), (608622, 0x0204), (608623, 0x0304), (608624, 0x0404), (608625, 0x0403), (608631, 0x0104), (608632, 0x0104),
+    (608633, 0x0204), (608634, 0x0404), (608635, 0x0404), (608636, 0x0402), (608637, 0x0402), (608642, 0x0104), (608643, 0x0104), (608644, 0x0104),
+    (608645, 0x0204), (608646, 0x0304), (608647, 0x0403), (608648, 0x0402), (608649, 0x0401), (608653, 0x0104), (608654, 0x0104), (608655, 0x0104),
+    (608656, 0x0104), (608657, 0x0204), (608658, 0x0403), (608659, 0x0402), (608660, 0x0401), (608661, 0x0401), (608664, 0x0104), (608665, 0x0104),
+    (608666, 0x0104), (608667, 0x0104), (608668, 0x0204), (608669, 0x0304), (608670, 0x0402), (608671, 0x0401), (608672, 0x0401), (608675, 0x0104),
+    (608676, 0x0104), (608677, 0x0104), (608678, 0x0104), (608679, 0x0104), (608680, 0x0304), (608681, 0x0403), (608682, 0x0401), (608683, 0x0401),
+    (608686, 0x0104), (608687, 0x0104), (608688, 0x0104), (608689, 0x0104), (608690, 0x0104), (608691, 0x0204), (608692, 0x0403), (608693, 0x0401),
+    (608694, 0x0401), (608697, 0x0104), (608698, 0x0104), (608699, 0x0104), (608700, 0x0104), (608701, 0x0104), (608702, 0x0104), (608703, 0x0304),
+    (608704, 0x0402), (608705, 0x0401), (608708, 0x0104), (608709, 0x0104), (608710, 0x0104), (608711, 0x0104), (608712, 0x0104), (608713, 0x0104),
+    (608714, 0x0204), (608715, 0x0402), (608716, 0x0401), (608752, 0x0104), (608753, 0x0104), (608754, 0x0204), (608755, 0x0304), (608756, 0x0403),
+    (608757, 0x0402), (608763, 0x0104), (608764, 0x0104), (608765, 0x0104), (608766, 0x0304), (608767, 0x0403), (608768, 0x0401), (608769, 0x0401),
+    (608774, 0x0104), (608775, 0x0104), (608776, 0x0104), (608777, 0x0204), (608778, 0x0403), (608779, 0x0401), (608780, 0x0401), (608781, 0x0401),
+    (608785, 0x0104), (608786, 0x0104), (608787, 0x0104), (608788, 0x0104), (608789, 0x0404), (608790, 0x0402), (608791, 0x0401), (608792, 0x0401),
+    (608796, 0x0104), (608797, 0x0104), (608798, 0x0104), (608799, 0x0104), (608800, 0x0304), (608801, 0x0402), (608802, 0x0401), (608803, 0x0401),
+    (608804, 0x0401), (608807, 0x0104), (608808, 0x0104), (608809, 0x0104), (608810, 0x0104), (608811, 0x0204), (608812, 0x0403), (608813, 0x0401),
+    (608814, 0x0401), (608815, 0x0401), (608818, 0x0104), (608819, 0x0104), (608820, 0x0104), (608821, 0x0104), (608822, 0x0104), (608823, 0x0404),
+    (608824, 0x0401), (608825, 0x0401), (608826, 0x0401), (608829, 0x0104), (608830, 0x0104), (608831, 0x0104), (608832, 0x0104), (608833, 0x0104),
+    (608834, 0x0204), (608835, 0x0403), (608836, 0x0401), (608837, 0x0401), (608840, 0x0104), (608841, 0x0104), (608842, 0x0104), (608843, 0x0104),
+    (608844, 0x0104), (608845, 0x0204), (608846, 0x0404), (608884, 0x0104), (608885, 0x0104), (608886, 0x0104), (608887, 0x0204), (608888, 0x0403),
+    (608889, 0x0402), (608895, 0x0104), (608896, 0x0104), (608897, 0x0104), (608898, 0x0304), (608899, 0x0404), (608900, 0x0402), (608901, 0x0401),
+    (608906, 0x0104), (608907, 0x0104), (608908, 0x0104), (608909, 0x0204), (608910, 0x0404), (608911, 0x0401), (608912, 0x0401), (608913, 0x0401),
+    (608917, 0x0104), (608918, 0x0104), (608919, 0x0104), (608920, 0x0104), (608921, 0x0304), (608922, 0x0402), (608923, 0x0401), (608924, 0x0401),
+    (608928, 0x0104), (608929, 0x0104), (608930, 0x0104), (608931, 0x0104), (608932, 0x0204), (608933, 0x0403), (608934, 0x0401), (608935, 0x0401),
+    (608936, 0x0401), (608939, 0x0104), (608940, 0x0104), (608941, 0x0104), (608942, 0x0104), (608943, 0x0204), (608944, 0x0403), (608945, 0x0401),
+    (608946, 0x0401), (608947, 0x0401), (608950, 0x0104), (608951, 0x0104), (608952, 0x0104), (608953, 0x0104), (608954, 0x0104), (608955, 0x0404),
+    (608956, 0x0401), (608957, 0x0401), (608958, 0x0401), (608961, 0x0104), (608962, 0x0104), (608963, 0x0104), (608964, 0x0104), (608965, 0x0104),
+    (608966, 0x0204), (608967, 0x0403), (608968, 0x0401), (608969, 0x0401), (608972, 0x0104), (608973, 0x0104), (608974, 0x0104), (608975, 0x0104),
+    (608976, 0x0104), (608977, 0x0104), (608978, 0x0304), (608979, 0x0401), (609016, 0x0104), (609017, 0x0104), (609018, 0x0104), (609019, 0x0204),
+    (609020, 0x0404), (609021, 0x0402), (609027, 0x0104), (609028, 0x0104), (609029, 0x0104), (609030, 0x0304), (609031, 0x0403), (609032, 0x0402),
+    (609033, 0x0401), (609038, 0x0104), (609039, 0x0104), (609040, 0x0104), (609041, 0x0204), (609042, 0x0403), (609043, 0x0401), (609044, 0x0401),
+    (609045, 0x0401), (609049, 0x0104), (609050, 0x0104), (609051, 0x0104), (609052, 0x0204), (609053, 0x0403), (609054, 0x0401), (609055, 0x0401),
+    (609056, 0x0401), (609060, 0x0104), (609061, 0x0104), (609062, 0x0104), (609063, 0x0104), (609064, 0x0404), (609065, 0x0402), (609066, 0x0401),
+    (609067, 0x0401), (609068, 0x0401), (609071, 0x0104), (609072, 0x0104), (609073, 0x0104), (609074, 0x0104), (609075, 0x0204), (609076, 0x0403),
+    (609077, 0x0401), (609078, 0x0401), (609079, 0x0401), (609082, 0x0104), (609083, 0x0104), (609084, 0x0104), (609085, 0x0104), (609086, 0x0104),
+    (609087, 0x0304), (609088, 0x0402), (609089, 0x0401), (609090, 0x0401), (609093, 0x0104), (609094, 0x0104), (609095, 0x0104), (609096, 0x0104),
+    (609097, 0x0104), (609098, 0x0104), (609099, 0x0404), (609100, 0x0401), (609101, 0x0401), (609104, 0x0104), (609105, 0x0104), (609106, 0x0104),
+    (609107, 0x0104), (609108, 0x0104), (609109, 0x0104), (609110, 0x0404), (609148, 0x0104), (609149, 0x0104), (609150, 0x0104), (609151, 0x0304),
+    (609152, 0x0402), (609153, 0x0401), (609159, 0x0104), (609160, 0x0104), (609161, 0x0104), (609162, 0x0404), (609163, 0x0402), (609164, 0x0401),
+    (609165, 0x0401), (609170, 0x0104), (609171, 0x0104), (609172, 0x0104), (609173, 0x0304), (609174, 0x0401), (609175, 0x0401), (609176, 0x0401),
+    (609181, 0x0104), (609182, 0x0104), (609183, 0x0104), (609184, 0x0304), (609185, 0x0402), (609186, 0x0401), (609187, 0x0401), (609188, 0x0401),
+    (609192, 0x0104), (609193, 0x0104), (609194, 0x0104), (609195, 0x0204), (609196, 0x0403), (609197, 0x0401), (609198, 0x0401), (609199, 0x0401),
+    (609203, 0x0104), (609204, 0x0104), (609205, 0x0104), (609206, 0x0104), (609207, 0x0404), (609208, 0x0401), (609209, 0x0401), (609210, 0x0401),
+    (609211, 0x0401), (609214, 0x0104), (609215, 0x0104), (609216, 0x0104), (609217, 0x0104), (609218, 0x0104), (609219, 0x0404), (609220, 0x0401),
+    (609221, 0x0401), (609222, 0x0401), (609225, 0x0104), (609226, 0x0104), (609227, 0x0104), (609228, 0x0104), (609229, 0x0104), (609230, 0x0204),
+    (609231, 0x0403), (609232, 0x0401), (609280, 0x0104), (609281, 0x0104), (609282, 0x0104), (609283, 0x0204), (609284, 0x0403), (609285, 0x0401),
+    (609291, 0x0104), (609292, 0x0104), (609293, 0x0104), (609294, 0x0204), (609295, 0x0403), (609296, 0x0402), (609297, 0x0401), (609302, 0x0104),
+    (609303, 0x0104), (609304, 0x0104), (609305, 0x0204), (609306, 0x0402), (609307, 0x0401), (609308, 0x0401), (609309, 0x0401), (609313, 0x0104),
+    (609314, 0x0104), (609315, 0x0104), (609316, 0x0204), (609317, 0x0403), (609318, 0x0401), (609319, 0x0401), (609320, 0x0401), (609324, 0x0104),
+    (609325, 0x0104), (609326, 0x0104), (609327, 0x0104), (609328, 0x0403), (609329, 0x0401), (609330, 0x0401), (609331, 0x0401), (609335, 0x0104),
+    (609336, 0x0104), (609337, 0x0104), (609338, 0x0104), (609339, 0x0304), (609340, 0x0402), (609341, 0x0401), (609342, 0x0401), (609343, 0x0401),
+    (609346, 0x0104), (609347, 0x0104), (609348, 0x0104), (609349, 0x0104), (609350, 0x0204), (609351, 0x0404), (609352, 0x0401), (609353, 0x0401),
+    (609354, 0x0401), (609357, 0x0104), (609358, 0x0104), (609359, 0x0104), (609360, 0x0104), (609361, 0x0104), (609362, 0x0104), (609363, 0x0403),
+    (609364, 0x0401), (609365, 0x0401), (609412, 0x0104), (609413, 0x0104), (609414, 0x0104), (609415, 0x0204), (609416, 0x0404), (609423, 0x0104),
+    (609424, 0x0104), (609425, 0x0104), (609426, 0x0204), (609427, 0x0304), (609428, 0x0402), (609429, 0x0401), (609434, 0x0104), (609435, 0x0104),
+    (609436, 0x0104), (609437, 0x0204), (609438, 0x0403), (609439, 0x0401), (609440, 0x0401), (609441, 0x0401), (609445, 0x0104), (609446, 0x0104),
+    (609447, 0x0104), (609448, 0x0204), (609449, 0x0403), (609450, 0x0401), (609451, 0x0401), (609452, 0x0401), (609456, 0x0104), (609457, 0x0104),
+    (609458, 0x0104), (609459, 0x0104), (609460, 0x0404), (609461, 0x0401), (609462, 0x0401), (609463, 0x0401), (609467, 0x0104), (609468, 0x0104),
+    (609469, 0x0104), (609470, 0x0104), (609471, 0x0304), (609472, 0x0402), (609473, 0x0401), (609474, 0x0401), (609475, 0x0401), (609478, 0x0104),
+    (609479, 0x0104), (609480, 0x0104), (609481, 0x0104), (609482, 0x0204), (609483, 0x0404), (609484, 0x0401), (609485, 0x0401), (609486, 0x0401),
+    (609489, 0x0104), (609490, 0x0104), (609491, 0x0104), (609492, 0x0104), (609493, 0x0104), (609494, 0x0204), (609495, 0x0403), (609496, 0x0401),
+    (609497, 0x0401), (609544, 0x0104), (609545, 0x0104), (609546, 0x0104), (609547, 0x0204), (609548, 0x0403), (609555, 0x0104), (609556, 0x0104),
+    (609557, 0x0104), (609558, 0x0204), (609559, 0x0404), (609560, 0x0402), (609561, 0x0401), (609566, 0x0104), (609567, 0x0104), (609568, 0x0104),
+    (609569, 0x0304), (609570, 0x0402), (609571, 0x0401), (609572, 0x0401), (609573, 0x0401), (609577, 0x0104), (609578, 0x0104), (609579, 0x0104),
+    (609580, 0x0204), (609581, 0x0402), (609582, 0x0401), (609583, 0x0401), (609584, 0x0401), (609588, 0x0104), (609589, 0x0104), (609590, 0x0104),
+    (609591, 0x0204), (609592, 0x0403), (609593, 0x0401), (609594, 0x0401), (609595, 0x0401), (609599, 0x0104), (609600, 0x0104), (609601, 0x0104),
+    (609602, 0x0104), (609603, 0x0404), (609604, 0x0401), (609605, 0x0401), (609606, 0x0401), (609607, 0x0401), (609610, 0x0104), (609611, 0x0104),
+    (609612, 0x0104), (609613, 0x0104), (609614, 0x0204), (609615, 0x0404), (609616, 0x0401), (609617, 0x0401), (609618, 0x0401), (609621, 0x0104),
+    (609622, 0x0104), (609623, 0x0104), (609624, 0x0104), (609625, 0x0104), (609626, 0x0204), (609627, 0x0403), (609628, 0x0401), (609629, 0x0401),
+    (609676, 0x0104), (609677, 0x0104), (609678, 0x0104), (609679, 0x0204), (609680, 0x0402), (609687, 0x0104), (609688, 0x0104), (609689, 0x0104),
+    (609690, 0x0304), (609691, 0x0402), (609692, 0x0401), (609698, 0x0104), (609699, 0x0104), (609700, 0x0104), (609701, 0x0404), (609702, 0x0401),
+    (609703, 0x0401), (609704, 0x0401), (609709, 0x0104), (609710, 0x0104), (609711, 0x0104), (609712, 0x0304), (609713, 0x0401), (609714, 0x0401),
+    (609715, 0x0401), (609716, 0x0401), (609720, 0x0104), (609721, 0x0104), (609722, 0x0104), (609723, 0x0204), (609724, 0x0402), (609725, 0x0401),
+    (609726, 0x0401), (609727, 0x0401), (609731, 0x0104), (609732, 0x0104), (609733, 0x0104), (609734, 0x0104), (609735, 0x0404), (609736, 0x0401),
+    (609737, 0x0401), (609738, 0x0401), (609742, 0x0104), (609743, 0x0104), (609744, 0x0104), (609745, 0x0104), (609746, 0x0104), (609747, 0x0404),
+    (609748, 0x0401), (609749, 0x0401), (609753, 0x0104), (609754, 0x0104), (609755, 0x0104), (609756, 0x0104), (609757, 0x0104), (609758, 0x0204),
+    (609759, 0x0401), (609808, 0x0104), (609809, 0x0104), (609810, 0x0104), (609811, 0x0204), (609812, 0x0404), (609819, 0x0104), (609820, 0x0104),
+    (609821, 0x0104), (609822, 0x0104), (609823, 0x0304), (609824, 0x0402), (609825, 0x0401), (609830, 0x0104), (609831, 0x0104), (609832, 0x0104),
+    (609833, 0x0204), (609834, 0x0403), (609835, 0x0401), (609836, 0x0401), (609841, 0x0104), (609842, 0x0104), (609843, 0x0104), (609844, 0x0204),
+    (609845, 0x0403), (609846, 0x0401), (609847, 0x0401), (609848, 0x0401), (609852, 0x0104), (609853, 0x0104), (609854, 0x0104), (609855, 0x0204),
+    (609856, 0x0403), (609857, 0x0401), (609858, 0x0401), (609859, 0x0401), (609863, 0x0104), (609864, 0x0104), (609865, 0x0104), (609866, 0x0104),
+    (609867, 0x0404), (609868, 0x0401), (609869, 0x0401), (609870, 0x0401), (609874, 0x0104), (609875, 0x0104), (609876, 0x0104), (609877, 0x0104),
+    (609878, 0x0104), (609879, 0x0304), (609880, 0x0401), (609881, 0x0401), (609882, 0x0401), (609885, 0x0104), (609886, 0x0104), (609887, 0x0104),
+    (609888, 0x0104), (609889, 0x0104), (609890, 0x0204), (609891, 0x0402), (609892, 0x0401), (609940, 0x0104), (609941, 0x0104), (609942, 0x0104),
+    (609943, 0x0104), (609951, 0x0104), (609952, 0x0104), (609953, 0x0104), (609954, 0x0104), (609955, 0x0204), (609956, 0x0402), (609957, 0x0401),
+    (609962, 0x0104), (609963, 0x0104), (609964, 0x0104), (609965, 0x0204), (609966, 0x0404), (609967, 0x0402), (609968, 0x0401), (609973, 0x0104),
+    (609974, 0x0104), (609975, 0x0104), (609976, 0x0204), (609977, 0x0404), (609978, 0x0401), (609979, 0x0401), (609980, 0x0401), (609984, 0x0104),
+    (609985, 0x0104), (609986, 0x0104), (609987, 0x0204), (609988, 0x0404), (609989, 0x0401), (609990, 0x0401), (609991, 0x0401), (609995, 0x0104),
+    (609996, 0x0104), (609997, 0x0104), (609998, 0x0104), (609999, 0x0304), (610000, 0x0401), (610001, 0x0401), (610002, 0x0401), (610006, 0x0104),
+    (610007, 0x0104), (610008, 0x0104), (610009, 0x0104), (610010, 0x0104), (610011, 0x0304), (610012, 0x0402), (610013, 0x0401), (610014, 0x0401),
+    (610017, 0x0104), (610018, 0x0104), (610019, 0x0104), (610020, 0x0104), (610021, 0x0104), (610022, 0x0204), (610023, 0x0402), (610024, 0x0401),
+    (610072, 0x0104), (610073, 0x0104), (610074, 0x0104), (610075, 0x0104), (610083, 0x0104), (610084, 0x0104), (610085, 0x0104), (610086, 0x0204),
+    (610087, 0x0304), (610088, 0x0401), (610094, 0x0104), (610095, 0x0104), (610096, 0x0104), (610097, 0x0104), (610098, 0x0404), (610099, 0x0401),
+    (610100, 0x0401), (610105, 0x0104), (610106, 0x0104), (610107, 0x0104), (610108, 0x0204), (610109, 0x0403), (610110, 0x0401), (610111, 0x0401),
+    (610112, 0x0401), (610116, 0x0104), (610117, 0x0104), (610118, 0x0104), (610119, 0x0104), (610120, 0x0403), (610121, 0x0401), (610122, 0x0401),
+    (610123, 0x0401), (610127, 0x0104), (610128, 0x0104), (610129, 0x0104), (610130, 0x0104), (610131, 0x0304), (610132, 0x0401), (610133, 0x0401),
+    (610134, 0x0401), (610138, 0x0104), (610139, 0x0104), (610140, 0x0104), (610141, 0x0104), (610142, 0x0104), (610143, 0x0304), (610144, 0x0402),
+    (610145, 0x0401), (610149, 0x0104), (610150, 0x0104), (610151, 0x0104), (610152, 0x0104), (610153, 0x0104), (610154, 0x0204), (610155, 0x0403),
+    (610156, 0x0401), (610204, 0x0104), (610205, 0x0104), (610206, 0x0104), (610207, 0x0204), (610215, 0x0104), (610216, 0x0104), (610217, 0x0104),
+    (610218, 0x0204), (610219, 0x0403), (610220, 0x0401), (610226, 0x0104), (610227, 0x0104), (610228, 0x0104), (610229, 0x0204), (610230, 0x0403),
+    (610231, 0x0401), (610232, 0x0401), (610237, 0x0104), (610238, 0x0104), (610239, 0x0104), (610240, 0x0204), (610241, 0x0403), (610242, 0x0401),
+    (610243, 0x0401), (610248, 0x0104), (610249, 0x0104), (610250, 0x0104), (610251, 0x0204), (610252, 0x0403), (610253, 0x0401), (610254, 0x0401),
+    (610255, 0x0401), (610259, 0x0104), (610260, 0x0104), (610261, 0x0104), (610262, 0x0104), (610263, 0x0304), (610264, 0x0402), (610265, 0x0401),
+    (610266, 0x0401), (610270, 0x0104), (610271, 0x0104), (610272, 0x0104), (610273, 0x0104), (610274, 0x0104), (610275, 0x0404), (610276, 0x0401),
+    (610277, 0x0401), (610281, 0x0104), (610282, 0x0104), (610283, 0x0104), (610284, 0x0104), (610285, 0x0104), (610286, 0x0204), (610336, 0x0104),
+    (610337, 0x0104), (610338, 0x0104), (610339, 0x0304), (610347, 0x0104), (610348, 0x0104), (610349, 0x0104), (610350, 0x0304), (610351, 0x0402),
+    (610352, 0x0401), (610358, 0x0104), (610359, 0x0104), (610360, 0x0104), (610361, 0x0304), (610362, 0x0401), (610363, 0x0401), (610364, 0x0401),
+    (610369, 0x0104), (610370, 0x0104), (610371, 0x0104), (610372, 0x0304), (610373, 0x0402), (610374, 0x0401), (610375, 0x0401), (610380, 0x0104),
+    (610381, 0x0104), (610382, 0x0104), (610383, 0x0204), (610384, 0x0402), (610385, 0x0401), (610386, 0x0401), (610391, 0x0104), (610392, 0x0104),
+    (610393, 0x0104), (610394, 0x0104), (610395, 0x0304), (610396, 0x0401), (610397, 0x0401), (610402, 0x0104), (610403, 0x0104), (610404, 0x0104),
+    (610405, 0x0104), (610406, 0x0204), (610407, 0x0402), (610479, 0x0104), (610480, 0x0104), (610481, 0x0104), (610482, 0x0104), (610483, 0x0404),
+    (610490, 0x0104), (610491, 0x0104), (610492, 0x0104), (610493, 0x0104), (610494, 0x0404), (610495, 0x0401), (610501, 0x0104), (610502, 0x0104),
+    (610503, 0x0104), (610504, 0x0104), (610505, 0x0304), (610506, 0x0401), (610507, 0x0401), (610512, 0x0104), (610513, 0x0104), (610514, 0x0104),
+    (610515, 0x0104), (610516, 0x0304), (610517, 0x0402), (610518, 0x0401), (610519, 0x0401), (610523, 0x0104), (610524, 0x0104), (610525, 0x0104),
+    (610526, 0x0104), (610527, 0x0204), (610528, 0x0403), (610529, 0x0401), (610530, 0x0401), (610534, 0x0104), (610535, 0x0104), (610536, 0x0104),
+    (610537, 0x0104), (610538, 0x0104), (610539, 0x0404), (610540, 0x0401), (610622, 0x0104), (610623, 0x0104), (610624, 0x0104), (610633, 0x0104),
+    (610634, 0x0104), (610635, 0x0104), (610636, 0x0104), (610637, 0x0204), (610638, 0x0403), (610639, 0x0401), (610644, 0x0104), (610645, 0x0104),
+    (610646, 0x0104), (610647, 0x0104), (610648, 0x0204), (610649, 0x0403), (610650, 0x0401), (610655, 0x0104), (610656, 0x0104), (610657, 0x0104),
+    (610658, 0x0104), (610659, 0x0104), (610660, 0x0404), (610661, 0x0401), (610662, 0x0401), (610666, 0x0104), (610667, 0x0104), (610668, 0x0104),
+    (610669, 0x0104), (610670, 0x0104), (610671, 0x0304), (610672, 0x0401), (610673, 0x0401), (621292, 0x0304), (621293, 0x0401), (621294, 0x0401),
+    (621295, 0x0401), (621303, 0x0304), (621304, 0x0401), (621305, 0x0401), (621306, 0x0401), (621314, 0x0404), (621315, 0x0402), (621316, 0x0401),
+    (621317, 0x0401), (621325, 0x0404), (621326, 0x0403), (621327, 0x0402), (621328, 0x0401), (621336, 0x0404), (621337, 0x0403), (621338, 0x0401),
+    (621424, 0x0404), (621425, 0x0401), (621426, 0x0401), (621427, 0x0401), (621435, 0x0404), (621436, 0x0401), (621437, 0x0401), (621438, 0x0401),
+    (621446, 0x0404), (621447, 0x0402), (621448, 0x0401), (621449, 0x0401), (621457, 0x0404), (621458, 0x0403), (621459, 0x0401), (621460, 0x0401),
+    (621468, 0x0404), (621469, 0x0403), (621556, 0x0104), (621557, 0x0401), (621558, 0x0401), (621559, 0x0401), (621567, 0x0104), (621568, 0x0402),
+    (621569, 0x0401), (621570, 0x0401), (621578, 0x0104), (621579, 0x0402), (621580, 0x0401), (621581, 0x0401), (621582, 0x0401), (621589, 0x0104),
+    (621590, 0x0404), (621591, 0x0401), (621592, 0x0401), (621600, 0x0104), (621601, 0x0304), (621602, 0x0402), (621688, 0x0402), (621689, 0x0401),
+    (621690, 0x0401), (621699, 0x0402), (621700, 0x0401), (621701, 0x0401), (621710, 0x0402), (621711, 0x0401), (621820, 0x0204), (621821, 0x0401),
+    (621822, 0x0401), (621831, 0x0404), (621832, 0x0401), (621833, 0x0401), (621842, 0x0403), (621843, 0x0401), (621844, 0x0401), (621853, 0x0403),
+    (621952, 0x0204), (621953, 0x0401), (621954, 0x0401), (621963, 0x0104), (621964, 0x0401), (621965, 0x0401), (621974, 0x0204), (621975, 0x0401),
+    (621976, 0x0401), (621985, 0x0204), (621986, 0x0401), (622084, 0x0401), (622085, 0x0401), (622095, 0x0402), (622096, 0x0401), (622216, 0x0402),
+    (622217, 0x0401), (622218, 0x0401), (622227, 0x0403), (622228, 0x0401), (622238, 0x0402), (622348, 0x0404), (622349, 0x0401), (622350, 0x0401),
+    (622359, 0x0403), (622360, 0x0401), (622370, 0x0402), (622371, 0x0401), (622480, 0x0402), (622481, 0x0401), (622482, 0x0401), (622491, 0x0402),
+    (622492, 0x0401), (622502, 0x0402), (622612, 0x0401), (622613, 0x0401), (622623, 0x0401), (622624, 0x0401), (622744, 0x0401), (622745, 0x0401),
+    (622755, 0x0401), (622756, 0x0401), (622876, 0x0402), (622877, 0x0401), (622887, 0x0401), (622888, 0x0401), (622898, 0x0402), (623008, 0x0401),
+    (623009, 0x0401), (623019, 0x0402), (623020, 0x0401), (623140, 0x0401), (623141, 0x0401), (623151, 0x0401), (623152, 0x0401), (623272, 0x0401),
+    (623273, 0x0401), (623283, 0x0401), (623404, 0x0401), (623405, 0x0401), (623415, 0x0401), (623416, 0x0401), (623536, 0x0401), (623537, 0x0401),
+    (623547, 0x0401), (623548, 0x0401), (623668, 0x0401), (623669, 0x0401), (623679, 0x0401), (623680, 0x0401), (623800, 0x0401), (623801, 0x0401),
+    (623811, 0x0401), (623812, 0x0401), (623932, 0x0401), (623933, 0x0401), (623943, 0x0401), (623944, 0x0401), (624064, 0x0401), (624065, 0x0401),
+    (624075, 0x0401), (624196, 0x0401), (624197, 0x0401), (624207, 0x0401), (624328, 0x0401), (624329, 0x0401), (624339, 0x0401), (624340, 0x0401),
+    (624460, 0x0401), (624461, 0x0401), (624471, 0x0401), (624472, 0x0401), (624592, 0x0401), (624593, 0x0401), (624603, 0x0401), (624724, 0x0401),
+    (624725, 0x0401), (624735, 0x0401), (624856, 0x0401), (624857, 0x0401), (624867, 0x0401), (624988, 0x0401), (624989, 0x0401), (624999, 0x0401),
+    (625120, 0x0401), (625121, 0x0401), (625131, 0x0401), (625252, 0x0401), (625253, 0x0401), (625263, 0x0401), (625384, 0x0401), (625385, 0x0401),
+    (625395, 0x0401), (625396, 0x0401), (625516, 0x0401), (625517, 0x0401), (625527, 0x0401), (625528, 0x0401), (625648, 0x0401), (625649, 0x0401),
+    (625659, 0x0401), (625780, 0x0401), (625781, 0x0401), (625791, 0x0401), (625912, 0x0401), (625913, 0x0401), (625923, 0x0401), (626044, 0x0401),
+    (626045, 0x0401), (626055, 0x0401), (626176, 0x0401), (626177, 0x0401), (626187, 0x0401), (626188, 0x0401), (626308, 0x0401), (626309, 0x0401),
+    (626319, 0x0401), (626440, 0x0401), (626441, 0x0401), (626451, 0x0401), (626572, 0x0401), (626573, 0x0401), (626583, 0x0401), (626704, 0x0401),
+    (626705, 0x0401), (626715, 0x0401), (626836, 0x0402), (626837, 0x0401), (626847, 0x0402), (626848, 0x0401), (626968, 0x0402), (626969, 0x0401),
+    (626979, 0x0401), (627100, 0x0401), (627101, 0x0401), (627111, 0x0401), (627232, 0x0402), (627233, 0x0401), (627243, 0x0402), (627244, 0x0401),
+    (627364, 0x0402), (627365, 0x0401), (627375, 0x0402), (627496, 0x0404), (627497, 0x0401), (627507, 0x0404), (627508, 0x0401), (627628, 0x0104),
+    (627629, 0x0401), (627630, 0x0401), (627639, 0x0104), (627640, 0x0401), (627760, 0x0104), (627761, 0x0401), (627762, 0x0401), (627763, 0x0401),
+    (627771, 0x0104), (627772, 0x0403), (627773, 0x0401), (627774, 0x0401), (627775, 0x0401), (627782, 0x0104), (627783, 0x0404), (627784, 0x0401),
+    (627785, 0x0401), (627786, 0x0401), (627793, 0x0104), (627794, 0x0304), (627795, 0x0403), (627796, 0x0401), (627797, 0x0401), (627804, 0x0104),
+    (627805, 0x0204), (627806, 0x0403), (627807, 0x0401), (627808, 0x0401), (627815, 0x0204), (627816, 0x0204), (627892, 0x0104), (627893, 0x0401),
+    (627894, 0x0401), (627895, 0x0401), (627903, 0x0104), (627904, 0x0402), (627905, 0x0401), (627906, 0x0401), (627907, 0x0401), (627914, 0x0104),
+    (627915, 0x0403), (627916, 0x0401), (627917, 0x0401), (627918, 0x0401), (627925, 0x0104), (627926, 0x0304), (627927, 0x0402), (627928, 0x0401),
+    (627929, 0x0401), (627936, 0x0104), (627937, 0x0304), (627938, 0x0402), (627939, 0x0402), (628024, 0x0104), (628025, 0x0403), (628026, 0x0401),
+    (628027, 0x0401), (628035, 0x0104), (628036, 0x0404), (628037, 0x0401), (628038, 0x0401), (628039, 0x0401), (628046, 0x0104), (628047, 0x0204),
+    (628048, 0x0402), (628049, 0x0401), (628050, 0x0401), (628057, 0x0104), (628058, 0x0204), (628059, 0x0403), (628060, 0x0401), (628061, 0x0401),
+    (628068, 0x0104), (628069, 0x0204), (628070, 0x0403), (628071, 0x0401), (628156, 0x0404), (628157, 0x0401), (628158, 0x0401), (628167, 0x0304),
+    (628168, 0x0401), (628169, 0x0401), (628178, 0x0304), (628179, 0x0401), (628180, 0x0401), (628288, 0x0104), (628289, 0x0401), (628290, 0x0401),
+    (628291, 0x0401), (628299, 0x0104), (628300, 0x0401), (628301, 0x0401), (628302, 0x0401), (628310, 0x0204), (628311, 0x0402), (628312, 0x0401),
+    (628321, 0x0204), (628322, 0x0402), (628420, 0x0204), (628421, 0x0401), (628422, 0x0401), (628423, 0x0401), (628431, 0x0104), (628432, 0x0401),
+    (628433, 0x0401), (628434, 0x0401), (628442, 0x0204), (628443, 0x0403), (628444, 0x0401), (628445, 0x0401), (628453, 0x0204), (628454, 0x0403),
+    (628552, 0x0401), (628553, 0x0401), (628554, 0x0401), (628563, 0x0404), (628564, 0x0401), (628574, 0x0402), (628684, 0x0403), (628685, 0x0401),
+    (628686, 0x0401), (628695, 0x0404), (628696, 0x0401), (628697, 0x0401), (628706, 0x0403), (628707, 0x0401), (628816, 0x0403), (628817, 0x0401),
+    (628818, 0x0401), (628827, 0x0403), (628828, 0x0401), (628829, 0x0401), (628838, 0x0403), (628839, 0x0401), (628948, 0x0403), (628949, 0x0401),
+    (628950, 0x0401), (628959, 0x0403), (628960, 0x0401), (628961, 0x0401), (628970, 0x0404), (628971, 0x0401), (629080, 0x0401), (629081, 0x0401),
+    (629091, 0x0401), (629092, 0x0401), (629212, 0x0401), (629213, 0x0401), (629214, 0x0401), (629223, 0x0402), (629224, 0x0401), (629344, 0x0402),
+    (629345, 0x0401), (629346, 0x0401), (629355, 0x0402), (629356, 0x0401), (629366, 0x0404), (629476, 0x0402), (629477, 0x0401), (629478, 0x0401),
+    (629487, 0x0402), (629488, 0x0401), (629608, 0x0402), (629609, 0x0401), (629619, 0x0402), (629620, 0x0401), (629740, 0x0401), (629741, 0x0401),
+    (629751, 0x0401), (629872, 0x0401), (629873, 0x0401), (629883, 0x0401), (629884, 0x0401), (630004, 0x0401), (630005, 0x0401), (630015, 0x0402),
+    (630016, 0x0401), (630136, 0x0401), (630137, 0x0401), (630138, 0x0401), (630147, 0x0402), (630148, 0x0401), (630268, 0x0401), (630269, 0x0401),
+    (630279, 0x0402), (630280, 0x0401), (630400, 0x0403), (630401, 0x0401), (630402, 0x0401), (630411, 0x0402), (630412, 0x0401), (630532, 0x0401),
+    (630533, 0x0401), (630543, 0x0401), (630664, 0x0401), (630665, 0x0401), (630675, 0x0401), (630676, 0x0401), (630796, 0x0401), (630797, 0x0401),
+    (630807, 0x0402), (630808, 0x0401), (630928, 0x0401), (630929, 0x0401), (630939, 0x0401), (630940, 0x0401), (631060, 0x0401), (631061, 0x0401),
+    (631071, 0x0401), (631072, 0x0401), (631192, 0x0401), (631193, 0x0401), (631203, 0x0401), (631204, 0x0401), (631324, 0x0401), (631325, 0x0401),
+    (631335, 0x0401), (631456, 0x0401), (631457, 0x0401), (631467, 0x0401), (631588, 0x0401), (631589, 0x0401), (631599, 0x0401), (631720, 0x0401),
+    (631721, 0x0401), (631731, 0x0401), (631732, 0x0401), (631852, 0x0401), (631853, 0x0401), (631863, 0x0401), (631864, 0x0401), (631984, 0x0402),
+    (631985, 0x0401), (631995, 0x0402), (631996, 0x0401), (632116, 0x0401), (632117, 0x0401), (632127, 0x0401), (632128, 0x0401), (632248, 0x0401),
+    (632249, 0x0401), (632259, 0x0402), (632260, 0x0401), (632380, 0x0402), (632381, 0x0401), (632391, 0x0401), (632392, 0x0401), (632512, 0x0402),
+    (632513, 0x0401), (632523, 0x0402), (632524, 0x0401), (632644, 0x0402), (632645, 0x0401), (632655, 0x0402), (632656, 0x0401), (632776, 0x0401),
+    (632777, 0x0401), (632787, 0x0402), (632788, 0x0401), (632908, 0x0401), (632909, 0x0401), (632919, 0x0402), (633040, 0x0401), (633041, 0x0401),
+    (633051, 0x0401), (633172, 0x0402), (633173, 0x0401), (633183, 0x0402), (633184, 0x0401), (633304, 0x0403), (633305, 0x0401), (633306, 0x0401),
+    (633315, 0x0403), (633316, 0x0401), (633436, 0x0402), (633437, 0x0401), (633447, 0x0404), (633448, 0x0401), (633568, 0x0403), (633569, 0x0401),
+    (633579, 0x0404), (633700, 0x0404), (633701, 0x0401), (633711, 0x0304), (633712, 0x0401), (633832, 0x0304), (633833, 0x0401), (633964, 0x0104),
+    (633965, 0x0401), (633966, 0x0401), (634228, 0x0104), (634229, 0x0404), (634230, 0x0401), (634231, 0x0401), (634232, 0x0401), (634239, 0x0104),
+    (634240, 0x0304), (634241, 0x0401), (634242, 0x0401), (634243, 0x0401), (634250, 0x0104), (634251, 0x0204), (634252, 0x0402), (634253, 0x0401),
+    (634254, 0x0401), (634261, 0x0104), (634262, 0x0104), (634263, 0x0404), (634264, 0x0401), (634265, 0x0401), (634272, 0x0104), (634273, 0x0104),
+    (634274, 0x0204), (634275, 0x0402), (634276, 0x0401), (634283, 0x0104), (634284, 0x0204), (634285, 0x0204), (634360, 0x0104), (634361, 0x0403),
+    (634362, 0x0401), (634363, 0x0401), (634364, 0x0401), (634371, 0x0104), (634372, 0x0404), (634373, 0x0401), (634374, 0x0401), (634375, 0x0401),
+    (634382, 0x0104), (634383, 0x0204), (634384, 0x0402), (634385, 0x0401), (634386, 0x0401), (634393, 0x0104), (634394, 0x0204), (634395, 0x0404),
+    (634396, 0x0401), (634397, 0x0401), (634404, 0x0104), (634405, 0x0104), (634406, 0x0304), (634407, 0x0402), (634408, 0x0401), (634492, 0x0104),
+    (634493, 0x0304), (634494, 0x0401), (634495, 0x0401), (634496, 0x0401), (634503, 0x0104), (634504, 0x0204), (634505, 0x0402), (634506, 0x0401),
+    (634507, 0x0401), (634514, 0x0104), (634515, 0x0104), (634516, 0x0403), (634517, 0x0401), (634518, 0x0401), (634525, 0x0104), (634526, 0x0104),
+    (634527, 0x0304), (634528, 0x0401), (634529, 0x0401), (634536, 0x0104), (634537, 0x0104), (634538, 0x0304), (634539, 0x0402), (634540, 0x0401),
+    (634624, 0x0204), (634625, 0x0401), (634626, 0x0401), (634635, 0x0204), (634636, 0x0401), (634637, 0x0401), (634646, 0x0204), (634647, 0x0402),
+    (634648, 0x0401), (634657, 0x0304), (634658, 0x0402), (634756, 0x0104), (634757, 0x0401), (634758, 0x0401), (634759, 0x0401), (634767, 0x0104),
+    (634768, 0x0402), (634769, 0x0401), (634770, 0x0401), (634778, 0x0104), (634779, 0x0402), (634780, 0x0401), (634781, 0x0401), (634789, 0x0204),
+    (634790, 0x0402), (634791, 0x0401), (634888, 0x0204), (634889, 0x0401), (634890, 0x0401), (634891, 0x0401), (634899, 0x0104), (634900, 0x0402),
+    (634901, 0x0401), (634902, 0x0401), (634910, 0x0104), (634911, 0x0404), (634912, 0x0401), (634913, 0x0401), (634921, 0x0104), (634922, 0x0304),
+    (635020, 0x0402), (635021, 0x0401), (635022, 0x0401), (635031, 0x0404), (635032, 0x0401), (635033, 0x0401), (635042, 0x0403), (635152, 0x0403),
+    (635153, 0x0401), (635154, 0x0401), (635163, 0x0304), (635164, 0x0401), (635165, 0x0401), (635174, 0x0404), (635175, 0x0401), (635284, 0x0404),
+    (635285, 0x0401), (635286, 0x0401), (635295, 0x0404), (635296, 0x0401), (635297, 0x0401), (635306, 0x0404), (635307, 0x0401), (635416, 0x0403),
+    (635417, 0x0401), (635418, 0x0401), (635427, 0x0403), (635428, 0x0401), (635429, 0x0401), (635438, 0x0304), (635439, 0x0401), (635548, 0x0401),
+    (635549, 0x0401), (635550, 0x0401), (635559, 0x0402), (635560, 0x0401), (635680, 0x0401), (635681, 0x0401), (635682, 0x0401), (635691, 0x0402),
+    (635692, 0x0401), (635702, 0x0402), (635812, 0x0402), (635813, 0x0401), (635814, 0x0401), (635823, 0x0403), (635824, 0x0401), (635825, 0x0401),
+    (635834, 0x0404), (635944, 0x0403), (635945, 0x0401), (635946, 0x0401), (635955, 0x0404), (635956, 0x0401), (635957, 0x0401), (636076, 0x0402),
+    (636077, 0x0401), (636078, 0x0401), (636087, 0x0403), (636088, 0x0401), (636208, 0x0401), (636209, 0x0401), (636219, 0x0401), (636340, 0x0401),
+    (636341, 0x0401), (636351, 0x0401), (636352, 0x0401), (636472, 0x0401), (636473, 0x0401), (636474, 0x0401), (636483, 0x0402), (636484, 0x0401),
+    (636604, 0x0401), (636605, 0x0401), (636606, 0x0401), (636615, 0x0402), (636616, 0x0401), (636736, 0x0402), (636737, 0x0401), (636738, 0x0401),
+    (636747, 0x0402), (636748, 0x0401), (636868, 0x0401), (636869, 0x0401), (636870, 0x0401), (636879, 0x0402), (636880, 0x0401), (637000, 0x0401),
+    (637001, 0x0401), (637011, 0x0401), (637132, 0x0401), (637133, 0x0401), (637143, 0x0402), (637144, 0x0401), (637264, 0x0401), (637265, 0x0401),
+    (637275, 0x0402), (637276, 0x0401), (637396, 0x0402), (637397, 0x0401), (637398, 0x0401), (637407, 0x0402), (637408, 0x0401), (637528, 0x0401),
+    (637529, 0x0401), (637539, 0x0402), (637540, 0x0401), (637660, 0x0401), (637661, 0x0401), (637671, 0x0402), (637672, 0x0401), (637792, 0x0401),
+    (637793, 0x0401), (637803, 0x0401), (637924, 0x0401), (637925, 0x0401), (637935, 0x0401), (638056, 0x0401), (638057, 0x0401), (638067, 0x0401),
+    (638068, 0x0401), (638188, 0x0402), (638189, 0x0401), (638199, 0x0402), (638200, 0x0401), (638320, 0x0402), (638321, 0x0401), (638322, 0x0401),
+    (638331, 0x0402), (638332, 0x0401), (638452, 0x0402), (638453, 0x0401), (638454, 0x0401), (638463, 0x0402), (638464, 0x0401), (638584, 0x0402),
+    (638585, 0x0401), (638595, 0x0402), (638596, 0x0401), (638716, 0x0402), (638717, 0x0401), (638727, 0x0402), (638728, 0x0401), (638848, 0x0402),
+    (638849, 0x0401), (638859, 0x0403), (638860, 0x0401), (638980, 0x0402), (638981, 0x0401), (638991, 0x0402), (638992, 0x0401), (639112, 0x0402),
+    (639113, 0x0401), (639114, 0x0401), (639123, 0x0403), (639124, 0x0401), (639244, 0x0402), (639245, 0x0401), (639255, 0x0403), (639256, 0x0401),
+    (639376, 0x0402), (639377, 0x0401), (639387, 0x0403), (639388, 0x0401), (639508, 0x0402), (639509, 0x0401), (639519, 0x0402), (639640, 0x0403),
+    (639641, 0x0401), (639651, 0x0403), (639652, 0x0401), (639772, 0x0403), (639773, 0x0401), (639774, 0x0401), (639783, 0x0304), (639784, 0x0401),
+    (639904, 0x0404), (639905, 0x0401), (639915, 0x0304), (640036, 0x0304), (640037, 0x0401), (640047, 0x0204), (640048, 0x0401), (640168, 0x0204),
+    (640169, 0x0401), (640300, 0x0104), (640301, 0x0401), (640696, 0x0104), (640697, 0x0204), (640698, 0x0403), (640699, 0x0401), (640700, 0x0401),
+    (640707, 0x0104), (640708, 0x0204), (640709, 0x0403), (640710, 0x0401), (640711, 0x0401), (640712, 0x0401), (640718, 0x0104), (640719, 0x0104),
+    (640720, 0x0304), (640721, 0x0401), (640722, 0x0401), (640723, 0x0401), (640729, 0x0104), (640730, 0x0104), (640731, 0x0204), (640732, 0x0402),
+    (640733, 0x0401), (640734, 0x0401), (640740, 0x0104), (640741, 0x0104), (640742, 0x0104), (640743, 0x0404), (640744, 0x0401), (640745, 0x0401),
+    (640751, 0x0104), (640752, 0x0104), (640753, 0x0104), (640754, 0x0304), (640755, 0x0402), (640756, 0x0401), (640828, 0x0104), (640829, 0x0304),
+    (640830, 0x0402), (640831, 0x0401), (640832, 0x0401), (640839, 0x0104), (640840, 0x0204), (640841, 0x0403), (640842, 0x0401), (640843, 0x0401),
+    (640850, 0x0104), (640851, 0x0104), (640852, 0x0403), (640853, 0x0401), (640854, 0x0401), (640861, 0x0104), (640862, 0x0104), (640863, 0x0304),
+    (640864, 0x0401), (640865, 0x0401), (640866, 0x0401), (640872, 0x0104), (640873, 0x0104), (640874, 0x0304), (640875, 0x0402), (640876, 0x0401),
+    (640877, 0x0401), (640960, 0x0104), (640961, 0x0204), (640962, 0x0403), (640963, 0x0401), (640964, 0x0401), (640971, 0x0104), (640972, 0x0104),
+    (640973, 0x0404), (640974, 0x0401), (640975, 0x0401), (640982, 0x0104), (640983, 0x0104), (640984, 0x0304), (640985, 0x0401), (640986, 0x0401),
+    (640993, 0x0104), (640994, 0x0104), (640995, 0x0304), (640996, 0x0402), (640997, 0x0401), (640998, 0x0401), (641004, 0x0104), (641005, 0x0104),
+    (641006, 0x0204), (641007, 0x0403), (641008, 0x0401), (641092, 0x0204), (641093, 0x0401), (641094, 0x0401), (641095, 0x0401), (641103, 0x0104),
+    (641104, 0x0402), (641105, 0x0401), (641106, 0x0401), (641114, 0x0104), (641115, 0x0402), (641116, 0x0401), (641117, 0x0401), (641125, 0x0204),
+    (641126, 0x0403), (641127, 0x0401), (641224, 0x0104), (641225, 0x0403), (641226, 0x0401), (641227, 0x0401), (641235, 0x0104), (641236, 0x0404),
+    (641237, 0x0401), (641238, 0x0401), (641246, 0x0104), (641247, 0x0404), (641248, 0x0401), (641249, 0x0401), (641257, 0x0104), (641258, 0x0403),
+    (641259, 0x0401), (641356, 0x0204), (641357, 0x0402), (641358, 0x0401), (641359, 0x0401), (641367, 0x0104), (641368, 0x0403), (641369, 0x0401),
+    (641370, 0x0401), (641378, 0x0104), (641379, 0x0304), (641380, 0x0402), (641381, 0x0401), (641389, 0x0104), (641390, 0x0304), (641391, 0x0402),
+    (641488, 0x0402), (641489, 0x0401), (641490, 0x0401), (641499, 0x0304), (641500, 0x0401), (641501, 0x0401), (641510, 0x0304), (641511, 0x0401),
+    (641620, 0x0404), (641621, 0x0401), (641622, 0x0401), (641631, 0x0204), (641632, 0x0401), (641633, 0x0401), (641642, 0x0304), (641643, 0x0401),
+    (641644, 0x0401), (641752, 0x0404), (641753, 0x0401), (641754, 0x0401), (641763, 0x0304), (641764, 0x0401), (641765, 0x0401), (641774, 0x0304),
+    (641775, 0x0401), (641776, 0x0401), (641884, 0x0403), (641885, 0x0401), (641886, 0x0401), (641895, 0x0404), (641896, 0x0401), (641897, 0x0401),
+    (641906, 0x0204), (641907, 0x0401), (642016, 0x0401), (642017, 0x0401), (642018, 0x0401), (642027, 0x0402), (642028, 0x0401), (642148, 0x0402),
+    (642149, 0x0401), (642150, 0x0401), (642159, 0x0404), (642160, 0x0401), (642161, 0x0401), (642170, 0x0304), (642171, 0x0401), (642280, 0x0402),
+    (642281, 0x0401), (642282, 0x0401), (642291, 0x0404), (642292, 0x0401), (642293, 0x0401), (642302, 0x0304), (642303, 0x0401), (642412, 0x0403),
+    (642413, 0x0401), (642414, 0x0401), (642423, 0x0404), (642424, 0x0401), (642425, 0x0401), (642434, 0x0204), (642435, 0x0401), (642544, 0x0402),
+    (642545, 0x0401), (642546, 0x0401), (642555, 0x0403), (642556, 0x0401), (642557, 0x0401), (642676, 0x0401), (642677, 0x0401), (642687, 0x0402),
+    (642688, 0x0401), (642808, 0x0401), (642809, 0x0401), (642810, 0x0401), (642819, 0x0402), (642820, 0x0401), (642940, 0x0402), (642941, 0x0401),
+    (642942, 0x0401), (642951, 0x0403), (642952, 0x0401), (643072, 0x0403), (643073, 0x0401), (643074, 0x0401), (643083, 0x0403), (643084, 0x0401),
+    (643085, 0x0401), (643204, 0x0402), (643205, 0x0401), (643206, 0x0401), (643215, 0x0403), (643216, 0x0401), (643217, 0x0401), (643336, 0x0401),
+    (643337, 0x0401), (643338, 0x0401), (643347, 0x0403), (643348, 0x0401), (643468, 0x0401), (643469, 0x0401), (643479, 0x0401), (643480, 0x0401),
+    (643600, 0x0401), (643601, 0x0401), (643602, 0x0401), (643611, 0x0402), (643612, 0x0401), (643732, 0x0402), (643733, 0x0401), (643734, 0x0401),
+    (643743, 0x0403), (643744, 0x0401), (643864, 0x0403), (643865, 0x0401), (643866, 0x0401), (643875, 0x0403), (643876, 0x0401), (643996, 0x0403),
+    (643997, 0x0401), (643998, 0x0401), (644007, 0x0403), (644008, 0x0401), (644128, 0x0402), (644129, 0x0401), (644130, 0x0401), (644139, 0x0402),
+    (644140, 0x0401), (644260, 0x0401), (644261, 0x0401), (644271, 0x0401), (644272, 0x0401), (644392, 0x0401), (644393, 0x0401), (644403, 0x0402),
+    (644404, 0x0401), (644524, 0x0402), (644525, 0x0401), (644526, 0x0401), (644535, 0x0402), (644536, 0x0401), (644656, 0x0403), (644657, 0x0401),
+    (644658, 0x0401), (644667, 0x0402), (644668, 0x0401), (644788, 0x0403), (644789, 0x0401), (644790, 0x0401), (644799, 0x0404), (644800, 0x0401),
+    (644920, 0x0403), (644921, 0x0401), (644922, 0x0401), (644931, 0x0404), (644932, 0x0401), (645052, 0x0402), (645053, 0x0401), (645054, 0x0401),
+    (645063, 0x0403), (645064, 0x0401), (645184, 0x0402), (645185, 0x0401), (645186, 0x0401), (645195, 0x0403), (645196, 0x0401), (645316, 0x0403),
+    (645317, 0x0401), (645318, 0x0401), (645327, 0x0404), (645328, 0x0401), (645448, 0x0403), (645449, 0x0401), (645450, 0x0401), (645459, 0x0404),
+    (645460, 0x0401), (645580, 0x0403), (645581, 0x0401), (645582, 0x0401), (645591, 0x0404), (645592, 0x0401), (645712, 0x0402), (645713, 0x0401),
+    (645714, 0x0401), (645723, 0x0304), (645724, 0x0401), (645844, 0x0403), (645845, 0x0401), (645846, 0x0401), (645855, 0x0404), (645856, 0x0401),
+    (645976, 0x0404), (645977, 0x0401), (645978, 0x0401), (645987, 0x0404), (645988, 0x0401), (646108, 0x0304), (646109, 0x0401), (646110, 0x0401),
+    (646119, 0x0304), (646120, 0x0401), (646240, 0x0204), (646241, 0x0401), (646242, 0x0401), (646251, 0x0204), (646252, 0x0401), (646372, 0x0204),
+    (646373, 0x0401), (646374, 0x0401), (646383, 0x0104), (646384, 0x0401), (646504, 0x0104), (646505, 0x0401), (646636, 0x0104), (646637, 0x0401),
+    (647164, 0x0104), (647165, 0x0104), (647166, 0x0304), (647167, 0x0403), (647168, 0x0401), (647169, 0x0401), (647175, 0x0104), (647176, 0x0104),
+    (647177, 0x0204), (647178, 0x0402), (647179, 0x0401), (647180, 0x0401), (647186, 0x0104), (647187, 0x0104), (647188, 0x0204), (647189, 0x0403),
+    (647190, 0x0401), (647191, 0x0401), (647197, 0x0104), (647198, 0x0104), (647199, 0x0104), (647200, 0x0304), (647201, 0x0401), (647202, 0x0401),
+    (647208, 0x0104), (647209, 0x0104), (647210, 0x0104), (647211, 0x0204), (647212, 0x0402), (647213, 0x0401), (647214, 0x0401), (647219, 0x0104),
+    (647220, 0x0104), (647221, 0x0104), (647222, 0x0104), (647223, 0x0403), (647224, 0x0401), (647296, 0x0104), (647297, 0x0204), (647298, 0x0404),
+    (647299, 0x0401), (647300, 0x0401), (647307, 0x0104), (647308, 0x0104), (647309, 0x0404), (647310, 0x0402), (647311, 0x0401), (647312, 0x0401),
+    (647318, 0x0104), (647319, 0x0104), (647320, 0x0204), (647321, 0x0402), (647322, 0x0401), (647323, 0x0401), (647329, 0x0104), (647330, 0x0104),
+    (647331, 0x0204), (647332, 0x0403), (647333, 0x0401), (647334, 0x0401), (647340, 0x0104), (647341, 0x0104), (647342, 0x0104), (647343, 0x0404),
+    (647344, 0x0401), (647345, 0x0401), (647351, 0x0104), (647352, 0x0104), (647353, 0x0104), (647354, 0x0204), (647428, 0x0104), (647429, 0x0204),
+    (647430, 0x0403), (647431, 0x0401), (647432, 0x0401), (647439, 0x0104), (647440, 0x0104), (647441, 0x0404), (647442, 0x0401), (647443, 0x0401),
+    (647450, 0x0104), (647451, 0x0104), (647452, 0x0204), (647453, 0x0402), (647454, 0x0401), (647455, 0x0401), (647461, 0x0104), (647462, 0x0104),
+    (647463, 0x0204), (647464, 0x0403), (647465, 0x0401), (647466, 0x0401), (647472, 0x0104), (647473, 0x0104), (647474, 0x0104), (647475, 0x0404),
+    (647476, 0x0401), (647477, 0x0401), (647483, 0x0104), (647484, 0x0104), (647485, 0x0104), (647486, 0x0304), (647560, 0x0204), (647561, 0x0402),
+    (647562, 0x0401), (647563, 0x0401), (647571, 0x0104), (647572, 0x0403), (647573, 0x0401), (647574, 0x0401), (647582, 0x0104), (647583, 0x0404),
+    (647584, 0x0401), (647585, 0x0401), (647593, 0x0104), (647594, 0x0204), (647595, 0x0401), (647596, 0x0401), (647692, 0x0104), (647693, 0x0403),
+    (647694, 0x0401), (647695, 0x0401), (647703, 0x0104), (647704, 0x0404), (647705, 0x0401), (647706, 0x0401), (647714, 0x0104), (647715, 0x0304),
+    (647716, 0x0401), (647717, 0x0401), (647725, 0x0104), (647726, 0x0204), (647727, 0x0401), (647728, 0x0401), (647824, 0x0204), (647825, 0x0402),
+    (647826, 0x0401), (647827, 0x0401), (647835, 0x0104), (647836, 0x0404), (647837, 0x0401), (647838, 0x0401), (647846, 0x0104), (647847, 0x0204),
+    (647848, 0x0402), (647849, 0x0401), (647857, 0x0104), (647858, 0x0204), (647859, 0x0401), (647860, 0x0401), (647956, 0x0403), (647957, 0x0401),
+    (647958, 0x0401), (647967, 0x0204), (647968, 0x0401), (647969, 0x0401), (647978, 0x0204), (647979, 0x0401), (648088, 0x0404), (648089, 0x0401),
+    (648090, 0x0401), (648099, 0x0204), (648100, 0x0401), (648101, 0x0401), (648110, 0x0204), (648111, 0x0402), (648112, 0x0401), (648220, 0x0404),
+    (648221, 0x0401), (648222, 0x0401), (648231, 0x0204), (648232, 0x0401), (648233, 0x0401), (648242, 0x0204), (648243, 0x0402), (648244, 0x0401),
+    (648352, 0x0404), (648353, 0x0401), (648354, 0x0401), (648363, 0x0304), (648364, 0x0401), (648365, 0x0401), (648374, 0x0204), (648375, 0x0402),
+    (648376, 0x0401), (648484, 0x0402), (648485, 0x0401), (648486, 0x0401), (648495, 0x0403), (648496, 0x0401), (648497, 0x0401), (648506, 0x0304),
+    (648616, 0x0403), (648617, 0x0401), (648618, 0x0401), (648627, 0x0404), (648628, 0x0401), (648629, 0x0401), (648638, 0x0204), (648639, 0x0401),
+    (648748, 0x0404), (648749, 0x0401), (648750, 0x0401), (648759, 0x0304), (648760, 0x0401), (648761, 0x0401), (648770, 0x0304), (648771, 0x0401),
+    (648880, 0x0403), (648881, 0x0401), (648882, 0x0401), (648891, 0x0304), (648892, 0x0401), (648893, 0x0401), (648902, 0x0304), (648903, 0x0401),
+    (649012, 0x0402), (649013, 0x0401), (649014, 0x0401), (649023, 0x0404), (649024, 0x0401), (649025, 0x0401), (649144, 0x0401), (649145, 0x0401),
+    (649146, 0x0401), (649155, 0x0402), (649156, 0x0401), (649276, 0x0402), (649277, 0x0401), (649278, 0x0401), (649287, 0x0403), (649288, 0x0401),
+    (649408, 0x0404), (649409, 0x0401), (649410, 0x0401), (649419, 0x0404), (649420, 0x0401), (649421, 0x0401), (649430, 0x0304), (649540, 0x0404),
+    (649541, 0x0401), (649542, 0x0401), (649551, 0x0404), (649552, 0x0401), (649553, 0x0401), (649562, 0x0304), (649563, 0x0401), (649672, 0x0404),
+    (649673, 0x0401), (649674, 0x0401), (649683, 0x0404), (649684, 0x0401), (649685, 0x0401), (649804, 0x0402), (649805, 0x0401), (649806, 0x0401),
+    (649815, 0x0404), (649816, 0x0401), (649936, 0x0401), (649937, 0x0401), (649938, 0x0401), (649947, 0x0402), (649948, 0x0401), (650068, 0x0402),
+    (650069, 0x0401), (650070, 0x0401), (650079, 0x0403), (650080, 0x0401), (650200, 0x0404), (650201, 0x0401), (650202, 0x0401), (650211, 0x0304),
+    (650212, 0x0401), (650213, 0x0401), (650332, 0x0404), (650333, 0x0401), (650334, 0x0401), (650343, 0x0404), (650344, 0x0401), (650345, 0x0401),
+    (650464, 0x0304), (650465, 0x0401), (650466, 0x0401), (650475, 0x0404), (650476, 0x0401), (650477, 0x0401), (650596, 0x0403), (650597, 0x0401),
+    (650598, 0x0401), (650607, 0x0403), (650608, 0x0401), (650728, 0x0402), (650729, 0x0401), (650730, 0x0401), (650739, 0x0403), (650740, 0x0401),
+    (650860, 0x0402), (650861, 0x0401), (650862, 0x0401), (650871, 0x0403), (650872, 0x0401), (650992, 0x0402), (650993, 0x0401), (650994, 0x0401),
+    (651003, 0x0403), (651004, 0x0401), (651124, 0x0404), (651125, 0x0401), (651126, 0x0401), (651135, 0x0304), (651136, 0x0401), (651137, 0x0401),
+    (651256, 0x0304), (651257, 0x0401), (651258, 0x0401), (651267, 0x0304), (651268, 0x0401), (651269, 0x0401), (651388, 0x0404), (651389, 0x0401),
+    (651390, 0x0401), (651399, 0x0304), (651400, 0x0401), (651520, 0x0404), (651521, 0x0401), (651522, 0x0401), (651531, 0x0404), (651532, 0x0401),
+    (651652, 0x0404), (651653, 0x0401), (651654, 0x0401), (651663, 0x0304), (651664, 0x0401), (651784, 0x0304), (651785, 0x0401), (651786, 0x0401),
+    (651795, 0x0304), (651796, 0x0401), (651916, 0x0304), (651917, 0x0401), (651918, 0x0401), (651927, 0x0304), (651928, 0x0401), (652048, 0x0304),
+    (652049, 0x0401), (652050, 0x0401), (652059, 0x0204), (652060, 0x0401), (652180, 0x0304), (652181, 0x0401), (652182, 0x0401), (652191, 0x0204),
+    (652192, 0x0401), (652312, 0x0204), (652313, 0x0401), (652314, 0x0401), (652323, 0x0204), (652324, 0x0401), (652444, 0x0204), (652445, 0x0401),
+    (652446, 0x0401), (652576, 0x0104), (652577, 0x0401), (652708, 0x0104), (652709, 0x0401), (652840, 0x0104), (652841, 0x0401), (653632, 0x0104),
+    (653633, 0x0104), (653634, 0x0204), (653635, 0x0403), (653636, 0x0401), (653643, 0x0104), (653644, 0x0104), (653645, 0x0204), (653646, 0x0403),
+    (653647, 0x0401), (653648, 0x0401), (653654, 0x0104), (653655, 0x0104), (653656, 0x0204), (653657, 0x0404), (653658, 0x0401), (653659, 0x0401),
+    (653665, 0x0104), (653666, 0x0104), (653667, 0x0104), (653668, 0x0304), (653669, 0x0402), (653670, 0x0401), (653676, 0x0104), (653677, 0x0104),
+    (653678, 0x0104), (653679, 0x0204), (653680, 0x0403), (653681, 0x0401), (653682, 0x0401), (653687, 0x0104), (653688, 0x0104), (653689, 0x0104),
+    (653690, 0x0104), (653691, 0x0404), (653692, 0x0401), (653693, 0x0401), (653764, 0x0104), (653765, 0x0204), (653766, 0x0404), (653767, 0x0401),
+    (653768, 0x0401), (653775, 0x0104), (653776, 0x0104), (653777, 0x0304), (653778, 0x0402), (653779, 0x0401), (653786, 0x0104), (653787, 0x0104),
+    (653788, 0x0204), (653789, 0x0403), (653790, 0x0401), (653791, 0x0401), (653797, 0x0104), (653798, 0x0104), (653799, 0x0104), (653800, 0x0304),
+    (653801, 0x0401), (653802, 0x0401), (653808, 0x0104), (653809, 0x0104), (653810, 0x0104), (653811, 0x0404), (653812, 0x0401), (653813, 0x0401),
+    (653819, 0x0104), (653820, 0x0104), (653821, 0x0104), (653822, 0x0304), (653823, 0x0402), (653896, 0x0104), (653897, 0x0204), (653898, 0x0404),
+    (653899, 0x0401), (653900, 0x0401), (653907, 0x0104), (653908, 0x0104), (653909, 0x0304), (653910, 0x0402), (653911, 0x0401), (653918, 0x0104),
+    (653919, 0x0104), (653920, 0x0304), (653921, 0x0402), (653922, 0x0401), (653923, 0x0401), (653929, 0x0104), (653930, 0x0104), (653931, 0x0204),
+    (653932, 0x0403), (653933, 0x0401), (653934, 0x0401), (653940, 0x0104), (653941, 0x0104), (653942, 0x0104), (653943, 0x0403), (653944, 0x0401),
+    (653945, 0x0401), (653951, 0x0104), (653952, 0x0104), (653953, 0x0104), (653954, 0x0404), (653955, 0x0401), (654028, 0x0204), (654029, 0x0402),
+    (654030, 0x0401), (654031, 0x0401), (654039, 0x0104), (654040, 0x0404), (654041, 0x0401), (654042, 0x0401), (654050, 0x0104), (654051, 0x0304),
+    (654052, 0x0401), (654053, 0x0401), (654061, 0x0104), (654062, 0x0204), (654063, 0x0402), (654064, 0x0401), (654160, 0x0104), (654161, 0x0403),
+    (654162, 0x0401), (654163, 0x0401), (654171, 0x0104), (654172, 0x0304), (654173, 0x0401), (654174, 0x0401), (654175, 0x0401), (654182, 0x0104),
+    (654183, 0x0204), (654184, 0x0402), (654185, 0x0401), (654186, 0x0401), (654193, 0x0104), (654194, 0x0204), (654195, 0x0402), (654196, 0x0401),
+    (654292, 0x0104), (654293, 0x0403), (654294, 0x0401), (654295, 0x0401), (654303, 0x0104), (654304, 0x0304), (654305, 0x0401), (654306, 0x0401),
+    (654314, 0x0104), (654315, 0x0304), (654316, 0x0402), (654317, 0x0401), (654325, 0x0104), (654326, 0x0204), (654327, 0x0402), (654328, 0x0401),
+    (654424, 0x0404), (654425, 0x0401), (654426, 0x0401), (654435, 0x0204), (654436, 0x0402), (654437, 0x0401), (654438, 0x0401), (654446, 0x0204),
+    (654447, 0x0403), (654448, 0x0401), (654556, 0x0304), (654557, 0x0401), (654558, 0x0401), (654559, 0x0401), (654567, 0x0204), (654568, 0x0402),
+    (654569, 0x0401), (654570, 0x0401), (654578, 0x0204), (654579, 0x0403), (654580, 0x0401), (654688, 0x0304), (654689, 0x0401), (654690, 0x0401),
+    (654691, 0x0401), (654699, 0x0204), (654700, 0x0402), (654701, 0x0401), (654702, 0x0401), (654710, 0x0204), (654711, 0x0402), (654712, 0x0401),
+    (654820, 0x0404), (654821, 0x0401), (654822, 0x0401), (654823, 0x0401), (654831, 0x0204), (654832, 0x0401), (654833, 0x0401), (654834, 0x0401),
+    (654842, 0x0204), (654843, 0x0403), (654844, 0x0401), (654952, 0x0403), (654953, 0x0401), (654954, 0x0401), (654963, 0x0404), (654964, 0x0401),
+    (654965, 0x0401), (654974, 0x0304), (654975, 0x0402), (655084, 0x0304), (655085, 0x0401), (655086, 0x0401), (655095, 0x0304), (655096, 0x0401),
+    (655097, 0x0401), (655106, 0x0204), (655107, 0x0402), (655108, 0x0401), (655216, 0x0204), (655217, 0x0401), (655218, 0x0401), (655227, 0x0204),
+    (655228, 0x0401), (655229, 0x0401), (655238, 0x0204), (655239, 0x0402), (655240, 0x0401), (655348, 0x0304), (655349, 0x0401), (655350, 0x0401),
+    (655359, 0x0204), (655360, 0x0401), (655361, 0x0401), (655370, 0x0204), (655371, 0x0402), (655372, 0x0401), (655480, 0x0404), (655481, 0x0401),
+    (655482, 0x0401), (655491, 0x0404), (655492, 0x0401), (655493, 0x0401), (655502, 0x0204), (655612, 0x0402), (655613, 0x0401), (655614, 0x0401),
+    (655623, 0x0402), (655624, 0x0401), (655744, 0x0404), (655745, 0x0401), (655746, 0x0401), (655755, 0x0404), (655756, 0x0401), (655757, 0x0401),
+    (655876, 0x0304), (655877, 0x0401), (655878, 0x0401), (655887, 0x0304), (655888, 0x0401), (655889, 0x0401), (655898, 0x0204), (655899, 0x0402),
+    (656008, 0x0204), (656009, 0x0401), (656010, 0x0401), (656019, 0x0204), (656020, 0x0401), (656021, 0x0401), (656030, 0x0204), (656031, 0x0402),
+    (656140, 0x0304), (656141, 0x0401), (656142, 0x0401), (656151, 0x0204), (656152, 0x0401), (656153, 0x0401), (656162, 0x0204), (656272, 0x0403),
+    (656273, 0x0401), (656274, 0x0401), (656283, 0x0304), (656284, 0x0401), (656285, 0x0401), (656404, 0x0402), (656405, 0x0401), (656406, 0x0401),
+    (656415, 0x0403), (656416, 0x0401), (656536, 0x0403), (656537, 0x0401), (656538, 0x0401), (656547, 0x0304), (656548, 0x0401), (656549, 0x0401),
+    (656668, 0x0304), (656669, 0x0401), (656670, 0x0401), (656679, 0x0204), (656680, 0x0401), (656681, 0x0401), (656800, 0x0304), (656801, 0x0401),
+    (656802, 0x0401), (656811, 0x0204), (656812, 0x0401), (656813, 0x0401), (656822, 0x0204), (656932, 0x0304), (656933, 0x0401), (656934, 0x0401),
+    (656943, 0x0204), (656944, 0x0401), (656945, 0x0401), (657064, 0x0404), (657065, 0x0401), (657066, 0x0401), (657075, 0x0304), (657076, 0x0401),
+    (657077, 0x0401), (657196, 0x0403), (657197, 0x0401), (657198, 0x0401), (657207, 0x0304), (657208, 0x0401), (657328, 0x0403), (657329, 0x0401),
+    (657330, 0x0401), (657339, 0x0304), (657340, 0x0401), (657460, 0x0404), (657461, 0x0401), (657462, 0x0401), (657471, 0x0304), (657472, 0x0401),
+    (657592, 0x0204), (657593, 0x0401), (657594, 0x0401), (657603, 0x0204), (657604, 0x0401), (657605, 0x0401), (657724, 0x0204), (657725, 0x0401),
+    (657726, 0x0401), (657735, 0x0204), (657736, 0x0401), (657737, 0x0401), (657856, 0x0204), (657857, 0x0401), (657858, 0x0401), (657867, 0x0204),
+    (657868, 0x0401), (657988, 0x0204), (657989, 0x0401), (657990, 0x0401), (657999, 0x0204), (658000, 0x0401), (658120, 0x0304), (658121, 0x0401),
+    (658122, 0x0401), (658131, 0x0104), (658132, 0x0401), (658252, 0x0104), (658253, 0x0401), (658254, 0x0401), (658263, 0x0204), (658264, 0x0401),
+    (658384, 0x0104), (658385, 0x0401), (658395, 0x0104), (658516, 0x0104), (658517, 0x0401), (658648, 0x0104), (658649, 0x0401), (660100, 0x0104),
+    (660101, 0x0104), (660102, 0x0204), (660103, 0x0403), (660104, 0x0401), (660111, 0x0104), (660112, 0x0104), (660113, 0x0204), (660114, 0x0403),
+    (660115, 0x0401), (660116, 0x0401), (660122, 0x0104), (660123, 0x0104), (660124, 0x0204), (660125, 0x0403), (660126, 0x0401), (660127, 0x0401),
+    (660133, 0x0104), (660134, 0x0104), (660135, 0x0104), (660136, 0x0304), (660137, 0x0401), (660138, 0x0401), (660139, 0x0401), (660144, 0x0104),
+    (660145, 0x0104), (660146, 0x0104), (660147, 0x0204), (660148, 0x0403), (660149, 0x0401), (660150, 0x0401), (660155, 0x0104), (660156, 0x0104),
+    (660157, 0x0104), (660158, 0x0104), (660159, 0x0404), (660160, 0x0401), (660161, 0x0401), (660166, 0x0104), (660167, 0x0104), (660168, 0x0104),
+    (660169, 0x0104), (660170, 0x0204), (660171, 0x0402), (660232, 0x0104), (660233, 0x0104), (660234, 0x0304), (660235, 0x0402), (660236, 0x0401),
+    (660243, 0x0104), (660244, 0x0104), (660245, 0x0304), (660246, 0x0403), (660247, 0x0401), (660248, 0x0401), (660254, 0x0104), (660255, 0x0104),
+    (660256, 0x0204), (660257, 0x0403), (660258, 0x0401), (660259, 0x0401), (660265, 0x0104), (660266, 0x0104), (660267, 0x0104), (660268, 0x0404),
+    (660269, 0x0401), (660270, 0x0401), (660276, 0x0104), (660277, 0x0104), (660278, 0x0104), (660279, 0x0404), (660280, 0x0401), (660281, 0x0401),
+    (660287, 0x0104), (660288, 0x0104), (660289, 0x0104), (660290, 0x0204), (660291, 0x0402), (660292, 0x0401), (660364, 0x0104), (660365, 0x0104),
+    (660366, 0x0204), (660367, 0x0402), (660368, 0x0401), (660375, 0x0104), (660376, 0x0104), (660377, 0x0204), (660378, 0x0403), (660379, 0x0401),
+    (660380, 0x0401), (660386, 0x0104), (660387, 0x0104), (660388, 0x0204), (660389, 0x0403), (660390, 0x0401), (660391, 0x0401), (660397, 0x0104),
+    (660398, 0x0104), (660399, 0x0104), (660400, 0x0404), (660401, 0x0401), (660402, 0x0401), (660408, 0x0104), (660409, 0x0104), (660410, 0x0104),
+    (660411, 0x0304), (660412, 0x0401), (660413, 0x0401), (660419, 0x0104), (660420, 0x0104), (660421, 0x0104), (660422, 0x0204), (660423, 0x0403),
+    (660424, 0x0401), (660496, 0x0104), (660497, 0x0304), (660498, 0x0401), (660499, 0x0401), (660507, 0x0104), (660508, 0x0304), (660509, 0x0401),
+    (660510, 0x0401), (660511, 0x0401), (660518, 0x0104), (660519, 0x0304), (660520, 0x0402), (660521, 0x0401), (660522, 0x0401), (660529, 0x0104),
+    (660530, 0x0204), (660531, 0x0403), (660532, 0x0401), (660533, 0x0401), (660628, 0x0104), (660629, 0x0304), (660630, 0x0402), (660631, 0x0401),
+    (660632, 0x0401), (660639, 0x0104), (660640, 0x0204), (660641, 0x0402), (660642, 0x0401), (660643, 0x0401), (660650, 0x0104), (660651, 0x0104),
+    (660652, 0x0403), (660653, 0x0401), (660654, 0x0401), (660661, 0x0104), (660662, 0x0104), (660663, 0x0403), (660664, 0x0401), (660665, 0x0401),
+    (660672, 0x0104), (660673, 0x0104), (660674, 0x0304), (660760, 0x0104), (660761, 0x0304), (660762, 0x0401), (660763, 0x0401), (660764, 0x0401),
+    (660771, 0x0104), (660772, 0x0204), (660773, 0x0402), (660774, 0x0401), (660775, 0x0401), (660782, 0x0104), (660783, 0x0204), (660784, 0x0402),
+    (660785, 0x0401), (660786, 0x0401), (660793, 0x0104), (660794, 0x0104), (660795, 0x0403), (660796, 0x0401), (660797, 0x0401), (660892, 0x0304),
+    (660893, 0x0401), (660894, 0x0401), (660895, 0x0401), (660903, 0x0204), (660904, 0x0402), (660905, 0x0401), (660906, 0x0401), (660914, 0x0104),
+    (660915, 0x0404), (660916, 0x0401), (660917, 0x0401), (661024, 0x0204), (661025, 0x0402), (661026, 0x0401), (661027, 0x0401), (661035, 0x0104),
+    (661036, 0x0403), (661037, 0x0401), (661038, 0x0401), (661046, 0x0104), (661047, 0x0404), (661048, 0x0401), (661049, 0x0401), (661156, 0x0204),
+    (661157, 0x0402), (661158, 0x0401), (661159, 0x0401), (661167, 0x0104), (661168, 0x0403), (661169, 0x0401), (661170, 0x0401), (661178, 0x0104),
+    (661179, 0x0404), (661180, 0x0401), (661181, 0x0401), (661189, 0x0104), (661190, 0x0404), (661288, 0x0204), (661289, 0x0401), (661290, 0x0401),
+    (661291, 0x0401), (661299, 0x0204), (661300, 0x0402), (661301, 0x0401), (661302, 0x0401), (661310, 0x0104), (661311, 0x0403), (661312, 0x0401),
+    (661313, 0x0401), (661420, 0x0304), (661421, 0x0401), (661422, 0x0401), (661431, 0x0204), (661432, 0x0401), (661433, 0x0401), (661442, 0x0204),
+    (661443, 0x0403), (661444, 0x0401), (661552, 0x0204), (661553, 0x0401), (661554, 0x0401), (661555, 0x0401), (661563, 0x0204), (661564, 0x0402),
+    (661565, 0x0401), (661566, 0x0401), (661574, 0x0104), (661575, 0x0403), (661576, 0x0401), (661684, 0x0204), (661685, 0x0401), (661686, 0x0401),
+    (661687, 0x0401), (661695, 0x0104), (661696, 0x0402), (661697, 0x0401), (661698, 0x0401), (661706, 0x0204), (661707, 0x0403), (661708, 0x0401),
+    (661816, 0x0204), (661817, 0x0401), (661818, 0x0401), (661819, 0x0401), (661827, 0x0104), (661828, 0x0402), (661829, 0x0401), (661830, 0x0401),
+    (661838, 0x0104), (661839, 0x0403), (661840, 0x0401), (661948, 0x0304), (661949, 0x0401), (661950, 0x0401), (661959, 0x0204), (661960, 0x0401),
+    (661961, 0x0401), (661970, 0x0104), (661971, 0x0403), (661972, 0x0401), (662080, 0x0403), (662081, 0x0401), (662082, 0x0401), (662091, 0x0404),
+    (662092, 0x0401), (662093, 0x0401), (662212, 0x0304), (662213, 0x0401), (662214, 0x0401), (662223, 0x0304), (662224, 0x0401), (662225, 0x0401),
+    (662234, 0x0204), (662344, 0x0104), (662345, 0x0401), (662346, 0x0401), (662355, 0x0104), (662356, 0x0402), (662357, 0x0401), (662366, 0x0204),
+    (662367, 0x0403), (662368, 0x0401), (662476, 0x0204), (662477, 0x0401), (662478, 0x0401), (662479, 0x0401), (662487, 0x0104), (662488, 0x0402),
+    (662489, 0x0401), (662498, 0x0104), (662499, 0x0403), (662500, 0x0401), (662608, 0x0204), (662609, 0x0401), (662610, 0x0401), (662619, 0x0204),
+    (662620, 0x0402), (662621, 0x0401), (662630, 0x0104), (662631, 0x0403), (662740, 0x0304), (662741, 0x0401), (662742, 0x0401), (662751, 0x0304),
+    (662752, 0x0401), (662753, 0x0401), (662872, 0x0404), (662873, 0x0401), (662874, 0x0401), (662883, 0x0304), (662884, 0x0401), (662885, 0x0401),
+    (663004, 0x0304), (663005, 0x0401), (663006, 0x0401), (663015, 0x0204), (663016, 0x0401), (663017, 0x0401), (663136, 0x0204), (663137, 0x0401),
+    (663138, 0x0401), (663147, 0x0104), (663148, 0x0402), (663149, 0x0401), (663268, 0x0204), (663269, 0x0401), (663270, 0x0401), (663279, 0x0104),
+    (663280, 0x0402), (663281, 0x0401), (663290, 0x0104), (663400, 0x0104), (663401, 0x0401), (663402, 0x0401), (663411, 0x0104), (663412, 0x0402),
+    (663413, 0x0401), (663532, 0x0204), (663533, 0x0401), (663534, 0x0401), (663543, 0x0104), (663544, 0x0401), (663664, 0x0304), (663665, 0x0401),
+    (663666, 0x0401), (663675, 0x0204), (663676, 0x0401), (663796, 0x0204), (663797, 0x0401), (663798, 0x0401), (663807, 0x0204), (663808, 0x0401),
+    (663928, 0x0104), (663929, 0x0401), (663930, 0x0401), (663939, 0x0204), (663940, 0x0401), (664060, 0x0104), (664061, 0x0401), (664062, 0x0401),
+    (664071, 0x0104), (664072, 0x0402), (664192, 0x0104), (664193, 0x0401), (664324, 0x0104), (664325, 0x0402), (664456, 0x0104), (666568, 0x0104),
+    (666569, 0x0104), (666570, 0x0104), (666571, 0x0204), (666572, 0x0402), (666579, 0x0104), (666580, 0x0104), (666581, 0x0104), (666582, 0x0204),
+    (666583, 0x0401), (666584, 0x0401), (666590, 0x0104), (666591, 0x0104), (666592, 0x0104), (666593, 0x0204), (666594, 0x0402), (666595, 0x0401),
+    (666601, 0x0104), (666602, 0x0104), (666603, 0x0104), (666604, 0x0204), (666605, 0x0402), (666606, 0x0401), (666607, 0x0401), (666612, 0x0104),
+    (666613, 0x0104), (666614, 0x0104), (666615, 0x0204), (666616, 0x0403), (666617, 0x0401), (666618, 0x0401), (666623, 0x0104), (666624, 0x0104),
+    (666625, 0x0104), (666626, 0x0104), (666627, 0x0304), (666628, 0x0401), (666629, 0x0401), (666634, 0x0104), (666635, 0x0104), (666636, 0x0104),
+    (666637, 0x0104), (666638, 0x0104), (666639, 0x0403), (666640, 0x0401), (666700, 0x0104), (666701, 0x0104), (666702, 0x0204), (666703, 0x0304),
+    (666704, 0x0401), (666711, 0x0104), (666712, 0x0104), (666713, 0x0104), (666714, 0x0404), (666715, 0x0401), (666716, 0x0401), (666722, 0x0104),
+    (666723, 0x0104), (666724, 0x0104), (666725, 0x0304), (666726, 0x0401), (666727, 0x0401), (666733, 0x0104), (666734, 0x0104), (666735, 0x0104),
+    (666736, 0x0304), (666737, 0x0401), (666738, 0x0401), (666744, 0x0104), (666745, 0x0104), (666746, 0x0104), (666747, 0x0304), (666748, 0x0402),
+    (666749, 0x0401), (666755, 0x0104), (666756, 0x0104), (666757, 0x0104), (666758, 0x0204), (666759, 0x0403), (666760, 0x0401), (666832, 0x0104),
+    (666833, 0x0104), (666834, 0x0104), (666835, 0x0304), (666836, 0x0401), (666843, 0x0104), (666844, 0x0104), (666845, 0x0104), (666846, 0x0404),
+    (666847, 0x0401), (666848, 0x0401), (666854, 0x0104), (666855, 0x0104), (666856, 0x0104), (666857, 0x0404), (666858, 0x0401), (666859, 0x0401),
+    (666865, 0x0104), (666866, 0x0104), (666867, 0x0104), (666868, 0x0404), (666869, 0x0401), (666870, 0x0401), (666876, 0x0104), (666877, 0x0104),
+    (666878, 0x0104), (666879, 0x0204), (666880, 0x0402), (666881, 0x0401), (666882, 0x0401), (666887, 0x0104), (666888, 0x0104), (666889, 0x0104),
+    (666890, 0x0104), (666891, 0x0403), (666892, 0x0401), (666964, 0x0104), (666965, 0x0204), (666966, 0x0402), (666967, 0x0401), (666968, 0x0401),
+    (666975, 0x0104), (666976, 0x0204), (666977, 0x0403), (666978, 0x0401), (666979, 0x0401), (666986, 0x0104), (666987, 0x0204), (666988, 0x0403),
+    (666989, 0x0401), (666990, 0x0401), (666997, 0x0104), (666998, 0x0104), (666999, 0x0404), (667000, 0x0401), (667001, 0x0401), (667008, 0x0104),
+    (667009, 0x0104), (667096, 0x0104), (667097, 0x0104), (667098, 0x0404), (667099, 0x0401), (667100, 0x0401), (667107, 0x0104), (667108, 0x0104),
+    (667109, 0x0404), (667110, 0x0401), (667111, 0x0401), (667118, 0x0104), (667119, 0x0104), (667120, 0x0404), (667121, 0x0401), (667122, 0x0401),
+    (667129, 0x0104), (667130, 0x0104), (667131, 0x0304), (667132, 0x0401), (667133, 0x0401), (667140, 0x0104), (667141, 0x0104), (667142, 0x0204),
+    (667143, 0x0402), (667228, 0x0104), (667229, 0x0204), (667230, 0x0403), (667231, 0x0401), (667239, 0x0104), (667240, 0x0204), (667241, 0x0403),
+    (667242, 0x0401), (667243, 0x0401), (667250, 0x0104), (667251, 0x0104), (667252, 0x0403), (667253, 0x0401), (667254, 0x0401), (667261, 0x0104),
+    (667262, 0x0104), (667263, 0x0404), (667264, 0x0401), (667265, 0x0401), (667360, 0x0104), (667361, 0x0402), (667362, 0x0401), (667363, 0x0401),
+    (667371, 0x0104), (667372, 0x0403), (667373, 0x0401), (667374, 0x0401), (667382, 0x0104), (667383, 0x0404), (667384, 0x0401), (667385, 0x0401),
+    (667492, 0x0104), (667493, 0x0304), (667494, 0x0401), (667495, 0x0401), (667503, 0x0104), (667504, 0x0404), (667505, 0x0401), (667506, 0x0401),
+    (667514, 0x0104), (667515, 0x0304), (667516, 0x0401), (667517, 0x0401), (667624, 0x0104), (667625, 0x0404), (667626, 0x0401), (667627, 0x0401),
+    (667635, 0x0104), (667636, 0x0304), (667637, 0x0401), (667638, 0x0401), (667646, 0x0104), (667647, 0x0304), (667648, 0x0401), (667649, 0x0401),
+    (667657, 0x0104), (667658, 0x0204), (667756, 0x0104), (667757, 0x0403), (667758, 0x0401), (667759, 0x0401), (667767, 0x0104), (667768, 0x0404),
+    (667769, 0x0401), (667770, 0x0401), (667778, 0x0104), (667779, 0x0304), (667780, 0x0401), (667781, 0x0401), (667888, 0x0204), (667889, 0x0401),
+    (667890, 0x0401), (667899, 0x0104), (667900, 0x0402), (667901, 0x0401), (667910, 0x0104), (667911, 0x0403), (667912, 0x0401), (668020, 0x0104),
+    (668021, 0x0403), (668022, 0x0401), (668023, 0x0401), (668031, 0x0104), (668032, 0x0403), (668033, 0x0401), (668034, 0x0401), (668042, 0x0104),
+    (668043, 0x0304), (668044, 0x0401), (668152, 0x0104), (668153, 0x0404), (668154, 0x0401), (668155, 0x0401), (668163, 0x0104), (668164, 0x0404),
+    (668165, 0x0401), (668166, 0x0401), (668174, 0x0104), (668175, 0x0304), (668176, 0x0401), (668284, 0x0104), (668285, 0x0403), (668286, 0x0401),
+    (668295, 0x0104), (668296, 0x0404), (668297, 0x0401), (668306, 0x0104), (668307, 0x0304), (668308, 0x0402), (668416, 0x0104), (668417, 0x0402),
+    (668418, 0x0401), (668427, 0x0104), (668428, 0x0402), (668429, 0x0401), (668548, 0x0204), (668549, 0x0401), (668550, 0x0401), (668559, 0x0204),
+    (668560, 0x0401), (668680, 0x0104), (668681, 0x0402), (668682, 0x0401), (668691, 0x0104), (668692, 0x0402), (668693, 0x0401), (668812, 0x0104),
+    (668813, 0x0403), (668814, 0x0401), (668823, 0x0104), (668824, 0x0403), (668825, 0x0401), (668944, 0x0104), (668945, 0x0403), (668946, 0x0401),
+    (668955, 0x0104), (668956, 0x0404), (668957, 0x0401), (669076, 0x0104), (669077, 0x0403), (669078, 0x0401), (669087, 0x0104), (669088, 0x0403),
+    (669208, 0x0104), (669209, 0x0402), (669340, 0x0104), (669341, 0x0402), (673058, 0x0104), (673059, 0x0104), (673060, 0x0104), (673061, 0x0104),
+    (673062, 0x0404), (673069, 0x0104), (673070, 0x0104), (673071, 0x0104), (673072, 0x0104), (673073, 0x0404), (673074, 0x0401), (673080, 0x0104),
+    (673081, 0x0104), (673082, 0x0104), (673083, 0x0104), (673084, 0x0304), (673085, 0x0401), (673086, 0x0401), (673091, 0x0104), (673092, 0x0104),
+    (673093, 0x0104), (673094, 0x0104), (673095, 0x0204), (673096, 0x0402), (673097, 0x0401), (673102, 0x0104), (673103, 0x0104), (673104, 0x0104),
+    (673105, 0x0104), (673106, 0x0104), (673107, 0x0304), (673190, 0x0104), (673191, 0x0104), (673192, 0x0104), (673193, 0x0204), (673194, 0x0402),
+    (673201, 0x0104), (673202, 0x0104), (673203, 0x0104), (673204, 0x0204), (673205, 0x0403), (673206, 0x0401), (673212, 0x0104), (673213, 0x0104),
+    (673214, 0x0104), (673215, 0x0104), (673216, 0x0403), (673217, 0x0401), (673223, 0x0104), (673224, 0x0104), (673225, 0x0104), (673226, 0x0104),
+    (673227, 0x0304), (673228, 0x0402), (673322, 0x0104), (673323, 0x0104), (673324, 0x0104), (673325, 0x0204), (673326, 0x0403), (673333, 0x0104),
+    (673334, 0x0104), (673335, 0x0104), (673336, 0x0204), (673337, 0x0403), (673338, 0x0401), (673344, 0x0104), (673345, 0x0104), (673346, 0x0104),
+    (673347, 0x0104), (673348, 0x0404), (673349, 0x0401), (673355, 0x0104), (673356, 0x0104), (673357, 0x0104), (673358, 0x0104), (673359, 0x0204),
+    (673360, 0x0402), (673432, 0x0104), (673433, 0x0104), (673443, 0x0104), (673444, 0x0104), (673445, 0x0304), (673446, 0x0401), (673454, 0x0104),
+    (673455, 0x0104), (673456, 0x0204), (673457, 0x0402), (673465, 0x0104), (673466, 0x0104), (673467, 0x0304), (673468, 0x0402), (673575, 0x0104),
+    (673576, 0x0104), (673577, 0x0204), (673586, 0x0104), (673587, 0x0104), (673588, 0x0104), (673589, 0x0403), (673597, 0x0104), (673598, 0x0104),
+    (673599, 0x0204), (673600, 0x0404), (673707, 0x0104), (673708, 0x0104), (673718, 0x0104), (673719, 0x0104), (673720, 0x0204), (673721, 0x0402),
+    (673729, 0x0104), (673730, 0x0104), (673828, 0x0104), (673829, 0x0404), (673839, 0x0104), (673840, 0x0204), (681484, 0x0403), (681485, 0x0304),
+    (681486, 0x0402), (681487, 0x0401), (681495, 0x0403), (681496, 0x0404), (681497, 0x0402), (681498, 0x0401), (681506, 0x0403), (681507, 0x0304),
+    (681508, 0x0402), (681509, 0x0401), (681510, 0x0401), (681517, 0x0404), (681518, 0x0204), (681519, 0x0404), (681520, 0x0401), (681521, 0x0401),
+    (681528, 0x0304), (681529, 0x0204), (681530, 0x0204), (681531, 0x0403), (681532, 0x0401), (681539, 0x0204), (681540, 0x0104), (681541, 0x0104),
+    (681542, 0x0204), (681543, 0x0304), (681544, 0x0401), (681550, 0x0204), (681551, 0x0104), (681616, 0x0404), (681617, 0x0404), (681618, 0x0401),
+    (681619, 0x0401), (681620, 0x0401), (681627, 0x0404), (681628, 0x0404), (681629, 0x0401), (681630, 0x0401), (681631, 0x0401), (681638, 0x0404),
+    (681639, 0x0304), (681640, 0x0402), (681641, 0x0401), (681642, 0x0401), (681649, 0x0304), (681650, 0x0204), (681651, 0x0402), (681652, 0x0401),
+    (681653, 0x0401), (681660, 0x0304), (681661, 0x0204), (681662, 0x0404), (681663, 0x0402), (681664, 0x0401), (681671, 0x0304), (681672, 0x0104),
+    (681673, 0x0204), (681674, 0x0403), (681675, 0x0401), (681676, 0x0401), (681682, 0x0204), (681683, 0x0104), (681684, 0x0204), (681685, 0x0204),
+    (681686, 0x0402), (681748, 0x0204), (681749, 0x0403), (681750, 0x0401), (681751, 0x0401), (681752, 0x0401), (681759, 0x0204), (681760, 0x0403),
+    (681761, 0x0401), (681762, 0x0401), (681763, 0x0401), (681770, 0x0204), (681771, 0x0403), (681772, 0x0401), (681773, 0x0401), (681774, 0x0401),
+    (681781, 0x0204), (681782, 0x0304), (681783, 0x0401), (681784, 0x0401), (681785, 0x0401), (681792, 0x0204), (681793, 0x0304), (681794, 0x0402),
+    (681795, 0x0401), (681796, 0x0401), (681803, 0x0204), (681804, 0x0204), (681805, 0x0404), (681806, 0x0401), (681807, 0x0401), (681814, 0x0104),
+    (681815, 0x0204), (681880, 0x0204), (681881, 0x0401), (681882, 0x0401), (681883, 0x0401), (681891, 0x0204), (681892, 0x0401), (681893, 0x0401),
+    (681894, 0x0401), (681902, 0x0204), (681903, 0x0402), (681904, 0x0401), (681905, 0x0401), (681906, 0x0401), (681913, 0x0204), (681914, 0x0403),
+    (681915, 0x0401), (681916, 0x0401), (681917, 0x0401), (681924, 0x0204), (681925, 0x0304), (681926, 0x0402), (681927, 0x0401), (681928, 0x0401),
+    (681935, 0x0304), (681936, 0x0104), (681937, 0x0304), (681938, 0x0402), (681939, 0x0402), (681946, 0x0404), (681947, 0x0104), (681948, 0x0104),
+    (681949, 0x0304), (682012, 0x0104), (682013, 0x0401), (682014, 0x0401), (682015, 0x0401), (682016, 0x0401), (682023, 0x0104), (682024, 0x0401),
+    (682025, 0x0401), (682026, 0x0401), (682027, 0x0401), (682034, 0x0104), (682035, 0x0401), (682036, 0x0401), (682037, 0x0401), (682038, 0x0401),
+    (682045, 0x0104), (682046, 0x0402), (682047, 0x0401), (682048, 0x0401), (682049, 0x0401), (682056, 0x0104), (682057, 0x0304), (682058, 0x0402),
+    (682059, 0x0401), (682060, 0x0401), (682061, 0x0401), (682067, 0x0204), (682068, 0x0104), (682069, 0x0304), (682070, 0x0402), (682071, 0x0401),
+    (682072, 0x0401), (682078, 0x0304), (682079, 0x0104), (682080, 0x0104), (682081, 0x0204), (682082, 0x0403), (682083, 0x0401), (682089, 0x0304),
+    (682090, 0x0104), (682091, 0x0104), (682092, 0x0204), (682093, 0x0404), (682144, 0x0104), (682145, 0x0401), (682146, 0x0401), (682147, 0x0401),
+    (682148, 0x0401), (682149, 0x0401), (682155, 0x0104), (682156, 0x0401), (682157, 0x0401), (682158, 0x0401), (682159, 0x0401), (682160, 0x0401),
+    (682166, 0x0104), (682167, 0x0401), (682168, 0x0401), (682169, 0x0401), (682170, 0x0401), (682171, 0x0401), (682177, 0x0104), (682178, 0x0402),
+    (682179, 0x0401), (682180, 0x0401), (682181, 0x0401), (682182, 0x0401), (682188, 0x0104), (682189, 0x0304), (682190, 0x0404), (682191, 0x0403),
+    (682192, 0x0401), (682193, 0x0401), (682199, 0x0104), (682200, 0x0104), (682201, 0x0304), (682202, 0x0402), (682203, 0x0401), (682204, 0x0401),
+    (682205, 0x0401), (682210, 0x0104), (682211, 0x0104), (682212, 0x0204), (682213, 0x0404), (682214, 0x0402), (682215, 0x0401), (682216, 0x0401),
+    (682221, 0x0104), (682222, 0x0104), (682223, 0x0104), (682224, 0x0204), (682225, 0x0403), (682226, 0x0401), (682276, 0x0104), (682277, 0x0401),
+    (682278, 0x0401), (682279, 0x0401), (682280, 0x0401), (682287, 0x0104), (682288, 0x0401), (682289, 0x0401), (682290, 0x0401), (682291, 0x0401),
+    (682298, 0x0104), (682299, 0x0401), (682300, 0x0401), (682301, 0x0401), (682302, 0x0401), (682309, 0x0104), (682310, 0x0402), (682311, 0x0402),
+    (682312, 0x0401), (682313, 0x0401), (682314, 0x0401), (682320, 0x0104), (682321, 0x0304), (682322, 0x0304), (682323, 0x0402), (682324, 0x0401),
+    (682325, 0x0401), (682331, 0x0104), (682332, 0x0204), (682333, 0x0403), (682334, 0x0401), (682335, 0x0401), (682336, 0x0401), (682342, 0x0104),
+    (682343, 0x0104), (682344, 0x0204), (682345, 0x0402), (682346, 0x0401), (682347, 0x0401), (682353, 0x0104), (682354, 0x0104), (682355, 0x0104),
+    (682408, 0x0403), (682409, 0x0304), (682410, 0x0401), (682411, 0x0401), (682419, 0x0403), (682420, 0x0304), (682421, 0x0402), (682422, 0x0401),
+    (682430, 0x0404), (682431, 0x0304), (682432, 0x0402), (682433, 0x0401), (682434, 0x0401), (682441, 0x0404), (682442, 0x0204), (682443, 0x0403),
+    (682444, 0x0401), (682445, 0x0401), (682452, 0x0304), (682453, 0x0104), (682454, 0x0204), (682455, 0x0403), (682456, 0x0401), (682463, 0x0204),
+    (682464, 0x0104), (682465, 0x0104), (682466, 0x0204), (682467, 0x0403), (682468, 0x0401), (682474, 0x0204), (682475, 0x0104), (682476, 0x0104),
+    (682540, 0x0304), (682541, 0x0304), (682542, 0x0402), (682543, 0x0401), (682544, 0x0401), (682551, 0x0304), (682552, 0x0304), (682553, 0x0402),
+    (682554, 0x0401), (682555, 0x0401), (682562, 0x0404), (682563, 0x0304), (682564, 0x0402), (682565, 0x0401), (682566, 0x0401), (682573, 0x0404),
+    (682574, 0x0204), (682575, 0x0402), (682576, 0x0401), (682577, 0x0401), (682584, 0x0304), (682585, 0x0104), (682586, 0x0404), (682587, 0x0401),
+    (682588, 0x0401), (682595, 0x0204), (682596, 0x0104), (682597, 0x0304), (682598, 0x0402), (682599, 0x0401), (682600, 0x0401), (682606, 0x0304),
+    (682607, 0x0104), (682608, 0x0204), (682609, 0x0403), (682672, 0x0204), (682673, 0x0403), (682674, 0x0401), (682675, 0x0401), (682683, 0x0204),
+    (682684, 0x0403), (682685, 0x0401), (682686, 0x0401), (682694, 0x0204), (682695, 0x0403), (682696, 0x0401), (682697, 0x0401), (682698, 0x0401),
+    (682705, 0x0204), (682706, 0x0404), (682707, 0x0401), (682708, 0x0401), (682709, 0x0401), (682716, 0x0204), (682717, 0x0304), (682718, 0x0402),
+    (682719, 0x0401), (682720, 0x0401), (682727, 0x0104), (682728, 0x0204), (682729, 0x0403), (682730, 0x0401), (682731, 0x0401), (682738, 0x0204),
+    (682804, 0x0204), (682805, 0x0401), (682806, 0x0401), (682807, 0x0401), (682815, 0x0204), (682816, 0x0401), (682817, 0x0401), (682818, 0x0401),
+    (682826, 0x0204), (682827, 0x0402), (682828, 0x0401), (682829, 0x0401), (682837, 0x0304), (682838, 0x0402), (682839, 0x0401), (682840, 0x0401),
+    (682841, 0x0401), (682848, 0x0204), (682849, 0x0404), (682850, 0x0402), (682851, 0x0401), (682852, 0x0401), (682859, 0x0304), (682860, 0x0204),
+    (682861, 0x0304), (682862, 0x0404), (682863, 0x0402), (682870, 0x0404), (682871, 0x0104), (682936, 0x0104), (682937, 0x0401), (682938, 0x0401),
+    (682939, 0x0401), (682940, 0x0401), (682947, 0x0104), (682948, 0x0401), (682949, 0x0401), (682950, 0x0401), (682951, 0x0401), (682958, 0x0204),
+    (682959, 0x0401), (682960, 0x0401), (682961, 0x0401), (682962, 0x0401), (682969, 0x0104), (682970, 0x0402), (682971, 0x0401), (682972, 0x0401),
+    (682973, 0x0401), (682980, 0x0204), (682981, 0x0304), (682982, 0x0403), (682983, 0x0401), (682984, 0x0401), (682985, 0x0401), (682991, 0x0204),
+    (682992, 0x0104), (682993, 0x0204), (682994, 0x0403), (682995, 0x0402), (682996, 0x0401), (683002, 0x0304), (683003, 0x0104), (683004, 0x0104),
+    (683005, 0x0204), (683006, 0x0403), (683007, 0x0401), (683013, 0x0204), (683014, 0x0104), (683015, 0x0104), (683016, 0x0104), (683017, 0x0304),
+    (683068, 0x0104), (683069, 0x0401), (683070, 0x0401), (683071, 0x0401), (683072, 0x0401), (683073, 0x0401), (683079, 0x0104), (683080, 0x0401),
+    (683081, 0x0401), (683082, 0x0401), (683083, 0x0401), (683084, 0x0401), (683090, 0x0104), (683091, 0x0401), (683092, 0x0401), (683093, 0x0401),
+    (683094, 0x0401), (683095, 0x0401), (683101, 0x0104), (683102, 0x0402), (683103, 0x0402), (683104, 0x0401), (683105, 0x0401), (683106, 0x0401),
+    (683112, 0x0104), (683113, 0x0304), (683114, 0x0403), (683115, 0x0402), (683116, 0x0401), (683117, 0x0401), (683118, 0x0401), (683123, 0x0104),
+    (683124, 0x0104), (683125, 0x0404), (683126, 0x0402), (683127, 0x0401), (683128, 0x0401), (683129, 0x0401), (683134, 0x0104), (683135, 0x0104),
+    (683136, 0x0104), (683137, 0x0404), (683138, 0x0402), (683139, 0x0401), (683140, 0x0401), (683145, 0x0104), (683146, 0x0104), (683147, 0x0104),
+    (683148, 0x0104), (683149, 0x0404), (683150, 0x0401), (683151, 0x0401), (683200, 0x0104), (683201, 0x0401), (683202, 0x0401), (683203, 0x0401),
+    (683204, 0x0401), (683211, 0x0104), (683212, 0x0401), (683213, 0x0401), (683214, 0x0401), (683215, 0x0401), (683222, 0x0104), (683223, 0x0401),
+    (683224, 0x0401), (683225, 0x0401), (683226, 0x0401), (683227, 0x0401), (683233, 0x0104), (683234, 0x0403), (683235, 0x0402), (683236, 0x0401),
+    (683237, 0x0401), (683238, 0x0401), (683244, 0x0104), (683245, 0x0304), (683246, 0x0404), (683247, 0x0403), (683248, 0x0401), (683249, 0x0401),
+    (683255, 0x0104), (683256, 0x0204), (683257, 0x0403), (683258, 0x0401), (683259, 0x0401), (683260, 0x0401), (683266, 0x0104), (683267, 0x0104),
+    (683268, 0x0304), (683269, 0x0402), (683270, 0x0401), (683271, 0x0401), (683277, 0x0104), (683278, 0x0104), (683279, 0x0204), (683464, 0x0204),
+    (683465, 0x0204), (683466, 0x0403), (683467, 0x0401), (683468, 0x0401), (683469, 0x0401), (683475, 0x0204), (683476, 0x0204), (683477, 0x0404),
+    (683478, 0x0401), (683479, 0x0401), (683486, 0x0204), (683487, 0x0104), (683488, 0x0404), (683489, 0x0401), (683490, 0x0401), (683491, 0x0401),
+    (683497, 0x0204), (683498, 0x0104), (683499, 0x0304), (683500, 0x0402), (683501, 0x0401), (683502, 0x0401), (683508, 0x0104), (683509, 0x0104),
+    (683510, 0x0104), (683511, 0x0404), (683512, 0x0401), (683513, 0x0401), (683519, 0x0104), (683520, 0x0104), (683521, 0x0104), (683522, 0x0104),
+    (683523, 0x0404), (683524, 0x0401), (683525, 0x0401), (683530, 0x0104), (683531, 0x0104), (683532, 0x0104), (683533, 0x0104), (683534, 0x0104),
+    (683535, 0x0304), (683536, 0x0401), (683541, 0x0104), (683542, 0x0104), (683543, 0x0104), (683544, 0x0104), (683545, 0x0104), (683546, 0x0104),
+    (683547, 0x0304), (683596, 0x0104), (683597, 0x0204), (683598, 0x0403), (683599, 0x0401), (683600, 0x0401), (683601, 0x0401), (683607, 0x0104),
+    (683608, 0x0204), (683609, 0x0403), (683610, 0x0401), (683611, 0x0401), (683612, 0x0401), (683618, 0x0104), (683619, 0x0204), (683620, 0x0403),
+    (683621, 0x0401), (683622, 0x0401), (683623, 0x0401), (683629, 0x0104), (683630, 0x0204), (683631, 0x0403), (683632, 0x0401), (683633, 0x0401),
+    (683634, 0x0401), (683640, 0x0104), (683641, 0x0104), (683642, 0x0304), (683643, 0x0403), (683644, 0x0401), (683645, 0x0401), (683651, 0x0104),
+    (683652, 0x0104), (683653, 0x0104), (683654, 0x0304), (683655, 0x0402), (683656, 0x0401), (683657, 0x0401), (683662, 0x0104), (683663, 0x0104),
+    (683664, 0x0104), (683665, 0x0204), (683666, 0x0404), (683667, 0x0402), (683668, 0x0401), (683673, 0x0104), (683674, 0x0104), (683675, 0x0104),
+    (683676, 0x0204), (683677, 0x0204), (683678, 0x0404), (683728, 0x0104), (683729, 0x0304), (683730, 0x0402), (683731, 0x0401), (683732, 0x0401),
+    (683739, 0x0104), (683740, 0x0304), (683741, 0x0402), (683742, 0x0401), (683743, 0x0401), (683750, 0x0104), (683751, 0x0304), (683752, 0x0402),
+    (683753, 0x0401), (683754, 0x0401), (683761, 0x0104), (683762, 0x0204), (683763, 0x0402), (683764, 0x0401), (683765, 0x0401), (683766, 0x0401),
+    (683772, 0x0104), (683773, 0x0104), (683774, 0x0404), (683775, 0x0402), (683776, 0x0401), (683777, 0x0401), (683783, 0x0104), (683784, 0x0104),
+    (683785, 0x0204), (683786, 0x0404), (683787, 0x0402), (683788, 0x0401), (683794, 0x0104), (683795, 0x0104), (683796, 0x0104), (683797, 0x0204),
+    (683798, 0x0404), (683799, 0x0402), (683805, 0x0104), (683806, 0x0104), (683807, 0x0104), (683808, 0x0204), (683860, 0x0104), (683861, 0x0403),
+    (683862, 0x0402), (683863, 0x0401), (683864, 0x0401), (683865, 0x0401), (683871, 0x0104), (683872, 0x0403), (683873, 0x0401), (683874, 0x0401),
+    (683875, 0x0401), (683876, 0x0401), (683882, 0x0104), (683883, 0x0403), (683884, 0x0401), (683885, 0x0401), (683886, 0x0401), (683887, 0x0401),
+    (683893, 0x0104), (683894, 0x0304), (683895, 0x0402), (683896, 0x0401), (683897, 0x0401), (683898, 0x0401), (683904, 0x0104), (683905, 0x0204),
+    (683906, 0x0403), (683907, 0x0402), (683908, 0x0401), (683909, 0x0401), (683915, 0x0104), (683916, 0x0104), (683917, 0x0204), (683918, 0x0404),
+    (683919, 0x0402), (683920, 0x0401), (683921, 0x0401), (683926, 0x0104), (683927, 0x0104), (683928, 0x0104), (683929, 0x0204), (683930, 0x0304),
+    (683931, 0x0403), (683932, 0x0401), (683937, 0x0104), (683938, 0x0104), (683939, 0x0104), (683940, 0x0104), (683941, 0x0304), (683942, 0x0403),
+    (683943, 0x0401), (683992, 0x0104), (683993, 0x0403), (683994, 0x0401), (683995, 0x0401), (683996, 0x0401), (683997, 0x0401), (684003, 0x0104),
+    (684004, 0x0403), (684005, 0x0401), (684006, 0x0401), (684007, 0x0401), (684008, 0x0401), (684014, 0x0104), (684015, 0x0403), (684016, 0x0402),
+    (684017, 0x0401), (684018, 0x0401), (684019, 0x0401), (684020, 0x0401), (684025, 0x0104), (684026, 0x0304), (684027, 0x0402), (684028, 0x0401),
+    (684029, 0x0401), (684030, 0x0401), (684031, 0x0401), (684036, 0x0104), (684037, 0x0204), (684038, 0x0304), (684039, 0x0403), (684040, 0x0401),
+    (684041, 0x0401), (684042, 0x0401), (684047, 0x0104), (684048, 0x0104), (684049, 0x0104), (684050, 0x0204), (684051, 0x0404), (684052, 0x0401),
+    (684053, 0x0401), (684058, 0x0104), (684059, 0x0104), (684060, 0x0104), (684061, 0x0304), (684062, 0x0403), (684063, 0x0401), (684064, 0x0401),
+    (684069, 0x0104), (684070, 0x0104), (684071, 0x0104), (684072, 0x0104), (684073, 0x0404), (684074, 0x0402), (684075, 0x0401), (684080, 0x0104),
+    (684081, 0x0104), (684082, 0x0104), (684083, 0x0104), (684084, 0x0204), (684085, 0x0404), (684124, 0x0104), (684125, 0x0403), (684126, 0x0402),
+    (684127, 0x0401), (684128, 0x0401), (684129, 0x0401), (684130, 0x0401), (684135, 0x0104), (684136, 0x0403), (684137, 0x0402), (684138, 0x0401),
+    (684139, 0x0401), (684140, 0x0401), (684146, 0x0104), (684147, 0x0404), (684148, 0x0402), (684149, 0x0401), (684150, 0x0401), (684151, 0x0401),
+    (684152, 0x0401), (684157, 0x0104), (684158, 0x0304), (684159, 0x0403), (684160, 0x0401), (684161, 0x0401), (684162, 0x0401), (684163, 0x0401),
+    (684168, 0x0104), (684169, 0x0104), (684170, 0x0204), (684171, 0x0403), (684172, 0x0401), (684173, 0x0401), (684174, 0x0401), (684179, 0x0104),
+    (684180, 0x0104), (684181, 0x0104), (684182, 0x0204), (684183, 0x0402), (684184, 0x0401), (684185, 0x0401), (684190, 0x0104), (684191, 0x0104),
+    (684192, 0x0104), (684193, 0x0304), (684194, 0x0401), (684195, 0x0401), (684196, 0x0401), (684197, 0x0401), (684201, 0x0104), (684202, 0x0104),
+    (684203, 0x0104), (684204, 0x0204), (684205, 0x0404), (684206, 0x0401), (684207, 0x0401), (684212, 0x0104), (684213, 0x0104), (684214, 0x0104),
+    (684215, 0x0104), (684216, 0x0204), (684217, 0x0403), (684388, 0x0204), (684389, 0x0204), (684390, 0x0403), (684391, 0x0401), (684392, 0x0401),
+    (684393, 0x0401), (684399, 0x0204), (684400, 0x0204), (684401, 0x0403), (684402, 0x0401), (684403, 0x0401), (684410, 0x0204), (684411, 0x0204),
+    (684412, 0x0404), (684413, 0x0401), (684414, 0x0401), (684415, 0x0401), (684421, 0x0104), (684422, 0x0104), (684423, 0x0304), (684424, 0x0402),
+    (684425, 0x0401), (684426, 0x0401), (684432, 0x0104), (684433, 0x0104), (684434, 0x0204), (684435, 0x0404), (684436, 0x0401), (684437, 0x0401),
+    (684443, 0x0104), (684444, 0x0104), (684445, 0x0104), (684446, 0x0204), (684447, 0x0404), (684448, 0x0401), (684449, 0x0401), (684454, 0x0104),
+    (684455, 0x0104), (684456, 0x0104), (684457, 0x0104), (684458, 0x0104), (684459, 0x0404), (684460, 0x0401), (684465, 0x0104), (684466, 0x0104),
+    (684467, 0x0104), (684468, 0x0104), (684469, 0x0104), (684470, 0x0204), (684471, 0x0404), (684520, 0x0104), (684521, 0x0204), (684522, 0x0403),
+    (684523, 0x0401), (684524, 0x0401), (684525, 0x0401), (684531, 0x0104), (684532, 0x0204), (684533, 0x0403), (684534, 0x0401), (684535, 0x0401),
+    (684536, 0x0401), (684542, 0x0104), (684543, 0x0204), (684544, 0x0404), (684545, 0x0401), (684546, 0x0401), (684547, 0x0401), (684553, 0x0104),
+    (684554, 0x0104), (684555, 0x0404), (684556, 0x0401), (684557, 0x0401), (684558, 0x0401), (684564, 0x0104), (684565, 0x0104), (684566, 0x0304),
+    (684567, 0x0402), (684568, 0x0401), (684569, 0x0401), (684575, 0x0104), (684576, 0x0104), (684577, 0x0204), (684578, 0x0304), (684579, 0x0402),
+    (684580, 0x0401), (684581, 0x0401), (684586, 0x0104), (684587, 0x0104), (684588, 0x0104), (684589, 0x0204), (684590, 0x0404), (684591, 0x0402),
+    (684592, 0x0401), (684597, 0x0104), (684598, 0x0104), (684599, 0x0104), (684600, 0x0204), (684601, 0x0304), (684602, 0x0304), (684652, 0x0104),
+    (684653, 0x0404), (684654, 0x0401), (684655, 0x0401), (684656, 0x0401), (684663, 0x0104), (684664, 0x0404), (684665, 0x0401), (684666, 0x0401),
+    (684667, 0x0401), (684674, 0x0104), (684675, 0x0404), (684676, 0x0401), (684677, 0x0401), (684678, 0x0401), (684685, 0x0104), (684686, 0x0304),
+    (684687, 0x0402), (684688, 0x0401), (684689, 0x0401), (684690, 0x0401), (684696, 0x0104), (684697, 0x0204), (684698, 0x0404), (684699, 0x0402),
+    (684700, 0x0401), (684701, 0x0401), (684707, 0x0104), (684708, 0x0104), (684709, 0x0204), (684710, 0x0404), (684711, 0x0402), (684712, 0x0401),
+    (684718, 0x0104), (684719, 0x0104), (684720, 0x0104), (684721, 0x0204), (684722, 0x0404), (684723, 0x0403), (684729, 0x0104), (684784, 0x0104),
+    (684785, 0x0403), (684786, 0x0401), (684787, 0x0401), (684788, 0x0401), (684795, 0x0104), (684796, 0x0403), (684797, 0x0401), (684798, 0x0401),
+    (684799, 0x0401), (684806, 0x0104), (684807, 0x0403), (684808, 0x0401), (684809, 0x0401), (684810, 0x0401), (684811, 0x0401), (684817, 0x0104),
+    (684818, 0x0404), (684819, 0x0402), (684820, 0x0401), (684821, 0x0401), (684822, 0x0401), (684828, 0x0104), (684829, 0x0204), (684830, 0x0404),
+    (684831, 0x0402), (684832, 0x0401), (684833, 0x0401), (684839, 0x0104), (684840, 0x0104), (684841, 0x0204), (684842, 0x0304), (684843, 0x0403),
+    (684844, 0x0401), (684845, 0x0401), (684850, 0x0104), (684851, 0x0104), (684852, 0x0104), (684853, 0x0104), (684854, 0x0304), (684855, 0x0403),
+    (684856, 0x0401), (684861, 0x0104), (684862, 0x0104), (684863, 0x0104), (684864, 0x0104), (684865, 0x0204), (684866, 0x0404), (684867, 0x0402),
+    (684872, 0x0104), (684873, 0x0104), (684874, 0x0104), (684916, 0x0104), (684917, 0x0403), (684918, 0x0401), (684919, 0x0401), (684920, 0x0401),
+    (684921, 0x0401), (684922, 0x0401), (684927, 0x0104), (684928, 0x0403), (684929, 0x0401), (684930, 0x0401), (684931, 0x0401), (684932, 0x0401),
+    (684938, 0x0104), (684939, 0x0403), (684940, 0x0401), (684941, 0x0401), (684942, 0x0401), (684943, 0x0401), (684944, 0x0401), (684949, 0x0104),
+    (684950, 0x0304), (684951, 0x0402), (684952, 0x0401), (684953, 0x0401), (684954, 0x0401), (684955, 0x0401), (684960, 0x0104), (684961, 0x0204),
+    (684962, 0x0404), (684963, 0x0402), (684964, 0x0401), (684965, 0x0401), (684966, 0x0401), (684971, 0x0104), (684972, 0x0104), (684973, 0x0104),
+    (684974, 0x0304), (684975, 0x0402), (684976, 0x0401), (684977, 0x0401), (684982, 0x0104), (684983, 0x0104), (684984, 0x0104), (684985, 0x0204),
+    (684986, 0x0304), (684987, 0x0402), (684988, 0x0401), (684989, 0x0401), (684993, 0x0104), (684994, 0x0104), (684995, 0x0104), (684996, 0x0104),
+    (684997, 0x0304), (684998, 0x0403), (684999, 0x0401), (685000, 0x0401), (685004, 0x0104), (685005, 0x0104), (685006, 0x0104), (685007, 0x0104),
+    (685008, 0x0204), (685009, 0x0404), (685048, 0x0104), (685049, 0x0403), (685050, 0x0401), (685051, 0x0401), (685052, 0x0401), (685053, 0x0401),
+    (685054, 0x0401), (685059, 0x0104), (685060, 0x0403), (685061, 0x0401), (685062, 0x0401), (685063, 0x0401), (685064, 0x0401), (685065, 0x0401),
+    (685070, 0x0104), (685071, 0x0404), (685072, 0x0402), (685073, 0x0401), (685074, 0x0401), (685075, 0x0401), (685076, 0x0401), (685081, 0x0104),
+    (685082, 0x0204), (685083, 0x0403), (685084, 0x0401), (685085, 0x0401), (685086, 0x0401), (685087, 0x0401), (685092, 0x0104), (685093, 0x0104),
+    (685094, 0x0204), (685095, 0x0403), (685096, 0x0401), (685097, 0x0401), (685098, 0x0401), (685103, 0x0104), (685104, 0x0104), (685105, 0x0104),
+    (685106, 0x0304), (685107, 0x0403), (685108, 0x0401), (685109, 0x0401), (685114, 0x0104), (685115, 0x0104), (685116, 0x0104), (685117, 0x0304),
+    (685118, 0x0402), (685119, 0x0401), (685120, 0x0401), (685121, 0x0401), (685125, 0x0104), (685126, 0x0104), (685127, 0x0104), (685128, 0x0204),
+    (685129, 0x0404), (685130, 0x0401), (685131, 0x0401), (685132, 0x0401), (685136, 0x0104), (685137, 0x0104), (685138, 0x0104), (685139, 0x0104),
+    (685140, 0x0204), (685141, 0x0402), (685444, 0x0104), (685445, 0x0104), (685446, 0x0304), (685447, 0x0402), (685448, 0x0401), (685449, 0x0401),
+    (685455, 0x0104), (685456, 0x0104), (685457, 0x0304), (685458, 0x0402), (685459, 0x0401), (685460, 0x0401), (685466, 0x0104), (685467, 0x0104),
+    (685468, 0x0304), (685469, 0x0402), (685470, 0x0401), (685471, 0x0401), (685472, 0x0401), (685477, 0x0104), (685478, 0x0104), (685479, 0x0204),
+    (685480, 0x0403), (685481, 0x0401), (685482, 0x0401), (685483, 0x0401), (685488, 0x0104), (685489, 0x0104), (685490, 0x0104), (685491, 0x0304),
+    (685492, 0x0401), (685493, 0x0401), (685494, 0x0401), (685499, 0x0104), (685500, 0x0104), (685501, 0x0104), (685502, 0x0104), (685503, 0x0404),
+    (685504, 0x0401), (685505, 0x0401), (685510, 0x0104), (685511, 0x0104), (685512, 0x0104), (685513, 0x0104), (685514, 0x0104), (685515, 0x0204),
+    (685516, 0x0401), (685517, 0x0401), (685521, 0x0104), (685522, 0x0104), (685523, 0x0104), (685524, 0x0104), (685525, 0x0104), (685526, 0x0104),
+    (685527, 0x0304), (685528, 0x0401), (685532, 0x0104), (685533, 0x0104), (685534, 0x0104), (685535, 0x0104), (685536, 0x0104), (685537, 0x0104),
+    (685538, 0x0104), (685539, 0x0104), (685540, 0x0401), (685576, 0x0104), (685577, 0x0104), (685578, 0x0404), (685579, 0x0402), (685580, 0x0401),
+    (685581, 0x0401), (685587, 0x0104), (685588, 0x0204), (685589, 0x0404), (685590, 0x0402), (685591, 0x0401), (685592, 0x0401), (685598, 0x0104),
+    (685599, 0x0104), (685600, 0x0404), (685601, 0x0402), (685602, 0x0401), (685603, 0x0401), (685604, 0x0401), (685609, 0x0104), (685610, 0x0104),
+    (685611, 0x0304), (685612, 0x0402), (685613, 0x0401), (685614, 0x0401), (685615, 0x0401), (685620, 0x0104), (685621, 0x0104), (685622, 0x0204),
+    (685623, 0x0404), (685624, 0x0401), (685625, 0x0401), (685626, 0x0401), (685631, 0x0104), (685632, 0x0104), (685633, 0x0104), (685634, 0x0204),
+    (685635, 0x0403), (685636, 0x0401), (685637, 0x0401), (685642, 0x0104), (685643, 0x0104), (685644, 0x0104), (685645, 0x0104), (685646, 0x0204),
+    (685647, 0x0403), (685648, 0x0401), (685649, 0x0401), (685653, 0x0104), (685654, 0x0104), (685655, 0x0104), (685656, 0x0104), (685657, 0x0204),
+    (685658, 0x0304), (685659, 0x0404), (685660, 0x0401), (685664, 0x0104), (685665, 0x0104), (685666, 0x0104), (685667, 0x0104), (685668, 0x0104),
+    (685669, 0x0204), (685670, 0x0404), (685708, 0x0104), (685709, 0x0204), (685710, 0x0403), (685711, 0x0401), (685712, 0x0401), (685713, 0x0401),
+    (685719, 0x0104), (685720, 0x0204), (685721, 0x0403), (685722, 0x0401), (685723, 0x0401), (685724, 0x0401), (685730, 0x0104), (685731, 0x0204),
+    (685732, 0x0403), (685733, 0x0401), (685734, 0x0401), (685735, 0x0401), (685741, 0x0104), (685742, 0x0204), (685743, 0x0404), (685744, 0x0402),
+    (685745, 0x0401), (685746, 0x0401), (685747, 0x0401), (685752, 0x0104), (685753, 0x0104), (685754, 0x0204), (685755, 0x0403), (685756, 0x0401),
+    (685757, 0x0401), (685758, 0x0401), (685763, 0x0104), (685764, 0x0104), (685765, 0x0104), (685766, 0x0304), (685767, 0x0403), (685768, 0x0401),
+    (685769, 0x0401), (685774, 0x0104), (685775, 0x0104), (685776, 0x0104), (685777, 0x0104), (685778, 0x0304), (685779, 0x0403), (685780, 0x0401),
+    (685781, 0x0401), (685785, 0x0104), (685786, 0x0104), (685787, 0x0104), (685788, 0x0104), (685789, 0x0204), (685790, 0x0304), (685791, 0x0403),
+    (685792, 0x0401), (685796, 0x0104), (685797, 0x0104), (685798, 0x0104), (685799, 0x0104), (685800, 0x0104), (685801, 0x0304), (685802, 0x0403),
+    (685840, 0x0104), (685841, 0x0204), (685842, 0x0403), (685843, 0x0401), (685844, 0x0401), (685845, 0x0401), (685846, 0x0401), (685851, 0x0104),
+    (685852, 0x0204), (685853, 0x0402), (685854, 0x0401), (685855, 0x0401), (685856, 0x0401), (685857, 0x0401), (685862, 0x0104), (685863, 0x0204),
+    (685864, 0x0403), (685865, 0x0401), (685866, 0x0401), (685867, 0x0401), (685868, 0x0401), (685873, 0x0104), (685874, 0x0204), (685875, 0x0404),
+    (685876, 0x0402), (685877, 0x0401), (685878, 0x0401), (685879, 0x0401), (685884, 0x0104), (685885, 0x0104), (685886, 0x0204), (685887, 0x0403),
+    (685888, 0x0401), (685889, 0x0401), (685890, 0x0401), (685891, 0x0401), (685895, 0x0104), (685896, 0x0104), (685897, 0x0104), (685898, 0x0204),
+    (685899, 0x0404), (685900, 0x0401), (685901, 0x0401), (685902, 0x0401), (685906, 0x0104), (685907, 0x0104), (685908, 0x0104), (685909, 0x0104),
+    (685910, 0x0204), (685911, 0x0304), (685912, 0x0401), (685913, 0x0401), (685917, 0x0104), (685918, 0x0104), (685919, 0x0104), (685920, 0x0104),
+    (685921, 0x0204), (685922, 0x0304), (685923, 0x0403), (685924, 0x0401), (685928, 0x0104), (685929, 0x0104), (685930, 0x0104), (685931, 0x0104),
+    (685932, 0x0104), (685933, 0x0304), (685934, 0x0402), (685935, 0x0401), (685972, 0x0104), (685973, 0x0204), (685974, 0x0403), (685975, 0x0401),
+    (685976, 0x0401), (685977, 0x0401), (685978, 0x0401), (685983, 0x0104), (685984, 0x0204), (685985, 0x0403), (685986, 0x0401), (685987, 0x0401),
+    (685988, 0x0401), (685989, 0x0401), (685994, 0x0104), (685995, 0x0204), (685996, 0x0403), (685997, 0x0401), (685998, 0x0401), (685999, 0x0401),
+    (686000, 0x0401), (686005, 0x0104), (686006, 0x0104), (686007, 0x0304), (686008, 0x0402), (686009, 0x0401), (686010, 0x0401), (686011, 0x0401),
+    (686012, 0x0401), (686016, 0x0104), (686017, 0x0104), (686018, 0x0204), (686019, 0x0304), (686020, 0x0401), (686021, 0x0401), (686022, 0x0401),
+    (686023, 0x0401), (686027, 0x0104), (686028, 0x0104), (686029, 0x0104), (686030, 0x0204), (686031, 0x0304), (686032, 0x0401), (686033, 0x0401),
+    (686034, 0x0401), (686038, 0x0104), (686039, 0x0104), (686040, 0x0104), (686041, 0x0204), (686042, 0x0404), (686043, 0x0402), (686044, 0x0401),
+    (686045, 0x0401), (686049, 0x0104), (686050, 0x0104), (686051, 0x0104), (686052, 0x0204), (686053, 0x0404), (686054, 0x0402), (686055, 0x0401),
+    (686056, 0x0401), (686060, 0x0104), (686061, 0x0104), (686062, 0x0104), (686063, 0x0104), (686064, 0x0104), (686065, 0x0304), (686066, 0x0402),
+    (686067, 0x0401), (686368, 0x0104), (686369, 0x0104), (686370, 0x0304), (686371, 0x0402), (686372, 0x0401), (686373, 0x0401), (686379, 0x0104),
+    (686380, 0x0104), (686381, 0x0304), (686382, 0x0402), (686383, 0x0401), (686384, 0x0401), (686390, 0x0104), (686391, 0x0104), (686392, 0x0304),
+    (686393, 0x0403), (686394, 0x0401), (686395, 0x0401), (686401, 0x0104), (686402, 0x0104), (686403, 0x0204), (686404, 0x0403), (686405, 0x0401),
+    (686406, 0x0401), (686407, 0x0401), (686412, 0x0104), (686413, 0x0104), (686414, 0x0104), (686415, 0x0304), (686416, 0x0401), (686417, 0x0401),
+    (686418, 0x0401), (686423, 0x0104), (686424, 0x0104), (686425, 0x0104), (686426, 0x0104), (686427, 0x0404), (686428, 0x0401), (686429, 0x0401),
+    (686434, 0x0104), (686435, 0x0104), (686436, 0x0104), (686437, 0x0104), (686438, 0x0104), (686439, 0x0304), (686440, 0x0401), (686441, 0x0401),
+    (686445, 0x0104), (686446, 0x0104), (686447, 0x0104), (686448, 0x0104), (686449, 0x0104), (686450, 0x0104), (686451, 0x0304), (686452, 0x0401),
+    (686456, 0x0104), (686457, 0x0104), (686458, 0x0104), (686459, 0x0104), (686460, 0x0104), (686461, 0x0104), (686462, 0x0104), (686463, 0x0204),
+    (686464, 0x0401), (686500, 0x0104), (686501, 0x0204), (686502, 0x0404), (686503, 0x0402), (686504, 0x0401), (686505, 0x0401), (686511, 0x0104),
+    (686512, 0x0104), (686513, 0x0404), (686514, 0x0402), (686515, 0x0401), (686516, 0x0401), (686522, 0x0104), (686523, 0x0104), (686524, 0x0404),
+    (686525, 0x0402), (686526, 0x0401), (686527, 0x0401), (686533, 0x0104), (686534, 0x0104), (686535, 0x0304), (686536, 0x0402), (686537, 0x0401),
+    (686538, 0x0401), (686539, 0x0401), (686544, 0x0104), (686545, 0x0104), (686546, 0x0204), (686547, 0x0404), (686548, 0x0401), (686549, 0x0401),
+    (686550, 0x0401), (686555, 0x0104), (686556, 0x0104), (686557, 0x0104), (686558, 0x0204), (686559, 0x0403), (686560, 0x0401), (686561, 0x0401),
+    (686566, 0x0104), (686567, 0x0104), (686568, 0x0104), (686569, 0x0104), (686570, 0x0204), (686571, 0x0404), (686572, 0x0401), (686573, 0x0401),
+    (686577, 0x0104), (686578, 0x0104), (686579, 0x0104), (686580, 0x0104), (686581, 0x0104), (686582, 0x0204), (686583, 0x0304), (686584, 0x0401),
+    (686588, 0x0104), (686589, 0x0104), (686590, 0x0104), (686591, 0x0104), (686592, 0x0104), (686593, 0x0204), (686594, 0x0204), (686632, 0x0104),
+    (686633, 0x0204), (686634, 0x0403), (686635, 0x0401), (686636, 0x0401), (686637, 0x0401), (686643, 0x0104), (686644, 0x0204), (686645, 0x0403),
+    (686646, 0x0401), (686647, 0x0401), (686648, 0x0401), (686654, 0x0104), (686655, 0x0204), (686656, 0x0403), (686657, 0x0402), (686658, 0x0401),
+    (686659, 0x0401), (686660, 0x0401), (686665, 0x0104), (686666, 0x0204), (686667, 0x0404), (686668, 0x0402), (686669, 0x0401), (686670, 0x0401),
+    (686671, 0x0401), (686676, 0x0104), (686677, 0x0104), (686678, 0x0204), (686679, 0x0403), (686680, 0x0401), (686681, 0x0401), (686682, 0x0401),
+    (686687, 0x0104), (686688, 0x0104), (686689, 0x0104), (686690, 0x0204), (686691, 0x0403), (686692, 0x0401), (686693, 0x0401), (686698, 0x0104),
+    (686699, 0x0104), (686700, 0x0104), (686701, 0x0104), (686702, 0x0204), (686703, 0x0404), (686704, 0x0401), (686705, 0x0401), (686709, 0x0104),
+    (686710, 0x0104), (686711, 0x0104), (686712, 0x0104), (686713, 0x0104), (686714, 0x0204), (686715, 0x0404), (686716, 0x0401), (686720, 0x0104),
+    (686721, 0x0104), (686722, 0x0104), (686723, 0x0104), (686724, 0x0104), (686725, 0x0204), (686726, 0x0404), (686764, 0x0104), (686765, 0x0304),
+    (686766, 0x0402), (686767, 0x0402), (686768, 0x0401), (686769, 0x0401), (686770, 0x0401), (686775, 0x0104), (686776, 0x0304), (686777, 0x0402),
+    (686778, 0x0402), (686779, 0x0401), (686780, 0x0401), (686781, 0x0401), (686786, 0x0104), (686787, 0x0304), (686788, 0x0403), (686789, 0x0402),
+    (686790, 0x0401), (686791, 0x0401), (686792, 0x0401), (686797, 0x0104), (686798, 0x0204), (686799, 0x0404), (686800, 0x0402), (686801, 0x0401),
+    (686802, 0x0401), (686803, 0x0401), (686804, 0x0401), (686808, 0x0104), (686809, 0x0104), (686810, 0x0204), (686811, 0x0403), (686812, 0x0401),
+    (686813, 0x0401), (686814, 0x0401), (686815, 0x0401), (686819, 0x0104), (686820, 0x0104), (686821, 0x0104), (686822, 0x0204), (686823, 0x0304),
+    (686824, 0x0401), (686825, 0x0401), (686826, 0x0401), (686830, 0x0104), (686831, 0x0104), (686832, 0x0104), (686833, 0x0104), (686834, 0x0104),
+    (686835, 0x0404), (686836, 0x0401), (686837, 0x0401), (686841, 0x0104), (686842, 0x0104), (686843, 0x0104), (686844, 0x0104), (686845, 0x0104),
+    (686846, 0x0304), (686847, 0x0403), (686848, 0x0401), (686852, 0x0104), (686853, 0x0104), (686854, 0x0104), (686855, 0x0104), (686856, 0x0104),
+    (686857, 0x0204), (686858, 0x0403), (686859, 0x0401), (686896, 0x0104), (686897, 0x0204), (686898, 0x0404), (686899, 0x0402), (686900, 0x0401),
+    (686901, 0x0401), (686902, 0x0401), (686907, 0x0104), (686908, 0x0204), (686909, 0x0404), (686910, 0x0402), (686911, 0x0401), (686912, 0x0401),
+    (686913, 0x0401), (686918, 0x0104), (686919, 0x0204), (686920, 0x0404), (686921, 0x0402), (686922, 0x0401), (686923, 0x0401), (686924, 0x0401),
+    (686925, 0x0401), (686929, 0x0104), (686930, 0x0104), (686931, 0x0204), (686932, 0x0402), (686933, 0x0401), (686934, 0x0401), (686935, 0x0401),
+    (686936, 0x0401), (686940, 0x0104), (686941, 0x0104), (686942, 0x0104), (686943, 0x0304), (686944, 0x0401), (686945, 0x0401), (686946, 0x0401),
+    (686947, 0x0401), (686951, 0x0104), (686952, 0x0104), (686953, 0x0104), (686954, 0x0204), (686955, 0x0404), (686956, 0x0401), (686957, 0x0401),
+    (686958, 0x0401), (686962, 0x0104), (686963, 0x0104), (686964, 0x0104), (686965, 0x0104), (686966, 0x0304), (686967, 0x0404), (686968, 0x0401),
+    (686969, 0x0401), (686973, 0x0104), (686974, 0x0104), (686975, 0x0104), (686976, 0x0104), (686977, 0x0304), (686978, 0x0402), (686979, 0x0401),
+    (686980, 0x0401), (686984, 0x0104), (686985, 0x0104), (686986, 0x0104), (686987, 0x0104), (686988, 0x0104), (686989, 0x0304), (686990, 0x0402),
+    (686991, 0x0401), (687424, 0x0104), (687425, 0x0104), (687426, 0x0204), (687427, 0x0403), (687428, 0x0401), (687429, 0x0401), (687430, 0x0401),
+    (687435, 0x0104), (687436, 0x0104), (687437, 0x0204), (687438, 0x0403), (687439, 0x0401), (687440, 0x0401), (687441, 0x0401), (687446, 0x0104),
+    (687447, 0x0104), (687448, 0x0204), (687449, 0x0403), (687450, 0x0401), (687451, 0x0401), (687452, 0x0401), (687457, 0x0104), (687458, 0x0104),
+    (687459, 0x0204), (687460, 0x0404), (687461, 0x0402), (687462, 0x0401), (687463, 0x0401), (687468, 0x0104), (687469, 0x0104), (687470, 0x0104),
+    (687471, 0x0304), (687472, 0x0402), (687473, 0x0401), (687474, 0x0401), (687475, 0x0401), (687479, 0x0104), (687480, 0x0104), (687481, 0x0104),
+    (687482, 0x0104), (687483, 0x0304), (687484, 0x0401), (687485, 0x0401), (687486, 0x0401), (687490, 0x0104), (687491, 0x0104), (687492, 0x0104),
+    (687493, 0x0104), (687494, 0x0104), (687495, 0x0204), (687496, 0x0401), (687497, 0x0401), (687501, 0x0104), (687502, 0x0104), (687503, 0x0104),
+    (687504, 0x0104), (687505, 0x0104), (687506, 0x0104), (687507, 0x0304), (687508, 0x0401), (687509, 0x0401), (687512, 0x0104), (687513, 0x0104),
+    (687514, 0x0104), (687515, 0x0104), (687516, 0x0104), (687517, 0x0104), (687518, 0x0104), (687519, 0x0204), (687520, 0x0401), (687523, 0x0104),
+    (687524, 0x0104), (687525, 0x0104), (687526, 0x0104), (687527, 0x0104), (687528, 0x0104), (687529, 0x0104), (687530, 0x0104), (687531, 0x0104),
+    (687532, 0x0401), (687556, 0x0104), (687557, 0x0104), (687558, 0x0304), (687559, 0x0403), (687560, 0x0402), (687561, 0x0401), (687562, 0x0401),
+    (687567, 0x0104), (687568, 0x0104), (687569, 0x0304), (687570, 0x0403), (687571, 0x0402), (687572, 0x0401), (687573, 0x0401), (687578, 0x0104),
+    (687579, 0x0104), (687580, 0x0304), (687581, 0x0404), (687582, 0x0401), (687583, 0x0401), (687584, 0x0401), (687589, 0x0104), (687590, 0x0104),
+    (687591, 0x0204), (687592, 0x0404), (687593, 0x0402), (687594, 0x0401), (687595, 0x0401), (687596, 0x0401), (687600, 0x0104), (687601, 0x0104),
+    (687602, 0x0104), (687603, 0x0204), (687604, 0x0402), (687605, 0x0401), (687606, 0x0401), (687607, 0x0401), (687611, 0x0104), (687612, 0x0104),
+    (687613, 0x0104), (687614, 0x0104), (687615, 0x0404), (687616, 0x0401), (687617, 0x0401), (687618, 0x0401), (687622, 0x0104), (687623, 0x0104),
+    (687624, 0x0104), (687625, 0x0104), (687626, 0x0204), (687627, 0x0404), (687628, 0x0401), (687629, 0x0401), (687633, 0x0104), (687634, 0x0104),
+    (687635, 0x0104), (687636, 0x0104), (687637, 0x0104), (687638, 0x0204), (687639, 0x0304), (687640, 0x0401), (687641, 0x0401), (687644, 0x0104),
+    (687645, 0x0104), (687646, 0x0104), (687647, 0x0104), (687648, 0x0104), (687649, 0x0104), (687650, 0x0204), (687651, 0x0304), (687652, 0x0401),
+    (687655, 0x0104), (687656, 0x0104), (687657, 0x0104), (687658, 0x0104), (687688, 0x0104), (687689, 0x0104), (687690, 0x0304), (687691, 0x0402),
+    (687692, 0x0402), (687693, 0x0401), (687694, 0x0401), (687699, 0x0104), (687700, 0x0104), (687701, 0x0304), (687702, 0x0402), (687703, 0x0402),
+    (687704, 0x0401), (687705, 0x0401), (687710, 0x0104), (687711, 0x0104), (687712, 0x0204), (687713, 0x0402), (687714, 0x0401), (687715, 0x0401),
+    (687716, 0x0401), (687717, 0x0401), (687721, 0x0104), (687722, 0x0104), (687723, 0x0204), (687724, 0x0403), (687725, 0x0401), (687726, 0x0401),
+    (687727, 0x0401), (687728, 0x0401), (687732, 0x0104), (687733, 0x0104), (687734, 0x0104), (687735, 0x0404), (687736, 0x0401), (687737, 0x0401),
+    (687738, 0x0401), (687739, 0x0401), (687743, 0x0104), (687744, 0x0104), (687745, 0x0104), (687746, 0x0204), (687747, 0x0404), (687748, 0x0401),
+    (687749, 0x0401), (687750, 0x0401), (687754, 0x0104), (687755, 0x0104), (687756, 0x0104), (687757, 0x0104), (687758, 0x0204), (687759, 0x0304),
+    (687760, 0x0401), (687761, 0x0401), (687765, 0x0104), (687766, 0x0104), (687767, 0x0104), (687768, 0x0104), (687769, 0x0104), (687770, 0x0204),
+    (687771, 0x0304), (687772, 0x0401), (687773, 0x0401), (687776, 0x0104), (687777, 0x0104), (687778, 0x0104), (687779, 0x0104), (687780, 0x0104),
+    (687781, 0x0104), (687782, 0x0304), (687783, 0x0403), (687784, 0x0401), (687787, 0x0104), (687788, 0x0104), (687789, 0x0104), (687790, 0x0104),
+    (687791, 0x0104), (687792, 0x0104), (687793, 0x0404), (687820, 0x0104), (687821, 0x0104), (687822, 0x0304), (687823, 0x0403), (687824, 0x0402),
+    (687825, 0x0401), (687826, 0x0401), (687827, 0x0401), (687831, 0x0104), (687832, 0x0104), (687833, 0x0204), (687834, 0x0403), (687835, 0x0401),
+    (687836, 0x0401), (687837, 0x0401), (687838, 0x0401), (687842, 0x0104), (687843, 0x0104), (687844, 0x0204), (687845, 0x0403), (687846, 0x0401),
+    (687847, 0x0401), (687848, 0x0401), (687849, 0x0401), (687853, 0x0104), (687854, 0x0104), (687855, 0x0204), (687856, 0x0404), (687857, 0x0401),
+    (687858, 0x0401), (687859, 0x0401), (687860, 0x0401), (687864, 0x0104), (687865, 0x0104), (687866, 0x0104), (687867, 0x0204), (687868, 0x0402),
+    (687869, 0x0401), (687870, 0x0401), (687871, 0x0401), (687875, 0x0104), (687876, 0x0104), (687877, 0x0104), (687878, 0x0104), (687879, 0x0304),
+    (687880, 0x0401), (687881, 0x0401), (687882, 0x0401), (687886, 0x0104), (687887, 0x0104), (687888, 0x0104), (687889, 0x0104), (687890, 0x0204),
+    (687891, 0x0304), (687892, 0x0401), (687893, 0x0401), (687894, 0x0401), (687897, 0x0104), (687898, 0x0104), (687899, 0x0104), (687900, 0x0104),
+    (687901, 0x0104), (687902, 0x0204), (687903, 0x0304), (687904, 0x0401), (687905, 0x0401), (687908, 0x0104), (687909, 0x0104), (687910, 0x0104),
+    (687911, 0x0104), (687912, 0x0104), (687913, 0x0204), (687914, 0x0404), (687915, 0x0401), (687916, 0x0401), (687919, 0x0104), (687920, 0x0104),
+    (687921, 0x0104), (687922, 0x0104), (687923, 0x0104), (687924, 0x0204), (687925, 0x0404), (688348, 0x0104), (688349, 0x0104), (688350, 0x0204),
+    (688351, 0x0403), (688352, 0x0401), (688353, 0x0401), (688354, 0x0401), (688359, 0x0104), (688360, 0x0104), (688361, 0x0204), (688362, 0x0403),
+    (688363, 0x0401), (688364, 0x0401), (688365, 0x0401), (688370, 0x0104), (688371, 0x0104), (688372, 0x0204), (688373, 0x0403), (688374, 0x0401),
+    (688375, 0x0401), (688376, 0x0401), (688381, 0x0104), (688382, 0x0104), (688383, 0x0204), (688384, 0x0404), (688385, 0x0401), (688386, 0x0401),
+    (688387, 0x0401), (688392, 0x0104), (688393, 0x0104), (688394, 0x0104), (688395, 0x0304), (688396, 0x0402), (688397, 0x0401), (688398, 0x0401),
+    (688399, 0x0401), (688403, 0x0104), (688404, 0x0104), (688405, 0x0104), (688406, 0x0104), (688407, 0x0304), (688408, 0x0401), (688409, 0x0401),
+    (688410, 0x0401), (688414, 0x0104), (688415, 0x0104), (688416, 0x0104), (688417, 0x0104), (688418, 0x0104), (688419, 0x0204), (688420, 0x0401),
+    (688421, 0x0401), (688425, 0x0104), (688426, 0x0104), (688427, 0x0104), (688428, 0x0104), (688429, 0x0104), (688430, 0x0104), (688431, 0x0204),
+    (688432, 0x0401), (688433, 0x0401), (688436, 0x0104), (688437, 0x0104), (688438, 0x0104), (688439, 0x0104), (688440, 0x0104), (688441, 0x0104),
+    (688442, 0x0104), (688443, 0x0104), (688444, 0x0401), (688447, 0x0104), (688448, 0x0104), (688449, 0x0104), (688450, 0x0104), (688451, 0x0104),
+    (688452, 0x0104), (688453, 0x0104), (688454, 0x0104), (688455, 0x0204), (688480, 0x0104), (688481, 0x0104), (688482, 0x0204), (688483, 0x0403),
+    (688484, 0x0402), (688485, 0x0401), (688486, 0x0401), (688491, 0x0104), (688492, 0x0104), (688493, 0x0304), (688494, 0x0403), (688495, 0x0401),
+    (688496, 0x0401), (688497, 0x0401), (688502, 0x0104), (688503, 0x0104), (688504, 0x0304), (688505, 0x0403), (688506, 0x0402), (688507, 0x0401),
+    (688508, 0x0401), (688513, 0x0104), (688514, 0x0104), (688515, 0x0204), (688516, 0x0404), (688517, 0x0402), (688518, 0x0401), (688519, 0x0401),
+    (688524, 0x0104), (688525, 0x0104), (688526, 0x0104), (688527, 0x0304), (688528, 0x0402), (688529, 0x0401), (688530, 0x0401), (688531, 0x0401),
+    (688535, 0x0104), (688536, 0x0104), (688537, 0x0104), (688538, 0x0104), (688539, 0x0404), (688540, 0x0401), (688541, 0x0401), (688542, 0x0401),
+    (688546, 0x0104), (688547, 0x0104), (688548, 0x0104), (688549, 0x0104), (688550, 0x0104), (688551, 0x0304), (688552, 0x0401), (688553, 0x0401),
+    (688557, 0x0104), (688558, 0x0104), (688559, 0x0104), (688560, 0x0104), (688561, 0x0104), (688562, 0x0104), (688563, 0x0204), (688564, 0x0401),
+    (688565, 0x0401), (688568, 0x0104), (688569, 0x0104), (688570, 0x0104), (688571, 0x0104), (688572, 0x0104), (688573, 0x0104), (688574, 0x0104),
+    (688575, 0x0204), (688576, 0x0401), (688579, 0x0104), (688580, 0x0104), (688581, 0x0104), (688612, 0x0104), (688613, 0x0104), (688614, 0x0304),
+    (688615, 0x0403), (688616, 0x0402), (688617, 0x0401), (688618, 0x0401), (688623, 0x0104), (688624, 0x0104), (688625, 0x0304), (688626, 0x0403),
+    (688627, 0x0401), (688628, 0x0402), (688629, 0x0401), (688634, 0x0104), (688635, 0x0104), (688636, 0x0304), (688637, 0x0403), (688638, 0x0401),
+    (688639, 0x0401), (688640, 0x0401), (688645, 0x0104), (688646, 0x0104), (688647, 0x0204), (688648, 0x0403), (688649, 0x0401), (688650, 0x0401),
+    (688651, 0x0401), (688652, 0x0401), (688656, 0x0104), (688657, 0x0104), (688658, 0x0104), (688659, 0x0304), (688660, 0x0402), (688661, 0x0401),
+    (688662, 0x0401), (688663, 0x0401), (688667, 0x0104), (688668, 0x0104), (688669, 0x0104), (688670, 0x0104), (688671, 0x0404), (688672, 0x0401),
+    (688673, 0x0401), (688674, 0x0401), (688678, 0x0104), (688679, 0x0104), (688680, 0x0104), (688681, 0x0104), (688682, 0x0104), (688683, 0x0304),
+    (688684, 0x0401), (688685, 0x0401), (688686, 0x0401), (688689, 0x0104), (688690, 0x0104), (688691, 0x0104), (688692, 0x0104), (688693, 0x0104),
+    (688694, 0x0104), (688695, 0x0304), (688696, 0x0401), (688697, 0x0401), (688700, 0x0104), (688701, 0x0104), (688702, 0x0104), (688703, 0x0104),
+    (688704, 0x0104), (688705, 0x0104), (688706, 0x0204), (688707, 0x0304), (688708, 0x0401), (688711, 0x0104), (688712, 0x0104), (688713, 0x0104),
+    (688714, 0x0104), (688715, 0x0104), (688716, 0x0104), (688717, 0x0304), (688744, 0x0104), (688745, 0x0104), (688746, 0x0204), (688747, 0x0403),
+    (688748, 0x0402), (688749, 0x0401), (688750, 0x0401), (688751, 0x0401), (688755, 0x0104), (688756, 0x0104), (688757, 0x0204), (688758, 0x0403),
+    (688759, 0x0401), (688760, 0x0401), (688761, 0x0401), (688762, 0x0401), (688766, 0x0104), (688767, 0x0104), (688768, 0x0204), (688769, 0x0403),
+    (688770, 0x0401), (688771, 0x0401), (688772, 0x0401), (688773, 0x0401), (688777, 0x0104), (688778, 0x0104), (688779, 0x0204), (688780, 0x0404),
+    (688781, 0x0401), (688782, 0x0401), (688783, 0x0401), (688784, 0x0401), (688788, 0x0104), (688789, 0x0104), (688790, 0x0104), (688791, 0x0204),
+    (688792, 0x0402), (688793, 0x0401), (688794, 0x0401), (688795, 0x0401), (688799, 0x0104), (688800, 0x0104), (688801, 0x0104), (688802, 0x0104),
+    (688803, 0x0304), (688804, 0x0401), (688805, 0x0401), (688806, 0x0401), (688807, 0x0401), (688810, 0x0104), (688811, 0x0104), (688812, 0x0104),
+    (688813, 0x0104), (688814, 0x0104), (688815, 0x0304), (688816, 0x0401), (688817, 0x0401), (688818, 0x0401), (688821, 0x0104), (688822, 0x0104),
+    (688823, 0x0104), (688824, 0x0104), (688825, 0x0104), (688826, 0x0204), (688827, 0x0304), (688828, 0x0401), (688829, 0x0401), (688832, 0x0104),
+    (688833, 0x0104), (688834, 0x0104), (688835, 0x0104), (688836, 0x0104), (688837, 0x0204), (688838, 0x0403), (688839, 0x0401), (688840, 0x0401),
+    (688843, 0x0104), (688844, 0x0104), (688845, 0x0104), (688846, 0x0104), (688847, 0x0104), (688848, 0x0104), (688849, 0x0204), (688850, 0x0402),
+    (689404, 0x0104), (689405, 0x0104), (689406, 0x0104), (689407, 0x0304), (689408, 0x0403), (689409, 0x0402), (689410, 0x0401), (689415, 0x0104),
+    (689416, 0x0104), (689417, 0x0104), (689418, 0x0404), (689419, 0x0402), (689420, 0x0401), (689421, 0x0401), (689426, 0x0104), (689427, 0x0104),
+    (689428, 0x0204), (689429, 0x0304), (689430, 0x0403), (689431, 0x0401), (689432, 0x0401), (689433, 0x0401), (689437, 0x0104), (689438, 0x0104),
+    (689439, 0x0104), (689440, 0x0304), (689441, 0x0403), (689442, 0x0401), (689443, 0x0401), (689444, 0x0401), (689448, 0x0104), (689449, 0x0104),
+    (689450, 0x0104), (689451, 0x0204), (689452, 0x0403), (689453, 0x0401), (689454, 0x0401), (689455, 0x0401), (689459, 0x0104), (689460, 0x0104),
+    (689461, 0x0104), (689462, 0x0104), (689463, 0x0304), (689464, 0x0402), (689465, 0x0401), (689466, 0x0401), (689467, 0x0401), (689470, 0x0104),
+    (689471, 0x0104), (689472, 0x0104), (689473, 0x0104), (689474, 0x0104), (689475, 0x0204), (689476, 0x0401), (689477, 0x0401), (689478, 0x0401),
+    (689481, 0x0104), (689482, 0x0104), (689483, 0x0104), (689484, 0x0104), (689485, 0x0104), (689486, 0x0104), (689487, 0x0204), (689488, 0x0401),
+    (689489, 0x0401), (689492, 0x0104), (689493, 0x0104), (689494, 0x0104), (689495, 0x0104), (689496, 0x0104), (689497, 0x0104), (689498, 0x0104),
+    (689499, 0x0204), (689500, 0x0401), (689501, 0x0401), (689503, 0x0104), (689504, 0x0104), (689505, 0x0104), (689506, 0x0104), (689507, 0x0104),
+    (689508, 0x0104), (689509, 0x0104), (689510, 0x0104), (689511, 0x0104), (689512, 0x0401), (689536, 0x0104), (689537, 0x0104), (689538, 0x0204),
+    (689539, 0x0304), (689540, 0x0403), (689541, 0x0402), (689542, 0x0401), (689543, 0x0401), (689547, 0x0104), (689548, 0x0104), (689549, 0x0104),
+    (689550, 0x0304), (689551, 0x0403), (689552, 0x0402), (689553, 0x0401), (689554, 0x0401), (689558, 0x0104), (689559, 0x0104), (689560, 0x0104),
+    (689561, 0x0204), (689562, 0x0403), (689563, 0x0401), (689564, 0x0401), (689565, 0x0401), (689569, 0x0104), (689570, 0x0104), (689571, 0x0104),
+    (689572, 0x0304), (689573, 0x0403), (689574, 0x0401), (689575, 0x0401), (689576, 0x0401), (689580, 0x0104), (689581, 0x0104), (689582, 0x0104),
+    (689583, 0x0204), (689584, 0x0403), (689585, 0x0401), (689586, 0x0401), (689587, 0x0401), (689591, 0x0104), (689592, 0x0104), (689593, 0x0104),
+    (689594, 0x0104), (689595, 0x0304), (689596, 0x0402), (689597, 0x0401), (689598, 0x0401), (689599, 0x0401), (689602, 0x0104), (689603, 0x0104),
+    (689604, 0x0104), (689605, 0x0104), (689606, 0x0104), (689607, 0x0304), (689608, 0x0401), (689609, 0x0401), (689610, 0x0401), (689613, 0x0104),
+    (689614, 0x0104), (689615, 0x0104), (689616, 0x0104), (689617, 0x0104), (689618, 0x0104), (689619, 0x0204), (689620, 0x0401), (689621, 0x0401),
+    (689624, 0x0104), (689625, 0x0104), (689626, 0x0104), (689627, 0x0104), (689628, 0x0104), (689629, 0x0104), (689630, 0x0104), (689631, 0x0204),
+    (689632, 0x0401), (689633, 0x0401), (689635, 0x0104), (689636, 0x0104), (689637, 0x0104), (689638, 0x0104), (689639, 0x0104), (689640, 0x0104),
+    (689641, 0x0104), (689642, 0x0204), (689643, 0x0204), (689644, 0x0401), (689668, 0x0104), (689669, 0x0104), (689670, 0x0104), (689671, 0x0304),
+    (689672, 0x0403), (689673, 0x0402), (689674, 0x0401), (689675, 0x0401), (689679, 0x0104), (689680, 0x0104), (689681, 0x0104), (689682, 0x0304),
+    (689683, 0x0403), (689684, 0x0402), (689685, 0x0401), (689686, 0x0401), (689690, 0x0104), (689691, 0x0104), (689692, 0x0104), (689693, 0x0304),
+    (689694, 0x0402), (689695, 0x0401), (689696, 0x0401), (689697, 0x0401), (689701, 0x0104), (689702, 0x0104), (689703, 0x0104), (689704, 0x0304),
+    (689705, 0x0402), (689706, 0x0401), (689707, 0x0401), (689708, 0x0401), (689712, 0x0104), (689713, 0x0104), (689714, 0x0104), (689715, 0x0204),
+    (689716, 0x0403), (689717, 0x0401), (689718, 0x0401), (689719, 0x0401), (689720, 0x0401), (689723, 0x0104), (689724, 0x0104), (689725, 0x0104),
+    (689726, 0x0104), (689727, 0x0204), (689728, 0x0401), (689729, 0x0401), (689730, 0x0401), (689731, 0x0401), (689734, 0x0104), (689735, 0x0104),
+    (689736, 0x0104), (689737, 0x0104), (689738, 0x0104), (689739, 0x0304), (689740, 0x0401), (689741, 0x0401), (689742, 0x0401), (689745, 0x0104),
+    (689746, 0x0104), (689747, 0x0104), (689748, 0x0104), (689749, 0x0104), (689750, 0x0104), (689751, 0x0204), (689752, 0x0401), (689753, 0x0401),
+    (689756, 0x0104), (689757, 0x0104), (689758, 0x0104), (689759, 0x0104), (689760, 0x0104), (689761, 0x0104), (689762, 0x0204), (689763, 0x0304),
+    (689764, 0x0401), (689767, 0x0104), (689768, 0x0104), (689769, 0x0104), (689770, 0x0104), (689771, 0x0104), (689772, 0x0104), (689773, 0x0204),
+    (689774, 0x0403), (689775, 0x0402), (690328, 0x0104), (690329, 0x0104), (690330, 0x0104), (690331, 0x0304), (690332, 0x0403), (690333, 0x0402),
+    (690334, 0x0401), (690335, 0x0401), (690339, 0x0104), (690340, 0x0104), (690341, 0x0204), (690342, 0x0404), (690343, 0x0402), (690344, 0x0401),
+    (690345, 0x0401), (690350, 0x0104), (690351, 0x0104), (690352, 0x0204), (690353, 0x0304), (690354, 0x0403), (690355, 0x0401), (690356, 0x0401),
+    (690357, 0x0401), (690361, 0x0104), (690362, 0x0104), (690363, 0x0104), (690364, 0x0304), (690365, 0x0402), (690366, 0x0401), (690367, 0x0401),
+    (690368, 0x0401), (690372, 0x0104), (690373, 0x0104), (690374, 0x0104), (690375, 0x0204), (690376, 0x0404), (690377, 0x0401), (690378, 0x0401),
+    (690379, 0x0401), (690383, 0x0104), (690384, 0x0104), (690385, 0x0104), (690386, 0x0104), (690387, 0x0204), (690388, 0x0402), (690389, 0x0401),
+    (690390, 0x0401), (690391, 0x0401), (690394, 0x0104), (690395, 0x0104), (690396, 0x0104), (690397, 0x0104), (690398, 0x0104), (690399, 0x0204),
+    (690400, 0x0401), (690401, 0x0401), (690402, 0x0401), (690405, 0x0104), (690406, 0x0104), (690407, 0x0104), (690408, 0x0104), (690409, 0x0104),
+    (690410, 0x0104), (690411, 0x0204), (690412, 0x0401), (690413, 0x0401), (690416, 0x0104), (690417, 0x0104), (690418, 0x0104), (690419, 0x0104),
+    (690420, 0x0104), (690421, 0x0104), (690422, 0x0104), (690423, 0x0204), (690424, 0x0401), (690425, 0x0401), (690427, 0x0104), (690428, 0x0104),
+    (690429, 0x0104), (690430, 0x0104), (690431, 0x0104), (690432, 0x0104), (690433, 0x0104), (690434, 0x0104), (690435, 0x0104), (690436, 0x0401),
+    (690460, 0x0104), (690461, 0x0104), (690462, 0x0204), (690463, 0x0304), (690464, 0x0403), (690465, 0x0402), (690466, 0x0401), (690467, 0x0401),
+    (690471, 0x0104), (690472, 0x0104), (690473, 0x0204), (690474, 0x0304), (690475, 0x0403), (690476, 0x0402), (690477, 0x0401), (690478, 0x0401),
+    (690482, 0x0104), (690483, 0x0104), (690484, 0x0204), (690485, 0x0304), (690486, 0x0403), (690487, 0x0401), (690488, 0x0401), (690489, 0x0401),
+    (690493, 0x0104), (690494, 0x0104), (690495, 0x0104), (690496, 0x0304), (690497, 0x0403), (690498, 0x0401), (690499, 0x0401), (690500, 0x0401),
+    (690504, 0x0104), (690505, 0x0104), (690506, 0x0104), (690507, 0x0204), (690508, 0x0404), (690509, 0x0401), (690510, 0x0401), (690511, 0x0401),
+    (690512, 0x0401), (690515, 0x0104), (690516, 0x0104), (690517, 0x0104), (690518, 0x0104), (690519, 0x0304), (690520, 0x0402), (690521, 0x0401),
+    (690522, 0x0401), (690523, 0x0401), (690526, 0x0104), (690527, 0x0104), (690528, 0x0104), (690529, 0x0104), (690530, 0x0104), (690531, 0x0304),
+    (690532, 0x0401), (690533, 0x0401), (690534, 0x0401), (690537, 0x0104), (690538, 0x0104), (690539, 0x0104), (690540, 0x0104), (690541, 0x0104),
+    (690542, 0x0104), (690543, 0x0204), (690544, 0x0401), (690545, 0x0401), (690548, 0x0104), (690549, 0x0104), (690550, 0x0104), (690551, 0x0104),
+    (690552, 0x0104), (690553, 0x0104), (690554, 0x0104), (690555, 0x0204), (690556, 0x0401), (690557, 0x0401), (690559, 0x0104), (690560, 0x0104),
+    (690561, 0x0104), (690562, 0x0104), (690563, 0x0104), (690564, 0x0104), (690565, 0x0104), (690566, 0x0104), (690567, 0x0104), (690568, 0x0401),
+    (690592, 0x0104), (690593, 0x0104), (690594, 0x0104), (690595, 0x0304), (690596, 0x0403), (690597, 0x0402), (690598, 0x0401), (690599, 0x0401),
+    (690603, 0x0104), (690604, 0x0104), (690605, 0x0104), (690606, 0x0304), (690607, 0x0403), (690608, 0x0401), (690609, 0x0401), (690610, 0x0401),
+    (690614, 0x0104), (690615, 0x0104), (690616, 0x0104), (690617, 0x0304), (690618, 0x0402), (690619, 0x0401), (690620, 0x0401), (690621, 0x0401),
+    (690625, 0x0104), (690626, 0x0104), (690627, 0x0104), (690628, 0x0204), (690629, 0x0402), (690630, 0x0401), (690631, 0x0401), (690632, 0x0401),
+    (690636, 0x0104), (690637, 0x0104), (690638, 0x0104), (690639, 0x0104), (690640, 0x0403), (690641, 0x0401), (690642, 0x0401), (690643, 0x0401),
+    (690644, 0x0401), (690647, 0x0104), (690648, 0x0104), (690649, 0x0104), (690650, 0x0104), (690651, 0x0204), (690652, 0x0401), (690653, 0x0401),
+    (690654, 0x0401), (690655, 0x0401), (690658, 0x0104), (690659, 0x0104), (690660, 0x0104), (690661, 0x0104), (690662, 0x0104), (690663, 0x0204),
+    (690664, 0x0401), (690665, 0x0401), (690666, 0x0401), (690669, 0x0104), (690670, 0x0104), (690671, 0x0104), (690672, 0x0104), (690673, 0x0104),
+    (690674, 0x0104), (690675, 0x0304), (690676, 0x0401), (690677, 0x0401), (690680, 0x0104), (690681, 0x0104), (690682, 0x0104), (690683, 0x0104),
+    (690684, 0x0104), (690685, 0x0104), (690686, 0x0204), (690687, 0x0304), (690688, 0x0401), (690691, 0x0104), (690692, 0x0104), (690693, 0x0104),
+    (690694, 0x0104), (690695, 0x0104), (690696, 0x0104), (690697, 0x0204), (690698, 0x0403), (690699, 0x0401), (691384, 0x0104), (691385, 0x0104),
+    (691386, 0x0104), (691387, 0x0204), (691388, 0x0304), (691389, 0x0403), (691390, 0x0402), (691391, 0x0402), (691392, 0x0401), (691395, 0x0104),
+    (691396, 0x0104), (691397, 0x0104), (691398, 0x0204), (691399, 0x0304), (691400, 0x0403), (691401, 0x0402), (691402, 0x0401), (691406, 0x0104),
+    (691407, 0x0104), (691408, 0x0104), (691409, 0x0204), (691410, 0x0404), (691411, 0x0403), (691412, 0x0401), (691413, 0x0401), (691417, 0x0104),
+    (691418, 0x0104), (691419, 0x0104), (691420, 0x0204), (691421, 0x0404), (691422, 0x0402), (691423, 0x0401), (691424, 0x0401), (691425, 0x0401),
+    (691428, 0x0104), (691429, 0x0104), (691430, 0x0104), (691431, 0x0104), (691432, 0x0304), (691433, 0x0402), (691434, 0x0401), (691435, 0x0401),
+    (691436, 0x0401), (691439, 0x0104), (691440, 0x0104), (691441, 0x0104), (691442, 0x0104), (691443, 0x0204), (691444, 0x0403), (691445, 0x0401),
+    (691446, 0x0401), (691447, 0x0401), (691450, 0x0104), (691451, 0x0104), (691452, 0x0104), (691453, 0x0104), (691454, 0x0104), (691455, 0x0204),
+    (691456, 0x0401), (691457, 0x0401), (691458, 0x0401), (691461, 0x0104), (691462, 0x0104), (691463, 0x0104), (691464, 0x0104), (691465, 0x0104),
+    (691466, 0x0104), (691467, 0x0204), (691468, 0x0401), (691469, 0x0401), (691470, 0x0401), (691472, 0x0104), (691473, 0x0104), (691474, 0x0104),
+    (691475, 0x0104), (691476, 0x0104), (691477, 0x0104), (691478, 0x0104), (691479, 0x0104), (691480, 0x0401), (691481, 0x0401), (691483, 0x0104),
+    (691484, 0x0104), (691485, 0x0104), (691486, 0x0104), (691487, 0x0104), (691488, 0x0104), (691489, 0x0104), (691490, 0x0104), (691491, 0x0104),
+    (691492, 0x0401), (691493, 0x0401), (691494, 0x0104), (691495, 0x0104), (691496, 0x0104), (691497, 0x0104), (691498, 0x0104), (691499, 0x0104),
+    (691500, 0x0104), (691501, 0x0104), (691502, 0x0104), (691503, 0x0104), (691504, 0x0401), (691516, 0x0104), (691517, 0x0104), (691518, 0x0104),
+    (691519, 0x0104), (691520, 0x0204), (691521, 0x0403), (691522, 0x0402), (691523, 0x0401), (691524, 0x0401), (691527, 0x0104), (691528, 0x0104),
+    (691529, 0x0104), (691530, 0x0204), (691531, 0x0304), (691532, 0x0403), (691533, 0x0401), (691534, 0x0401), (691538, 0x0104), (691539, 0x0104),
+    (691540, 0x0104), (691541, 0x0104), (691542, 0x0304), (691543, 0x0402), (691544, 0x0401), (691545, 0x0401), (691546, 0x0401), (691549, 0x0104),
+    (691550, 0x0104), (691551, 0x0104), (691552, 0x0104), (691553, 0x0304), (691554, 0x0402), (691555, 0x0401), (691556, 0x0401), (691557, 0x0401),
+    (691560, 0x0104), (691561, 0x0104), (691562, 0x0104), (691563, 0x0104), (691564, 0x0304), (691565, 0x0402), (691566, 0x0401), (691567, 0x0401),
+    (691568, 0x0401), (691571, 0x0104), (691572, 0x0104), (691573, 0x0104), (691574, 0x0104), (691575, 0x0204), (691576, 0x0403), (691577, 0x0401),
+    (691578, 0x0401), (691579, 0x0401), (691582, 0x0104), (691583, 0x0104), (691584, 0x0104), (691585, 0x0104), (691586, 0x0104), (691587, 0x0204),
+    (691588, 0x0401), (691589, 0x0401), (691590, 0x0401), (691593, 0x0104), (691594, 0x0104), (691595, 0x0104), (691596, 0x0104), (691597, 0x0104),
+    (691598, 0x0104), (691599, 0x0204), (691600, 0x0401), (691601, 0x0401), (691604, 0x0104), (691605, 0x0104), (691606, 0x0104), (691607, 0x0104),
+    (691608, 0x0104), (691609, 0x0104), (691610, 0x0104), (691611, 0x0204), (691612, 0x0401), (691613, 0x0401), (691615, 0x0104), (691616, 0x0104),
+    (691617, 0x0104), (691618, 0x0104), (691619, 0x0104), (691620, 0x0104), (691621, 0x0104), (691622, 0x0204), (691623, 0x0204), (691624, 0x0401),
+    (692308, 0x0104), (692309, 0x0104), (692310, 0x0104), (692311, 0x0204), (692312, 0x0304), (692313, 0x0404), (692314, 0x0402), (692315, 0x0401),
+    (692319, 0x0104), (692320, 0x0104), (692321, 0x0204), (692322, 0x0204), (692323, 0x0304), (692324, 0x0402), (692325, 0x0402), (692326, 0x0402),
+    (692330, 0x0104), (692331, 0x0104), (692332, 0x0104), (692333, 0x0204), (692334, 0x0404), (692335, 0x0402), (692336, 0x0401), (692337, 0x0401),
+    (692341, 0x0104), (692342, 0x0104), (692343, 0x0104), (692344, 0x0204), (692345, 0x0404), (692346, 0x0402), (692347, 0x0401), (692348, 0x0401),
+    (692349, 0x0401), (692352, 0x0104), (692353, 0x0104), (692354, 0x0104), (692355, 0x0104), (692356, 0x0304), (692357, 0x0402), (692358, 0x0401),
+    (692359, 0x0401), (692360, 0x0401), (692363, 0x0104), (692364, 0x0104), (692365, 0x0104), (692366, 0x0104), (692367, 0x0104), (692368, 0x0403),
+    (692369, 0x0401), (692370, 0x0401), (692371, 0x0401), (692374, 0x0104), (692375, 0x0104), (692376, 0x0104), (692377, 0x0104), (692378, 0x0104),
+    (692379, 0x0104), (692380, 0x0401), (692381, 0x0401), (692382, 0x0401), (692385, 0x0104), (692386, 0x0104), (692387, 0x0104), (692388, 0x0104),
+    (692389, 0x0104), (692390, 0x0104), (692391, 0x0204), (692392, 0x0401), (692393, 0x0401), (692394, 0x0401), (692396, 0x0104), (692397, 0x0104),
+    (692398, 0x0104), (692399, 0x0104), (692400, 0x0104), (692401, 0x0104), (692402, 0x0104), (692403, 0x0104), (692404, 0x0401), (692405, 0x0401),
+    (692407, 0x0104), (692408, 0x0104), (692409, 0x0104), (692410, 0x0104), (692411, 0x0104), (692412, 0x0104), (692413, 0x0104), (692414, 0x0104),
+    (692415, 0x0104), (692416, 0x0401), (692418, 0x0104), (692419, 0x0104), (692420, 0x0104), (692421, 0x0104), (692422, 0x0104), (692423, 0x0104),
+    (692424, 0x0104), (692425, 0x0104), (692426, 0x0104), (692427, 0x0104), (692428, 0x0401), (692440, 0x0104), (692441, 0x0104), (692442, 0x0104),
+    (692443, 0x0104), (692444, 0x0204), (692445, 0x0404), (692446, 0x0402), (692447, 0x0401), (692448, 0x0401), (692451, 0x0104), (692452, 0x0104),
+    (692453, 0x0104), (692454, 0x0104), (692455, 0x0304), (692456, 0x0402), (692457, 0x0401), (692458, 0x0401), (692462, 0x0104), (692463, 0x0104),
+    (692464, 0x0104), (692465, 0x0104), (692466, 0x0304), (692467, 0x0402), (692468, 0x0401), (692469, 0x0401), (692470, 0x0401), (692473, 0x0104),
+    (692474, 0x0104), (692475, 0x0104), (692476, 0x0104), (692477, 0x0304), (692478, 0x0402), (692479, 0x0401), (692480, 0x0401), (692481, 0x0401),
+    (692484, 0x0104), (692485, 0x0104), (692486, 0x0104), (692487, 0x0104), (692488, 0x0204), (692489, 0x0402), (692490, 0x0401), (692491, 0x0401),
+    (692492, 0x0401), (692495, 0x0104), (692496, 0x0104), (692497, 0x0104), (692498, 0x0104), (692499, 0x0104), (692500, 0x0403), (692501, 0x0401),
+    (692502, 0x0401), (692503, 0x0401), (692506, 0x0104), (692507, 0x0104), (692508, 0x0104), (692509, 0x0104), (692510, 0x0104), (692511, 0x0204),
+    (692512, 0x0401), (692513, 0x0401), (692514, 0x0401), (692517, 0x0104), (692518, 0x0104), (692519, 0x0104), (692520, 0x0104), (692521, 0x0104),
+    (692522, 0x0104), (692523, 0x0204), (692524, 0x0401), (692525, 0x0401), (692526, 0x0401), (692528, 0x0104), (692529, 0x0104), (692530, 0x0104),
+    (692531, 0x0104), (692532, 0x0104), (692533, 0x0104), (692534, 0x0104), (692535, 0x0204), (692536, 0x0401), (692537, 0x0401), (692539, 0x0104),
+    (692540, 0x0104), (692541, 0x0104), (692542, 0x0104), (692543, 0x0104), (692544, 0x0104), (692545, 0x0104), (692546, 0x0104), (692547, 0x0204),
+    (692548, 0x0401), (693364, 0x0104), (693365, 0x0104), (693366, 0x0104), (693367, 0x0104), (693368, 0x0204), (693369, 0x0304), (693370, 0x0403),
+    (693371, 0x0402), (693372, 0x0401), (693375, 0x0104), (693376, 0x0104), (693377, 0x0104), (693378, 0x0104), (693379, 0x0204), (693380, 0x0404),
+    (693381, 0x0402), (693382, 0x0401), (693383, 0x0401), (693386, 0x0104), (693387, 0x0104), (693388, 0x0104), (693389, 0x0104), (693390, 0x0204),
+    (693391, 0x0404), (693392, 0x0402), (693393, 0x0401), (693394, 0x0401), (693397, 0x0104), (693398, 0x0104), (693399, 0x0104), (693400, 0x0104),
+    (693401, 0x0304), (693402, 0x0403), (693403, 0x0401), (693404, 0x0401), (693405, 0x0401), (693408, 0x0104), (693409, 0x0104), (693410, 0x0104),
+    (693411, 0x0104), (693412, 0x0204), (693413, 0x0403), (693414, 0x0401), (693415, 0x0401), (693416, 0x0401), (693419, 0x0104), (693420, 0x0104),
+    (693421, 0x0104), (693422, 0x0104), (693423, 0x0104), (693424, 0x0404), (693425, 0x0401), (693426, 0x0401), (693427, 0x0401), (693430, 0x0104),
+    (693431, 0x0104), (693432, 0x0104), (693433, 0x0104), (693434, 0x0104), (693435, 0x0104), (693436, 0x0402), (693437, 0x0401), (693438, 0x0401),
+    (693439, 0x0401), (693441, 0x0104), (693442, 0x0104), (693443, 0x0104), (693444, 0x0104), (693445, 0x0104), (693446, 0x0104), (693447, 0x0104),
+    (693448, 0x0401), (693449, 0x0401), (693450, 0x0401), (693452, 0x0104), (693453, 0x0104), (693454, 0x0104), (693455, 0x0104), (693456, 0x0104),
+    (693457, 0x0104), (693458, 0x0104), (693459, 0x0104), (693460, 0x0401), (693461, 0x0401), (693463, 0x0104), (693464, 0x0104), (693465, 0x0104),
+    (693466, 0x0104), (693467, 0x0104), (693468, 0x0104), (693469, 0x0104), (693470, 0x0104), (693471, 0x0104), (693472, 0x0401), (693473, 0x0401),
+    (693474, 0x0104), (693475, 0x0104), (693476, 0x0104), (693477, 0x0104), (693478, 0x0104), (693479, 0x0104), (693480, 0x0104), (693481, 0x0104),
+    (693482, 0x0104), (693483, 0x0104), (693484, 0x0401), (694288, 0x0104), (694289, 0x0104), (694290, 0x0104), (694291, 0x0104), (694292, 0x0204),
+    (694293, 0x0304), (694294, 0x0403), (694295, 0x0401), (694296, 0x0401), (694299, 0x0104), (694300, 0x0104), (694301, 0x0104), (694302, 0x0104),
+    (694303, 0x0204), (694304, 0x0404), (694305, 0x0402), (694306, 0x0401), (694307, 0x0401), (694310, 0x0104), (694311, 0x0104), (694312, 0x0104),
+    (694313, 0x0104), (694314, 0x0204), (694315, 0x0404), (694316, 0x0402), (694317, 0x0401), (694318, 0x0401), (694321, 0x0104), (694322, 0x0104),
+    (694323, 0x0104), (694324, 0x0104), (694325, 0x0304), (694326, 0x0403), (694327, 0x0401), (694328, 0x0401), (694329, 0x0401), (694332, 0x0104),
+    (694333, 0x0104), (694334, 0x0104), (694335, 0x0104), (694336, 0x0204), (694337, 0x0403), (694338, 0x0401), (694339, 0x0401), (694340, 0x0401),
+    (694343, 0x0104), (694344, 0x0104), (694345, 0x0104), (694346, 0x0104), (694347, 0x0104), (694348, 0x0404), (694349, 0x0401), (694350, 0x0401),
+    (694351, 0x0401), (694352, 0x0401), (694354, 0x0104), (694355, 0x0104), (694356, 0x0104), (694357, 0x0104), (694358, 0x0104), (694359, 0x0104),
+    (694360, 0x0402), (694361, 0x0401), (694362, 0x0401), (694363, 0x0401), (694365, 0x0104), (694366, 0x0104), (694367, 0x0104), (694368, 0x0104),
+    (694369, 0x0104), (694370, 0x0104), (694371, 0x0104), (694372, 0x0401), (694373, 0x0401), (694374, 0x0401), (694376, 0x0104), (694377, 0x0104),
+    (694378, 0x0104), (694379, 0x0104), (694380, 0x0104), (694381, 0x0104), (694382, 0x0104), (694383, 0x0104), (694384, 0x0401), (694385, 0x0401),
+    (694387, 0x0104), (694388, 0x0104), (694389, 0x0104), (694390, 0x0104), (694391, 0x0104), (694392, 0x0104), (694393, 0x0104), (694394, 0x0104),
+    (694395, 0x0104), (694396, 0x0401), (694397, 0x0401), (694398, 0x0104), (694399, 0x0104), (694400, 0x0104), (694401, 0x0104), (694402, 0x0104),
+    (694403, 0x0104), (694404, 0x0104), (694405, 0x0104), (694406, 0x0104), (694407, 0x0104), (694408, 0x0401), (701284, 0x0104), (701285, 0x0402),
+    (701286, 0x0401), (701287, 0x0401), (701288, 0x0401), (701295, 0x0104), (701296, 0x0403), (701297, 0x0401), (701298, 0x0401), (701299, 0x0401),
+    (701306, 0x0104), (701307, 0x0304), (701308, 0x0401), (701309, 0x0401), (701310, 0x0401), (701317, 0x0104), (701318, 0x0104), (701319, 0x0404),
+    (701320, 0x0401), (701321, 0x0401), (701328, 0x0104), (701329, 0x0104), (701330, 0x0104), (701331, 0x0404), (701332, 0x0401), (701333, 0x0401),
+    (701339, 0x0104), (701340, 0x0104), (701341, 0x0104), (701342, 0x0204), (701343, 0x0304), (701344, 0x0401), (701350, 0x0104), (701351, 0x0104),
+    (701352, 0x0104), (701353, 0x0204), (701354, 0x0204), (701355, 0x0304), (701416, 0x0104), (701417, 0x0401), (701418, 0x0401), (701419, 0x0401),
+    (701420, 0x0401), (701427, 0x0104), (701428, 0x0401), (701429, 0x0401), (701430, 0x0401), (701431, 0x0401), (701438, 0x0104), (701439, 0x0403),
+    (701440, 0x0401), (701441, 0x0401), (701442, 0x0401), (701449, 0x0104), (701450, 0x0204), (701451, 0x0402), (701452, 0x0401), (701453, 0x0401),
+    (701460, 0x0104), (701461, 0x0104), (701462, 0x0204), (701463, 0x0403), (701464, 0x0401), (701465, 0x0401), (701471, 0x0104), (701472, 0x0104),
+    (701473, 0x0104), (701474, 0x0204), (701475, 0x0402), (701476, 0x0401), (701548, 0x0104), (701549, 0x0401), (701550, 0x0401), (701551, 0x0401),
+    (701559, 0x0104), (701560, 0x0401), (701561, 0x0401), (701562, 0x0401), (701570, 0x0104), (701571, 0x0401), (701572, 0x0401), (701573, 0x0401),
+    (701574, 0x0401), (701581, 0x0104), (701582, 0x0404), (701583, 0x0401), (701584, 0x0401), (701585, 0x0401), (701592, 0x0104), (701593, 0x0104),
+    (701594, 0x0404), (701595, 0x0401), (701596, 0x0401), (701680, 0x0104), (701681, 0x0401), (701682, 0x0401), (701691, 0x0104), (701692, 0x0401),
+    (701693, 0x0401), (701702, 0x0104), (701703, 0x0401), (701704, 0x0401), (701713, 0x0204), (701714, 0x0402), (701715, 0x0401), (701716, 0x0401),
+    (701724, 0x0204), (701725, 0x0403), (701812, 0x0204), (701813, 0x0401), (701814, 0x0401), (701823, 0x0104), (701824, 0x0401), (701825, 0x0401),
+    (701834, 0x0104), (701835, 0x0401), (701836, 0x0401), (701845, 0x0204), (701846, 0x0401), (701847, 0x0401), (701856, 0x0304), (701944, 0x0304),
+    (701945, 0x0401), (701946, 0x0401), (701955, 0x0104), (701956, 0x0401), (701957, 0x0401), (701966, 0x0104), (701967, 0x0401), (701968, 0x0401),
+    (701977, 0x0204), (701978, 0x0401), (701979, 0x0401), (702076, 0x0104), (702077, 0x0401), (702078, 0x0401), (702087, 0x0104), (702088, 0x0401),
+    (702089, 0x0401), (702098, 0x0104), (702099, 0x0401), (702100, 0x0401), (702109, 0x0104), (702110, 0x0401), (702111, 0x0401), (702208, 0x0104),
+    (702209, 0x0402), (702210, 0x0401), (702211, 0x0401), (702212, 0x0401), (702219, 0x0104), (702220, 0x0403), (702221, 0x0401), (702222, 0x0401),
+    (702223, 0x0401), (702230, 0x0104), (702231, 0x0404), (702232, 0x0401), (702233, 0x0401), (702234, 0x0401), (702241, 0x0104), (702242, 0x0104),
+    (702243, 0x0404), (702244, 0x0401), (702245, 0x0401), (702252, 0x0104), (702253, 0x0104), (702254, 0x0104), (702255, 0x0404), (702256, 0x0401),
+    (702257, 0x0401), (702263, 0x0104), (702264, 0x0104), (702265, 0x0104), (702266, 0x0104), (702267, 0x0304), (702268, 0x0401), (702274, 0x0104),
+    (702275, 0x0104), (702276, 0x0104), (702277, 0x0204), (702278, 0x0104), (702340, 0x0104), (702341, 0x0401), (702342, 0x0401), (702343, 0x0401),
+    (702344, 0x0401), (702351, 0x0104), (702352, 0x0401), (702353, 0x0401), (702354, 0x0401), (702355, 0x0401), (702362, 0x0104), (702363, 0x0403),
+    (702364, 0x0401), (702365, 0x0401), (702366, 0x0401), (702373, 0x0104), (702374, 0x0204), (702375, 0x0403), (702376, 0x0401), (702377, 0x0401),
+    (702384, 0x0104), (702385, 0x0104), (702386, 0x0204), (702387, 0x0402), (702388, 0x0401), (702389, 0x0401), (702395, 0x0104), (702396, 0x0104),
+    (702397, 0x0104), (702398, 0x0204), (702399, 0x0402), (702400, 0x0401), (702472, 0x0104), (702473, 0x0401), (702474, 0x0401), (702475, 0x0401),
+    (702483, 0x0104), (702484, 0x0401), (702485, 0x0401), (702486, 0x0401), (702494, 0x0104), (702495, 0x0402), (702496, 0x0401), (702497, 0x0401),
+    (702505, 0x0104), (702506, 0x0404), (702507, 0x0401), (702508, 0x0401), (702509, 0x0401), (702516, 0x0104), (702517, 0x0204), (702518, 0x0403),
+    (702519, 0x0401), (702604, 0x0104), (702605, 0x0401), (702606, 0x0401), (702615, 0x0104), (702616, 0x0401), (702617, 0x0401), (702626, 0x0104),
+    (702627, 0x0401), (702628, 0x0401), (702629, 0x0401), (702637, 0x0204), (702638, 0x0402), (702639, 0x0401), (702640, 0x0401), (702648, 0x0204),
+    (702736, 0x0204), (702737, 0x0401), (702738, 0x0401), (702747, 0x0104), (702748, 0x0401), (702749, 0x0401), (702758, 0x0204), (702759, 0x0401),
+    (702760, 0x0401), (702769, 0x0304), (702770, 0x0401), (702771, 0x0401), (702868, 0x0204), (702869, 0x0401), (702870, 0x0401), (702879, 0x0104),
+    (702880, 0x0401), (702881, 0x0401), (702890, 0x0204), (702891, 0x0401), (702892, 0x0401), (702901, 0x0204), (702902, 0x0401), (702903, 0x0401),
+    (703000, 0x0104), (703001, 0x0401), (703002, 0x0401), (703011, 0x0104), (703012, 0x0401), (703013, 0x0401), (703022, 0x0104), (703023, 0x0401),
+    (703024, 0x0401), (703033, 0x0104), (703034, 0x0401), (703035, 0x0401), (703264, 0x0104), (703265, 0x0404), (703266, 0x0401), (703267, 0x0401),
+    (703268, 0x0401), (703269, 0x0401), (703275, 0x0104), (703276, 0x0304), (703277, 0x0401), (703278, 0x0401), (703279, 0x0401), (703280, 0x0401),
+    (703286, 0x0104), (703287, 0x0204), (703288, 0x0402), (703289, 0x0401), (703290, 0x0401), (703291, 0x0401), (703297, 0x0104), (703298, 0x0104),
+    (703299, 0x0204), (703300, 0x0401), (703301, 0x0401), (703302, 0x0401), (703308, 0x0104), (703309, 0x0104), (703310, 0x0104), (703311, 0x0204),
+    (703312, 0x0401), (703313, 0x0401), (703314, 0x0401), (703319, 0x0104), (703320, 0x0104), (703321, 0x0104), (703322, 0x0104), (703323, 0x0204),
+    (703324, 0x0401), (703325, 0x0401), (703330, 0x0104), (703331, 0x0104), (703332, 0x0104), (703333, 0x0104), (703334, 0x0104), (703335, 0x0104),
+    (703336, 0x0401), (703341, 0x0104), (703342, 0x0104), (703343, 0x0104), (703344, 0x0104), (703345, 0x0104), (703346, 0x0104), (703347, 0x0104),
+    (703348, 0x0401), (703396, 0x0104), (703397, 0x0304), (703398, 0x0401), (703399, 0x0401), (703400, 0x0401), (703401, 0x0401), (703407, 0x0104),
+    (703408, 0x0304), (703409, 0x0401), (703410, 0x0401), (703411, 0x0401), (703412, 0x0401), (703418, 0x0104), (703419, 0x0204), (703420, 0x0402),
+    (703421, 0x0401), (703422, 0x0401), (703423, 0x0401), (703429, 0x0104), (703430, 0x0104), (703431, 0x0304), (703432, 0x0401), (703433, 0x0401),
+    (703434, 0x0401), (703440, 0x0104), (703441, 0x0104), (703442, 0x0104), (703443, 0x0304), (703444, 0x0401), (703445, 0x0401), (703451, 0x0104),
+    (703452, 0x0104), (703453, 0x0104), (703454, 0x0104), (703455, 0x0404), (703456, 0x0401), (703457, 0x0401), (703462, 0x0104), (703463, 0x0104),
+    (703464, 0x0104), (703465, 0x0104), (703466, 0x0104), (703467, 0x0204), (703468, 0x0401), (703528, 0x0104), (703529, 0x0404), (703530, 0x0401),
+    (703531, 0x0401), (703539, 0x0104), (703540, 0x0404), (703541, 0x0401), (703542, 0x0401), (703550, 0x0104), (703551, 0x0204), (703552, 0x0401),
+    (703553, 0x0401), (703554, 0x0401), (703561, 0x0104), (703562, 0x0104), (703563, 0x0404), (703564, 0x0401), (703565, 0x0401), (703572, 0x0104),
+    (703573, 0x0104), (703574, 0x0204), (703575, 0x0403), (703576, 0x0401), (703583, 0x0104), (703584, 0x0104), (703585, 0x0204), (703660, 0x0104),
+    (703661, 0x0401), (703662, 0x0401), (703663, 0x0401), (703671, 0x0104), (703672, 0x0401), (703673, 0x0401), (703674, 0x0401), (703682, 0x0104),
+    (703683, 0x0403), (703684, 0x0401), (703685, 0x0401), (703693, 0x0104), (703694, 0x0404), (703695, 0x0401), (703696, 0x0401), (703704, 0x0104),
+    (703705, 0x0404), (703706, 0x0402), (703792, 0x0104), (703793, 0x0401), (703794, 0x0401), (703803, 0x0104), (703804, 0x0401), (703805, 0x0401),
+    (703814, 0x0104), (703815, 0x0402), (703816, 0x0401), (703825, 0x0104), (703826, 0x0402), (703827, 0x0401), (703836, 0x0104), (703924, 0x0104),
+    (703925, 0x0401), (703926, 0x0401), (703935, 0x0104), (703936, 0x0401), (703937, 0x0401), (703946, 0x0104), (703947, 0x0401), (703948, 0x0401),
+    (703957, 0x0104), (703958, 0x0401), (703959, 0x0401), (704188, 0x0104), (704189, 0x0404), (704190, 0x0401), (704191, 0x0401), (704192, 0x0401),
+    (704193, 0x0401), (704199, 0x0104), (704200, 0x0304), (704201, 0x0401), (704202, 0x0401), (704203, 0x0401), (704204, 0x0401), (704210, 0x0104),
+    (704211, 0x0104), (704212, 0x0402), (704213, 0x0401), (704214, 0x0401), (704215, 0x0401), (704221, 0x0104), (704222, 0x0104), (704223, 0x0204),
+    (704224, 0x0401), (704225, 0x0401), (704226, 0x0401), (704232, 0x0104), (704233, 0x0104), (704234, 0x0104), (704235, 0x0204), (704236, 0x0401),
+    (704237, 0x0401), (704243, 0x0104), (704244, 0x0104), (704245, 0x0104), (704246, 0x0104), (704247, 0x0204), (704248, 0x0401), (704249, 0x0401),
+    (704254, 0x0104), (704255, 0x0104), (704256, 0x0104), (704257, 0x0104), (704258, 0x0104), (704259, 0x0104), (704260, 0x0401), (704265, 0x0104),
+    (704266, 0x0104), (704267, 0x0104), (704268, 0x0104), (704269, 0x0104), (704270, 0x0104), (704271, 0x0104), (704272, 0x0401), (704320, 0x0104),
+    (704321, 0x0404), (704322, 0x0401), (704323, 0x0401), (704324, 0x0401), (704331, 0x0104), (704332, 0x0304), (704333, 0x0401), (704334, 0x0401),
+    (704335, 0x0401), (704342, 0x0104), (704343, 0x0204), (704344, 0x0402), (704345, 0x0401), (704346, 0x0401), (704347, 0x0401), (704353, 0x0104),
+    (704354, 0x0104), (704355, 0x0304), (704356, 0x0401), (704357, 0x0401), (704358, 0x0401), (704364, 0x0104), (704365, 0x0104), (704366, 0x0104),
+    (704367, 0x0404), (704368, 0x0401), (704369, 0x0401), (704375, 0x0104), (704376, 0x0104), (704377, 0x0104), (704378, 0x0104), (704379, 0x0304),
+    (704380, 0x0401), (704381, 0x0401), (704386, 0x0104), (704387, 0x0104), (704388, 0x0104), (704389, 0x0104), (704390, 0x0104), (704391, 0x0404),
+    (704392, 0x0401), (704452, 0x0104), (704453, 0x0404), (704454, 0x0401), (704455, 0x0401), (704463, 0x0104), (704464, 0x0404), (704465, 0x0401),
+    (704466, 0x0401), (704474, 0x0104), (704475, 0x0204), (704476, 0x0401), (704477, 0x0401), (704478, 0x0401), (704485, 0x0104), (704486, 0x0104),
+    (704487, 0x0404), (704488, 0x0401), (704489, 0x0401), (704496, 0x0104), (704497, 0x0104), (704498, 0x0204), (704499, 0x0403), (704500, 0x0401),
+    (704507, 0x0104), (704508, 0x0104), (704509, 0x0204), (704584, 0x0104), (704585, 0x0401), (704586, 0x0401), (704587, 0x0401), (704595, 0x0104),
+    (704596, 0x0401), (704597, 0x0401), (704598, 0x0401), (704606, 0x0104), (704607, 0x0403), (704608, 0x0401), (704609, 0x0401), (704617, 0x0104),
+    (704618, 0x0403), (704619, 0x0401), (704620, 0x0401), (704628, 0x0104), (704629, 0x0304), (704630, 0x0401), (704716, 0x0104), (704717, 0x0401),
+    (704718, 0x0401), (704727, 0x0104), (704728, 0x0401), (704729, 0x0401), (704738, 0x0104), (704739, 0x0402), (704740, 0x0401), (704741, 0x0401),
+    (704749, 0x0104), (704750, 0x0402), (704751, 0x0401), (704760, 0x0104), (704761, 0x0402), (704848, 0x0104), (704849, 0x0401), (704850, 0x0401),
+    (704859, 0x0104), (704860, 0x0401), (704861, 0x0401), (704870, 0x0104), (704871, 0x0403), (704872, 0x0401), (704881, 0x0104), (704882, 0x0402),
+    (704883, 0x0401), (705244, 0x0104), (705245, 0x0104), (705246, 0x0404), (705247, 0x0401), (705248, 0x0401), (705249, 0x0401), (705255, 0x0104),
+    (705256, 0x0104), (705257, 0x0404), (705258, 0x0401), (705259, 0x0401), (705260, 0x0401), (705266, 0x0104), (705267, 0x0104), (705268, 0x0304),
+    (705269, 0x0401), (705270, 0x0401), (705271, 0x0401), (705277, 0x0104), (705278, 0x0104), (705279, 0x0104), (705280, 0x0402), (705281, 0x0401),
+    (705282, 0x0401), (705283, 0x0401), (705288, 0x0104), (705289, 0x0104), (705290, 0x0104), (705291, 0x0104), (705292, 0x0401), (705293, 0x0401),
+    (705294, 0x0401), (705299, 0x0104), (705300, 0x0104), (705301, 0x0104), (705302, 0x0104), (705303, 0x0104), (705304, 0x0401), (705305, 0x0401),
+    (705310, 0x0104), (705311, 0x0104), (705312, 0x0104), (705313, 0x0104), (705314, 0x0104), (705315, 0x0104), (705316, 0x0401), (705317, 0x0401),
+    (705321, 0x0104), (705322, 0x0104), (705323, 0x0104), (705324, 0x0104), (705325, 0x0104), (705326, 0x0104), (705327, 0x0104), (705328, 0x0401),
+    (705376, 0x0104), (705377, 0x0104), (705378, 0x0304), (705379, 0x0402), (705380, 0x0401), (705381, 0x0401), (705387, 0x0104), (705388, 0x0104),
+    (705389, 0x0304), (705390, 0x0402), (705391, 0x0401), (705392, 0x0401), (705398, 0x0104), (705399, 0x0104), (705400, 0x0304), (705401, 0x0401),
+    (705402, 0x0401), (705403, 0x0401), (705409, 0x0104), (705410, 0x0104), (705411, 0x0104), (705412, 0x0402), (705413, 0x0401), (705414, 0x0401),
+    (705420, 0x0104), (705421, 0x0104), (705422, 0x0104), (705423, 0x0204), (705424, 0x0401), (705425, 0x0401), (705431, 0x0104), (705432, 0x0104),
+    (705433, 0x0104), (705434, 0x0104), (705435, 0x0304), (705436, 0x0401), (705437, 0x0401), (705442, 0x0104), (705443, 0x0104), (705444, 0x0104),
+    (705445, 0x0104), (705446, 0x0204), (705447, 0x0304), (705448, 0x0401), (705508, 0x0104), (705509, 0x0304), (705510, 0x0402), (705511, 0x0401),
+    (705512, 0x0401), (705519, 0x0104), (705520, 0x0204), (705521, 0x0401), (705522, 0x0401), (705530, 0x0104), (705531, 0x0104), (705532, 0x0402),
+    (705533, 0x0401), (705534, 0x0401), (705541, 0x0104), (705542, 0x0104), (705543, 0x0204), (705544, 0x0401), (705545, 0x0401), (705552, 0x0104),
+    (705553, 0x0104), (705554, 0x0104), (705555, 0x0304), (705556, 0x0401), (705563, 0x0104), (705564, 0x0104), (705565, 0x0104), (705566, 0x0304),
+    (705567, 0x0304), (705640, 0x0104), (705641, 0x0402), (705642, 0x0401), (705643, 0x0401), (705651, 0x0104), (705652, 0x0401), (705653, 0x0401),
+    (705654, 0x0401), (705662, 0x0104), (705663, 0x0304), (705664, 0x0401), (705665, 0x0401), (705673, 0x0104), (705674, 0x0304), (705675, 0x0402),
+    (705676, 0x0401), (705684, 0x0104), (705685, 0x0304), (705686, 0x0403), (705772, 0x0104), (705773, 0x0401), (705774, 0x0401), (705783, 0x0104),
+    (705784, 0x0401), (705785, 0x0401), (705794, 0x0104), (705795, 0x0403), (705796, 0x0401), (705805, 0x0104), (705806, 0x0403), (705807, 0x0401),
+    (705808, 0x0401), (706168, 0x0104), (706169, 0x0204), (706170, 0x0404), (706171, 0x0402), (706172, 0x0401), (706173, 0x0401), (706179, 0x0104),
+    (706180, 0x0104), (706181, 0x0404), (706182, 0x0401), (706183, 0x0401), (706184, 0x0401), (706190, 0x0104), (706191, 0x0104), (706192, 0x0304),
+    (706193, 0x0401), (706194, 0x0401), (706195, 0x0401), (706201, 0x0104), (706202, 0x0104), (706203, 0x0104), (706204, 0x0402), (706205, 0x0401),
+    (706206, 0x0401), (706212, 0x0104), (706213, 0x0104), (706214, 0x0104), (706215, 0x0204), (706216, 0x0401), (706217, 0x0401), (706218, 0x0401),
+    (706223, 0x0104), (706224, 0x0104), (706225, 0x0104), (706226, 0x0104), (706227, 0x0104), (706228, 0x0401), (706229, 0x0401), (706234, 0x0104),
+    (706235, 0x0104), (706236, 0x0104), (706237, 0x0104), (706238, 0x0104), (706239, 0x0104), (706240, 0x0401), (706241, 0x0401), (706245, 0x0104),
+    (706246, 0x0104), (706247, 0x0104), (706248, 0x0104), (706249, 0x0104), (706250, 0x0104), (706251, 0x0104), (706252, 0x0401), (706300, 0x0104),
+    (706301, 0x0104), (706302, 0x0404), (706303, 0x0401), (706304, 0x0401), (706305, 0x0401), (706311, 0x0104), (706312, 0x0104), (706313, 0x0404),
+    (706314, 0x0401), (706315, 0x0401), (706316, 0x0401), (706322, 0x0104), (706323, 0x0104), (706324, 0x0304), (706325, 0x0401), (706326, 0x0401),
+    (706327, 0x0401), (706333, 0x0104), (706334, 0x0104), (706335, 0x0104), (706336, 0x0402), (706337, 0x0401), (706338, 0x0401), (706344, 0x0104),
+    (706345, 0x0104), (706346, 0x0104), (706347, 0x0104), (706348, 0x0401), (706349, 0x0401), (706355, 0x0104), (706356, 0x0104), (706357, 0x0104),
+    (706358, 0x0104), (706359, 0x0204), (706360, 0x0401), (706361, 0x0401), (706366, 0x0104), (706367, 0x0104), (706368, 0x0104), (706369, 0x0104),
+    (706370, 0x0104), (706371, 0x0304), (706372, 0x0401), (706432, 0x0104), (706433, 0x0304), (706434, 0x0401), (706435, 0x0401), (706436, 0x0401),
+    (706443, 0x0104), (706444, 0x0204), (706445, 0x0401), (706446, 0x0401), (706447, 0x0401), (706454, 0x0104), (706455, 0x0104), (706456, 0x0402),
+    (706457, 0x0401), (706458, 0x0401), (706465, 0x0104), (706466, 0x0104), (706467, 0x0204), (706468, 0x0401), (706469, 0x0401), (706476, 0x0104),
+    (706477, 0x0104), (706478, 0x0104), (706479, 0x0404), (706480, 0x0401), (706487, 0x0104), (706488, 0x0104), (706489, 0x0104), (706490, 0x0204),
+    (706491, 0x0404), (706492, 0x0401), (706564, 0x0104), (706565, 0x0401), (706566, 0x0401), (706567, 0x0401), (706575, 0x0104), (706576, 0x0401),
+    (706577, 0x0401), (706578, 0x0401), (706586, 0x0104), (706587, 0x0404), (706588, 0x0401), (706589, 0x0401), (706597, 0x0104), (706598, 0x0304),
+    (706599, 0x0402), (706600, 0x0401), (706608, 0x0104), (706609, 0x0204), (706610, 0x0403), (706611, 0x0401), (706696, 0x0104), (706697, 0x0401),
+    (706698, 0x0401), (706707, 0x0104), (706708, 0x0401), (706709, 0x0401), (706718, 0x0104), (706719, 0x0404), (706720, 0x0401), (706721, 0x0401),
+    (706729, 0x0104), (706730, 0x0404), (706731, 0x0401), (706732, 0x0401), (706740, 0x0104), (706741, 0x0204), (707224, 0x0104), (707225, 0x0104),
+    (707226, 0x0204), (707227, 0x0402), (707228, 0x0401), (707229, 0x0401), (707235, 0x0104), (707236, 0x0104), (707237, 0x0204), (707238, 0x0402),
+    (707239, 0x0401), (707240, 0x0401), (707246, 0x0104), (707247, 0x0104), (707248, 0x0204), (707249, 0x0402), (707250, 0x0401), (707251, 0x0401),
+    (707257, 0x0104), (707258, 0x0104), (707259, 0x0104), (707260, 0x0404), (707261, 0x0401), (707262, 0x0401), (707263, 0x0401), (707268, 0x0104),
+    (707269, 0x0104), (707270, 0x0104), (707271, 0x0104), (707272, 0x0402), (707273, 0x0401), (707274, 0x0401), (707279, 0x0104), (707280, 0x0104),
+    (707281, 0x0104), (707282, 0x0104), (707283, 0x0104), (707284, 0x0401), (707285, 0x0401), (707290, 0x0104), (707291, 0x0104), (707292, 0x0104),
+    (707293, 0x0104), (707294, 0x0104), (707295, 0x0104), (707296, 0x0401), (707297, 0x0401), (707301, 0x0104), (707302, 0x0104), (707303, 0x0104),
+    (707304, 0x0104), (707305, 0x0104), (707306, 0x0104), (707307, 0x0104), (707308, 0x0401), (707312, 0x0104), (707313, 0x0104), (707314, 0x0104),
+    (707315, 0x0104), (707316, 0x0104), (707317, 0x0104), (707318, 0x0104), (707319, 0x0104), (707320, 0x0401), (707356, 0x0104), (707357, 0x0104),
+    (707358, 0x0204), (707359, 0x0402), (707360, 0x0401), (707361, 0x0401), (707367, 0x0104), (707368, 0x0104), (707369, 0x0204), (707370, 0x0402),
+    (707371, 0x0401), (707372, 0x0401), (707378, 0x0104), (707379, 0x0104), (707380, 0x0204), (707381, 0x0402), (707382, 0x0401), (707383, 0x0401),
+    (707389, 0x0104), (707390, 0x0104), (707391, 0x0104), (707392, 0x0403), (707393, 0x0401), (707394, 0x0401), (707400, 0x0104), (707401, 0x0104),
+    (707402, 0x0104), (707403, 0x0104), (707404, 0x0401), (707405, 0x0401), (707411, 0x0104), (707412, 0x0104), (707413, 0x0104), (707414, 0x0104),
+    (707415, 0x0204), (707416, 0x0401), (707417, 0x0401), (707422, 0x0104), (707423, 0x0104), (707424, 0x0104), (707425, 0x0104), (707426, 0x0104),
+    (707427, 0x0304), (707428, 0x0401), (707488, 0x0104), (707489, 0x0304), (707490, 0x0402), (707491, 0x0401), (707492, 0x0401), (707499, 0x0104),
+    (707500, 0x0204), (707501, 0x0402), (707502, 0x0401), (707510, 0x0104), (707511, 0x0104), (707512, 0x0403), (707513, 0x0401), (707514, 0x0401),
+    (707521, 0x0104), (707522, 0x0104), (707523, 0x0204), (707524, 0x0401), (707525, 0x0401), (707532, 0x0104), (707533, 0x0104), (707534, 0x0104),
+    (707535, 0x0304), (707536, 0x0401), (707543, 0x0104), (707544, 0x0104), (707545, 0x0104), (707546, 0x0104), (707547, 0x0404), (707548, 0x0401),
+    (707620, 0x0104), (707621, 0x0402), (707622, 0x0401), (707631, 0x0104), (707632, 0x0403), (707633, 0x0401), (707634, 0x0401), (707642, 0x0104),
+    (707643, 0x0304), (707644, 0x0401), (707645, 0x0401), (707653, 0x0104), (707654, 0x0204), (707655, 0x0403), (707656, 0x0401), (707664, 0x0104),
+    (707665, 0x0104), (707666, 0x0204), (708148, 0x0104), (708149, 0x0104), (708150, 0x0304), (708151, 0x0403), (708152, 0x0401), (708153, 0x0401),
+    (708159, 0x0104), (708160, 0x0104), (708161, 0x0204), (708162, 0x0402), (708163, 0x0401), (708164, 0x0401), (708170, 0x0104), (708171, 0x0104),
+    (708172, 0x0204), (708173, 0x0403), (708174, 0x0401), (708175, 0x0401), (708181, 0x0104), (708182, 0x0104), (708183, 0x0104), (708184, 0x0404),
+    (708185, 0x0401), (708186, 0x0401), (708187, 0x0401), (708192, 0x0104), (708193, 0x0104), (708194, 0x0104), (708195, 0x0104), (708196, 0x0402),
+    (708197, 0x0401), (708198, 0x0401), (708203, 0x0104), (708204, 0x0104), (708205, 0x0104), (708206, 0x0104), (708207, 0x0104), (708208, 0x0401),
+    (708209, 0x0401), (708214, 0x0104), (708215, 0x0104), (708216, 0x0104), (708217, 0x0104), (708218, 0x0104), (708219, 0x0104), (708220, 0x0401),
+    (708221, 0x0401), (708225, 0x0104), (708226, 0x0104), (708227, 0x0104), (708228, 0x0104), (708229, 0x0104), (708230, 0x0104), (708231, 0x0104),
+    (708232, 0x0401), (708236, 0x0104), (708237, 0x0104), (708238, 0x0104), (708239, 0x0104), (708240, 0x0104), (708241, 0x0104), (708242, 0x0104),
+    (708243, 0x0104), (708244, 0x0401), (708280, 0x0104), (708281, 0x0104), (708282, 0x0204), (708283, 0x0402), (708284, 0x0401), (708285, 0x0401),
+    (708291, 0x0104), (708292, 0x0104), (708293, 0x0204), (708294, 0x0402), (708295, 0x0401), (708296, 0x0401), (708302, 0x0104), (708303, 0x0104),
+    (708304, 0x0204), (708305, 0x0402), (708306, 0x0401), (708307, 0x0401), (708313, 0x0104), (708314, 0x0104), (708315, 0x0104), (708316, 0x0403),
+    (708317, 0x0401), (708318, 0x0401), (708324, 0x0104), (708325, 0x0104), (708326, 0x0104), (708327, 0x0104), (708328, 0x0401), (708329, 0x0401),
+    (708330, 0x0401), (708335, 0x0104), (708336, 0x0104), (708337, 0x0104), (708338, 0x0104), (708339, 0x0204), (708340, 0x0401), (708341, 0x0401),
+    (708346, 0x0104), (708347, 0x0104), (708348, 0x0104), (708349, 0x0104), (708350, 0x0104), (708351, 0x0204), (708352, 0x0401), (708357, 0x0104),
+    (708358, 0x0104), (708359, 0x0104), (708360, 0x0104), (708361, 0x0104), (708362, 0x0104), (708363, 0x0104), (708364, 0x0401), (708412, 0x0104),
+    (708413, 0x0204), (708414, 0x0402), (708415, 0x0401), (708416, 0x0401), (708423, 0x0104), (708424, 0x0204), (708425, 0x0402), (708426, 0x0401),
+    (708427, 0x0401), (708434, 0x0104), (708435, 0x0104), (708436, 0x0403), (708437, 0x0401), (708438, 0x0401), (708445, 0x0104), (708446, 0x0104),
+    (708447, 0x0204), (708448, 0x0401), (708449, 0x0401), (708456, 0x0104), (708457, 0x0104), (708458, 0x0104), (708459, 0x0304), (708460, 0x0401),
+    (708461, 0x0401), (708467, 0x0104), (708468, 0x0104), (708469, 0x0104), (708470, 0x0104), (708471, 0x0204), (708472, 0x0401), (708544, 0x0104),
+    (708545, 0x0402), (708546, 0x0401), (708547, 0x0401), (708555, 0x0104), (708556, 0x0403), (708557, 0x0401), (708558, 0x0401), (708566, 0x0104),
+    (708567, 0x0204), (708568, 0x0401), (708569, 0x0401), (708577, 0x0104), (708578, 0x0204), (708579, 0x0403), (708580, 0x0401), (708588, 0x0104),
+    (708589, 0x0104), (708590, 0x0204), (708591, 0x0402), (709204, 0x0104), (709205, 0x0104), (709206, 0x0104), (709207, 0x0404), (709208, 0x0401),
+    (709209, 0x0401), (709215, 0x0104), (709216, 0x0104), (709217, 0x0204), (709218, 0x0404), (709219, 0x0401), (709220, 0x0401), (709226, 0x0104),
+    (709227, 0x0104), (709228, 0x0204), (709229, 0x0404), (709230, 0x0401), (709231, 0x0401), (709237, 0x0104), (709238, 0x0104), (709239, 0x0104),
+    (709240, 0x0304), (709241, 0x0401), (709242, 0x0401), (709243, 0x0401), (709248, 0x0104), (709249, 0x0104), (709250, 0x0104), (709251, 0x0104),
+    (709252, 0x0402), (709253, 0x0401), (709254, 0x0401), (709259, 0x0104), (709260, 0x0104), (709261, 0x0104), (709262, 0x0104), (709263, 0x0104),
+    (709264, 0x0401), (709265, 0x0401), (709270, 0x0104), (709271, 0x0104), (709272, 0x0104), (709273, 0x0104), (709274, 0x0104), (709275, 0x0104),
+    (709276, 0x0401), (709277, 0x0401), (709281, 0x0104), (709282, 0x0104), (709283, 0x0104), (709284, 0x0104), (709285, 0x0104), (709286, 0x0104),
+    (709287, 0x0104), (709288, 0x0401), (709292, 0x0104), (709293, 0x0104), (709294, 0x0104), (709295, 0x0104), (709296, 0x0104), (709297, 0x0104),
+    (709298, 0x0104), (709299, 0x0104), (709300, 0x0401), (709336, 0x0104), (709337, 0x0104), (709338, 0x0104), (709339, 0x0404), (709340, 0x0401),
+    (709341, 0x0401), (709347, 0x0104), (709348, 0x0104), (709349, 0x0204), (709350, 0x0403), (709351, 0x0401), (709352, 0x0401), (709358, 0x0104),
+    (709359, 0x0104), (709360, 0x0104), (709361, 0x0404), (709362, 0x0401), (709363, 0x0401), (709369, 0x0104), (709370, 0x0104), (709371, 0x0104),
+    (709372, 0x0404), (709373, 0x0401), (709374, 0x0401), (709380, 0x0104), (709381, 0x0104), (709382, 0x0104), (709383, 0x0104), (709384, 0x0401),
+    (709385, 0x0401), (709391, 0x0104), (709392, 0x0104), (709393, 0x0104), (709394, 0x0104), (709395, 0x0204), (709396, 0x0401), (709397, 0x0401),
+    (709402, 0x0104), (709403, 0x0104), (709404, 0x0104), (709405, 0x0104), (709406, 0x0104), (709407, 0x0304), (709408, 0x0401), (709413, 0x0104),
+    (709414, 0x0104), (709415, 0x0104), (709416, 0x0104), (709417, 0x0104), (709418, 0x0104), (709468, 0x0104), (709469, 0x0104), (709470, 0x0403),
+    (709471, 0x0401), (709479, 0x0104), (709480, 0x0204), (709481, 0x0403), (709482, 0x0401), (709490, 0x0104), (709491, 0x0104), (709492, 0x0403),
+    (709493, 0x0401), (709494, 0x0401), (709501, 0x0104), (709502, 0x0104), (709503, 0x0204), (709504, 0x0401), (709505, 0x0401), (709512, 0x0104),
+    (709513, 0x0104), (709514, 0x0104), (709515, 0x0404), (709516, 0x0401), (709523, 0x0104), (709524, 0x0104), (709525, 0x0104), (709526, 0x0104),
+    (709527, 0x0204), (710128, 0x0104), (710129, 0x0104), (710130, 0x0204), (710131, 0x0404), (710132, 0x0401), (710133, 0x0401), (710139, 0x0104),
+    (710140, 0x0104), (710141, 0x0204), (710142, 0x0403), (710143, 0x0401), (710144, 0x0401), (710150, 0x0104), (710151, 0x0104), (710152, 0x0204),
+    (710153, 0x0404), (710154, 0x0401), (710155, 0x0401), (710161, 0x0104), (710162, 0x0104), (710163, 0x0104), (710164, 0x0304), (710165, 0x0401),
+    (710166, 0x0401), (710167, 0x0401), (710172, 0x0104), (710173, 0x0104), (710174, 0x0104), (710175, 0x0104), (710176, 0x0402), (710177, 0x0401),
+    (710178, 0x0401), (710183, 0x0104), (710184, 0x0104), (710185, 0x0104), (710186, 0x0104), (710187, 0x0104), (710188, 0x0401), (710189, 0x0401),
+    (710194, 0x0104), (710195, 0x0104), (710196, 0x0104), (710197, 0x0104), (710198, 0x0104), (710199, 0x0104), (710200, 0x0401), (710201, 0x0401),
+    (710205, 0x0104), (710206, 0x0104), (710207, 0x0104), (710208, 0x0104), (710209, 0x0104), (710210, 0x0104), (710211, 0x0104), (710212, 0x0401),
+    (710216, 0x0104), (710217, 0x0104), (710218, 0x0104), (710219, 0x0104), (710220, 0x0104), (710221, 0x0104), (710222, 0x0104), (710223, 0x0104),
+    (710224, 0x0401), (710260, 0x0104), (710261, 0x0104), (710262, 0x0204), (710263, 0x0403), (710264, 0x0401), (710265, 0x0401), (710271, 0x0104),
+    (710272, 0x0104), (710273, 0x0204), (710274, 0x0404), (710275, 0x0401), (710276, 0x0401), (710282, 0x0104), (710283, 0x0104), (710284, 0x0104),
+    (710285, 0x0403), (710286, 0x0401), (710287, 0x0401), (710293, 0x0104), (710294, 0x0104), (710295, 0x0104), (710296, 0x0404), (710297, 0x0401),
+    (710298, 0x0401), (710304, 0x0104), (710305, 0x0104), (710306, 0x0104), (710307, 0x0104), (710308, 0x0402), (710309, 0x0401), (710310, 0x0401),
+    (710315, 0x0104), (710316, 0x0104), (710317, 0x0104), (710318, 0x0104), (710319, 0x0204), (710320, 0x0401), (710321, 0x0401), (710326, 0x0104),
+    (710327, 0x0104), (710328, 0x0104), (710329, 0x0104), (710330, 0x0104), (710331, 0x0204), (710332, 0x0401), (710337, 0x0104), (710338, 0x0104),
+    (710339, 0x0104), (710340, 0x0104), (710341, 0x0104), (710342, 0x0104), (710343, 0x0104), (710344, 0x0401), (710392, 0x0104), (710393, 0x0204),
+    (710394, 0x0403), (710395, 0x0401), (710403, 0x0104), (710404, 0x0204), (710405, 0x0403), (710406, 0x0401), (710414, 0x0104), (710415, 0x0104),
+    (710416, 0x0404), (710417, 0x0401), (710418, 0x0401), (710425, 0x0104), (710426, 0x0104), (710427, 0x0204), (710428, 0x0402), (710429, 0x0401),
+    (710436, 0x0104), (710437, 0x0104), (710438, 0x0104), (710439, 0x0204), (710440, 0x0401), (710441, 0x0401), (710447, 0x0104), (710448, 0x0104),
+    (710449, 0x0104), (710450, 0x0104), (710451, 0x0104), (710452, 0x0401), (711184, 0x0104), (711185, 0x0104), (711186, 0x0104), (711187, 0x0304),
+    (711188, 0x0402), (711189, 0x0401), (711195, 0x0104), (711196, 0x0104), (711197, 0x0104), (711198, 0x0304), (711199, 0x0402), (711200, 0x0401),
+    (711206, 0x0104), (711207, 0x0104), (711208, 0x0104), (711209, 0x0304), (711210, 0x0402), (711211, 0x0401), (711217, 0x0104), (711218, 0x0104),
+    (711219, 0x0104), (711220, 0x0304), (711221, 0x0402), (711222, 0x0401), (711223, 0x0401), (711228, 0x0104), (711229, 0x0104), (711230, 0x0104),
+    (711231, 0x0104), (711232, 0x0402), (711233, 0x0401), (711234, 0x0401), (711239, 0x0104), (711240, 0x0104), (711241, 0x0104), (711242, 0x0104),
+    (711243, 0x0104), (711244, 0x0401), (711245, 0x0401), (711250, 0x0104), (711251, 0x0104), (711252, 0x0104), (711253, 0x0104), (711254, 0x0104),
+    (711255, 0x0104), (711256, 0x0401), (711257, 0x0401), (711261, 0x0104), (711262, 0x0104), (711263, 0x0104), (711264, 0x0104), (711265, 0x0104),
+    (711266, 0x0104), (711267, 0x0204), (711268, 0x0401), (711272, 0x0104), (711273, 0x0104), (711274, 0x0104), (711275, 0x0104), (711276, 0x0104),
+    (711277, 0x0104), (711278, 0x0104), (711279, 0x0104), (711280, 0x0401), (711316, 0x0104), (711317, 0x0104), (711318, 0x0104), (711319, 0x0304),
+    (711320, 0x0401), (711327, 0x0104), (711328, 0x0104), (711329, 0x0104), (711330, 0x0404), (711331, 0x0402), (711338, 0x0104), (711339, 0x0104),
+    (711340, 0x0104), (711341, 0x0304), (711342, 0x0401), (711349, 0x0104), (711350, 0x0104), (711351, 0x0104), (711352, 0x0204), (711353, 0x0401),
+    (711354, 0x0401), (711360, 0x0104), (711361, 0x0104), (711362, 0x0104), (711363, 0x0104), (711364, 0x0402), (711365, 0x0401), (711371, 0x0104),
+    (711372, 0x0104), (711373, 0x0104), (711374, 0x0104), (711375, 0x0204), (711376, 0x0401), (711377, 0x0401), (711382, 0x0104), (711383, 0x0104),
+    (711384, 0x0104), (711385, 0x0104), (711386, 0x0104), (711387, 0x0204), (711388, 0x0401), (712108, 0x0104), (712109, 0x0104), (712110, 0x0104),
+    (712111, 0x0304), (712112, 0x0401), (712113, 0x0401), (712119, 0x0104), (712120, 0x0104), (712121, 0x0104), (712122, 0x0304), (712123, 0x0402),
+    (712124, 0x0401), (712130, 0x0104), (712131, 0x0104), (712132, 0x0104), (712133, 0x0304), (712134, 0x0402), (712135, 0x0401), (712141, 0x0104),
+    (712142, 0x0104), (712143, 0x0104), (712144, 0x0304), (712145, 0x0401), (712146, 0x0401), (712152, 0x0104), (712153, 0x0104), (712154, 0x0104),
+    (712155, 0x0104), (712156, 0x0402), (712157, 0x0401), (712158, 0x0401), (712163, 0x0104), (712164, 0x0104), (712165, 0x0104), (712166, 0x0104),
+    (712167, 0x0104), (712168, 0x0401), (712169, 0x0401), (712174, 0x0104), (712175, 0x0104), (712176, 0x0104), (712177, 0x0104), (712178, 0x0104),
+    (712179, 0x0104), (712180, 0x0401), (712181, 0x0401), (712185, 0x0104), (712186, 0x0104), (712187, 0x0104), (712188, 0x0104), (712189, 0x0104),
+    (712190, 0x0104), (712191, 0x0104), (712192, 0x0401), (712196, 0x0104), (712197, 0x0104), (712198, 0x0104), (712199, 0x0104), (712200, 0x0104),
+    (712201, 0x0104), (712202, 0x0104), (712203, 0x0104), (712204, 0x0401), (712240, 0x0104), (712241, 0x0104), (712242, 0x0104), (712243, 0x0304),
+    (712244, 0x0402), (712251, 0x0104), (712252, 0x0104), (712253, 0x0104), (712254, 0x0304), (712255, 0x0401), (712262, 0x0104), (712263, 0x0104),
+    (712264, 0x0104), (712265, 0x0404), (712266, 0x0401), (712267, 0x0401), (712273, 0x0104), (712274, 0x0104), (712275, 0x0104), (712276, 0x0204),
+    (712277, 0x0401), (712278, 0x0401), (712284, 0x0104), (712285, 0x0104), (712286, 0x0104), (712287, 0x0104), (712288, 0x0402), (712289, 0x0401),
+    (712295, 0x0104), (712296, 0x0104), (712297, 0x0104), (712298, 0x0104), (712299, 0x0104), (712300, 0x0401), (712301, 0x0401), (712306, 0x0104),
+    (712307, 0x0104), (712308, 0x0104), (712309, 0x0104), (712310, 0x0104), (712311, 0x0104), (712312, 0x0401), (712317, 0x0104), (712318, 0x0104),
+    (712319, 0x0104), (712320, 0x0104), (712321, 0x0104), (712322, 0x0104), (712323, 0x0104), (712324, 0x0401), (713164, 0x0104), (713165, 0x0104),
+    (713166, 0x0104), (713167, 0x0304), (713168, 0x0402), (713175, 0x0104), (713176, 0x0104), (713177, 0x0104), (713178, 0x0304), (713179, 0x0402),
+    (713186, 0x0104), (713187, 0x0104), (713188, 0x0104), (713189, 0x0204), (713190, 0x0402), (713191, 0x0401), (713197, 0x0104), (713198, 0x0104),
+    (713199, 0x0104), (713200, 0x0204), (713201, 0x0402), (713202, 0x0401), (713208, 0x0104), (713209, 0x0104), (713210, 0x0104), (713211, 0x0104),
+    (713212, 0x0403), (713213, 0x0401), (713219, 0x0104), (713220, 0x0104), (713221, 0x0104), (713222, 0x0104), (713223, 0x0104), (713224, 0x0401),
+    (713225, 0x0401), (713230, 0x0104), (713231, 0x0104), (713232, 0x0104), (713233, 0x0104), (713234, 0x0104), (713235, 0x0104), (713236, 0x0401),
+    (713237, 0x0401), (713241, 0x0104), (713242, 0x0104), (713243, 0x0104), (713244, 0x0104), (713245, 0x0104), (713246, 0x0104), (713247, 0x0104),
+    (713248, 0x0401), (714088, 0x0104), (714089, 0x0104), (714090, 0x0104), (714091, 0x0204), (714092, 0x0402), (714099, 0x0104), (714100, 0x0104),
+    (714101, 0x0104), (714102, 0x0304), (714103, 0x0402), (714110, 0x0104), (714111, 0x0104), (714112, 0x0104), (714113, 0x0204), (714114, 0x0403),
+    (714115, 0x0401), (714121, 0x0104), (714122, 0x0104), (714123, 0x0104), (714124, 0x0204), (714125, 0x0402), (714126, 0x0401), (714132, 0x0104),
+    (714133, 0x0104), (714134, 0x0104), (714135, 0x0104), (714136, 0x0403), (714137, 0x0401), (714143, 0x0104), (714144, 0x0104), (714145, 0x0104),
+    (714146, 0x0104), (714147, 0x0104), (714148, 0x0401), (714149, 0x0401), (714154, 0x0104), (714155, 0x0104), (714156, 0x0104), (714157, 0x0104),
+    (714158, 0x0104), (714159, 0x0104), (714160, 0x0401), (714161, 0x0401), (714165, 0x0104), (714166, 0x0104), (714167, 0x0104), (714168, 0x0104),
+    (714169, 0x0104), (714170, 0x0104), (714171, 0x0104), (714172, 0x0401), (714176, 0x0104), (714177, 0x0104), (714178, 0x0104), (714179, 0x0104),
+    (714180, 0x0104), (714181, 0x0104), (714182, 0x0104), (714183, 0x0104), (714184, 0x0401), (719104, 0x0404), (719105, 0x0404), (719106, 0x0304),
+    (719107, 0x0404), (719117, 0x0304), (719118, 0x0304), (719119, 0x0204), (719120, 0x0204), (719121, 0x0104), (719122, 0x0104), (719123, 0x0104),
+    (719124, 0x0104), (719125, 0x0104), (719126, 0x0104), (719129, 0x0404), (719130, 0x0403), (719131, 0x0402), (719132, 0x0402), (719133, 0x0402),
+    (719134, 0x0401), (719135, 0x0401), (719136, 0x0401), (719137, 0x0401), (719138, 0x0401), (719152, 0x0404), (719153, 0x0404), (719154, 0x0404),
+    (719155, 0x0404), (719165, 0x0204), (719166, 0x0104), (719167, 0x0104), (719168, 0x0104), (719169, 0x0104), (719170, 0x0104), (719171, 0x0104),
+    (719172, 0x0104), (719177, 0x0401), (719178, 0x0401), (719179, 0x0401), (719180, 0x0401), (719181, 0x0401), (719182, 0x0401), (719183, 0x0401),
+    (719184, 0x0401), (719200, 0x0404), (719201, 0x0402), (719202, 0x0401), (719203, 0x0401), (719204, 0x0402), (719211, 0x0304), (719212, 0x0402),
+    (719213, 0x0401), (719214, 0x0401), (719222, 0x0204), (719223, 0x0403), (719224, 0x0401), (719225, 0x0401), (719233, 0x0204), (719234, 0x0401),
+    (719235, 0x0401), (719387, 0x0204), (719388, 0x0404), (719389, 0x0401), (719390, 0x0401), (719391, 0x0401), (719398, 0x0204), (719399, 0x0404),
+    (719400, 0x0401), (719401, 0x0401), (719402, 0x0401), (719409, 0x0204), (719410, 0x0404), (719411, 0x0401), (719412, 0x0401), (719413, 0x0401),
+    (719420, 0x0104), (719421, 0x0404), (719422, 0x0401), (719423, 0x0401), (719424, 0x0401), (719431, 0x0204), (719432, 0x0402), (719574, 0x0104),
+    (719575, 0x0304), (719576, 0x0403), (719577, 0x0401), (719578, 0x0401), (719579, 0x0401), (719585, 0x0204), (719586, 0x0304), (719587, 0x0402),
+    (719588, 0x0401), (719589, 0x0401), (719596, 0x0104), (719597, 0x0304), (719598, 0x0402), (719599, 0x0401), (719600, 0x0401), (719607, 0x0104),
+    (719608, 0x0304), (719609, 0x0403), (719610, 0x0401), (719611, 0x0401), (719618, 0x0104), (719619, 0x0204), (719620, 0x0304), (719621, 0x0402),
+    (719622, 0x0401), (719761, 0x0104), (719762, 0x0104), (719763, 0x0304), (719764, 0x0402), (719765, 0x0401), (719766, 0x0401), (719772, 0x0104),
+    (719773, 0x0104), (719774, 0x0304), (719775, 0x0402), (719776, 0x0401), (719777, 0x0401), (719783, 0x0104), (719784, 0x0104), (719785, 0x0304),
+    (719786, 0x0402), (719787, 0x0401), (719788, 0x0401), (719794, 0x0104), (719795, 0x0104), (719796, 0x0204), (719797, 0x0402), (719798, 0x0401),
+    (719799, 0x0401), (719805, 0x0104), (719806, 0x0104), (719807, 0x0204), (719808, 0x0403), (719809, 0x0401), (719810, 0x0401), (719816, 0x0104),
+    (719817, 0x0104), (719818, 0x0104), (719819, 0x0204), (719820, 0x0304), (719821, 0x0401), (719948, 0x0104), (719949, 0x0104), (719950, 0x0104),
+    (719951, 0x0404), (719952, 0x0401), (719953, 0x0401), (719954, 0x0401), (719959, 0x0104), (719960, 0x0104), (719961, 0x0104), (719962, 0x0404),
+    (719963, 0x0401), (719964, 0x0401), (719970, 0x0104), (719971, 0x0104), (719972, 0x0104), (719973, 0x0404), (719974, 0x0401), (719975, 0x0401),
+    (719976, 0x0401), (719981, 0x0104), (719982, 0x0104), (719983, 0x0104), (719984, 0x0404), (719985, 0x0401), (719986, 0x0401), (719987, 0x0401),
+    (719992, 0x0104), (719993, 0x0104), (719994, 0x0104), (719995, 0x0304), (719996, 0x0401), (719997, 0x0401), (719998, 0x0401), (720003, 0x0104),
+    (720004, 0x0104), (720005, 0x0104), (720006, 0x0104), (720007, 0x0404), (720008, 0x0401), (720009, 0x0401), (720014, 0x0104), (720015, 0x0104),
+    (720016, 0x0104), (720017, 0x0104), (720018, 0x0104), (720019, 0x0204), (720020, 0x0401), (720135, 0x0104), (720136, 0x0104), (720137, 0x0104),
+    (720138, 0x0204), (720139, 0x0403), (720140, 0x0401), (720141, 0x0401), (720142, 0x0402), (720146, 0x0104), (720147, 0x0104), (720148, 0x0104),
+    (720149, 0x0204), (720150, 0x0403), (720151, 0x0401), (720152, 0x0401), (720157, 0x0104), (720158, 0x0104), (720159, 0x0104), (720160, 0x0204),
+    (720161, 0x0403), (720162, 0x0401), (720163, 0x0401), (720168, 0x0104), (720169, 0x0104), (720170, 0x0104), (720171, 0x0204), (720172, 0x0403),
+    (720173, 0x0401), (720174, 0x0401), (720175, 0x0401), (720179, 0x0104), (720180, 0x0104), (720181, 0x0104), (720182, 0x0204), (720183, 0x0403),
+    (720184, 0x0401), (720185, 0x0401), (720186, 0x0401), (720190, 0x0104), (720191, 0x0104), (720192, 0x0104), (720193, 0x0104), (720194, 0x0304),
+    (720195, 0x0401), (720196, 0x0401), (720197, 0x0401), (720201, 0x0104), (720202, 0x0104), (720203, 0x0104), (720204, 0x0104), (720205, 0x0104),
+    (720206, 0x0404), (720207, 0x0401), (720208, 0x0401), (720212, 0x0104), (720213, 0x0104), (720214, 0x0104), (720215, 0x0104), (720216, 0x0104),
+    (720217, 0x0104), (720218, 0x0204), (720219, 0x0401), (720322, 0x0104), (720323, 0x0104), (720324, 0x0104), (720325, 0x0104), (720326, 0x0204),
+    (720327, 0x0404), (720328, 0x0402), (720329, 0x0401), (720333, 0x0104), (720334, 0x0104), (720335, 0x0104), (720336, 0x0104), (720337, 0x0304),
+    (720338, 0x0402), (720339, 0x0401), (720340, 0x0401), (720344, 0x0104), (720345, 0x0104), (720346, 0x0104), (720347, 0x0104), (720348, 0x0304),
+    (720349, 0x0402), (720350, 0x0401), (720351, 0x0401), (720355, 0x0104), (720356, 0x0104), (720357, 0x0104), (720358, 0x0104), (720359, 0x0304),
+    (720360, 0x0401), (720361, 0x0401), (720362, 0x0401), (720366, 0x0104), (720367, 0x0104), (720368, 0x0104), (720369, 0x0104), (720370, 0x0304),
+    (720371, 0x0402), (720372, 0x0401), (720373, 0x0401), (720374, 0x0401), (720377, 0x0104), (720378, 0x0104), (720379, 0x0104), (720380, 0x0104),
+    (720381, 0x0204), (720382, 0x0402), (720383, 0x0401), (720384, 0x0401), (720385, 0x0401), (720388, 0x0104), (720389, 0x0104), (720390, 0x0104),
+    (720391, 0x0104), (720392, 0x0104), (720393, 0x0304), (720394, 0x0401), (720395, 0x0401), (720396, 0x0401), (720399, 0x0104), (720400, 0x0104),
+    (720401, 0x0104), (720402, 0x0104), (720403, 0x0104), (720404, 0x0104), (720405, 0x0204), (720406, 0x0401), (720407, 0x0401), (720410, 0x0104),
+    (720411, 0x0104), (720412, 0x0104), (720413, 0x0104), (720414, 0x0104), (720415, 0x0104), (720416, 0x0104), (720417, 0x0104), (720418, 0x0401),
+    (720509, 0x0104), (720510, 0x0104), (720511, 0x0104), (720512, 0x0104), (720513, 0x0104), (720514, 0x0204), (720515, 0x0404), (720516, 0x0402),
+    (720520, 0x0104), (720521, 0x0104), (720522, 0x0104), (720523, 0x0104), (720524, 0x0104), (720525, 0x0204), (720526, 0x0403), (720527, 0x0401),
+    (720531, 0x0104), (720532, 0x0104), (720533, 0x0104), (720534, 0x0104), (720535, 0x0204), (720536, 0x0304), (720537, 0x0403), (720538, 0x0401),
+    (720539, 0x0401), (720542, 0x0104), (720543, 0x0104), (720544, 0x0104), (720545, 0x0104), (720546, 0x0104), (720547, 0x0304), (720548, 0x0402),
+    (720549, 0x0401), (720550, 0x0401), (720553, 0x0104), (720554, 0x0104), (720555, 0x0104), (720556, 0x0104), (720557, 0x0104), (720558, 0x0404),
+    (720559, 0x0402), (720560, 0x0401), (720561, 0x0401), (720564, 0x0104), (720565, 0x0104), (720566, 0x0104), (720567, 0x0104), (720568, 0x0104),
+    (720569, 0x0304), (720570, 0x0402), (720571, 0x0401), (720572, 0x0401), (720575, 0x0104), (720576, 0x0104), (720577, 0x0104), (720578, 0x0104),
+    (720579, 0x0104), (720580, 0x0204), (720581, 0x0402), (720582, 0x0401), (720583, 0x0401), (720586, 0x0104), (720587, 0x0104), (720588, 0x0104),
+    (720589, 0x0104), (720590, 0x0104), (720591, 0x0104), (720592, 0x0204), (720593, 0x0401), (720594, 0x0401), (720595, 0x0401), (720597, 0x0104),
+    (720598, 0x0104), (720599, 0x0104), (720600, 0x0104), (720601, 0x0104), (720602, 0x0104), (720603, 0x0104), (720604, 0x0204), (720605, 0x0401),
+    (720606, 0x0401), (720608, 0x0104), (720609, 0x0104), (720610, 0x0104), (720611, 0x0104), (720612, 0x0104), (720613, 0x0104), (720614, 0x0104),
+    (720615, 0x0104), (720616, 0x0104), (720617, 0x0401), (720696, 0x0104), (720697, 0x0104), (720698, 0x0104), (720699, 0x0104), (720700, 0x0104),
+    (720707, 0x0104), (720708, 0x0104), (720709, 0x0104), (720710, 0x0104), (720711, 0x0104), (720712, 0x0204), (720713, 0x0304), (720718, 0x0104),
+    (720719, 0x0104), (720720, 0x0104), (720721, 0x0104), (720722, 0x0104), (720723, 0x0104), (720724, 0x0304), (720725, 0x0401), (720729, 0x0104),
+    (720730, 0x0104), (720731, 0x0104), (720732, 0x0104), (720733, 0x0104), (720734, 0x0104), (720735, 0x0304), (720736, 0x0401), (720737, 0x0401),
+    (720740, 0x0104), (720741, 0x0104), (720742, 0x0104), (720743, 0x0104), (720744, 0x0104), (720745, 0x0204), (720746, 0x0304), (720747, 0x0401),
+    (720748, 0x0401), (720751, 0x0104), (720752, 0x0104), (720753, 0x0104), (720754, 0x0104), (720755, 0x0104), (720756, 0x0104), (720757, 0x0404),
+    (720758, 0x0401), (720759, 0x0401), (720762, 0x0104), (720763, 0x0104), (720764, 0x0104), (720765, 0x0104), (720766, 0x0104), (720767, 0x0104),
+    (720768, 0x0404), (720769, 0x0401), (720770, 0x0401), (720773, 0x0104), (720774, 0x0104), (720775, 0x0104), (720776, 0x0104), (720777, 0x0104),
+    (720778, 0x0104), (720779, 0x0204), (720780, 0x0401), (720781, 0x0401), (720784, 0x0104), (720785, 0x0104), (720786, 0x0104), (720787, 0x0104),
+    (720788, 0x0104), (720789, 0x0104), (720790, 0x0104), (720791, 0x0304), (720792, 0x0401), (720793, 0x0401), (720795, 0x0104), (720796, 0x0104),
+    (720797, 0x0104), (720798, 0x0104), (720799, 0x0104), (720800, 0x0104), (720801, 0x0104), (720802, 0x0104), (720803, 0x0204), (720804, 0x0401),
+    (721444, 0x0401), (721445, 0x0401), (721446, 0x0401), (721454, 0x0402), (721455, 0x0401), (721456, 0x0401), (721464, 0x0402), (721465, 0x0402),
+    (721466, 0x0401), (721467, 0x0401), (721474, 0x0403), (721475, 0x0403), (721476, 0x0401), (721477, 0x0401), (721484, 0x0404), (721485, 0x0403),
+    (721486, 0x0402), (721487, 0x0401), (721488, 0x0401), (721494, 0x0404), (721495, 0x0403), (721496, 0x0403), (721497, 0x0402), (721498, 0x0401),
+    (721499, 0x0401), (721504, 0x0404), (721505, 0x0404), (721506, 0x0403), (721507, 0x0403), (721508, 0x0402), (721509, 0x0401), (721510, 0x0401),
+    (721514, 0x0404), (721515, 0x0404), (721516, 0x0404), (721517, 0x0403), (721518, 0x0403), (721519, 0x0402), (721520, 0x0402), (721521, 0x0401),
+    (721524, 0x0404), (721525, 0x0404), (721526, 0x0404), (721527, 0x0404), (721528, 0x0403), (721529, 0x0403), (721530, 0x0402), (721531, 0x0402),
+];
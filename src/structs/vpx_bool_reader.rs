@@ -22,26 +22,64 @@ Neither the name of Google nor the names of its contributors may be used to endo
 THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
-use std::io::{Read, Result};
+use std::io::Read;
 
+use anyhow::Result;
+
+use crate::helpers::err_exit_code;
+use crate::lepton_error::ExitCode;
 use crate::metrics::{Metrics, ModelComponent};
 
 #[cfg(feature = "compression_stats")]
 use crate::metrics::ModelStatsCollector;
 
 use super::{branch::Branch, simple_hash::SimpleHash};
+#[cfg(feature = "experimental-tuning")]
+use crate::enabled_features::ModelTuning;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::SymmetryLog;
+
+/// the register that the decisions are decoded from, which holds the byte that the range is compared with and as
+/// many of the bytes after it as fit. A smaller register is refilled more often, but decodes exactly the same, so
+/// targets where 64-bit operations are emulated use one of their native size, as do builds with the coder32
+/// feature.
+#[cfg(not(any(target_pointer_width = "32", feature = "coder32")))]
+type BoolValue = u64;
+#[cfg(any(target_pointer_width = "32", feature = "coder32"))]
+type BoolValue = u32;
 
 const BITS_IN_BYTE: i32 = 8;
-const BITS_IN_LONG: i32 = 64;
-const BITS_IN_LONG_MINUS_LAST_BYTE: i32 = BITS_IN_LONG - BITS_IN_BYTE;
+const BITS_IN_VALUE: i32 = BoolValue::BITS as i32;
+const BITS_IN_VALUE_MINUS_LAST_BYTE: i32 = BITS_IN_VALUE - BITS_IN_BYTE;
+
+/// how many bits past the end of the data the decoder may get before it fails with UnexpectedEndOfSegment. Past
+/// the end the data is padded with zeros, which the decisions at the end of a stream are decoded with since the
+/// split is compared with the bits after the position too. That is the only grace that a stream needs: the flush of
+/// VPXBoolWriter::finish keeps the position itself before the end, even without the last byte of the flush (and the
+/// zero that may come after it), so a truncated stream fails right away instead of decoding the padding as
+/// decisions.
+const MAX_PADDING_BITS: i32 = 0;
 
 pub struct VPXBoolReader<R> {
-    value: u64,
+    value: BoolValue,
     range: u32,
     count: i32,
+    /// the bytes that were read from upstream_reader into value
+    bytes_read: u64,
+    /// the last two bytes that were read, which verify_end checks the end of the flush with
+    last_bytes: u16,
     upstream_reader: R,
     model_statistics: Metrics,
     pub hash: SimpleHash,
+    /// how branches are updated if it isn't the regular way
+    #[cfg(feature = "experimental-tuning")]
+    tuning: Option<ModelTuning>,
+    /// where the decisions are logged, see SymmetryLog
+    #[cfg(feature = "debug-symmetry")]
+    symmetry_log: Option<SymmetryLog>,
+    /// the component that the decisions are counted for in the CoderStats
+    #[cfg(feature = "stats")]
+    stats_component: usize,
 }
 
 impl<R: Read> VPXBoolReader<R> {
@@ -50,12 +88,26 @@ impl<R: Read> VPXBoolReader<R> {
             upstream_reader: reader,
             value: 0,
             count: -8,
+            bytes_read: 0,
+            last_bytes: 0,
             range: 255,
             model_statistics: Metrics::default(),
             hash: SimpleHash::new(),
+            #[cfg(feature = "experimental-tuning")]
+            tuning: None,
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_log: None,
+            #[cfg(feature = "stats")]
+            stats_component: 0,
         };
 
-        Self::vpx_reader_fill(&mut r.value, &mut r.count, &mut r.upstream_reader)?;
+        Self::vpx_reader_fill(
+            &mut r.value,
+            &mut r.count,
+            &mut r.bytes_read,
+            &mut r.last_bytes,
+            &mut r.upstream_reader,
+        )?;
 
         let mut dummy_branch = Branch::new();
         r.get(&mut dummy_branch, ModelComponent::Dummy)?; // marker bit
@@ -63,10 +115,123 @@ impl<R: Read> VPXBoolReader<R> {
         return Ok(r);
     }
 
+    /// the position of the next bit to be decoded in bits from where the reader started. The decisions that are
+    /// decoded from a byte depend on the bits after it, so this is where the decoding has got to rather than the
+    /// exact bit that a decision came from.
+    pub fn position_bits(&self) -> u64 {
+        (self.bytes_read as i64 * 8 - i64::from(self.count) - 8).max(0) as u64
+    }
+
     pub fn drain_stats(&mut self) -> Metrics {
         self.model_statistics.drain()
     }
 
+    /// checks that after the last decision the data ends the way VPXBoolWriter::finish ends it, failing with
+    /// TrailingGarbageInSegment if there is more to it, or with UnexpectedEndOfSegment if there is less. The
+    /// decoder doesn't need the flush, so without this a corrupted or overlong end of a segment goes unnoticed.
+    pub fn verify_end(mut self) -> Result<()> {
+        // the flush is 32 falses with the probability of a new branch, which narrow the range of the decoder
+        // the same way as that of the encoder
+        let probability = u32::from(Branch::new().get_probability());
+        let mut range = self.range;
+        let mut position = self.position_bits();
+        for _i in 0..32 {
+            let split = 1 + (((range - 1) * probability) >> BITS_IN_BYTE);
+            let shift = (split as u8).leading_zeros();
+            range = split << shift;
+            position += u64::from(shift);
+        }
+
+        // the encoder keeps 24 bits of its low value back and writes out a byte for each 8 bits past them, which
+        // finish leaves as they are except for a zero after a last byte that looks like a marker
+        let coded_bytes = (position - 16) / 8;
+
+        let mut v = [0u8; 1];
+        while self.bytes_read <= coded_bytes + 1 && self.upstream_reader.read(&mut v)? == 1 {
+            self.bytes_read += 1;
+            self.last_bytes = (self.last_bytes << 8) | u16::from(v[0]);
+        }
+
+        let [before_last, last] = self.last_bytes.to_be_bytes();
+        let zero_after_marker = (before_last & 0xe0) == 0xc0 && last == 0;
+
+        if self.bytes_read > coded_bytes + 1
+            || (self.bytes_read == coded_bytes + 1 && !zero_after_marker)
+        {
+            return err_exit_code(
+                ExitCode::TrailingGarbageInSegment,
+                &format!(
+                    "the segment goes on after the {0} bytes of its coded data",
+                    coded_bytes
+                ),
+            );
+        }
+
+        if self.bytes_read < coded_bytes || (last & 0xe0) == 0xc0 {
+            return err_exit_code(
+                ExitCode::UnexpectedEndOfSegment,
+                &format!(
+                    "the segment ended after {0} bytes before the end of its flush",
+                    self.bytes_read
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// counts the decisions from now on for component in the CoderStats
+    #[cfg(feature = "stats")]
+    pub fn set_stats_component(&mut self, component: usize) {
+        self.stats_component = component;
+    }
+
+    /// logs the decisions from now on in log, see SymmetryLog
+    #[cfg(feature = "debug-symmetry")]
+    pub fn set_symmetry_log(&mut self, log: Option<SymmetryLog>) {
+        self.symmetry_log = log;
+    }
+
+    /// the decisions from now on belong to the block at dpos of component
+    #[cfg(feature = "debug-symmetry")]
+    #[inline(always)]
+    pub fn set_symmetry_block(&mut self, component: usize, dpos: i32) {
+        if let Some(log) = &mut self.symmetry_log {
+            log.set_block(component, dpos);
+        }
+    }
+
+    /// the decisions from now on belong to the coefficient, see SymmetryLog::set_coefficient
+    #[cfg(feature = "debug-symmetry")]
+    #[inline(always)]
+    pub fn set_symmetry_coefficient(&mut self, coefficient: u8) {
+        if let Some(log) = &mut self.symmetry_log {
+            log.set_coefficient(coefficient);
+        }
+    }
+
+    /// updates the branches with the values of tuning from now on
+    #[cfg(feature = "experimental-tuning")]
+    pub fn set_tuning(&mut self, tuning: &ModelTuning) {
+        self.tuning = if *tuning == ModelTuning::default() {
+            None
+        } else {
+            Some(*tuning)
+        };
+    }
+
+    /// updates the branch with the bit that was coded
+    #[inline(always)]
+    fn record_obs(&self, branch: &mut Branch, bit: bool) {
+        #[cfg(feature = "experimental-tuning")]
+        if let Some(tuning) = &self.tuning {
+            branch.record_and_update_tuned_obs(bit, tuning);
+            return;
+        }
+
+        branch.record_and_update_obs(bit);
+    }
+
     #[inline(never)]
     pub fn get_grid<const A: usize, const B: usize>(
         &mut self,
@@ -139,39 +304,45 @@ impl<R: Read> VPXBoolReader<R> {
         let mut tmp_count = self.count;
 
         if tmp_count < 0 {
-            Self::vpx_reader_fill(&mut tmp_value, &mut tmp_count, &mut self.upstream_reader)?;
+            Self::vpx_reader_fill(
+                &mut tmp_value,
+                &mut tmp_count,
+                &mut self.bytes_read,
+                &mut self.last_bytes,
+                &mut self.upstream_reader,
+            )?;
         }
 
         let probability = branch.get_probability() as u32;
 
         let split = 1 + (((tmp_range - 1) * probability) >> BITS_IN_BYTE);
-        let big_split = (split as u64) << BITS_IN_LONG_MINUS_LAST_BYTE;
+        let big_split = (split as BoolValue) << BITS_IN_VALUE_MINUS_LAST_BYTE;
         let bit = tmp_value >= big_split;
 
-        let shift;
-        if bit {
-            branch.record_and_update_true_obs();
-            tmp_range -= split;
-            tmp_value -= big_split;
+        #[cfg(feature = "debug-symmetry")]
+        if let Some(log) = &mut self.symmetry_log {
+            log.record(_cmp, branch.get_counts(), bit);
+        }
 
-            // so optimizer understands that 0 should never happen and uses a cold jump
-            // if we don't have LZCNT on x86 CPUs (older BSR instruction requires check for zero).
-            // This is better since the branch prediction figures quickly this never happens and can run
-            // the code sequentially.
-            #[cfg(all(
-                not(target_feature = "lzcnt"),
-                any(target_arch = "x86", target_arch = "x86_64")
-            ))]
-            assert!(tmp_range > 0);
+        // the part of the range that the bit leaves, selected without a branch since the bits are hard to predict
+        let mask = BoolValue::from(bit).wrapping_neg();
+        tmp_range = split ^ ((tmp_range.wrapping_sub(split) ^ split) & mask as u32);
+        tmp_value -= big_split & mask;
 
-            shift = tmp_range.leading_zeros() as i32 - 24;
-        } else {
-            branch.record_and_update_false_obs();
-            tmp_range = split;
+        self.record_obs(branch, bit);
 
-            // optimizer understands that split > 0
-            shift = split.leading_zeros() as i32 - 24;
-        }
+        // so optimizer understands that 0 should never happen and uses a cold jump
+        // if we don't have LZCNT on x86 CPUs (older BSR instruction requires check for zero).
+        // This is better since the branch prediction figures quickly this never happens and can run
+        // the code sequentially.
+        #[cfg(all(
+            not(target_feature = "lzcnt"),
+            any(target_arch = "x86", target_arch = "x86_64")
+        ))]
+        assert!(tmp_range > 0);
+
+        // renormalize so that the range is at least 128 again
+        let shift = tmp_range.leading_zeros() as i32 - 24;
 
         self.value = tmp_value << shift;
         self.range = tmp_range << shift;
@@ -183,6 +354,13 @@ impl<R: Read> VPXBoolReader<R> {
                 .record_compression_stats(_cmp, 1, i64::from(shift));
         }
 
+        // the bits that start the stream don't belong to the model
+        #[cfg(feature = "stats")]
+        if _cmp != ModelComponent::Dummy {
+            self.model_statistics
+                .record_coded_decision(self.stats_component, shift);
+        }
+
         #[cfg(feature = "detailed_tracing")]
         {
             self.hash.hash(branch.get_u64());
@@ -207,25 +385,275 @@ impl<R: Read> VPXBoolReader<R> {
     #[cold]
     #[inline(always)]
     fn vpx_reader_fill(
-        tmp_value: &mut u64,
+        tmp_value: &mut BoolValue,
         tmp_count: &mut i32,
+        total_bytes_read: &mut u64,
+        last_bytes: &mut u16,
         upstream_reader: &mut R,
     ) -> Result<()> {
-        let mut shift = BITS_IN_LONG_MINUS_LAST_BYTE - (*tmp_count + BITS_IN_BYTE);
+        let start_shift = BITS_IN_VALUE_MINUS_LAST_BYTE - (*tmp_count + BITS_IN_BYTE);
+        let mut shift = start_shift;
 
         while shift >= 0 {
             // BufReader is already pretty efficient handling small reads, so optimization doesn't help that much
             let mut v = [0u8; 1];
             let bytes_read = upstream_reader.read(&mut v)?;
             if bytes_read == 0 {
-                break;
+                // past the end the data is padded with zeros, which count doesn't include, so the decoder is
+                // -count - 8 bits past the end
+                if *tmp_count < -(BITS_IN_BYTE + MAX_PADDING_BITS) {
+                    return err_exit_code(
+                        ExitCode::UnexpectedEndOfSegment,
+                        &format!(
+                            "the segment ended after {0} bytes but more decisions are coded in it",
+                            total_bytes_read
+                        ),
+                    );
+                }
+
+                // fewer bytes than usual were read, so the last two may not both be from this time
+                if start_shift - shift == BITS_IN_BYTE {
+                    *last_bytes = (*last_bytes << 8) | ((*tmp_value >> (shift + 8)) as u16 & 0xff);
+                } else if start_shift > shift {
+                    *last_bytes = (*tmp_value >> (shift + 8)) as u16;
+                }
+                return Ok(());
             }
 
-            *tmp_value |= (v[0] as u64) << shift;
+            *tmp_value |= BoolValue::from(v[0]) << shift;
             shift -= BITS_IN_BYTE;
             *tmp_count += BITS_IN_BYTE;
+            *total_bytes_read += 1;
         }
 
+        // the register is filled with at least two bytes at a time, and the last two are still as they were read
+        *last_bytes = (*tmp_value >> (shift + 8)) as u16;
+
         return Ok(());
     }
 }
+
+/// decodes a bit like VPXBoolReader::get did before it selected the part of the range without a branch, to check
+/// that the state of the reader goes through the same transitions
+#[cfg(test)]
+impl<R: Read> VPXBoolReader<R> {
+    fn get_reference(&mut self, branch: &mut Branch) -> Result<bool> {
+        let mut tmp_value = self.value;
+        let mut tmp_range = self.range;
+        let mut tmp_count = self.count;
+
+        if tmp_count < 0 {
+            Self::vpx_reader_fill(
+                &mut tmp_value,
+                &mut tmp_count,
+                &mut self.bytes_read,
+                &mut self.last_bytes,
+                &mut self.upstream_reader,
+            )?;
+        }
+
+        let probability = branch.get_probability() as u32;
+
+        let split = 1 + (((tmp_range - 1) * probability) >> BITS_IN_BYTE);
+        let big_split = (split as BoolValue) << BITS_IN_VALUE_MINUS_LAST_BYTE;
+        let bit = tmp_value >= big_split;
+
+        let shift;
+        if bit {
+            branch.record_and_update_true_obs();
+            tmp_range -= split;
+            tmp_value -= big_split;
+
+            shift = tmp_range.leading_zeros() as i32 - 24;
+        } else {
+            branch.record_and_update_false_obs();
+            tmp_range = split;
+
+            shift = split.leading_zeros() as i32 - 24;
+        }
+
+        self.value = tmp_value << shift;
+        self.range = tmp_range << shift;
+        self.count = tmp_count - shift;
+
+        Ok(bit)
+    }
+
+    /// the internal state of the reader
+    fn state(&self) -> (BoolValue, u32, i32, u64) {
+        (self.value, self.range, self.count, self.bytes_read)
+    }
+}
+
+/// decoding goes through exactly the same states as the reference, for random data and branches with all kinds of
+/// probabilities, including the ones that are all trues or all falses, up to the end of the data
+#[test]
+fn get_matches_reference() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([6u8; 32]);
+
+    for trial in 0..200 {
+        let data: Vec<u8> = (0..rng.gen_range(0..256)).map(|_| rng.gen()).collect();
+
+        let counts = [(); 16].map(|_| match trial % 4 {
+            0 => 0x0101,
+            1 => 0x00ff,
+            _ => (rng.gen_range(1..=255u16) << 8) | rng.gen_range(1..=255u16),
+        });
+        let new_branches = || {
+            counts.map(|c| {
+                let mut b = Branch::new();
+                assert!(b.set_counts(c));
+                b
+            })
+        };
+        let mut branches = new_branches();
+        let mut reference_branches = new_branches();
+
+        let mut reader = VPXBoolReader::new(&data[..]).unwrap();
+        let mut reference = VPXBoolReader::new(&data[..]).unwrap();
+        assert_eq!(reader.state(), reference.state());
+
+        for _ in 0..4096 {
+            let i = rng.gen_range(0..branches.len());
+
+            let bit = reader.get(&mut branches[i], ModelComponent::Dummy);
+            let reference_bit = reference.get_reference(&mut reference_branches[i]);
+
+            // both fail at the same bit once the data has run out
+            assert_eq!(bit.is_err(), reference_bit.is_err());
+            if bit.is_err() {
+                break;
+            }
+
+            assert_eq!(bit.unwrap(), reference_bit.unwrap());
+            assert_eq!(reader.state(), reference.state());
+            assert_eq!(branches[i].get_counts(), reference_branches[i].get_counts());
+        }
+    }
+}
+
+/// the register of the width that the build doesn't use, see BoolValue
+#[cfg(all(test, not(any(target_pointer_width = "32", feature = "coder32"))))]
+type OtherBoolValue = u32;
+#[cfg(all(test, any(target_pointer_width = "32", feature = "coder32")))]
+type OtherBoolValue = u64;
+
+/// decodes like VPXBoolReader, but with the register of the other width, so that a single build can check that
+/// the two decode the same
+#[cfg(test)]
+struct OtherWidthReader<'a> {
+    data: &'a [u8],
+    value: OtherBoolValue,
+    range: u32,
+    count: i32,
+}
+
+#[cfg(test)]
+impl<'a> OtherWidthReader<'a> {
+    const BITS: i32 = OtherBoolValue::BITS as i32;
+
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let mut r = OtherWidthReader {
+            data,
+            value: 0,
+            range: 255,
+            count: -8,
+        };
+        r.get(128)?;
+        Some(r)
+    }
+
+    /// the bit decoded with probability out of 256 that it is false, or None once the data has run out
+    fn get(&mut self, probability: u8) -> Option<bool> {
+        if self.count < 0 {
+            let mut shift = Self::BITS - BITS_IN_BYTE - (self.count + BITS_IN_BYTE);
+            while shift >= 0 {
+                match self.data.split_first() {
+                    Some((b, rest)) => {
+                        self.value |= OtherBoolValue::from(*b) << shift;
+                        self.data = rest;
+                        shift -= BITS_IN_BYTE;
+                        self.count += BITS_IN_BYTE;
+                    }
+                    None if self.count < -(BITS_IN_BYTE + MAX_PADDING_BITS) => return None,
+                    None => break,
+                }
+            }
+        }
+
+        let split = 1 + (((self.range - 1) * u32::from(probability)) >> BITS_IN_BYTE);
+        let big_split = OtherBoolValue::from(split) << (Self::BITS - BITS_IN_BYTE);
+        let bit = self.value >= big_split;
+        if bit {
+            self.range -= split;
+            self.value -= big_split;
+        } else {
+            self.range = split;
+        }
+
+        let shift = self.range.leading_zeros() as i32 - 24;
+        self.value <<= shift;
+        self.range <<= shift;
+        self.count -= shift;
+
+        Some(bit)
+    }
+}
+
+/// the registers of both widths decode the same bits from coded decisions and from random data, and run out of
+/// data at the same decision
+#[test]
+fn both_widths_decode_the_same() {
+    use crate::structs::vpx_bool_writer::VPXBoolWriter;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([12u8; 32]);
+
+    for trial in 0..100 {
+        let probabilities: Vec<u8> = (0..4096).map(|_| rng.gen()).collect();
+
+        let data: Vec<u8> = if trial % 2 == 0 {
+            let mut data = Vec::new();
+            let mut writer = VPXBoolWriter::new(&mut data).unwrap();
+            for p in &probabilities {
+                let bit = rng.gen_range(0..256) >= u32::from(*p);
+                writer
+                    .put(
+                        bit,
+                        &mut Branch::with_probability(*p),
+                        ModelComponent::Dummy,
+                    )
+                    .unwrap();
+            }
+            writer.finish().unwrap();
+            data
+        } else {
+            (0..rng.gen_range(0..1024)).map(|_| rng.gen()).collect()
+        };
+
+        let reader = VPXBoolReader::new(&data[..]);
+        let other = OtherWidthReader::new(&data);
+        assert_eq!(reader.is_ok(), other.is_some());
+        let (mut reader, mut other) = match (reader, other) {
+            (Ok(reader), Some(other)) => (reader, other),
+            _ => continue,
+        };
+
+        for p in &probabilities {
+            let bit = reader.get(&mut Branch::with_probability(*p), ModelComponent::Dummy);
+            let other_bit = other.get(*p);
+
+            assert_eq!(bit.is_ok(), other_bit.is_some());
+            if other_bit.is_none() {
+                break;
+            }
+            assert_eq!(bit.unwrap(), other_bit.unwrap());
+        }
+    }
+}
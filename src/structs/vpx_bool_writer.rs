@@ -22,7 +22,7 @@ Neither the name of Google nor the names of its contributors may be used to endo
 THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
-use std::io::{Result, Write};
+use std::io::{self, Result, Write};
 
 use crate::metrics::{Metrics, ModelComponent};
 
@@ -30,15 +30,78 @@ use crate::metrics::{Metrics, ModelComponent};
 use crate::metrics::ModelStatsCollector;
 
 use super::{branch::Branch, simple_hash::SimpleHash};
+#[cfg(feature = "experimental-tuning")]
+use crate::enabled_features::ModelTuning;
+#[cfg(feature = "debug-symmetry")]
+use crate::structs::symmetry_log::SymmetryLog;
+
+/// the most bytes that are buffered before they are written, which also bounds a run of 0xFF bytes that is
+/// resolved, see VPXBoolWriter::pending_ff. The buffer can go one byte over before it is flushed, and it is
+/// allocated with room for that up front so that it never grows to twice the size.
+const MAX_BUFFERED_BYTES: usize = 65536 - 128;
+
+/// the bits that a block cost, measured by a VPXBoolWriter that only estimates, see new_estimator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockBits {
+    pub component: usize,
+    pub dpos: i32,
+    pub bits: f32,
+}
+
+/// what a VPXBoolWriter that only estimates keeps track of instead of the coded data, which stays empty in a
+/// writer that codes
+#[derive(Default)]
+struct BitEstimate {
+    /// the sum of -log2 of the probability of each decision so far
+    bits: f64,
+    /// the block that is being coded, with the bits before it
+    block: Option<(usize, i32, f64)>,
+    blocks: Vec<BlockBits>,
+}
 
-pub struct VPXBoolWriter<W> {
+impl BitEstimate {
+    fn end_block(&mut self) {
+        if let Some((component, dpos, start)) = self.block.take() {
+            self.blocks.push(BlockBits {
+                component,
+                dpos,
+                bits: (self.bits - start) as f32,
+            });
+        }
+    }
+}
+
+/// codes the decisions into writer, or only estimates what they cost if ESTIMATE is set. Since the choice is made
+/// at compile time, the writer that codes doesn't pay for the estimation on each decision.
+pub struct VPXBoolWriter<W, const ESTIMATE: bool = false> {
     low_value: u32,
     range: u32,
     count: i32,
     writer: W,
+    /// the coded bytes that haven't been written yet. Only the last one can still change, when a carry goes into
+    /// it, so the others are final and are written to writer whenever the buffer fills up, rather than being kept
+    /// until the end of the segment.
     buffer: Vec<u8>,
+    /// the number of 0xFF bytes after the buffer that are waiting for the byte after them to know if they are
+    /// final. A carry turns them into zeros and goes into the last byte of the buffer. They are only counted, so
+    /// that a run of them, which a long enough sequence of decisions can make as long as it likes, takes no
+    /// memory until it is resolved.
+    pending_ff: usize,
+    /// the bytes that have been written to writer so far
+    bytes_flushed: u64,
     model_statistics: Metrics,
     pub hash: SimpleHash,
+    /// how branches are updated if it isn't the regular way
+    #[cfg(feature = "experimental-tuning")]
+    tuning: Option<ModelTuning>,
+    /// what the decisions cost if the writer only estimates, see new_estimator
+    estimate: BitEstimate,
+    /// where the decisions are logged, see SymmetryLog
+    #[cfg(feature = "debug-symmetry")]
+    symmetry_log: Option<SymmetryLog>,
+    /// the component that the decisions are counted for in the CoderStats
+    #[cfg(feature = "stats")]
+    stats_component: usize,
 }
 
 impl<W: Write> VPXBoolWriter<W> {
@@ -47,10 +110,51 @@ impl<W: Write> VPXBoolWriter<W> {
             low_value: 0,
             range: 255,
             count: -24,
-            buffer: Vec::new(),
+            buffer: Vec::with_capacity(MAX_BUFFERED_BYTES + 1),
+            pending_ff: 0,
+            bytes_flushed: 0,
             writer: writer,
             model_statistics: Metrics::default(),
             hash: SimpleHash::new(),
+            #[cfg(feature = "experimental-tuning")]
+            tuning: None,
+            estimate: BitEstimate::default(),
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_log: None,
+            #[cfg(feature = "stats")]
+            stats_component: 0,
+        };
+
+        let mut dummy_branch = Branch::new();
+        retval.put(false, &mut dummy_branch, ModelComponent::Dummy)?;
+
+        Ok(retval)
+    }
+}
+
+impl VPXBoolWriter<io::Sink, true> {
+    /// a writer that doesn't code anything, but adds up the bits that the coder would spend on each decision
+    /// with the probability that its branch gives it, which only leaves out how the coder rounds the last byte.
+    /// The branches are updated as if the decisions were coded. The bits are counted per block between calls to
+    /// start_block.
+    pub fn new_estimator() -> Result<Self> {
+        let mut retval = VPXBoolWriter {
+            low_value: 0,
+            range: 255,
+            count: -24,
+            buffer: Vec::new(),
+            pending_ff: 0,
+            bytes_flushed: 0,
+            writer: io::sink(),
+            model_statistics: Metrics::default(),
+            hash: SimpleHash::new(),
+            #[cfg(feature = "experimental-tuning")]
+            tuning: None,
+            estimate: BitEstimate::default(),
+            #[cfg(feature = "debug-symmetry")]
+            symmetry_log: None,
+            #[cfg(feature = "stats")]
+            stats_component: 0,
         };
 
         let mut dummy_branch = Branch::new();
@@ -59,10 +163,94 @@ impl<W: Write> VPXBoolWriter<W> {
         Ok(retval)
     }
 
+    /// the bits of each block that were counted, in the order they were coded
+    pub fn take_block_bits(&mut self) -> Vec<BlockBits> {
+        self.estimate.end_block();
+        std::mem::take(&mut self.estimate.blocks)
+    }
+}
+
+impl<W: Write, const ESTIMATE: bool> VPXBoolWriter<W, ESTIMATE> {
+    /// if the writer only estimates, the decisions from now on are counted for the block at dpos of component,
+    /// and if it logs them, they are logged for it. With the stats feature they are counted for component.
+    #[inline(always)]
+    pub fn start_block(&mut self, component: usize, dpos: i32) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats_component = component;
+        }
+
+        if ESTIMATE {
+            self.estimate.end_block();
+            self.estimate.block = Some((component, dpos, self.estimate.bits));
+        }
+
+        #[cfg(feature = "debug-symmetry")]
+        if let Some(log) = &mut self.symmetry_log {
+            log.set_block(component, dpos);
+        }
+    }
+
+    /// the number of coded bytes that have been written to the writer so far. Together with bytes_pending_carry
+    /// this is the size of the coded data so far, and after finish it is the size of all of it.
+    pub fn bytes_flushed(&self) -> u64 {
+        self.bytes_flushed
+    }
+
+    /// the number of coded bytes that haven't been written yet, since they are buffered or are a run of 0xFF
+    /// that a carry can still go through, see pending_ff. None are left after finish.
+    pub fn bytes_pending_carry(&self) -> u64 {
+        (self.buffer.len() + self.pending_ff) as u64
+    }
+
     pub fn drain_stats(&mut self) -> Metrics {
         self.model_statistics.drain()
     }
 
+    /// logs the decisions from now on in log, see SymmetryLog
+    #[cfg(feature = "debug-symmetry")]
+    pub fn set_symmetry_log(&mut self, log: Option<SymmetryLog>) {
+        self.symmetry_log = log;
+    }
+
+    /// the decisions from now on belong to the coefficient, see SymmetryLog::set_coefficient
+    #[cfg(feature = "debug-symmetry")]
+    #[inline(always)]
+    pub fn set_symmetry_coefficient(&mut self, coefficient: u8) {
+        if let Some(log) = &mut self.symmetry_log {
+            log.set_coefficient(coefficient);
+        }
+    }
+
+    /// updates the branches with the values of tuning from now on
+    #[cfg(feature = "experimental-tuning")]
+    pub fn set_tuning(&mut self, tuning: &ModelTuning) {
+        self.tuning = if *tuning == ModelTuning::default() {
+            None
+        } else {
+            Some(*tuning)
+        };
+    }
+
+    /// updates the branch with the bit that was coded
+    #[inline(always)]
+    fn record_obs(&self, branch: &mut Branch, bit: bool) {
+        #[cfg(feature = "stats")]
+        branch.record_visit();
+
+        #[cfg(feature = "experimental-tuning")]
+        if let Some(tuning) = &self.tuning {
+            branch.record_and_update_tuned_obs(bit, tuning);
+            return;
+        }
+
+        if bit {
+            branch.record_and_update_true_obs();
+        } else {
+            branch.record_and_update_false_obs();
+        }
+    }
+
     #[inline(never)]
     pub fn put_grid<const A: usize, const B: usize>(
         &mut self,
@@ -153,8 +341,28 @@ impl<W: Write> VPXBoolWriter<W> {
             }
         }
 
+        #[cfg(feature = "debug-symmetry")]
+        if let Some(log) = &mut self.symmetry_log {
+            log.record(_cmp, branch.get_counts(), value);
+        }
+
         let probability = branch.get_probability() as u32;
 
+        if ESTIMATE {
+            // the coder narrows the range to the share of the decision, and the bits that it shifts out of the
+            // range to renormalize it are what the decision costs. The bits that start and end the stream don't
+            // belong to any block.
+            let split = 1 + (((self.range - 1) * probability) >> 8);
+            let range = if value { self.range - split } else { split };
+            if _cmp != ModelComponent::Dummy {
+                self.estimate.bits += (f64::from(self.range) / f64::from(range)).log2();
+            }
+            self.range = range << (range as u8).leading_zeros();
+
+            self.record_obs(branch, value);
+            return Ok(());
+        }
+
         let mut tmp_range = self.range;
         let split = 1 + (((tmp_range - 1) * probability) >> 8);
 
@@ -162,13 +370,13 @@ impl<W: Write> VPXBoolWriter<W> {
 
         let mut shift;
         if value {
-            branch.record_and_update_true_obs();
+            self.record_obs(branch, true);
             tmp_low_value += split;
             tmp_range -= split;
 
             shift = (tmp_range as u8).leading_zeros() as i32;
         } else {
-            branch.record_and_update_false_obs();
+            self.record_obs(branch, false);
             tmp_range = split;
 
             // optimizer understands that split > 0, so it can optimize this
@@ -181,6 +389,13 @@ impl<W: Write> VPXBoolWriter<W> {
                 .record_compression_stats(_cmp, 1, i64::from(shift));
         }
 
+        // the bits that start and end the stream don't belong to the model
+        #[cfg(feature = "stats")]
+        if _cmp != ModelComponent::Dummy {
+            self.model_statistics
+                .record_coded_decision(self.stats_component, shift);
+        }
+
         tmp_range <<= shift;
 
         let mut tmp_count = self.count;
@@ -190,19 +405,23 @@ impl<W: Write> VPXBoolWriter<W> {
             let offset = shift - tmp_count;
 
             if ((tmp_low_value << (offset - 1)) & 0x80000000) != 0 {
-                let mut x = self.buffer.len() - 1;
-
-                while self.buffer[x] == 0xFF {
-                    self.buffer[x] = 0;
+                #[cfg(feature = "stats")]
+                self.model_statistics.record_carry();
 
-                    assert!(x > 0);
-                    x -= 1;
-                }
+                // the run of 0xFF bytes turns into zeros and the byte before it can't be 0xFF, since a carry can
+                // only go into a byte once
+                *self.buffer.last_mut().unwrap() += 1;
+                self.resolve_pending_ff(0)?;
+            }
 
-                self.buffer[x] += 1;
+            let byte = (tmp_low_value >> (24 - offset)) as u8;
+            if byte == 0xFF {
+                self.pending_ff += 1;
+            } else {
+                self.resolve_pending_ff(0xFF)?;
+                self.buffer.push(byte);
             }
 
-            self.buffer.push((tmp_low_value >> (24 - offset)) as u8);
             tmp_low_value <<= offset;
             shift = tmp_count;
             tmp_low_value &= 0xffffff;
@@ -216,7 +435,7 @@ impl<W: Write> VPXBoolWriter<W> {
         self.range = tmp_range;
 
         // check if we're out of buffer space, if yes - send the buffer to output,
-        if self.buffer.len() > 65536 - 128 {
+        if self.buffer.len() > MAX_BUFFERED_BYTES {
             self.flush_non_final_data()?;
         }
 
@@ -224,32 +443,75 @@ impl<W: Write> VPXBoolWriter<W> {
     }
 
     pub fn finish(&mut self) -> Result<()> {
+        if ESTIMATE {
+            self.estimate.end_block();
+            return Ok(());
+        }
+
         for _i in 0..32 {
             let mut dummy_branch = Branch::new();
             self.put(false, &mut dummy_branch, ModelComponent::Dummy)?;
         }
 
+        // nothing can carry into the run of 0xFF at the end anymore
+        self.resolve_pending_ff(0xFF)?;
+
         // Ensure there's no ambigous collision with any index marker bytes
         if (self.buffer.last().unwrap() & 0xe0) == 0xc0 {
             self.buffer.push(0);
         }
 
-        self.writer.write_all(&self.buffer[..])?;
+        self.write_buffered(self.buffer.len())?;
         Ok(())
     }
 
-    /// When buffer is full and is going to be sent to output, preserve buffer data that
-    /// is not final and should carried over to the next buffer.
+    /// When buffer is full and is going to be sent to output, keep the last byte, which a carry can still
+    /// change, and write the others.
     fn flush_non_final_data(&mut self) -> Result<()> {
-        // carry over buffer data that might be not final
-        let mut i = self.buffer.len() - 1;
-        while self.buffer[i] == 0xFF {
-            assert!(i > 0);
-            i -= 1;
+        self.write_buffered(self.buffer.len() - 1)
+    }
+
+    /// writes the first len bytes of the buffer and removes them from it
+    fn write_buffered(&mut self, len: usize) -> Result<()> {
+        self.writer.write_all(&self.buffer[..len])?;
+        self.buffer.drain(..len);
+        self.bytes_flushed += len as u64;
+
+        Ok(())
+    }
+
+    /// adds the run of 0xFF bytes that was pending to the buffer as fill, which is 0xFF if the byte after them
+    /// made them final or zero if a carry went through them
+    #[inline(always)]
+    fn resolve_pending_ff(&mut self, fill: u8) -> Result<()> {
+        if self.pending_ff > 0 {
+            self.write_run(fill)?;
         }
+        Ok(())
+    }
 
-        self.writer.write_all(&self.buffer[..i])?;
-        self.buffer.drain(..i);
+    #[cold]
+    #[inline(never)]
+    fn write_run(&mut self, fill: u8) -> Result<()> {
+        let len = self.buffer.len() + self.pending_ff;
+        if len <= MAX_BUFFERED_BYTES {
+            self.buffer.resize(len, fill);
+        } else {
+            // the buffer and the run are final except for the last byte of the run, which a carry can still go
+            // into if it is a zero, so a run that doesn't fit is written in pieces and only its last byte is kept
+            self.write_buffered(self.buffer.len())?;
+
+            let piece = [fill; 4096];
+            let mut remaining = self.pending_ff - 1;
+            while remaining > 0 {
+                let n = remaining.min(piece.len());
+                self.writer.write_all(&piece[..n])?;
+                self.bytes_flushed += n as u64;
+                remaining -= n;
+            }
+            self.buffer.push(fill);
+        }
+        self.pending_ff = 0;
 
         Ok(())
     }
@@ -387,3 +649,316 @@ fn test_roundtrip_vpxboolwriter_single_bit() {
         assert_eq!(read_value, i % 10 == 0);
     }
 }
+
+/// a stream that is cut short fails with UnexpectedEndOfSegment instead of decoding the zeros that it is padded with,
+/// wherever it is cut. Only the last byte of the flush, and the zero that finish may add after it, can be missing
+/// without the decoder noticing, since the decisions at the end are decoded with the bits after them.
+#[test]
+fn test_truncated_vpxboolwriter_fails() {
+    use crate::lepton_error::{ExitCode, LeptonError};
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([8u8; 32]);
+
+    for trial in 0..8 {
+        // from decisions that are close to random to ones that are easy to predict
+        let bits: Vec<(bool, usize)> = (0..4096)
+            .map(|_| (rng.gen_range(0..trial + 2) == 0, rng.gen_range(0..4)))
+            .collect();
+
+        let mut buffer = Vec::new();
+        let mut writer = VPXBoolWriter::new(&mut buffer).unwrap();
+        let mut branches = [(); 4].map(|_| Branch::new());
+        for (bit, i) in &bits {
+            writer
+                .put(*bit, &mut branches[*i], ModelComponent::Dummy)
+                .unwrap();
+        }
+        writer.finish().unwrap();
+
+        let flush_start = buffer.len() - if buffer.last() == Some(&0) { 2 } else { 1 };
+
+        for len in 0..=buffer.len() {
+            let mut branches = [(); 4].map(|_| Branch::new());
+            let decoded = VPXBoolReader::new(&buffer[..len]).and_then(|mut reader| {
+                let mut decoded = Vec::new();
+                for (_, i) in &bits {
+                    decoded.push(reader.get(&mut branches[*i], ModelComponent::Dummy)?);
+                }
+                Ok(decoded)
+            });
+
+            match decoded {
+                Ok(decoded) => {
+                    assert!(len >= flush_start, "length {0}", len);
+                    if len == buffer.len() {
+                        assert!(decoded.iter().eq(bits.iter().map(|(bit, _)| bit)));
+                    }
+                }
+                Err(e) => {
+                    assert!(len < buffer.len());
+                    let e = e.downcast_ref::<LeptonError>().unwrap();
+                    assert_eq!(e.exit_code, ExitCode::UnexpectedEndOfSegment);
+                }
+            }
+        }
+    }
+}
+
+/// after the last decision verify_end accepts the data as finish leaves it, with or without the zero after a last
+/// byte that looks like a marker, and notices a stream that is cut anywhere or that has any byte after it
+#[test]
+fn test_verify_end() {
+    use crate::lepton_error::{ExitCode, LeptonError};
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([12u8; 32]);
+    let mut endings = [false; 2];
+
+    for trial in 0..200 {
+        let decisions: Vec<(u8, bool)> = (0..rng.gen_range(0..2000))
+            .map(|_| (rng.gen(), rng.gen_range(0..trial % 4 + 2) == 0))
+            .collect();
+
+        let mut buffer = Vec::new();
+        let mut writer = VPXBoolWriter::new(&mut buffer).unwrap();
+        for (probability, bit) in &decisions {
+            writer
+                .put(
+                    *bit,
+                    &mut Branch::with_probability(*probability),
+                    ModelComponent::Dummy,
+                )
+                .unwrap();
+        }
+        writer.finish().unwrap();
+
+        endings[usize::from(buffer.last() == Some(&0))] = true;
+
+        let verify = |data: &[u8]| {
+            let mut reader = VPXBoolReader::new(data)?;
+            for (probability, _) in &decisions {
+                reader.get(
+                    &mut Branch::with_probability(*probability),
+                    ModelComponent::Dummy,
+                )?;
+            }
+            reader.verify_end()
+        };
+        let exit_code =
+            |r: anyhow::Result<()>| r.unwrap_err().downcast::<LeptonError>().unwrap().exit_code;
+
+        verify(&buffer).unwrap();
+
+        for len in 0..buffer.len() {
+            assert_eq!(
+                exit_code(verify(&buffer[..len])),
+                ExitCode::UnexpectedEndOfSegment,
+                "length {0} of {1}",
+                len,
+                buffer.len()
+            );
+        }
+
+        for garbage in [0, 0xff, rng.gen()] {
+            let mut longer = buffer.clone();
+            longer.push(garbage);
+            assert_eq!(
+                exit_code(verify(&longer)),
+                ExitCode::TrailingGarbageInSegment
+            );
+        }
+    }
+
+    // both ways that finish ends the data came up
+    assert_eq!(endings, [true, true]);
+}
+
+/// decisions that keep the coder in the middle of a run of 0xFF bytes for as long as it is asked to, and then
+/// either carry through the run or end it, are decoded back, with the buffer staying bounded however long the
+/// run gets, and the bytes that were written and that are pending add up to the coded data all along
+#[test]
+fn test_long_carry_runs_roundtrip() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::from_seed([9u8; 32]);
+
+    for (run, carry) in [
+        (1, true),
+        (2, false),
+        (300, true),
+        (5000, false),
+        (5000, true),
+        (MAX_BUFFERED_BYTES + 1, true),
+        (3 * MAX_BUFFERED_BYTES, false),
+        (3 * MAX_BUFFERED_BYTES, true),
+    ] {
+        let mut writer = VPXBoolWriter::new(Vec::new()).unwrap();
+        let mut decisions = Vec::new();
+        let mut coded = 0;
+        let capacity = writer.buffer.capacity();
+
+        // the distance of a point from the bottom of the interval of the coder. The decisions keep the point in
+        // the interval, so the bytes are the ones of the point, and with the point at 0x40, those just below it
+        // are 0x3f followed by 0xff until the interval starts at the point, which carries into the 0x3f.
+        let mut distance = writer.range / 2;
+        let mut steering = true;
+        let mut max_pending = 0;
+
+        while decisions.len() < 32 || steering || writer.pending_ff > 0 {
+            let split = |probability: u8| 1 + (((writer.range - 1) * u32::from(probability)) >> 8);
+            let mut probability = rng.gen::<u8>();
+            let carry_probability = if steering && writer.pending_ff >= run && carry {
+                (0..=255).find(|p| split(*p) == distance)
+            } else {
+                None
+            };
+
+            let bit = if !steering {
+                rng.gen()
+            } else if let Some(p) = carry_probability {
+                // the interval starts at the point, so the next byte carries through the run
+                steering = false;
+                probability = p;
+                true
+            } else if writer.pending_ff >= run && !carry {
+                // the interval ends at or below the point, so nothing can carry into the run anymore
+                steering = false;
+                if split(probability) > distance {
+                    probability = 0;
+                }
+                false
+            } else {
+                // keeps the point in the interval without letting the interval start at it
+                while split(probability) == distance {
+                    probability = rng.gen();
+                }
+                distance > split(probability)
+            };
+
+            if steering {
+                let s = split(probability);
+                let range = if bit {
+                    distance -= s;
+                    writer.range - s
+                } else {
+                    s
+                };
+                distance <<= (range as u8).leading_zeros();
+            }
+
+            writer
+                .put(
+                    bit,
+                    &mut Branch::with_probability(probability),
+                    ModelComponent::Dummy,
+                )
+                .unwrap();
+            decisions.push((probability, bit));
+
+            max_pending = max_pending.max(writer.pending_ff);
+            assert!(writer.buffer.len() <= MAX_BUFFERED_BYTES + 1);
+            assert_eq!(writer.buffer.capacity(), capacity);
+
+            // a carry changes bytes that are pending, but never how many there are
+            assert_eq!(writer.bytes_flushed(), writer.writer.len() as u64);
+            assert!(writer.bytes_flushed() + writer.bytes_pending_carry() >= coded);
+            coded = writer.bytes_flushed() + writer.bytes_pending_carry();
+        }
+        writer.finish().unwrap();
+
+        assert_eq!(writer.bytes_pending_carry(), 0);
+        assert_eq!(writer.bytes_flushed(), writer.writer.len() as u64);
+        let buffer = writer.writer;
+
+        assert!(max_pending >= run);
+
+        // the run ends up in the output as 0xff or zeros
+        let fill = if carry { 0 } else { 0xff };
+        let longest = buffer.split(|b| *b != fill).map(|r| r.len()).max().unwrap();
+        assert!(longest >= run, "run {0} longest {1}", run, longest);
+
+        let mut reader = VPXBoolReader::new(&buffer[..]).unwrap();
+        for (probability, bit) in &decisions {
+            assert_eq!(
+                reader
+                    .get(
+                        &mut Branch::with_probability(*probability),
+                        ModelComponent::Dummy
+                    )
+                    .unwrap(),
+                *bit
+            );
+        }
+    }
+}
+
+/// the writer gets the bytes as they become final, so one that only takes a few bytes at a time, or that gets
+/// interrupted, ends up with the same output as a Vec, including the output of a run of 0xff that is longer than
+/// the buffer
+#[test]
+fn test_short_writes_give_same_output() {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use std::io::{Error, ErrorKind};
+
+    struct ShortWriter {
+        output: Vec<u8>,
+        rng: StdRng,
+    }
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            if self.rng.gen_range(0..4) == 0 {
+                return Err(Error::new(ErrorKind::Interrupted, "interrupted"));
+            }
+
+            let n = buf.len().min(self.rng.gen_range(1..8));
+            self.output.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut rng = StdRng::from_seed([10u8; 32]);
+    let mut decisions: Vec<(u8, bool)> = (0..600000).map(|_| (rng.gen(), rng.gen())).collect();
+    // a run of 0xff bytes, since the interval keeps its top when a true is coded
+    decisions.extend(std::iter::repeat((128, true)).take(8 * 3 * MAX_BUFFERED_BYTES));
+    decisions.extend((0..1000).map(|_| (rng.gen(), rng.gen())));
+
+    let encode = |writer: &mut dyn Write| {
+        let mut writer = VPXBoolWriter::new(writer).unwrap();
+        for (probability, bit) in &decisions {
+            writer
+                .put(
+                    *bit,
+                    &mut Branch::with_probability(*probability),
+                    ModelComponent::Dummy,
+                )
+                .unwrap();
+        }
+        writer.finish().unwrap();
+    };
+
+    let mut expected = Vec::new();
+    encode(&mut expected);
+
+    let mut short = ShortWriter {
+        output: Vec::new(),
+        rng: StdRng::from_seed([11u8; 32]),
+    };
+    encode(&mut short);
+
+    assert!(expected.len() > 3 * MAX_BUFFERED_BYTES);
+    assert!(short.output == expected);
+}
@@ -5,18 +5,49 @@
  *--------------------------------------------------------------------------------------------*/
 
 use core::result::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
 use std::{io::Cursor, path::Path};
 
 use std::fs::File;
-use std::io::Read;
+use std::io::{Read, Write};
 
 use lepton_jpeg::metrics::Metrics;
 use lepton_jpeg::{
-    decode_lepton, encode_lepton, encode_lepton_verify,
+    assemble, compute_decoded_size, decode_entry, decode_lepton, decode_lepton_chunked,
+    decode_lepton_lenient, decode_lepton_streaming, decode_lepton_streaming_prefetch,
+    decode_lepton_with_features, decode_segment, decode_shard, encode_lepton, encode_lepton_verify,
+    encode_many, encode_many_named, estimate_block_bits, estimate_compression,
+    extract_trailing_data, extract_trailing_data_streaming, inspect_lepton_structure, is_container,
+    join_lepton,
     lepton_error::{ExitCode, LeptonError},
-    EnabledFeatures,
+    plan_decode, primer_from_bytes, read_container_entries, read_encoder_info,
+    read_lepton_file_type, read_original_file_size, rewrite_header, split_lepton, train_primer,
+    ContainerEntry, DecodePlan, EnabledFeatures, EncodeMode, HeaderEdit, LeptonVersion,
+    ModelSnapshot, ModelSnapshotHook, SegmentOutput, ThreadLifecycleEvent, ThreadLifecycleHook,
+    WorkerKind,
 };
-use lepton_jpeg::{WrapperCompressImage, WrapperDecompressImage};
+use lepton_jpeg::{
+    WrapperCompressImage, WrapperCompressImageWithOptions, WrapperCompressOptions,
+    WrapperDecompressImage, WrapperDecompressImageChunked, WrapperGetEncoderInfo,
+    WrapperGetLastErrorMessage, WrapperGetLeptonFileType, WrapperGetOriginalFileSize,
+};
+
+#[cfg(feature = "experimental-tuning")]
+use lepton_jpeg::ModelTuning;
+
+#[cfg(feature = "experimental-codec")]
+use lepton_jpeg::CoefficientCodecKind;
+
+#[cfg(feature = "debug-symmetry")]
+use lepton_jpeg::check_symmetry;
+
+#[cfg(feature = "test-utils")]
+use lepton_jpeg::testing::{bool_coder_roundtrip, roundtrip_blocks, with_chunk_delay};
+#[cfg(feature = "test-utils")]
+use proptest::prelude::*;
 
 use rstest::rstest;
 
@@ -34,7 +65,8 @@ fn read_file(filename: &str, ext: &str) -> Vec<u8> {
 }
 
 /// verifies that the decode will accept existing Lepton files and generate
-/// exactly the same jpeg from them. Used to detect unexpected divergences in coding format.
+/// exactly the same jpeg from them. Used to detect unexpected divergences in coding format. The data of each
+/// segment has to end right after the flush of the encoder, also for the files written by Lepton C++.
 #[rstest]
 fn verify_decode(
     #[values(
@@ -60,6 +92,7 @@ fn verify_decode(
         "out_of_order_dqt",     // image with quanatization table dqt that comes after image definition SOF
         "narrowrst",
         "nofsync",
+        "paddedsof", // image with extra bytes at the end of the SOF segment, written by an earlier version
         "slrcity",
         "slrhills",
         "slrindoor",
@@ -77,11 +110,355 @@ fn verify_decode(
 
     let mut output = Vec::new();
 
+    decode_lepton_with_features(
+        &mut Cursor::new(input),
+        &mut output,
+        8,
+        &EnabledFeatures {
+            strict_segment_end: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    assert!(output[..] == expected[..]);
+}
+
+/// files written by the original C++ implementation (which stores its git revision where we keep the
+/// uncompressed header size) decode to exactly the same jpeg, including progressive and truncated images.
+#[rstest]
+fn verify_decode_cpp_lepton(
+    #[values("android", "androidprogressive", "iphoneprogressive", "trunc")] file: &str,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    assert!(
+        &input[8..10] != b"MS",
+        "{file} wasn't written by the C++ implementation"
+    );
+
+    let mut output = Vec::new();
     decode_lepton(&mut Cursor::new(input), &mut output, 8).unwrap();
 
     assert!(output[..] == expected[..]);
 }
 
+/// versions and file types we can't decode are reported as such rather than as a corrupt file
+#[rstest]
+fn verify_decode_unsupported_version(#[values((2, 2), (3, b'Y'))] patch: (usize, u8)) {
+    let mut input = read_file("android", ".lep");
+    input[patch.0] = patch.1;
+
+    assert_exception(
+        ExitCode::VersionUnsupported,
+        decode_lepton(&mut Cursor::new(input), &mut Vec::new(), 8),
+    );
+}
+
+/// writer that records how the decoder chunks its output, to make sure we stream rather than
+/// handing over the whole file in one go
+#[derive(Default)]
+struct ChunkRecordingWriter {
+    data: Vec<u8>,
+    chunks: usize,
+}
+
+impl Write for ChunkRecordingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.data.extend_from_slice(buf);
+        self.chunks += 1;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// decodes through a streaming writer and makes sure the result is identical to decoding into memory
+#[rstest]
+fn verify_decode_streaming(
+    #[values(
+        "android",
+        "androidcrop",
+        "androidcropoptions",
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "colorswap",
+        "gray2sf",
+        "grayscale",
+        "hq",
+        "iphone",
+        "iphonecity",
+        "iphonecity_with_16KGarbage",
+        "iphonecity_with_1MGarbage",
+        "iphonecrop",
+        "iphonecrop2",
+        "iphoneprogressive",
+        "iphoneprogressive2",
+        "progressive_late_dht",
+        "out_of_order_dqt",
+        "narrowrst",
+        "nofsync",
+        "slrcity",
+        "slrhills",
+        "slrindoor",
+        "tiny",
+        "trailingrst",
+        "trailingrst2",
+        "trunc"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let mut in_memory = Vec::new();
+    decode_lepton(&mut Cursor::new(&input), &mut in_memory, 8).unwrap();
+
+    let mut streamed = ChunkRecordingWriter::default();
+    decode_lepton(&mut Cursor::new(&input), &mut streamed, 8).unwrap();
+
+    assert!(streamed.chunks > 1, "output should be written in chunks");
+    assert!(streamed.data[..] == in_memory[..]);
+    assert!(streamed.data[..] == expected[..]);
+}
+
+/// reader that hands out the data in small pieces of random size, like a slow download would
+struct TrickleReader {
+    data: Vec<u8>,
+    position: usize,
+    rng: rand::rngs::StdRng,
+}
+
+impl Read for TrickleReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        use rand::Rng;
+
+        let n = self
+            .rng
+            .gen_range(1..=64)
+            .min(buf.len())
+            .min(self.data.len() - self.position);
+        buf[..n].copy_from_slice(&self.data[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+/// decodes from a stream that delivers the input in small random increments, both for existing files and for
+/// ones written with a segment index, and both with and without reading the input ahead on a thread of its own
+#[rstest]
+fn verify_decode_streaming_input(
+    #[values(
+        "android",
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "gray2sf",
+        "iphonecity_with_16KGarbage",
+        "iphonecrop",
+        "iphoneprogressive",
+        "narrowrst",
+        "slrcity",
+        "tiny",
+        "trailingrst",
+        "trunc"
+    )]
+    file: &str,
+    #[values(false, true)] segment_index: bool,
+    #[values(false, true)] prefetch: bool,
+) {
+    use rand::SeedableRng;
+
+    let expected = read_file(file, ".jpg");
+
+    let input = if segment_index {
+        let mut input = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(&expected),
+            &mut Cursor::new(&mut input),
+            8,
+            &EnabledFeatures::all(),
+        )
+        .unwrap();
+        input
+    } else {
+        read_file(file, ".lep")
+    };
+
+    let mut reader = TrickleReader {
+        data: input,
+        position: 0,
+        rng: rand::rngs::StdRng::seed_from_u64(2),
+    };
+
+    let mut output = Vec::new();
+    if prefetch {
+        // little enough read ahead that the prefetcher has to wait for the decoder
+        let features = EnabledFeatures {
+            max_read_ahead: 65536,
+            ..EnabledFeatures::default()
+        };
+        decode_lepton_streaming_prefetch(&mut reader, &mut output, 8, &features).unwrap();
+    } else {
+        decode_lepton_streaming(&mut reader, &mut output, 8).unwrap();
+    }
+
+    assert!(output[..] == expected[..]);
+}
+
+/// decodes into fixed size chunks handed to a callback and makes sure the chunks add up to the original
+#[rstest]
+fn verify_decode_chunked(
+    #[values(
+        "android",
+        "androidcrop",
+        "androidcropoptions",
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "colorswap",
+        "gray2sf",
+        "grayscale",
+        "hq",
+        "iphone",
+        "iphonecity",
+        "iphonecity_with_16KGarbage",
+        "iphonecity_with_1MGarbage",
+        "iphonecrop",
+        "iphonecrop2",
+        "iphoneprogressive",
+        "iphoneprogressive2",
+        "progressive_late_dht",
+        "out_of_order_dqt",
+        "narrowrst",
+        "nofsync",
+        "slrcity",
+        "slrhills",
+        "slrindoor",
+        "tiny",
+        "trailingrst",
+        "trailingrst2",
+        "trunc"
+    )]
+    file: &str,
+) {
+    const CHUNK_SIZE: usize = 16384;
+
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let mut chunks = Vec::new();
+    decode_lepton_chunked(&mut Cursor::new(&input), CHUNK_SIZE, 8, |chunk| {
+        chunks.push(chunk.to_vec());
+        Ok(())
+    })
+    .unwrap();
+
+    let (last, full) = chunks.split_last().unwrap();
+    assert!(full.iter().all(|c| c.len() == CHUNK_SIZE));
+    assert!(!last.is_empty() && last.len() <= CHUNK_SIZE);
+
+    assert!(chunks.concat()[..] == expected[..]);
+}
+
+/// an error returned by the chunk callback stops decoding and is passed back to the caller
+#[test]
+fn verify_decode_chunked_callback_error() {
+    let input = read_file("iphonecity", ".lep");
+
+    let mut calls = 0;
+    let r = decode_lepton_chunked(&mut Cursor::new(&input), 65536, 8, |_| {
+        calls += 1;
+        if calls == 3 {
+            return Err(LeptonError {
+                exit_code: ExitCode::WrapperOutputWriteFailed,
+                message: "out of space".to_owned(),
+            });
+        }
+        Ok(())
+    });
+
+    assert_exception(ExitCode::WrapperOutputWriteFailed, r);
+    assert_eq!(calls, 3);
+}
+
+/// the dry run size computation must match the size of the file we actually recreate
+#[rstest]
+fn verify_compute_decoded_size(
+    #[values(
+        "android",
+        "androidcrop",
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "gray2sf",
+        "iphonecity_with_16KGarbage",
+        "iphoneprogressive2",
+        "narrowrst",
+        "nofsync",
+        "slrcity",
+        "tiny",
+        "trailingrst",
+        "trailingrst2",
+        "trunc"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let size = compute_decoded_size(&mut Cursor::new(&input), 8).unwrap();
+
+    assert_eq!(size, expected.len() as u64);
+}
+
+/// baseline images are Huffman encoded in parallel by each decoding thread, starting from the
+/// DC predictors and partial byte recorded in the thread handoffs. Make sure the stitched output is
+/// identical to the original regardless of how many threads share the work.
+#[rstest]
+fn verify_decode_thread_counts(
+    #[values("android", "iphonecity", "slrcity", "trailingrst", "trunc")] file: &str,
+    #[values(1, 2, 3, 5)] num_threads: usize,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let mut output = Vec::new();
+
+    decode_lepton(&mut Cursor::new(input), &mut output, num_threads).unwrap();
+
+    assert!(output[..] == expected[..]);
+}
+
+/// progressive scans are encoded in parallel into separate buffers once all the coefficients are decoded,
+/// and then written out in scan order along with the header segments between them. Make sure the output
+/// is identical to the original regardless of how many threads are used.
+#[rstest]
+fn verify_progressive_decode_thread_counts(
+    #[values(
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "iphoneprogressive",
+        "iphoneprogressive2",
+        "progressive_late_dht"
+    )]
+    file: &str,
+    #[values(1, 2, 3, 8)] num_threads: usize,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let mut output = Vec::new();
+
+    decode_lepton(&mut Cursor::new(input), &mut output, num_threads).unwrap();
+
+    assert!(output[..] == expected[..]);
+}
+
 /// encodes as LEP and codes back to JPG to mostly test the encoder. Can't check against
 /// the original LEP file since there's no guarantee they are binary identical (especially the zlib encoded part)
 #[rstest]
@@ -109,6 +486,7 @@ fn verify_encode(
             "out_of_order_dqt",
             //"narrowrst",
             //"nofsync",
+            "paddedsof",
             "slrcity",
             "slrhills",
             "slrindoor",
@@ -116,6 +494,9 @@ fn verify_encode(
             "trailingrst",
             "trailingrst2",
             "trunc",
+            "missingrst",   // one RST marker in the middle of the scan is missing
+            "redundantrst", // one RST marker in the middle of the scan is repeated
+            "scancomment",  // COM and APP5 segments between restart intervals inside the scan
         )]
     file: &str,
 ) {
@@ -140,41 +521,428 @@ fn verify_encode(
 /// encodes as LEP and codes back to JPG to mostly test the encoder. Can't check against
 /// the original LEP file since there's no guarantee they are binary identical (especially the zlib encoded part)
 #[rstest]
-fn verify_encode_verify(#[values("slrcity")] file: &str) {
+fn verify_encode_verify(
+    #[values(
+        "slrcity",
+        "androidcrop",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "gray2sf",
+        "iphonecity_with_16KGarbage",
+        "iphoneprogressive2",
+        "progressive_late_dht",
+        "tiny",
+        "trailingrst",
+        "trunc",
+        "missingrst",
+        "redundantrst",
+        "scancomment"
+    )]
+    file: &str,
+) {
     let input = read_file(file, ".jpg");
 
-    encode_lepton_verify(&input[..], 8, &EnabledFeatures::all()).unwrap();
-}
+    // the segments are verified while they are encoded, which the output doesn't depend on
+    let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &EnabledFeatures::all()).unwrap();
 
-fn assert_exception(expected_error: ExitCode, result: Result<Metrics, LeptonError>) {
-    match result {
-        Ok(_) => panic!("failure was expected"),
-        Err(e) => {
-            assert_eq!(expected_error, e.exit_code, "unexpected error {0:?}", e);
-        }
-    }
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+
+    assert!(input[..] == output[..]);
 }
 
-/// ensures we error out if we have the progressive flag disabled
+/// the decoder handles the output of the threads interleaved in chunks of any size, from single bytes
+/// to chunks that don't fit into one frame
 #[rstest]
-fn verify_encode_progressive_false(
-    #[values("androidprogressive", "iphoneprogressive", "iphoneprogressive2")] file: &str,
+fn verify_encode_chunk_size(
+    #[values(Some(1), Some(1000), Some(65537), Some(8 * 1024 * 1024), None)] chunk_size: Option<
+        usize,
+    >,
+    #[values(false, true)] segment_index: bool,
 ) {
-    let input = read_file(file, ".jpg");
-    let mut lepton = Vec::new();
-    assert_exception(
-        ExitCode::ProgressiveUnsupported,
-        encode_lepton(
-            &mut Cursor::new(&input),
-            &mut Cursor::new(&mut lepton),
-            8,
-            &EnabledFeatures {
-                progressive: false,
-                ..Default::default()
-            },
-        ),
-    );
-}
+    let input = read_file("iphonecrop", ".jpg");
+
+    let mut lepton = Vec::new();
+    let mut output = Vec::new();
+
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            chunk_size,
+            segment_index,
+            passthrough: false,
+            ..EnabledFeatures::all()
+        },
+    )
+    .unwrap();
+
+    // each single byte chunk comes with a thread id and length
+    if chunk_size == Some(1) {
+        assert!(lepton.len() > input.len() * 2);
+    }
+
+    decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+
+    assert!(input[..] == output[..]);
+}
+
+/// the encoder still produces the conformance vectors that are checked in for the test images. Run with
+/// LEPTON_UPDATE_CONFORMANCE_VECTORS set to regenerate them after an intentional change to the format.
+#[cfg(feature = "conformance")]
+#[rstest]
+fn verify_conformance_vectors(
+    #[values(
+        "android",
+        "androidcrop",
+        "androidcropoptions",
+        "androidprogressive",
+        "androidprogressive_garbage",
+        "androidtrail",
+        "colorswap",
+        "gray2sf",
+        "grayscale",
+        "hq",
+        "iphone",
+        "iphonecity",
+        "iphonecity_with_16KGarbage",
+        "iphonecity_with_1MGarbage",
+        "iphonecrop",
+        "iphonecrop2",
+        "iphoneprogressive",
+        "iphoneprogressive2",
+        "progressive_late_dht",
+        "out_of_order_dqt",
+        "slrcity",
+        "slrhills",
+        "slrindoor",
+        "tiny",
+        "trailingrst",
+        "trailingrst2",
+        "trunc"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let vectors = lepton_jpeg::generate_conformance_vectors(&input, 64).unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&vectors.lepton), &mut output, 8).unwrap();
+    assert!(input[..] == output[..]);
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("images")
+        .join("conformance");
+
+    if std::env::var_os("LEPTON_UPDATE_CONFORMANCE_VECTORS").is_some() {
+        std::fs::create_dir_all(&dir).unwrap();
+        vectors.write_to(&dir, file).unwrap();
+    }
+
+    let expected_json = std::fs::read_to_string(dir.join(file.to_owned() + ".json")).unwrap();
+    assert_eq!(vectors.json, expected_json);
+
+    let expected_prefixes = std::fs::read(dir.join(file.to_owned() + ".bin")).unwrap();
+    assert!(vectors.segment_prefixes == expected_prefixes);
+}
+
+/// the data after the end of the image can be read from the header without decoding the image, and is what
+/// the full decode ends with
+#[rstest]
+fn verify_extract_trailing_data(
+    #[values(
+        "iphonecity",
+        "iphonecity_with_16KGarbage",
+        "iphonecity_with_1MGarbage",
+        "androidprogressive_garbage"
+    )]
+    file: &str,
+) {
+    let lepton = read_file(file, ".lep");
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+
+    let trailing = extract_trailing_data(&lepton).unwrap();
+    assert!(output.ends_with(&trailing));
+
+    match file {
+        "iphonecity" => assert_eq!(trailing.len(), 0),
+        "iphonecity_with_16KGarbage" => assert_eq!(trailing.len(), 16 * 1024),
+        "iphonecity_with_1MGarbage" => assert_eq!(trailing.len(), 1024 * 1024),
+        _ => assert!(!trailing.is_empty()),
+    }
+
+    // a stream only needs to have gotten as far as the end of the header
+    let header_size = inspect_lepton_structure(&lepton).unwrap().header_size as usize;
+    let streamed = extract_trailing_data_streaming(&mut &lepton[..header_size]).unwrap();
+    assert!(streamed == trailing);
+}
+
+/// in deterministic mode the output doesn't depend on the number of threads the encoder was given
+#[rstest]
+fn verify_encode_deterministic(
+    #[values("iphonecity", "android", "iphoneprogressive", "tiny")] file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let encode = |max_threads| {
+        let mut lepton = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&mut lepton),
+            max_threads,
+            &EnabledFeatures {
+                deterministic: true,
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
+        lepton
+    };
+
+    let lepton = encode(1);
+    assert!(encode(4) == lepton);
+    assert!(encode(16) == lepton);
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 1).unwrap();
+    assert!(input[..] == output[..]);
+}
+
+/// in deterministic mode the chunks of the segments are written in the same order however long the workers take
+/// for each of them
+#[cfg(feature = "test-utils")]
+#[test]
+fn verify_deterministic_chunk_order() {
+    use std::time::Duration;
+
+    let input = read_file("iphonecity", ".jpg");
+
+    let encode = || {
+        let mut lepton = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                deterministic: true,
+                chunk_size: Some(4096),
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
+        lepton
+    };
+    let encode_with_delay = |delay: fn(usize, usize) -> Duration| with_chunk_delay(delay, encode);
+
+    let lepton = encode();
+
+    // the first segment falls behind the others, then the others fall behind it, and then they take turns
+    assert!(
+        encode_with_delay(|segment, _| Duration::from_millis(if segment == 0 { 2 } else { 0 }))
+            == lepton
+    );
+    assert!(
+        encode_with_delay(|segment, _| Duration::from_millis(if segment == 0 { 0 } else { 2 }))
+            == lepton
+    );
+    assert!(
+        encode_with_delay(|segment, chunk| Duration::from_millis(((segment + chunk) % 3) as u64))
+            == lepton
+    );
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(input[..] == output[..]);
+}
+
+/// with passthrough enabled, files that don't compress are stored as is and all of them decode back to the original
+#[rstest]
+fn verify_encode_passthrough(
+    #[values("colorswap", "tiny", "android", "iphoneprogressive")] file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            passthrough: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    if file == "colorswap" {
+        // the original plus a fixed size header
+        assert!(lepton.len() > input.len() && lepton.len() < input.len() + 256);
+    } else {
+        assert!(lepton.len() < input.len());
+    }
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(input[..] == output[..]);
+
+    let mut output = Vec::new();
+    decode_lepton_streaming(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(input[..] == output[..]);
+}
+
+fn assert_exception(expected_error: ExitCode, result: Result<Metrics, LeptonError>) {
+    match result {
+        Ok(_) => panic!("failure was expected"),
+        Err(e) => {
+            assert_eq!(expected_error, e.exit_code, "unexpected error {0:?}", e);
+        }
+    }
+}
+
+/// with strict_segment_end the decoder accepts what the encoder writes, but a segment that goes on after the end
+/// of its coded data fails with TrailingGarbageInSegment, which otherwise goes unnoticed
+#[rstest]
+fn verify_strict_segment_end(#[values("iphone", "android", "tiny")] file: &str) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        segment_checksums: false,
+        strict_segment_end: true,
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features,
+    )
+    .unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton_with_features(&mut Cursor::new(&lepton), &mut output, 8, &features).unwrap();
+    assert!(output == input);
+
+    // a chunk with a byte of garbage for the first segment at the end of the multiplexed data
+    let layout = inspect_lepton_structure(&lepton).unwrap();
+    let data_end = layout.header_size
+        + layout
+            .segments
+            .iter()
+            .map(|s| s.compressed_size + s.framing_size)
+            .sum::<u64>();
+    let mut mutated = lepton.clone();
+    mutated.splice(data_end as usize..data_end as usize, [0, 0, 0, 0x55]);
+
+    assert_exception(
+        ExitCode::TrailingGarbageInSegment,
+        decode_lepton_with_features(&mut Cursor::new(&mutated), &mut Vec::new(), 8, &features),
+    );
+
+    let mut output = Vec::new();
+    decode_lepton_with_features(
+        &mut Cursor::new(&mutated),
+        &mut output,
+        8,
+        &EnabledFeatures {
+            strict_segment_end: false,
+            ..features
+        },
+    )
+    .unwrap();
+    assert!(output == input);
+}
+
+/// ensures we error out if we have the progressive flag disabled
+#[rstest]
+fn verify_encode_progressive_false(
+    #[values("androidprogressive", "iphoneprogressive", "iphoneprogressive2")] file: &str,
+) {
+    let input = read_file(file, ".jpg");
+    let mut lepton = Vec::new();
+    assert_exception(
+        ExitCode::ProgressiveUnsupported,
+        encode_lepton(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                progressive: false,
+                ..Default::default()
+            },
+        ),
+    );
+}
+
+/// inputs with nothing in them fail with an error that says what is wrong with them rather than whatever the
+/// parser trips over first
+#[test]
+fn verify_degenerate_inputs() {
+    let encode = |input: &[u8], enabled_features: &EnabledFeatures| {
+        let mut lepton = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(input),
+            &mut Cursor::new(&mut lepton),
+            8,
+            enabled_features,
+        )
+        .unwrap_err()
+        .exit_code
+    };
+
+    let decode = |input: &[u8]| {
+        let mut output = Vec::new();
+        let streaming = decode_lepton_streaming(&mut Cursor::new(input), &mut output, 8)
+            .unwrap_err()
+            .exit_code;
+        assert_eq!(
+            decode_lepton(&mut Cursor::new(input), &mut output, 8)
+                .unwrap_err()
+                .exit_code,
+            streaming
+        );
+        streaming
+    };
+
+    // an empty file is neither a JPEG nor a lepton file
+    let empty = read_file("empty", ".jpg");
+    assert_eq!(
+        encode(&empty, &EnabledFeatures::default()),
+        ExitCode::NotAJpeg
+    );
+    assert_eq!(decode(&empty), ExitCode::NotLepton);
+    assert_eq!(
+        inspect_lepton_structure(&empty).unwrap_err().exit_code,
+        ExitCode::NotLepton
+    );
+
+    // just an SOI and EOI has no image to encode, even if the original could be stored as is
+    let soi_eoi = read_file("soi_eoi", ".jpg");
+    assert_eq!(
+        encode(&soi_eoi, &EnabledFeatures::default()),
+        ExitCode::NoScanData
+    );
+    assert_eq!(
+        encode(&soi_eoi, &EnabledFeatures::all()),
+        ExitCode::NoScanData
+    );
+    assert_eq!(decode(&soi_eoi), ExitCode::NotLepton);
+
+    // the encoder never writes a file without thread segments, so one that has none is damaged
+    let zero_segments = read_file("zero_segments", ".lep");
+    assert_eq!(decode(&zero_segments), ExitCode::BadLeptonFile);
+    assert_eq!(
+        decode_lepton_lenient(&mut Cursor::new(&zero_segments), &mut Vec::new(), 8)
+            .unwrap_err()
+            .exit_code,
+        ExitCode::BadLeptonFile
+    );
+}
 
 /// non-optimally zero length encoding progressive JPEGs cannot be recreated properly since the encoder always tries to create the longest zero runs
 /// legally allowed given the available huffman codes.
@@ -216,6 +984,13 @@ fn extern_interface() {
         assert_eq!(retval, 0);
     }
 
+    // the file is one that the decoders from before the format versions can read
+    let lepton = &compressed[..result_size as usize];
+    assert_eq!(u32::from_le_bytes(lepton[14..18].try_into().unwrap()), 0);
+    assert!(read_encoder_info(&mut Cursor::new(lepton))
+        .unwrap()
+        .is_none());
+
     let mut original = Vec::new();
     original.resize(input.len() + 10000, 0);
 
@@ -235,3 +1010,2487 @@ fn extern_interface() {
     assert_eq!(input.len() as u64, original_size);
     assert_eq!(input[..], original[..(original_size as usize)]);
 }
+
+/// the options of the extern interface reach the encoder, and the decoder doesn't need them
+#[test]
+fn extern_interface_with_options() {
+    let input = read_file("android", ".jpg");
+
+    let compress = |compression_effort, format_version| {
+        let mut compressed = vec![0; input.len() + 10000];
+        let mut result_size: u64 = 0;
+        let options = WrapperCompressOptions {
+            compression_effort,
+            format_version,
+        };
+
+        let retval = unsafe {
+            WrapperCompressImageWithOptions(
+                input[..].as_ptr(),
+                input.len() as u64,
+                compressed[..].as_mut_ptr(),
+                compressed.len() as u64,
+                8,
+                &options,
+                (&mut result_size) as *mut u64,
+            )
+        };
+
+        compressed.truncate(result_size as usize);
+        (retval, compressed)
+    };
+
+    let (retval, fast) = compress(0, 7);
+    assert_eq!(retval, 0);
+    let (retval, default) = compress(WrapperCompressOptions::default().compression_effort, 7);
+    assert_eq!(retval, 0);
+    assert!(fast[..] != default[..]);
+
+    let mut original = vec![0; input.len()];
+    let mut original_size: u64 = 0;
+    unsafe {
+        let retval = WrapperDecompressImage(
+            fast[..].as_ptr(),
+            fast.len() as u64,
+            original[..].as_mut_ptr(),
+            original.len() as u64,
+            8,
+            (&mut original_size) as *mut u64,
+        );
+
+        assert_eq!(retval, 0);
+    }
+    assert!(original[..] == input[..]);
+
+    assert_eq!(compress(3, 7).0, ExitCode::SyntaxError as i32);
+    assert_eq!(compress(1, 8).0, ExitCode::SyntaxError as i32);
+
+    // the fast compression effort needs a newer version than the default one
+    assert_eq!(
+        compress(0, WrapperCompressOptions::default().format_version).0,
+        ExitCode::FeatureRequiresNewerVersion as i32
+    );
+}
+
+/// an output buffer that is too small fails with BufferTooSmall and reports the size needed
+#[test]
+fn extern_interface_buffer_too_small() {
+    let input = read_file("android", ".jpg");
+    let compressed = read_file("android", ".lep");
+
+    let mut original = vec![0; input.len() - 1];
+
+    let mut original_size: u64 = 0;
+    unsafe {
+        let retval = WrapperDecompressImage(
+            compressed[..].as_ptr(),
+            compressed.len() as u64,
+            original[..].as_mut_ptr(),
+            original.len() as u64,
+            8,
+            (&mut original_size) as *mut u64,
+        );
+
+        assert_eq!(retval, ExitCode::BufferTooSmall as i32);
+    }
+    assert_eq!(input.len() as u64, original_size);
+}
+
+#[test]
+fn extern_interface_encoder_info() {
+    let input = read_file("android", ".jpg");
+
+    let mut compressed = vec![0; input.len() + 10000];
+
+    // the encoder info and the size are only written in the newer versions of the format
+    let options = WrapperCompressOptions {
+        format_version: 7,
+        ..WrapperCompressOptions::default()
+    };
+
+    let mut result_size: u64 = 0;
+    unsafe {
+        let retval = WrapperCompressImageWithOptions(
+            input[..].as_ptr(),
+            input.len() as u64,
+            compressed[..].as_mut_ptr(),
+            compressed.len() as u64,
+            8,
+            &options,
+            (&mut result_size) as *mut u64,
+        );
+
+        assert_eq!(retval, 0);
+    }
+
+    let mut version = [0xffu8; 64];
+    let mut git_revision = [0xffu8; 64];
+    let mut enabled_features: u32 = 0;
+    unsafe {
+        let retval = WrapperGetEncoderInfo(
+            compressed[..].as_ptr(),
+            result_size,
+            version[..].as_mut_ptr(),
+            version.len() as u64,
+            git_revision[..].as_mut_ptr(),
+            git_revision.len() as u64,
+            (&mut enabled_features) as *mut u32,
+        );
+
+        assert_eq!(retval, 0);
+    }
+
+    let version_len = version.iter().position(|&b| b == 0).unwrap();
+    assert_eq!(
+        &version[..version_len],
+        env!("CARGO_PKG_VERSION").as_bytes()
+    );
+    assert!(git_revision.contains(&0));
+    assert_eq!(enabled_features, 43);
+
+    // files written before the encoder info was recorded return empty strings
+    let compressed = read_file("android", ".lep");
+    let mut version = [0xffu8; 4];
+    unsafe {
+        let retval = WrapperGetEncoderInfo(
+            compressed[..].as_ptr(),
+            compressed.len() as u64,
+            version[..].as_mut_ptr(),
+            version.len() as u64,
+            git_revision[..].as_mut_ptr(),
+            git_revision.len() as u64,
+            (&mut enabled_features) as *mut u32,
+        );
+
+        assert_eq!(retval, 0);
+    }
+    assert_eq!(version[0], 0);
+    assert_eq!(git_revision[0], 0);
+    assert_eq!(enabled_features, 0);
+}
+
+/// the size of the original file can be read from the header without decoding
+#[test]
+fn extern_interface_original_file_size() {
+    let input = read_file("android", ".jpg");
+
+    let mut compressed = vec![0; input.len() + 10000];
+
+    // the encoder info and the size are only written in the newer versions of the format
+    let options = WrapperCompressOptions {
+        format_version: 7,
+        ..WrapperCompressOptions::default()
+    };
+
+    let mut result_size: u64 = 0;
+    unsafe {
+        let retval = WrapperCompressImageWithOptions(
+            input[..].as_ptr(),
+            input.len() as u64,
+            compressed[..].as_mut_ptr(),
+            compressed.len() as u64,
+            8,
+            &options,
+            (&mut result_size) as *mut u64,
+        );
+
+        assert_eq!(retval, 0);
+    }
+
+    let mut original_file_size: u64 = 0;
+    unsafe {
+        let retval = WrapperGetOriginalFileSize(
+            compressed[..].as_ptr(),
+            result_size,
+            (&mut original_file_size) as *mut u64,
+        );
+
+        assert_eq!(retval, 0);
+    }
+    assert_eq!(original_file_size, input.len() as u64);
+    assert_eq!(
+        read_original_file_size(&mut Cursor::new(&compressed[..result_size as usize])).unwrap(),
+        Some(input.len() as u64)
+    );
+
+    // files written before the size was recorded return zero
+    let compressed = read_file("android", ".lep");
+    unsafe {
+        let retval = WrapperGetOriginalFileSize(
+            compressed[..].as_ptr(),
+            compressed.len() as u64,
+            (&mut original_file_size) as *mut u64,
+        );
+
+        assert_eq!(retval, 0);
+    }
+    assert_eq!(original_file_size, 0);
+    assert_eq!(
+        read_original_file_size(&mut Cursor::new(&compressed)).unwrap(),
+        None
+    );
+}
+
+/// the message of an error in the scan data says where in the file it was found
+#[test]
+fn extern_interface_error_message() {
+    let mut input = read_file("android", ".jpg");
+
+    // an invalid Huffman code in the middle of the scan data, which starts after the SOS segment
+    let sos = input.windows(2).position(|w| w == [0xff, 0xda]).unwrap();
+    let scan_start = sos + 2 + usize::from(u16::from_be_bytes([input[sos + 2], input[sos + 3]]));
+    let mut offset = (scan_start + input.len()) / 2;
+    while input[offset - 1] == 0xff {
+        offset += 1;
+    }
+    for i in 0..6 {
+        input[offset + i * 2..offset + i * 2 + 2].copy_from_slice(&[0xff, 0x00]);
+    }
+
+    let mut compressed = vec![0; input.len() + 10000];
+
+    let mut result_size: u64 = 0;
+    unsafe {
+        let retval = WrapperCompressImage(
+            input[..].as_ptr(),
+            input.len() as u64,
+            compressed[..].as_mut_ptr(),
+            compressed.len() as u64,
+            8,
+            (&mut result_size) as *mut u64,
+        );
+
+        assert_eq!(retval, ExitCode::UnsupportedJpeg as i32);
+    }
+
+    let mut message = [0xffu8; 256];
+    unsafe {
+        let retval = WrapperGetLastErrorMessage(message[..].as_mut_ptr(), message.len() as u64);
+
+        assert_eq!(retval, 0);
+    }
+
+    let message_len = message.iter().position(|&b| b == 0).unwrap();
+    let message = std::str::from_utf8(&message[..message_len]).unwrap();
+    assert!(message.starts_with("UnsupportedJpeg: "), "{0}", message);
+
+    let location = "CorruptScan { byte_offset: ";
+    let byte_offset: usize = message[message.find(location).unwrap() + location.len()..]
+        .split(',')
+        .next()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(
+        byte_offset >= offset && byte_offset <= offset + 12,
+        "{0} at offset {1}",
+        message,
+        offset
+    );
+
+    // a message that doesn't fit is cut off
+    let mut short = [0xffu8; 8];
+    unsafe {
+        WrapperGetLastErrorMessage(short[..].as_mut_ptr(), short.len() as u64);
+    }
+    assert_eq!(&short[..], b"Unsuppo\0");
+}
+
+/// the message of an error that a decoder worker ran into says which segment and rows it was decoding
+#[test]
+fn extern_interface_error_message_segment() {
+    let input = read_file("iphonecity", ".jpg");
+
+    // without checksums the damage is only found by the decoder of the segment
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            target_segments: Some(4),
+            segment_checksums: false,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    let layout = inspect_lepton_structure(&lepton).unwrap();
+    assert_eq!(layout.segments.len(), 4);
+
+    // flip the start of the first chunk of segment 2, walking the three byte chunk headers after the header
+    let mut position = layout.header_size as usize;
+    while lepton[position] != 2 {
+        position +=
+            3 + usize::from(u16::from_le_bytes([
+                lepton[position + 1],
+                lepton[position + 2],
+            ])) + 1;
+    }
+    for b in &mut lepton[position + 3..position + 3 + 32] {
+        *b = !*b;
+    }
+
+    let mut output = vec![0u8; input.len()];
+    let mut result_size: u64 = 0;
+    unsafe {
+        let retval = WrapperDecompressImage(
+            lepton[..].as_ptr(),
+            lepton.len() as u64,
+            output[..].as_mut_ptr(),
+            output.len() as u64,
+            4,
+            (&mut result_size) as *mut u64,
+        );
+
+        assert!(retval > 0);
+    }
+
+    let mut message = [0xffu8; 1024];
+    unsafe {
+        WrapperGetLastErrorMessage(message[..].as_mut_ptr(), message.len() as u64);
+    }
+
+    let message_len = message.iter().position(|&b| b == 0).unwrap();
+    let message = std::str::from_utf8(&message[..message_len]).unwrap();
+    let location = format!(
+        "Segment {{ index: 2, luma_y_start: {0}, luma_y_end: {1} }}",
+        layout.segments[2].luma_y_start, layout.segments[2].luma_y_end
+    );
+    assert!(message.contains(&location), "{0}", message);
+}
+
+/// several images stored in one container can be decoded individually
+#[test]
+fn container_encode_many() {
+    let files = ["android", "iphone", "trailingrst"];
+    let inputs: Vec<Vec<u8>> = files.iter().map(|f| read_file(f, ".jpg")).collect();
+    let input_refs: Vec<&[u8]> = inputs.iter().map(|i| &i[..]).collect();
+
+    let container = encode_many(&input_refs[..], 8, &EnabledFeatures::default()).unwrap();
+    assert!(is_container(&container));
+    assert!(!is_container(&read_file("android", ".lep")));
+
+    // decode out of order to make sure the entries don't depend on each other
+    for i in [2, 0, 1] {
+        assert!(decode_entry(&container, i, 8).unwrap() == inputs[i]);
+    }
+
+    let named: Vec<(&str, &[u8])> = files.iter().copied().zip(input_refs).collect();
+    let container = encode_many_named(&named[..], 8, &EnabledFeatures::default()).unwrap();
+
+    let entries = read_container_entries(&container).unwrap();
+    let iphone = entries
+        .iter()
+        .position(|e| e.name_hash == ContainerEntry::hash_name("iphone"))
+        .unwrap();
+    assert!(decode_entry(&container, iphone, 8).unwrap() == inputs[1]);
+
+    // the container isn't a lepton file by itself
+    let mut output = Vec::new();
+    let e = decode_lepton(&mut Cursor::new(&container), &mut output, 8).unwrap_err();
+    assert_eq!(e.exit_code, ExitCode::VersionUnsupported);
+}
+
+/// appends each chunk to the Vec<u8> passed as context, failing once the output gets bigger than 1MB
+extern "C" fn append_chunk(context: *mut std::ffi::c_void, data: *const u8, size: u64) -> i32 {
+    let output = unsafe { &mut *(context as *mut Vec<u8>) };
+    if output.len() + size as usize > 1024 * 1024 {
+        return 1;
+    }
+
+    output.extend_from_slice(unsafe { std::slice::from_raw_parts(data, size as usize) });
+    0
+}
+
+#[test]
+fn extern_interface_chunked() {
+    for (file, expected_retval) in [
+        ("android", 0),
+        ("slrcity", ExitCode::WrapperOutputWriteFailed as i32),
+    ] {
+        let input = read_file(file, ".lep");
+        let expected = read_file(file, ".jpg");
+
+        let mut output = Vec::<u8>::new();
+
+        let retval = unsafe {
+            WrapperDecompressImageChunked(
+                input[..].as_ptr(),
+                input.len() as u64,
+                65536,
+                8,
+                append_chunk,
+                (&mut output) as *mut Vec<u8> as *mut std::ffi::c_void,
+            )
+        };
+
+        assert_eq!(retval, expected_retval);
+        if retval == 0 {
+            assert_eq!(output[..], expected[..]);
+        }
+    }
+}
+
+/// standard luminance DC huffman table from Annex K of the JPEG spec (bit counts followed by values)
+const STD_DC_BITS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+const STD_DC_VALUES: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+/// standard luminance AC huffman table from Annex K of the JPEG spec
+const STD_AC_BITS: [u8; 16] = [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7d];
+const STD_AC_VALUES: [u8; 162] = [
+    0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06, 0x13, 0x51, 0x61, 0x07,
+    0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xa1, 0x08, 0x23, 0x42, 0xb1, 0xc1, 0x15, 0x52, 0xd1, 0xf0,
+    0x24, 0x33, 0x62, 0x72, 0x82, 0x09, 0x0a, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x25, 0x26, 0x27, 0x28,
+    0x29, 0x2a, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4a, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6a, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+    0x8a, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7,
+    0xa8, 0xa9, 0xaa, 0xb2, 0xb3, 0xb4, 0xb5, 0xb6, 0xb7, 0xb8, 0xb9, 0xba, 0xc2, 0xc3, 0xc4, 0xc5,
+    0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xe1, 0xe2,
+    0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8,
+    0xf9, 0xfa,
+];
+
+/// builds the canonical (code, length) pairs indexed by symbol for a huffman table
+fn build_huffman_codes(bits: &[u8; 16], values: &[u8]) -> [(u16, u8); 256] {
+    let mut codes = [(0u16, 0u8); 256];
+    let mut code = 0u16;
+    let mut k = 0;
+    for len in 1..=16 {
+        for _ in 0..bits[len - 1] {
+            codes[usize::from(values[k])] = (code, len as u8);
+            code += 1;
+            k += 1;
+        }
+        code <<= 1;
+    }
+    codes
+}
+
+/// minimal huffman bit writer with 0xff byte stuffing used to synthesize test images
+struct SyntheticScanWriter {
+    output: Vec<u8>,
+    current: u32,
+    bits: u32,
+}
+
+impl SyntheticScanWriter {
+    fn put(&mut self, value: u16, len: u8) {
+        for i in (0..len).rev() {
+            self.current = (self.current << 1) | u32::from((value >> i) & 1);
+            self.bits += 1;
+            if self.bits == 8 {
+                self.output.push(self.current as u8);
+                if self.current == 0xff {
+                    self.output.push(0);
+                }
+                self.current = 0;
+                self.bits = 0;
+            }
+        }
+    }
+
+    /// writes the huffman code for the category of the value followed by the value bits
+    fn put_coef(&mut self, codes: &[(u16, u8); 256], run: u8, coef: i16) {
+        let category = (16 - coef.unsigned_abs().leading_zeros()) as u8;
+        let (code, len) = codes[usize::from((run << 4) | category)];
+        self.put(code, len);
+        let bits = if coef < 0 { coef - 1 } else { coef } as u16;
+        self.put(bits & ((1u16 << category) - 1), category);
+    }
+
+    /// pads out the current byte with the low bits of fill (most significant first)
+    fn pad(&mut self, fill: u8) {
+        if self.bits != 0 {
+            let num_bits = 8 - self.bits as u8;
+            self.put(u16::from(fill) & ((1 << num_bits) - 1), num_bits);
+        }
+    }
+}
+
+fn push_segment(output: &mut Vec<u8>, marker: u8, contents: &[u8]) {
+    output.extend_from_slice(&[0xff, marker]);
+    output.extend_from_slice(&((contents.len() + 2) as u16).to_be_bytes());
+    output.extend_from_slice(contents);
+}
+
+/// description of a synthetic baseline JPEG used to exercise corner cases that we don't have sample images for
+struct SyntheticJpeg<'a> {
+    width: u16,
+    height: u16,
+    /// sampling factors (h,v) per component
+    sampling: &'a [(u8, u8)],
+    /// components coded in each scan, in scan order
+    scans: Vec<Vec<usize>>,
+    /// number of MCUs between restart markers, zero for none
+    restart_interval: u16,
+    /// returns the fill bits used to pad the nth restart interval (counting across all scans)
+    fill_bits: fn(usize) -> u8,
+    seed: u64,
+}
+
+impl<'a> SyntheticJpeg<'a> {
+    fn new(width: u16, height: u16, sampling: &'a [(u8, u8)], interleaved: bool) -> Self {
+        SyntheticJpeg {
+            width,
+            height,
+            sampling,
+            scans: if interleaved {
+                vec![(0..sampling.len()).collect()]
+            } else {
+                (0..sampling.len()).map(|c| vec![c]).collect()
+            },
+            restart_interval: 0,
+            // pad with ones like libjpeg does
+            fill_bits: |_| 0xff,
+            seed: 0,
+        }
+    }
+
+    /// synthesizes the JPEG. Every coded block gets random coefficients, including the blocks that pad out
+    /// partial MCUs on the right and bottom edges, since real encoders put (smoothed) image data there rather than zeros.
+    fn build(&self) -> Vec<u8> {
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        let (width, height, sampling) = (self.width, self.height, self.sampling);
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        let dc_codes = build_huffman_codes(&STD_DC_BITS, &STD_DC_VALUES);
+        let ac_codes = build_huffman_codes(&STD_AC_BITS, &STD_AC_VALUES);
+
+        let mut output = vec![0xff, 0xd8];
+
+        let mut dqt = vec![0u8];
+        dqt.extend((0..64).map(|i| 1 + (i / 8) as u8));
+        push_segment(&mut output, 0xdb, &dqt);
+
+        let mut sof = vec![8];
+        sof.extend_from_slice(&height.to_be_bytes());
+        sof.extend_from_slice(&width.to_be_bytes());
+        sof.push(sampling.len() as u8);
+        for (i, (h, v)) in sampling.iter().enumerate() {
+            sof.extend_from_slice(&[i as u8 + 1, (h << 4) | v, 0]);
+        }
+        push_segment(&mut output, 0xc0, &sof);
+
+        let mut dht = vec![0x00];
+        dht.extend_from_slice(&STD_DC_BITS);
+        dht.extend_from_slice(&STD_DC_VALUES);
+        dht.push(0x10);
+        dht.extend_from_slice(&STD_AC_BITS);
+        dht.extend_from_slice(&STD_AC_VALUES);
+        push_segment(&mut output, 0xc4, &dht);
+
+        if self.restart_interval != 0 {
+            push_segment(&mut output, 0xdd, &self.restart_interval.to_be_bytes());
+        }
+
+        let hmax = u32::from(sampling.iter().map(|x| x.0).max().unwrap());
+        let vmax = u32::from(sampling.iter().map(|x| x.1).max().unwrap());
+        let mcuh = (u32::from(width) + 8 * hmax - 1) / (8 * hmax);
+        let mcuv = (u32::from(height) + 8 * vmax - 1) / (8 * vmax);
+
+        let mut write_block = |scan: &mut SyntheticScanWriter, last_dc: &mut i16| {
+            let dc = rng.gen_range(-30..=30);
+            scan.put_coef(&dc_codes, 0, dc - *last_dc);
+            *last_dc = dc;
+
+            let mut run = 0;
+            for _ in 1..64 {
+                // mostly zeros so that we get runs of all lengths including ZRL
+                let coef: i16 = if rng.gen_range(0..4) == 0 {
+                    rng.gen_range(-6..=6)
+                } else {
+                    0
+                };
+                if coef == 0 {
+                    run += 1;
+                    continue;
+                }
+                while run >= 16 {
+                    scan.put(ac_codes[0xf0].0, ac_codes[0xf0].1);
+                    run -= 16;
+                }
+                scan.put_coef(&ac_codes, run, coef);
+                run = 0;
+            }
+            if run > 0 {
+                scan.put(ac_codes[0].0, ac_codes[0].1);
+            }
+        };
+
+        let mut pad_index = 0;
+
+        for components in &self.scans {
+            let mut sos = vec![components.len() as u8];
+            for &c in components {
+                sos.extend_from_slice(&[c as u8 + 1, 0x00]);
+            }
+            sos.extend_from_slice(&[0, 63, 0]);
+            push_segment(&mut output, 0xda, &sos);
+
+            let mut scan = SyntheticScanWriter {
+                output: Vec::new(),
+                current: 0,
+                bits: 0,
+            };
+
+            // non-interleaved scans only code the blocks that cover the component, not the whole MCU,
+            // and each block counts as an MCU for the restart interval
+            let num_mcus = if components.len() == 1 {
+                let (h, v) = sampling[components[0]];
+                let blocks_h = (u32::from(width) * u32::from(h) + 8 * hmax - 1) / (8 * hmax);
+                let blocks_v = (u32::from(height) * u32::from(v) + 8 * vmax - 1) / (8 * vmax);
+                blocks_h * blocks_v
+            } else {
+                mcuh * mcuv
+            };
+
+            let mut last_dc = [0i16; 4];
+            for mcu in 0..num_mcus {
+                if self.restart_interval != 0
+                    && mcu != 0
+                    && mcu % u32::from(self.restart_interval) == 0
+                {
+                    scan.pad((self.fill_bits)(pad_index));
+                    pad_index += 1;
+
+                    let rst = (mcu / u32::from(self.restart_interval) - 1) % 8;
+                    scan.output.extend_from_slice(&[0xff, 0xd0 + rst as u8]);
+                    last_dc = [0; 4];
+                }
+
+                if components.len() == 1 {
+                    write_block(&mut scan, &mut last_dc[components[0]]);
+                } else {
+                    for &c in components {
+                        let (h, v) = sampling[c];
+                        for _ in 0..h * v {
+                            write_block(&mut scan, &mut last_dc[c]);
+                        }
+                    }
+                }
+            }
+
+            scan.pad((self.fill_bits)(pad_index));
+            pad_index += 1;
+
+            output.extend(scan.output);
+        }
+
+        output.extend_from_slice(&[0xff, 0xd9]);
+        output
+    }
+}
+
+/// images whose dimensions aren't a multiple of the MCU size have padding blocks on the right and bottom
+/// that are coded (with real coefficients) in interleaved scans but skipped in non-interleaved scans.
+/// Walk every residue mod 16 of the width and height for each sampling geometry and make sure the
+/// image round trips exactly.
+#[rstest]
+fn verify_partial_mcu_edges(
+    #[values(
+        &[(1, 1)][..],
+        &[(2, 2)][..],
+        &[(1, 1), (1, 1), (1, 1)][..],
+        &[(2, 1), (1, 1), (1, 1)][..],
+        &[(1, 2), (1, 1), (1, 1)][..],
+        &[(2, 2), (1, 1), (1, 1)][..],
+        &[(1, 1), (2, 2), (1, 1)][..]
+    )]
+    sampling: &[(u8, u8)],
+    #[values(true, false)] interleaved: bool,
+) {
+    for residue in 0..16u16 {
+        for (width, height) in [
+            (32 + residue, 40),
+            (40, 32 + residue),
+            (16 + residue, 16 + residue),
+            (129 + residue, 257 + residue),
+        ] {
+            let input = SyntheticJpeg {
+                seed: residue.into(),
+                ..SyntheticJpeg::new(width, height, sampling, interleaved)
+            }
+            .build();
+
+            let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &EnabledFeatures::all())
+                .unwrap_or_else(|e| {
+                    panic!("{width}x{height} {sampling:?} interleaved={interleaved} failed {e:?}")
+                });
+
+            let mut output = Vec::new();
+            decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+
+            assert!(
+                input[..] == output[..],
+                "{width}x{height} {sampling:?} interleaved={interleaved} mismatch"
+            );
+        }
+    }
+}
+
+/// sequential images can split the components across several scans, some of which may be interleaved.
+/// All the scans need to be recreated in their original order along with the segments between them.
+#[rstest]
+fn verify_multi_scan_sequential(
+    #[values(
+        &[&[0][..], &[1], &[2]][..],
+        &[&[0][..], &[1, 2]][..],
+        &[&[0, 1][..], &[2]][..],
+        &[&[1][..], &[0, 2]][..]
+    )]
+    scans: &[&[usize]],
+    #[values(0, 3)] restart_interval: u16,
+) {
+    let input = SyntheticJpeg {
+        scans: scans.iter().map(|s| s.to_vec()).collect(),
+        restart_interval,
+        ..SyntheticJpeg::new(61, 45, &[(2, 2), (1, 1), (1, 1)], false)
+    }
+    .build();
+
+    let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &EnabledFeatures::all()).unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+
+    assert!(input[..] == output[..]);
+}
+
+/// some encoders pad with zeros rather than ones, or even mix them (or arbitrary patterns) from one
+/// restart interval to the next. Make sure the exact fill bits are reproduced.
+#[rstest]
+fn verify_pad_bits(
+    #[values(
+        |_| 0xff,
+        |_| 0,
+        |i| if i % 2 == 0 { 0xff } else { 0 },
+        |i| if i % 3 == 1 { 0 } else { 0xff },
+        |i| (i * 37) as u8
+    )]
+    fill_bits: fn(usize) -> u8,
+    #[values(0, 1, 3)] restart_interval: u16,
+    #[values(true, false)] interleaved: bool,
+) {
+    let input = SyntheticJpeg {
+        restart_interval,
+        fill_bits,
+        ..SyntheticJpeg::new(61, 45, &[(2, 2), (1, 1), (1, 1)], interleaved)
+    }
+    .build();
+
+    let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &EnabledFeatures::all()).unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+
+    assert!(input[..] == output[..]);
+}
+
+/// intact files come out of the lenient decoder exactly like the original without any damage reported
+#[rstest]
+fn verify_decode_lenient(
+    #[values(
+        "android",
+        "androidcrop",
+        "androidcropoptions",
+        "colorswap",
+        "gray2sf",
+        "grayscale",
+        "hq",
+        "iphone",
+        "iphonecity",
+        "iphonecity_with_16KGarbage",
+        "iphonecity_with_1MGarbage",
+        "iphonecrop",
+        "iphonecrop2",
+        "out_of_order_dqt",
+        "narrowrst",
+        "nofsync",
+        "slrcity",
+        "slrhills",
+        "slrindoor",
+        "tiny",
+        "trailingrst",
+        "trailingrst2",
+        "trunc"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let mut output = Vec::new();
+    let report = decode_lepton_lenient(&mut Cursor::new(input), &mut output, 8).unwrap();
+
+    assert_eq!(report.damaged_mcu_rows, Vec::new());
+    assert_ne!(report.checksum_matches, Some(false));
+    assert!(output[..] == expected[..]);
+}
+
+/// rewriting the header copies the coded data through unchanged and the file still decodes to the same JPEG,
+/// both for the checked in files from older versions and for files with all the optional parts
+#[rstest]
+fn verify_rewrite_header(
+    #[values("iphonecity", "iphoneprogressive", "android", "trunc", "tiny")] file: &str,
+    #[values(false, true)] reencode: bool,
+) {
+    let jpeg = read_file(file, ".jpg");
+
+    let lepton = if reencode {
+        let mut lepton = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(&jpeg),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &EnabledFeatures {
+                segment_index: true,
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
+        lepton
+    } else {
+        read_file(file, ".lep")
+    };
+
+    // the multiplexed data of the thread segments, which is all the decoder reads the coefficients from
+    let coded_data = |lepton: &[u8]| {
+        let layout = inspect_lepton_structure(lepton).unwrap();
+        let start = layout.header_size as usize;
+        let len: u64 = layout
+            .segments
+            .iter()
+            .map(|s| s.compressed_size + s.framing_size)
+            .sum();
+        lepton[start..start + len as usize].to_vec()
+    };
+
+    let edits = [
+        HeaderEdit::default(),
+        HeaderEdit {
+            recompute_segment_checksums: true,
+            original_file_size: Some(jpeg.len() as u64),
+            ..HeaderEdit::default()
+        },
+        HeaderEdit {
+            remove_original_file_crc: true,
+            remove_original_file_size: true,
+            remove_encoder_info: true,
+            ..HeaderEdit::default()
+        },
+        HeaderEdit {
+            remove_segment_index: true,
+            remove_segment_checksums: true,
+            ..HeaderEdit::default()
+        },
+    ];
+
+    for edit in edits {
+        let rewritten = rewrite_header(&lepton, edit.clone()).unwrap();
+        assert!(coded_data(&rewritten) == coded_data(&lepton));
+
+        let mut output = Vec::new();
+        decode_lepton(&mut Cursor::new(&rewritten), &mut output, 8).unwrap();
+        assert!(output == jpeg);
+
+        let original_file_size = read_original_file_size(&mut Cursor::new(&rewritten)).unwrap();
+        if edit.remove_original_file_size {
+            assert_eq!(original_file_size, None);
+        } else if edit.original_file_size.is_some() {
+            assert_eq!(original_file_size, edit.original_file_size);
+        }
+
+        let layout = inspect_lepton_structure(&rewritten).unwrap();
+        if edit.recompute_segment_checksums {
+            assert_eq!(
+                layout.segment_checksums_size,
+                4 * layout.segments.len() as u64
+            );
+        }
+        if edit.remove_segment_checksums {
+            assert_eq!(layout.segment_checksums_size, 0);
+        }
+        if edit.remove_segment_index {
+            assert_eq!(layout.segment_index_size, 0);
+        }
+    }
+
+    // edits that would change what the file decodes to are refused
+    let refused = |edit: HeaderEdit| rewrite_header(&lepton, edit).unwrap_err().exit_code;
+
+    assert_eq!(
+        refused(HeaderEdit {
+            original_file_size: Some(jpeg.len() as u64 + 1),
+            ..HeaderEdit::default()
+        }),
+        ExitCode::SyntaxError
+    );
+    assert_eq!(
+        refused(HeaderEdit {
+            recompute_segment_checksums: true,
+            remove_segment_checksums: true,
+            ..HeaderEdit::default()
+        }),
+        ExitCode::SyntaxError
+    );
+}
+
+/// the type of the JPEG is recorded in the header of new files, and the checked in files written before that
+/// get the same answer from the JPEG header they store
+#[rstest]
+fn verify_lepton_file_type(
+    #[values("android", "tiny", "iphoneprogressive", "androidprogressive", "trunc")] file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::default(),
+    )
+    .unwrap();
+
+    let file_type = read_lepton_file_type(&mut Cursor::new(&lepton)).unwrap();
+    assert_eq!(file_type.progressive, file.contains("progressive"));
+    if file_type.progressive {
+        assert!(file_type.scan_count > 1);
+    } else {
+        assert_eq!(file_type.scan_count, 1);
+    }
+
+    let old_file = read_file(file, ".lep");
+    assert_eq!(
+        read_lepton_file_type(&mut Cursor::new(&old_file)).unwrap(),
+        file_type
+    );
+
+    // the header is all that is needed
+    let header_size = inspect_lepton_structure(&lepton).unwrap().header_size as usize;
+    let mut progressive = -1;
+    let mut scan_count = 0;
+    unsafe {
+        let retval = WrapperGetLeptonFileType(
+            lepton[..header_size].as_ptr(),
+            header_size as u64,
+            &mut progressive,
+            &mut scan_count,
+        );
+
+        assert_eq!(retval, 0);
+    }
+    assert_eq!(progressive, i32::from(file_type.progressive));
+    assert_eq!(scan_count, file_type.scan_count);
+}
+
+/// sequential images with the components in separate scans are counted as well
+#[test]
+fn verify_lepton_file_type_multi_scan() {
+    let input = SyntheticJpeg {
+        scans: vec![vec![0], vec![1, 2]],
+        ..SyntheticJpeg::new(61, 45, &[(2, 2), (1, 1), (1, 1)], false)
+    }
+    .build();
+
+    let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &EnabledFeatures::all()).unwrap();
+
+    let file_type = read_lepton_file_type(&mut Cursor::new(&lepton)).unwrap();
+    assert!(!file_type.progressive);
+    assert_eq!(file_type.scan_count, 2);
+}
+
+/// a tiny file whose header claims gigabytes of data after the end of the image is refused before anything
+/// is allocated, and the encoder refuses JPEGs with more trailing data than allowed
+#[test]
+fn verify_trailing_data_limit() {
+    let lepton = read_file("huge_garbage", ".lep");
+
+    let mut output = Vec::new();
+    assert_eq!(
+        decode_lepton(&mut Cursor::new(&lepton), &mut output, 8)
+            .unwrap_err()
+            .exit_code,
+        ExitCode::TrailingDataTooLarge
+    );
+    assert_eq!(
+        decode_lepton_streaming(&mut Cursor::new(&lepton), &mut output, 8)
+            .unwrap_err()
+            .exit_code,
+        ExitCode::TrailingDataTooLarge
+    );
+
+    // without a limit the claim is still checked against the size of the header that contains the data
+    assert_eq!(
+        decode_lepton_with_features(
+            &mut Cursor::new(&lepton),
+            &mut output,
+            8,
+            &EnabledFeatures::all()
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::BadLeptonFile
+    );
+
+    let input = read_file("iphonecity_with_16KGarbage", ".jpg");
+    let features = EnabledFeatures {
+        max_trailing_bytes: 1024,
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    assert_eq!(
+        encode_lepton(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&mut lepton),
+            8,
+            &features
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::TrailingDataTooLarge
+    );
+
+    // the same limit applies when decoding a file that was encoded without it
+    let lepton = read_file("iphonecity_with_16KGarbage", ".lep");
+    assert_eq!(
+        decode_lepton_with_features(&mut Cursor::new(&lepton), &mut output, 8, &features)
+            .unwrap_err()
+            .exit_code,
+        ExitCode::TrailingDataTooLarge
+    );
+}
+
+/// in coefficients only mode the file decodes to a clean JPEG with the same image, without the metadata and
+/// the data after the image, and the inspection API shows that the original wasn't kept
+#[rstest]
+fn verify_coefficients_only(
+    #[values(
+        "iphone",
+        "iphoneprogressive",
+        "scancomment",
+        "iphonecity_with_16KGarbage",
+        "narrowrst",
+        "trunc"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        encode_mode: EncodeMode::CoefficientsOnly,
+        ..EnabledFeatures::default()
+    };
+
+    // verification compares the coefficients, since the bytes are different
+    let (lepton, _metrics) = encode_lepton_verify(&input[..], 8, &features).unwrap();
+
+    let layout = inspect_lepton_structure(&lepton).unwrap();
+    assert!(layout.coefficients_only);
+
+    // truncated images keep the last two bytes of the scan in place of the data after the image
+    assert!(layout.garbage_size <= 2);
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert_eq!(output.len() as u64, layout.original_file_size);
+    assert!(output.len() < input.len());
+
+    // only the segments needed to decode the image are left in front of the first scan
+    let mut pos = 2;
+    loop {
+        let marker = output[pos + 1];
+        assert!(
+            matches!(marker, 0xC0..=0xC3 | 0xC4 | 0xDB | 0xDD | 0xDA),
+            "unexpected marker {marker:x}"
+        );
+        if marker == 0xDA {
+            break;
+        }
+        pos += 2 + usize::from(u16::from_be_bytes([output[pos + 2], output[pos + 3]]));
+    }
+
+    // the clean JPEG is a regular JPEG that encodes exactly
+    encode_lepton_verify(&output[..], 8, &EnabledFeatures::default()).unwrap();
+
+    // exact files don't have the flag
+    let (exact, _metrics) =
+        encode_lepton_verify(&input[..], 8, &EnabledFeatures::default()).unwrap();
+    assert!(!inspect_lepton_structure(&exact).unwrap().coefficients_only);
+}
+
+/// the estimate made from a sample of the rows should be close to the size of the full encode. Prints the
+/// mean absolute error over the corpus so that changes to the estimate can be compared.
+#[test]
+fn verify_estimate_compression() {
+    let files = [
+        "android",
+        "androidcrop",
+        "androidprogressive",
+        "gray2sf",
+        "grayscale",
+        "hq",
+        "iphone",
+        "iphonecity",
+        "iphonecrop",
+        "iphoneprogressive",
+        "narrowrst",
+        "slrcity",
+        "slrhills",
+        "slrindoor",
+    ];
+
+    let mut total_error = 0.0;
+    for file in files {
+        let input = read_file(file, ".jpg");
+
+        let (lepton, _metrics) =
+            encode_lepton_verify(&input[..], 8, &EnabledFeatures::default()).unwrap();
+        let estimate = estimate_compression(&input, 16).unwrap();
+
+        assert_eq!(estimate.original_size, input.len() as u64);
+        assert!(estimate.sampled_rows >= 1 && estimate.sampled_rows <= estimate.total_rows);
+        assert!(estimate.lower_bound <= estimate.estimated_size);
+        assert!(estimate.estimated_size <= estimate.upper_bound);
+
+        let error =
+            (estimate.estimated_size as f64 - lepton.len() as f64).abs() / lepton.len() as f64;
+        println!(
+            "{}: actual {} estimated {} ({}..{}) from {}/{} rows, error {:.2}%",
+            file,
+            lepton.len(),
+            estimate.estimated_size,
+            estimate.lower_bound,
+            estimate.upper_bound,
+            estimate.sampled_rows,
+            estimate.total_rows,
+            error * 100.0
+        );
+        total_error += error;
+    }
+
+    let mean_error = total_error / files.len() as f64;
+    println!("mean absolute error {:.2}%", mean_error * 100.0);
+    assert!(mean_error < 0.05);
+
+    // sampling every row encodes the whole image
+    let input = read_file("tiny", ".jpg");
+    let estimate = estimate_compression(&input, 1).unwrap();
+    assert_eq!(estimate.sampled_rows, estimate.total_rows);
+    assert_eq!(estimate.lower_bound, estimate.upper_bound);
+
+    assert_eq!(
+        estimate_compression(&input, 0).unwrap_err().exit_code,
+        ExitCode::SyntaxError
+    );
+}
+
+/// the bits estimated for the blocks of an image add up to about the size of the coded data of a single thread
+/// segment. Prints how far apart they are.
+#[rstest]
+fn verify_estimate_block_bits(
+    #[values(
+        "android",
+        "iphone",
+        "iphonecity",
+        "iphoneprogressive",
+        "gray2sf",
+        "slrindoor"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        1,
+        &EnabledFeatures {
+            target_segments: Some(1),
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    let layout = inspect_lepton_structure(&lepton).unwrap();
+    assert_eq!(layout.segments.len(), 1);
+    let actual_bits = layout.segments[0].compressed_size as f64 * 8.0;
+
+    let maps = estimate_block_bits(&input).unwrap();
+    for map in &maps {
+        assert_eq!(map.bits.len(), (map.width * map.height) as usize);
+        assert!(map.bits.iter().all(|b| *b >= 0.0));
+    }
+
+    let estimated_bits: f64 = maps.iter().map(|m| m.total_bits()).sum();
+    let difference = (estimated_bits - actual_bits) / actual_bits;
+    println!(
+        "{}: actual {} bits, estimated {:.0} bits, difference {:.3}%",
+        file,
+        actual_bits,
+        estimated_bits,
+        difference * 100.0
+    );
+    assert!(difference.abs() < 0.005);
+}
+
+/// files written for an older version of the format only need the features that version has, so a decoder
+/// that only knows those (simulated by decoding with the same format_version) recreates the original
+#[rstest]
+fn verify_format_version(
+    #[values(
+        "iphone",
+        "iphoneprogressive",
+        "android",
+        "iphonecity_with_16KGarbage",
+        "trunc"
+    )]
+    file: &str,
+    #[values(
+        LeptonVersion::V1,
+        LeptonVersion::V2,
+        LeptonVersion::V3,
+        LeptonVersion::V4,
+        LeptonVersion::V5,
+        LeptonVersion::V6,
+        LeptonVersion::V7
+    )]
+    format_version: LeptonVersion,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures::default().with_format_version(format_version);
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features,
+    )
+    .unwrap();
+
+    let required_features = u32::from_le_bytes(lepton[14..18].try_into().unwrap());
+    assert_eq!(required_features & !format_version.supported_features(), 0);
+
+    // the trailers after the compressed header are newer than the original format
+    assert_eq!(
+        read_encoder_info(&mut Cursor::new(&lepton))
+            .unwrap()
+            .is_some(),
+        format_version >= LeptonVersion::V2
+    );
+
+    let mut output = Vec::new();
+    decode_lepton_with_features(&mut Cursor::new(&lepton), &mut output, 8, &features).unwrap();
+    assert!(output[..] == input[..]);
+}
+
+/// the files that were written for each version of the format, with the options that the version added, keep
+/// decoding with the initialization of the model that they were encoded with
+#[rstest]
+fn verify_format_version_fixtures(#[values(1, 2, 3, 4, 5, 6, 7)] version: u32) {
+    let input = read_file("androidcropoptions", ".jpg");
+    let lepton = read_file(&format!("androidcropoptions_v{0}", version), ".lep");
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(output[..] == input[..]);
+}
+
+/// options and images that need a newer version of the format than the one asked for are refused, and so are
+/// files that need features the decoder's version doesn't have
+#[test]
+fn verify_format_version_refused() {
+    let encode = |file: &str, features: &EnabledFeatures| {
+        let mut lepton = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(read_file(file, ".jpg")),
+            &mut Cursor::new(&mut lepton),
+            8,
+            features,
+        )
+        .map(|_| lepton)
+    };
+
+    // options turned on after picking the version
+    let features = EnabledFeatures {
+        checksum: true,
+        ..EnabledFeatures::default().with_format_version(LeptonVersion::V1)
+    };
+    assert_eq!(
+        encode("iphone", &features).unwrap_err().exit_code,
+        ExitCode::FeatureRequiresNewerVersion
+    );
+
+    let features = EnabledFeatures {
+        encode_mode: EncodeMode::CoefficientsOnly,
+        ..EnabledFeatures::default().with_format_version(LeptonVersion::V2)
+    };
+    assert_eq!(
+        encode("iphone", &features).unwrap_err().exit_code,
+        ExitCode::FeatureRequiresNewerVersion
+    );
+
+    // images that need restart exceptions or scan segments
+    let features = EnabledFeatures::default().with_format_version(LeptonVersion::V1);
+    for file in ["missingrst", "scancomment"] {
+        assert_eq!(
+            encode(file, &features).unwrap_err().exit_code,
+            ExitCode::FeatureRequiresNewerVersion
+        );
+    }
+
+    // a file with segment checksums is refused by a decoder that doesn't know about them
+    let lepton = encode("iphone", &EnabledFeatures::default()).unwrap();
+    let mut output = Vec::new();
+    assert_eq!(
+        decode_lepton_with_features(
+            &mut Cursor::new(&lepton),
+            &mut output,
+            8,
+            &EnabledFeatures::default().with_format_version(LeptonVersion::V2)
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::VersionUnsupported
+    );
+}
+
+/// a primer trained on images like the ones being encoded makes them smaller, and the files only decode with
+/// the same primer. The corpus is crops and variants of the same photo, which share the statistics of the
+/// camera and the scene.
+#[test]
+fn verify_model_primer() {
+    let training: Vec<Vec<u8>> = ["androidcrop", "androidcropoptions"]
+        .iter()
+        .map(|file| read_file(file, ".jpg"))
+        .collect();
+    let training: Vec<&[u8]> = training.iter().map(|jpeg| &jpeg[..]).collect();
+
+    let primer = train_primer(&training[..]).unwrap();
+
+    // the primer survives serialization
+    let primer = Arc::new(primer_from_bytes(&primer.to_bytes()).unwrap());
+
+    let primed_features = EnabledFeatures {
+        model_primer: Some(primer.clone()),
+        ..EnabledFeatures::default()
+    };
+
+    let mut total_plain = 0;
+    let mut total_primed = 0;
+    for file in ["android", "androidtrail", "androidprogressive"] {
+        let input = read_file(file, ".jpg");
+
+        let (plain, _) = encode_lepton_verify(&input, 8, &EnabledFeatures::default()).unwrap();
+        let (primed, _) = encode_lepton_verify(&input, 8, &primed_features).unwrap();
+
+        println!(
+            "{0}: {1} bytes without the primer, {2} with it ({3:+.2}%)",
+            file,
+            plain.len(),
+            primed.len(),
+            (primed.len() as f64 / plain.len() as f64 - 1.0) * 100.0
+        );
+        total_plain += plain.len();
+        total_primed += primed.len();
+
+        let mut output = Vec::new();
+        decode_lepton_with_features(&mut Cursor::new(&primed), &mut output, 8, &primed_features)
+            .unwrap();
+        assert!(output[..] == input[..]);
+
+        // without the primer, or with another one, the file can't be decoded
+        assert_eq!(
+            decode_lepton(&mut Cursor::new(&primed), &mut Vec::new(), 8)
+                .unwrap_err()
+                .exit_code,
+            ExitCode::MissingDictionary
+        );
+
+        let other_features = EnabledFeatures {
+            model_primer: Some(Arc::new(train_primer(&[&input[..]]).unwrap())),
+            ..EnabledFeatures::default()
+        };
+        assert_eq!(
+            decode_lepton_with_features(
+                &mut Cursor::new(&primed),
+                &mut Vec::new(),
+                8,
+                &other_features
+            )
+            .unwrap_err()
+            .exit_code,
+            ExitCode::MissingDictionary
+        );
+
+        // a primer doesn't change how files without one decode
+        let mut output = Vec::new();
+        decode_lepton_with_features(&mut Cursor::new(&plain), &mut output, 8, &primed_features)
+            .unwrap();
+        assert!(output[..] == input[..]);
+    }
+
+    println!(
+        "total: {0} bytes without the primer, {1} with it ({2:+.2}%)",
+        total_plain,
+        total_primed,
+        (total_primed as f64 / total_plain as f64 - 1.0) * 100.0
+    );
+    assert!(total_primed < total_plain);
+
+    // older versions of the format don't have primers
+    let features = EnabledFeatures {
+        model_primer: Some(primer),
+        ..EnabledFeatures::default().with_format_version(LeptonVersion::V3)
+    };
+    assert_eq!(
+        encode_lepton(
+            &mut Cursor::new(read_file("android", ".jpg")),
+            &mut Cursor::new(Vec::new()),
+            8,
+            &features
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::FeatureRequiresNewerVersion
+    );
+
+    assert_eq!(
+        train_primer(&[]).unwrap_err().exit_code,
+        ExitCode::SyntaxError
+    );
+}
+
+/// other model tunings round trip and are recorded in the file, while the default one writes the same file as
+/// not setting it at all
+#[cfg(feature = "experimental-tuning")]
+#[rstest]
+fn verify_model_tuning(
+    #[values("android", "iphonecrop2", "androidprogressive", "trunc")] file: &str,
+    #[values((1, 255, 128), (2, 255, 128), (1, 60, 128), (1, 255, 100), (3, 30, 160))] tuning: (
+        u8,
+        u8,
+        u8,
+    ),
+) {
+    let input = read_file(file, ".jpg");
+
+    let model_tuning = ModelTuning {
+        adaptation_shift: tuning.0,
+        max_count: tuning.1,
+        initial_probability: tuning.2,
+    };
+    // the threads otherwise interleave their output in whatever order they finish it
+    let plain_features = EnabledFeatures {
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+    let features = EnabledFeatures {
+        model_tuning,
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+
+    let (tuned, _) = encode_lepton_verify(&input, 8, &features).unwrap();
+    let (plain, _) = encode_lepton_verify(&input, 8, &plain_features).unwrap();
+
+    println!(
+        "{0} with {1:?}: {2} bytes instead of {3} ({4:+.2}%)",
+        file,
+        model_tuning,
+        tuned.len(),
+        plain.len(),
+        (tuned.len() as f64 / plain.len() as f64 - 1.0) * 100.0
+    );
+
+    let required_features = u32::from_le_bytes(tuned[14..18].try_into().unwrap());
+    if model_tuning == ModelTuning::default() {
+        assert!(tuned == plain);
+        assert_eq!(required_features & (1 << 11), 0);
+    } else {
+        assert_ne!(required_features & (1 << 11), 0);
+    }
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&tuned), &mut output, 8).unwrap();
+    assert!(output[..] == input[..]);
+}
+
+/// tunings that would make the counts stop adapting or overflow are refused
+#[cfg(feature = "experimental-tuning")]
+#[test]
+fn verify_model_tuning_refused() {
+    for model_tuning in [
+        ModelTuning {
+            adaptation_shift: 0,
+            ..ModelTuning::default()
+        },
+        ModelTuning {
+            adaptation_shift: 8,
+            ..ModelTuning::default()
+        },
+        ModelTuning {
+            max_count: 1,
+            ..ModelTuning::default()
+        },
+        ModelTuning {
+            initial_probability: 0,
+            ..ModelTuning::default()
+        },
+    ] {
+        let features = EnabledFeatures {
+            model_tuning,
+            ..EnabledFeatures::default()
+        };
+
+        assert_eq!(
+            encode_lepton(
+                &mut Cursor::new(read_file("tiny", ".jpg")),
+                &mut Cursor::new(Vec::new()),
+                8,
+                &features
+            )
+            .unwrap_err()
+            .exit_code,
+            ExitCode::SyntaxError
+        );
+    }
+}
+
+/// separate chroma models round trip and change the coding of images with color, and files with them are
+/// refused by decoders of older versions of the format
+#[rstest]
+fn verify_separate_chroma_models(
+    #[values("android", "iphoneprogressive", "slrhills", "grayscale", "trunc")] file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        separate_chroma_models: true,
+        ..EnabledFeatures::default()
+    };
+
+    let (separate, _) = encode_lepton_verify(&input, 8, &features).unwrap();
+    let (shared, _) = encode_lepton_verify(&input, 8, &EnabledFeatures::default()).unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&separate), &mut output, 8).unwrap();
+    assert!(output[..] == input[..]);
+
+    // grayscale images have no Cr component, so only the flag in the header changes
+    assert_eq!(separate[..14], shared[..14]);
+    assert_eq!(
+        separate.len() == shared.len(),
+        file == "grayscale",
+        "{0}: {1} bytes with separate chroma models, {2} without",
+        file,
+        separate.len(),
+        shared.len()
+    );
+
+    assert_eq!(
+        decode_lepton_with_features(
+            &mut Cursor::new(&separate),
+            &mut Vec::new(),
+            8,
+            &EnabledFeatures::default().with_format_version(LeptonVersion::V4)
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::VersionUnsupported
+    );
+
+    // older versions of the format turn the option off
+    let features = features.with_format_version(LeptonVersion::V4);
+    assert!(!features.separate_chroma_models);
+}
+
+/// quantized DC prediction round trips on its own and together with the other options, shrinks images with
+/// finely quantized DCs, and files with it are refused by decoders of older versions of the format
+#[rstest]
+fn verify_quantized_dc_prediction(
+    #[values(
+        "android",
+        "iphoneprogressive",
+        "slrhills",
+        "grayscale",
+        "trailingrst2"
+    )]
+    file: &str,
+    #[values(false, true)] separate_chroma_models: bool,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        quantized_dc_prediction: true,
+        separate_chroma_models,
+        ..EnabledFeatures::default()
+    };
+    let pixel_features = EnabledFeatures {
+        separate_chroma_models,
+        ..EnabledFeatures::default()
+    };
+
+    let (quantized, _) = encode_lepton_verify(&input, 8, &features).unwrap();
+    let (pixel, _) = encode_lepton_verify(&input, 8, &pixel_features).unwrap();
+
+    for lepton in [&quantized, &pixel] {
+        let mut output = Vec::new();
+        decode_lepton(&mut Cursor::new(lepton), &mut output, 8).unwrap();
+        assert!(output[..] == input[..]);
+    }
+
+    // these images quantize the DC in steps of less than 16
+    if file == "slrhills" || file == "trailingrst2" {
+        assert!(
+            quantized.len() < pixel.len(),
+            "{0}: {1} bytes with quantized DC prediction, {2} without",
+            file,
+            quantized.len(),
+            pixel.len()
+        );
+    }
+
+    assert_eq!(
+        decode_lepton_with_features(
+            &mut Cursor::new(&quantized),
+            &mut Vec::new(),
+            8,
+            &EnabledFeatures::default().with_format_version(LeptonVersion::V5)
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::VersionUnsupported
+    );
+
+    // older versions of the format turn the option off
+    let features = features.with_format_version(LeptonVersion::V5);
+    assert!(!features.quantized_dc_prediction);
+}
+
+/// the trained initial probabilities round trip, shrink small photos that weren't part of the training, and
+/// files with them are refused by decoders of older versions of the format. Images that are unlike photos only
+/// have to round trip.
+#[rstest]
+fn verify_trained_initial_probs(
+    #[values(
+        "android",
+        "androidprogressive",
+        "iphoneprogressive2",
+        "narrowrst",
+        "tiny",
+        "gray2sf",
+        "trailingrst2"
+    )]
+    file: &str,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        trained_initial_probs: true,
+        ..EnabledFeatures::default()
+    };
+
+    let (trained, _) = encode_lepton_verify(&input, 8, &features).unwrap();
+    let (uniform, _) = encode_lepton_verify(&input, 8, &EnabledFeatures::default()).unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&trained), &mut output, 8).unwrap();
+    assert!(output[..] == input[..]);
+
+    if file != "gray2sf" && file != "trailingrst2" {
+        assert!(
+            trained.len() < uniform.len(),
+            "{0}: {1} bytes with trained initial probabilities, {2} without",
+            file,
+            trained.len(),
+            uniform.len()
+        );
+    }
+
+    assert_eq!(
+        decode_lepton_with_features(
+            &mut Cursor::new(&trained),
+            &mut Vec::new(),
+            8,
+            &EnabledFeatures::default().with_format_version(LeptonVersion::V6)
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::VersionUnsupported
+    );
+
+    // older versions of the format turn the option off
+    let features = features.with_format_version(LeptonVersion::V6);
+    assert!(!features.trained_initial_probs);
+}
+
+/// every compression effort round trips with a decoder that only looks at the header, level 0 is faster but
+/// larger, and level 1 writes the same file as the default. Prints the size and time of each level for the
+/// corpus.
+#[test]
+fn verify_compression_effort() {
+    let files = [
+        "android",
+        "iphone",
+        "iphoneprogressive",
+        "slrhills",
+        "grayscale",
+        "trailingrst2",
+    ];
+
+    let mut totals = [0; 3];
+    for file in files {
+        let input = read_file(file, ".jpg");
+
+        // deterministic, so that the segments don't depend on how the threads are scheduled
+        let mut default = Vec::new();
+        encode_lepton(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&mut default),
+            8,
+            &EnabledFeatures {
+                deterministic: true,
+                ..EnabledFeatures::default()
+            },
+        )
+        .unwrap();
+
+        for compression_effort in 0..=2 {
+            let features = EnabledFeatures {
+                compression_effort,
+                deterministic: true,
+                ..EnabledFeatures::default()
+            };
+
+            let start = std::time::Instant::now();
+            let mut lepton = Vec::new();
+            encode_lepton(
+                &mut Cursor::new(&input),
+                &mut Cursor::new(&mut lepton),
+                8,
+                &features,
+            )
+            .unwrap();
+            let elapsed = start.elapsed();
+
+            let mut output = Vec::new();
+            decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+            assert!(output[..] == input[..]);
+
+            let required_features = u32::from_le_bytes(lepton[14..18].try_into().unwrap());
+            // the flags the level turns on are in the header, which is all the decoder goes by
+            assert_eq!(
+                required_features & features.required_features(),
+                features.required_features()
+            );
+            if compression_effort == 1 {
+                assert!(lepton[..] == default[..]);
+            }
+
+            println!(
+                "{0}: effort {1}, {2} bytes ({3:+.2}%) in {4:?}",
+                file,
+                compression_effort,
+                lepton.len(),
+                (lepton.len() as f64 / default.len() as f64 - 1.0) * 100.0,
+                elapsed
+            );
+            totals[usize::from(compression_effort)] += lepton.len();
+        }
+    }
+
+    for (compression_effort, total) in totals.iter().enumerate() {
+        println!(
+            "total: effort {0}, {1} bytes ({2:+.2}%)",
+            compression_effort,
+            total,
+            (*total as f64 / totals[1] as f64 - 1.0) * 100.0
+        );
+    }
+    assert!(totals[0] > totals[1]);
+
+    // levels whose flags the format version doesn't have fall back to level 1, and others are refused
+    for compression_effort in [0, 2] {
+        let features = EnabledFeatures {
+            compression_effort,
+            ..EnabledFeatures::default()
+        };
+        assert_eq!(
+            features
+                .with_format_version(LeptonVersion::V5)
+                .compression_effort,
+            1
+        );
+    }
+    assert_eq!(
+        encode_lepton(
+            &mut Cursor::new(read_file("android", ".jpg")),
+            &mut Cursor::new(Vec::new()),
+            8,
+            &EnabledFeatures {
+                compression_effort: 3,
+                ..EnabledFeatures::default()
+            }
+        )
+        .unwrap_err()
+        .exit_code,
+        ExitCode::SyntaxError
+    );
+}
+
+/// other coefficient codecs round trip (also with a model for Cr) and are recorded in the file, while the model
+/// writes the same file as not choosing a codec at all
+#[cfg(feature = "experimental-codec")]
+#[rstest]
+fn verify_coefficient_codec(
+    #[values("android", "iphoneprogressive", "grayscale", "trunc")] file: &str,
+    #[values(CoefficientCodecKind::Model, CoefficientCodecKind::PositionalSign)]
+    coefficient_codec: CoefficientCodecKind,
+    #[values(false, true)] separate_chroma_models: bool,
+) {
+    let input = read_file(file, ".jpg");
+
+    // the threads otherwise interleave their output in whatever order they finish it
+    let plain_features = EnabledFeatures {
+        separate_chroma_models,
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+    let features = EnabledFeatures {
+        coefficient_codec,
+        separate_chroma_models,
+        deterministic: true,
+        ..EnabledFeatures::default()
+    };
+
+    let (coded, _) = encode_lepton_verify(&input, 8, &features).unwrap();
+    let (plain, _) = encode_lepton_verify(&input, 8, &plain_features).unwrap();
+
+    println!(
+        "{0} with {1:?}: {2} bytes instead of {3} ({4:+.2}%)",
+        file,
+        coefficient_codec,
+        coded.len(),
+        plain.len(),
+        (coded.len() as f64 / plain.len() as f64 - 1.0) * 100.0
+    );
+
+    let required_features = u32::from_le_bytes(coded[14..18].try_into().unwrap());
+    if coefficient_codec == CoefficientCodecKind::Model {
+        assert!(coded == plain);
+        assert_eq!(required_features & (1 << 13), 0);
+    } else {
+        assert!(coded != plain);
+        assert_ne!(required_features & (1 << 13), 0);
+    }
+
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&coded), &mut output, 8).unwrap();
+    assert!(output[..] == input[..]);
+}
+
+/// allocating the whole model up front codes exactly like allocating it as it is used
+#[rstest]
+fn verify_low_latency(#[values("tiny", "android", "iphoneprogressive", "trunc")] file: &str) {
+    let input = read_file(file, ".jpg");
+
+    // the threads otherwise interleave their output in whatever order they finish it
+    let (lazy, _) = encode_lepton_verify(
+        &input,
+        8,
+        &EnabledFeatures {
+            deterministic: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+    let (eager, _) = encode_lepton_verify(
+        &input,
+        8,
+        &EnabledFeatures {
+            deterministic: true,
+            low_latency: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+    assert!(lazy == eager);
+
+    let mut output = Vec::new();
+    decode_lepton_with_features(
+        &mut Cursor::new(&lazy),
+        &mut output,
+        8,
+        &EnabledFeatures {
+            low_latency: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+    assert!(output[..] == input[..]);
+}
+
+/// every bit the encoder codes is counted by exactly one branch of the models
+#[cfg(feature = "stats")]
+#[rstest]
+fn verify_branch_usage(#[values(false, true)] separate_chroma_models: bool) {
+    let input = read_file("tiny", ".jpg");
+
+    let metrics = encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(Vec::new()),
+        8,
+        &EnabledFeatures {
+            separate_chroma_models,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    let usage = metrics.get_branch_usage();
+    let visits: u64 = usage.iter().map(|u| u64::from(u.visits)).sum();
+
+    assert!(visits > 0);
+    assert_eq!(visits, metrics.get_coded_decisions());
+
+    // every branch of the models is reported, including the ones that were never used
+    assert!(usage.iter().any(|u| u.visits == 0));
+    assert_eq!(usage.iter().any(|u| u.model == 1), separate_chroma_models);
+    assert_eq!(metrics.branch_usage_csv().lines().count(), usage.len() + 1);
+}
+
+/// the decoder counts the same decisions, renormalizations and bits as the encoder of the same file, and the
+/// bits of the components fit in the coded data
+#[cfg(feature = "stats")]
+#[rstest]
+fn verify_coder_stats(#[values("tiny", "android", "gray2sf", "iphoneprogressive")] file: &str) {
+    let input = read_file(file, ".jpg");
+
+    let mut lepton = Vec::new();
+    let encoded = encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures::default(),
+    )
+    .unwrap();
+
+    let decoded = decode_lepton_with_features(
+        &mut Cursor::new(&lepton),
+        &mut Vec::new(),
+        8,
+        &EnabledFeatures::default(),
+    )
+    .unwrap();
+
+    let encoded = encoded.get_coder_stats();
+    let decoded = decoded.get_coder_stats();
+
+    assert!(encoded.decisions > 0);
+    assert!(encoded.renormalizations > 0 && encoded.renormalizations < encoded.decisions);
+    assert_eq!(encoded.decisions, decoded.decisions);
+    assert_eq!(encoded.renormalizations, decoded.renormalizations);
+    assert_eq!(encoded.component_bits, decoded.component_bits);
+    assert_eq!(decoded.carries, 0);
+
+    let coded_bytes: u64 = (0..4).map(|c| encoded.component_bytes(c)).sum();
+    assert!(coded_bytes > 0 && coded_bytes < lepton.len() as u64);
+}
+
+/// the decoder makes the same decisions in the same contexts as the encoder for the small images, with each
+/// compression effort since they pick the contexts of the DC differently
+#[cfg(feature = "debug-symmetry")]
+#[rstest]
+fn verify_symmetry(
+    #[values(
+        "tiny",
+        "android",
+        "iphoneprogressive",
+        "gray2sf",
+        "trailingrst2",
+        "narrowrst",
+        "colorswap"
+    )]
+    file: &str,
+    #[values(0, 1, 2)] compression_effort: u8,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        compression_effort,
+        ..EnabledFeatures::default()
+    };
+
+    if let Some(divergence) = check_symmetry(&input, &features, 1 << 16).unwrap() {
+        panic!("{0}: {1}", file, divergence);
+    }
+}
+
+#[cfg(feature = "test-utils")]
+proptest! {
+    /// the decoder gets back every coefficient of pseudo-random blocks, whatever the shape of the image and the
+    /// quantization tables that the seed picks
+    #[test]
+    fn verify_roundtrip_blocks(seed in any::<u64>(), blocks in 1usize..600) {
+        if let Err(divergence) = roundtrip_blocks(seed, blocks) {
+            prop_assert!(false, "seed {0} blocks {1}: {2}", seed, blocks, divergence);
+        }
+    }
+}
+
+/// the number of cases of the property tests that are cheap enough to run many more of in the nightly build,
+/// which sets LEPTON_NIGHTLY_TESTS
+#[cfg(feature = "test-utils")]
+fn nightly_config() -> ProptestConfig {
+    if std::env::var_os("LEPTON_NIGHTLY_TESTS").is_some() {
+        ProptestConfig::with_cases(100_000)
+    } else {
+        ProptestConfig::default()
+    }
+}
+
+#[cfg(feature = "test-utils")]
+proptest! {
+    #![proptest_config(nightly_config())]
+
+    /// the bool coder decodes every decision that it coded, whatever their probabilities
+    #[test]
+    fn verify_bool_coder_roundtrip(seed in any::<u64>(), decisions in 0usize..50000) {
+        match bool_coder_roundtrip(seed, decisions) {
+            Ok(stats) => prop_assert_eq!(stats.decisions, decisions),
+            Err(divergence) => {
+                prop_assert!(false, "seed {0} decisions {1}: {2}", seed, decisions, divergence)
+            }
+        }
+    }
+}
+
+/// the encoder and decoder see the same model state before every block of a healthy file, and a hook for a
+/// single dpos only gets that block
+#[rstest]
+fn verify_model_snapshots(#[values("tiny", "nofsync")] file: &str) {
+    type Snapshots = Arc<Mutex<HashMap<(usize, i32), u64>>>;
+
+    let features_with_hook = |snapshots: &Snapshots, dpos: Option<i32>| {
+        let snapshots = snapshots.clone();
+        EnabledFeatures {
+            model_snapshot_hook: Some(ModelSnapshotHook {
+                dpos,
+                callback: Arc::new(move |component, dpos, snapshot: &ModelSnapshot| {
+                    let mut hasher = DefaultHasher::new();
+                    snapshot.hash(&mut hasher);
+
+                    let previous = snapshots
+                        .lock()
+                        .unwrap()
+                        .insert((component, dpos), hasher.finish());
+                    assert!(previous.is_none(), "block coded twice");
+                }),
+            }),
+            ..EnabledFeatures::default()
+        }
+    };
+
+    let input = read_file(file, ".jpg");
+
+    let encoded = Snapshots::default();
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features_with_hook(&encoded, None),
+    )
+    .unwrap();
+
+    let decoded = Snapshots::default();
+    let mut output = Vec::new();
+    decode_lepton_with_features(
+        &mut Cursor::new(&lepton),
+        &mut output,
+        8,
+        &features_with_hook(&decoded, None),
+    )
+    .unwrap();
+    assert!(output[..] == input[..]);
+
+    let encoded = encoded.lock().unwrap();
+    assert!(encoded.len() > 1);
+    assert!(*encoded == *decoded.lock().unwrap());
+
+    // the hook doesn't change the file
+    let mut plain = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut plain),
+        8,
+        &EnabledFeatures::default(),
+    )
+    .unwrap();
+    assert!(plain[..] == lepton[..]);
+
+    let single = Snapshots::default();
+    decode_lepton_with_features(
+        &mut Cursor::new(&lepton),
+        &mut Vec::new(),
+        8,
+        &features_with_hook(&single, Some(1)),
+    )
+    .unwrap();
+
+    let single = single.lock().unwrap();
+    assert!(!single.is_empty());
+    for (key, hash) in single.iter() {
+        assert_eq!(key.1, 1);
+        assert_eq!(encoded[key], *hash);
+    }
+}
+
+/// the hook is told once about the start and once about the end of each segment by the encoder and the decoder,
+/// on the thread that works on it
+#[test]
+fn verify_thread_lifecycle_hook() {
+    type Events = Arc<Mutex<Vec<(ThreadLifecycleEvent, std::thread::ThreadId)>>>;
+
+    let input = read_file("iphonecity", ".jpg");
+
+    let events: Events = Arc::new(Mutex::new(Vec::new()));
+    let features = EnabledFeatures {
+        target_segments: Some(4),
+        thread_lifecycle_hook: Some(ThreadLifecycleHook {
+            callback: {
+                let events = events.clone();
+                Arc::new(move |e| {
+                    events
+                        .lock()
+                        .unwrap()
+                        .push((*e, std::thread::current().id()))
+                })
+            },
+        }),
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        2,
+        &features,
+    )
+    .unwrap();
+
+    let mut output = Vec::new();
+    decode_lepton_with_features(&mut Cursor::new(&lepton), &mut output, 2, &features).unwrap();
+    assert!(input[..] == output[..]);
+
+    let events = events.lock().unwrap();
+    for kind in [WorkerKind::Encoder, WorkerKind::Decoder] {
+        for segment in 0..4 {
+            let started: Vec<_> = events
+                .iter()
+                .filter(|(e, _)| *e == ThreadLifecycleEvent::Started { kind, segment })
+                .collect();
+            let finished: Vec<_> = events
+                .iter()
+                .filter(|(e, _)| {
+                    matches!(e, ThreadLifecycleEvent::Finished { kind: k, segment: s, .. }
+                        if *k == kind && *s == segment)
+                })
+                .collect();
+
+            assert_eq!(started.len(), 1, "{0:?} {1}", kind, segment);
+            assert_eq!(finished.len(), 1, "{0:?} {1}", kind, segment);
+            assert_eq!(started[0].1, finished[0].1);
+        }
+    }
+    assert_eq!(events.len(), 16);
+}
+
+/// splitting a file into shards and joining them in any order gives back the same file
+#[rstest]
+fn verify_split_join(
+    #[values(
+        "iphone",
+        "iphoneprogressive",
+        "android",
+        "iphonecity_with_16KGarbage",
+        "trunc"
+    )]
+    file: &str,
+    #[values(false, true)] segment_index: bool,
+) {
+    let input = read_file(file, ".jpg");
+
+    let features = EnabledFeatures {
+        segment_index,
+        target_segments: Some(5),
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features,
+    )
+    .unwrap();
+
+    let mut shards = split_lepton(&lepton).unwrap();
+    assert_eq!(
+        shards.len(),
+        inspect_lepton_structure(&lepton).unwrap().segments.len()
+    );
+    assert_eq!(join_lepton(&shards).unwrap(), lepton);
+
+    shards.reverse();
+    assert_eq!(join_lepton(&shards).unwrap(), lepton);
+
+    shards.rotate_left(2);
+    assert_eq!(join_lepton(&shards).unwrap(), lepton);
+}
+
+/// files written by the C++ implementation, which has a different framing for its chunks, split and join as well
+#[rstest]
+fn verify_split_join_cpp(#[values("android", "iphonecity", "slrcity")] file: &str) {
+    let lepton = read_file(file, ".lep");
+
+    let shards = split_lepton(&lepton).unwrap();
+    assert_eq!(join_lepton(&shards).unwrap(), lepton);
+}
+
+/// the shards of a baseline image decode to the scan data of their rows
+#[test]
+fn verify_decode_shards() {
+    let input = read_file("iphone", ".jpg");
+
+    let features = EnabledFeatures {
+        segment_index: true,
+        target_segments: Some(4),
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features,
+    )
+    .unwrap();
+
+    let shards = split_lepton(&lepton).unwrap();
+    assert_eq!(shards.len(), 4);
+
+    // each shard is decoded on its own, as it would be on a different machine
+    let mut scan = Vec::new();
+    for shard in &shards {
+        scan.extend_from_slice(&decode_shard(shard).unwrap().data);
+    }
+
+    // which together is the scan data of the original JPEG
+    let scan_start = input.windows(16).position(|w| w == &scan[..16]).unwrap();
+    assert!(input[scan_start..scan_start + scan.len()] == scan[..]);
+
+    // a damaged shard is caught by its checksum
+    let mut damaged = shards[1].clone();
+    let last = damaged.chunks.len() - 1;
+    damaged.chunks[last] ^= 1;
+    assert_eq!(
+        decode_shard(&damaged).unwrap_err().exit_code,
+        ExitCode::CorruptSegment
+    );
+}
+
+/// every thread segment starts from a model of its own, so the segments decode to the same rows whatever order
+/// they are decoded in
+#[rstest]
+fn verify_segments_decode_in_reverse_order(
+    #[values(false, true)] separate_chroma_models: bool,
+    #[values(false, true)] trained_initial_probs: bool,
+) {
+    let input = read_file("iphone", ".jpg");
+
+    let features = EnabledFeatures {
+        segment_index: true,
+        target_segments: Some(4),
+        separate_chroma_models,
+        trained_initial_probs,
+        ..EnabledFeatures::default()
+    };
+
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &features,
+    )
+    .unwrap();
+
+    let shards = split_lepton(&lepton).unwrap();
+    assert_eq!(shards.len(), 4);
+
+    let forward: Vec<Vec<u8>> = shards
+        .iter()
+        .map(|shard| decode_shard(shard).unwrap().data)
+        .collect();
+
+    let mut reverse: Vec<Vec<u8>> = shards
+        .iter()
+        .rev()
+        .map(|shard| decode_shard(shard).unwrap().data)
+        .collect();
+    reverse.reverse();
+
+    assert!(forward == reverse);
+
+    // and the whole file still decodes to the original
+    let mut output = Vec::new();
+    decode_lepton(&mut Cursor::new(&lepton), &mut output, 8).unwrap();
+    assert!(output == input);
+}
+
+/// the segments of a plan decoded on a thread pool of the caller, in whatever order the pool runs them, assemble
+/// into the original JPEG
+#[rstest]
+fn verify_decode_plan_on_caller_pool(
+    #[values(
+        "android",
+        "androidprogressive",
+        "hq",
+        "iphonecity",
+        "iphonecity_with_1MGarbage",
+        "iphoneprogressive",
+        "trailingrst",
+        "trunc"
+    )]
+    file: &str,
+) {
+    use rayon::prelude::*;
+
+    let input = read_file(file, ".lep");
+    let expected = read_file(file, ".jpg");
+
+    let plan = plan_decode(&input).unwrap();
+    assert_eq!(plan.num_segments(), plan.segments().len());
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let outputs = pool
+        .install(|| {
+            (0..plan.num_segments())
+                .into_par_iter()
+                .rev()
+                .map(|index| decode_segment(&plan, index))
+                .collect::<Result<Vec<SegmentOutput>, LeptonError>>()
+        })
+        .unwrap();
+
+    let output = assemble(plan, outputs).unwrap();
+    assert!(output[..] == expected[..]);
+}
+
+/// the plan can be shared between threads and the outputs sent between them, files that store the original as is
+/// have nothing to decode, and every segment has to be decoded exactly once
+#[test]
+fn verify_decode_plan_outputs() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DecodePlan>();
+    assert_send_sync::<SegmentOutput>();
+
+    let input = read_file("colorswap", ".jpg");
+    let mut lepton = Vec::new();
+    encode_lepton(
+        &mut Cursor::new(&input),
+        &mut Cursor::new(&mut lepton),
+        8,
+        &EnabledFeatures {
+            passthrough: true,
+            ..EnabledFeatures::default()
+        },
+    )
+    .unwrap();
+
+    let plan = plan_decode(&lepton).unwrap();
+    assert_eq!(plan.num_segments(), 0);
+    assert!(assemble(plan, Vec::new()).unwrap() == input);
+
+    let lepton = read_file("iphonecity", ".lep");
+    let decode_all = |plan: &DecodePlan| {
+        (0..plan.num_segments())
+            .map(|index| decode_segment(plan, index).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    let plan = plan_decode(&lepton).unwrap();
+    assert!(plan.num_segments() > 1);
+    let mut outputs = decode_all(&plan);
+    outputs.pop();
+    assert_eq!(
+        assemble(plan, outputs).unwrap_err().exit_code,
+        ExitCode::ShardMismatch
+    );
+
+    let plan = plan_decode(&lepton).unwrap();
+    let mut outputs = decode_all(&plan);
+    outputs.push(decode_segment(&plan, 0).unwrap());
+    assert_eq!(
+        assemble(plan, outputs).unwrap_err().exit_code,
+        ExitCode::ShardMismatch
+    );
+}
+
+/// shards that don't make up a complete file are refused
+#[test]
+fn verify_join_refused() {
+    let lepton = read_file("iphonecity", ".lep");
+    let shards = split_lepton(&lepton).unwrap();
+    assert!(shards.len() > 2);
+
+    let join_error =
+        |shards: &[lepton_jpeg::LeptonShard]| join_lepton(shards).unwrap_err().exit_code;
+
+    // missing
+    assert_eq!(join_error(&shards[1..]), ExitCode::ShardMismatch);
+    assert_eq!(join_error(&[]), ExitCode::ShardMismatch);
+
+    let mut missing_middle = shards.clone();
+    missing_middle.remove(1);
+    assert_eq!(join_error(&missing_middle), ExitCode::ShardMismatch);
+
+    // duplicated
+    let mut duplicated = shards.clone();
+    duplicated.push(shards[0].clone());
+    assert_eq!(join_error(&duplicated), ExitCode::ShardMismatch);
+
+    // rows that don't follow each other
+    let mut gap = shards.clone();
+    gap[1].thread_handoff.luma_y_start += 8;
+    assert_eq!(join_error(&gap), ExitCode::ShardMismatch);
+
+    // chunks that take the same place
+    let mut overlapping = shards.clone();
+    overlapping[1].chunk_positions[0] = overlapping[0].chunk_positions[0];
+    assert_eq!(join_error(&overlapping), ExitCode::ShardMismatch);
+
+    // a shard from another file
+    let other = split_lepton(&read_file("slrcity", ".lep")).unwrap();
+    let mut mixed = shards.clone();
+    mixed[0] = other[0].clone();
+    assert_eq!(join_error(&mixed), ExitCode::ShardMismatch);
+}